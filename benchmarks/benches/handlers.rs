@@ -0,0 +1,46 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use ibc::core::entrypoint::{dispatch, validate};
+use ibc_benchmarks::{
+    mock_recv_packet_fixture, recv_packet_fixture, update_client_fixture, HandlerFixture,
+};
+
+/// Benchmarks the validation of the message of the fixture, which leaves the
+/// host untouched, and its full dispatch on a fresh copy of the host.
+fn bench_fixture(c: &mut Criterion, name: &str, fixture: HandlerFixture) {
+    let HandlerFixture {
+        ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("validate", |b| {
+        b.iter(|| validate(&ctx, &router, msg.clone()).expect("Never fails"))
+    });
+
+    group.bench_function("dispatch", |b| {
+        b.iter_batched(
+            || (ctx.clone(), msg.clone()),
+            |(mut ctx, msg)| dispatch(&mut ctx, &mut router, msg).expect("Never fails"),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn update_client(c: &mut Criterion) {
+    bench_fixture(c, "update_client_tendermint", update_client_fixture());
+}
+
+fn recv_packet(c: &mut Criterion) {
+    bench_fixture(c, "recv_packet_ics23_proof", recv_packet_fixture());
+}
+
+fn dispatch_overhead(c: &mut Criterion) {
+    bench_fixture(c, "recv_packet_mock_proof", mock_recv_packet_fixture());
+}
+
+criterion_group!(handlers, update_client, recv_packet, dispatch_overhead);
+criterion_main!(handlers);
@@ -0,0 +1,287 @@
+//! Fixtures of the handler benchmarks.
+//!
+//! Each fixture provides a mock host, its router, and a message whose handling
+//! is benchmarked. The fixtures are kept out of the benchmark targets so that
+//! they can be checked without running the benchmarks.
+
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::types::{
+    client_type as tm_client_type, ConsensusState as TmConsensusStateType,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{
+    calculate_existence_root, CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager,
+    LeafOp, LengthOp,
+};
+use ibc::core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+/// The store prefix of the IBC state of the counterparty chain.
+const STORE_PREFIX: &[u8] = b"ibc";
+
+/// A mock host, its router, and a message to be handled by the host.
+pub struct HandlerFixture {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgEnvelope,
+}
+
+/// Returns a host with a Tendermint client, and an update of the client with
+/// the next block of the counterparty chain, so that the full verification of
+/// the header is exercised.
+pub fn update_client_fixture() -> HandlerFixture {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).expect("Never fails");
+    let update_height = client_height.increment();
+    let chain_id_b = ChainId::new("mockgaiaB-1").expect("Never fails");
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").expect("Never fails"))
+        .latest_height(Height::new(1, 1).expect("Never fails"))
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b
+        .host_block(&update_height)
+        .expect("Never fails")
+        .clone();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    HandlerFixture {
+        ctx,
+        router: MockRouter::new_with_transfer(),
+        msg: MsgEnvelope::from(ClientMsg::from(msg)),
+    }
+}
+
+/// Returns a host with a Tendermint client and an open transfer channel, and
+/// the receipt of a packet with a proof of its commitment built with ICS-23,
+/// so that the full verification of the proof is exercised.
+pub fn recv_packet_fixture() -> HandlerFixture {
+    let client_id = tm_client_type().build_client_id(0);
+    let proof_height = Height::new(1, 2).expect("Never fails");
+    let packet = dummy_packet();
+
+    let ctx = channel_host(&client_id, tm_client_type().as_str(), proof_height);
+
+    // Commit the packet on the counterparty chain, whose state is made of a
+    // single IBC store holding the packet commitment only.
+    let path = CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+    let commitment = compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    );
+    let (store_proof, store_root) = single_leaf_proof(
+        path.to_string().into_bytes(),
+        commitment.into_vec(),
+        // An IAVL leaf at height 0, of size 1, and at version 1.
+        vec![0, 2, 2],
+    );
+    let (root_proof, root) = single_leaf_proof(STORE_PREFIX.to_vec(), store_root, vec![0]);
+
+    {
+        let mut ibc_store = ctx.ibc_store.lock();
+        let consensus_state = ibc_store
+            .clients
+            .get_mut(&client_id)
+            .expect("Never fails")
+            .consensus_states
+            .get_mut(&proof_height)
+            .expect("Never fails");
+
+        let tm_consensus_state = match consensus_state {
+            AnyConsensusState::Tendermint(tm_consensus_state) => tm_consensus_state,
+            _ => unreachable!("the client is a Tendermint client"),
+        };
+        let inner = tm_consensus_state.inner();
+
+        *consensus_state = TmConsensusState::from(TmConsensusStateType::new(
+            root.into(),
+            inner.timestamp,
+            inner.next_validators_hash,
+        ))
+        .into();
+    }
+
+    let proof_commitment_on_a = CommitmentProofBytes::try_from(RawMerkleProof::from(MerkleProof {
+        proofs: vec![store_proof, root_proof],
+    }))
+    .expect("Never fails");
+
+    let msg = MsgRecvPacket {
+        packet,
+        proof_commitment_on_a,
+        proof_height_on_a: proof_height,
+        signer: dummy_account_id(),
+    };
+
+    HandlerFixture {
+        ctx,
+        router: MockRouter::new_with_transfer(),
+        msg: MsgEnvelope::Packet(PacketMsg::Recv(msg)),
+    }
+}
+
+/// Returns a host with a mock client and an open transfer channel, and the
+/// receipt of a packet, whose proof the mock client does not verify, so that
+/// the overhead of the dispatch of the message is measured.
+pub fn mock_recv_packet_fixture() -> HandlerFixture {
+    let client_id = mock_client_type().build_client_id(0);
+    let proof_height = Height::new(1, 2).expect("Never fails");
+
+    let msg = MsgRecvPacket {
+        packet: dummy_packet(),
+        proof_commitment_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a: proof_height,
+        signer: dummy_account_id(),
+    };
+
+    HandlerFixture {
+        ctx: channel_host(&client_id, mock_client_type().as_str(), proof_height),
+        router: MockRouter::new_with_transfer(),
+        msg: MsgEnvelope::Packet(PacketMsg::Recv(msg)),
+    }
+}
+
+/// Returns a host with a counterparty client of the given type at
+/// `client_height`, and an open transfer channel over it.
+fn channel_host(client_id: &ClientId, client_type: &str, client_height: Height) -> MockContext {
+    let conn_id = ConnectionId::new(0);
+    let chan_id = ChannelId::new(0);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(conn_id.clone()),
+            CommitmentPrefix::try_from(STORE_PREFIX.to_vec()).expect("Never fails"),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .expect("Never fails");
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .expect("Never fails");
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaB-1").expect("Never fails"))
+        .latest_height(Height::new(1, 5).expect("Never fails"))
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(ChainId::new("mockgaiaA-1").expect("Never fails"))
+                .client_id(client_id.clone())
+                .client_type(client_type.parse().expect("Never fails"))
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(conn_id, conn_end)
+        .with_channel(PortId::transfer(), chan_id, chan_end);
+
+    // The client update metadata is needed to check the connection delay.
+    {
+        let mut ibc_store = ctx.ibc_store.lock();
+        let key = (client_id.clone(), client_height);
+        ibc_store
+            .client_processed_times
+            .insert(key.clone(), Timestamp::now());
+        ibc_store
+            .client_processed_heights
+            .insert(key, ctx.latest_height());
+    }
+
+    ctx
+}
+
+fn dummy_packet() -> Packet {
+    Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        data: br#"{"denom":"uatom","amount":"100","sender":"a","receiver":"b"}"#.to_vec(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+/// Returns the proof of the given key-value pair in a tree made of a single
+/// leaf, along with the root of the tree.
+fn single_leaf_proof(key: Vec<u8>, value: Vec<u8>, prefix: Vec<u8>) -> (CommitmentProof, Vec<u8>) {
+    let existence_proof = ExistenceProof {
+        key,
+        value,
+        leaf: Some(LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        }),
+        path: vec![],
+    };
+
+    let root =
+        calculate_existence_root::<HostFunctionsManager>(&existence_proof).expect("Never fails");
+
+    (
+        CommitmentProof {
+            proof: Some(Proof::Exist(existence_proof)),
+        },
+        root,
+    )
+}
@@ -0,0 +1,14 @@
+use ibc::core::entrypoint::dispatch;
+use ibc_benchmarks::{mock_recv_packet_fixture, recv_packet_fixture, update_client_fixture};
+
+#[test]
+fn fixtures_are_handled() {
+    for mut fixture in [
+        update_client_fixture(),
+        recv_packet_fixture(),
+        mock_recv_packet_fixture(),
+    ] {
+        let res = dispatch(&mut fixture.ctx, &mut fixture.router, fixture.msg);
+        assert!(res.is_ok(), "{res:?}");
+    }
+}
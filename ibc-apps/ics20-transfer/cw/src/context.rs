@@ -0,0 +1,393 @@
+//! Implements the token transfer contexts on top of the storage of a CosmWasm
+//! contract.
+//!
+//! The contract keeps its own ledger of the tokens received over IBC: the
+//! vouchers minted for the accounts, and the tokens escrowed by each channel
+//! of the contract. Amounts are stored as decimal strings under keys made of
+//! length-prefixed segments, so that denominations containing `/` cannot
+//! collide with the identifiers of the holders.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use cosmwasm_std::{Env, Storage};
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_app_transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::host::types::identifiers::{ChannelId, PortId, PortPrefix};
+use ibc_core::primitives::Signer;
+
+use crate::error::ContractError;
+
+/// The namespace of the balances in the storage of the contract.
+const BALANCES_NAMESPACE: &str = "balances";
+
+/// The address of an account holding tokens in the ledger of the contract.
+///
+/// The address is not validated against the address format of the chain,
+/// since the conversion from a [`Signer`] has no access to the API of the
+/// contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountId(String);
+
+impl AccountId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<Signer> for AccountId {
+    type Error = TokenTransferError;
+
+    fn try_from(signer: Signer) -> Result<Self, Self::Error> {
+        let address = signer.as_ref().trim();
+
+        if address.is_empty() {
+            return Err(TokenTransferError::ParseAccountFailure);
+        }
+
+        Ok(Self(address.to_string()))
+    }
+}
+
+impl Display for AccountId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A holder of tokens in the ledger of the contract.
+enum Holder<'a> {
+    Account(&'a AccountId),
+    Escrow(&'a PortId, &'a ChannelId),
+}
+
+impl Holder<'_> {
+    fn balance_key(&self, denom: &PrefixedDenom) -> Vec<u8> {
+        let denom = denom.to_string();
+
+        match self {
+            Self::Account(account) => {
+                storage_key(&[BALANCES_NAMESPACE, "account", account.as_str(), &denom])
+            }
+            Self::Escrow(port_id, channel_id) => storage_key(&[
+                BALANCES_NAMESPACE,
+                "escrow",
+                port_id.as_str(),
+                channel_id.as_str(),
+                &denom,
+            ]),
+        }
+    }
+}
+
+/// Returns the storage key made of the given segments, each prefixed by its
+/// length.
+fn storage_key(segments: &[&str]) -> Vec<u8> {
+    let mut key = Vec::new();
+
+    for segment in segments {
+        key.extend_from_slice(&(segment.len() as u32).to_be_bytes());
+        key.extend_from_slice(segment.as_bytes());
+    }
+
+    key
+}
+
+/// The token transfer contexts of a CosmWasm contract, keeping the balances of
+/// the accounts and of the escrows of its channels in the storage of the
+/// contract.
+pub struct StorageTransferContext<'a> {
+    storage: &'a mut dyn Storage,
+    port_id: PortId,
+}
+
+impl<'a> StorageTransferContext<'a> {
+    /// Returns the context of the contract of `env`, bound to the port
+    /// assigned to the contract by `wasmd`, i.e. `wasm.{contract_address}`.
+    pub fn new(storage: &'a mut dyn Storage, env: &Env) -> Result<Self, ContractError> {
+        let port_id = PortPrefix::wasm().port_id(env.contract.address.as_str())?;
+
+        Ok(Self { storage, port_id })
+    }
+
+    /// Returns the amount of `denom` held by `account`.
+    pub fn balance(
+        &self,
+        account: &AccountId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, ContractError> {
+        self.read_balance(&Holder::Account(account), denom)
+    }
+
+    /// Returns the amount of `denom` escrowed by the given channel.
+    pub fn escrowed(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, ContractError> {
+        self.read_balance(&Holder::Escrow(port_id, channel_id), denom)
+    }
+
+    fn read_balance(
+        &self,
+        holder: &Holder<'_>,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, ContractError> {
+        let key = holder.balance_key(denom);
+
+        match self.storage.get(&key) {
+            Some(value) => core::str::from_utf8(&value)
+                .ok()
+                .and_then(|amount| Amount::from_str(amount).ok())
+                .ok_or(ContractError::CorruptedAmount { key }),
+            None => Ok(Amount::from(0)),
+        }
+    }
+
+    fn write_balance(&mut self, holder: &Holder<'_>, denom: &PrefixedDenom, amount: Amount) {
+        let key = holder.balance_key(denom);
+
+        if amount == Amount::from(0) {
+            self.storage.remove(&key);
+        } else {
+            self.storage.set(&key, amount.to_string().as_bytes());
+        }
+    }
+
+    /// Checks that `holder` holds at least the amount of `coin`, and returns
+    /// its balance after the amount is withdrawn.
+    fn checked_withdrawal(
+        &self,
+        holder: &Holder<'_>,
+        coin: &PrefixedCoin,
+    ) -> Result<Amount, TokenTransferError> {
+        let balance = self.read_balance(holder, &coin.denom)?;
+
+        balance
+            .checked_sub(coin.amount)
+            .ok_or_else(|| TokenTransferError::InsufficientFunds {
+                send_attempt: coin.to_string(),
+                available_funds: PrefixedCoin {
+                    denom: coin.denom.clone(),
+                    amount: balance,
+                }
+                .to_string(),
+            })
+    }
+
+    /// Checks that the amount of `coin` can be deposited to `holder`, and
+    /// returns its balance after the deposit.
+    fn checked_deposit(
+        &self,
+        holder: &Holder<'_>,
+        coin: &PrefixedCoin,
+    ) -> Result<Amount, TokenTransferError> {
+        let balance = self.read_balance(holder, &coin.denom)?;
+
+        balance
+            .checked_add(coin.amount)
+            .ok_or_else(|| TokenTransferError::Other(format!("balance overflow: {coin}")))
+    }
+
+    fn transfer(
+        &mut self,
+        from: &Holder<'_>,
+        to: &Holder<'_>,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let from_balance = self.checked_withdrawal(from, coin)?;
+        self.write_balance(from, &coin.denom, from_balance);
+
+        let to_balance = self.checked_deposit(to, coin)?;
+        self.write_balance(to, &coin.denom, to_balance);
+
+        Ok(())
+    }
+}
+
+impl TokenTransferValidationContext for StorageTransferContext<'_> {
+    type AccountId = AccountId;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(self.port_id.clone())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.checked_withdrawal(&Holder::Account(from_account), coin)?;
+        self.checked_deposit(&Holder::Escrow(port_id, channel_id), coin)?;
+
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.checked_withdrawal(&Holder::Escrow(port_id, channel_id), coin)?;
+        self.checked_deposit(&Holder::Account(to_account), coin)?;
+
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.checked_deposit(&Holder::Account(account), coin)?;
+
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.checked_withdrawal(&Holder::Account(account), coin)?;
+
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for StorageTransferContext<'_> {
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.transfer(
+            &Holder::Account(from_account),
+            &Holder::Escrow(port_id, channel_id),
+            coin,
+        )
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.transfer(
+            &Holder::Escrow(port_id, channel_id),
+            &Holder::Account(to_account),
+            coin,
+        )
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        let holder = Holder::Account(account);
+        let balance = self.checked_deposit(&holder, coin)?;
+        self.write_balance(&holder, &coin.denom, balance);
+
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        let holder = Holder::Account(account);
+        let balance = self.checked_withdrawal(&holder, coin)?;
+        self.write_balance(&holder, &coin.denom, balance);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+
+    use super::*;
+
+    fn coin(amount: u64) -> PrefixedCoin {
+        PrefixedCoin {
+            denom: "transfer/channel-0/uatom".parse().unwrap(),
+            amount: amount.into(),
+        }
+    }
+
+    #[test]
+    fn test_escrow_roundtrip() {
+        let mut storage = MockStorage::new();
+        let mut ctx = StorageTransferContext::new(&mut storage, &mock_env()).unwrap();
+        let account = AccountId::try_from(Signer::from("sender".to_string())).unwrap();
+        let port_id = ctx.get_port().unwrap();
+        let channel_id = ChannelId::new(0);
+        let memo = Memo::from(String::new());
+
+        ctx.mint_coins_execute(&account, &coin(100)).unwrap();
+        ctx.escrow_coins_execute(&account, &port_id, &channel_id, &coin(60), &memo)
+            .unwrap();
+
+        assert_eq!(
+            ctx.balance(&account, &coin(0).denom).unwrap(),
+            Amount::from(40)
+        );
+        assert_eq!(
+            ctx.escrowed(&port_id, &channel_id, &coin(0).denom).unwrap(),
+            Amount::from(60)
+        );
+
+        ctx.unescrow_coins_execute(&account, &port_id, &channel_id, &coin(60))
+            .unwrap();
+
+        assert_eq!(
+            ctx.balance(&account, &coin(0).denom).unwrap(),
+            Amount::from(100)
+        );
+        assert_eq!(
+            ctx.escrowed(&port_id, &channel_id, &coin(0).denom).unwrap(),
+            Amount::from(0)
+        );
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        let mut storage = MockStorage::new();
+        let mut ctx = StorageTransferContext::new(&mut storage, &mock_env()).unwrap();
+        let account = AccountId::try_from(Signer::from("sender".to_string())).unwrap();
+        let memo = Memo::from(String::new());
+
+        ctx.mint_coins_execute(&account, &coin(10)).unwrap();
+
+        assert!(matches!(
+            ctx.burn_coins_validate(&account, &coin(11), &memo),
+            Err(TokenTransferError::InsufficientFunds { .. })
+        ));
+        assert!(ctx.burn_coins_validate(&account, &coin(10), &memo).is_ok());
+    }
+}
@@ -0,0 +1,603 @@
+//! Implements the IBC entry points of a CosmWasm contract with the callbacks of
+//! the transfer module.
+//!
+//! Each function has the signature of the entry point of the same name, so
+//! that a contract can forward its entry points to them. The messages of
+//! CosmWasm are converted into the domain types of `ibc-rs`, and the events
+//! of the callbacks are turned into the events of the responses.
+
+use core::str::FromStr;
+
+use cosmwasm_std::{
+    DepsMut, Env, Event, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, IbcPacket,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
+};
+use ibc_app_transfer::module::{
+    on_acknowledgement_packet_execute, on_acknowledgement_packet_validate,
+    on_chan_close_confirm_execute, on_chan_close_confirm_validate, on_chan_close_init_execute,
+    on_chan_close_init_validate, on_chan_open_ack_execute, on_chan_open_ack_validate,
+    on_chan_open_confirm_execute, on_chan_open_confirm_validate, on_chan_open_init_execute,
+    on_chan_open_init_validate, on_chan_open_try_execute, on_chan_open_try_validate,
+    on_recv_packet_execute, on_timeout_packet_execute, on_timeout_packet_validate,
+};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::channel::types::Version;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::{Signer, Timestamp};
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::StorageTransferContext;
+use crate::error::ContractError;
+
+/// Handles the `ChanOpenInit` and `ChanOpenTry` steps of the channel
+/// handshake, and returns the version of the transfer application.
+pub fn ibc_channel_open(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let channel = DomainChannel::try_from(msg.channel())?;
+
+    let (_, version) = match msg.counterparty_version() {
+        None => {
+            on_chan_open_init_validate(
+                &ctx,
+                channel.order,
+                &channel.connection_hops,
+                &channel.port_id,
+                &channel.channel_id,
+                &channel.counterparty,
+                &channel.version,
+            )?;
+            on_chan_open_init_execute(
+                &mut ctx,
+                channel.order,
+                &channel.connection_hops,
+                &channel.port_id,
+                &channel.channel_id,
+                &channel.counterparty,
+                &channel.version,
+            )?
+        }
+        Some(counterparty_version) => {
+            let counterparty_version = Version::new(counterparty_version.to_string());
+
+            on_chan_open_try_validate(
+                &ctx,
+                channel.order,
+                &channel.connection_hops,
+                &channel.port_id,
+                &channel.channel_id,
+                &channel.counterparty,
+                &counterparty_version,
+            )?;
+            on_chan_open_try_execute(
+                &mut ctx,
+                channel.order,
+                &channel.connection_hops,
+                &channel.port_id,
+                &channel.channel_id,
+                &channel.counterparty,
+                &counterparty_version,
+            )?
+        }
+    };
+
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: version.to_string(),
+    }))
+}
+
+/// Handles the `ChanOpenAck` and `ChanOpenConfirm` steps of the channel
+/// handshake.
+pub fn ibc_channel_connect(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let channel = DomainChannel::try_from(msg.channel())?;
+
+    let extras = match msg.counterparty_version() {
+        Some(counterparty_version) => {
+            let counterparty_version = Version::new(counterparty_version.to_string());
+
+            on_chan_open_ack_validate(
+                &ctx,
+                &channel.port_id,
+                &channel.channel_id,
+                &counterparty_version,
+            )?;
+            on_chan_open_ack_execute(
+                &mut ctx,
+                &channel.port_id,
+                &channel.channel_id,
+                &counterparty_version,
+            )?
+        }
+        None => {
+            on_chan_open_confirm_validate(&ctx, &channel.port_id, &channel.channel_id)?;
+            on_chan_open_confirm_execute(&mut ctx, &channel.port_id, &channel.channel_id)?
+        }
+    };
+
+    Ok(basic_response(extras))
+}
+
+/// Handles the closing of a channel, which the transfer application only
+/// accepts when initiated by the counterparty.
+pub fn ibc_channel_close(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let channel = DomainChannel::try_from(msg.channel())?;
+
+    let extras = match msg {
+        IbcChannelCloseMsg::CloseInit { .. } => {
+            on_chan_close_init_validate(&ctx, &channel.port_id, &channel.channel_id)?;
+            on_chan_close_init_execute(&mut ctx, &channel.port_id, &channel.channel_id)?
+        }
+        IbcChannelCloseMsg::CloseConfirm { .. } => {
+            on_chan_close_confirm_validate(&ctx, &channel.port_id, &channel.channel_id)?;
+            on_chan_close_confirm_execute(&mut ctx, &channel.port_id, &channel.channel_id)?
+        }
+    };
+
+    Ok(basic_response(extras))
+}
+
+/// Handles the receipt of a packet, and returns its acknowledgement.
+///
+/// The failure of the transfer application to receive the tokens is not an
+/// error: it is reported to the sender by an error acknowledgement, so that
+/// the tokens are refunded. Since the transfer callbacks validate each step
+/// before executing it, no state is written by a failed receipt.
+pub fn ibc_packet_receive(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let packet = packet_from_cw(&msg.packet)?;
+
+    let (extras, acknowledgement) = on_recv_packet_execute(&mut ctx, &packet);
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(Vec::<u8>::from(acknowledgement))
+        .add_events(module_events(extras)))
+}
+
+/// Handles the acknowledgement of a packet sent by the contract, refunding
+/// the tokens of the packet if the acknowledgement is an error.
+pub fn ibc_packet_ack(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let packet = packet_from_cw(&msg.original_packet)?;
+    let acknowledgement = Acknowledgement::try_from(msg.acknowledgement.data.to_vec())?;
+    let relayer = Signer::from(msg.relayer.to_string());
+
+    on_acknowledgement_packet_validate(&ctx, &packet, &acknowledgement, &relayer)?;
+    let (extras, result) =
+        on_acknowledgement_packet_execute(&mut ctx, &packet, &acknowledgement, &relayer);
+    result?;
+
+    Ok(basic_response(extras))
+}
+
+/// Handles the timeout of a packet sent by the contract, refunding the tokens
+/// of the packet.
+pub fn ibc_packet_timeout(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = StorageTransferContext::new(deps.storage, &env)?;
+    let packet = packet_from_cw(&msg.packet)?;
+    let relayer = Signer::from(msg.relayer.to_string());
+
+    on_timeout_packet_validate(&ctx, &packet, &relayer)?;
+    let (extras, result) = on_timeout_packet_execute(&mut ctx, &packet, &relayer);
+    result?;
+
+    Ok(basic_response(extras))
+}
+
+/// The fields of a CosmWasm channel as domain types, as expected by the
+/// channel callbacks.
+struct DomainChannel {
+    order: Order,
+    connection_hops: Vec<ConnectionId>,
+    port_id: PortId,
+    channel_id: ChannelId,
+    counterparty: Counterparty,
+    version: Version,
+}
+
+impl TryFrom<&IbcChannel> for DomainChannel {
+    type Error = ContractError;
+
+    fn try_from(channel: &IbcChannel) -> Result<Self, Self::Error> {
+        let order = match channel.order {
+            IbcOrder::Unordered => Order::Unordered,
+            IbcOrder::Ordered => Order::Ordered,
+        };
+
+        // The channel identifier of the counterparty is not known yet during
+        // `ChanOpenInit`, in which case it is empty.
+        let counterparty_channel_id = match channel.counterparty_endpoint.channel_id.as_str() {
+            "" => None,
+            channel_id => Some(ChannelId::from_str(channel_id)?),
+        };
+
+        Ok(Self {
+            order,
+            connection_hops: vec![ConnectionId::from_str(&channel.connection_id)?],
+            port_id: PortId::from_str(&channel.endpoint.port_id)?,
+            channel_id: ChannelId::from_str(&channel.endpoint.channel_id)?,
+            counterparty: Counterparty::new(
+                PortId::from_str(&channel.counterparty_endpoint.port_id)?,
+                counterparty_channel_id,
+            ),
+            version: Version::new(channel.version.clone()),
+        })
+    }
+}
+
+/// Converts a CosmWasm packet into a domain packet.
+fn packet_from_cw(packet: &IbcPacket) -> Result<Packet, ContractError> {
+    let timeout_height_on_b = match packet.timeout.block() {
+        Some(block) => TimeoutHeight::At(Height::new(block.revision, block.height)?),
+        None => TimeoutHeight::Never,
+    };
+
+    let timeout_timestamp_on_b = match packet.timeout.timestamp() {
+        Some(timestamp) => Timestamp::from_nanoseconds(timestamp.nanos())?,
+        None => Timestamp::none(),
+    };
+
+    Ok(Packet {
+        seq_on_a: packet.sequence.into(),
+        port_id_on_a: PortId::from_str(&packet.src.port_id)?,
+        chan_id_on_a: ChannelId::from_str(&packet.src.channel_id)?,
+        port_id_on_b: PortId::from_str(&packet.dest.port_id)?,
+        chan_id_on_b: ChannelId::from_str(&packet.dest.channel_id)?,
+        data: packet.data.to_vec(),
+        timeout_height_on_b,
+        timeout_timestamp_on_b,
+    })
+}
+
+/// Converts the events of a callback into CosmWasm events. The logs of the
+/// callbacks have no counterpart in CosmWasm and are dropped.
+fn module_events(extras: ModuleExtras) -> Vec<Event> {
+    extras
+        .events
+        .into_iter()
+        .map(|event| {
+            Event::new(event.kind).add_attributes(
+                event
+                    .attributes
+                    .into_iter()
+                    .map(|attribute| (attribute.key, attribute.value)),
+            )
+        })
+        .collect()
+}
+
+fn basic_response(extras: ModuleExtras) -> IbcBasicResponse {
+    IbcBasicResponse::new().add_events(module_events(extras))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{
+        to_json_binary, Addr, IbcAcknowledgement, IbcEndpoint, IbcTimeout, IbcTimeoutBlock,
+    };
+    use ibc_app_transfer::context::TokenTransferValidationContext;
+    use ibc_app_transfer::types::packet::PacketData;
+    use ibc_app_transfer::types::{ack_success_b64, PrefixedCoin, PrefixedDenom, VERSION};
+    use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+
+    use super::*;
+    use crate::context::AccountId;
+
+    fn contract_port() -> String {
+        StorageTransferContext::new(&mut cosmwasm_std::MemoryStorage::new(), &mock_env())
+            .unwrap()
+            .get_port()
+            .unwrap()
+            .to_string()
+    }
+
+    fn channel(counterparty_channel_id: &str) -> IbcChannel {
+        IbcChannel::new(
+            IbcEndpoint {
+                port_id: contract_port(),
+                channel_id: "channel-0".to_string(),
+            },
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: counterparty_channel_id.to_string(),
+            },
+            IbcOrder::Unordered,
+            VERSION,
+            "connection-0",
+        )
+    }
+
+    fn packet_data(denom: &str) -> PacketData {
+        PacketData {
+            token: PrefixedCoin {
+                denom: denom.parse().unwrap(),
+                amount: 100u64.into(),
+            },
+            sender: Signer::from("sender".to_string()),
+            receiver: Signer::from("receiver".to_string()),
+            memo: String::new().into(),
+        }
+    }
+
+    /// Returns the packet of `data` sent by the contract on `channel-0` to
+    /// the counterparty.
+    fn sent_packet(data: &PacketData) -> IbcPacket {
+        IbcPacket::new(
+            to_json_binary(data).unwrap(),
+            IbcEndpoint {
+                port_id: contract_port(),
+                channel_id: "channel-0".to_string(),
+            },
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-1".to_string(),
+            },
+            1,
+            IbcTimeout::with_block(IbcTimeoutBlock {
+                revision: 0,
+                height: 10,
+            }),
+        )
+    }
+
+    /// Returns the packet of `data` sent by the counterparty to the contract
+    /// on `channel-0`.
+    fn received_packet(data: &PacketData) -> IbcPacket {
+        let mut packet = sent_packet(data);
+        core::mem::swap(&mut packet.src, &mut packet.dest);
+        packet
+    }
+
+    fn balance(storage: &mut dyn cosmwasm_std::Storage, account: &str, denom: &str) -> u64 {
+        let ctx = StorageTransferContext::new(storage, &mock_env()).unwrap();
+        let account = AccountId::try_from(Signer::from(account.to_string())).unwrap();
+        let denom = PrefixedDenom::from_str(denom).unwrap();
+
+        ctx.balance(&account, &denom)
+            .unwrap()
+            .to_string()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_packet_from_cw() {
+        let data = packet_data("uatom");
+        let mut cw_packet = sent_packet(&data);
+
+        let packet = packet_from_cw(&cw_packet).unwrap();
+        assert_eq!(packet.seq_on_a, 1u64.into());
+        assert_eq!(packet.port_id_on_a.to_string(), contract_port());
+        assert_eq!(packet.chan_id_on_a, ChannelId::new(0));
+        assert_eq!(packet.chan_id_on_b, ChannelId::new(1));
+        assert_eq!(
+            packet.timeout_height_on_b,
+            TimeoutHeight::At(Height::new(0, 10).unwrap())
+        );
+        assert_eq!(packet.timeout_timestamp_on_b, Timestamp::none());
+
+        cw_packet.timeout = IbcTimeout::with_timestamp(cosmwasm_std::Timestamp::from_nanos(5));
+        let packet = packet_from_cw(&cw_packet).unwrap();
+        assert_eq!(packet.timeout_height_on_b, TimeoutHeight::Never);
+        assert_eq!(
+            packet.timeout_timestamp_on_b,
+            Timestamp::from_nanoseconds(5).unwrap()
+        );
+
+        // A block height of zero is not a valid timeout height.
+        cw_packet.timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+            revision: 0,
+            height: 0,
+        });
+        assert!(packet_from_cw(&cw_packet).is_err());
+    }
+
+    #[test]
+    fn test_domain_channel() {
+        let channel = DomainChannel::try_from(&channel("")).unwrap();
+        assert_eq!(channel.order, Order::Unordered);
+        assert_eq!(channel.connection_hops, vec![ConnectionId::new(0)]);
+        assert_eq!(channel.channel_id, ChannelId::new(0));
+        assert_eq!(channel.counterparty.channel_id(), None);
+
+        let channel = DomainChannel::try_from(&self::channel("channel-1")).unwrap();
+        assert_eq!(channel.counterparty.channel_id(), Some(&ChannelId::new(1)));
+
+        let mut invalid = self::channel("channel-1");
+        invalid.connection_id = "invalid connection".to_string();
+        assert!(DomainChannel::try_from(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_ibc_channel_open() {
+        let mut deps = mock_dependencies();
+
+        let response = ibc_channel_open(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelOpenMsg::new_init(channel("")),
+        )
+        .unwrap();
+        assert_eq!(response.unwrap().version, VERSION);
+
+        let response = ibc_channel_open(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelOpenMsg::new_try(channel("channel-1"), VERSION),
+        )
+        .unwrap();
+        assert_eq!(response.unwrap().version, VERSION);
+
+        // The counterparty must speak the transfer version.
+        assert!(ibc_channel_open(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelOpenMsg::new_try(channel("channel-1"), "ics20-2"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_ibc_channel_close() {
+        let mut deps = mock_dependencies();
+
+        assert!(ibc_channel_close(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelCloseMsg::new_init(channel("channel-1")),
+        )
+        .is_err());
+        assert!(ibc_channel_close(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelCloseMsg::new_confirm(channel("channel-1")),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_ibc_packet_receive() {
+        let mut deps = mock_dependencies();
+        let packet = received_packet(&packet_data("uatom"));
+
+        let response = ibc_packet_receive(
+            deps.as_mut(),
+            mock_env(),
+            IbcPacketReceiveMsg::new(packet, Addr::unchecked("relayer")),
+        )
+        .unwrap();
+
+        let acknowledgement =
+            AcknowledgementStatus::from_json_bytes(response.acknowledgement.as_slice()).unwrap();
+        assert!(acknowledgement.is_successful());
+
+        let voucher = format!("{}/channel-0/uatom", contract_port());
+        assert_eq!(balance(&mut deps.storage, "receiver", &voucher), 100);
+    }
+
+    #[test]
+    fn test_ibc_packet_receive_error_acknowledgement() {
+        let mut deps = mock_dependencies();
+        // The contract holds no escrow of its own tokens to return.
+        let voucher = format!("{}/channel-0/uatom", contract_port());
+        let packet = received_packet(&packet_data(&format!("transfer/channel-1/{voucher}")));
+
+        let response = ibc_packet_receive(
+            deps.as_mut(),
+            mock_env(),
+            IbcPacketReceiveMsg::new(packet, Addr::unchecked("relayer")),
+        )
+        .unwrap();
+
+        let acknowledgement =
+            AcknowledgementStatus::from_json_bytes(response.acknowledgement.as_slice()).unwrap();
+        assert!(!acknowledgement.is_successful());
+    }
+
+    fn ack_msg(packet: IbcPacket, status: AcknowledgementStatus) -> IbcPacketAckMsg {
+        IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(Vec::<u8>::from(status)),
+            packet,
+            Addr::unchecked("relayer"),
+        )
+    }
+
+    #[test]
+    fn test_ibc_packet_ack() {
+        let mut deps = mock_dependencies();
+        // A voucher of the counterparty, burnt when the packet was sent.
+        let denom = &format!("{}/channel-0/uatom", contract_port());
+        let packet = sent_packet(&packet_data(denom));
+
+        ibc_packet_ack(
+            deps.as_mut(),
+            mock_env(),
+            ack_msg(
+                packet.clone(),
+                AcknowledgementStatus::success(ack_success_b64()),
+            ),
+        )
+        .unwrap();
+        assert_eq!(balance(&mut deps.storage, "sender", denom), 0);
+
+        // The vouchers are minted back to the sender.
+        ibc_packet_ack(
+            deps.as_mut(),
+            mock_env(),
+            ack_msg(
+                packet,
+                AcknowledgementStatus::error(StatusValue::new("failure").unwrap()),
+            ),
+        )
+        .unwrap();
+        assert_eq!(balance(&mut deps.storage, "sender", denom), 100);
+    }
+
+    #[test]
+    fn test_ibc_packet_ack_invalid_acknowledgement() {
+        let mut deps = mock_dependencies();
+        let packet = sent_packet(&packet_data("uatom"));
+
+        let msg = IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(b"not an acknowledgement".to_vec()),
+            packet,
+            Addr::unchecked("relayer"),
+        );
+        assert!(ibc_packet_ack(deps.as_mut(), mock_env(), msg).is_err());
+    }
+
+    #[test]
+    fn test_ibc_packet_timeout() {
+        let mut deps = mock_dependencies();
+        // A voucher of the counterparty, burnt when the packet was sent.
+        let denom = &format!("{}/channel-0/uatom", contract_port());
+        let packet = sent_packet(&packet_data(denom));
+
+        ibc_packet_timeout(
+            deps.as_mut(),
+            mock_env(),
+            IbcPacketTimeoutMsg::new(packet, Addr::unchecked("relayer")),
+        )
+        .unwrap();
+        assert_eq!(balance(&mut deps.storage, "sender", denom), 100);
+
+        // The tokens escrowed on sending must be held by the escrow.
+        let packet = sent_packet(&packet_data("uatom"));
+        assert!(ibc_packet_timeout(
+            deps.as_mut(),
+            mock_env(),
+            IbcPacketTimeoutMsg::new(packet, Addr::unchecked("relayer")),
+        )
+        .is_err());
+    }
+}
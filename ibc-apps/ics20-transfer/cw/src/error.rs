@@ -0,0 +1,76 @@
+//! Defines the errors of the CosmWasm adapter of the transfer application.
+
+use displaydoc::Display;
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::primitives::ParseTimestampError;
+
+#[derive(Debug, Display)]
+pub enum ContractError {
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid timeout height: `{0}`
+    InvalidTimeoutHeight(ClientError),
+    /// invalid timeout timestamp: `{0}`
+    InvalidTimeoutTimestamp(ParseTimestampError),
+    /// invalid packet: `{0}`
+    InvalidPacket(PacketError),
+    /// corrupted amount stored under key `{key:?}`
+    CorruptedAmount { key: Vec<u8> },
+}
+
+impl From<TokenTransferError> for ContractError {
+    fn from(err: TokenTransferError) -> Self {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<IdentifierError> for ContractError {
+    fn from(err: IdentifierError) -> Self {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<ClientError> for ContractError {
+    fn from(err: ClientError) -> Self {
+        Self::InvalidTimeoutHeight(err)
+    }
+}
+
+impl From<ParseTimestampError> for ContractError {
+    fn from(err: ParseTimestampError) -> Self {
+        Self::InvalidTimeoutTimestamp(err)
+    }
+}
+
+impl From<PacketError> for ContractError {
+    fn from(err: PacketError) -> Self {
+        Self::InvalidPacket(err)
+    }
+}
+
+impl From<ContractError> for TokenTransferError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::TokenTransfer(err) => err,
+            err => TokenTransferError::Other(err.to_string()),
+        }
+    }
+}
+
+impl std::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::TokenTransfer(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidTimeoutHeight(e) => Some(e),
+            Self::InvalidTimeoutTimestamp(e) => Some(e),
+            Self::InvalidPacket(e) => Some(e),
+            Self::CorruptedAmount { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Adapter running the IBC [fungible token
+//! transfer](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
+//! (ICS-20) application of `ibc-app-transfer` as a CosmWasm contract.
+//!
+//! The functions of [`entry_points`] have the signatures of the IBC entry
+//! points of CosmWasm, so that a contract only needs to forward its own
+//! entry points to them. The state of the application is kept in the storage
+//! of the contract by [`StorageTransferContext`].
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+pub mod context;
+pub mod entry_points;
+pub mod error;
+
+pub use context::{AccountId, StorageTransferContext};
+pub use entry_points::*;
+pub use error::ContractError;
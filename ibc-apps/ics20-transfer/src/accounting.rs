@@ -0,0 +1,334 @@
+//! Provides the accounting of the tokens moved by the transfer module: the
+//! supply of the vouchers it mints and burns per denomination, and the
+//! balance of the tokens it escrows per channel, along with their
+//! reconciliation against the bank module of the host.
+//!
+//! A voucher supply or an escrow balance of the bank module that differs from
+//! the accounted one reveals that tokens were minted, burnt, escrowed or
+//! unescrowed outside of the transfer module, e.g. by a faulty integration,
+//! which may lead to unbacked vouchers.
+//!
+//! Hosts opt into the accounting by returning their accounting storage from
+//! [`TokenTransferExecutionContext::transfer_accounting_mut`](crate::context::TokenTransferExecutionContext::transfer_accounting_mut),
+//! e.g.
+//! ```rust,ignore
+//! fn transfer_accounting_mut(&mut self) -> Option<&mut dyn TransferAccountingContext> {
+//!     Some(&mut self.accounting)
+//! }
+//! ```
+//! in which the send, receive and refund handlers then record the movements
+//! of the tokens, and periodically check them with [`reconcile`], e.g. at
+//! the end of every block.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+/// Storage of the amounts accounted by the transfer module, to be implemented
+/// by the host. Amounts never stored are zero.
+pub trait TransferAccountingContext {
+    /// Returns the accounted supply of the vouchers of the given denomination.
+    fn voucher_supply(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
+
+    /// Stores the accounted supply of the vouchers of the given denomination.
+    fn store_voucher_supply(
+        &mut self,
+        denom: &PrefixedDenom,
+        supply: Amount,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Returns the accounted balance of the tokens of the given denomination
+    /// escrowed for the given channel.
+    fn escrow_balance(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError>;
+
+    /// Stores the accounted balance of the tokens of the given denomination
+    /// escrowed for the given channel.
+    fn store_escrow_balance(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        balance: Amount,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Returns the denominations of all the vouchers ever minted.
+    fn voucher_denoms(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError>;
+
+    /// Returns the channels and denominations of all the tokens ever
+    /// escrowed.
+    fn escrowed_denoms(
+        &self,
+    ) -> Result<Vec<(PortId, ChannelId, PrefixedDenom)>, TokenTransferError>;
+}
+
+/// Balances of the bank module of the host, against which the accounted
+/// amounts are reconciled.
+pub trait BankBalanceContext {
+    /// Returns the total supply of the given denomination.
+    fn total_supply(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
+
+    /// Returns the balance of the given denomination held by the escrow
+    /// account of the given channel.
+    fn escrow_account_balance(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError>;
+}
+
+/// A difference between an accounted amount and the matching amount of the
+/// bank module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SupplyMismatch {
+    /// The total supply of vouchers differs from the minted and not burnt
+    /// ones.
+    VoucherSupply {
+        denom: PrefixedDenom,
+        accounted: Amount,
+        actual: Amount,
+    },
+    /// The balance of an escrow account differs from the escrowed and not
+    /// unescrowed tokens.
+    EscrowBalance {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        accounted: Amount,
+        actual: Amount,
+    },
+}
+
+/// Accounts for the minting of the given vouchers.
+pub fn record_mint(
+    ctx: &mut (impl TransferAccountingContext + ?Sized),
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let supply = add(ctx.voucher_supply(&coin.denom)?, coin)?;
+    ctx.store_voucher_supply(&coin.denom, supply)
+}
+
+/// Accounts for the burning of the given vouchers.
+pub fn record_burn(
+    ctx: &mut (impl TransferAccountingContext + ?Sized),
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let supply = sub(ctx.voucher_supply(&coin.denom)?, coin)?;
+    ctx.store_voucher_supply(&coin.denom, supply)
+}
+
+/// Accounts for the escrow of the given tokens for the given channel.
+pub fn record_escrow(
+    ctx: &mut (impl TransferAccountingContext + ?Sized),
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let balance = add(ctx.escrow_balance(port_id, channel_id, &coin.denom)?, coin)?;
+    ctx.store_escrow_balance(port_id, channel_id, &coin.denom, balance)
+}
+
+/// Accounts for the unescrow of the given tokens from the given channel.
+pub fn record_unescrow(
+    ctx: &mut (impl TransferAccountingContext + ?Sized),
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let balance = sub(ctx.escrow_balance(port_id, channel_id, &coin.denom)?, coin)?;
+    ctx.store_escrow_balance(port_id, channel_id, &coin.denom, balance)
+}
+
+/// Compares every accounted voucher supply and escrow balance with the bank
+/// module, and returns the mismatches, if any.
+///
+/// Denominations never accounted are not compared, e.g. those of vouchers
+/// minted before the accounting was set up.
+pub fn reconcile(
+    ctx: &impl TransferAccountingContext,
+    bank: &impl BankBalanceContext,
+) -> Result<Vec<SupplyMismatch>, TokenTransferError> {
+    let mut mismatches = Vec::new();
+
+    for denom in ctx.voucher_denoms()? {
+        let accounted = ctx.voucher_supply(&denom)?;
+        let actual = bank.total_supply(&denom)?;
+
+        if accounted != actual {
+            mismatches.push(SupplyMismatch::VoucherSupply {
+                denom,
+                accounted,
+                actual,
+            });
+        }
+    }
+
+    for (port_id, channel_id, denom) in ctx.escrowed_denoms()? {
+        let accounted = ctx.escrow_balance(&port_id, &channel_id, &denom)?;
+        let actual = bank.escrow_account_balance(&port_id, &channel_id, &denom)?;
+
+        if accounted != actual {
+            mismatches.push(SupplyMismatch::EscrowBalance {
+                port_id,
+                channel_id,
+                denom,
+                accounted,
+                actual,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+fn add(tracked: Amount, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+    tracked
+        .checked_add(coin.amount)
+        .ok_or_else(|| TokenTransferError::TrackedAmountOverflow {
+            denom: coin.denom.to_string(),
+        })
+}
+
+fn sub(tracked: Amount, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+    tracked
+        .checked_sub(coin.amount)
+        .ok_or_else(|| TokenTransferError::TrackedAmountUnderflow {
+            denom: coin.denom.to_string(),
+            tracked,
+            amount: coin.amount,
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    type EscrowKey = (PortId, ChannelId, PrefixedDenom);
+
+    /// Accounts for the amounts in memory, and doubles as the bank module.
+    #[derive(Default)]
+    pub(crate) struct Ledger {
+        supplies: BTreeMap<PrefixedDenom, Amount>,
+        escrows: BTreeMap<EscrowKey, Amount>,
+    }
+
+    impl TransferAccountingContext for Ledger {
+        fn voucher_supply(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+            Ok(self
+                .supplies
+                .get(denom)
+                .copied()
+                .unwrap_or_else(|| 0u64.into()))
+        }
+
+        fn store_voucher_supply(
+            &mut self,
+            denom: &PrefixedDenom,
+            supply: Amount,
+        ) -> Result<(), TokenTransferError> {
+            self.supplies.insert(denom.clone(), supply);
+            Ok(())
+        }
+
+        fn escrow_balance(
+            &self,
+            port_id: &PortId,
+            channel_id: &ChannelId,
+            denom: &PrefixedDenom,
+        ) -> Result<Amount, TokenTransferError> {
+            Ok(self
+                .escrows
+                .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+                .copied()
+                .unwrap_or_else(|| 0u64.into()))
+        }
+
+        fn store_escrow_balance(
+            &mut self,
+            port_id: &PortId,
+            channel_id: &ChannelId,
+            denom: &PrefixedDenom,
+            balance: Amount,
+        ) -> Result<(), TokenTransferError> {
+            self.escrows.insert(
+                (port_id.clone(), channel_id.clone(), denom.clone()),
+                balance,
+            );
+            Ok(())
+        }
+
+        fn voucher_denoms(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError> {
+            Ok(self.supplies.keys().cloned().collect())
+        }
+
+        fn escrowed_denoms(&self) -> Result<Vec<EscrowKey>, TokenTransferError> {
+            Ok(self.escrows.keys().cloned().collect())
+        }
+    }
+
+    impl BankBalanceContext for Ledger {
+        fn total_supply(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+            self.voucher_supply(denom)
+        }
+
+        fn escrow_account_balance(
+            &self,
+            port_id: &PortId,
+            channel_id: &ChannelId,
+            denom: &PrefixedDenom,
+        ) -> Result<Amount, TokenTransferError> {
+            self.escrow_balance(port_id, channel_id, denom)
+        }
+    }
+
+    pub(crate) fn coin(coin: &str) -> PrefixedCoin {
+        coin.parse().expect("valid coin")
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        let mut accounted = Ledger::default();
+        record_mint(&mut accounted, &coin("100transfer/channel-1/uatom")).unwrap();
+        record_burn(&mut accounted, &coin("40transfer/channel-1/uatom")).unwrap();
+        record_escrow(&mut accounted, &port_id, &channel_id, &coin("50stake")).unwrap();
+        record_unescrow(&mut accounted, &port_id, &channel_id, &coin("10stake")).unwrap();
+
+        let mut bank = Ledger::default();
+        record_mint(&mut bank, &coin("60transfer/channel-1/uatom")).unwrap();
+        record_escrow(&mut bank, &port_id, &channel_id, &coin("30stake")).unwrap();
+
+        assert_eq!(
+            reconcile(&accounted, &bank).unwrap(),
+            vec![SupplyMismatch::EscrowBalance {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                denom: "stake".parse().unwrap(),
+                accounted: 40u64.into(),
+                actual: 30u64.into(),
+            }]
+        );
+
+        record_escrow(&mut bank, &port_id, &channel_id, &coin("10stake")).unwrap();
+        assert!(reconcile(&accounted, &bank).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_record_burn_underflow() {
+        let mut accounted = Ledger::default();
+        record_mint(&mut accounted, &coin("10transfer/channel-1/uatom")).unwrap();
+
+        assert!(matches!(
+            record_burn(&mut accounted, &coin("11transfer/channel-1/uatom")),
+            Err(TokenTransferError::TrackedAmountUnderflow { .. })
+        ));
+    }
+}
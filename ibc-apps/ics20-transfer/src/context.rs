@@ -1,11 +1,15 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom, TransferParams};
+use ibc_core::channel::types::packet::Packet;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 
+use crate::accounting::TransferAccountingContext;
+
 /// Methods required in token transfer validation, to be implemented by the host
 pub trait TokenTransferValidationContext {
     type AccountId: TryFrom<Signer>;
@@ -14,10 +18,45 @@ pub trait TokenTransferValidationContext {
     fn get_port(&self) -> Result<PortId, TokenTransferError>;
 
     /// Returns Ok() if the host chain supports sending coins.
-    fn can_send_coins(&self) -> Result<(), TokenTransferError>;
+    ///
+    /// By default, sending coins is enabled by the transfer params.
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        if self.transfer_params()?.send_enabled {
+            Ok(())
+        } else {
+            Err(TokenTransferError::SendDisabled {
+                reason: "disabled by the transfer params".to_string(),
+            })
+        }
+    }
 
     /// Returns Ok() if the host chain supports receiving coins.
-    fn can_receive_coins(&self) -> Result<(), TokenTransferError>;
+    ///
+    /// By default, receiving coins is enabled by the transfer params.
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        if self.transfer_params()?.receive_enabled {
+            Ok(())
+        } else {
+            Err(TokenTransferError::ReceiveDisabled {
+                reason: "disabled by the transfer params".to_string(),
+            })
+        }
+    }
+
+    /// Returns the parameters of the token transfer module, which enable both
+    /// sending and receiving coins by default.
+    fn transfer_params(&self) -> Result<TransferParams, TokenTransferError> {
+        Ok(TransferParams::default())
+    }
+
+    /// Returns the authority allowed to update the parameters of the token
+    /// transfer module, usually the account of the governance module of the
+    /// host.
+    ///
+    /// By default, there is none, and the parameters cannot be updated.
+    fn params_authority(&self) -> Option<Signer> {
+        None
+    }
 
     /// Validates that the tokens can be escrowed successfully.
     ///
@@ -60,7 +99,11 @@ pub trait TokenTransferValidationContext {
     ) -> Result<(), TokenTransferError>;
 
     /// Returns a hash of the prefixed denom.
-    /// Implement only if the host chain supports hashed denominations.
+    /// Implement only if the host chain supports hashed denominations, e.g.
+    /// with `Some(Sha256DenomHasher.denom_hash(denom))` for compatibility
+    /// with ibc-go, or with a custom [`DenomHasher`].
+    ///
+    /// [`DenomHasher`]: ibc_app_transfer_types::DenomHasher
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
@@ -107,4 +150,54 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Stores the parameters of the token transfer module, as updated by the
+    /// [`params_authority`](TokenTransferValidationContext::params_authority).
+    ///
+    /// Hosts with a params authority must override this method, along with
+    /// [`transfer_params`](TokenTransferValidationContext::transfer_params).
+    fn store_transfer_params(&mut self, _params: TransferParams) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::Other(
+            "the host does not store the transfer params".to_string(),
+        ))
+    }
+
+    /// Returns the accounting of the tokens moved by the transfer module, in
+    /// which the handlers record every escrow, unescrow, mint and burn they
+    /// execute, right after executing it.
+    ///
+    /// Hosts opt into the accounting, and its [`reconcile`] with their bank
+    /// module, by overriding this method. By default, there is no accounting.
+    ///
+    /// [`reconcile`]: crate::accounting::reconcile
+    fn transfer_accounting_mut(&mut self) -> Option<&mut dyn TransferAccountingContext> {
+        None
+    }
+
+    /// Called once the tokens of a transfer that timed out or was
+    /// acknowledged with an error are refunded to its sender, with the packet
+    /// of the transfer and its decoded data.
+    ///
+    /// Hosts may override this method to notify the sender, revert the state
+    /// derived from the transfer, or queue it again. An error fails the
+    /// processing of the timeout or of the acknowledgement, refund included.
+    /// The default implementation does nothing.
+    fn on_refund_execute(
+        &mut self,
+        _packet: &Packet,
+        _data: &PacketData,
+        _reason: &RefundReason,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+/// The reason why the tokens of a transfer are refunded to its sender.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RefundReason {
+    /// The packet timed out before being received by the counterparty.
+    Timeout,
+    /// The counterparty failed to process the packet, and acknowledged it
+    /// with the given error.
+    ErrorAcknowledgement { error: String },
 }
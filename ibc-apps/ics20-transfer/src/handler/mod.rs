@@ -1,6 +1,7 @@
 //! Implements the processing logic for ICS20 (token transfer) message.
 mod on_recv_packet;
 mod send_transfer;
+mod update_params;
 
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::is_sender_chain_source;
@@ -8,7 +9,9 @@ use ibc_app_transfer_types::packet::PacketData;
 use ibc_core::channel::types::packet::Packet;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
+pub use update_params::*;
 
+use crate::accounting::{record_mint, record_unescrow};
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 pub fn refund_packet_token_execute(
@@ -32,12 +35,27 @@ pub fn refund_packet_token_execute(
             &packet.port_id_on_a,
             &packet.chan_id_on_a,
             &data.token,
-        )
+        )?;
+
+        if let Some(accounting) = ctx_a.transfer_accounting_mut() {
+            record_unescrow(
+                accounting,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &data.token,
+            )?;
+        }
     }
     // mint vouchers back to sender
     else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+        ctx_a.mint_coins_execute(&sender, &data.token)?;
+
+        if let Some(accounting) = ctx_a.transfer_accounting_mut() {
+            record_mint(accounting, &data.token)?;
+        }
     }
+
+    Ok(())
 }
 
 pub fn refund_packet_token_validate(
@@ -6,6 +6,7 @@ use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use crate::accounting::{record_mint, record_unescrow};
 use crate::context::TokenTransferExecutionContext;
 
 /// This function handles the transfer receiving logic.
@@ -70,6 +71,16 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
             )
             .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
 
+        if let Some(accounting) = ctx_b.transfer_accounting_mut() {
+            record_unescrow(
+                accounting,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &coin,
+            )
+            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+        }
+
         ModuleExtras::empty()
     } else {
         // sender chain is the source, mint vouchers
@@ -109,6 +120,10 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
             .mint_coins_execute(&receiver_account, &coin)
             .map_err(|token_err| (extras.clone(), token_err))?;
 
+        if let Some(accounting) = ctx_b.transfer_accounting_mut() {
+            record_mint(accounting, &coin).map_err(|token_err| (extras.clone(), token_err))?;
+        }
+
         extras
     };
 
@@ -10,6 +10,7 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use crate::accounting::{record_burn, record_escrow};
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
@@ -89,7 +90,7 @@ where
             chan_id_on_a: msg.chan_id_on_a,
             port_id_on_b,
             chan_id_on_b,
-            data,
+            data: data.into(),
             timeout_height_on_b: msg.timeout_height_on_b,
             timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
         }
@@ -148,8 +149,16 @@ where
             token,
             &msg.packet_data.memo,
         )?;
+
+        if let Some(accounting) = token_ctx_a.transfer_accounting_mut() {
+            record_escrow(accounting, &msg.port_id_on_a, &msg.chan_id_on_a, token)?;
+        }
     } else {
         token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+
+        if let Some(accounting) = token_ctx_a.transfer_accounting_mut() {
+            record_burn(accounting, token)?;
+        }
     }
 
     let packet = {
@@ -164,7 +173,7 @@ where
             chan_id_on_a: msg.chan_id_on_a,
             port_id_on_b: port_on_b,
             chan_id_on_b: chan_on_b,
-            data,
+            data: data.into(),
             timeout_height_on_b: msg.timeout_height_on_b,
             timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
         }
@@ -0,0 +1,37 @@
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::update_params::MsgUpdateParams;
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Validates the update of the transfer params, which only their authority may
+/// sign.
+///
+/// Like [`send_transfer`](crate::handler::send_transfer), it is called by the
+/// host directly, e.g. when its governance module executes a `MsgUpdateParams`
+/// of the transfer module, since the core `dispatch` does not route it.
+pub fn update_params_validate<Ctx>(
+    ctx: &Ctx,
+    msg: &MsgUpdateParams,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferValidationContext,
+{
+    if ctx.params_authority().as_ref() != Some(&msg.signer) {
+        return Err(TokenTransferError::UnauthorizedParamsUpdate {
+            signer: msg.signer.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Executes the update of the transfer params.
+pub fn update_params_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgUpdateParams,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    ctx.store_transfer_params(msg.params)
+}
@@ -0,0 +1,143 @@
+//! Provides the escrow invariant of the transfer module, which hosts wire
+//! into their invariant framework, e.g. the `x/crisis` module of the Cosmos
+//! SDK, or run in their end-blockers.
+//!
+//! The tokens escrowed for a channel back the vouchers circulating on the
+//! counterparty chain. The invariant holds as long as every escrow account
+//! holds at least the tokens escrowed and not unescrowed yet, as accounted by
+//! the [`accounting`](crate::accounting) module, i.e. the vouchers that the
+//! counterparty may still send back.
+
+use core::fmt::{self, Display, Formatter};
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::accounting::{BankBalanceContext, TransferAccountingContext};
+
+/// The route of the escrow invariant, as registered in the invariant
+/// framework of the host.
+pub const ESCROW_INVARIANT_ROUTE: &str = "transfer/escrow";
+
+/// An escrow account holding fewer tokens than the vouchers outstanding on
+/// the counterparty chain of its channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowShortfall {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub denom: PrefixedDenom,
+    pub escrowed: Amount,
+    pub outstanding: Amount,
+}
+
+impl Display for EscrowShortfall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "escrow of {}/{} holds {}{} for {}{} outstanding",
+            self.port_id, self.channel_id, self.escrowed, self.denom, self.outstanding, self.denom
+        )
+    }
+}
+
+/// Iterates the escrow accounts of all the transfer channels, and returns
+/// those holding fewer tokens than outstanding on the counterparty chains.
+pub fn check_escrow_invariant(
+    ctx: &impl TransferAccountingContext,
+    bank: &impl BankBalanceContext,
+) -> Result<Vec<EscrowShortfall>, TokenTransferError> {
+    let mut shortfalls = Vec::new();
+
+    for (port_id, channel_id, denom) in ctx.escrowed_denoms()? {
+        let outstanding = ctx.escrow_balance(&port_id, &channel_id, &denom)?;
+        let escrowed = bank.escrow_account_balance(&port_id, &channel_id, &denom)?;
+
+        if escrowed < outstanding {
+            shortfalls.push(EscrowShortfall {
+                port_id,
+                channel_id,
+                denom,
+                escrowed,
+                outstanding,
+            });
+        }
+    }
+
+    Ok(shortfalls)
+}
+
+/// Runs the escrow invariant, and returns its report along with whether it
+/// is broken, as expected by the invariant framework of the Cosmos SDK.
+///
+/// The invariant is reported as broken if the balances cannot be read.
+pub fn escrow_invariant(
+    ctx: &impl TransferAccountingContext,
+    bank: &impl BankBalanceContext,
+) -> (String, bool) {
+    let (message, broken) = match check_escrow_invariant(ctx, bank) {
+        Ok(shortfalls) if shortfalls.is_empty() => {
+            ("all the escrow accounts are backed\n".to_string(), false)
+        }
+        Ok(shortfalls) => {
+            let mut message = String::new();
+
+            for shortfall in shortfalls {
+                message.push_str(&shortfall.to_string());
+                message.push('\n');
+            }
+
+            (message, true)
+        }
+        Err(e) => (format!("failed to read the escrow balances: {e}\n"), true),
+    };
+
+    (
+        format!("{ESCROW_INVARIANT_ROUTE} invariant\n{message}"),
+        broken,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounting::record_escrow;
+    use crate::accounting::tests::{coin, Ledger};
+
+    #[test]
+    fn test_escrow_invariant() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        let mut accounted = Ledger::default();
+        record_escrow(&mut accounted, &port_id, &channel_id, &coin("50stake")).unwrap();
+
+        let mut bank = Ledger::default();
+        record_escrow(&mut bank, &port_id, &channel_id, &coin("60stake")).unwrap();
+
+        // An escrow holding more than outstanding, e.g. after a direct
+        // deposit, does not break the invariant.
+        assert!(!escrow_invariant(&accounted, &bank).1);
+
+        record_escrow(&mut accounted, &port_id, &channel_id, &coin("20stake")).unwrap();
+
+        assert_eq!(
+            check_escrow_invariant(&accounted, &bank).unwrap(),
+            vec![EscrowShortfall {
+                port_id,
+                channel_id,
+                denom: "stake".parse().unwrap(),
+                escrowed: 60u64.into(),
+                outstanding: 70u64.into(),
+            }]
+        );
+        assert_eq!(
+            escrow_invariant(&accounted, &bank),
+            (
+                "transfer/escrow invariant\nescrow of transfer/channel-0 holds 60stake for 70stake outstanding\n".to_string(),
+                true
+            )
+        );
+    }
+}
@@ -24,8 +24,10 @@ pub mod types {
     pub use ibc_app_transfer_types::*;
 }
 
+pub mod accounting;
 pub mod context;
 #[cfg(feature = "serde")]
 pub mod handler;
+pub mod invariant;
 #[cfg(feature = "serde")]
 pub mod module;
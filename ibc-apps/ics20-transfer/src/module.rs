@@ -1,7 +1,7 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
 use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
+use ibc_app_transfer_types::VERSION;
 use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
@@ -12,7 +12,7 @@ use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
 
-use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::context::{RefundReason, TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
     process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
 };
@@ -180,7 +180,7 @@ pub fn on_recv_packet_execute(
     };
 
     let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
-        Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
+        Ok(extras) => (extras, AcknowledgementStatus::success_b64()),
         Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
     };
 
@@ -209,7 +209,7 @@ where
     let data = serde_json::from_slice::<PacketData>(&packet.data)
         .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
 
-    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    let acknowledgement = AcknowledgementStatus::from_json_bytes(acknowledgement)
         .map_err(|_| TokenTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
@@ -235,19 +235,24 @@ pub fn on_acknowledgement_packet_execute(
         }
     };
 
-    let acknowledgement =
-        match serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref()) {
-            Ok(ack) => ack,
-            Err(_) => {
-                return (
-                    ModuleExtras::empty(),
-                    Err(TokenTransferError::AckDeserialization),
-                );
-            }
+    let acknowledgement = match AcknowledgementStatus::from_json_bytes(acknowledgement) {
+        Ok(ack) => ack,
+        Err(_) => {
+            return (
+                ModuleExtras::empty(),
+                Err(TokenTransferError::AckDeserialization),
+            );
+        }
+    };
+
+    if let AcknowledgementStatus::Error(error) = &acknowledgement {
+        let reason = RefundReason::ErrorAcknowledgement {
+            error: error.to_string(),
         };
 
-    if !acknowledgement.is_successful() {
-        if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
+        if let Err(err) = refund_packet_token_execute(ctx, packet, &data)
+            .and_then(|_| ctx.on_refund_execute(packet, &data, &reason))
+        {
             return (ModuleExtras::empty(), Err(err));
         }
     }
@@ -300,7 +305,9 @@ pub fn on_timeout_packet_execute(
         }
     };
 
-    if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
+    if let Err(err) = refund_packet_token_execute(ctx, packet, &data)
+        .and_then(|_| ctx.on_refund_execute(packet, &data, &RefundReason::Timeout))
+    {
         return (ModuleExtras::empty(), Err(err));
     }
 
@@ -330,10 +337,7 @@ mod test {
             assert_eq!(ser, json_str)
         }
 
-        ser_json_assert_eq(
-            AcknowledgementStatus::success(ack_success_b64()),
-            r#"{"result":"AQ=="}"#,
-        );
+        ser_json_assert_eq(AcknowledgementStatus::success_b64(), r#"{"result":"AQ=="}"#);
         ser_json_assert_eq(
             AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into()),
             r#"{"error":"failed to deserialize packet data"}"#,
@@ -342,7 +346,7 @@ mod test {
 
     #[test]
     fn test_ack_success_to_vec() {
-        let ack_success: Vec<u8> = AcknowledgementStatus::success(ack_success_b64()).into();
+        let ack_success: Vec<u8> = AcknowledgementStatus::success_b64().into();
 
         // Check that it's the same output as ibc-go
         // Note: this also implicitly checks that the ack bytes are non-empty,
@@ -372,10 +376,7 @@ mod test {
             assert_eq!(de, ack)
         }
 
-        de_json_assert_eq(
-            r#"{"result":"AQ=="}"#,
-            AcknowledgementStatus::success(ack_success_b64()),
-        );
+        de_json_assert_eq(r#"{"result":"AQ=="}"#, AcknowledgementStatus::success_b64());
         de_json_assert_eq(
             r#"{"error":"failed to deserialize packet data"}"#,
             AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into()),
@@ -10,6 +10,10 @@ use primitive_types::U256;
 
 use super::error::TokenTransferError;
 
+/// The largest number of decimals of a token whose amounts can be expressed
+/// in base units, as `10^77` is the largest power of ten fitting in a `U256`.
+pub const MAX_DECIMALS: u32 = 77;
+
 /// A type for representing token transfer amounts.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -99,6 +103,143 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    /// Converts an amount of base units of a token with `from_decimals`
+    /// decimals into base units of the same token with `to_decimals`
+    /// decimals, e.g. when bridging an 18-decimals token to a host using 6
+    /// decimals.
+    ///
+    /// Converting to fewer decimals only succeeds if no precision is lost,
+    /// unless a lossy `rounding` mode is requested.
+    pub fn rescale(
+        self,
+        from_decimals: u32,
+        to_decimals: u32,
+        rounding: Rounding,
+    ) -> Result<Self, TokenTransferError> {
+        if to_decimals >= from_decimals {
+            return pow10(to_decimals - from_decimals)
+                .and_then(|factor| self.0.checked_mul(factor))
+                .map(Self)
+                .ok_or(TokenTransferError::AmountOverflow {
+                    amount: self,
+                    from_decimals,
+                    to_decimals,
+                });
+        }
+
+        // A factor overflowing `U256` is larger than any amount.
+        let (quotient, remainder) = match pow10(from_decimals - to_decimals) {
+            Some(factor) => self.0.div_mod(factor),
+            None => (U256::zero(), self.0),
+        };
+
+        if remainder.is_zero() {
+            return Ok(Self(quotient));
+        }
+
+        match rounding {
+            Rounding::Exact => Err(TokenTransferError::InexactAmount {
+                amount: self,
+                from_decimals,
+                to_decimals,
+            }),
+            Rounding::Down => Ok(Self(quotient)),
+            Rounding::Up => quotient.checked_add(U256::one()).map(Self).ok_or(
+                TokenTransferError::AmountOverflow {
+                    amount: self,
+                    from_decimals,
+                    to_decimals,
+                },
+            ),
+        }
+    }
+
+    /// Parses a decimal amount of tokens, e.g. `1.5`, into base units of a
+    /// token with the given number of decimals.
+    ///
+    /// Fails if the amount has more fractional digits than `decimals`, as it
+    /// cannot be represented without rounding, or if `decimals` exceeds
+    /// [`MAX_DECIMALS`].
+    pub fn from_decimal_str(amount: &str, decimals: u32) -> Result<Self, TokenTransferError> {
+        let invalid = || TokenTransferError::InvalidDecimalAmount {
+            amount: amount.to_string(),
+            decimals,
+        };
+
+        // No amount has base units beyond `U256::MAX`, and bounding the
+        // decimals bounds the padding below
+        if decimals > MAX_DECIMALS {
+            return Err(invalid());
+        }
+
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+        if integer.is_empty()
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+            || fraction.len() > decimals as usize
+        {
+            return Err(invalid());
+        }
+
+        let padding = "0".repeat(decimals as usize - fraction.len());
+        let base_units = format!("{integer}{fraction}{padding}");
+
+        U256::from_dec_str(&base_units)
+            .map(Self)
+            .map_err(|_| invalid())
+    }
+
+    /// Formats the amount of base units as a decimal amount of tokens with
+    /// the given number of decimals, e.g. `1500000` with 6 decimals as `1.5`.
+    ///
+    /// Fails if `decimals` exceeds [`MAX_DECIMALS`], which also bounds the
+    /// zero padding of the formatted amount.
+    pub fn to_decimal_string(&self, decimals: u32) -> Result<String, TokenTransferError> {
+        let base_units = self.0.to_string();
+
+        if decimals > MAX_DECIMALS {
+            return Err(TokenTransferError::InvalidDecimalAmount {
+                amount: base_units,
+                decimals,
+            });
+        }
+
+        let decimals = decimals as usize;
+
+        if decimals == 0 {
+            return Ok(base_units);
+        }
+
+        let base_units = format!("{base_units:0>width$}", width = decimals + 1);
+        let (integer, fraction) = base_units.split_at(base_units.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            Ok(integer.to_string())
+        } else {
+            Ok(format!("{integer}.{fraction}"))
+        }
+    }
+}
+
+/// The rounding applied by [`Amount::rescale`] when an amount cannot be
+/// represented exactly with fewer decimals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Rounding {
+    /// Fails with [`TokenTransferError::InexactAmount`].
+    #[default]
+    Exact,
+    /// Rounds towards zero, discarding the dust.
+    Down,
+    /// Rounds away from zero.
+    Up,
+}
+
+/// Returns `10^exp`, or `None` on overflow.
+fn pow10(exp: u32) -> Option<U256> {
+    U256::from(10).checked_pow(U256::from(exp))
 }
 
 impl AsRef<U256> for Amount {
@@ -134,7 +275,75 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::Amount;
+    use rstest::rstest;
+
+    use super::{Amount, Rounding};
+
+    #[rstest]
+    #[case("1500000000000000000", 18, 6, Rounding::Exact, Some("1500000"))]
+    #[case("1500000", 6, 18, Rounding::Exact, Some("1500000000000000000"))]
+    #[case("1500000000000000001", 18, 6, Rounding::Exact, None)]
+    #[case("1500000000000000001", 18, 6, Rounding::Down, Some("1500000"))]
+    #[case("1500000000000000001", 18, 6, Rounding::Up, Some("1500001"))]
+    #[case("1", 100, 0, Rounding::Down, Some("0"))]
+    #[case("1", 0, 100, Rounding::Exact, None)]
+    fn rescale_amount(
+        #[case] amount: &str,
+        #[case] from_decimals: u32,
+        #[case] to_decimals: u32,
+        #[case] rounding: Rounding,
+        #[case] expected: Option<&str>,
+    ) {
+        let amount: Amount = amount.parse().unwrap();
+        let res = amount.rescale(from_decimals, to_decimals, rounding);
+
+        assert_eq!(res.ok(), expected.map(|e| e.parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case("1.5", 6, Some("1500000"))]
+    #[case("0.000001", 6, Some("1"))]
+    #[case("2", 6, Some("2000000"))]
+    #[case("2.", 6, Some("2000000"))]
+    #[case("0.0000001", 6, None)]
+    #[case(".5", 6, None)]
+    #[case("1.-5", 6, None)]
+    #[case("1e6", 6, None)]
+    #[case(
+        "0.1",
+        77,
+        Some("10000000000000000000000000000000000000000000000000000000000000000000000000000")
+    )]
+    #[case("1", 78, None)]
+    #[case("1", u32::MAX, None)]
+    fn decimal_amount(#[case] amount: &str, #[case] decimals: u32, #[case] expected: Option<&str>) {
+        let res = Amount::from_decimal_str(amount, decimals);
+
+        assert_eq!(res.ok(), expected.map(|e| e.parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case("1500000", 6, Some("1.5"))]
+    #[case("1", 6, Some("0.000001"))]
+    #[case("2000000", 6, Some("2"))]
+    #[case("0", 6, Some("0"))]
+    #[case("42", 0, Some("42"))]
+    #[case(
+        "1",
+        77,
+        Some("0.00000000000000000000000000000000000000000000000000000000000000000000000000001")
+    )]
+    #[case("1", 78, None)]
+    #[case("1", u32::MAX, None)]
+    fn amount_to_decimal_string(
+        #[case] amount: &str,
+        #[case] decimals: u32,
+        #[case] expected: Option<&str>,
+    ) {
+        let amount: Amount = amount.parse().unwrap();
+
+        assert_eq!(amount.to_decimal_string(decimals).ok().as_deref(), expected);
+    }
 
     #[cfg(feature = "serde")]
     #[test]
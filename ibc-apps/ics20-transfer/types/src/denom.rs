@@ -8,6 +8,8 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
 
 use super::error::TokenTransferError;
 
@@ -285,6 +287,41 @@ pub fn is_receiver_chain_source(
     denom.trace_path.starts_with(&prefix)
 }
 
+/// Derives the denomination of the vouchers minted for a prefixed denom.
+///
+/// Hosts whose bank module constrains the length or charset of the
+/// denominations can implement their own strategy, while [`Sha256DenomHasher`]
+/// is compatible with ibc-go.
+pub trait DenomHasher {
+    /// Returns the hash of the full trace of the given prefixed denom.
+    fn denom_hash(&self, denom: &PrefixedDenom) -> String;
+
+    /// Returns the voucher denomination of the given prefixed denom, which
+    /// is `ibc/{hash}` by default. Denoms without a trace are native to the
+    /// host and are returned as is.
+    fn voucher_denom(&self, denom: &PrefixedDenom) -> String {
+        if denom.trace_path.is_empty() {
+            return denom.base_denom.to_string();
+        }
+
+        format!("ibc/{}", self.denom_hash(denom))
+    }
+}
+
+/// The ibc-go denom hashing strategy: the upper case hex encoding of the
+/// SHA-256 hash of the full trace, e.g. `transfer/channel-0/uatom`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Sha256DenomHasher;
+
+impl DenomHasher for Sha256DenomHasher {
+    fn denom_hash(&self, denom: &PrefixedDenom) -> String {
+        let hash = Sha256::digest(denom.to_string());
+
+        String::from_utf8(hex::encode_upper(hash))
+            .expect("Never fails because hexadecimal is valid UTF-8")
+    }
+}
+
 impl FromStr for PrefixedDenom {
     type Err = TokenTransferError;
 
@@ -354,6 +391,20 @@ impl Display for PrefixedDenom {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sha256_denom_hasher() -> Result<(), TokenTransferError> {
+        let denom = PrefixedDenom::from_str("transfer/channel-0/uatom")?;
+        assert_eq!(
+            Sha256DenomHasher.voucher_denom(&denom),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+
+        let denom = PrefixedDenom::from_str("uatom")?;
+        assert_eq!(Sha256DenomHasher.voucher_denom(&denom), "uatom");
+
+        Ok(())
+    }
+
     #[test]
     fn test_denom_validation() -> Result<(), TokenTransferError> {
         assert!(BaseDenom::from_str("").is_err(), "empty base denom");
@@ -9,8 +9,11 @@ use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
 use uint::FromDecStrErr;
 
+use crate::amount::Amount;
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -43,6 +46,28 @@ pub enum TokenTransferError {
     InvalidTraceLength { len: u64 },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// invalid decimal amount `{amount}` with `{decimals}` decimals
+    InvalidDecimalAmount { amount: String, decimals: u32 },
+    /// amount `{amount}` overflows when converted from `{from_decimals}` to `{to_decimals}` decimals
+    AmountOverflow {
+        amount: Amount,
+        from_decimals: u32,
+        to_decimals: u32,
+    },
+    /// amount `{amount}` cannot be converted from `{from_decimals}` to `{to_decimals}` decimals without rounding
+    InexactAmount {
+        amount: Amount,
+        from_decimals: u32,
+        to_decimals: u32,
+    },
+    /// tracked amount of `{denom}` overflows
+    TrackedAmountOverflow { denom: String },
+    /// tracked amount `{tracked}` of `{denom}` is lower than the deducted amount `{amount}`
+    TrackedAmountUnderflow {
+        denom: String,
+        tracked: Amount,
+        amount: Amount,
+    },
     /// invalid token
     InvalidToken,
     /// expected `{expect_order}` channel, got `{got_order}`
@@ -71,10 +96,14 @@ pub enum TokenTransferError {
     DecodeRawMsg { reason: String },
     /// unknown msg type: `{msg_type}`
     UnknownMsgType { msg_type: String },
+    /// invalid memo: `{description}`
+    InvalidMemo { description: String },
     /// invalid coin string: `{coin}`
     InvalidCoin { coin: String },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
+    /// signer `{signer}` is not the authority allowed to update the transfer params
+    UnauthorizedParamsUpdate { signer: Signer },
     /// other error: `{0}`
     Other(String),
 }
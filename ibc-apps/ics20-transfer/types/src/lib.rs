@@ -27,7 +27,11 @@ pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet;
+mod params;
 pub use memo::*;
+pub use params::*;
+#[cfg(feature = "serde")]
+pub mod structured_memo;
 /// Re-exports `U256` from `primitive-types` crate for convenience.
 pub use primitive_types::U256;
 
@@ -46,11 +50,10 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+use ibc_core::channel::types::acknowledgement::StatusValue;
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
-pub const ACK_SUCCESS_B64: &str = "AQ==";
-
-use ibc_core::channel::types::acknowledgement::StatusValue;
+pub use ibc_core::channel::types::acknowledgement::ACK_SUCCESS_B64;
 
 /// Returns a successful acknowledgement status for the token transfer application.
 pub fn ack_success_b64() -> StatusValue {
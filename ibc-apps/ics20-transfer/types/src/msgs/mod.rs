@@ -1,2 +1,3 @@
 //! Defines the token transfer message type
 pub mod transfer;
+pub mod update_params;
@@ -0,0 +1,51 @@
+//! Defines the message updating the parameters of the token transfer module
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::applications::transfer::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::TokenTransferError;
+use crate::TransferParams;
+
+pub const UPDATE_PARAMS_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgUpdateParams";
+
+/// Message updating the parameters of the token transfer module.
+///
+/// As with `MsgTransfer`, it is not routed through the core `dispatch`, since
+/// ICS-26 only routes packet and channel messages to the modules. The host
+/// hands it to the `update_params_validate` and `update_params_execute`
+/// handlers of the transfer module instead, where it must be signed by the
+/// parameters authority, i.e. usually its governance module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateParams {
+    pub params: TransferParams,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateParams {
+    type Error = TokenTransferError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateParams {
+            params: raw
+                .params
+                .ok_or_else(|| TokenTransferError::DecodeRawMsg {
+                    reason: "missing params".to_string(),
+                })?
+                .try_into()?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgUpdateParams> for RawMsgUpdateParams {
+    fn from(msg: MsgUpdateParams) -> Self {
+        RawMsgUpdateParams {
+            signer: msg.signer.to_string(),
+            params: Some(msg.params.into()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Defines the parameters of the token transfer module.
+
+use ibc_proto::ibc::applications::transfer::v1::Params as RawTransferParams;
+use ibc_proto::Protobuf;
+
+use crate::error::TokenTransferError;
+
+/// The parameters of the token transfer module, as in ibc-go.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferParams {
+    /// Whether tokens can be sent from the host.
+    pub send_enabled: bool,
+    /// Whether tokens can be received by the host.
+    pub receive_enabled: bool,
+}
+
+impl Default for TransferParams {
+    /// Enables both sending and receiving tokens.
+    fn default() -> Self {
+        Self {
+            send_enabled: true,
+            receive_enabled: true,
+        }
+    }
+}
+
+impl Protobuf<RawTransferParams> for TransferParams {}
+
+impl TryFrom<RawTransferParams> for TransferParams {
+    type Error = TokenTransferError;
+
+    fn try_from(raw: RawTransferParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            send_enabled: raw.send_enabled,
+            receive_enabled: raw.receive_enabled,
+        })
+    }
+}
+
+impl From<TransferParams> for RawTransferParams {
+    fn from(params: TransferParams) -> Self {
+        Self {
+            send_enabled: params.send_enabled,
+            receive_enabled: params.receive_enabled,
+        }
+    }
+}
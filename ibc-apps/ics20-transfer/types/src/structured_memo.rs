@@ -0,0 +1,312 @@
+//! Typed parsers and serializers for the common JSON memo schemas used by
+//! middleware and hosts on top of token transfers:
+//!
+//! - packet forwarding, e.g. `{"forward":{"receiver":"..","port":"transfer","channel":"channel-1"}}`
+//! - Wasm hooks, e.g. `{"wasm":{"contract":"..","msg":{..}}}`
+//! - callbacks, e.g. `{"src_callback":{"address":"..","gas_limit":"100000"}}`
+//!
+//! Unknown fields nested under the known keys are ignored in both parsing
+//! modes, so that newer versions of the schemas remain readable.
+
+use core::str::FromStr;
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::error::TokenTransferError;
+use super::Memo;
+
+/// The top-level memo keys known to [`StructuredMemo`].
+pub const KNOWN_MEMO_KEYS: [&str; 4] = ["forward", "wasm", "src_callback", "dest_callback"];
+
+/// How strictly [`StructuredMemo::parse`] handles memos which do not follow
+/// the known schemas.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MemoParseMode {
+    /// Fails on memos which are not JSON objects, and on unknown top-level
+    /// keys.
+    Strict,
+    /// Treats memos which are not JSON objects as plain text without any
+    /// structured content, and ignores unknown top-level keys.
+    #[default]
+    Lenient,
+}
+
+/// The structured content of a token transfer memo.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StructuredMemo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<ForwardMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<WasmHook>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub src_callback: Option<CallbackData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dest_callback: Option<CallbackData>,
+}
+
+impl StructuredMemo {
+    /// Parses and validates the structured content of the given memo. An
+    /// empty memo has no structured content in both parsing modes.
+    pub fn parse(memo: &Memo, mode: MemoParseMode) -> Result<Self, TokenTransferError> {
+        let memo = memo.as_ref();
+
+        if memo.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let keys = match serde_json::from_str::<BTreeMap<String, IgnoredAny>>(memo) {
+            Ok(keys) => keys,
+            Err(_) if mode == MemoParseMode::Lenient => return Ok(Self::default()),
+            Err(e) => return Err(invalid_memo(e)),
+        };
+
+        if mode == MemoParseMode::Strict {
+            if let Some(key) = keys
+                .keys()
+                .find(|key| !KNOWN_MEMO_KEYS.contains(&key.as_str()))
+            {
+                return Err(invalid_memo(format!("unknown key `{key}`")));
+            }
+        }
+
+        let structured: Self = serde_json::from_str(memo).map_err(invalid_memo)?;
+        structured.validate()?;
+
+        Ok(structured)
+    }
+
+    /// Encodes the structured content as a memo.
+    pub fn to_memo(&self) -> Memo {
+        serde_json::to_string(self)
+            .expect("Never fails because the structured memo has string keys only")
+            .into()
+    }
+
+    /// Returns `true` if the memo has none of the known keys.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    fn validate(&self) -> Result<(), TokenTransferError> {
+        if let Some(forward) = &self.forward {
+            forward.validate()?;
+        }
+        if let Some(wasm) = &self.wasm {
+            wasm.validate()?;
+        }
+        if let Some(callback) = &self.src_callback {
+            callback.validate()?;
+        }
+        if let Some(callback) = &self.dest_callback {
+            callback.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The packet forwarding metadata, under the `forward` key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForwardMetadata {
+    /// The receiver on the next chain.
+    pub receiver: String,
+    pub port: PortId,
+    pub channel: ChannelId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<ForwardTimeout>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// The memo of the forwarded transfer, as a nested JSON object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<StructuredMemo>>,
+}
+
+impl ForwardMetadata {
+    fn validate(&self) -> Result<(), TokenTransferError> {
+        if self.receiver.trim().is_empty() {
+            return Err(invalid_memo("empty forward receiver"));
+        }
+
+        PortId::from_str(self.port.as_str()).map_err(TokenTransferError::InvalidIdentifier)?;
+        ChannelId::from_str(self.channel.as_str())
+            .map_err(TokenTransferError::InvalidIdentifier)?;
+
+        if let Some(next) = &self.next {
+            next.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The timeout of a forwarded transfer, either in nanoseconds or as a Go
+/// duration string, e.g. `10m`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ForwardTimeout {
+    Nanoseconds(u64),
+    Duration(String),
+}
+
+/// The Wasm hook metadata, under the `wasm` key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WasmHook {
+    /// The address of the contract to execute.
+    pub contract: String,
+    /// The execute message of the contract, which must be a JSON object.
+    pub msg: Value,
+}
+
+impl WasmHook {
+    fn validate(&self) -> Result<(), TokenTransferError> {
+        if self.contract.trim().is_empty() {
+            return Err(invalid_memo("empty wasm contract"));
+        }
+
+        if !matches!(self.msg, Value::Object(_)) {
+            return Err(invalid_memo("wasm msg must be a JSON object"));
+        }
+
+        Ok(())
+    }
+}
+
+/// The callback metadata, under the `src_callback` and `dest_callback` keys.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallbackData {
+    /// The address of the callback actor.
+    pub address: String,
+    /// The maximum gas the callback may use, encoded as a string.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_gas_limit",
+        deserialize_with = "deserialize_gas_limit"
+    )]
+    pub gas_limit: Option<u64>,
+}
+
+impl CallbackData {
+    fn validate(&self) -> Result<(), TokenTransferError> {
+        if self.address.trim().is_empty() {
+            return Err(invalid_memo("empty callback address"));
+        }
+
+        Ok(())
+    }
+}
+
+fn serialize_gas_limit<S>(gas_limit: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match gas_limit {
+        Some(gas_limit) => serializer.serialize_str(&gas_limit.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_gas_limit<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let gas_limit = String::deserialize(deserializer)?;
+
+    gas_limit
+        .parse()
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+fn invalid_memo(description: impl ToString) -> TokenTransferError {
+    TokenTransferError::InvalidMemo {
+        description: description.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(memo: &str, mode: MemoParseMode) -> Result<StructuredMemo, TokenTransferError> {
+        StructuredMemo::parse(&memo.to_string().into(), mode)
+    }
+
+    #[test]
+    fn parse_forward_memo() {
+        let memo = r#"{"forward":{"receiver":"cosmos1","port":"transfer","channel":"channel-1","timeout":"10m","retries":2,"next":{"wasm":{"contract":"osmo1","msg":{"swap":{"min_out":100}}}}}}"#;
+        let structured = parse(memo, MemoParseMode::Strict).unwrap();
+
+        let forward = structured.forward.as_ref().unwrap();
+        assert_eq!(forward.channel, ChannelId::new(1));
+        assert_eq!(
+            forward.timeout,
+            Some(ForwardTimeout::Duration("10m".to_string()))
+        );
+
+        let next = forward.next.as_ref().unwrap();
+        assert_eq!(next.wasm.as_ref().unwrap().contract, "osmo1");
+
+        assert_eq!(
+            parse(structured.to_memo().as_ref(), MemoParseMode::Strict).unwrap(),
+            structured
+        );
+    }
+
+    #[test]
+    fn parse_wasm_memo_with_floats() {
+        let memo = r#"{"wasm":{"contract":"osmo1","msg":{"swap":{"slippage":0.5,"min_out":-1}}}}"#;
+        let structured = parse(memo, MemoParseMode::Strict).unwrap();
+
+        let msg = &structured.wasm.as_ref().unwrap().msg;
+        assert_eq!(msg["swap"]["slippage"], 0.5);
+        assert_eq!(msg["swap"]["min_out"], -1);
+
+        assert_eq!(
+            parse(structured.to_memo().as_ref(), MemoParseMode::Strict).unwrap(),
+            structured
+        );
+    }
+
+    #[test]
+    fn parse_callback_memo() {
+        let memo = r#"{"src_callback":{"address":"cosmos1","gas_limit":"100000"}}"#;
+        let structured = parse(memo, MemoParseMode::Strict).unwrap();
+
+        assert_eq!(structured.src_callback.unwrap().gas_limit, Some(100_000));
+    }
+
+    #[test]
+    fn parse_modes() {
+        let plain_text = "thanks for the tokens";
+        assert!(parse(plain_text, MemoParseMode::Strict).is_err());
+        assert!(parse(plain_text, MemoParseMode::Lenient)
+            .unwrap()
+            .is_empty());
+
+        let unknown_key = r#"{"other_middleware":{},"src_callback":{"address":"cosmos1"}}"#;
+        assert!(parse(unknown_key, MemoParseMode::Strict).is_err());
+        assert!(parse(unknown_key, MemoParseMode::Lenient)
+            .unwrap()
+            .src_callback
+            .is_some());
+
+        assert!(parse("", MemoParseMode::Strict).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_invalid_memos() {
+        for memo in [
+            r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-1"}}"#,
+            r#"{"forward":{"receiver":"cosmos1","port":"transfer","channel":"chan"}}"#,
+            r#"{"wasm":{"contract":"osmo1","msg":"swap"}}"#,
+            r#"{"src_callback":{"address":"cosmos1","gas_limit":"lots"}}"#,
+        ] {
+            assert!(parse(memo, MemoParseMode::Lenient).is_err(), "{memo}");
+        }
+    }
+}
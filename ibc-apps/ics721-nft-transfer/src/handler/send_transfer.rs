@@ -121,7 +121,7 @@ where
             chan_id_on_a: msg.chan_id_on_a,
             port_id_on_b,
             chan_id_on_b,
-            data,
+            data: data.into(),
             timeout_height_on_b: msg.timeout_height_on_b,
             timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
         }
@@ -223,7 +223,7 @@ where
             chan_id_on_a: msg.chan_id_on_a,
             port_id_on_b: port_on_b,
             chan_id_on_b: chan_on_b,
-            data,
+            data: data.into(),
             timeout_height_on_b: msg.timeout_height_on_b,
             timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
         }
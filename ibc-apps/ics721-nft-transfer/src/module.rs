@@ -17,7 +17,7 @@ use crate::handler::{
 use crate::types::error::NftTransferError;
 use crate::types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
 use crate::types::packet::PacketData;
-use crate::types::{ack_success_b64, VERSION};
+use crate::types::VERSION;
 
 pub fn on_chan_open_init_validate(
     ctx: &impl NftTransferValidationContext,
@@ -182,7 +182,7 @@ pub fn on_recv_packet_execute(
     };
 
     let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
-        Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
+        Ok(extras) => (extras, AcknowledgementStatus::success_b64()),
         Err(boxed_error) => {
             let (extras, error) = *boxed_error;
             (extras, AcknowledgementStatus::error(error.into()))
@@ -211,7 +211,7 @@ pub fn on_acknowledgement_packet_validate(
     let data = serde_json::from_slice::<PacketData>(&packet.data)
         .map_err(|_| NftTransferError::PacketDataDeserialization)?;
 
-    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    let acknowledgement = AcknowledgementStatus::from_json_bytes(acknowledgement)
         .map_err(|_| NftTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
@@ -237,16 +237,15 @@ pub fn on_acknowledgement_packet_execute(
         }
     };
 
-    let acknowledgement =
-        match serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref()) {
-            Ok(ack) => ack,
-            Err(_) => {
-                return (
-                    ModuleExtras::empty(),
-                    Err(NftTransferError::AckDeserialization),
-                );
-            }
-        };
+    let acknowledgement = match AcknowledgementStatus::from_json_bytes(acknowledgement) {
+        Ok(ack) => ack,
+        Err(_) => {
+            return (
+                ModuleExtras::empty(),
+                Err(NftTransferError::AckDeserialization),
+            );
+        }
+    };
 
     if !acknowledgement.is_successful() {
         if let Err(err) = refund_packet_nft_execute(ctx, packet, &data) {
@@ -329,10 +328,7 @@ mod test {
             assert_eq!(ser, json_str)
         }
 
-        ser_json_assert_eq(
-            AcknowledgementStatus::success(ack_success_b64()),
-            r#"{"result":"AQ=="}"#,
-        );
+        ser_json_assert_eq(AcknowledgementStatus::success_b64(), r#"{"result":"AQ=="}"#);
         ser_json_assert_eq(
             AcknowledgementStatus::error(NftTransferError::PacketDataDeserialization.into()),
             r#"{"error":"failed to deserialize packet data"}"#,
@@ -341,7 +337,7 @@ mod test {
 
     #[test]
     fn test_ack_success_to_vec() {
-        let ack_success: Vec<u8> = AcknowledgementStatus::success(ack_success_b64()).into();
+        let ack_success: Vec<u8> = AcknowledgementStatus::success_b64().into();
 
         // Check that it's the same output as ibc-go
         // Note: this also implicitly checks that the ack bytes are non-empty,
@@ -370,10 +366,7 @@ mod test {
             assert_eq!(de, ack)
         }
 
-        de_json_assert_eq(
-            r#"{"result":"AQ=="}"#,
-            AcknowledgementStatus::success(ack_success_b64()),
-        );
+        de_json_assert_eq(r#"{"result":"AQ=="}"#, AcknowledgementStatus::success_b64());
         de_json_assert_eq(
             r#"{"error":"failed to deserialize packet data"}"#,
             AcknowledgementStatus::error(NftTransferError::PacketDataDeserialization.into()),
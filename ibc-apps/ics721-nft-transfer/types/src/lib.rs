@@ -45,11 +45,10 @@ pub const PORT_ID_STR: &str = "nft-transfer";
 /// ICS-721 application current version.
 pub const VERSION: &str = "ics721-1";
 
+use ibc_core::channel::types::acknowledgement::StatusValue;
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
-pub const ACK_SUCCESS_B64: &str = "AQ==";
-
-use ibc_core::channel::types::acknowledgement::StatusValue;
+pub use ibc_core::channel::types::acknowledgement::ACK_SUCCESS_B64;
 
 /// Returns a successful acknowledgement status for the NFT transfer application.
 pub fn ack_success_b64() -> StatusValue {
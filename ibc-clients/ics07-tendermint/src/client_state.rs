@@ -8,6 +8,9 @@
 //! Rust). As such, this module also includes some trait implementations that
 //! serve to pass through traits implemented on the wrapped `ClientState` type.
 
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::marker::PhantomData;
+
 use ibc_client_tendermint_types::error::Error;
 use ibc_client_tendermint_types::proto::v1::ClientState as RawTmClientState;
 use ibc_client_tendermint_types::ClientState as ClientStateType;
@@ -33,44 +36,78 @@ pub use validation::*;
 /// `ibc-client-tendermint-types` crate. This wrapper exists so that we can
 /// bypass Rust's orphan rules and implement traits from
 /// `ibc::core::client::context` on the `ClientState` type.
+///
+/// The `K` parameter is the [`UpgradeKeyEncoding`] under which the
+/// `ClientStateCommon` implementation looks up the upgraded states of the
+/// counterparty chain, read from the `upgrade_path` of the client state. It
+/// defaults to the [`IbcGoUpgradeKeys`] of ibc-go's `x/upgrade` module; hosts
+/// tracking chains with another upgrade key layout use `ClientState<MyKeys>`
+/// in their `AnyClientState` instead.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, PartialEq, derive_more::From)]
-pub struct ClientState(ClientStateType);
+#[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
+pub struct ClientState<K = IbcGoUpgradeKeys>(
+    ClientStateType,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<fn() -> K>,
+);
 
-impl ClientState {
+impl<K> ClientState<K> {
     pub fn inner(&self) -> &ClientStateType {
         &self.0
     }
 }
 
-impl Protobuf<RawTmClientState> for ClientState {}
+impl<K> From<ClientStateType> for ClientState<K> {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state, PhantomData)
+    }
+}
+
+impl<K> Clone for ClientState<K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<K> Debug for ClientState<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("ClientState").field(&self.0).finish()
+    }
+}
+
+impl<K> PartialEq for ClientState<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K> Protobuf<RawTmClientState> for ClientState<K> {}
 
-impl TryFrom<RawTmClientState> for ClientState {
+impl<K> TryFrom<RawTmClientState> for ClientState<K> {
     type Error = Error;
 
     fn try_from(raw: RawTmClientState) -> Result<Self, Self::Error> {
-        Ok(Self(ClientStateType::try_from(raw)?))
+        Ok(ClientStateType::try_from(raw)?.into())
     }
 }
 
-impl From<ClientState> for RawTmClientState {
-    fn from(client_state: ClientState) -> Self {
+impl<K> From<ClientState<K>> for RawTmClientState {
+    fn from(client_state: ClientState<K>) -> Self {
         client_state.0.into()
     }
 }
 
-impl Protobuf<Any> for ClientState {}
+impl<K> Protobuf<Any> for ClientState<K> {}
 
-impl TryFrom<Any> for ClientState {
+impl<K> TryFrom<Any> for ClientState<K> {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        Ok(Self(ClientStateType::try_from(raw)?))
+        Ok(ClientStateType::try_from(raw)?.into())
     }
 }
 
-impl From<ClientState> for Any {
-    fn from(client_state: ClientState) -> Self {
+impl<K> From<ClientState<K>> for Any {
+    fn from(client_state: ClientState<K>) -> Self {
         client_state.0.into()
     }
 }
@@ -82,10 +119,24 @@ mod tests {
     use ibc_client_tendermint_types::{
         AllowUpdate, ClientState as ClientStateType, TrustThreshold,
     };
+    use ibc_core_client::context::client_state::ClientStateCommon;
     use ibc_core_client::types::Height;
+    use ibc_core_commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc_core_commitment_types::merkle::MerkleProof;
+    use ibc_core_commitment_types::proto::ics23::commitment_proof::Proof;
+    use ibc_core_commitment_types::proto::ics23::{
+        calculate_existence_root, CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager,
+        LeafOp, LengthOp, NonExistenceProof,
+    };
+    use ibc_core_commitment_types::proto::v1::{MerklePath, MerkleProof as RawMerkleProof};
     use ibc_core_commitment_types::specs::ProofSpecs;
-    use ibc_core_host::types::identifiers::ChainId;
-    use tests::common::validate_proof_height;
+    use ibc_core_host::types::identifiers::{ChainId, ChannelId, PortId, Sequence};
+    use ibc_core_host::types::path::{Path, ReceiptPath};
+    use tests::common::{
+        validate_proof_height, IbcGoUpgradeKeys, UpgradeKeyEncoding, UpgradeKeysFromPath,
+    };
 
     use super::*;
 
@@ -157,8 +208,8 @@ mod tests {
             )
             .expect("Never fails");
             let client_state = match test.setup {
-                Some(setup) => (setup)(ClientState(client_state)),
-                _ => ClientState(client_state),
+                Some(setup) => (setup)(ClientState::from(client_state)),
+                _ => ClientState::from(client_state),
             };
             let res = validate_proof_height(client_state.inner(), test.height);
 
@@ -172,4 +223,177 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn ibc_go_upgrade_keys() {
+        let upgrade_path = ["upgrade".to_string(), "upgradedIBCState".to_string()];
+
+        let upgrade_keys = IbcGoUpgradeKeys::from_upgrade_path(&upgrade_path).unwrap();
+
+        assert_eq!(
+            upgrade_keys.upgraded_client_state_path(10).key_path,
+            ["upgrade", "upgradedIBCState/10/upgradedClient"]
+        );
+        assert_eq!(
+            upgrade_keys.upgraded_consensus_state_path(10).key_path,
+            ["upgrade", "upgradedIBCState/10/upgradedConsState"]
+        );
+
+        assert!(upgrade_keys.validate_upgrade_path(&upgrade_path).is_ok());
+        assert!(upgrade_keys.validate_upgrade_path(&[]).is_err());
+        assert!(upgrade_keys
+            .validate_upgrade_path(&["upgrade".to_string(), "custom".to_string()])
+            .is_err());
+        assert!(upgrade_keys
+            .validate_upgrade_path(&["upgradedIBCState".to_string()])
+            .is_err());
+
+        assert!(IbcGoUpgradeKeys::from_upgrade_path(&[]).is_err());
+    }
+
+    #[test]
+    fn ibc_go_upgrade_keys_from_custom_upgrade_path() {
+        let upgrade_path = ["custom".to_string(), "upgradedState".to_string()];
+
+        let upgrade_keys = IbcGoUpgradeKeys::from_upgrade_path(&upgrade_path).unwrap();
+
+        assert_eq!(
+            upgrade_keys.upgraded_client_state_path(3).key_path,
+            ["custom", "upgradedState/3/upgradedClient"]
+        );
+        assert!(upgrade_keys.validate_upgrade_path(&upgrade_path).is_ok());
+    }
+
+    /// Upgrade keys of a chain whose upgrade path is never supported.
+    struct UnsupportedUpgradeKeys;
+
+    impl UpgradeKeyEncoding for UnsupportedUpgradeKeys {
+        fn upgraded_client_state_path(&self, _height: u64) -> MerklePath {
+            unreachable!()
+        }
+
+        fn upgraded_consensus_state_path(&self, _height: u64) -> MerklePath {
+            unreachable!()
+        }
+    }
+
+    impl UpgradeKeysFromPath for UnsupportedUpgradeKeys {
+        fn from_upgrade_path(_upgrade_path: &[String]) -> Result<Self, ClientError> {
+            Err(ClientError::ClientSpecific {
+                description: "unsupported upgrade path".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn client_state_verifies_upgrades_with_its_upgrade_keys() {
+        let client_state = ClientStateType::new(
+            ChainId::new("ibc-1").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).expect("Never fails"),
+            ProofSpecs::default(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap();
+
+        let res = ClientState::<UnsupportedUpgradeKeys>::from(client_state).verify_upgrade_client(
+            Any::default(),
+            Any::default(),
+            CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            &CommitmentRoot::from_bytes(&[1]),
+        );
+
+        assert!(matches!(
+            res,
+            Err(ClientError::ClientSpecific { description }) if description == "unsupported upgrade path"
+        ));
+    }
+
+    fn existence_proof(key: Vec<u8>, value: Vec<u8>, prefix: Vec<u8>) -> ExistenceProof {
+        ExistenceProof {
+            key,
+            value,
+            leaf: Some(LeafOp {
+                hash: HashOp::Sha256.into(),
+                prehash_key: HashOp::NoHash.into(),
+                prehash_value: HashOp::Sha256.into(),
+                length: LengthOp::VarProto.into(),
+                prefix,
+            }),
+            path: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_packet_receipt_non_membership() {
+        let client_state = ClientStateType::new(
+            ChainId::new("ibc-1").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).expect("Never fails"),
+            ProofSpecs::default(),
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let receipt_path = |seq: u64| {
+            ReceiptPath::new(&PortId::transfer(), &ChannelId::new(0), Sequence::from(seq))
+        };
+
+        // The IBC store of the counterparty only holds the receipt of the
+        // packet of sequence 1, in an IAVL leaf at height 0, of size 1, and
+        // at version 1.
+        let leaf = existence_proof(
+            receipt_path(1).to_string().into_bytes(),
+            vec![1],
+            vec![0, 2, 2],
+        );
+        let store_root = calculate_existence_root::<HostFunctionsManager>(&leaf).unwrap();
+        let store = existence_proof(prefix.as_bytes().to_vec(), store_root, vec![0]);
+        let root =
+            CommitmentRoot::from(calculate_existence_root::<HostFunctionsManager>(&store).unwrap());
+
+        let proof = CommitmentProofBytes::try_from(RawMerkleProof::from(MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Nonexist(NonExistenceProof {
+                        key: receipt_path(2).to_string().into_bytes(),
+                        left: Some(leaf),
+                        right: None,
+                    })),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store)),
+                },
+            ],
+        }))
+        .unwrap();
+
+        let verify = |seq: u64| {
+            verify_non_membership::<HostFunctionsManager>(
+                &client_state,
+                &prefix,
+                &proof,
+                &root,
+                Path::Receipt(receipt_path(seq)),
+            )
+        };
+
+        assert!(verify(2).is_ok());
+        assert!(verify(1).is_err());
+    }
 }
@@ -3,12 +3,15 @@ use ibc_core_client::context::client_state::ClientStateCommon;
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::types::error::{ClientError, UpgradeClientError};
 use ibc_core_client::types::Height;
+use ibc_core_commitment_types::cache::ProofVerificationCache;
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
+use ibc_core_commitment_types::host_functions::{DefaultHostFunctions, HostFunctions};
 use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc_core_commitment_types::proto::v1::MerklePath;
 use ibc_core_host::types::identifiers::ClientType;
-use ibc_core_host::types::path::{Path, UpgradeClientPath};
+use ibc_core_host::types::path::{Path, UPGRADED_CLIENT_CONSENSUS_STATE, UPGRADED_CLIENT_STATE};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 use ibc_primitives::ToVec;
@@ -16,7 +19,7 @@ use ibc_primitives::ToVec;
 use super::ClientState;
 use crate::consensus_state::ConsensusState as TmConsensusState;
 
-impl ClientStateCommon for ClientState {
+impl<K: UpgradeKeysFromPath> ClientStateCommon for ClientState<K> {
     fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
         verify_consensus_state(consensus_state)
     }
@@ -41,13 +44,14 @@ impl ClientStateCommon for ClientState {
         proof_upgrade_consensus_state: CommitmentProofBytes,
         root: &CommitmentRoot,
     ) -> Result<(), ClientError> {
-        verify_upgrade_client(
+        verify_upgrade_client::<DefaultHostFunctions>(
             self.inner(),
             upgraded_client_state,
             upgraded_consensus_state,
             proof_upgrade_client,
             proof_upgrade_consensus_state,
             root,
+            &K::from_upgrade_path(&self.inner().upgrade_path)?,
         )
     }
 
@@ -59,7 +63,7 @@ impl ClientStateCommon for ClientState {
         path: Path,
         value: Vec<u8>,
     ) -> Result<(), ClientError> {
-        verify_membership(self.inner(), prefix, proof, root, path, value)
+        verify_membership::<DefaultHostFunctions>(self.inner(), prefix, proof, root, path, value)
     }
 
     fn verify_non_membership(
@@ -69,7 +73,7 @@ impl ClientStateCommon for ClientState {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError> {
-        verify_non_membership(self.inner(), prefix, proof, root, path)
+        verify_non_membership::<DefaultHostFunctions>(self.inner(), prefix, proof, root, path)
     }
 }
 
@@ -83,9 +87,7 @@ pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
     let tm_consensus_state = TmConsensusState::try_from(consensus_state)?;
 
     if tm_consensus_state.root().is_empty() {
-        return Err(ClientError::Other {
-            description: "empty commitment root".into(),
-        });
+        return Err(ClientError::EmptyCommitmentRoot);
     };
 
     Ok(())
@@ -121,25 +123,32 @@ pub fn validate_proof_height(
 /// [this](https://ibc.cosmos.network/main/ibc/upgrades/quick-guide.html)
 /// guide.
 ///
+/// The upgraded states are looked up under the keys given by `upgrade_keys`,
+/// which must agree with the `upgrade_path` of the client state, and their
+/// proofs are verified with the host functions `H`; the `ClientStateCommon`
+/// implementation of [`ClientState<K>`] reads the keys `K` from the upgrade
+/// path of the client state and uses the [`DefaultHostFunctions`].
+///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateCommon`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
-pub fn verify_upgrade_client(
+pub fn verify_upgrade_client<H: HostFunctions>(
     client_state: &ClientStateType,
     upgraded_client_state: Any,
     upgraded_consensus_state: Any,
     proof_upgrade_client: CommitmentProofBytes,
     proof_upgrade_consensus_state: CommitmentProofBytes,
     root: &CommitmentRoot,
+    upgrade_keys: &impl UpgradeKeyEncoding,
 ) -> Result<(), ClientError> {
     // Make sure that the client type is of Tendermint type `ClientState`
-    let upgraded_tm_client_state = ClientState::try_from(upgraded_client_state.clone())?;
+    let upgraded_tm_client_state = ClientStateType::try_from(upgraded_client_state.clone())?;
 
     // Make sure that the consensus type is of Tendermint type `ConsensusState`
     TmConsensusState::try_from(upgraded_consensus_state.clone())?;
 
     let latest_height = client_state.latest_height;
-    let upgraded_tm_client_state_height = upgraded_tm_client_state.latest_height();
+    let upgraded_tm_client_state_height = upgraded_tm_client_state.latest_height;
 
     // Make sure the latest height of the current client is not greater then
     // the upgrade height This condition checks both the revision number and
@@ -151,76 +160,293 @@ pub fn verify_upgrade_client(
         })?
     }
 
-    // Check to see if the upgrade path is set
-    let mut upgrade_path = client_state.upgrade_path.clone();
-
-    if upgrade_path.pop().is_none() {
-        return Err(ClientError::ClientSpecific {
-            description: "cannot upgrade client as no upgrade path has been set".to_string(),
-        });
-    };
-
-    let upgrade_path_prefix = CommitmentPrefix::try_from(upgrade_path[0].clone().into_bytes())
-        .map_err(ClientError::InvalidCommitmentProof)?;
+    upgrade_keys.validate_upgrade_path(&client_state.upgrade_path)?;
 
     let last_height = latest_height.revision_height();
 
     // Verify the proof of the upgraded client state
-    verify_membership(
+    verify_merkle_membership::<H>(
         client_state,
-        &upgrade_path_prefix,
         &proof_upgrade_client,
         root,
-        Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(last_height)),
+        upgrade_keys.upgraded_client_state_path(last_height),
         upgraded_client_state.to_vec(),
     )?;
 
     // Verify the proof of the upgraded consensus state
-    verify_membership(
+    verify_merkle_membership::<H>(
         client_state,
-        &upgrade_path_prefix,
         &proof_upgrade_consensus_state,
         root,
-        Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(last_height)),
+        upgrade_keys.upgraded_consensus_state_path(last_height),
         upgraded_consensus_state.to_vec(),
     )?;
 
     Ok(())
 }
 
-/// Verify membership of the given value against the client's merkle proof.
+/// Encodes the store keys under which the upgrade module of a chain commits
+/// the upgraded client and consensus states.
+///
+/// Chains whose upgrade module does not follow ibc-go's `x/upgrade` layout
+/// can implement this trait and pass it to [`verify_upgrade_client`], or
+/// implement [`UpgradeKeysFromPath`] too and track the chain with a
+/// [`ClientState<K>`].
+pub trait UpgradeKeyEncoding {
+    /// Validates the upgrade path stored in the client state against this
+    /// encoding. By default, only requires the upgrade path to be non-empty.
+    fn validate_upgrade_path(&self, upgrade_path: &[String]) -> Result<(), ClientError> {
+        if upgrade_path.is_empty() {
+            return Err(ClientError::ClientSpecific {
+                description: "cannot upgrade client as no upgrade path has been set".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the Merkle path of the upgraded client state committed at the
+    /// given height.
+    fn upgraded_client_state_path(&self, height: u64) -> MerklePath;
+
+    /// Returns the Merkle path of the upgraded consensus state committed at
+    /// the given height.
+    fn upgraded_consensus_state_path(&self, height: u64) -> MerklePath;
+}
+
+/// The upgrade key encoding of ibc-go's `x/upgrade` module, read from the
+/// `upgrade_path` of a client state: the upgraded states are committed under
+/// `{upgrade_key}/{height}/upgradedClient` and
+/// `{upgrade_key}/{height}/upgradedConsState`, where the upgrade key is the
+/// last segment of the upgrade path and the previous segments are the store
+/// prefixes of the proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IbcGoUpgradeKeys {
+    store_prefixes: Vec<String>,
+    upgrade_key: String,
+}
+
+/// An [`UpgradeKeyEncoding`] read from the `upgrade_path` of a client state,
+/// as done by the `ClientStateCommon` implementation of [`ClientState<K>`].
+pub trait UpgradeKeysFromPath: UpgradeKeyEncoding + Sized {
+    /// Reads the upgrade keys from the `upgrade_path` of a client state.
+    fn from_upgrade_path(upgrade_path: &[String]) -> Result<Self, ClientError>;
+}
+
+impl IbcGoUpgradeKeys {
+    fn merkle_path(&self, key: String) -> MerklePath {
+        let mut key_path = self.store_prefixes.clone();
+        key_path.push(key);
+        MerklePath { key_path }
+    }
+}
+
+impl UpgradeKeysFromPath for IbcGoUpgradeKeys {
+    /// Reads the upgrade keys from the `upgrade_path` of a client state, e.g.
+    /// `["upgrade", "upgradedIBCState"]` for the Cosmos SDK chains.
+    fn from_upgrade_path(upgrade_path: &[String]) -> Result<Self, ClientError> {
+        let (upgrade_key, store_prefixes) =
+            upgrade_path
+                .split_last()
+                .ok_or_else(|| ClientError::ClientSpecific {
+                    description: "cannot upgrade client as no upgrade path has been set"
+                        .to_string(),
+                })?;
+
+        Ok(Self {
+            store_prefixes: store_prefixes.to_vec(),
+            upgrade_key: upgrade_key.clone(),
+        })
+    }
+}
+
+impl UpgradeKeyEncoding for IbcGoUpgradeKeys {
+    fn validate_upgrade_path(&self, upgrade_path: &[String]) -> Result<(), ClientError> {
+        let matches = upgrade_path
+            .split_last()
+            .map_or(false, |(upgrade_key, store_prefixes)| {
+                *upgrade_key == self.upgrade_key && store_prefixes == self.store_prefixes
+            });
+
+        if !matches {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "upgrade path `{}` does not match the upgrade keys ending with `{}`",
+                    upgrade_path.join("/"),
+                    self.upgrade_key
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn upgraded_client_state_path(&self, height: u64) -> MerklePath {
+        self.merkle_path(format!(
+            "{}/{height}/{UPGRADED_CLIENT_STATE}",
+            self.upgrade_key
+        ))
+    }
+
+    fn upgraded_consensus_state_path(&self, height: u64) -> MerklePath {
+        self.merkle_path(format!(
+            "{}/{height}/{UPGRADED_CLIENT_CONSENSUS_STATE}",
+            self.upgrade_key
+        ))
+    }
+}
+
+fn verify_merkle_membership<H: HostFunctions>(
+    client_state: &ClientStateType,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_membership::<H>(
+            &client_state.proof_specs,
+            root.clone().into(),
+            merkle_path,
+            value,
+            0,
+        )
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verify membership of the given value against the client's merkle proof,
+/// hashing with the host functions `H`.
 ///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateCommon`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
-pub fn verify_membership(
+pub fn verify_membership<H: HostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_membership::<H>(
+            &client_state.proof_specs,
+            root.clone().into(),
+            merkle_path,
+            value,
+            0,
+        )
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verify membership of the given value against the client's merkle proof,
+/// consulting the given `cache` to skip the verifications that already
+/// succeeded for the same root, path and value under the proof specs of the
+/// client.
+///
+/// Hosts that want to enable proof caching can call this function from their
+/// own `ClientStateCommon::verify_membership` implementation.
+pub fn verify_membership_cached<H: HostFunctions>(
     client_state: &ClientStateType,
     prefix: &CommitmentPrefix,
     proof: &CommitmentProofBytes,
     root: &CommitmentRoot,
     path: Path,
     value: Vec<u8>,
+    cache: &impl ProofVerificationCache,
 ) -> Result<(), ClientError> {
     let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
-        .verify_membership(
+        .verify_membership_cached::<H>(
             &client_state.proof_specs,
             root.clone().into(),
             merkle_path,
             value,
             0,
+            cache,
         )
         .map_err(ClientError::Ics23Verification)
 }
 
-/// Verify that the given value does not belong in the client's merkle proof.
+/// Verify the membership of all the given `(path, value)` items against a
+/// single merkle proof of the client, whose lowest proof is an ICS-23 batch
+/// proof of the items, e.g. the commitments of many packets of a channel.
+pub fn verify_batch_membership<H: HostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    items: Vec<(Path, Vec<u8>)>,
+) -> Result<(), ClientError> {
+    let items = prefix_batch_items(prefix, items);
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_batch_membership::<H>(&client_state.proof_specs, root.clone().into(), &items)
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Same as [`verify_batch_membership`], but records every verified item in
+/// the given `cache`.
+///
+/// This is the fast path of hosts proving many packets against the same
+/// root: once the batch is verified, the verifications of the items one by
+/// one with [`verify_membership_cached`] are cache hits.
+///
+/// Note that the core handlers verify the proofs of the messages one by one
+/// through `ClientStateCommon::verify_membership`, and neither call this
+/// function nor consult any cache. Hosts opting into the fast path call it
+/// themselves, e.g. before dispatching the messages of a transaction, and
+/// call [`verify_membership_cached`] with the same cache from their own
+/// `ClientStateCommon::verify_membership` implementation.
+pub fn verify_batch_membership_cached<H: HostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    items: Vec<(Path, Vec<u8>)>,
+    cache: &impl ProofVerificationCache,
+) -> Result<(), ClientError> {
+    let items = prefix_batch_items(prefix, items);
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_batch_membership_cached::<H>(
+            &client_state.proof_specs,
+            root.clone().into(),
+            &items,
+            cache,
+        )
+        .map_err(ClientError::Ics23Verification)
+}
+
+fn prefix_batch_items(
+    prefix: &CommitmentPrefix,
+    items: Vec<(Path, Vec<u8>)>,
+) -> Vec<(MerklePath, Vec<u8>)> {
+    items
+        .into_iter()
+        .map(|(path, value)| (apply_prefix(prefix, vec![path.to_string()]), value))
+        .collect()
+}
+
+/// Verify that the given value does not belong in the client's merkle proof,
+/// hashing with the host functions `H`.
 ///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateCommon`] trait, but has been made a standalone function
-/// in order to make the ClientState APIs more flexible.
-pub fn verify_non_membership(
+/// in order to make the ClientState APIs more flexible, e.g. to let relayers
+/// check the absence of a packet receipt before submitting a timeout. The
+/// verification against the proof specs of the client only is provided by
+/// [`verify_non_membership_proof`](ibc_core_commitment_types::merkle::verify_non_membership_proof).
+pub fn verify_non_membership<H: HostFunctions>(
     client_state: &ClientStateType,
     prefix: &CommitmentPrefix,
     proof: &CommitmentProofBytes,
@@ -231,6 +457,6 @@ pub fn verify_non_membership(
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
-        .verify_non_membership(&client_state.proof_specs, root.clone().into(), merkle_path)
+        .verify_non_membership::<H>(&client_state.proof_specs, root.clone().into(), merkle_path)
         .map_err(ClientError::Ics23Verification)
 }
@@ -2,24 +2,27 @@ use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
 };
 use ibc_core_client::context::client_state::ClientStateExecution;
+use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::context::ClientExecutionContext;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_commitment_types::host_functions::HostFunctions;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
-use ibc_core_host::ExecutionContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
-use super::ClientState;
+use super::{verify_upgrade_client, ClientState, UpgradeKeyEncoding, UpgradeKeysFromPath};
 use crate::consensus_state::ConsensusState as TmConsensusState;
-use crate::context::{CommonContext, ExecutionContext as TmExecutionContext};
+use crate::context::{
+    ExecutionContext as TmExecutionContext, ValidationContext as TmValidationContext,
+};
 
-impl<E> ClientStateExecution<E> for ClientState
+impl<K, E> ClientStateExecution<E> for ClientState<K>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientStateType>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<ConsensusStateType>,
+    K: UpgradeKeysFromPath,
+    E: TmExecutionContext,
 {
     fn initialise(
         &self,
@@ -77,18 +80,16 @@ pub fn initialise<E>(
     consensus_state: Any,
 ) -> Result<(), ClientError>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientStateType>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<ConsensusStateType>,
+    E: TmExecutionContext,
 {
-    let host_timestamp = CommonContext::host_timestamp(ctx)?;
-    let host_height = CommonContext::host_height(ctx)?;
+    let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
+    let host_height = TmValidationContext::host_height(ctx)?;
 
     let tm_consensus_state = ConsensusStateType::try_from(consensus_state)?;
 
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
-        client_state.clone().into(),
+        into_host_client_state::<E>(client_state.clone())?,
     )?;
     ctx.store_consensus_state(
         ClientConsensusStatePath::new(
@@ -96,7 +97,7 @@ where
             client_state.latest_height.revision_number(),
             client_state.latest_height.revision_height(),
         ),
-        tm_consensus_state.into(),
+        into_host_consensus_state::<E>(tm_consensus_state)?,
     )?;
 
     ctx.store_update_meta(
@@ -112,6 +113,9 @@ where
 /// Update the host store with a new client state, pruning old states from the
 /// store if need be.
 ///
+/// If a consensus state already exists at the header height, the update is a
+/// no-op and the store is left untouched.
+///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateExecution`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
@@ -122,14 +126,10 @@ pub fn update_state<E>(
     header: Any,
 ) -> Result<Vec<Height>, ClientError>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientStateType>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<ConsensusStateType>,
+    E: TmExecutionContext,
 {
     let header = TmHeader::try_from(header)?;
-    let header_height = header.height();
-
-    prune_oldest_consensus_state(client_state, ctx, client_id)?;
+    let header_height = client_state.header_height(&header);
 
     let maybe_existing_consensus_state = {
         let path_at_header_height = ClientConsensusStatePath::new(
@@ -138,41 +138,45 @@ where
             header_height.revision_height(),
         );
 
-        CommonContext::consensus_state(ctx, &path_at_header_height).ok()
+        TmValidationContext::consensus_state(ctx, &path_at_header_height).ok()
     };
 
     if maybe_existing_consensus_state.is_some() {
         // if we already had the header installed by a previous relayer
         // then this is a no-op.
         //
-        // Do nothing.
-    } else {
-        let host_timestamp = CommonContext::host_timestamp(ctx)?;
-        let host_height = CommonContext::host_height(ctx)?;
-
-        let new_consensus_state = ConsensusStateType::from(header.clone());
-        let new_client_state = client_state.clone().with_header(header)?;
-
-        ctx.store_consensus_state(
-            ClientConsensusStatePath::new(
-                client_id.clone(),
-                header_height.revision_number(),
-                header_height.revision_height(),
-            ),
-            new_consensus_state.into(),
-        )?;
-        ctx.store_client_state(
-            ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
-        )?;
-        ctx.store_update_meta(
-            client_id.clone(),
-            header_height,
-            host_timestamp,
-            host_height,
-        )?;
+        // Return early without touching the store: neither pruning nor any
+        // client state or metadata write happens on this path.
+        return Ok(vec![header_height]);
     }
 
+    prune_oldest_consensus_state(client_state, ctx, client_id)?;
+
+    let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
+    let host_height = TmValidationContext::host_height(ctx)?;
+
+    let new_consensus_state = ConsensusStateType::from(header.clone());
+    let new_client_state = client_state.clone().with_header(header)?;
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        ),
+        into_host_consensus_state::<E>(new_consensus_state)?,
+    )?;
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        into_host_client_state::<E>(new_client_state)?,
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        header_height,
+        host_timestamp,
+        host_height,
+    )?;
+
     Ok(vec![header_height])
 }
 
@@ -189,25 +193,26 @@ pub fn update_on_misbehaviour<E>(
     _client_message: Any,
 ) -> Result<(), ClientError>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientStateType>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<ConsensusStateType>,
+    E: TmExecutionContext,
 {
-    // NOTE: frozen height is  set to `Height {revision_height: 0,
-    // revision_number: 1}` and it is the same for all misbehaviour. This
-    // aligns with the
-    // [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
-    // implementation.
-    let frozen_client_state = client_state.clone().with_frozen_height(Height::min(0));
-
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
-        frozen_client_state.into(),
+        into_host_client_state::<E>(frozen_client_state(client_state))?,
     )?;
 
     Ok(())
 }
 
+/// Returns the given client state frozen upon misbehaviour.
+pub fn frozen_client_state(client_state: &ClientStateType) -> ClientStateType {
+    // NOTE: frozen height is  set to `Height {revision_height: 0,
+    // revision_number: 1}` and it is the same for all misbehaviour. This
+    // aligns with the
+    // [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
+    // implementation.
+    client_state.clone().with_frozen_height(Height::min(0))
+}
+
 /// Commit the new client state and consensus state to the store.
 ///
 /// Note that this function is typically implemented as part of the
@@ -221,30 +226,29 @@ pub fn update_on_upgrade<E>(
     upgraded_consensus_state: Any,
 ) -> Result<Height, ClientError>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientStateType>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<ConsensusStateType>,
+    E: TmExecutionContext,
 {
-    let mut upgraded_tm_client_state = ClientState::try_from(upgraded_client_state)?;
+    let mut upgraded_tm_client_state = ClientStateType::try_from(upgraded_client_state)?;
     let upgraded_tm_cons_state = TmConsensusState::try_from(upgraded_consensus_state)?;
 
-    upgraded_tm_client_state.0.zero_custom_fields();
+    upgraded_tm_client_state.zero_custom_fields();
 
     // Construct new client state and consensus state relayer chosen client
     // parameters are ignored. All chain-chosen parameters come from
     // committed client, all client-chosen parameters come from current
     // client.
     let new_client_state = ClientStateType::new(
-        upgraded_tm_client_state.0.chain_id,
+        upgraded_tm_client_state.chain_id,
         client_state.trust_level,
         client_state.trusting_period,
-        upgraded_tm_client_state.0.unbonding_period,
+        upgraded_tm_client_state.unbonding_period,
         client_state.max_clock_drift,
-        upgraded_tm_client_state.0.latest_height,
-        upgraded_tm_client_state.0.proof_specs,
-        upgraded_tm_client_state.0.upgrade_path,
+        upgraded_tm_client_state.latest_height,
+        upgraded_tm_client_state.proof_specs,
+        upgraded_tm_client_state.upgrade_path,
         client_state.allow_update,
-    )?;
+    )?
+    .with_revision_format(upgraded_tm_client_state.revision_format)?;
 
     // The new consensus state is merely used as a trusted kernel against
     // which headers on the new chain can be verified. The root is just a
@@ -267,12 +271,12 @@ where
     );
 
     let latest_height = new_client_state.latest_height;
-    let host_timestamp = CommonContext::host_timestamp(ctx)?;
-    let host_height = CommonContext::host_height(ctx)?;
+    let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
+    let host_height = TmValidationContext::host_height(ctx)?;
 
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
-        new_client_state.into(),
+        into_host_client_state::<E>(new_client_state)?,
     )?;
     ctx.store_consensus_state(
         ClientConsensusStatePath::new(
@@ -280,7 +284,7 @@ where
             latest_height.revision_number(),
             latest_height.revision_height(),
         ),
-        new_consensus_state.into(),
+        into_host_consensus_state::<E>(new_consensus_state)?,
     )?;
     ctx.store_update_meta(
         client_id.clone(),
@@ -292,6 +296,88 @@ where
     Ok(latest_height)
 }
 
+/// Verify the upgraded client and consensus states against the upgrade path
+/// committed by the counterparty, then commit them to the store, returning
+/// the latest height of the upgraded client.
+///
+/// The proofs are verified with the proof specs of the client, against the
+/// root of its latest consensus state and with the host functions `H`, the
+/// upgraded states being looked up under the keys given by `upgrade_keys`.
+///
+/// Note that this function composes [`verify_upgrade_client`] and
+/// [`update_on_upgrade`], typically called by the upgrade client handler
+/// through the `ClientStateCommon` and [`ClientStateExecution`] traits, but
+/// has been made a standalone function in order to make the ClientState APIs
+/// more flexible.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_upgrade_and_update_state<E, H>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    upgraded_client_state: Any,
+    upgraded_consensus_state: Any,
+    proof_upgrade_client: CommitmentProofBytes,
+    proof_upgrade_consensus_state: CommitmentProofBytes,
+    upgrade_keys: &impl UpgradeKeyEncoding,
+) -> Result<Height, ClientError>
+where
+    E: TmExecutionContext,
+    H: HostFunctions,
+{
+    let latest_height = client_state.latest_height;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        latest_height.revision_number(),
+        latest_height.revision_height(),
+    );
+    let latest_consensus_state: TmConsensusState = ctx
+        .consensus_state(&client_cons_state_path)?
+        .try_into()
+        .map_err(|err| ClientError::ConsensusStateConversionFailed {
+            client_id: client_id.clone(),
+            description: err.to_string(),
+        })?;
+
+    verify_upgrade_client::<H>(
+        client_state,
+        upgraded_client_state.clone(),
+        upgraded_consensus_state.clone(),
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+        latest_consensus_state.root(),
+        upgrade_keys,
+    )?;
+
+    update_on_upgrade(
+        client_state,
+        ctx,
+        client_id,
+        upgraded_client_state,
+        upgraded_consensus_state,
+    )
+}
+
+/// Converts a Tendermint client state into the host's client state type.
+fn into_host_client_state<E>(
+    client_state: ClientStateType,
+) -> Result<<E as TmExecutionContext>::HostClientState, ClientError>
+where
+    E: TmExecutionContext,
+{
+    <E as TmExecutionContext>::HostClientState::try_from(client_state).map_err(Into::into)
+}
+
+/// Converts a Tendermint consensus state into the host's consensus state type.
+fn into_host_consensus_state<E>(
+    consensus_state: ConsensusStateType,
+) -> Result<<E as TmExecutionContext>::HostConsensusState, ClientError>
+where
+    E: TmExecutionContext,
+{
+    <E as TmExecutionContext>::HostConsensusState::try_from(consensus_state).map_err(Into::into)
+}
+
 /// Removes consensus states from the client store whose timestamps
 /// are less than or equal to the host timestamp. This ensures that
 /// the client store does not amass a buildup of stale consensus states.
@@ -301,7 +387,7 @@ pub fn prune_oldest_consensus_state<E>(
     client_id: &ClientId,
 ) -> Result<(), ClientError>
 where
-    E: ClientExecutionContext + CommonContext,
+    E: ClientExecutionContext + TmValidationContext,
 {
     let mut heights = ctx.consensus_state_heights(client_id)?;
 
@@ -313,30 +399,21 @@ where
             height.revision_number(),
             height.revision_height(),
         );
-        let consensus_state = CommonContext::consensus_state(ctx, &client_consensus_state_path)?;
-        let tm_consensus_state = consensus_state
-            .try_into()
-            .map_err(|err| ClientError::Other {
+        let consensus_state =
+            TmValidationContext::consensus_state(ctx, &client_consensus_state_path)?;
+        let tm_consensus_state = consensus_state.try_into().map_err(|err| {
+            ClientError::ConsensusStateConversionFailed {
+                client_id: client_id.clone(),
                 description: err.to_string(),
-            })?;
-
-        let host_timestamp =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::Other {
-                    description: String::from("host timestamp is not a valid TM timestamp"),
-                })?;
-
-        let tm_consensus_state_timestamp = tm_consensus_state.timestamp();
-        let tm_consensus_state_expiry = (tm_consensus_state_timestamp
-            + client_state.trusting_period)
-            .map_err(|_| ClientError::Other {
-                description: String::from(
-                    "Timestamp overflow error occurred while attempting to parse TmConsensusState",
-                ),
-            })?;
-
-        if tm_consensus_state_expiry > host_timestamp {
+            }
+        })?;
+
+        let host_timestamp = ctx.host_timestamp()?;
+        let consensus_state_timestamp = tm_consensus_state.timestamp().into();
+
+        if !host_timestamp
+            .has_elapsed_since(&consensus_state_timestamp, client_state.trusting_period)
+        {
             break;
         }
 
@@ -11,21 +11,23 @@ use ibc_primitives::Timestamp;
 use tendermint_light_client_verifier::Verifier;
 
 use super::TmValidationContext;
+use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::context::TmVerifier;
 
 /// Determines whether or not two conflicting headers at the same height would
 /// have convinced the light client.
-pub fn verify_misbehaviour<V>(
+pub fn verify_misbehaviour<V, T>(
     client_state: &ClientStateType,
     ctx: &V,
     client_id: &ClientId,
     misbehaviour: &TmMisbehaviour,
-    verifier: &impl TmVerifier,
+    verifier: &T,
 ) -> Result<(), ClientError>
 where
     V: TmValidationContext,
+    T: TmVerifier,
 {
-    misbehaviour.validate_basic()?;
+    misbehaviour.validate_basic_with_format::<T::Sha256>(client_state.revision_format)?;
 
     let header_1 = misbehaviour.header1();
     let trusted_consensus_state_1 = {
@@ -38,7 +40,8 @@ where
 
         consensus_state
             .try_into()
-            .map_err(|err| ClientError::Other {
+            .map_err(|err| ClientError::ConsensusStateConversionFailed {
+                client_id: client_id.clone(),
                 description: err.to_string(),
             })?
     };
@@ -54,7 +57,8 @@ where
 
         consensus_state
             .try_into()
-            .map_err(|err| ClientError::Other {
+            .map_err(|err| ClientError::ConsensusStateConversionFailed {
+                client_id: client_id.clone(),
                 description: err.to_string(),
             })?
     };
@@ -77,15 +81,18 @@ where
     )
 }
 
-pub fn verify_misbehaviour_header(
+pub fn verify_misbehaviour_header<T>(
     client_state: &ClientStateType,
     header: &TmHeader,
     trusted_consensus_state: &ConsensusStateType,
     current_timestamp: Timestamp,
-    verifier: &impl TmVerifier,
-) -> Result<(), ClientError> {
+    verifier: &T,
+) -> Result<(), ClientError>
+where
+    T: TmVerifier,
+{
     // ensure correctness of the trusted next validator set provided by the relayer
-    header.check_trusted_next_validator_set(trusted_consensus_state)?;
+    header.check_trusted_next_validator_set_with::<T::Sha256>(trusted_consensus_state)?;
 
     // ensure trusted consensus state is within trusting period
     {
@@ -113,16 +120,19 @@ pub fn verify_misbehaviour_header(
             .chain_id
             .to_string()
             .try_into()
-            .map_err(|e| ClientError::Other {
-                description: format!("failed to parse chain id: {}", e),
-            })?;
+            .map_err(
+                |e: tendermint::Error| ClientError::InvalidLightClientChainId {
+                    chain_id: client_state.chain_id.clone(),
+                    description: e.to_string(),
+                },
+            )?;
 
     let trusted_state = header.as_trusted_block_state(trusted_consensus_state, &chain_id)?;
 
     let options = client_state.as_light_client_options()?;
-    let current_timestamp = current_timestamp.into_tm_time().ok_or(ClientError::Other {
-        description: "host timestamp must not be zero".to_string(),
-    })?;
+    let current_timestamp = current_timestamp
+        .into_tm_time()
+        .ok_or(ClientError::ZeroHostTimestamp)?;
 
     verifier
         .verifier()
@@ -132,6 +142,8 @@ pub fn verify_misbehaviour_header(
     Ok(())
 }
 
+/// Checks whether the two conflicting headers of the given misbehaviour are
+/// evidence of misbehaviour.
 pub fn check_for_misbehaviour_misbehavior(
     misbehaviour: &TmMisbehaviour,
 ) -> Result<bool, ClientError> {
@@ -139,17 +151,50 @@ pub fn check_for_misbehaviour_misbehavior(
     let header_2 = misbehaviour.header2();
 
     if header_1.height() == header_2.height() {
-        // when the height of the 2 headers are equal, we only have evidence
-        // of misbehaviour in the case where the headers are different
-        // (otherwise, the same header was added twice in the message,
-        // and this is evidence of nothing)
-        Ok(header_1.signed_header.commit.block_id.hash
-            != header_2.signed_header.commit.block_id.hash)
+        Ok(is_conflicting_header_pair(header_1, header_2))
     } else {
-        // header_1 is at greater height than header_2, therefore
-        // header_1 time must be less than or equal to
-        // header_2 time in order to be valid misbehaviour (violation of
-        // monotonic time).
-        Ok(header_1.signed_header.header.time <= header_2.signed_header.header.time)
+        Ok(is_non_monotonic_header_pair(header_1, header_2))
     }
 }
+
+/// Checks whether two headers at the same height commit different blocks.
+///
+/// The same header submitted twice is evidence of nothing.
+pub fn is_conflicting_header_pair(header_1: &TmHeader, header_2: &TmHeader) -> bool {
+    header_1.height() == header_2.height()
+        && header_1.signed_header.commit.block_id.hash
+            != header_2.signed_header.commit.block_id.hash
+}
+
+/// Checks whether two headers violate the monotonicity of the BFT time, the
+/// first header being at a greater height than the second one but not at a
+/// later time.
+pub fn is_non_monotonic_header_pair(header_1: &TmHeader, header_2: &TmHeader) -> bool {
+    header_1.height() > header_2.height()
+        && header_1.signed_header.header.time <= header_2.signed_header.header.time
+}
+
+/// Checks whether the given header conflicts with the consensus state already
+/// installed at its height.
+pub fn is_conflicting_header(
+    header: &TmHeader,
+    existing_consensus_state: &TmConsensusState,
+) -> bool {
+    existing_consensus_state != &TmConsensusState::from(ConsensusStateType::from(header.clone()))
+}
+
+/// Checks whether the given header violates the monotonicity of the BFT time
+/// with respect to the consensus states installed right before and right
+/// after its height, if any.
+pub fn is_non_monotonic_header(
+    header: &TmHeader,
+    prev_consensus_state: Option<&TmConsensusState>,
+    next_consensus_state: Option<&TmConsensusState>,
+) -> bool {
+    let header_time = header.signed_header.header().time;
+
+    // The new header cannot occur before the previous consensus state, nor
+    // after the next one.
+    prev_consensus_state.map_or(false, |prev_cs| header_time <= prev_cs.timestamp())
+        || next_consensus_state.map_or(false, |next_cs| header_time >= next_cs.timestamp())
+}
@@ -1,4 +1,4 @@
-use ibc_client_tendermint_types::error::{Error, IntoResult};
+use ibc_client_tendermint_types::error::Error;
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
 };
@@ -6,11 +6,11 @@ use ibc_core_client::types::error::ClientError;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
-use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
-use tendermint_light_client_verifier::Verifier;
 
+use super::{is_conflicting_header, is_non_monotonic_header};
 use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::context::{TmVerifier, ValidationContext as TmValidationContext};
+use crate::light_client::{self, TrustedState};
 
 pub fn verify_header<V>(
     client_state: &ClientStateType,
@@ -22,78 +22,147 @@ pub fn verify_header<V>(
 where
     V: TmValidationContext,
 {
-    // Checks that the header fields are valid.
-    header.validate_basic()?;
+    let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.trusted_height.revision_number(),
+        header.trusted_height.revision_height(),
+    );
+    let trusted_consensus_state: TmConsensusState = ctx
+        .consensus_state(&trusted_client_cons_state_path)?
+        .try_into()
+        .map_err(|err| ClientError::ConsensusStateConversionFailed {
+            client_id: client_id.clone(),
+            description: err.to_string(),
+        })?;
+
+    let trusted_state = TrustedState {
+        chain_id: &client_state.chain_id,
+        revision_format: client_state.revision_format,
+        consensus_state: trusted_consensus_state.inner(),
+    };
 
-    // The tendermint-light-client crate though works on heights that are assumed
-    // to have the same revision number. We ensure this here.
-    header.verify_chain_id_version_matches_height(&client_state.chain_id())?;
+    let mut options = client_state.as_light_client_options()?;
+    options.clock_drift = ctx
+        .max_clock_drift(client_id, client_state)?
+        .min(client_state.max_clock_drift);
+
+    light_client::verify_header(
+        trusted_state,
+        header,
+        &options,
+        verifier,
+        ctx.host_timestamp()?,
+    )?;
+
+    if let Some(provider_client_id) = ctx.provider_client_id(client_id)? {
+        verify_consumer_validator_set(ctx, &provider_client_id, header)?;
+    }
 
-    // Delegate to tendermint-light-client, which contains the required checks
-    // of the new header against the trusted consensus state.
+    Ok(())
+}
+
+/// The number of the latest consensus states of a provider client whose
+/// validator sets the headers of its consumer chains are matched against.
+pub const PROVIDER_VALIDATOR_SET_WINDOW: usize = 16;
+
+/// Checks that the validator set of the given header of an Interchain
+/// Security consumer chain is one of the validator sets of its provider chain,
+/// as known to the consensus states of the provider client.
+///
+/// The changes of the validator set of the provider chain reach its consumer
+/// chains with some delay, so the validator set of the header is matched
+/// against the next validator sets of the [`PROVIDER_VALIDATOR_SET_WINDOW`]
+/// latest provider consensus states, starting from the latest one.
+///
+/// The validator set hashes are compared byte for byte, hence the check only
+/// holds for consumer chains without key assignment, whose validators sign
+/// with their provider keys.
+pub fn verify_consumer_validator_set<V>(
+    ctx: &V,
+    provider_client_id: &ClientId,
+    header: &TmHeader,
+) -> Result<(), ClientError>
+where
+    V: TmValidationContext,
+{
+    let validators_hash = header.signed_header.header.validators_hash;
+
+    for height in ctx
+        .consensus_state_heights(provider_client_id)?
+        .into_iter()
+        .rev()
+        .take(PROVIDER_VALIDATOR_SET_WINDOW)
     {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: TmConsensusState = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(|err| ClientError::Other {
-                    description: err.to_string(),
-                })?;
-
-            header.check_trusted_next_validator_set(trusted_consensus_state.inner())?;
-
-            TrustedBlockState {
-                chain_id: &client_state.chain_id.to_string().try_into().map_err(|e| {
-                    ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    }
-                })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash(),
-            }
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
-        };
-
-        let options = client_state.as_light_client_options()?;
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
-
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verifier()
-            .verify_update_header(untrusted_state, trusted_state, &options, now)
-            .into_result()?;
+        let provider_cons_state_path = ClientConsensusStatePath::new(
+            provider_client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let provider_consensus_state: TmConsensusState = ctx
+            .consensus_state(&provider_cons_state_path)?
+            .try_into()
+            .map_err(|err| ClientError::ConsensusStateConversionFailed {
+                client_id: provider_client_id.clone(),
+                description: err.to_string(),
+            })?;
+
+        if provider_consensus_state.inner().next_validators_hash == validators_hash {
+            return Ok(());
+        }
     }
 
-    Ok(())
+    Err(Error::ConsumerValidatorSetNotFromProvider {
+        validators_hash,
+        provider_client_id: provider_client_id.clone(),
+    }
+    .into())
+}
+
+/// Returns whether the consensus state of the given header is already stored
+/// for the client at the height of the header, e.g. when several relayers
+/// submit the same update.
+///
+/// Such updates are no-ops, which hosts may reject from their ante handlers
+/// with this function, before paying for any light client verification.
+///
+/// Note that the header itself is not verified: only its consensus state is
+/// compared to the stored one. [`verify_header`] therefore still runs the
+/// full verification of duplicate headers, as the update emits an event
+/// carrying the header.
+pub fn is_duplicate_header<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: &TmHeader,
+) -> Result<bool, ClientError>
+where
+    V: TmValidationContext,
+{
+    let header_height = client_state.header_height(header);
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    let existing_consensus_state = match ctx.consensus_state(&path_at_header_height) {
+        Ok(existing_consensus_state) => existing_consensus_state,
+        Err(_) => return Ok(false),
+    };
+
+    let existing_consensus_state: TmConsensusState =
+        existing_consensus_state.try_into().map_err(|err| {
+            ClientError::ConsensusStateConversionFailed {
+                client_id: client_id.clone(),
+                description: err.to_string(),
+            }
+        })?;
+
+    Ok(
+        existing_consensus_state
+            == TmConsensusState::from(ConsensusStateType::from(header.clone())),
+    )
 }
 
 /// Checks for misbehaviour upon receiving a new consensus state as part
@@ -107,11 +176,13 @@ pub fn check_for_misbehaviour_update_client<V>(
 where
     V: TmValidationContext,
 {
+    let header_height = client_state.header_height(&header);
+
     let maybe_existing_consensus_state = {
         let path_at_header_height = ClientConsensusStatePath::new(
             client_id.clone(),
-            header.height().revision_number(),
-            header.height().revision_height(),
+            header_height.revision_number(),
+            header_height.revision_height(),
         );
 
         ctx.consensus_state(&path_at_header_height).ok()
@@ -119,61 +190,56 @@ where
 
     match maybe_existing_consensus_state {
         Some(existing_consensus_state) => {
-            let existing_consensus_state =
-                existing_consensus_state
-                    .try_into()
-                    .map_err(|err| ClientError::Other {
-                        description: err.to_string(),
-                    })?;
-
-            let header_consensus_state =
-                TmConsensusState::from(ConsensusStateType::from(header.clone()));
+            let existing_consensus_state = existing_consensus_state.try_into().map_err(|err| {
+                ClientError::ConsensusStateConversionFailed {
+                    client_id: client_id.clone(),
+                    description: err.to_string(),
+                }
+            })?;
 
             // There is evidence of misbehaviour if the stored consensus state
             // is different from the new one we received.
-            Ok(existing_consensus_state != header_consensus_state)
+            Ok(is_conflicting_header(&header, &existing_consensus_state))
         }
         None => {
-            // If no header was previously installed, we ensure the monotonicity of timestamps.
-
-            // 1. for all headers, the new header needs to have a larger timestamp than
-            //    the “previous header”
-            {
-                let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header.height())?;
-
-                if let Some(prev_cs) = maybe_prev_cs {
-                    // New header timestamp cannot occur *before* the
-                    // previous consensus state's height
-                    let prev_cs: TmConsensusState =
-                        prev_cs.try_into().map_err(|err| ClientError::Other {
-                            description: err.to_string(),
-                        })?;
-
-                    if header.signed_header.header().time <= prev_cs.timestamp() {
-                        return Ok(true);
-                    }
-                }
-            }
-
-            // 2. if a header comes in and is not the “last” header, then we also ensure
-            //    that its timestamp is less than the “next header”
-            if header.height() < client_state.latest_height {
-                let maybe_next_cs = ctx.next_consensus_state(client_id, &header.height())?;
-
-                if let Some(next_cs) = maybe_next_cs {
-                    // New (untrusted) header timestamp cannot occur *after* next
-                    // consensus state's height
-                    let next_cs = next_cs.try_into().map_err(|err| ClientError::Other {
-                        description: err.to_string(),
-                    })?;
-
-                    if header.signed_header.header().time >= next_cs.timestamp() {
-                        return Ok(true);
-                    }
-                }
-            }
-
-            Ok(false)
+            // If no header was previously installed, we ensure the
+            // monotonicity of timestamps with respect to the “previous
+            // header” and, if the header is not the “last” one, to the “next
+            // header”.
+            let prev_cs = ctx
+                .prev_consensus_state(client_id, &header_height)?
+                .map(|prev_cs| into_tm_consensus_state(prev_cs, client_id))
+                .transpose()?;
+
+            let next_cs = if header_height < client_state.latest_height {
+                ctx.next_consensus_state(client_id, &header_height)?
+                    .map(|next_cs| into_tm_consensus_state(next_cs, client_id))
+                    .transpose()?
+            } else {
+                None
+            };
+
+            Ok(is_non_monotonic_header(
+                &header,
+                prev_cs.as_ref(),
+                next_cs.as_ref(),
+            ))
         }
     }
 }
+
+fn into_tm_consensus_state<S>(
+    consensus_state: S,
+    client_id: &ClientId,
+) -> Result<TmConsensusState, ClientError>
+where
+    S: TryInto<TmConsensusState>,
+    S::Error: ToString,
+{
+    consensus_state
+        .try_into()
+        .map_err(|err| ClientError::ConsensusStateConversionFailed {
+            client_id: client_id.clone(),
+            description: err.to_string(),
+        })
+}
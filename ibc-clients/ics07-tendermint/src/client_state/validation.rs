@@ -1,3 +1,5 @@
+use alloc::collections::BTreeSet;
+
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
     TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
@@ -5,24 +7,26 @@ use ibc_client_tendermint_types::{
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_client::context::ClientValidationContext;
 use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::events::UpdateClientTelemetry;
 use ibc_core_client::types::Status;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
 
 use super::{
     check_for_misbehaviour_misbehavior, check_for_misbehaviour_update_client, ClientState,
+    UpgradeKeysFromPath,
 };
 use crate::client_state::{verify_header, verify_misbehaviour};
 use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::context::{DefaultVerifier, TmVerifier, ValidationContext as TmValidationContext};
 
-impl<V> ClientStateValidation<V> for ClientState
+impl<K, V> ClientStateValidation<V> for ClientState<K>
 where
-    V: ClientValidationContext + TmValidationContext,
-    V::AnyConsensusState: TryInto<TmConsensusState>,
-    ClientError: From<<V::AnyConsensusState as TryInto<TmConsensusState>>::Error>,
+    K: UpgradeKeysFromPath,
+    V: TmValidationContext,
 {
     /// The default verification logic exposed by ibc-rs simply delegates to a
     /// standalone `verify_client_message` function. This is to make it as simple
@@ -52,11 +56,50 @@ where
         check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
     }
 
+    fn check_for_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_redundant_update(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_telemetry(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Option<UpdateClientTelemetry>, ClientError> {
+        update_telemetry(self.inner(), ctx, client_id, header).map(Some)
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         status(self.inner(), ctx, client_id)
     }
 }
 
+impl<K> ClientState<K> {
+    /// Verifies the client message with the given verifier rather than with
+    /// the `DefaultVerifier` used by the `ClientStateValidation`
+    /// implementation.
+    ///
+    /// This is the method called by the `ClientState` derive macro for
+    /// enum variants annotated with `#[verifier(<your TmVerifier>)]`.
+    pub fn verify_client_message_with<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+        verifier: &impl TmVerifier,
+    ) -> Result<(), ClientError>
+    where
+        V: ClientValidationContext + TmValidationContext,
+    {
+        verify_client_message(self.inner(), ctx, client_id, client_message, verifier)
+    }
+}
+
 /// Verify the client message as part of the client state validation process.
 ///
 /// Note that this function is typically implemented as part of the
@@ -143,6 +186,82 @@ where
     }
 }
 
+/// Check whether the given header was already installed by a previous
+/// update, in which case applying it is a no-op.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_redundant_update<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<bool, ClientError>
+where
+    V: ClientValidationContext + TmValidationContext,
+{
+    let header = TmHeader::try_from(header)?;
+    let header_height = client_state.header_height(&header);
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    Ok(ctx.consensus_state(&path_at_header_height).is_ok())
+}
+
+/// Collect the telemetry attached to the `UpdateClient` event emitted for the
+/// given header: the number of validators that joined or left the set since
+/// the trusted height, the lag between the header time and the host time,
+/// whether the header is adjacent to its trusted height, as well as the
+/// trusted height itself and the hash of the header.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_telemetry<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    _client_id: &ClientId,
+    header: Any,
+) -> Result<UpdateClientTelemetry, ClientError>
+where
+    V: ClientValidationContext + TmValidationContext,
+{
+    let header = TmHeader::try_from(header)?;
+
+    let trusted_addresses: BTreeSet<_> = header
+        .trusted_next_validator_set
+        .validators()
+        .iter()
+        .map(|validator| validator.address)
+        .collect();
+    let new_addresses: BTreeSet<_> = header
+        .validator_set
+        .validators()
+        .iter()
+        .map(|validator| validator.address)
+        .collect();
+    let validator_set_rotation_size = trusted_addresses
+        .symmetric_difference(&new_addresses)
+        .count() as u64;
+
+    let header_time_lag = ctx
+        .host_timestamp()?
+        .saturating_duration_since(&header.timestamp());
+
+    let adjacent = header.trusted_height.increment() == client_state.header_height(&header);
+
+    Ok(
+        UpdateClientTelemetry::new(validator_set_rotation_size, header_time_lag, adjacent)
+            .with_trusted_height(header.trusted_height)
+            .with_header_hash(header.signed_header.header().hash().as_bytes().to_vec()),
+    )
+}
+
 /// Query the status of the client state.
 ///
 /// Note that this function is typically implemented as part of the
@@ -160,37 +279,65 @@ where
         return Ok(Status::Frozen);
     }
 
-    let latest_consensus_state: TmConsensusState = {
-        let any_latest_consensus_state = match ctx.consensus_state(&ClientConsensusStatePath::new(
-            client_id.clone(),
-            client_state.latest_height.revision_number(),
-            client_state.latest_height.revision_height(),
-        )) {
-            Ok(cs) => cs,
-            // if the client state does not have an associated consensus state for its latest height
-            // then it must be expired
-            Err(_) => return Ok(Status::Expired),
-        };
-
-        any_latest_consensus_state
-            .try_into()
-            .map_err(|err| ClientError::Other {
-                description: err.to_string(),
-            })?
+    // if the client state does not have an associated consensus state for its
+    // latest height then it must be expired
+    let latest_timestamp = match latest_consensus_timestamp(client_state, ctx, client_id)? {
+        Some(latest_timestamp) => latest_timestamp,
+        None => return Ok(Status::Expired),
     };
 
-    // Note: if the `duration_since()` is `None`, indicating that the latest
-    // consensus state is in the future, then we don't consider the client
-    // to be expired.
-    let now = ctx.host_timestamp()?;
-
-    if let Some(elapsed_since_latest_consensus_state) =
-        now.duration_since(&latest_consensus_state.timestamp().into())
-    {
-        if elapsed_since_latest_consensus_state > client_state.trusting_period {
-            return Ok(Status::Expired);
-        }
+    if client_state.is_expired(latest_timestamp, ctx.host_timestamp()?) {
+        return Ok(Status::Expired);
     }
 
     Ok(Status::Active)
 }
+
+/// Returns the time at which the client expires, i.e. once its trusting
+/// period has elapsed since the timestamp of its latest consensus state, or
+/// `None` if the client has no consensus state at its latest height, in which
+/// case it is already expired.
+///
+/// This lets monitoring systems alert before clients expire. Note that frozen
+/// clients are not active regardless of their expiry, see [`status`].
+pub fn expires_at<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Option<Timestamp>, ClientError>
+where
+    V: TmValidationContext,
+{
+    Ok(latest_consensus_timestamp(client_state, ctx, client_id)?
+        .map(|latest_timestamp| client_state.expires_at(latest_timestamp)))
+}
+
+/// Returns the timestamp of the consensus state of the client at its latest
+/// height, if any.
+fn latest_consensus_timestamp<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Option<Timestamp>, ClientError>
+where
+    V: TmValidationContext,
+{
+    let any_latest_consensus_state = match ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        client_state.latest_height.revision_number(),
+        client_state.latest_height.revision_height(),
+    )) {
+        Ok(cs) => cs,
+        Err(_) => return Ok(None),
+    };
+
+    let latest_consensus_state: TmConsensusState =
+        any_latest_consensus_state.try_into().map_err(|err| {
+            ClientError::ConsensusStateConversionFailed {
+                client_id: client_id.clone(),
+                description: err.to_string(),
+            }
+        })?;
+
+    Ok(Some(latest_consensus_state.timestamp().into()))
+}
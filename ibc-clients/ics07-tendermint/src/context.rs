@@ -1,16 +1,72 @@
-use ibc_core_client::context::ClientExecutionContext;
+//! Defines the context traits that hosts implement in order to use the
+//! Tendermint light client.
+//!
+//! Hosts only need to implement [`ValidationContext`], on top of the generic
+//! client contexts. [`ExecutionContext`] is then implemented automatically,
+//! as long as the host's client and consensus state types can be converted
+//! from the Tendermint ones. A minimal host looks like the following:
+//!
+//! ```ignore
+//! impl ClientValidationContext for MyHost {
+//!     fn update_meta(&self, client_id: &ClientId, height: &Height)
+//!         -> Result<(Timestamp, Height), ContextError> { .. }
+//! }
+//!
+//! impl ClientExecutionContext for MyHost {
+//!     type V = Self;
+//!     // Must implement `TryFrom<ibc_client_tendermint_types::ClientState>`.
+//!     type AnyClientState = MyClientState;
+//!     // Must implement `TryFrom<ibc_client_tendermint_types::ConsensusState>`.
+//!     type AnyConsensusState = MyConsensusState;
+//!
+//!     // store and delete methods ..
+//! }
+//!
+//! impl ibc::clients::tendermint::context::ValidationContext for MyHost {
+//!     type ConversionError = &'static str;
+//!     // Must implement `TryInto<ibc::clients::tendermint::consensus_state::ConsensusState>`.
+//!     type AnyConsensusState = MyConsensusState;
+//!
+//!     // host and consensus state query methods ..
+//! }
+//!
+//! // `MyHost` now implements `ibc::clients::tendermint::context::ExecutionContext`.
+//! ```
+
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
-use tendermint_light_client_verifier::ProdVerifier;
+use tendermint::crypto::default::Sha256 as DefaultSha256;
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, ProvidedVotingPowerCalculator,
+};
+use tendermint_light_client_verifier::predicates::ProdPredicates;
+use tendermint_light_client_verifier::{PredicateVerifier, ProdVerifier};
 
 use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::host_functions::{HostPredicates, HostSha256, HostSignatureVerifier, TmHostFunctions};
 
-/// Client's context required during both validation and execution
-pub trait CommonContext {
+/// Client's context required during validation.
+///
+/// This is the only context trait hosts need to implement in order to
+/// validate Tendermint client messages, on top of the generic
+/// [`ClientValidationContext`].
+pub trait ValidationContext: ClientValidationContext {
     type ConversionError: ToString;
     type AnyConsensusState: TryInto<TmConsensusState, Error = Self::ConversionError>;
 
@@ -31,10 +87,7 @@ pub trait CommonContext {
 
     /// Returns all the heights at which a consensus state is stored
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
-}
 
-/// Client's context required during validation
-pub trait ValidationContext: CommonContext {
     /// Search for the lowest consensus state higher than `height`.
     fn next_consensus_state(
         &self,
@@ -48,15 +101,101 @@ pub trait ValidationContext: CommonContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::AnyConsensusState>, ContextError>;
+
+    /// Returns the maximum clock drift tolerated when verifying the headers
+    /// of the updates of the given client, whose client state holds the
+    /// clock drift agreed upon by both chains.
+    ///
+    /// Hosts may override it in order to tighten the tolerance for the
+    /// clients of counterparty chains known to have unreliable block
+    /// timestamps, without altering the client state. Values larger than the
+    /// one of the client state are ignored.
+    fn max_clock_drift(
+        &self,
+        _client_id: &ClientId,
+        client_state: &ClientStateType,
+    ) -> Result<Duration, ContextError> {
+        Ok(client_state.max_clock_drift)
+    }
+
+    /// Returns the client of the provider chain whose validators secure the
+    /// chain tracked by the given client, if the latter is an Interchain
+    /// Security consumer chain.
+    ///
+    /// The validator sets of the headers of the updates of such clients are
+    /// then cross-checked against the validator sets of the provider chain
+    /// known to the latest consensus states of the provider client, on top of
+    /// the light client verification, as done by
+    /// [`verify_consumer_validator_set`].
+    ///
+    /// The check is opt-in and only meant for consumer chains without key
+    /// assignment: it compares the validator set hashes byte for byte, so it
+    /// rejects all the headers of the consumer chains whose validators sign
+    /// with assigned consumer keys. Hosts must return `None` for the clients
+    /// of such chains, which is the default.
+    ///
+    /// [`verify_consumer_validator_set`]: crate::client_state::verify_consumer_validator_set
+    fn provider_client_id(&self, _client_id: &ClientId) -> Result<Option<ClientId>, ContextError> {
+        Ok(None)
+    }
 }
 
 /// Client's context required during execution.
 ///
 /// This trait is automatically implemented for all types that implement
-/// [`CommonContext`] and [`ClientExecutionContext`]
-pub trait ExecutionContext: CommonContext + ClientExecutionContext {}
+/// [`ValidationContext`] and [`ClientExecutionContext`], provided that the
+/// host's client and consensus state types can be converted from the
+/// Tendermint ones. The associated types merely name the host's types: since
+/// they are tied to the ones of [`ClientExecutionContext`], bounding a generic
+/// parameter with `ExecutionContext` is enough for the conversions to be
+/// available, without any additional `TryFrom` bound.
+///
+/// Conversions are fallible so that hosts whose state enums may lack a
+/// Tendermint variant can report it. Hosts with infallible `From` conversions
+/// get `Infallible` as error type through the standard blanket
+/// implementations.
+pub trait ExecutionContext:
+    ValidationContext
+    + ClientExecutionContext<
+        AnyClientState = <Self as ExecutionContext>::HostClientState,
+        AnyConsensusState = <Self as ExecutionContext>::HostConsensusState,
+    >
+{
+    /// The client state type of the host.
+    type HostClientState: TryFrom<ClientStateType, Error = Self::ClientStateConversionError>;
+
+    /// The error returned when converting a Tendermint client state into the
+    /// host's client state type.
+    type ClientStateConversionError: Into<ClientError>;
 
-impl<T> ExecutionContext for T where T: CommonContext + ClientExecutionContext {}
+    /// The consensus state type of the host.
+    type HostConsensusState: TryFrom<
+        ConsensusStateType,
+        Error = Self::ConsensusStateConversionError,
+    >;
+
+    /// The error returned when converting a Tendermint consensus state into
+    /// the host's consensus state type.
+    type ConsensusStateConversionError: Into<ClientError>;
+}
+
+impl<T> ExecutionContext for T
+where
+    T: ValidationContext + ClientExecutionContext,
+    <T as ClientExecutionContext>::AnyClientState: TryFrom<ClientStateType>,
+    <<T as ClientExecutionContext>::AnyClientState as TryFrom<ClientStateType>>::Error:
+        Into<ClientError>,
+    <T as ClientExecutionContext>::AnyConsensusState: TryFrom<ConsensusStateType>,
+    <<T as ClientExecutionContext>::AnyConsensusState as TryFrom<ConsensusStateType>>::Error:
+        Into<ClientError>,
+{
+    type HostClientState = <T as ClientExecutionContext>::AnyClientState;
+    type ClientStateConversionError =
+        <<T as ClientExecutionContext>::AnyClientState as TryFrom<ClientStateType>>::Error;
+    type HostConsensusState = <T as ClientExecutionContext>::AnyConsensusState;
+    type ConsensusStateConversionError =
+        <<T as ClientExecutionContext>::AnyConsensusState as TryFrom<ConsensusStateType>>::Error;
+}
 
 /// Specifies the Verifier interface that hosts must adhere to when customizing
 /// Tendermint client verification behaviour.
@@ -76,9 +215,22 @@ impl<T> ExecutionContext for T where T: CommonContext + ClientExecutionContext {
 /// importing and calling their analogous standalone version from the
 /// `tendermint::client_state` module, unless bespoke logic is desired for any
 /// of those functions.
+///
+/// Hosts relying on the `ClientState` derive macro can instead annotate their
+/// Tendermint variant with `#[verifier(<your TmVerifier>)]`, and the derived
+/// implementation will pass it to `verify_client_message`.
+///
+/// Hosts tracking chains whose validators sign with other schemes than
+/// ed25519 only customize the verification of the signatures, with the
+/// [`SignatureSchemeVerifier`], while hosts providing their own hashing and
+/// signature verification, e.g. zkVMs, use the [`HostFunctionsVerifier`].
 pub trait TmVerifier {
     type Verifier: tendermint_light_client_verifier::Verifier;
 
+    /// The SHA256 implementation with which the validator sets of the headers
+    /// are hashed, before their verification by the `Verifier`.
+    type Sha256: MerkleHash + Sha256 + Default;
+
     fn verifier(&self) -> Self::Verifier;
 }
 
@@ -88,8 +240,88 @@ pub struct DefaultVerifier;
 
 impl TmVerifier for DefaultVerifier {
     type Verifier = ProdVerifier;
+    type Sha256 = DefaultSha256;
 
     fn verifier(&self) -> Self::Verifier {
         ProdVerifier::default()
     }
 }
+
+/// A verifier verifying the signatures of the votes with the signature
+/// verifier `S`, for counterparty chains whose validators sign with other
+/// schemes than ed25519, e.g. the secp256k1 or sr25519 keys of some CometBFT
+/// forks. Apart from the signatures, the headers are verified as by the
+/// [`DefaultVerifier`].
+///
+/// The validator sets mixing several schemes are supported by combining the
+/// signature verifiers of each scheme with [`FallbackSignatureVerifier`], e.g.
+/// ```ignore
+/// type MixedVerifier = SignatureSchemeVerifier<
+///     FallbackSignatureVerifier<Sr25519Verifier, tendermint::crypto::default::signature::Verifier>,
+/// >;
+/// ```
+pub struct SignatureSchemeVerifier<S>(PhantomData<S>);
+
+impl<S> Default for SignatureSchemeVerifier<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> TmVerifier for SignatureSchemeVerifier<S>
+where
+    S: SignatureVerifier,
+{
+    type Verifier =
+        PredicateVerifier<ProdPredicates, ProvidedVotingPowerCalculator<S>, ProdCommitValidator>;
+    type Sha256 = DefaultSha256;
+
+    fn verifier(&self) -> Self::Verifier {
+        PredicateVerifier::default()
+    }
+}
+
+/// A verifier hashing and verifying the signatures of the votes with the host
+/// functions `H`, for hosts whose cryptographic primitives are provided by
+/// the environment, e.g. as precompiles of a zkVM.
+pub struct HostFunctionsVerifier<H>(PhantomData<fn() -> H>);
+
+impl<H> Default for HostFunctionsVerifier<H> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H> TmVerifier for HostFunctionsVerifier<H>
+where
+    H: TmHostFunctions,
+{
+    type Verifier = PredicateVerifier<
+        HostPredicates<H>,
+        ProvidedVotingPowerCalculator<HostSignatureVerifier<H>>,
+        ProdCommitValidator,
+    >;
+    type Sha256 = HostSha256<H>;
+
+    fn verifier(&self) -> Self::Verifier {
+        PredicateVerifier::default()
+    }
+}
+
+/// A signature verifier dispatching on the type of the keys: the signatures
+/// are verified by `A`, or by `B` when `A` does not support the type of the
+/// key of the signer.
+pub struct FallbackSignatureVerifier<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> SignatureVerifier for FallbackSignatureVerifier<A, B>
+where
+    A: SignatureVerifier,
+    B: SignatureVerifier,
+{
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        match A::verify(pubkey, msg, signature) {
+            Err(SignatureError::UnsupportedKeyType) => B::verify(pubkey, msg, signature),
+            result => result,
+        }
+    }
+}
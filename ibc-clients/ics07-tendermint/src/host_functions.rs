@@ -0,0 +1,105 @@
+//! Defines the host functions through which the Tendermint light client hashes
+//! and verifies signatures, so that hosts such as zkVMs or `no_std` runtimes
+//! can provide accelerated or precompiled implementations.
+//!
+//! Hosts implement [`TmHostFunctions`] on top of the
+//! [`HostFunctions`] of the commitment proofs, and verify the client messages
+//! with the [`HostFunctionsVerifier`](crate::context::HostFunctionsVerifier).
+//! The pure-Rust [`DefaultHostFunctions`] are used otherwise.
+
+use core::marker::PhantomData;
+
+pub use ibc_core_commitment_types::host_functions::{DefaultHostFunctions, HostFunctions};
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::crypto::Sha256;
+use tendermint::merkle::{Hash, MerkleHash, NonIncremental, HASH_SIZE};
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::predicates::VerificationPredicates;
+
+/// The host functions required by the Tendermint light client, on top of the
+/// hashing functions of the commitment proofs.
+pub trait TmHostFunctions: HostFunctions {
+    /// Verifies the ed25519 `signature` of `message` by `public_key`.
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+impl TmHostFunctions for DefaultHostFunctions {
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        match (
+            PublicKey::from_raw_ed25519(public_key),
+            Signature::try_from(signature),
+        ) {
+            (Some(public_key), Ok(signature)) => {
+                DefaultSignatureVerifier::verify(public_key, message, &signature).is_ok()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The SHA256 implementation of the host functions `H`, hashing the
+/// validator sets and the headers.
+pub struct HostSha256<H>(PhantomData<fn() -> H>);
+
+impl<H> Default for HostSha256<H> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H: HostFunctions> Sha256 for HostSha256<H> {
+    fn digest(data: impl AsRef<[u8]>) -> [u8; HASH_SIZE] {
+        H::sha2_256(data.as_ref())
+    }
+}
+
+// The host functions only provide one-piece digests.
+impl<H: HostFunctions> MerkleHash for HostSha256<H> {
+    fn empty_hash(&mut self) -> Hash {
+        NonIncremental::<Self>::default().empty_hash()
+    }
+
+    fn leaf_hash(&mut self, bytes: &[u8]) -> Hash {
+        NonIncremental::<Self>::default().leaf_hash(bytes)
+    }
+
+    fn inner_hash(&mut self, left: Hash, right: Hash) -> Hash {
+        NonIncremental::<Self>::default().inner_hash(left, right)
+    }
+}
+
+/// The signature verifier of the host functions `H`, verifying the ed25519
+/// signatures of the votes. The signatures of the other schemes are
+/// unsupported.
+pub struct HostSignatureVerifier<H>(PhantomData<fn() -> H>);
+
+impl<H: TmHostFunctions> SignatureVerifier for HostSignatureVerifier<H> {
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        #[allow(unreachable_patterns)]
+        match pubkey {
+            PublicKey::Ed25519(public_key) => {
+                if H::ed25519_verify(public_key.as_bytes(), msg, signature.as_bytes()) {
+                    Ok(())
+                } else {
+                    Err(SignatureError::VerificationFailed)
+                }
+            }
+            _ => Err(SignatureError::UnsupportedKeyType),
+        }
+    }
+}
+
+/// The verification predicates of the Tendermint light client, hashing with
+/// the host functions `H`.
+pub struct HostPredicates<H>(PhantomData<fn() -> H>);
+
+impl<H> Default for HostPredicates<H> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H: HostFunctions> VerificationPredicates for HostPredicates<H> {
+    type Sha256 = HostSha256<H>;
+}
@@ -13,12 +13,15 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
 pub mod client_state;
 pub mod consensus_state;
 pub mod context;
+pub mod host_functions;
+pub mod light_client;
 
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
@@ -0,0 +1,91 @@
+//! Provides the light client verification of Tendermint headers without any
+//! host context, so that relayers and light client daemons can verify headers
+//! off-chain with exactly the same logic as the on-chain client.
+//!
+//! The on-chain [`verify_header`](crate::client_state::verify_header) reads
+//! the trusted consensus state and the current time from the host, and then
+//! delegates to [`verify_header`].
+
+use ibc_client_tendermint_types::error::IntoResult;
+use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::identifiers::{ChainId, RevisionFormat};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint_light_client_verifier::options::Options;
+use tendermint_light_client_verifier::Verifier;
+
+use crate::context::TmVerifier;
+
+/// The state trusted by a light client of a Tendermint chain, against which a
+/// new header is verified.
+#[derive(Clone, Copy, Debug)]
+pub struct TrustedState<'a> {
+    /// The identifier of the chain tracked by the light client.
+    pub chain_id: &'a ChainId,
+    /// The format in which the chain identifier carries the revision number
+    /// of the chain, as stored by the light client.
+    pub revision_format: RevisionFormat,
+    /// The consensus state at the trusted height of the header.
+    pub consensus_state: &'a ConsensusStateType,
+}
+
+/// Verifies `untrusted_header` against `trusted_state` at time `now`, with the
+/// light client `options`, e.g. as returned by
+/// [`ClientState::as_light_client_options`](ibc_client_tendermint_types::ClientState::as_light_client_options).
+///
+/// The trusted consensus state must be the one at the trusted height of the
+/// header.
+pub fn verify_header<T>(
+    trusted_state: TrustedState<'_>,
+    untrusted_header: &TmHeader,
+    options: &Options,
+    verifier: &T,
+    now: Timestamp,
+) -> Result<(), ClientError>
+where
+    T: TmVerifier,
+{
+    // Checks that the header fields are valid.
+    untrusted_header.validate_basic_with_format::<T::Sha256>(trusted_state.revision_format)?;
+
+    // The tendermint-light-client crate though works on heights that are assumed
+    // to have the same revision number. We ensure this here.
+    untrusted_header.verify_chain_id_version_matches_height(trusted_state.chain_id)?;
+
+    untrusted_header
+        .check_trusted_next_validator_set_with::<T::Sha256>(trusted_state.consensus_state)?;
+
+    let chain_id =
+        trusted_state
+            .chain_id
+            .to_string()
+            .try_into()
+            .map_err(
+                |e: tendermint::Error| ClientError::InvalidLightClientChainId {
+                    chain_id: trusted_state.chain_id.clone(),
+                    description: e.to_string(),
+                },
+            )?;
+
+    let trusted_block_state =
+        untrusted_header.as_trusted_block_state(trusted_state.consensus_state, &chain_id)?;
+
+    // NB: This skips the VerificationPredicates::next_validators_match check
+    // for the untrusted state.
+    let untrusted_block_state = untrusted_header.as_untrusted_block_state();
+
+    let now = now
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verifier()
+        .verify_update_header(untrusted_block_state, trusted_block_state, options, now)
+        .into_result()?;
+
+    Ok(())
+}
@@ -8,9 +8,9 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::proto::v1::Height as RawHeight;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::specs::ProofSpecs;
-use ibc_core_host_types::identifiers::ChainId;
+use ibc_core_host_types::identifiers::{ChainId, RevisionFormat};
 use ibc_primitives::prelude::*;
-use ibc_primitives::ZERO_DURATION;
+use ibc_primitives::{Timestamp, ZERO_DURATION};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
@@ -25,17 +25,57 @@ use crate::trust_threshold::TrustThreshold;
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct AllowUpdate {
     pub after_expiry: bool,
     pub after_misbehaviour: bool,
 }
 
+/// The scheme by which the chain tracked by a Tendermint client numbers the
+/// revisions of its heights.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RevisionScheme {
+    /// The chain identifier carries the revision number as an epoch, e.g.
+    /// `cosmoshub-4`, which the chain increments when upgrading with a reset
+    /// of its heights.
+    Epoch,
+    /// The chain identifier carries no epoch, e.g. `mainnet`, even if it ends
+    /// with a number, and the heights of the chain are all in revision 0.
+    NoEpoch,
+}
+
+impl RevisionScheme {
+    /// Parses the given chain identifier according to the scheme.
+    ///
+    /// ```
+    /// use ibc_client_tendermint_types::RevisionScheme;
+    ///
+    /// let chain_id = RevisionScheme::Epoch.parse_chain_id("chain-2024").unwrap();
+    /// assert_eq!(chain_id.revision_number(), 2024);
+    ///
+    /// let chain_id = RevisionScheme::NoEpoch.parse_chain_id("chain-2024").unwrap();
+    /// assert_eq!(chain_id.revision_number(), 0);
+    /// ```
+    pub fn parse_chain_id(&self, chain_id: &str) -> Result<ChainId, Error> {
+        let chain_id = match self {
+            Self::Epoch => ChainId::from_str(chain_id)?,
+            Self::NoEpoch => ChainId::new_with_format(chain_id, RevisionFormat::Revisionless)?,
+        };
+
+        Ok(chain_id)
+    }
+}
+
 /// Defines data structure for Tendermint client state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClientState {
     pub chain_id: ChainId,
+    /// The format in which the chain identifier carries the revision number
+    /// of the chain.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub revision_format: RevisionFormat,
     pub trust_level: TrustThreshold,
     pub trusting_period: Duration,
     pub unbonding_period: Duration,
@@ -51,6 +91,7 @@ impl ClientState {
     #[allow(clippy::too_many_arguments)]
     fn new_without_validation(
         chain_id: ChainId,
+        revision_format: RevisionFormat,
         trust_level: TrustThreshold,
         trusting_period: Duration,
         unbonding_period: Duration,
@@ -63,6 +104,7 @@ impl ClientState {
     ) -> Self {
         Self {
             chain_id,
+            revision_format,
             trust_level,
             trusting_period,
             unbonding_period,
@@ -77,6 +119,10 @@ impl ClientState {
 
     /// Constructs a new Tendermint `ClientState` by given parameters and checks
     /// if the parameters are valid.
+    ///
+    /// The revision format of the client is the one in which the given chain
+    /// identifier carries its revision number, see
+    /// [`with_revision_format`](Self::with_revision_format) to set another.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: ChainId,
@@ -89,8 +135,10 @@ impl ClientState {
         upgrade_path: Vec<String>,
         allow_update: AllowUpdate,
     ) -> Result<Self, Error> {
+        let revision_format = chain_id.revision_format();
         let client_state = Self::new_without_validation(
             chain_id,
+            revision_format,
             trust_level,
             trusting_period,
             unbonding_period,
@@ -105,9 +153,34 @@ impl ClientState {
         Ok(client_state)
     }
 
+    /// Returns the revision scheme of the chain tracked by the client, as
+    /// stored in its revision format.
+    pub fn revision_scheme(&self) -> RevisionScheme {
+        match self.revision_format {
+            RevisionFormat::Delimited(_) => RevisionScheme::Epoch,
+            RevisionFormat::Revisionless => RevisionScheme::NoEpoch,
+        }
+    }
+
+    /// Sets the format in which the chain identifier of the client carries
+    /// the revision number of the chain, parsing the identifier accordingly.
+    pub fn with_revision_format(self, revision_format: RevisionFormat) -> Result<Self, Error> {
+        Ok(Self {
+            chain_id: ChainId::new_with_format(self.chain_id.as_str(), revision_format)?,
+            revision_format,
+            ..self
+        })
+    }
+
+    /// Returns the height of the given header, whose revision number is
+    /// carried by its chain identifier in the revision format of the client.
+    pub fn header_height(&self, header: &TmHeader) -> Height {
+        header.height_with_format(self.revision_format)
+    }
+
     pub fn with_header(self, header: TmHeader) -> Result<Self, Error> {
         Ok(Self {
-            latest_height: max(header.height(), self.latest_height),
+            latest_height: max(self.header_height(&header), self.latest_height),
             ..self
         })
     }
@@ -122,6 +195,15 @@ impl ClientState {
     pub fn validate(&self) -> Result<(), Error> {
         self.chain_id.validate_length(3, MaxChainIdLen as u64)?;
 
+        if ChainId::new_with_format(self.chain_id.as_str(), self.revision_format)?.revision_number()
+            != self.chain_id.revision_number()
+        {
+            return Err(Error::MismatchRevisionFormat {
+                chain_id: self.chain_id.to_string(),
+                revision_format: self.revision_format,
+            });
+        }
+
         // `TrustThreshold` is guaranteed to be in the range `[0, 1)`, but a `TrustThreshold::ZERO`
         // value is invalid in this context
         if self.trust_level == TrustThreshold::ZERO {
@@ -170,9 +252,17 @@ impl ClientState {
         }
 
         if self.latest_height.revision_number() != self.chain_id.revision_number() {
+            let reason = match self.revision_scheme() {
+                RevisionScheme::Epoch => {
+                    "ClientState latest-height revision number must match chain-id version"
+                }
+                RevisionScheme::NoEpoch => {
+                    "ClientState latest-height revision number must be zero for a chain-id without version"
+                }
+            };
+
             return Err(Error::InvalidLatestHeight {
-                reason: "ClientState latest-height revision number must match chain-id version"
-                    .to_string(),
+                reason: reason.to_string(),
             });
         }
 
@@ -198,6 +288,23 @@ impl ClientState {
         Some(2 * self.trusting_period / 3)
     }
 
+    /// Returns the time at which the client expires, given the timestamp of
+    /// its latest consensus state, i.e. once its trusting period has elapsed
+    /// since then.
+    pub fn expires_at(&self, latest_consensus_timestamp: Timestamp) -> Timestamp {
+        latest_consensus_timestamp.saturating_add(self.trusting_period)
+    }
+
+    /// Returns whether the client is expired at `now`, given the timestamp of
+    /// its latest consensus state, i.e. whether more than its trusting period
+    /// has elapsed since then.
+    ///
+    /// Note that if the latest consensus state is in the future, no time is
+    /// considered to have elapsed, and the client is not expired.
+    pub fn is_expired(&self, latest_consensus_timestamp: Timestamp, now: Timestamp) -> bool {
+        now.saturating_duration_since(&latest_consensus_timestamp) > self.trusting_period
+    }
+
     /// Helper method to produce a [`Options`] struct for use in
     /// Tendermint-specific light client verification.
     pub fn as_light_client_options(&self) -> Result<Options, Error> {
@@ -231,14 +338,56 @@ impl ClientState {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Draw the parameters within the bounds checked by `validate`, so that
+        // most inputs yield a client state that the handlers then accept.
+        let chain_id = ChainId::arbitrary(u)?;
+        let latest_height = Height::new(chain_id.revision_number(), u.int_in_range(1..=u64::MAX)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        let denominator = u.int_in_range(2..=u64::from(u32::MAX))?;
+        let numerator = u.int_in_range((denominator + 2) / 3..=denominator - 1)?;
+        let trust_level = TrustThreshold::new(numerator, denominator)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        let unbonding_period = Duration::from_nanos(u.int_in_range(2..=u64::MAX)?);
+        let trusting_period =
+            Duration::from_nanos(u.int_in_range(1..=unbonding_period.as_nanos() as u64 - 1)?);
+        let max_clock_drift = Duration::from_nanos(u.int_in_range(1..=u64::MAX)?);
+
+        let upgrade_path = Vec::<String>::arbitrary(u)?
+            .into_iter()
+            .filter(|key| !key.trim().is_empty())
+            .collect();
+
+        let client_state = ClientState::new(
+            chain_id,
+            trust_level,
+            trusting_period,
+            unbonding_period,
+            max_clock_drift,
+            latest_height,
+            ProofSpecs::arbitrary(u)?,
+            upgrade_path,
+            AllowUpdate::arbitrary(u)?,
+        )
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        Ok(match Option::<Height>::arbitrary(u)? {
+            Some(frozen_height) => client_state.with_frozen_height(frozen_height),
+            None => client_state,
+        })
+    }
+}
+
 impl Protobuf<RawTmClientState> for ClientState {}
 
 impl TryFrom<RawTmClientState> for ClientState {
     type Error = Error;
 
     fn try_from(raw: RawTmClientState) -> Result<Self, Self::Error> {
-        let chain_id = ChainId::from_str(raw.chain_id.as_str())?;
-
         let trust_level = {
             let trust_level = raw
                 .trust_level
@@ -269,12 +418,17 @@ impl TryFrom<RawTmClientState> for ClientState {
             .try_into()
             .map_err(|_| Error::NegativeMaxClockDrift)?;
 
-        let latest_height = raw
+        let latest_height: Height = raw
             .latest_height
             .ok_or(Error::MissingLatestHeight)?
             .try_into()
             .map_err(|_| Error::MissingLatestHeight)?;
 
+        // The raw message of ibc-go has no revision format, so the chain
+        // identifier is parsed in the default one, which the `Any` encoding
+        // of the client state overrides if need be.
+        let chain_id = ChainId::from_str(&raw.chain_id)?;
+
         // NOTE: In `RawClientState`, a `frozen_height` of `0` means "not
         // frozen". See:
         // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
@@ -291,6 +445,7 @@ impl TryFrom<RawTmClientState> for ClientState {
 
         let client_state = Self::new_without_validation(
             chain_id,
+            RevisionFormat::default(),
             trust_level,
             trusting_period,
             unbonding_period,
@@ -335,6 +490,57 @@ impl From<ClientState> for RawTmClientState {
     }
 }
 
+/// The revision format of a client state, encoded along with its fields in the
+/// raw `ClientState` message of ibc-go, which has no such field.
+///
+/// It is only encoded for the formats other than the default one, in the field
+/// 100 of the message, which ibc-go skips when decoding. Client states in the
+/// default format are thus encoded exactly as in ibc-go.
+#[derive(Clone, PartialEq, prost::Message)]
+struct RawClientStateRevisionFormat {
+    #[prost(message, optional, tag = "100")]
+    revision_format: Option<RawRevisionFormat>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct RawRevisionFormat {
+    /// The delimiter preceding the revision number in the chain identifier,
+    /// if the chain identifier carries a revision number.
+    #[prost(string, optional, tag = "1")]
+    delimiter: Option<String>,
+}
+
+impl TryFrom<RawRevisionFormat> for RevisionFormat {
+    type Error = Error;
+
+    fn try_from(raw: RawRevisionFormat) -> Result<Self, Self::Error> {
+        let delimiter = match raw.delimiter {
+            Some(delimiter) => delimiter,
+            None => return Ok(RevisionFormat::Revisionless),
+        };
+
+        let mut chars = delimiter.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(delimiter), None) => Ok(RevisionFormat::Delimited(delimiter)),
+            _ => Err(Error::InvalidRawClientState {
+                reason: format!("invalid revision delimiter `{delimiter}`"),
+            }),
+        }
+    }
+}
+
+impl From<RevisionFormat> for RawRevisionFormat {
+    fn from(revision_format: RevisionFormat) -> Self {
+        let delimiter = match revision_format {
+            RevisionFormat::Delimited(delimiter) => Some(delimiter.to_string()),
+            RevisionFormat::Revisionless => None,
+        };
+
+        Self { delimiter }
+    }
+}
+
 impl Protobuf<Any> for ClientState {}
 
 impl TryFrom<Any> for ClientState {
@@ -342,11 +548,24 @@ impl TryFrom<Any> for ClientState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
-            let client_state =
-                Protobuf::<RawTmClientState>::decode(value).map_err(|e| ClientError::Other {
-                    description: e.to_string(),
-                })?;
-            Ok(client_state)
+            let decoding_failed = |description: String| ClientError::ProtoDecodingFailed {
+                type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+                description,
+            };
+
+            let client_state: ClientState = Protobuf::<RawTmClientState>::decode(value)
+                .map_err(|e| decoding_failed(e.to_string()))?;
+
+            let revision_format = <RawClientStateRevisionFormat as prost::Message>::decode(value)
+                .map_err(|e| decoding_failed(e.to_string()))?
+                .revision_format;
+
+            match revision_format {
+                Some(revision_format) => {
+                    Ok(client_state.with_revision_format(revision_format.try_into()?)?)
+                }
+                None => Ok(client_state),
+            }
         }
 
         match raw.type_url.as_str() {
@@ -360,9 +579,21 @@ impl TryFrom<Any> for ClientState {
 
 impl From<ClientState> for Any {
     fn from(client_state: ClientState) -> Self {
+        let revision_format = client_state.revision_format;
+        let mut value = Protobuf::<RawTmClientState>::encode_vec(client_state);
+
+        if revision_format != RevisionFormat::default() {
+            let raw_revision_format = RawClientStateRevisionFormat {
+                revision_format: Some(revision_format.into()),
+            };
+
+            // Concatenated protobuf encodings decode as a single message.
+            value.extend(prost::Message::encode_to_vec(&raw_revision_format));
+        }
+
         Any {
             type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
-            value: Protobuf::<RawTmClientState>::encode_vec(client_state),
+            value,
         }
     }
 }
@@ -609,4 +840,146 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_client_state_round_trips() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=u8::MAX).rev().cycle().take(8192).collect();
+        let mut u = Unstructured::new(&data);
+
+        let mut generated = 0;
+        while !u.is_empty() {
+            if let Ok(client_state) = ClientState::arbitrary(&mut u) {
+                let raw = RawTmClientState::from(client_state.clone());
+                assert_eq!(ClientState::try_from(raw).unwrap(), client_state);
+                generated += 1;
+            }
+        }
+        assert!(generated > 0);
+    }
+
+    #[test]
+    fn client_state_revision_scheme() {
+        let tests = [
+            ("chain-4", RevisionScheme::Epoch),
+            ("chain-0", RevisionScheme::Epoch),
+            ("mainnet", RevisionScheme::NoEpoch),
+            ("chain-2024", RevisionScheme::NoEpoch),
+        ];
+
+        for (chain_id, scheme) in tests {
+            let chain_id = scheme.parse_chain_id(chain_id).unwrap();
+
+            let client_state = match scheme {
+                RevisionScheme::Epoch => client_state_with_chain_id(chain_id),
+                RevisionScheme::NoEpoch => client_state_with_chain_id(chain_id)
+                    .with_revision_format(RevisionFormat::Revisionless)
+                    .unwrap(),
+            };
+
+            assert_eq!(client_state.revision_scheme(), scheme);
+
+            // The scheme survives the encoding, which carries the revision
+            // format of the client.
+            let decoded = ClientState::try_from(Any::from(client_state.clone())).unwrap();
+            assert_eq!(decoded.revision_scheme(), scheme);
+            assert_eq!(decoded, client_state);
+
+            // The raw message of ibc-go carries no scheme, which is then not
+            // inferred from the chain identifier.
+            let decoded =
+                ClientState::try_from(RawTmClientState::from(client_state.clone())).unwrap();
+            assert_eq!(decoded.revision_scheme(), RevisionScheme::Epoch);
+        }
+    }
+
+    fn client_state_with_chain_id(chain_id: ChainId) -> ClientState {
+        let revision_number = chain_id.revision_number();
+
+        ClientState::new(
+            chain_id,
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(revision_number, 10).unwrap(),
+            ProofSpecs::default(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn client_state_revision_format() {
+        let tests = [
+            ("chainA-1", RevisionFormat::Delimited('-'), 1),
+            ("chainA", RevisionFormat::Delimited('-'), 0),
+            ("chainA_1", RevisionFormat::Delimited('_'), 1),
+            ("chainA-1", RevisionFormat::Revisionless, 0),
+        ];
+
+        for (chain_id, revision_format, revision_number) in tests {
+            let client_state = client_state_with_chain_id(
+                ChainId::new_with_format(chain_id, revision_format).unwrap(),
+            );
+            assert_eq!(client_state.revision_format, revision_format);
+            assert_eq!(client_state.chain_id.revision_number(), revision_number);
+
+            let any = Any::from(client_state.clone());
+            assert_eq!(ClientState::try_from(any.clone()).unwrap(), client_state);
+
+            // The client states in the default format are encoded as in ibc-go.
+            let raw = Protobuf::<RawTmClientState>::encode_vec(client_state.clone());
+            assert_eq!(
+                any.value == raw,
+                revision_format == RevisionFormat::default()
+            );
+        }
+    }
+
+    #[test]
+    fn client_state_revision_format_validation() {
+        let client_state = client_state_with_chain_id(ChainId::new("chainA-1").unwrap());
+
+        // The chain identifier does not carry a revision number in the format.
+        let mismatched = ClientState {
+            revision_format: RevisionFormat::Revisionless,
+            ..client_state.clone()
+        };
+        assert!(matches!(
+            mismatched.validate(),
+            Err(Error::MismatchRevisionFormat { .. })
+        ));
+
+        // Setting the format parses the chain identifier again, which then no
+        // longer matches the revision of the latest height.
+        let client_state = client_state
+            .with_revision_format(RevisionFormat::Revisionless)
+            .unwrap();
+        assert_eq!(client_state.chain_id.revision_number(), 0);
+        assert!(matches!(
+            client_state.validate(),
+            Err(Error::InvalidLatestHeight { .. })
+        ));
+
+        // The encoded delimiter must be a single character.
+        let client_state = client_state_with_chain_id(
+            ChainId::new_with_format("chainA_1", RevisionFormat::Delimited('_')).unwrap(),
+        );
+        let mut any = Any::from(client_state);
+        any.value.extend(prost::Message::encode_to_vec(
+            &RawClientStateRevisionFormat {
+                revision_format: Some(RawRevisionFormat {
+                    delimiter: Some("__".to_string()),
+                }),
+            },
+        ));
+        assert!(ClientState::try_from(any).is_err());
+    }
 }
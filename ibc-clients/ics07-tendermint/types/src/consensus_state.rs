@@ -44,6 +44,24 @@ impl ConsensusState {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ConsensusState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Stay within the range of `Time`, which ends with the year 9999.
+        let timestamp = Time::from_unix_timestamp(
+            u.int_in_range(0..=253_402_300_799)?,
+            u.int_in_range(0..=999_999_999)?,
+        )
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        Ok(Self {
+            timestamp,
+            root: CommitmentRoot::arbitrary(u)?,
+            next_validators_hash: Hash::Sha256(u.arbitrary()?),
+        })
+    }
+}
+
 impl Protobuf<RawConsensusState> for ConsensusState {}
 
 impl TryFrom<RawConsensusState> for ConsensusState {
@@ -107,10 +125,12 @@ impl TryFrom<Any> for ConsensusState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
-            let client_state =
-                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+            let client_state = Protobuf::<RawConsensusState>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                     description: e.to_string(),
-                })?;
+                }
+            })?;
             Ok(client_state)
         }
 
@@ -7,7 +7,7 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::identifiers::{ClientId, RevisionFormat};
 use ibc_primitives::prelude::*;
 use tendermint::{Error as TendermintError, Hash};
 use tendermint_light_client_verifier::errors::VerificationErrorDetail as LightClientErrorDetail;
@@ -73,6 +73,11 @@ pub enum Error {
     },
     /// the given chain-id (`{given}`) does not match the chain-id of the client (`{expected}`)
     MismatchHeaderChainId { given: String, expected: String },
+    /// the chain-id `{chain_id}` does not carry its revision number in the revision format `{revision_format:?}` of the client
+    MismatchRevisionFormat {
+        chain_id: String,
+        revision_format: RevisionFormat,
+    },
     /// not enough trust because insufficient validators overlap: `{reason}`
     NotEnoughTrustedValsSigned { reason: VotingPowerTally },
     /// verification failed: `{detail}`
@@ -89,6 +94,11 @@ pub enum Error {
         duration_since_consensus_state: Duration,
         trusting_period: Duration,
     },
+    /// the validator set `{validators_hash}` of the consumer chain header is not a validator set of the provider chain tracked by the client `{provider_client_id}`
+    ConsumerValidatorSetNotFromProvider {
+        validators_hash: Hash,
+        provider_client_id: ClientId,
+    },
     /// headers block hashes are equal
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
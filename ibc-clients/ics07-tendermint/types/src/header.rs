@@ -1,11 +1,10 @@
 //! Defines the domain type for tendermint headers
 
 use core::fmt::{Display, Error as FmtError, Formatter};
-use core::str::FromStr;
 
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
-use ibc_core_host_types::identifiers::ChainId;
+use ibc_core_host_types::identifiers::{ChainId, RevisionFormat};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
@@ -14,6 +13,9 @@ use ibc_proto::Protobuf;
 use pretty::{PrettySignedHeader, PrettyValidatorSet};
 use tendermint::block::signed_header::SignedHeader;
 use tendermint::chain::Id as TmChainId;
+use tendermint::crypto::default::Sha256 as DefaultSha256;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 
@@ -49,14 +51,29 @@ impl Header {
         self.signed_header.header.time.into()
     }
 
+    /// Returns the height of the header, whose revision number is carried by
+    /// its chain identifier in the {chain name}-{revision number} format.
+    ///
+    /// The clients of chains formatting their identifiers otherwise must use
+    /// [`height_with_format`](Self::height_with_format) instead.
     pub fn height(&self) -> Height {
-        Height::new(
-            ChainId::from_str(self.signed_header.header.chain_id.as_str())
-                .expect("chain id")
-                .revision_number(),
-            u64::from(self.signed_header.header.height),
-        )
-        .expect("malformed tendermint header domain type has an illegal height of 0")
+        self.height_with_format(RevisionFormat::default())
+    }
+
+    /// Returns the height of the header, whose revision number is carried by
+    /// its chain identifier in the given format.
+    ///
+    /// A chain identifier which is not a valid [`ChainId`] carries no revision
+    /// number, and is rejected anyway when checked against the one of the
+    /// client.
+    pub fn height_with_format(&self, revision_format: RevisionFormat) -> Height {
+        let revision_number =
+            ChainId::new_with_format(self.signed_header.header.chain_id.as_str(), revision_format)
+                .map(|chain_id| chain_id.revision_number())
+                .unwrap_or_default();
+
+        Height::new(revision_number, u64::from(self.signed_header.header.height))
+            .expect("malformed tendermint header domain type has an illegal height of 0")
     }
 
     pub fn as_untrusted_block_state(&self) -> UntrustedBlockState<'_> {
@@ -88,7 +105,11 @@ impl Header {
     }
 
     pub fn verify_chain_id_version_matches_height(&self, chain_id: &ChainId) -> Result<(), Error> {
-        if self.height().revision_number() != chain_id.revision_number() {
+        if self
+            .height_with_format(chain_id.revision_format())
+            .revision_number()
+            != chain_id.revision_number()
+        {
             return Err(Error::MismatchHeaderChainId {
                 given: self.signed_header.header.chain_id.to_string(),
                 expected: chain_id.to_string(),
@@ -104,7 +125,21 @@ impl Header {
         &self,
         trusted_consensus_state: &TmConsensusState,
     ) -> Result<(), ClientError> {
-        if self.trusted_next_validator_set.hash() == trusted_consensus_state.next_validators_hash {
+        self.check_trusted_next_validator_set_with::<DefaultSha256>(trusted_consensus_state)
+    }
+
+    /// Same as [`check_trusted_next_validator_set`](Self::check_trusted_next_validator_set),
+    /// but hashes the validator set with the given SHA256 implementation.
+    pub fn check_trusted_next_validator_set_with<H>(
+        &self,
+        trusted_consensus_state: &TmConsensusState,
+    ) -> Result<(), ClientError>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        if self.trusted_next_validator_set.hash_with::<H>()
+            == trusted_consensus_state.next_validators_hash
+        {
             Ok(())
         } else {
             Err(ClientError::HeaderVerificationFailure {
@@ -117,10 +152,34 @@ impl Header {
 
     /// Checks if the fields of a given header are consistent with the trusted fields of this header.
     pub fn validate_basic(&self) -> Result<(), Error> {
-        if self.height().revision_number() != self.trusted_height.revision_number() {
+        self.validate_basic_with::<DefaultSha256>()
+    }
+
+    /// Same as [`validate_basic`](Self::validate_basic), but hashes the
+    /// validator set with the given SHA256 implementation.
+    pub fn validate_basic_with<H>(&self) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.validate_basic_with_format::<H>(RevisionFormat::default())
+    }
+
+    /// Same as [`validate_basic_with`](Self::validate_basic_with), but
+    /// parses the revision number of the header from its chain identifier in
+    /// the given format, as [`height_with_format`](Self::height_with_format).
+    pub fn validate_basic_with_format<H>(
+        &self,
+        revision_format: RevisionFormat,
+    ) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        let height = self.height_with_format(revision_format);
+
+        if height.revision_number() != self.trusted_height.revision_number() {
             return Err(Error::MismatchHeightRevisions {
                 trusted_revision: self.trusted_height.revision_number(),
-                header_revision: self.height().revision_number(),
+                header_revision: height.revision_number(),
             });
         }
 
@@ -128,16 +187,18 @@ impl Header {
         // height of the header already on chain for which this client update is
         // based on) must be smaller than height of the new header that we're
         // installing.
-        if self.trusted_height >= self.height() {
+        if self.trusted_height >= height {
             return Err(Error::InvalidHeaderHeight {
-                height: self.height().revision_height(),
+                height: height.revision_height(),
             });
         }
 
-        if self.validator_set.hash() != self.signed_header.header.validators_hash {
+        let validators_hash = self.validator_set.hash_with::<H>();
+
+        if validators_hash != self.signed_header.header.validators_hash {
             return Err(Error::MismatchValidatorsHashes {
                 signed_header_validators_hash: self.signed_header.header.validators_hash,
-                validators_hash: self.validator_set.hash(),
+                validators_hash,
             });
         }
 
@@ -187,8 +248,11 @@ impl TryFrom<Any> for Header {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
-            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| ClientError::Other {
-                description: e.to_string(),
+            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
+                    description: e.to_string(),
+                }
             })?;
             Ok(header)
         }
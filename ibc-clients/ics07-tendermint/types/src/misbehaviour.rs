@@ -1,11 +1,14 @@
 //! Defines the misbehaviour type for the tendermint light client
 
 use ibc_core_client_types::error::ClientError;
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::identifiers::{ClientId, RevisionFormat};
 use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use ibc_proto::Protobuf;
+use tendermint::crypto::default::Sha256 as DefaultSha256;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
 
 use crate::error::Error;
 use crate::header::Header;
@@ -43,8 +46,32 @@ impl Misbehaviour {
     }
 
     pub fn validate_basic(&self) -> Result<(), Error> {
-        self.header1.validate_basic()?;
-        self.header2.validate_basic()?;
+        self.validate_basic_with::<DefaultSha256>()
+    }
+
+    /// Same as [`validate_basic`](Self::validate_basic), but hashes the
+    /// validator sets of the headers with the given SHA256 implementation.
+    pub fn validate_basic_with<H>(&self) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.validate_basic_with_format::<H>(RevisionFormat::default())
+    }
+
+    /// Same as [`validate_basic_with`](Self::validate_basic_with), but
+    /// parses the revision numbers of the headers from their chain identifier
+    /// in the given format, see [`Header::height_with_format`].
+    pub fn validate_basic_with_format<H>(
+        &self,
+        revision_format: RevisionFormat,
+    ) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.header1
+            .validate_basic_with_format::<H>(revision_format)?;
+        self.header2
+            .validate_basic_with_format::<H>(revision_format)?;
 
         if self.header1.signed_header.header.chain_id != self.header2.signed_header.header.chain_id
         {
@@ -111,10 +138,12 @@ impl TryFrom<Any> for Misbehaviour {
 
     fn try_from(raw: Any) -> Result<Self, ClientError> {
         fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
-            let misbehaviour =
-                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+            let misbehaviour = Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
                     description: e.to_string(),
-                })?;
+                }
+            })?;
             Ok(misbehaviour)
         }
         match raw.type_url.as_str() {
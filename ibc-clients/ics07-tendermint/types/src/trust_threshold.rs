@@ -89,6 +89,18 @@ impl TrustThreshold {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TrustThreshold {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let denominator = u.int_in_range(1..=u64::MAX)?;
+        let numerator = u.int_in_range(0..=denominator - 1)?;
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
 /// Conversion from Tendermint domain type into
 /// IBC domain type.
 impl From<TrustThresholdFraction> for TrustThreshold {
@@ -0,0 +1,29 @@
+//! Defines the checksums addressing the codes of the Wasm light clients.
+
+use ibc_primitives::prelude::*;
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
+
+use crate::error::WasmClientError;
+use crate::types::Bytes;
+
+/// The checksum of a Wasm code, i.e. the SHA256 hash of the stored bytes.
+pub type Checksum = Bytes;
+
+/// Computes the checksum of the given Wasm code.
+pub fn compute_checksum(wasm_code: &[u8]) -> Checksum {
+    Sha256::digest(wasm_code).to_vec()
+}
+
+/// Encodes the given checksum as a lowercase hex string, as reported by the
+/// queries of ibc-go.
+pub fn checksum_to_hex(checksum: &[u8]) -> String {
+    String::from_utf8(hex::encode(checksum)).expect("Never fails because hexadecimal is valid UTF8")
+}
+
+/// Decodes the given hex encoded checksum.
+pub fn checksum_from_hex(checksum: &str) -> Result<Checksum, WasmClientError> {
+    hex::decode(checksum).map_err(|_| WasmClientError::InvalidChecksum {
+        checksum: checksum.to_string(),
+    })
+}
@@ -0,0 +1,99 @@
+//! Defines the context traits that hosts implement in order to store the
+//! codes of the Wasm light clients, and to run their contracts.
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::checksum::{checksum_to_hex, Checksum};
+use crate::error::WasmClientError;
+use crate::types::client_state::ClientState as WasmClientState;
+use crate::types::Bytes;
+use crate::vm::{ContractStore, GasCosts, VmLimits};
+
+/// The maximum size of the stored Wasm codes by default, 3 MiB as in ibc-go.
+pub const DEFAULT_MAX_WASM_CODE_SIZE: usize = 3 * 1024 * 1024;
+
+/// The gas available to the contract calls by default.
+pub const DEFAULT_WASM_GAS_LIMIT: u64 = 3_000_000;
+
+/// Context required to validate the storage of Wasm codes, and to serve the
+/// codes and their checksums.
+pub trait WasmValidationContext {
+    /// Returns the authority allowed to store Wasm codes, usually the account
+    /// of the governance module of the host.
+    ///
+    /// By default, there is none, and no code can be stored.
+    fn wasm_authority(&self) -> Option<Signer> {
+        None
+    }
+
+    /// Returns the maximum size of the stored Wasm codes.
+    fn max_wasm_code_size(&self) -> usize {
+        DEFAULT_MAX_WASM_CODE_SIZE
+    }
+
+    /// Returns the Wasm code stored under the given checksum, if any.
+    fn wasm_code(&self, checksum: &Checksum) -> Result<Option<Bytes>, ContextError>;
+
+    /// Returns the checksums of all the stored Wasm codes.
+    fn checksums(&self) -> Result<Vec<Checksum>, ContextError>;
+
+    /// Returns whether the Wasm clients may run the code of the given
+    /// checksum. By default, the checksums of all the stored codes are
+    /// allowed.
+    fn is_checksum_allowed(&self, checksum: &Checksum) -> Result<bool, ContextError> {
+        Ok(self.checksums()?.contains(checksum))
+    }
+
+    /// Returns the state of the Wasm client with the given identifier.
+    fn wasm_client_state(&self, client_id: &ClientId) -> Result<WasmClientState, ContextError>;
+
+    /// Returns the resources the contract calls are allowed to use.
+    fn wasm_vm_limits(&self) -> VmLimits {
+        VmLimits::new(DEFAULT_WASM_GAS_LIMIT)
+    }
+
+    /// Returns the gas costs of the operations of the contracts.
+    fn wasm_gas_costs(&self) -> GasCosts {
+        GasCosts::default()
+    }
+}
+
+/// Context required to store Wasm codes.
+pub trait WasmExecutionContext: WasmValidationContext {
+    /// Stores the given Wasm code under its checksum, and adds the checksum to
+    /// the ones of the stored codes.
+    fn store_wasm_code(&mut self, checksum: Checksum, wasm_code: Bytes)
+        -> Result<(), ContextError>;
+
+    /// Stores the state of the Wasm client with the given identifier.
+    fn store_wasm_client_state(
+        &mut self,
+        client_id: ClientId,
+        client_state: WasmClientState,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the client store of the Wasm client with the given identifier,
+    /// which its contract reads and writes.
+    fn contract_store(
+        &mut self,
+        client_id: &ClientId,
+    ) -> Result<Box<dyn ContractStore + '_>, ContextError>;
+}
+
+/// Checks that the Wasm clients may run the code of the given checksum, e.g.
+/// when creating or migrating a Wasm client.
+pub fn ensure_checksum_allowed<Ctx>(ctx: &Ctx, checksum: &Checksum) -> Result<(), WasmClientError>
+where
+    Ctx: WasmValidationContext,
+{
+    if !ctx.is_checksum_allowed(checksum)? {
+        return Err(WasmClientError::ChecksumNotAllowed {
+            checksum: checksum_to_hex(checksum),
+        });
+    }
+
+    Ok(())
+}
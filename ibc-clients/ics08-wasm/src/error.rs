@@ -0,0 +1,61 @@
+//! Defines the error type of the host-side logic of the Wasm light client.
+
+use displaydoc::Display;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::vm::VmError;
+
+#[derive(Debug, Display)]
+pub enum WasmClientError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// virtual machine error: `{0}`
+    Vm(VmError),
+    /// signer `{signer}` is not the authority allowed to store Wasm codes
+    UnauthorizedCodeStore { signer: Signer },
+    /// Wasm code is empty
+    EmptyWasmCode,
+    /// Wasm code of `{size}` bytes exceeds the maximum size of `{max_size}` bytes
+    WasmCodeTooLarge { size: usize, max_size: usize },
+    /// Wasm code with checksum `{checksum}` is already stored
+    ChecksumAlreadyStored { checksum: String },
+    /// signer `{signer}` is not the authority allowed to migrate Wasm clients
+    UnauthorizedMigration { signer: Signer },
+    /// Wasm client `{client_id}` already runs the code with checksum `{checksum}`
+    ChecksumUnchanged {
+        client_id: ClientId,
+        checksum: String,
+    },
+    /// Wasm code with checksum `{checksum}` not found
+    WasmCodeNotFound { checksum: String },
+    /// checksum `{checksum}` is not allowed
+    ChecksumNotAllowed { checksum: String },
+    /// invalid checksum `{checksum}`
+    InvalidChecksum { checksum: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WasmClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::Vm(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for WasmClientError {
+    fn from(e: ContextError) -> Self {
+        Self::ContextError(e)
+    }
+}
+
+impl From<VmError> for WasmClientError {
+    fn from(e: VmError) -> Self {
+        Self::Vm(e)
+    }
+}
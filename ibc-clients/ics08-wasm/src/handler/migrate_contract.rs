@@ -0,0 +1,186 @@
+//! Protocol logic specific to processing ICS8 messages of type
+//! `MsgMigrateContract`.
+
+use ibc_primitives::prelude::*;
+
+use crate::checksum::{checksum_to_hex, Checksum};
+use crate::context::{ensure_checksum_allowed, WasmExecutionContext, WasmValidationContext};
+use crate::error::WasmClientError;
+use crate::types::msgs::migrate_contract::MsgMigrateContract;
+use crate::types::Bytes;
+use crate::vm::{call_contract, EntryPoint, WasmVm};
+
+/// Validates the migration of the Wasm client of the message to the code of
+/// its checksum.
+///
+/// The migration is only accepted from the authority of the host, and the new
+/// code must be stored, allowed, and differ from the current one of the
+/// client.
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgMigrateContract) -> Result<(), WasmClientError>
+where
+    Ctx: WasmValidationContext,
+{
+    if ctx.wasm_authority().as_ref() != Some(&msg.signer) {
+        return Err(WasmClientError::UnauthorizedMigration {
+            signer: msg.signer.clone(),
+        });
+    }
+
+    let client_state = ctx.wasm_client_state(&msg.client_id)?;
+
+    if client_state.checksum == msg.checksum {
+        return Err(WasmClientError::ChecksumUnchanged {
+            client_id: msg.client_id.clone(),
+            checksum: checksum_to_hex(&msg.checksum),
+        });
+    }
+
+    wasm_code(ctx, &msg.checksum)?;
+
+    ensure_checksum_allowed(ctx, &msg.checksum)
+}
+
+/// Migrates the Wasm client of the message, calling the `migrate` entry point
+/// of its new code with the message of the migration, and returns the gas
+/// used by the call.
+///
+/// Once the call succeeds, the checksum of the client state, which the
+/// contract may have rewritten, is replaced by the new one.
+pub fn execute<Ctx, V>(
+    ctx: &mut Ctx,
+    vm: &V,
+    msg: MsgMigrateContract,
+) -> Result<u64, WasmClientError>
+where
+    Ctx: WasmExecutionContext,
+    V: WasmVm,
+{
+    let code = wasm_code(ctx, &msg.checksum)?;
+    let limits = ctx.wasm_vm_limits();
+    let costs = ctx.wasm_gas_costs();
+
+    let outcome = {
+        let mut store = ctx.contract_store(&msg.client_id)?;
+
+        call_contract(
+            vm,
+            &code,
+            EntryPoint::Migrate,
+            &msg.msg,
+            store.as_mut(),
+            limits,
+            costs,
+        )
+    };
+
+    outcome.result?;
+
+    let mut client_state = ctx.wasm_client_state(&msg.client_id)?;
+    client_state.checksum = msg.checksum;
+
+    ctx.store_wasm_client_state(msg.client_id, client_state)?;
+
+    Ok(outcome.gas_used)
+}
+
+fn wasm_code<Ctx>(ctx: &Ctx, checksum: &Checksum) -> Result<Bytes, WasmClientError>
+where
+    Ctx: WasmValidationContext,
+{
+    ctx.wasm_code(checksum)?
+        .ok_or_else(|| WasmClientError::WasmCodeNotFound {
+            checksum: checksum_to_hex(checksum),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client_types::Height;
+    use ibc_core_host_types::identifiers::ClientId;
+    use ibc_primitives::Signer;
+
+    use super::*;
+    use crate::checksum::compute_checksum;
+    use crate::handler::mock::{Host, Vm, AUTHORITY};
+    use crate::types::client_state::ClientState as WasmClientState;
+
+    const OLD_CODE: &[u8] = b"\0asm\x01";
+    const NEW_CODE: &[u8] = b"\0asm\x02";
+
+    fn client_id() -> ClientId {
+        ClientId::new("08-wasm", 0).unwrap()
+    }
+
+    fn host() -> Host {
+        let mut host = Host::default();
+
+        for code in [OLD_CODE, NEW_CODE] {
+            host.codes.insert(compute_checksum(code), code.to_vec());
+        }
+        host.client_states.insert(
+            client_id(),
+            WasmClientState {
+                data: b"data".to_vec(),
+                checksum: compute_checksum(OLD_CODE),
+                latest_height: Height::new(0, 1).unwrap(),
+            },
+        );
+
+        host
+    }
+
+    fn msg(signer: &str, code: &[u8], migrate_msg: &[u8]) -> MsgMigrateContract {
+        MsgMigrateContract {
+            signer: Signer::from(signer.to_string()),
+            client_id: client_id(),
+            checksum: compute_checksum(code),
+            msg: migrate_msg.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_contract() {
+        let mut host = host();
+        let msg = msg(AUTHORITY, NEW_CODE, b"migrate");
+
+        validate(&host, &msg).unwrap();
+        assert!(execute(&mut host, &Vm, msg.clone()).unwrap() > 0);
+
+        let client_state = host.wasm_client_state(&client_id()).unwrap();
+        assert_eq!(client_state.checksum, msg.checksum);
+        assert_eq!(client_state.data, b"data".to_vec());
+        assert_eq!(
+            host.stores[&client_id()].get(b"msg".as_slice()),
+            Some(&msg.msg)
+        );
+
+        assert!(matches!(
+            validate(&host, &msg),
+            Err(WasmClientError::ChecksumUnchanged { .. })
+        ));
+    }
+
+    #[test]
+    fn test_migrate_contract_rejections() {
+        let mut host = host();
+
+        assert!(matches!(
+            validate(&host, &msg("relayer", NEW_CODE, b"migrate")),
+            Err(WasmClientError::UnauthorizedMigration { .. })
+        ));
+        assert!(matches!(
+            validate(&host, &msg(AUTHORITY, b"\0asm\x03", b"migrate")),
+            Err(WasmClientError::WasmCodeNotFound { .. })
+        ));
+
+        // The client keeps its code when the migration of the contract fails.
+        assert!(matches!(
+            execute(&mut host, &Vm, msg(AUTHORITY, NEW_CODE, b"")),
+            Err(WasmClientError::Vm(_))
+        ));
+        assert_eq!(
+            host.wasm_client_state(&client_id()).unwrap().checksum,
+            compute_checksum(OLD_CODE)
+        );
+    }
+}
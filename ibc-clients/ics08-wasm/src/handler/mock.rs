@@ -0,0 +1,127 @@
+//! Mock host and virtual machine for the tests of the handlers.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::checksum::Checksum;
+use crate::context::{WasmExecutionContext, WasmValidationContext};
+use crate::types::client_state::ClientState as WasmClientState;
+use crate::types::Bytes;
+use crate::vm::{ContractStore, EntryPoint, VmEnv, VmError, WasmVm};
+
+pub(crate) const AUTHORITY: &str = "gov";
+
+#[derive(Default)]
+pub(crate) struct Host {
+    pub codes: BTreeMap<Checksum, Bytes>,
+    pub client_states: BTreeMap<ClientId, WasmClientState>,
+    pub stores: BTreeMap<ClientId, BTreeMap<Vec<u8>, Vec<u8>>>,
+}
+
+impl WasmValidationContext for Host {
+    fn wasm_authority(&self) -> Option<Signer> {
+        Some(Signer::from(AUTHORITY.to_string()))
+    }
+
+    fn max_wasm_code_size(&self) -> usize {
+        8
+    }
+
+    fn wasm_code(&self, checksum: &Checksum) -> Result<Option<Bytes>, ContextError> {
+        Ok(self.codes.get(checksum).cloned())
+    }
+
+    fn checksums(&self) -> Result<Vec<Checksum>, ContextError> {
+        Ok(self.codes.keys().cloned().collect())
+    }
+
+    fn wasm_client_state(&self, client_id: &ClientId) -> Result<WasmClientState, ContextError> {
+        self.client_states.get(client_id).cloned().ok_or_else(|| {
+            ContextError::ClientError(ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })
+        })
+    }
+}
+
+impl WasmExecutionContext for Host {
+    fn store_wasm_code(
+        &mut self,
+        checksum: Checksum,
+        wasm_code: Bytes,
+    ) -> Result<(), ContextError> {
+        self.codes.insert(checksum, wasm_code);
+        Ok(())
+    }
+
+    fn store_wasm_client_state(
+        &mut self,
+        client_id: ClientId,
+        client_state: WasmClientState,
+    ) -> Result<(), ContextError> {
+        self.client_states.insert(client_id, client_state);
+        Ok(())
+    }
+
+    fn contract_store(
+        &mut self,
+        client_id: &ClientId,
+    ) -> Result<Box<dyn ContractStore + '_>, ContextError> {
+        Ok(Box::new(Store(
+            self.stores.entry(client_id.clone()).or_default(),
+        )))
+    }
+}
+
+struct Store<'a>(&'a mut BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl ContractStore for Store<'_> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, VmError> {
+        Ok(self.0.get(key).cloned())
+    }
+
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), VmError> {
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    fn remove(&mut self, key: &[u8]) -> Result<(), VmError> {
+        self.0.remove(key);
+        Ok(())
+    }
+}
+
+/// An engine accepting the codes starting with the Wasm magic number, whose
+/// contracts store the message of their last call under `msg`, and fail on
+/// empty messages.
+pub(crate) struct Vm;
+
+impl WasmVm for Vm {
+    fn validate_code(&self, code: &[u8]) -> Result<(), VmError> {
+        if !code.starts_with(b"\0asm") {
+            return Err(VmError::InvalidCode {
+                reason: "missing magic number".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn call(
+        &self,
+        _code: &[u8],
+        _entry_point: EntryPoint,
+        msg: &[u8],
+        env: &mut VmEnv<'_>,
+    ) -> Result<Vec<u8>, VmError> {
+        if msg.is_empty() {
+            return Err(VmError::Execution {
+                reason: "empty message".to_string(),
+            });
+        }
+        env.set(b"msg".to_vec(), msg.to_vec())?;
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,7 @@
+//! Implements the handlers of the messages of the Wasm light client module.
+
+pub mod migrate_contract;
+pub mod store_code;
+
+#[cfg(test)]
+mod mock;
@@ -0,0 +1,127 @@
+//! Protocol logic specific to processing ICS8 messages of type `MsgStoreCode`.
+
+use ibc_primitives::prelude::*;
+
+use crate::checksum::{checksum_to_hex, compute_checksum, Checksum};
+use crate::context::{WasmExecutionContext, WasmValidationContext};
+use crate::error::WasmClientError;
+use crate::types::msgs::store_code::MsgStoreCode;
+use crate::vm::WasmVm;
+
+/// Validates the storage of the Wasm code of the message, returning its
+/// checksum.
+///
+/// The code is only accepted from the authority of the host, must not exceed
+/// the maximum size, must not be already stored, and must be valid for the
+/// given virtual machine.
+pub fn validate<Ctx, V>(ctx: &Ctx, vm: &V, msg: &MsgStoreCode) -> Result<Checksum, WasmClientError>
+where
+    Ctx: WasmValidationContext,
+    V: WasmVm,
+{
+    if ctx.wasm_authority().as_ref() != Some(&msg.signer) {
+        return Err(WasmClientError::UnauthorizedCodeStore {
+            signer: msg.signer.clone(),
+        });
+    }
+
+    if msg.wasm_byte_code.is_empty() {
+        return Err(WasmClientError::EmptyWasmCode);
+    }
+
+    let max_size = ctx.max_wasm_code_size();
+
+    if msg.wasm_byte_code.len() > max_size {
+        return Err(WasmClientError::WasmCodeTooLarge {
+            size: msg.wasm_byte_code.len(),
+            max_size,
+        });
+    }
+
+    let checksum = compute_checksum(&msg.wasm_byte_code);
+
+    if ctx.wasm_code(&checksum)?.is_some() {
+        return Err(WasmClientError::ChecksumAlreadyStored {
+            checksum: checksum_to_hex(&checksum),
+        });
+    }
+
+    vm.validate_code(&msg.wasm_byte_code)?;
+
+    Ok(checksum)
+}
+
+/// Stores the Wasm code of the message, returning its checksum.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgStoreCode) -> Result<Checksum, WasmClientError>
+where
+    Ctx: WasmExecutionContext,
+{
+    let checksum = compute_checksum(&msg.wasm_byte_code);
+
+    ctx.store_wasm_code(checksum.clone(), msg.wasm_byte_code)?;
+
+    Ok(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::Signer;
+
+    use super::*;
+    use crate::context::ensure_checksum_allowed;
+    use crate::handler::mock::{Host, Vm, AUTHORITY};
+    use crate::vm::VmError;
+
+    fn msg(signer: &str, wasm_byte_code: &[u8]) -> MsgStoreCode {
+        MsgStoreCode {
+            signer: Signer::from(signer.to_string()),
+            wasm_byte_code: wasm_byte_code.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_store_code() {
+        let mut host = Host::default();
+        let msg = msg(AUTHORITY, b"\0asm\x01");
+
+        let checksum = validate(&host, &Vm, &msg).unwrap();
+        assert!(ensure_checksum_allowed(&host, &checksum).is_err());
+
+        assert_eq!(execute(&mut host, msg.clone()).unwrap(), checksum);
+        assert_eq!(
+            host.wasm_code(&checksum).unwrap(),
+            Some(msg.wasm_byte_code.clone())
+        );
+        assert!(ensure_checksum_allowed(&host, &checksum).is_ok());
+
+        assert!(matches!(
+            validate(&host, &Vm, &msg),
+            Err(WasmClientError::ChecksumAlreadyStored { .. })
+        ));
+    }
+
+    #[test]
+    fn test_store_code_rejections() {
+        let host = Host::default();
+
+        assert!(matches!(
+            validate(&host, &Vm, &msg("relayer", b"\0asm")),
+            Err(WasmClientError::UnauthorizedCodeStore { .. })
+        ));
+        assert!(matches!(
+            validate(&host, &Vm, &msg(AUTHORITY, b"")),
+            Err(WasmClientError::EmptyWasmCode)
+        ));
+        assert!(matches!(
+            validate(&host, &Vm, &msg(AUTHORITY, b"\0asm\x01\0\0\0\0")),
+            Err(WasmClientError::WasmCodeTooLarge {
+                size: 9,
+                max_size: 8
+            })
+        ));
+        assert!(matches!(
+            validate(&host, &Vm, &msg(AUTHORITY, b"wasm")),
+            Err(WasmClientError::Vm(VmError::InvalidCode { .. }))
+        ));
+    }
+}
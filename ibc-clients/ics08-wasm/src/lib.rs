@@ -0,0 +1,29 @@
+//! ICS 08: Wasm proxy light client host-side logic, along with re-exporting
+//! data structures of the `ibc-client-wasm-types` crate.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod checksum;
+pub mod context;
+pub mod error;
+pub mod handler;
+pub mod vm;
+
+/// Re-export of Wasm light client data structures from `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+}
@@ -0,0 +1,517 @@
+//! Defines the interface of the virtual machines executing the contracts of
+//! the Wasm light clients, along with the resources they are allowed to use.
+//!
+//! The light client contracts are executed by the host through a [`WasmVm`],
+//! within a [`VmEnv`] which enforces the limits of the call: every executed
+//! instruction and every access to the contract store is charged to a
+//! [`GasMeter`] according to the [`GasCosts`] of the host, and the linear
+//! memory of the instances is capped. As the costs are charged by the
+//! environment rather than by the engines, the gas used by a call only
+//! depends on the contract, its inputs and the store, and the engines remain
+//! interchangeable across the nodes of a chain. A reference engine, backed by
+//! the `wasmi` interpreter, is provided behind the `wasmi` feature.
+//!
+//! Example
+//! ```rust,ignore
+//! let outcome = call_contract(
+//!     &my_engine,
+//!     &code,
+//!     EntryPoint::Sudo,
+//!     &msg,
+//!     &mut client_store,
+//!     VmLimits::new(3_000_000),
+//!     GasCosts::default(),
+//! );
+//! ```
+
+#[cfg(feature = "wasmi")]
+pub mod wasmi;
+
+use displaydoc::Display;
+use ibc_primitives::prelude::*;
+
+/// The size in bytes of a page of the linear memory of Wasm instances.
+pub const WASM_PAGE_SIZE: u64 = 65_536;
+
+/// The entry points of the light client contracts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntryPoint {
+    /// Initializes the client and consensus states of the contract.
+    Instantiate,
+    /// Updates the states of the contract, e.g. on client updates.
+    Sudo,
+    /// Reads the states of the contract, e.g. to verify proofs. Queries are
+    /// not allowed to write to the contract store.
+    Query,
+    /// Migrates the states of the contract to a new code.
+    Migrate,
+}
+
+impl EntryPoint {
+    /// Returns the name of the exported function of the entry point.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Instantiate => "instantiate",
+            Self::Sudo => "sudo",
+            Self::Query => "query",
+            Self::Migrate => "migrate",
+        }
+    }
+
+    /// Returns whether the entry point may write to the contract store.
+    pub fn is_mutable(&self) -> bool {
+        !matches!(self, Self::Query)
+    }
+}
+
+/// The resources a contract call is allowed to use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VmLimits {
+    /// The gas available to the call.
+    pub gas_limit: u64,
+    /// The maximum number of pages of the linear memory of the instance.
+    pub memory_limit_pages: u32,
+}
+
+impl VmLimits {
+    /// The default memory cap, 32 MiB, as enforced by wasmd.
+    pub const DEFAULT_MEMORY_LIMIT_PAGES: u32 = 512;
+
+    /// Creates the limits of a call with the given gas, and the default memory
+    /// cap.
+    pub fn new(gas_limit: u64) -> Self {
+        Self {
+            gas_limit,
+            memory_limit_pages: Self::DEFAULT_MEMORY_LIMIT_PAGES,
+        }
+    }
+
+    pub fn with_memory_limit_pages(self, memory_limit_pages: u32) -> Self {
+        Self {
+            memory_limit_pages,
+            ..self
+        }
+    }
+
+    /// Returns the memory cap in bytes.
+    pub fn memory_limit_bytes(&self) -> u64 {
+        u64::from(self.memory_limit_pages) * WASM_PAGE_SIZE
+    }
+
+    /// Checks that an instance may grow its linear memory to the given number
+    /// of pages.
+    pub fn check_memory_pages(&self, pages: u32) -> Result<(), VmError> {
+        if pages > self.memory_limit_pages {
+            return Err(VmError::MemoryLimitExceeded {
+                pages,
+                limit: self.memory_limit_pages,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The gas costs of the operations of the contracts.
+///
+/// The default costs of the store accesses are the ones of the KV stores of
+/// the Cosmos SDK.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasCosts {
+    /// The cost of an instruction, in the unit metered by the engine.
+    pub instruction: u64,
+    /// The flat cost of a read from the contract store.
+    pub read_flat: u64,
+    /// The cost of a byte read from the contract store.
+    pub read_per_byte: u64,
+    /// The flat cost of a write to the contract store.
+    pub write_flat: u64,
+    /// The cost of a byte written to the contract store.
+    pub write_per_byte: u64,
+    /// The cost of a removal from the contract store.
+    pub remove: u64,
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self {
+            instruction: 1,
+            read_flat: 1_000,
+            read_per_byte: 3,
+            write_flat: 2_000,
+            write_per_byte: 30,
+            remove: 1_000,
+        }
+    }
+}
+
+/// Tracks the gas used by a contract call against its limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasMeter {
+    limit: u64,
+    used: u64,
+}
+
+impl GasMeter {
+    pub fn new(limit: u64) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.used
+    }
+
+    /// Consumes the given amount of gas, failing if the limit is exceeded, in
+    /// which case the whole limit is considered used.
+    pub fn consume(&mut self, amount: u64) -> Result<(), VmError> {
+        match self.used.checked_add(amount) {
+            Some(used) if used <= self.limit => {
+                self.used = used;
+                Ok(())
+            }
+            _ => {
+                self.used = self.limit;
+                Err(VmError::OutOfGas { limit: self.limit })
+            }
+        }
+    }
+}
+
+/// The store of a contract, i.e. the client store of its Wasm client.
+pub trait ContractStore {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, VmError>;
+
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), VmError>;
+
+    fn remove(&mut self, key: &[u8]) -> Result<(), VmError>;
+}
+
+/// The environment of a contract call, through which the engine charges the
+/// executed instructions and accesses the contract store.
+pub struct VmEnv<'a> {
+    store: &'a mut dyn ContractStore,
+    entry_point: EntryPoint,
+    limits: VmLimits,
+    costs: GasCosts,
+    gas_meter: GasMeter,
+}
+
+impl<'a> VmEnv<'a> {
+    pub fn new(
+        store: &'a mut dyn ContractStore,
+        entry_point: EntryPoint,
+        limits: VmLimits,
+        costs: GasCosts,
+    ) -> Self {
+        Self {
+            store,
+            entry_point,
+            limits,
+            costs,
+            gas_meter: GasMeter::new(limits.gas_limit),
+        }
+    }
+
+    pub fn entry_point(&self) -> EntryPoint {
+        self.entry_point
+    }
+
+    pub fn limits(&self) -> &VmLimits {
+        &self.limits
+    }
+
+    pub fn costs(&self) -> &GasCosts {
+        &self.costs
+    }
+
+    pub fn gas_meter(&self) -> &GasMeter {
+        &self.gas_meter
+    }
+
+    /// Charges the given amount of gas, e.g. for host functions with their
+    /// own costs.
+    pub fn charge_gas(&mut self, amount: u64) -> Result<(), VmError> {
+        self.gas_meter.consume(amount)
+    }
+
+    /// Charges the given number of executed instructions.
+    pub fn charge_instructions(&mut self, count: u64) -> Result<(), VmError> {
+        self.charge_gas(count.saturating_mul(self.costs.instruction))
+    }
+
+    /// Checks that the instance may grow its linear memory to the given
+    /// number of pages.
+    pub fn check_memory_pages(&self, pages: u32) -> Result<(), VmError> {
+        self.limits.check_memory_pages(pages)
+    }
+
+    pub fn get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, VmError> {
+        self.charge_gas(self.costs.read_flat)?;
+
+        let value = self.store.get(key)?;
+
+        let read_bytes = key.len() + value.as_ref().map_or(0, Vec::len);
+        self.charge_gas(per_byte(self.costs.read_per_byte, read_bytes))?;
+
+        Ok(value)
+    }
+
+    pub fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), VmError> {
+        self.ensure_mutable()?;
+        self.charge_gas(self.costs.write_flat)?;
+        self.charge_gas(per_byte(self.costs.write_per_byte, key.len() + value.len()))?;
+
+        self.store.set(key, value)
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Result<(), VmError> {
+        self.ensure_mutable()?;
+        self.charge_gas(self.costs.remove)?;
+
+        self.store.remove(key)
+    }
+
+    fn ensure_mutable(&self) -> Result<(), VmError> {
+        if !self.entry_point.is_mutable() {
+            return Err(VmError::ImmutableEntryPoint {
+                entry_point: self.entry_point.as_str(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn per_byte(cost: u64, bytes: usize) -> u64 {
+    cost.saturating_mul(bytes as u64)
+}
+
+/// The virtual machine executing the light client contracts.
+///
+/// Engines must be deterministic: they reject the codes relying on
+/// non-deterministic features, such as floating-point instructions, meter
+/// every executed instruction with [`VmEnv::charge_instructions`], check
+/// every growth of the linear memory with [`VmEnv::check_memory_pages`], and
+/// access the contract store through the [`VmEnv`] only.
+pub trait WasmVm {
+    /// Validates the given code before it is stored, e.g. checking that it
+    /// exports the entry points of light client contracts and only uses
+    /// deterministic instructions.
+    fn validate_code(&self, code: &[u8]) -> Result<(), VmError>;
+
+    /// Calls the entry point of the given code with the given message,
+    /// returning the response of the contract.
+    fn call(
+        &self,
+        code: &[u8],
+        entry_point: EntryPoint,
+        msg: &[u8],
+        env: &mut VmEnv<'_>,
+    ) -> Result<Vec<u8>, VmError>;
+}
+
+/// The outcome of a contract call, along with the gas it used, which is also
+/// reported when the call fails.
+#[derive(Debug)]
+pub struct CallOutcome {
+    pub result: Result<Vec<u8>, VmError>,
+    pub gas_used: u64,
+}
+
+/// Calls the entry point of the given code with `vm`, within the given
+/// limits.
+pub fn call_contract(
+    vm: &impl WasmVm,
+    code: &[u8],
+    entry_point: EntryPoint,
+    msg: &[u8],
+    store: &mut dyn ContractStore,
+    limits: VmLimits,
+    costs: GasCosts,
+) -> CallOutcome {
+    let mut env = VmEnv::new(store, entry_point, limits, costs);
+
+    let result = vm.call(code, entry_point, msg, &mut env);
+
+    CallOutcome {
+        result,
+        gas_used: env.gas_meter().used(),
+    }
+}
+
+/// The errors of the contract calls.
+#[derive(Debug, Display)]
+pub enum VmError {
+    /// out of gas: limit of `{limit}` exceeded
+    OutOfGas { limit: u64 },
+    /// memory limit exceeded: `{pages}` pages requested, limit is `{limit}`
+    MemoryLimitExceeded { pages: u32, limit: u32 },
+    /// entry point `{entry_point}` cannot write to the contract store
+    ImmutableEntryPoint { entry_point: &'static str },
+    /// invalid contract code: `{reason}`
+    InvalidCode { reason: String },
+    /// contract store error: `{reason}`
+    Store { reason: String },
+    /// contract execution failed: `{reason}`
+    Execution { reason: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VmError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Store(BTreeMap<Vec<u8>, Vec<u8>>);
+
+    impl ContractStore for Store {
+        fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, VmError> {
+            Ok(self.0.get(key).cloned())
+        }
+
+        fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), VmError> {
+            self.0.insert(key, value);
+            Ok(())
+        }
+
+        fn remove(&mut self, key: &[u8]) -> Result<(), VmError> {
+            self.0.remove(key);
+            Ok(())
+        }
+    }
+
+    /// An engine running a counter contract, incremented by `sudo` calls,
+    /// executing one instruction per byte of the message and growing its
+    /// memory to as many pages as the value of the counter.
+    struct CounterVm;
+
+    impl WasmVm for CounterVm {
+        fn validate_code(&self, code: &[u8]) -> Result<(), VmError> {
+            if code.is_empty() {
+                return Err(VmError::InvalidCode {
+                    reason: "empty code".to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn call(
+            &self,
+            _code: &[u8],
+            entry_point: EntryPoint,
+            msg: &[u8],
+            env: &mut VmEnv<'_>,
+        ) -> Result<Vec<u8>, VmError> {
+            env.charge_instructions(msg.len() as u64)?;
+
+            let counter = env
+                .get(b"counter")?
+                .map_or(0, |value| u32::from_be_bytes(value.try_into().unwrap()));
+
+            match entry_point {
+                EntryPoint::Query => Ok(counter.to_be_bytes().to_vec()),
+                _ => {
+                    let counter = counter + 1;
+                    env.check_memory_pages(counter)?;
+                    env.set(b"counter".to_vec(), counter.to_be_bytes().to_vec())?;
+                    Ok(Vec::new())
+                }
+            }
+        }
+    }
+
+    fn call(
+        store: &mut Store,
+        entry_point: EntryPoint,
+        msg: &[u8],
+        limits: VmLimits,
+    ) -> CallOutcome {
+        call_contract(
+            &CounterVm,
+            b"code",
+            entry_point,
+            msg,
+            store,
+            limits,
+            GasCosts::default(),
+        )
+    }
+
+    #[test]
+    fn test_gas_metering_is_deterministic() {
+        let limits = VmLimits::new(100_000);
+
+        let mut store = Store::default();
+        let first = call(&mut store, EntryPoint::Sudo, b"msg", limits);
+        assert!(first.result.is_ok());
+        // An instruction per byte of the message, a read of the missing
+        // counter and a write of the counter.
+        assert_eq!(first.gas_used, 3 + (1_000 + 3 * 7) + (2_000 + 30 * 11));
+
+        let mut other_store = Store::default();
+        let second = call(&mut other_store, EntryPoint::Sudo, b"msg", limits);
+        assert_eq!(first.gas_used, second.gas_used);
+
+        let query = call(&mut store, EntryPoint::Query, b"", limits);
+        assert_eq!(query.result.unwrap(), 1u32.to_be_bytes().to_vec());
+        assert_eq!(query.gas_used, 1_000 + 3 * 11);
+    }
+
+    #[test]
+    fn test_gas_limit() {
+        let mut store = Store::default();
+
+        let outcome = call(&mut store, EntryPoint::Sudo, b"msg", VmLimits::new(2_000));
+        assert!(matches!(
+            outcome.result,
+            Err(VmError::OutOfGas { limit: 2_000 })
+        ));
+        assert_eq!(outcome.gas_used, 2_000);
+        assert!(store.0.is_empty());
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let mut store = Store::default();
+        let limits = VmLimits::new(100_000).with_memory_limit_pages(1);
+
+        assert!(call(&mut store, EntryPoint::Sudo, b"", limits)
+            .result
+            .is_ok());
+        assert!(matches!(
+            call(&mut store, EntryPoint::Sudo, b"", limits).result,
+            Err(VmError::MemoryLimitExceeded { pages: 2, limit: 1 })
+        ));
+        assert_eq!(limits.memory_limit_bytes(), WASM_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_queries_cannot_write() {
+        let mut store = Store::default();
+        let mut env = VmEnv::new(
+            &mut store,
+            EntryPoint::Query,
+            VmLimits::new(100_000),
+            GasCosts::default(),
+        );
+
+        assert!(matches!(
+            env.set(b"key".to_vec(), b"value".to_vec()),
+            Err(VmError::ImmutableEntryPoint {
+                entry_point: "query"
+            })
+        ));
+        assert!(env.remove(b"key").is_err());
+        assert!(CounterVm.validate_code(b"").is_err());
+    }
+}
@@ -0,0 +1,621 @@
+//! A reference [`WasmVm`] executing the contracts with the `wasmi`
+//! interpreter.
+//!
+//! The contracts follow a minimal ABI of their own rather than the CosmWasm
+//! one. They export their linear memory as `memory`, an `allocate(len: i32) ->
+//! i32` function returning the offset of a buffer of `len` bytes, into which
+//! the message is written, and their entry points as `(msg_ptr: i32, msg_len:
+//! i32) -> i64` functions returning the offset and the length of their
+//! response, packed as `ptr << 32 | len`. They access the contract store
+//! through the following functions of the `env` module:
+//!
+//! - `db_read(key_ptr: i32, key_len: i32, value_ptr: i32, value_cap: i32) ->
+//!   i32` returns the length of the value, or `-1` if there is none, and
+//!   writes the value at `value_ptr` if it fits in `value_cap` bytes.
+//! - `db_write(key_ptr: i32, key_len: i32, value_ptr: i32, value_len: i32)`
+//! - `db_remove(key_ptr: i32, key_len: i32)`
+//!
+//! The executed instructions are metered with the fuel of `wasmi`, and the
+//! fuel left to the instances is cut down to the gas left by the store
+//! accesses, so that a call runs out of fuel exactly when it runs out of gas.
+
+use core::ops::Range;
+
+use ::wasmi::core::{HostError, Trap, TrapCode, ValueType};
+use ::wasmi::errors::{MemoryError, TableError};
+use ::wasmi::{
+    Caller, Config, Engine, Error, Extern, ExternType, Linker, Memory, Module, ResourceLimiter,
+    Store,
+};
+use ibc_primitives::prelude::*;
+
+use super::{EntryPoint, VmEnv, VmError, WasmVm, WASM_PAGE_SIZE};
+
+const HOST_MODULE: &str = "env";
+const MEMORY_EXPORT: &str = "memory";
+const ALLOCATE_EXPORT: &str = "allocate";
+
+const ENTRY_POINTS: [EntryPoint; 4] = [
+    EntryPoint::Instantiate,
+    EntryPoint::Sudo,
+    EntryPoint::Query,
+    EntryPoint::Migrate,
+];
+
+/// A [`WasmVm`] executing the contracts with the `wasmi` interpreter, which
+/// rejects the codes with floating-point instructions.
+#[derive(Debug)]
+pub struct WasmiVm {
+    engine: Engine,
+}
+
+impl WasmiVm {
+    pub fn new() -> Self {
+        let mut config = Config::default();
+        config.floats(false).consume_fuel(true);
+
+        Self {
+            engine: Engine::new(&config),
+        }
+    }
+
+    fn compile(&self, code: &[u8]) -> Result<Module, VmError> {
+        Module::new(&self.engine, code).map_err(|e| VmError::InvalidCode {
+            reason: e.to_string(),
+        })
+    }
+
+    fn linker<'a, 'b>(&self) -> Result<Linker<HostState<'a, 'b>>, VmError> {
+        let mut linker = Linker::new(&self.engine);
+
+        linker
+            .func_wrap(HOST_MODULE, "db_read", db_read)
+            .and_then(|linker| linker.func_wrap(HOST_MODULE, "db_write", db_write))
+            .and_then(|linker| linker.func_wrap(HOST_MODULE, "db_remove", db_remove))
+            .map_err(|e| VmError::Execution {
+                reason: e.to_string(),
+            })?;
+
+        Ok(linker)
+    }
+}
+
+impl Default for WasmiVm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WasmVm for WasmiVm {
+    fn validate_code(&self, code: &[u8]) -> Result<(), VmError> {
+        let module = self.compile(code)?;
+
+        let exports_memory = module.exports().any(|export| {
+            export.name() == MEMORY_EXPORT && matches!(export.ty(), ExternType::Memory(_))
+        });
+
+        if !exports_memory {
+            return Err(VmError::InvalidCode {
+                reason: format!("missing `{MEMORY_EXPORT}` memory export"),
+            });
+        }
+
+        check_func_export(
+            &module,
+            ALLOCATE_EXPORT,
+            &[ValueType::I32],
+            &[ValueType::I32],
+        )?;
+
+        for entry_point in ENTRY_POINTS {
+            check_func_export(
+                &module,
+                entry_point.as_str(),
+                &[ValueType::I32, ValueType::I32],
+                &[ValueType::I64],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn call(
+        &self,
+        code: &[u8],
+        entry_point: EntryPoint,
+        msg: &[u8],
+        env: &mut VmEnv<'_>,
+    ) -> Result<Vec<u8>, VmError> {
+        let module = self.compile(code)?;
+        let linker = self.linker()?;
+
+        let mut store = Store::new(&self.engine, HostState::new(env));
+        store.limiter(limiter);
+
+        let fuel = store.data().fuel_added;
+        store.add_fuel(fuel).map_err(|e| VmError::Execution {
+            reason: e.to_string(),
+        })?;
+
+        let result = run(&linker, &mut store, &module, entry_point, msg);
+
+        let fuel_consumed = store.fuel_consumed().unwrap_or_default();
+        let mut state = store.into_data();
+        state.charge_instructions(fuel_consumed)?;
+
+        result.map_err(|e| state.into_vm_error(e))
+    }
+}
+
+fn check_func_export(
+    module: &Module,
+    name: &str,
+    params: &[ValueType],
+    results: &[ValueType],
+) -> Result<(), VmError> {
+    let exports_func = module.exports().any(|export| {
+        export.name() == name
+            && matches!(
+                export.ty(),
+                ExternType::Func(func_type)
+                    if func_type.params() == params && func_type.results() == results
+            )
+    });
+
+    if !exports_func {
+        return Err(VmError::InvalidCode {
+            reason: format!("missing `{name}` function export of type `{params:?} -> {results:?}`"),
+        });
+    }
+
+    Ok(())
+}
+
+/// Instantiates the contract and calls its entry point with the given
+/// message.
+fn run<'a, 'b>(
+    linker: &Linker<HostState<'a, 'b>>,
+    store: &mut Store<HostState<'a, 'b>>,
+    module: &Module,
+    entry_point: EntryPoint,
+    msg: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let instance = linker
+        .instantiate(&mut *store, module)?
+        .start(&mut *store)?;
+
+    let memory = instance
+        .get_memory(&*store, MEMORY_EXPORT)
+        .ok_or_else(|| Trap::new(format!("missing `{MEMORY_EXPORT}` memory export")))?;
+    let allocate = instance.get_typed_func::<u32, u32>(&*store, ALLOCATE_EXPORT)?;
+    let entry_point = instance.get_typed_func::<(u32, u32), u64>(&*store, entry_point.as_str())?;
+
+    let msg_len = u32::try_from(msg.len()).map_err(|_| Trap::new("message too long"))?;
+    let msg_ptr = allocate.call(&mut *store, msg_len)?;
+    memory.write(&mut *store, msg_ptr as usize, msg)?;
+
+    let response = entry_point.call(&mut *store, (msg_ptr, msg_len))?;
+    let (response_ptr, response_len) = ((response >> 32) as u32, response as u32);
+
+    let response = region(response_ptr, response_len)
+        .and_then(|region| memory.data(&*store).get(region))
+        .ok_or_else(|| Trap::from(TrapCode::MemoryOutOfBounds))?;
+
+    Ok(response.to_vec())
+}
+
+/// The state of the instances, through which the host functions access the
+/// environment of the call.
+struct HostState<'a, 'b> {
+    env: &'a mut VmEnv<'b>,
+    /// The fuel given to the instance, i.e. the instructions the gas limit
+    /// pays for.
+    fuel_added: u64,
+    /// The fuel consumed by the instance which is already charged to the gas
+    /// meter.
+    fuel_charged: u64,
+    /// The error of a growth of the linear memory beyond its limit, which
+    /// `wasmi` reports as a bare trap.
+    memory_error: Option<VmError>,
+}
+
+impl<'a, 'b> HostState<'a, 'b> {
+    fn new(env: &'a mut VmEnv<'b>) -> Self {
+        let mut state = Self {
+            env,
+            fuel_added: 0,
+            fuel_charged: 0,
+            memory_error: None,
+        };
+        state.fuel_added = state.affordable_instructions();
+        state
+    }
+
+    /// Returns the number of instructions the remaining gas pays for.
+    fn affordable_instructions(&self) -> u64 {
+        match self.env.costs().instruction {
+            0 => u64::MAX,
+            cost => self.env.gas_meter().remaining() / cost,
+        }
+    }
+
+    /// Charges the instructions executed since the last charge, given the
+    /// fuel consumed so far.
+    fn charge_instructions(&mut self, fuel_consumed: u64) -> Result<(), VmError> {
+        let executed = fuel_consumed.saturating_sub(self.fuel_charged);
+        self.fuel_charged = fuel_consumed;
+        self.env.charge_instructions(executed)
+    }
+
+    /// Returns the fuel left to the instance beyond the instructions the
+    /// remaining gas pays for.
+    fn excess_fuel(&self, fuel_consumed: u64) -> u64 {
+        self.fuel_added
+            .saturating_sub(fuel_consumed)
+            .saturating_sub(self.affordable_instructions())
+    }
+
+    /// Converts the error of a call, once its executed instructions are
+    /// charged.
+    fn into_vm_error(mut self, error: Error) -> VmError {
+        if let Some(memory_error) = self.memory_error.take() {
+            return memory_error;
+        }
+
+        let trap = match error {
+            Error::Trap(trap) => trap,
+            error => {
+                return VmError::Execution {
+                    reason: error.to_string(),
+                }
+            }
+        };
+
+        if matches!(trap.trap_code(), Some(TrapCode::OutOfFuel)) {
+            // The remaining gas does not pay for the next instructions.
+            let next_instructions = self.affordable_instructions().saturating_add(1);
+
+            return match self.env.charge_instructions(next_instructions) {
+                Err(e) => e,
+                Ok(()) => VmError::OutOfGas {
+                    limit: self.env.gas_meter().limit(),
+                },
+            };
+        }
+
+        let reason = trap.to_string();
+
+        trap.downcast::<VmError>()
+            .unwrap_or(VmError::Execution { reason })
+    }
+}
+
+impl ResourceLimiter for HostState<'_, '_> {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> Result<bool, MemoryError> {
+        let pages = u32::try_from(desired as u64 / WASM_PAGE_SIZE).unwrap_or(u32::MAX);
+
+        if let Err(e) = self.env.check_memory_pages(pages) {
+            self.memory_error = Some(e);
+            return Err(MemoryError::OutOfBoundsGrowth);
+        }
+
+        Ok(maximum.map_or(true, |maximum| desired <= maximum))
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        desired: u32,
+        maximum: Option<u32>,
+    ) -> Result<bool, TableError> {
+        Ok(maximum.map_or(true, |maximum| desired <= maximum))
+    }
+}
+
+fn limiter<'s>(state: &'s mut HostState<'_, '_>) -> &'s mut dyn ResourceLimiter {
+    state
+}
+
+impl HostError for VmError {}
+
+fn db_read(
+    mut caller: Caller<'_, HostState<'_, '_>>,
+    key_ptr: u32,
+    key_len: u32,
+    value_ptr: u32,
+    value_cap: u32,
+) -> Result<i32, Trap> {
+    let key = read_memory(&caller, key_ptr, key_len)?;
+
+    let value = match access_store(&mut caller, |env| env.get(&key))? {
+        Some(value) => value,
+        None => return Ok(-1),
+    };
+
+    let value_len = i32::try_from(value.len()).map_err(|_| Trap::new("value too long"))?;
+
+    if value.len() <= value_cap as usize {
+        memory(&caller)?
+            .write(&mut caller, value_ptr as usize, &value)
+            .map_err(|_| Trap::from(TrapCode::MemoryOutOfBounds))?;
+    }
+
+    Ok(value_len)
+}
+
+fn db_write(
+    mut caller: Caller<'_, HostState<'_, '_>>,
+    key_ptr: u32,
+    key_len: u32,
+    value_ptr: u32,
+    value_len: u32,
+) -> Result<(), Trap> {
+    let key = read_memory(&caller, key_ptr, key_len)?;
+    let value = read_memory(&caller, value_ptr, value_len)?;
+
+    access_store(&mut caller, |env| env.set(key, value))
+}
+
+fn db_remove(
+    mut caller: Caller<'_, HostState<'_, '_>>,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<(), Trap> {
+    let key = read_memory(&caller, key_ptr, key_len)?;
+
+    access_store(&mut caller, |env| env.remove(&key))
+}
+
+/// Accesses the contract store, charging beforehand the instructions
+/// executed since the last charge, and cutting down afterwards the fuel of
+/// the instance to the gas left by the access.
+fn access_store<R>(
+    caller: &mut Caller<'_, HostState<'_, '_>>,
+    access: impl FnOnce(&mut VmEnv<'_>) -> Result<R, VmError>,
+) -> Result<R, Trap> {
+    let fuel_consumed = caller.fuel_consumed().unwrap_or_default();
+
+    let state = caller.data_mut();
+    state.charge_instructions(fuel_consumed)?;
+    let output = access(state.env)?;
+
+    let excess_fuel = state.excess_fuel(fuel_consumed);
+
+    if excess_fuel > 0 {
+        caller
+            .consume_fuel(excess_fuel)
+            .map_err(|e| Trap::new(e.to_string()))?;
+        caller.data_mut().fuel_charged += excess_fuel;
+    }
+
+    Ok(output)
+}
+
+fn memory(caller: &Caller<'_, HostState<'_, '_>>) -> Result<Memory, Trap> {
+    caller
+        .get_export(MEMORY_EXPORT)
+        .and_then(Extern::into_memory)
+        .ok_or_else(|| Trap::new(format!("missing `{MEMORY_EXPORT}` memory export")))
+}
+
+fn read_memory(
+    caller: &Caller<'_, HostState<'_, '_>>,
+    ptr: u32,
+    len: u32,
+) -> Result<Vec<u8>, Trap> {
+    let data = memory(caller)?.data(caller);
+
+    region(ptr, len)
+        .and_then(|region| data.get(region))
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| Trap::from(TrapCode::MemoryOutOfBounds))
+}
+
+fn region(ptr: u32, len: u32) -> Option<Range<usize>> {
+    let start = ptr as usize;
+    let end = start.checked_add(len as usize)?;
+
+    Some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{call_contract, CallOutcome, ContractStore, GasCosts, VmLimits};
+
+    /// A counter contract, incremented by `instantiate` and `sudo` calls, and
+    /// returned by `query` calls as a little-endian `u32`, which loops
+    /// indefinitely on `migrate` calls.
+    ///
+    /// ```wat
+    /// (module
+    ///   (import "env" "db_read" (func $db_read (param i32 i32 i32 i32) (result i32)))
+    ///   (import "env" "db_write" (func $db_write (param i32 i32 i32 i32)))
+    ///   (memory (export "memory") 1)
+    ///   (func (export "allocate") (param i32) (result i32)
+    ///     (i32.const 1024))
+    ///   (func $increment (export "instantiate") (export "sudo") (param i32 i32) (result i64)
+    ///     (if (i32.eq (call $db_read (i32.const 0) (i32.const 7) (i32.const 16) (i32.const 4))
+    ///                 (i32.const -1))
+    ///       (then (i32.store (i32.const 16) (i32.const 0))))
+    ///     (i32.store (i32.const 16) (i32.add (i32.load (i32.const 16)) (i32.const 1)))
+    ///     (call $db_write (i32.const 0) (i32.const 7) (i32.const 16) (i32.const 4))
+    ///     (i64.const 0))
+    ///   (func (export "query") (param i32 i32) (result i64)
+    ///     (drop (call $db_read (i32.const 0) (i32.const 7) (i32.const 16) (i32.const 4)))
+    ///     (i64.const 0x10_0000_0004))
+    ///   (func (export "migrate") (param i32 i32) (result i64)
+    ///     (loop $forever (br $forever))
+    ///     (i64.const 0))
+    ///   (data (i32.const 0) "counter"))
+    /// ```
+    #[rustfmt::skip]
+    const COUNTER: &[u8] = &[
+        // magic and version
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // type
+        0x01, 0x1b, 0x04, 0x60, 0x04, 0x7f, 0x7f, 0x7f, 0x7f, 0x01, 0x7f, 0x60,
+        0x04, 0x7f, 0x7f, 0x7f, 0x7f, 0x00, 0x60, 0x01, 0x7f, 0x01, 0x7f, 0x60,
+        0x02, 0x7f, 0x7f, 0x01, 0x7e,
+        // import
+        0x02, 0x1e, 0x02, 0x03, 0x65, 0x6e, 0x76, 0x07, 0x64, 0x62, 0x5f, 0x72,
+        0x65, 0x61, 0x64, 0x00, 0x00, 0x03, 0x65, 0x6e, 0x76, 0x08, 0x64, 0x62,
+        0x5f, 0x77, 0x72, 0x69, 0x74, 0x65, 0x00, 0x01,
+        // function
+        0x03, 0x05, 0x04, 0x02, 0x03, 0x03, 0x03,
+        // memory
+        0x05, 0x03, 0x01, 0x00, 0x01,
+        // export
+        0x07, 0x3c, 0x06, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00,
+        0x08, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x65, 0x00, 0x02, 0x0b,
+        0x69, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x69, 0x61, 0x74, 0x65, 0x00,
+        0x03, 0x04, 0x73, 0x75, 0x64, 0x6f, 0x00, 0x03, 0x05, 0x71, 0x75, 0x65,
+        0x72, 0x79, 0x00, 0x04, 0x07, 0x6d, 0x69, 0x67, 0x72, 0x61, 0x74, 0x65,
+        0x00, 0x05,
+        // code
+        0x0a, 0x59, 0x04, 0x05, 0x00, 0x41, 0x80, 0x08, 0x0b, 0x32, 0x00, 0x41,
+        0x00, 0x41, 0x07, 0x41, 0x10, 0x41, 0x04, 0x10, 0x00, 0x41, 0x7f, 0x46,
+        0x04, 0x40, 0x41, 0x10, 0x41, 0x00, 0x36, 0x02, 0x00, 0x0b, 0x41, 0x10,
+        0x41, 0x10, 0x28, 0x02, 0x00, 0x41, 0x01, 0x6a, 0x36, 0x02, 0x00, 0x41,
+        0x00, 0x41, 0x07, 0x41, 0x10, 0x41, 0x04, 0x10, 0x01, 0x42, 0x00, 0x0b,
+        0x14, 0x00, 0x41, 0x00, 0x41, 0x07, 0x41, 0x10, 0x41, 0x04, 0x10, 0x00,
+        0x1a, 0x42, 0x84, 0x80, 0x80, 0x80, 0x80, 0x02, 0x0b, 0x09, 0x00, 0x03,
+        0x40, 0x0c, 0x00, 0x0b, 0x42, 0x00, 0x0b,
+        // data
+        0x0b, 0x0d, 0x01, 0x00, 0x41, 0x00, 0x0b, 0x07, 0x63, 0x6f, 0x75, 0x6e,
+        0x74, 0x65, 0x72,
+    ];
+
+    /// ```wat
+    /// (module
+    ///   (func (result f32)
+    ///     (f32.const 0)))
+    /// ```
+    #[rustfmt::skip]
+    const FLOATS: &[u8] = &[
+        // magic and version
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // type
+        0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7d,
+        // function
+        0x03, 0x02, 0x01, 0x00,
+        // code
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x43, 0x00, 0x00, 0x00, 0x00, 0x0b,
+    ];
+
+    #[derive(Default)]
+    struct Store(BTreeMap<Vec<u8>, Vec<u8>>);
+
+    impl ContractStore for Store {
+        fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, VmError> {
+            Ok(self.0.get(key).cloned())
+        }
+
+        fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), VmError> {
+            self.0.insert(key, value);
+            Ok(())
+        }
+
+        fn remove(&mut self, key: &[u8]) -> Result<(), VmError> {
+            self.0.remove(key);
+            Ok(())
+        }
+    }
+
+    fn call(store: &mut Store, entry_point: EntryPoint, limits: VmLimits) -> CallOutcome {
+        call_contract(
+            &WasmiVm::new(),
+            COUNTER,
+            entry_point,
+            b"msg",
+            store,
+            limits,
+            GasCosts::default(),
+        )
+    }
+
+    #[test]
+    fn test_call_counter_contract() {
+        let vm = WasmiVm::new();
+        assert!(vm.validate_code(COUNTER).is_ok());
+
+        let limits = VmLimits::new(100_000);
+        let mut store = Store::default();
+
+        let instantiate = call(&mut store, EntryPoint::Instantiate, limits);
+        assert_eq!(instantiate.result.unwrap(), Vec::<u8>::new());
+
+        let sudo = call(&mut store, EntryPoint::Sudo, limits);
+        assert!(sudo.result.is_ok());
+        // The reads of the missing and of the stored counter differ in
+        // their cost, and in the executed branch.
+        assert_ne!(instantiate.gas_used, sudo.gas_used);
+        assert!(sudo.gas_used > (1_000 + 3 * 11) + (2_000 + 30 * 11));
+
+        let query = call(&mut store, EntryPoint::Query, limits);
+        assert_eq!(query.result.unwrap(), 2u32.to_le_bytes().to_vec());
+        assert_eq!(
+            store.0.get(b"counter".as_slice()),
+            Some(&2u32.to_le_bytes().to_vec())
+        );
+
+        let mut other_store = Store::default();
+        let other_instantiate = call(&mut other_store, EntryPoint::Instantiate, limits);
+        assert_eq!(instantiate.gas_used, other_instantiate.gas_used);
+    }
+
+    #[test]
+    fn test_out_of_gas() {
+        // The gas runs out on the write of the counter.
+        let mut store = Store::default();
+        let outcome = call(&mut store, EntryPoint::Sudo, VmLimits::new(2_500));
+
+        assert!(matches!(
+            outcome.result,
+            Err(VmError::OutOfGas { limit: 2_500 })
+        ));
+        assert_eq!(outcome.gas_used, 2_500);
+        assert!(store.0.is_empty());
+
+        // The gas runs out on the executed instructions.
+        let outcome = call(&mut store, EntryPoint::Migrate, VmLimits::new(100_000));
+
+        assert!(matches!(
+            outcome.result,
+            Err(VmError::OutOfGas { limit: 100_000 })
+        ));
+        assert_eq!(outcome.gas_used, 100_000);
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let mut store = Store::default();
+        let limits = VmLimits::new(100_000).with_memory_limit_pages(0);
+
+        assert!(matches!(
+            call(&mut store, EntryPoint::Sudo, limits).result,
+            Err(VmError::MemoryLimitExceeded { pages: 1, limit: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_code() {
+        let vm = WasmiVm::new();
+
+        // The code is only rejected for its floating-point instructions.
+        assert!(Module::new(&Engine::default(), FLOATS).is_ok());
+        assert!(matches!(
+            vm.compile(FLOATS),
+            Err(VmError::InvalidCode { .. })
+        ));
+        assert!(matches!(
+            vm.validate_code(&FLOATS[..8]),
+            Err(VmError::InvalidCode { .. })
+        ));
+    }
+}
@@ -60,16 +60,18 @@ impl TryFrom<Any> for ConsensusState {
 
     fn try_from(any: Any) -> Result<Self, Self::Error> {
         fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
-            let consensus_state =
-                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+            let consensus_state = Protobuf::<RawConsensusState>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
                     description: e.to_string(),
-                })?;
+                }
+            })?;
             Ok(consensus_state)
         }
         match any.type_url.as_str() {
             WASM_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&any.value),
-            _ => Err(ClientError::Other {
-                description: "type_url does not match".into(),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: any.type_url,
             }),
         }
     }
@@ -0,0 +1,202 @@
+use ibc_client_rollup_types::error::Error;
+use ibc_client_rollup_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as RollupHeader,
+};
+use ibc_core_client::context::client_state::ClientStateExecution;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+use crate::context::{
+    ExecutionContext as RollupExecutionContext, ValidationContext as RollupValidationContext,
+};
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: RollupExecutionContext,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self.inner(), ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    /// Rollup clients are not upgraded through IBC, see
+    /// `ClientStateCommon::verify_upgrade_client`.
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: RollupExecutionContext,
+{
+    let host_timestamp = RollupValidationContext::host_timestamp(ctx)?;
+    let host_height = RollupValidationContext::host_height(ctx)?;
+
+    let rollup_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        into_host_client_state::<E>(client_state.clone())?,
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            client_state.latest_height.revision_number(),
+            client_state.latest_height.revision_height(),
+        ),
+        into_host_consensus_state::<E>(rollup_consensus_state)?,
+    )?;
+
+    ctx.store_update_meta(
+        client_id.clone(),
+        client_state.latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(())
+}
+
+/// Update the host store with the consensus state of the published block,
+/// raising the latest height of the client state if need be.
+///
+/// If a consensus state already exists at the header height, the update is a
+/// no-op and the store is left untouched.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: RollupExecutionContext,
+{
+    let header = RollupHeader::try_from(header)?;
+    let header_height = header.height();
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    if RollupValidationContext::consensus_state(ctx, &path_at_header_height).is_ok() {
+        // if we already had the block installed by a previous relayer
+        // then this is a no-op.
+        return Ok(vec![header_height]);
+    }
+
+    let host_timestamp = RollupValidationContext::host_timestamp(ctx)?;
+    let host_height = RollupValidationContext::host_height(ctx)?;
+
+    let new_client_state = client_state.clone().with_header(&header);
+    let new_consensus_state = ConsensusStateType::from(header);
+
+    ctx.store_consensus_state(
+        path_at_header_height,
+        into_host_consensus_state::<E>(new_consensus_state)?,
+    )?;
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        into_host_client_state::<E>(new_client_state)?,
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        header_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(vec![header_height])
+}
+
+/// Commit a frozen client state, which was frozen as a result of having exhibited
+/// misbehaviour, to the store.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    _client_message: Any,
+) -> Result<(), ClientError>
+where
+    E: RollupExecutionContext,
+{
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        into_host_client_state::<E>(client_state.clone().with_frozen_height(Height::min(0)))?,
+    )?;
+
+    Ok(())
+}
+
+/// Converts a rollup client state into the host's client state type.
+fn into_host_client_state<E>(
+    client_state: ClientStateType,
+) -> Result<<E as RollupExecutionContext>::HostClientState, ClientError>
+where
+    E: RollupExecutionContext,
+{
+    <E as RollupExecutionContext>::HostClientState::try_from(client_state).map_err(Into::into)
+}
+
+/// Converts a rollup consensus state into the host's consensus state type.
+fn into_host_consensus_state<E>(
+    consensus_state: ConsensusStateType,
+) -> Result<<E as RollupExecutionContext>::HostConsensusState, ClientError>
+where
+    E: RollupExecutionContext,
+{
+    <E as RollupExecutionContext>::HostConsensusState::try_from(consensus_state).map_err(Into::into)
+}
@@ -0,0 +1,742 @@
+use ibc_client_rollup_types::error::Error;
+use ibc_client_rollup_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as RollupHeader,
+    Misbehaviour as RollupMisbehaviour, ROLLUP_HEADER_TYPE_URL, ROLLUP_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_commitment_types::host_functions::{DefaultHostFunctions, HostFunctions};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState as RollupConsensusState;
+use crate::context::{DaCommitment, ValidationContext as RollupValidationContext};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: RollupValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message::<V, DefaultHostFunctions>(
+            self.inner(),
+            ctx,
+            client_id,
+            client_message,
+        )
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn check_for_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_redundant_update(self.inner(), ctx, client_id, header)
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        status(self.inner(), ctx, client_id)
+    }
+}
+
+/// Verify the client message as part of the client state validation process,
+/// hashing with the host functions `H`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: RollupValidationContext,
+    H: HostFunctions,
+{
+    match client_message.type_url.as_str() {
+        ROLLUP_HEADER_TYPE_URL => {
+            let header = RollupHeader::try_from(client_message)?;
+            verify_header::<V, H>(client_state, ctx, client_id, &header)
+        }
+        ROLLUP_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = RollupMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour::<V, H>(client_state, ctx, client_id, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verify that the block of the header was published in the namespace of the
+/// rollup, in a DA block known to the client of the DA layer, and that the
+/// fraud-proof window of the rollup has elapsed since the publication.
+pub fn verify_header<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    _client_id: &ClientId,
+    header: &RollupHeader,
+) -> Result<(), ClientError>
+where
+    V: RollupValidationContext,
+    H: HostFunctions,
+{
+    let da_commitment = verify_publication::<V, H>(client_state, ctx, header)?;
+
+    let now = ctx.host_timestamp()?;
+
+    if !now.has_elapsed_since(&da_commitment.timestamp, client_state.fraud_proof_window) {
+        return Err(Error::FraudProofWindowNotElapsed {
+            published_at: da_commitment.timestamp,
+            window: client_state.fraud_proof_window,
+            now,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Verify that both headers of the misbehaviour are distinct blocks published
+/// at the same rollup height in DA blocks known to the client of the DA layer.
+///
+/// The fraud-proof window does not apply to misbehaviour, which freezes the
+/// client as soon as the conflicting publications are proven.
+pub fn verify_misbehaviour<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    _client_id: &ClientId,
+    misbehaviour: &RollupMisbehaviour,
+) -> Result<(), ClientError>
+where
+    V: RollupValidationContext,
+    H: HostFunctions,
+{
+    misbehaviour.validate_basic()?;
+
+    verify_publication::<V, H>(client_state, ctx, misbehaviour.header1())?;
+    verify_publication::<V, H>(client_state, ctx, misbehaviour.header2())?;
+
+    Ok(())
+}
+
+/// Verifies the publication of the block of the header on the DA layer,
+/// returning the commitment of the DA block it was published in.
+fn verify_publication<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    header: &RollupHeader,
+) -> Result<DaCommitment, ClientError>
+where
+    V: RollupValidationContext,
+    H: HostFunctions,
+{
+    let latest_revision = client_state.latest_height.revision_number();
+    let header_revision = header.height().revision_number();
+
+    if latest_revision != header_revision {
+        return Err(Error::MismatchHeightRevisions {
+            latest_revision,
+            header_revision,
+        }
+        .into());
+    }
+
+    let da_client_id = &client_state.da_client_id;
+    let da_status = ctx.da_client_status(da_client_id)?;
+
+    if !da_status.is_active() {
+        return Err(Error::DaClientNotActive {
+            da_client_id: da_client_id.clone(),
+            status: da_status,
+        }
+        .into());
+    }
+
+    let da_commitment = ctx.da_commitment(da_client_id, &header.da_height)?;
+
+    header.verify_publication::<H>(&client_state.namespace, &da_commitment.data_root)?;
+
+    if header.timestamp() > da_commitment.timestamp {
+        return Err(Error::BlockTimestampAfterPublication {
+            block_timestamp: header.timestamp(),
+            da_timestamp: da_commitment.timestamp,
+        }
+        .into());
+    }
+
+    Ok(da_commitment)
+}
+
+/// Check for misbehaviour on the client state as part of the client state
+/// validation process.
+///
+/// A header is evidence of misbehaviour if a different consensus state was
+/// installed at its height, i.e. if the rollup published two blocks at the
+/// same height. A verified misbehaviour is evidence of misbehaviour.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_misbehaviour<V>(
+    _client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: RollupValidationContext,
+{
+    match client_message.type_url.as_str() {
+        ROLLUP_HEADER_TYPE_URL => {
+            let header = RollupHeader::try_from(client_message)?;
+            let header_height = header.height();
+
+            let path_at_header_height = ClientConsensusStatePath::new(
+                client_id.clone(),
+                header_height.revision_number(),
+                header_height.revision_height(),
+            );
+
+            let existing_consensus_state = match ctx.consensus_state(&path_at_header_height) {
+                Ok(existing_consensus_state) => existing_consensus_state,
+                Err(_) => return Ok(false),
+            };
+
+            let existing_consensus_state: RollupConsensusState = existing_consensus_state
+                .try_into()
+                .map_err(|err| ClientError::ConsensusStateConversionFailed {
+                    client_id: client_id.clone(),
+                    description: err.to_string(),
+                })?;
+
+            Ok(existing_consensus_state.inner() != &ConsensusStateType::from(header))
+        }
+        ROLLUP_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Check whether the consensus state of the given header was already
+/// installed by a previous update, in which case applying it is a no-op.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_redundant_update<V>(
+    _client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<bool, ClientError>
+where
+    V: RollupValidationContext,
+{
+    let header = RollupHeader::try_from(header)?;
+    let header_height = header.height();
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    let existing_consensus_state = match ctx.consensus_state(&path_at_header_height) {
+        Ok(existing_consensus_state) => existing_consensus_state,
+        Err(_) => return Ok(false),
+    };
+
+    let existing_consensus_state: RollupConsensusState = existing_consensus_state
+        .try_into()
+        .map_err(|err| ClientError::ConsensusStateConversionFailed {
+            client_id: client_id.clone(),
+            description: err.to_string(),
+        })?;
+
+    Ok(existing_consensus_state.inner() == &ConsensusStateType::from(header))
+}
+
+/// Query the status of the client state.
+///
+/// A client that is not frozen shares the status of the client of the DA
+/// layer it depends on, as no rollup block can be verified while the DA
+/// client is not active.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn status<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    _client_id: &ClientId,
+) -> Result<Status, ClientError>
+where
+    V: RollupValidationContext,
+{
+    if client_state.is_frozen() {
+        return Ok(Status::Frozen);
+    }
+
+    let da_status = ctx.da_client_status(&client_state.da_client_id)?;
+
+    if !da_status.is_active() {
+        return Ok(da_status);
+    }
+
+    Ok(Status::Active)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::time::Duration;
+
+    use ibc_client_rollup_types::proto::v1::RollupBlock as RawRollupBlock;
+    use ibc_client_rollup_types::{
+        BinaryMerkleProof, Namespace, NamespaceProof, NamespacedHash, RollupBlock, RowProof,
+        ShareProof, NAMESPACE_SIZE, SHARE_SIZE,
+    };
+    use ibc_core_client::context::ClientValidationContext;
+    use ibc_core_client::types::Height;
+    use ibc_core_commitment_types::commitment::CommitmentRoot;
+    use ibc_core_commitment_types::host_functions::HostFunctionsProvider;
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::ChainId;
+    use ibc_primitives::proto::Protobuf;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    type H = DefaultHostFunctions;
+
+    /// A host tracking a single rollup client, whose DA client knows the DA
+    /// blocks of the given commitments.
+    struct Ctx {
+        now: Cell<Timestamp>,
+        da_status: Cell<fn() -> Status>,
+        da_commitments: Vec<(Height, DaCommitment)>,
+        consensus_state: Option<ConsensusStateType>,
+    }
+
+    impl Ctx {
+        fn new(da_commitments: Vec<(Height, DaCommitment)>) -> Self {
+            Self {
+                now: Cell::new(timestamp(1_200)),
+                da_status: Cell::new(|| Status::Active),
+                da_commitments,
+                consensus_state: None,
+            }
+        }
+    }
+
+    impl ClientValidationContext for Ctx {
+        fn update_meta(
+            &self,
+            client_id: &ClientId,
+            height: &Height,
+        ) -> Result<(Timestamp, Height), ContextError> {
+            Err(ClientError::UpdateMetaDataNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            }
+            .into())
+        }
+    }
+
+    impl RollupValidationContext for Ctx {
+        type ConversionError = core::convert::Infallible;
+        type AnyConsensusState = RollupConsensusState;
+
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            Ok(self.now.get())
+        }
+
+        fn host_height(&self) -> Result<Height, ContextError> {
+            Ok(Height::new(0, 1).expect("valid height"))
+        }
+
+        fn consensus_state(
+            &self,
+            path: &ClientConsensusStatePath,
+        ) -> Result<Self::AnyConsensusState, ContextError> {
+            let height = Height::new(path.revision_number, path.revision_height)?;
+
+            self.consensus_state
+                .clone()
+                .filter(|_| height == block_height())
+                .map(Into::into)
+                .ok_or_else(|| {
+                    ClientError::ConsensusStateNotFound {
+                        client_id: path.client_id.clone(),
+                        height,
+                    }
+                    .into()
+                })
+        }
+
+        fn da_client_status(&self, _da_client_id: &ClientId) -> Result<Status, ContextError> {
+            Ok(self.da_status.get()())
+        }
+
+        fn da_commitment(
+            &self,
+            da_client_id: &ClientId,
+            da_height: &Height,
+        ) -> Result<DaCommitment, ContextError> {
+            self.da_commitments
+                .iter()
+                .find(|(height, _)| height == da_height)
+                .map(|(_, da_commitment)| *da_commitment)
+                .ok_or_else(|| {
+                    ClientError::ConsensusStateNotFound {
+                        client_id: da_client_id.clone(),
+                        height: *da_height,
+                    }
+                    .into()
+                })
+        }
+    }
+
+    fn timestamp(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).expect("valid timestamp")
+    }
+
+    fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
+        H::sha2_256(&[&[0], leaf].concat())
+    }
+
+    fn namespace(id: u8) -> Namespace {
+        let mut namespace = [0; NAMESPACE_SIZE];
+        namespace[NAMESPACE_SIZE - 1] = id;
+        Namespace::new(namespace)
+    }
+
+    fn block_height() -> Height {
+        Height::new(1, 11).expect("valid height")
+    }
+
+    fn client_id() -> ClientId {
+        ClientId::new("rollup-da", 0).expect("valid client id")
+    }
+
+    /// Returns a client of the rollup of the namespace `1`, with a fraud-proof
+    /// window of 100 seconds.
+    fn client_state() -> ClientStateType {
+        ClientStateType::new(
+            ChainId::new("rollup-1").expect("valid chain id"),
+            Height::new(1, 10).expect("valid height"),
+            ClientId::new("07-tendermint", 0).expect("valid client id"),
+            namespace(1),
+            Duration::from_secs(100),
+            ProofSpecs::cosmos(),
+        )
+        .expect("valid client state")
+    }
+
+    /// Returns a header publishing the block of the given state root alone in
+    /// a square of a single share at the given DA height, along with the
+    /// commitment of the DA block, published at 1010 seconds.
+    fn header(
+        namespace: &Namespace,
+        state_root: u8,
+        da_height: u64,
+    ) -> (RollupHeader, (Height, DaCommitment)) {
+        let block = RollupBlock {
+            height: block_height(),
+            timestamp: timestamp(1_000),
+            state_root: CommitmentRoot::from_bytes(&[state_root; 32]),
+        };
+        let blob = Protobuf::<RawRollupBlock>::encode_vec(block.clone());
+
+        let mut share = namespace.as_bytes().to_vec();
+        share.push(1);
+        share.extend_from_slice(&(blob.len() as u32).to_be_bytes());
+        share.extend_from_slice(&blob);
+        share.resize(SHARE_SIZE, 0);
+
+        let row_root = NamespacedHash::leaf::<H>(&share).expect("valid share");
+        let data_root = leaf_hash(&row_root.to_bytes());
+        let da_height = Height::new(0, da_height).expect("valid height");
+
+        let header = RollupHeader {
+            block,
+            da_height,
+            share_proof: ShareProof {
+                data: vec![share],
+                share_proofs: vec![NamespaceProof {
+                    start: 0,
+                    end: 1,
+                    nodes: vec![],
+                }],
+                namespace: *namespace,
+                row_proof: RowProof {
+                    row_roots: vec![row_root],
+                    proofs: vec![BinaryMerkleProof {
+                        total: 1,
+                        index: 0,
+                        leaf_hash: data_root,
+                        aunts: vec![],
+                    }],
+                    start_row: 0,
+                    end_row: 0,
+                },
+            },
+        };
+        let da_commitment = DaCommitment {
+            data_root,
+            timestamp: timestamp(1_010),
+        };
+
+        (header, (da_height, da_commitment))
+    }
+
+    fn is_client_specific(result: Result<(), ClientError>, description: &str) -> bool {
+        matches!(
+            result,
+            Err(ClientError::ClientSpecific { description: actual }) if actual.contains(description)
+        )
+    }
+
+    #[test]
+    fn test_verify_header_after_fraud_proof_window() {
+        let client_state = client_state();
+        let (header, da_commitment) = header(&namespace(1), 1, 5);
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        ctx.now.set(timestamp(1_050));
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "fraud-proof window",
+        ));
+
+        ctx.now.set(timestamp(1_200));
+        verify_header::<_, H>(&client_state, &ctx, &client_id(), &header)
+            .expect("the fraud-proof window has elapsed");
+
+        let mut tampered = header;
+        tampered.block.state_root = CommitmentRoot::from_bytes(&[2; 32]);
+        assert!(verify_header::<_, H>(&client_state, &ctx, &client_id(), &tampered).is_err());
+    }
+
+    #[test]
+    fn test_verify_header_without_fraud_proof_window() {
+        let client_state = ClientStateType {
+            fraud_proof_window: Duration::ZERO,
+            ..client_state()
+        };
+        let (header, da_commitment) = header(&namespace(1), 1, 5);
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        // The block is accepted as soon as it is published.
+        ctx.now.set(da_commitment.1.timestamp);
+        verify_header::<_, H>(&client_state, &ctx, &client_id(), &header)
+            .expect("no fraud-proof window");
+    }
+
+    #[test]
+    fn test_verify_header_inactive_da_client() {
+        let client_state = client_state();
+        let (header, da_commitment) = header(&namespace(1), 1, 5);
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        ctx.da_status.set(|| Status::Frozen);
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "is not active",
+        ));
+
+        ctx.da_status.set(|| Status::Expired);
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "is not active",
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_unknown_da_block() {
+        let client_state = client_state();
+        let (header, _) = header(&namespace(1), 1, 5);
+        let ctx = Ctx::new(vec![]);
+
+        assert!(verify_header::<_, H>(&client_state, &ctx, &client_id(), &header).is_err());
+    }
+
+    #[test]
+    fn test_verify_header_namespace_mismatch() {
+        let client_state = client_state();
+        // The block is validly published, but under the namespace of another
+        // rollup.
+        let (header, da_commitment) = header(&namespace(2), 1, 5);
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "namespace",
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_timestamp_after_publication() {
+        let client_state = client_state();
+        let (header, (da_height, mut da_commitment)) = header(&namespace(1), 1, 5);
+        da_commitment.timestamp = timestamp(999);
+        let ctx = Ctx::new(vec![(da_height, da_commitment)]);
+
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "is after the timestamp",
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_revision_mismatch() {
+        let client_state = client_state();
+        let (mut header, da_commitment) = header(&namespace(1), 1, 5);
+        header.block.height = Height::new(2, 11).expect("valid height");
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        assert!(is_client_specific(
+            verify_header::<_, H>(&client_state, &ctx, &client_id(), &header),
+            "does not match the revision",
+        ));
+    }
+
+    #[test]
+    fn test_verify_misbehaviour() {
+        let client_state = client_state();
+        let (header1, da_commitment1) = header(&namespace(1), 1, 5);
+        let (header2, da_commitment2) = header(&namespace(1), 2, 6);
+        let ctx = Ctx::new(vec![da_commitment1, da_commitment2]);
+
+        // The fraud-proof window does not delay the freezing of the client.
+        ctx.now.set(timestamp(1_010));
+        let misbehaviour = RollupMisbehaviour::new(client_id(), header1.clone(), header2.clone());
+        verify_misbehaviour::<_, H>(&client_state, &ctx, &client_id(), &misbehaviour)
+            .expect("valid misbehaviour");
+        assert!(
+            check_for_misbehaviour(&client_state, &ctx, &client_id(), misbehaviour.into())
+                .expect("valid misbehaviour")
+        );
+
+        let same_blocks = RollupMisbehaviour::new(client_id(), header1.clone(), header1.clone());
+        assert!(
+            verify_misbehaviour::<_, H>(&client_state, &ctx, &client_id(), &same_blocks).is_err()
+        );
+
+        let mut other_height = header2.clone();
+        other_height.block.height = Height::new(1, 12).expect("valid height");
+        let other_heights = RollupMisbehaviour::new(client_id(), header1.clone(), other_height);
+        assert!(
+            verify_misbehaviour::<_, H>(&client_state, &ctx, &client_id(), &other_heights).is_err()
+        );
+
+        // Both blocks must be published.
+        let ctx = Ctx::new(vec![da_commitment1]);
+        let unpublished = RollupMisbehaviour::new(client_id(), header1, header2);
+        assert!(
+            verify_misbehaviour::<_, H>(&client_state, &ctx, &client_id(), &unpublished).is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_for_misbehaviour_and_redundant_update() {
+        let client_state = client_state();
+        let (header, da_commitment) = header(&namespace(1), 1, 5);
+        let (conflicting_header, _) = self::header(&namespace(1), 2, 6);
+        let mut ctx = Ctx::new(vec![da_commitment]);
+
+        assert!(
+            !check_for_misbehaviour(&client_state, &ctx, &client_id(), header.clone().into())
+                .expect("valid header")
+        );
+        assert!(!check_for_redundant_update(
+            &client_state,
+            &ctx,
+            &client_id(),
+            header.clone().into()
+        )
+        .expect("valid header"));
+
+        ctx.consensus_state = Some(header.clone().into());
+        assert!(
+            !check_for_misbehaviour(&client_state, &ctx, &client_id(), header.clone().into())
+                .expect("valid header")
+        );
+        assert!(
+            check_for_redundant_update(&client_state, &ctx, &client_id(), header.into())
+                .expect("valid header")
+        );
+
+        // A different block at the same height is not redundant, but evidence
+        // of misbehaviour.
+        assert!(check_for_misbehaviour(
+            &client_state,
+            &ctx,
+            &client_id(),
+            conflicting_header.clone().into()
+        )
+        .expect("valid header"));
+        assert!(!check_for_redundant_update(
+            &client_state,
+            &ctx,
+            &client_id(),
+            conflicting_header.into()
+        )
+        .expect("valid header"));
+    }
+
+    #[test]
+    fn test_status() {
+        let ctx = Ctx::new(vec![]);
+
+        assert_eq!(
+            status(&client_state(), &ctx, &client_id()).expect("known status"),
+            Status::Active
+        );
+
+        ctx.da_status.set(|| Status::Expired);
+        assert_eq!(
+            status(&client_state(), &ctx, &client_id()).expect("known status"),
+            Status::Expired
+        );
+
+        let frozen = client_state().with_frozen_height(Height::min(0));
+        assert_eq!(
+            status(&frozen, &ctx, &client_id()).expect("known status"),
+            Status::Frozen
+        );
+    }
+
+    #[test]
+    fn test_verify_client_message_unknown_type() {
+        let (header, da_commitment) = header(&namespace(1), 1, 5);
+        let ctx = Ctx::new(vec![da_commitment]);
+
+        let mut client_message = Any::from(header);
+        client_message.type_url = "/ibc.lightclients.unknown.v1.Header".to_string();
+
+        assert!(matches!(
+            verify_client_message::<_, H>(&client_state(), &ctx, &client_id(), client_message),
+            Err(ClientError::InvalidUpdateClientMessage)
+        ));
+    }
+}
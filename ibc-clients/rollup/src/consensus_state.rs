@@ -0,0 +1,77 @@
+//! This module includes trait implementations for the
+//! `ibc_client_rollup_types::ConsensusState` type. It implements the
+//! `ConsensusStateTrait` for `ConsensusState` by defining a newtype wrapper in
+//! order to circumvent Rust's orphan rule, which disallows foreign traits from
+//! being implemented on foreign types. This module also includes some trait
+//! implementations that serve to pass through traits implemented on the wrapped
+//! `ConsensusState` type.
+
+use ibc_client_rollup_types::error::Error;
+use ibc_client_rollup_types::proto::v1::ConsensusState as RawRollupConsensusState;
+use ibc_client_rollup_types::ConsensusState as ConsensusStateType;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Newtype wrapper around the `ConsensusState` type imported from the
+/// `ibc-client-rollup-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ConsensusState` type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub struct ConsensusState(ConsensusStateType);
+
+impl ConsensusState {
+    pub fn inner(&self) -> &ConsensusStateType {
+        &self.0
+    }
+}
+
+impl Protobuf<RawRollupConsensusState> for ConsensusState {}
+
+impl TryFrom<RawRollupConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawRollupConsensusState) -> Result<Self, Self::Error> {
+        Ok(Self(ConsensusStateType::try_from(raw)?))
+    }
+}
+
+impl From<ConsensusState> for RawRollupConsensusState {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ConsensusStateType::try_from(raw)?))
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.0.into()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.0.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.0.timestamp
+    }
+
+    fn encode_vec(self) -> Vec<u8> {
+        <Self as Protobuf<Any>>::encode_vec(self)
+    }
+}
@@ -0,0 +1,118 @@
+//! Defines the context traits that hosts implement in order to use the rollup
+//! light client.
+//!
+//! On top of the consensus states of the rollup clients, hosts expose the
+//! commitments of the DA blocks known to the clients of the DA layer the
+//! rollup clients depend on, e.g. the data roots of the Celestia headers
+//! recorded by the updates of a Tendermint client of Celestia. Hosts only need
+//! to implement [`ValidationContext`], on top of the generic client contexts.
+//! [`ExecutionContext`] is then implemented automatically, as long as the
+//! host's client and consensus state types can be converted from the rollup
+//! ones.
+
+use ibc_client_rollup_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, DIGEST_SIZE,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use crate::consensus_state::ConsensusState as RollupConsensusState;
+
+/// The commitment of a block of the DA layer to the data published in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DaCommitment {
+    /// The root of the binary Merkle tree of the row and column roots of the
+    /// extended square of the block.
+    pub data_root: [u8; DIGEST_SIZE],
+    /// The timestamp of the block.
+    pub timestamp: Timestamp,
+}
+
+/// Client's context required during validation.
+pub trait ValidationContext: ClientValidationContext {
+    type ConversionError: ToString;
+    type AnyConsensusState: TryInto<RollupConsensusState, Error = Self::ConversionError>;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Retrieve the consensus state for the given client ID at the specified
+    /// height.
+    ///
+    /// Returns an error if no such state exists.
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError>;
+
+    /// Returns the status of the given client of the DA layer.
+    fn da_client_status(&self, da_client_id: &ClientId) -> Result<Status, ContextError>;
+
+    /// Returns the commitment of the DA block at the given height, as known to
+    /// the given client of the DA layer.
+    ///
+    /// Returns an error if the client does not know the block.
+    fn da_commitment(
+        &self,
+        da_client_id: &ClientId,
+        da_height: &Height,
+    ) -> Result<DaCommitment, ContextError>;
+}
+
+/// Client's context required during execution.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`ValidationContext`] and [`ClientExecutionContext`], provided that the
+/// host's client and consensus state types can be converted from the rollup
+/// ones.
+pub trait ExecutionContext:
+    ValidationContext
+    + ClientExecutionContext<
+        AnyClientState = <Self as ExecutionContext>::HostClientState,
+        AnyConsensusState = <Self as ExecutionContext>::HostConsensusState,
+    >
+{
+    /// The client state type of the host.
+    type HostClientState: TryFrom<ClientStateType, Error = Self::ClientStateConversionError>;
+
+    /// The error returned when converting a rollup client state into the
+    /// host's client state type.
+    type ClientStateConversionError: Into<ClientError>;
+
+    /// The consensus state type of the host.
+    type HostConsensusState: TryFrom<
+        ConsensusStateType,
+        Error = Self::ConsensusStateConversionError,
+    >;
+
+    /// The error returned when converting a rollup consensus state into the
+    /// host's consensus state type.
+    type ConsensusStateConversionError: Into<ClientError>;
+}
+
+impl<T> ExecutionContext for T
+where
+    T: ValidationContext + ClientExecutionContext,
+    <T as ClientExecutionContext>::AnyClientState: TryFrom<ClientStateType>,
+    <<T as ClientExecutionContext>::AnyClientState as TryFrom<ClientStateType>>::Error:
+        Into<ClientError>,
+    <T as ClientExecutionContext>::AnyConsensusState: TryFrom<ConsensusStateType>,
+    <<T as ClientExecutionContext>::AnyConsensusState as TryFrom<ConsensusStateType>>::Error:
+        Into<ClientError>,
+{
+    type HostClientState = <T as ClientExecutionContext>::AnyClientState;
+    type ClientStateConversionError =
+        <<T as ClientExecutionContext>::AnyClientState as TryFrom<ClientStateType>>::Error;
+    type HostConsensusState = <T as ClientExecutionContext>::AnyConsensusState;
+    type ConsensusStateConversionError =
+        <<T as ClientExecutionContext>::AnyConsensusState as TryFrom<ConsensusStateType>>::Error;
+}
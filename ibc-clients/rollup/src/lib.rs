@@ -0,0 +1,37 @@
+//! Rollup light client implementation, tracking the rollups that publish their
+//! blocks on a data availability (DA) layer such as Celestia, along with
+//! re-exporting data structures and implementations of IBC core client
+//! module.
+//!
+//! A rollup client depends on a client of the DA layer, e.g. a Tendermint
+//! client of Celestia, identified by its client state: the rollup blocks are
+//! only accepted once their publication in the namespace of the rollup is
+//! proven against the data root of a DA block known to the host, and the
+//! fraud-proof window of the rollup has elapsed since.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+
+pub const ROLLUP_CLIENT_TYPE: &str = "rollup-da";
+
+/// Re-export of rollup light client data structures from `ibc-client-rollup-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_rollup_types::*;
+}
@@ -0,0 +1,274 @@
+//! Contains the implementation of the rollup `ClientState` domain type.
+
+use core::cmp::max;
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::proto::v1::Height as RawHeight;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::specs::ProofSpecs;
+use ibc_core_host_types::identifiers::{ChainId, ClientId};
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::header::Header;
+use crate::namespace::Namespace;
+use crate::proto::v1::ClientState as RawRollupClientState;
+
+pub const ROLLUP_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.rollup.v1.ClientState";
+
+/// Contains the core implementation of the rollup light client.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    pub rollup_id: ChainId,
+    pub latest_height: Height,
+    /// The client of the DA layer on which the rollup publishes its blocks.
+    pub da_client_id: ClientId,
+    /// The namespace under which the rollup publishes its blocks.
+    pub namespace: Namespace,
+    /// The time during which the published blocks may be challenged before
+    /// the client accepts them, zero for the rollups without fraud proofs.
+    pub fraud_proof_window: Duration,
+    /// The proof specs of the state commitments of the rollup.
+    pub proof_specs: ProofSpecs,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn new(
+        rollup_id: ChainId,
+        latest_height: Height,
+        da_client_id: ClientId,
+        namespace: Namespace,
+        fraud_proof_window: Duration,
+        proof_specs: ProofSpecs,
+    ) -> Result<Self, Error> {
+        let client_state = Self {
+            rollup_id,
+            latest_height,
+            da_client_id,
+            namespace,
+            fraud_proof_window,
+            proof_specs,
+            frozen_height: None,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+
+    pub fn with_header(self, header: &Header) -> Self {
+        Self {
+            latest_height: max(header.height(), self.latest_height),
+            ..self
+        }
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.namespace == Namespace::PARITY_SHARES {
+            return Err(Error::InvalidNamespace {
+                reason: "the namespace of the parity shares is reserved".to_string(),
+            });
+        }
+
+        if self.latest_height.revision_number() != self.rollup_id.revision_number() {
+            return Err(Error::InvalidRawClientState {
+                reason: format!(
+                    "the revision of the latest height {} does not match the rollup {}",
+                    self.latest_height, self.rollup_id
+                ),
+            });
+        }
+
+        self.proof_specs.validate()?;
+
+        Ok(())
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+}
+
+impl Protobuf<RawRollupClientState> for ClientState {}
+
+impl TryFrom<RawRollupClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawRollupClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing latest height".to_string(),
+            })?
+            .try_into()
+            .map_err(|e: ClientError| Error::InvalidRawClientState {
+                reason: format!("invalid latest height: {e}"),
+            })?;
+
+        let fraud_proof_window = raw
+            .fraud_proof_window
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing fraud-proof window".to_string(),
+            })?
+            .try_into()
+            .map_err(|_| Error::InvalidRawClientState {
+                reason: "negative fraud-proof window".to_string(),
+            })?;
+
+        // As for the Tendermint clients, a `frozen_height` of `0` means "not
+        // frozen".
+        let frozen_height = raw
+            .frozen_height
+            .and_then(|height| Height::try_from(height).ok());
+
+        Ok(Self {
+            rollup_id: ChainId::from_str(&raw.rollup_id)?,
+            latest_height,
+            da_client_id: ClientId::from_str(&raw.da_client_id)?,
+            namespace: Namespace::from_bytes(&raw.namespace)?,
+            fraud_proof_window,
+            proof_specs: raw.proof_specs.into(),
+            frozen_height,
+        })
+    }
+}
+
+impl From<ClientState> for RawRollupClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            rollup_id: value.rollup_id.to_string(),
+            latest_height: Some(value.latest_height.into()),
+            da_client_id: value.da_client_id.to_string(),
+            namespace: value.namespace.as_bytes().to_vec(),
+            fraud_proof_window: Some(value.fraud_proof_window.into()),
+            proof_specs: value.proof_specs.into(),
+            frozen_height: Some(value.frozen_height.map(|height| height.into()).unwrap_or(
+                RawHeight {
+                    revision_number: 0,
+                    revision_height: 0,
+                },
+            )),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawRollupClientState>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: ROLLUP_CLIENT_STATE_TYPE_URL.to_string(),
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            ROLLUP_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: ROLLUP_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawRollupClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::namespace::NAMESPACE_SIZE;
+
+    fn client_state() -> ClientState {
+        let mut namespace = [0; NAMESPACE_SIZE];
+        namespace[NAMESPACE_SIZE - 1] = 1;
+
+        ClientState::new(
+            ChainId::new("rollup-1").unwrap(),
+            Height::new(1, 10).unwrap(),
+            ClientId::new("07-tendermint", 0).unwrap(),
+            Namespace::new(namespace),
+            Duration::from_secs(100),
+            ProofSpecs::cosmos(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_client_state_any_round_trip() {
+        let client_state = client_state();
+        assert_eq!(
+            ClientState::try_from(Any::from(client_state.clone())).unwrap(),
+            client_state
+        );
+
+        let frozen = client_state.with_frozen_height(Height::min(0));
+        assert_eq!(
+            ClientState::try_from(Any::from(frozen.clone())).unwrap(),
+            frozen
+        );
+    }
+
+    #[test]
+    fn test_client_state_any_rejections() {
+        let mut any = Any::from(client_state());
+        any.type_url = "/ibc.lightclients.tendermint.v1.ClientState".to_string();
+        assert!(matches!(
+            ClientState::try_from(any),
+            Err(ClientError::UnknownClientStateType { .. })
+        ));
+
+        let mut any = Any::from(client_state());
+        any.value = vec![0xff];
+        assert!(matches!(
+            ClientState::try_from(any),
+            Err(ClientError::ProtoDecodingFailed { .. })
+        ));
+
+        let mut raw = RawRollupClientState::from(client_state());
+        raw.latest_height = None;
+        assert!(ClientState::try_from(raw).is_err());
+
+        let mut raw = RawRollupClientState::from(client_state());
+        raw.namespace.pop();
+        assert!(ClientState::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn test_client_state_validation() {
+        let reserved = ClientState {
+            namespace: Namespace::PARITY_SHARES,
+            ..client_state()
+        };
+        assert!(reserved.validate().is_err());
+
+        let other_revision = ClientState {
+            latest_height: Height::new(2, 10).unwrap(),
+            ..client_state()
+        };
+        assert!(other_revision.validate().is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! Defines the rollup light client's `ConsensusState` type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::header::Header;
+use crate::proto::v1::ConsensusState as RawConsensusState;
+
+pub const ROLLUP_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.rollup.v1.ConsensusState";
+
+/// Defines the rollup light client's consensus state
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    /// The state root of the rollup block.
+    pub root: CommitmentRoot,
+    /// The height of the DA block in which the rollup block was published.
+    pub da_height: Height,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp, da_height: Height) -> Self {
+        Self {
+            timestamp,
+            root,
+            da_height,
+        }
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: format!("invalid timestamp: {e}"),
+            }
+        })?;
+
+        let da_height = raw
+            .da_height
+            .ok_or_else(|| Error::InvalidRawConsensusState {
+                reason: "missing DA height".to_string(),
+            })?
+            .try_into()
+            .map_err(|e: ClientError| Error::InvalidRawConsensusState {
+                reason: format!("invalid DA height: {e}"),
+            })?;
+
+        Ok(Self {
+            timestamp,
+            root: raw.root.into(),
+            da_height,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root.into_vec(),
+            da_height: Some(value.da_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state = Protobuf::<RawConsensusState>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: ROLLUP_CONSENSUS_STATE_TYPE_URL.to_string(),
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            ROLLUP_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: ROLLUP_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
+
+impl From<Header> for ConsensusState {
+    fn from(header: Header) -> Self {
+        Self {
+            timestamp: header.block.timestamp,
+            root: header.block.state_root,
+            da_height: header.da_height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            Timestamp::from_nanoseconds(1).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_consensus_state_any_round_trip() {
+        let consensus_state = consensus_state();
+        assert_eq!(
+            ConsensusState::try_from(Any::from(consensus_state.clone())).unwrap(),
+            consensus_state
+        );
+    }
+
+    #[test]
+    fn test_consensus_state_any_rejections() {
+        let mut any = Any::from(consensus_state());
+        any.type_url = "/ibc.lightclients.tendermint.v1.ConsensusState".to_string();
+        assert!(matches!(
+            ConsensusState::try_from(any),
+            Err(ClientError::UnknownConsensusStateType { .. })
+        ));
+
+        let mut raw = RawConsensusState::from(consensus_state());
+        raw.da_height = None;
+        assert!(ConsensusState::try_from(raw).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Defines the rollup light client's error type
+
+use core::time::Duration;
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::{Height, Status};
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_host_types::error::IdentifierError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid proof specs: `{0}`
+    InvalidProofSpec(CommitmentError),
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// invalid namespace: `{reason}`
+    InvalidNamespace { reason: String },
+    /// invalid namespaced hash of `{length}` bytes
+    InvalidNamespacedHash { length: usize },
+    /// invalid namespace proof: `{reason}`
+    InvalidNamespaceProof { reason: String },
+    /// invalid row proof: `{reason}`
+    InvalidRowProof { reason: String },
+    /// invalid share: `{reason}`
+    InvalidShare { reason: String },
+    /// the published data does not match the rollup block
+    MismatchPublishedBlock,
+    /// the block was published under the namespace `{actual}` instead of `{expected}`
+    MismatchNamespace { expected: String, actual: String },
+    /// the header revision `{header_revision}` does not match the revision `{latest_revision}` of the rollup
+    MismatchHeightRevisions {
+        latest_revision: u64,
+        header_revision: u64,
+    },
+    /// the block timestamp `{block_timestamp}` is after the timestamp `{da_timestamp}` of its publication
+    BlockTimestampAfterPublication {
+        block_timestamp: Timestamp,
+        da_timestamp: Timestamp,
+    },
+    /// the fraud-proof window of `{window:?}` has not elapsed since the publication of the block at `{published_at}`, now is `{now}`
+    FraudProofWindowNotElapsed {
+        published_at: Timestamp,
+        window: Duration,
+        now: Timestamp,
+    },
+    /// the client `{da_client_id}` of the DA layer is not active, its status is `{status}`
+    DaClientNotActive {
+        da_client_id: ClientId,
+        status: Status,
+    },
+    /// the consensus state of the rollup at height `{height}` conflicts with the header
+    ConflictingConsensusState { height: Height },
+    /// the misbehaviour headers are not at the same height
+    MisbehaviourHeadersNotAtSameHeight,
+    /// the misbehaviour headers carry the same block
+    MisbehaviourHeadersBlocksEqual,
+    /// the rollup light client cannot be upgraded
+    UpgradeNotSupported,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidProofSpec(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<IdentifierError> for Error {
+    fn from(e: IdentifierError) -> Self {
+        Self::InvalidIdentifier(e)
+    }
+}
+
+impl From<CommitmentError> for Error {
+    fn from(e: CommitmentError) -> Self {
+        Self::InvalidProofSpec(e)
+    }
+}
@@ -0,0 +1,242 @@
+//! Defines the headers of the rollup light client, carrying a block of the
+//! rollup along with the proof of its publication on the DA layer.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::proto::v1::Height as RawHeight;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_commitment_types::host_functions::HostFunctions;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::namespace::{Namespace, DIGEST_SIZE};
+use crate::proto::v1::{Header as RawHeader, RollupBlock as RawRollupBlock};
+use crate::share_proof::ShareProof;
+
+pub const ROLLUP_HEADER_TYPE_URL: &str = "/ibc.lightclients.rollup.v1.Header";
+
+/// The part of a block of the rollup tracked by the client, as published on
+/// the DA layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollupBlock {
+    pub height: Height,
+    pub timestamp: Timestamp,
+    pub state_root: CommitmentRoot,
+}
+
+/// The rollup light client header, proving that a block of the rollup was
+/// published under the namespace of the rollup at a height of the DA layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub block: RollupBlock,
+    /// The height of the DA block in which the block was published.
+    pub da_height: Height,
+    pub share_proof: ShareProof,
+}
+
+impl Header {
+    pub fn height(&self) -> Height {
+        self.block.height
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.block.timestamp
+    }
+
+    /// Verifies that the block of the header is the blob published under the
+    /// given namespace in the DA block of the given data root.
+    pub fn verify_publication<H: HostFunctions>(
+        &self,
+        namespace: &Namespace,
+        data_root: &[u8; DIGEST_SIZE],
+    ) -> Result<(), Error> {
+        if self.share_proof.namespace != *namespace {
+            return Err(Error::MismatchNamespace {
+                expected: namespace.to_string(),
+                actual: self.share_proof.namespace.to_string(),
+            });
+        }
+
+        self.share_proof.verify::<H>(data_root)?;
+
+        if self.share_proof.blob()? != Protobuf::<RawRollupBlock>::encode_vec(self.block.clone()) {
+            return Err(Error::MismatchPublishedBlock);
+        }
+
+        Ok(())
+    }
+}
+
+fn height_from_raw(raw: Option<RawHeight>, field: &str) -> Result<Height, Error> {
+    raw.ok_or_else(|| Error::InvalidRawHeader {
+        reason: format!("missing {field}"),
+    })?
+    .try_into()
+    .map_err(|e: ClientError| Error::InvalidRawHeader {
+        reason: format!("invalid {field}: {e}"),
+    })
+}
+
+impl Protobuf<RawRollupBlock> for RollupBlock {}
+
+impl TryFrom<RawRollupBlock> for RollupBlock {
+    type Error = Error;
+
+    fn try_from(raw: RawRollupBlock) -> Result<Self, Self::Error> {
+        let timestamp =
+            Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| Error::InvalidRawHeader {
+                reason: format!("invalid timestamp: {e}"),
+            })?;
+
+        if raw.state_root.is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "empty state root".to_string(),
+            });
+        }
+
+        Ok(Self {
+            height: height_from_raw(raw.height, "height")?,
+            timestamp,
+            state_root: raw.state_root.into(),
+        })
+    }
+}
+
+impl From<RollupBlock> for RawRollupBlock {
+    fn from(value: RollupBlock) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.nanoseconds(),
+            state_root: value.state_root.into_vec(),
+        }
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block: raw
+                .block
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing block".to_string(),
+                })?
+                .try_into()?,
+            da_height: height_from_raw(raw.da_height, "DA height")?,
+            share_proof: raw
+                .share_proof
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing share proof".to_string(),
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            block: Some(value.block.into()),
+            da_height: Some(value.da_height.into()),
+            share_proof: Some(value.share_proof.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: ROLLUP_HEADER_TYPE_URL.to_string(),
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(header)
+        }
+        match raw.type_url.as_str() {
+            ROLLUP_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: ROLLUP_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::namespace::NAMESPACE_SIZE;
+    use crate::share_proof::RowProof;
+
+    /// Returns a header of the block of the given state root, whose share
+    /// proof is empty.
+    pub(crate) fn dummy_header(state_root: u8) -> Header {
+        Header {
+            block: RollupBlock {
+                height: Height::new(1, 11).unwrap(),
+                timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+                state_root: CommitmentRoot::from_bytes(&[state_root; 32]),
+            },
+            da_height: Height::new(0, 5).unwrap(),
+            share_proof: ShareProof {
+                data: vec![],
+                share_proofs: vec![],
+                namespace: Namespace::new([1; NAMESPACE_SIZE]),
+                row_proof: RowProof {
+                    row_roots: vec![],
+                    proofs: vec![],
+                    start_row: 0,
+                    end_row: 0,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_header_any_round_trip() {
+        let header = dummy_header(1);
+        assert_eq!(Header::try_from(Any::from(header.clone())).unwrap(), header);
+    }
+
+    #[test]
+    fn test_header_any_rejections() {
+        let mut any = Any::from(dummy_header(1));
+        any.type_url = "/ibc.lightclients.tendermint.v1.Header".to_string();
+        assert!(matches!(
+            Header::try_from(any),
+            Err(ClientError::UnknownHeaderType { .. })
+        ));
+
+        let mut raw = RawHeader::from(dummy_header(1));
+        raw.share_proof = None;
+        assert!(Header::try_from(raw).is_err());
+
+        let mut raw = RawHeader::from(dummy_header(1));
+        if let Some(block) = raw.block.as_mut() {
+            block.state_root.clear();
+        }
+        assert!(Header::try_from(raw).is_err());
+    }
+}
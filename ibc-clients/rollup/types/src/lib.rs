@@ -0,0 +1,62 @@
+//! Rollup Client implements a client verification algorithm for the rollups
+//! publishing their blocks on a data availability (DA) layer, such as the
+//! optimistic and sovereign rollups settling on Celestia.
+//!
+//! The blocks of the rollup are accepted once their inclusion in the
+//! namespace of the rollup is proven against the data root of a DA block,
+//! known to the client of the DA layer the rollup client depends on, and the
+//! fraud-proof window of the rollup has elapsed since their publication.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::ClientType;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+mod namespace;
+mod share_proof;
+
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+pub use namespace::*;
+pub use share_proof::*;
+
+pub mod error;
+pub mod proto;
+
+pub const ROLLUP_CLIENT_TYPE: &str = "rollup-da";
+
+/// Returns the rollup `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(ROLLUP_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the rollup client type
+    #[test]
+    pub fn test_rollup_client_type() {
+        let _ = ClientType::from_str(ROLLUP_CLIENT_TYPE).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+//! Defines the misbehaviour type for the rollup light client
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::header::Header;
+use crate::proto::v1::Misbehaviour as RawMisbehaviour;
+
+pub const ROLLUP_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.rollup.v1.Misbehaviour";
+
+/// Rollup light client's misbehaviour type: two distinct blocks of the rollup
+/// at the same height, both published on the DA layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    client_id: ClientId,
+    header1: Header,
+    header2: Header,
+}
+
+impl Misbehaviour {
+    pub fn new(client_id: ClientId, header1: Header, header2: Header) -> Self {
+        Self {
+            client_id,
+            header1,
+            header2,
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn header1(&self) -> &Header {
+        &self.header1
+    }
+
+    pub fn header2(&self) -> &Header {
+        &self.header2
+    }
+
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.header1.height() != self.header2.height() {
+            return Err(Error::MisbehaviourHeadersNotAtSameHeight);
+        }
+
+        if self.header1.block == self.header2.block {
+            return Err(Error::MisbehaviourHeadersBlocksEqual);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let client_id = raw.client_id.parse()?;
+
+        let header1: Header = raw
+            .header_1
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing header1".into(),
+            })?
+            .try_into()?;
+
+        let header2: Header = raw
+            .header_2
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing header2".into(),
+            })?
+            .try_into()?;
+
+        Ok(Self::new(client_id, header1, header2))
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            header_1: Some(value.header1.into()),
+            header_2: Some(value.header2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, ClientError> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: ROLLUP_MISBEHAVIOUR_TYPE_URL.to_string(),
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+        match raw.type_url.as_str() {
+            ROLLUP_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: ROLLUP_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client_types::Height;
+
+    use super::*;
+    use crate::header::tests::dummy_header;
+
+    #[test]
+    fn test_misbehaviour_any_round_trip() {
+        let misbehaviour = Misbehaviour::new(
+            ClientId::new("rollup-da", 0).unwrap(),
+            dummy_header(1),
+            dummy_header(2),
+        );
+        assert_eq!(
+            Misbehaviour::try_from(Any::from(misbehaviour.clone())).unwrap(),
+            misbehaviour
+        );
+
+        let mut any = Any::from(misbehaviour);
+        any.type_url = "/ibc.lightclients.tendermint.v1.Misbehaviour".to_string();
+        assert!(matches!(
+            Misbehaviour::try_from(any),
+            Err(ClientError::UnknownMisbehaviourType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_misbehaviour_validate_basic() {
+        let client_id = ClientId::new("rollup-da", 0).unwrap();
+
+        assert!(
+            Misbehaviour::new(client_id.clone(), dummy_header(1), dummy_header(2))
+                .validate_basic()
+                .is_ok()
+        );
+        assert!(matches!(
+            Misbehaviour::new(client_id.clone(), dummy_header(1), dummy_header(1)).validate_basic(),
+            Err(Error::MisbehaviourHeadersBlocksEqual)
+        ));
+
+        let mut other_height = dummy_header(2);
+        other_height.block.height = Height::new(1, 12).unwrap();
+        assert!(matches!(
+            Misbehaviour::new(client_id, dummy_header(1), other_height).validate_basic(),
+            Err(Error::MisbehaviourHeadersNotAtSameHeight)
+        ));
+    }
+}
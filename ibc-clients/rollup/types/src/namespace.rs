@@ -0,0 +1,298 @@
+//! Defines the namespaces of the data published on Celestia, along with the
+//! namespaced Merkle trees (NMTs) committing to the shares of each row of the
+//! DA blocks, and the proofs of the shares of a namespace against their roots.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_commitment_types::host_functions::HostFunctions;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::proto::v1::NamespaceProof as RawNamespaceProof;
+
+/// The size of the namespaces, a version byte followed by a 28-byte
+/// identifier.
+pub const NAMESPACE_SIZE: usize = 29;
+
+/// The size of the SHA256 digests of the NMTs.
+pub const DIGEST_SIZE: usize = 32;
+
+/// The size of the nodes of the NMTs, the minimum and maximum namespaces of
+/// their leaves followed by their digest.
+pub const NAMESPACED_HASH_SIZE: usize = 2 * NAMESPACE_SIZE + DIGEST_SIZE;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// The namespace under which a rollup publishes its blocks.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Namespace([u8; NAMESPACE_SIZE]);
+
+impl Namespace {
+    /// The namespace of the parity shares of the extended rows, which the
+    /// maximum namespaces of the nodes ignore.
+    pub const PARITY_SHARES: Self = Self([0xff; NAMESPACE_SIZE]);
+
+    pub fn new(bytes: [u8; NAMESPACE_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = bytes.try_into().map_err(|_| Error::InvalidNamespace {
+            reason: format!("expected {NAMESPACE_SIZE} bytes, got {}", bytes.len()),
+        })?;
+
+        Ok(Self(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for Namespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// A node of a namespaced Merkle tree.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamespacedHash {
+    pub min_namespace: Namespace,
+    pub max_namespace: Namespace,
+    pub digest: [u8; DIGEST_SIZE],
+}
+
+impl NamespacedHash {
+    /// Hashes the given leaf, whose first bytes are its namespace.
+    pub fn leaf<H: HostFunctions>(data: &[u8]) -> Result<Self, Error> {
+        let namespace = data
+            .get(..NAMESPACE_SIZE)
+            .ok_or_else(|| Error::InvalidShare {
+                reason: format!("share of {} bytes has no namespace", data.len()),
+            })
+            .and_then(Namespace::from_bytes)?;
+
+        let mut preimage = Vec::with_capacity(1 + data.len());
+        preimage.push(LEAF_PREFIX);
+        preimage.extend_from_slice(data);
+
+        Ok(Self {
+            min_namespace: namespace,
+            max_namespace: namespace,
+            digest: H::sha2_256(&preimage),
+        })
+    }
+
+    /// Hashes the given children, whose namespaces must be ordered.
+    pub fn node<H: HostFunctions>(left: &Self, right: &Self) -> Result<Self, Error> {
+        if left.max_namespace > right.min_namespace {
+            return Err(Error::InvalidNamespaceProof {
+                reason: "unordered namespaces".to_string(),
+            });
+        }
+
+        let max_namespace = if right.min_namespace == Namespace::PARITY_SHARES {
+            left.max_namespace
+        } else {
+            right.max_namespace
+        };
+
+        let mut preimage = Vec::with_capacity(1 + 2 * NAMESPACED_HASH_SIZE);
+        preimage.push(NODE_PREFIX);
+        preimage.extend_from_slice(&left.to_bytes());
+        preimage.extend_from_slice(&right.to_bytes());
+
+        Ok(Self {
+            min_namespace: left.min_namespace,
+            max_namespace,
+            digest: H::sha2_256(&preimage),
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != NAMESPACED_HASH_SIZE {
+            return Err(Error::InvalidNamespacedHash {
+                length: bytes.len(),
+            });
+        }
+
+        let (min_namespace, rest) = bytes.split_at(NAMESPACE_SIZE);
+        let (max_namespace, digest) = rest.split_at(NAMESPACE_SIZE);
+
+        Ok(Self {
+            min_namespace: Namespace::from_bytes(min_namespace)?,
+            max_namespace: Namespace::from_bytes(max_namespace)?,
+            digest: digest
+                .try_into()
+                .map_err(|_| Error::InvalidNamespacedHash {
+                    length: bytes.len(),
+                })?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(NAMESPACED_HASH_SIZE);
+        bytes.extend_from_slice(self.min_namespace.as_bytes());
+        bytes.extend_from_slice(self.max_namespace.as_bytes());
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+}
+
+/// The proof of the inclusion of the leaves `start..end` of a namespace in a
+/// namespaced Merkle tree, made of the roots of the subtrees around them, in
+/// order.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamespaceProof {
+    pub start: u32,
+    pub end: u32,
+    pub nodes: Vec<NamespacedHash>,
+}
+
+impl NamespaceProof {
+    /// Verifies that the given leaves of the namespace are the leaves
+    /// `start..end` of the tree of the given root.
+    pub fn verify_inclusion<H: HostFunctions>(
+        &self,
+        root: &NamespacedHash,
+        namespace: &Namespace,
+        leaves: &[Vec<u8>],
+    ) -> Result<(), Error> {
+        if self.start >= self.end || leaves.len() != (self.end - self.start) as usize {
+            return Err(Error::InvalidNamespaceProof {
+                reason: format!(
+                    "range {}..{} does not match the {} leaves",
+                    self.start,
+                    self.end,
+                    leaves.len()
+                ),
+            });
+        }
+
+        let mut leaf_hashes = Vec::with_capacity(leaves.len());
+        for leaf in leaves {
+            let leaf_hash = NamespacedHash::leaf::<H>(leaf)?;
+            if leaf_hash.min_namespace != *namespace {
+                return Err(Error::MismatchNamespace {
+                    expected: namespace.to_string(),
+                    actual: leaf_hash.min_namespace.to_string(),
+                });
+            }
+            leaf_hashes.push(leaf_hash);
+        }
+
+        let mut verifier = RangeVerifier {
+            start: self.start as usize,
+            end: self.end as usize,
+            leaves: leaf_hashes.into_iter(),
+            nodes: self.nodes.iter().copied(),
+        };
+
+        let subtree_size = (split_point(self.end as usize) * 2).max(1);
+        let mut computed_root = verifier
+            .compute_root::<H>(0, subtree_size)?
+            .ok_or_else(|| Error::InvalidNamespaceProof {
+                reason: "empty tree".to_string(),
+            })?;
+
+        // The remaining nodes are the roots of the subtrees on the right of
+        // the one containing the range.
+        for node in verifier.nodes {
+            computed_root = NamespacedHash::node::<H>(&computed_root, &node)?;
+        }
+
+        if computed_root != *root {
+            return Err(Error::InvalidNamespaceProof {
+                reason: "the leaves do not match the root".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Recomputes the root of a namespaced Merkle tree from the leaves of the
+/// proven range and the roots of the subtrees around it.
+struct RangeVerifier<L, N> {
+    start: usize,
+    end: usize,
+    leaves: L,
+    nodes: N,
+}
+
+impl<L, N> RangeVerifier<L, N>
+where
+    L: Iterator<Item = NamespacedHash>,
+    N: Iterator<Item = NamespacedHash>,
+{
+    fn compute_root<H: HostFunctions>(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<Option<NamespacedHash>, Error> {
+        if end <= self.start || start >= self.end {
+            return Ok(self.nodes.next());
+        }
+
+        if end - start == 1 {
+            return Ok(self.leaves.next());
+        }
+
+        let split = start + split_point(end - start);
+        let left = self.compute_root::<H>(start, split)?;
+        let right = self.compute_root::<H>(split, end)?;
+
+        match (left, right) {
+            (Some(left), Some(right)) => NamespacedHash::node::<H>(&left, &right).map(Some),
+            // Only the rightmost subtrees may be missing, when the tree is not
+            // full.
+            (left, None) => Ok(left),
+            (None, Some(_)) => Err(Error::InvalidNamespaceProof {
+                reason: "missing left subtree".to_string(),
+            }),
+        }
+    }
+}
+
+/// Returns the largest power of two strictly less than `length`, or zero.
+pub(crate) fn split_point(length: usize) -> usize {
+    if length <= 1 {
+        return 0;
+    }
+
+    length.next_power_of_two() / 2
+}
+
+impl Protobuf<RawNamespaceProof> for NamespaceProof {}
+
+impl TryFrom<RawNamespaceProof> for NamespaceProof {
+    type Error = Error;
+
+    fn try_from(raw: RawNamespaceProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            start: raw.start,
+            end: raw.end,
+            nodes: raw
+                .nodes
+                .iter()
+                .map(|node| NamespacedHash::from_bytes(node))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<NamespaceProof> for RawNamespaceProof {
+    fn from(value: NamespaceProof) -> Self {
+        Self {
+            start: value.start,
+            end: value.end,
+            nodes: value.nodes.iter().map(NamespacedHash::to_bytes).collect(),
+        }
+    }
+}
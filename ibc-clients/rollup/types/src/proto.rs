@@ -0,0 +1,143 @@
+//! Defines the protobuf messages of the rollup light client, under the
+//! `ibc.lightclients.rollup.v1` package, which `ibc-proto` does not provide.
+
+/// The protobuf messages of the `ibc.lightclients.rollup.v1` package.
+pub mod v1 {
+    use ibc_primitives::prelude::*;
+    use ibc_proto::google::protobuf::Duration;
+    use ibc_proto::ibc::core::client::v1::Height;
+    use ibc_proto::ics23::ProofSpec;
+
+    const PACKAGE: &str = "ibc.lightclients.rollup.v1";
+
+    macro_rules! impl_name {
+        ($($message:ident),+) => {
+            $(
+                impl prost::Name for $message {
+                    const NAME: &'static str = stringify!($message);
+                    const PACKAGE: &'static str = PACKAGE;
+
+                    fn full_name() -> String {
+                        format!("{}.{}", PACKAGE, stringify!($message))
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_name!(
+        ClientState,
+        ConsensusState,
+        RollupBlock,
+        Header,
+        Misbehaviour,
+        ShareProof,
+        NamespaceProof,
+        RowProof,
+        BinaryMerkleProof
+    );
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ClientState {
+        #[prost(string, tag = "1")]
+        pub rollup_id: String,
+        #[prost(message, optional, tag = "2")]
+        pub latest_height: Option<Height>,
+        #[prost(string, tag = "3")]
+        pub da_client_id: String,
+        #[prost(bytes = "vec", tag = "4")]
+        pub namespace: Vec<u8>,
+        #[prost(message, optional, tag = "5")]
+        pub fraud_proof_window: Option<Duration>,
+        #[prost(message, repeated, tag = "6")]
+        pub proof_specs: Vec<ProofSpec>,
+        #[prost(message, optional, tag = "7")]
+        pub frozen_height: Option<Height>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ConsensusState {
+        #[prost(uint64, tag = "1")]
+        pub timestamp: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub root: Vec<u8>,
+        #[prost(message, optional, tag = "3")]
+        pub da_height: Option<Height>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct RollupBlock {
+        #[prost(message, optional, tag = "1")]
+        pub height: Option<Height>,
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        pub state_root: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Header {
+        #[prost(message, optional, tag = "1")]
+        pub block: Option<RollupBlock>,
+        #[prost(message, optional, tag = "2")]
+        pub da_height: Option<Height>,
+        #[prost(message, optional, tag = "3")]
+        pub share_proof: Option<ShareProof>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Misbehaviour {
+        #[prost(string, tag = "1")]
+        pub client_id: String,
+        #[prost(message, optional, tag = "2")]
+        pub header_1: Option<Header>,
+        #[prost(message, optional, tag = "3")]
+        pub header_2: Option<Header>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ShareProof {
+        #[prost(bytes = "vec", repeated, tag = "1")]
+        pub data: Vec<Vec<u8>>,
+        #[prost(message, repeated, tag = "2")]
+        pub share_proofs: Vec<NamespaceProof>,
+        #[prost(bytes = "vec", tag = "3")]
+        pub namespace: Vec<u8>,
+        #[prost(message, optional, tag = "4")]
+        pub row_proof: Option<RowProof>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct NamespaceProof {
+        #[prost(uint32, tag = "1")]
+        pub start: u32,
+        #[prost(uint32, tag = "2")]
+        pub end: u32,
+        #[prost(bytes = "vec", repeated, tag = "3")]
+        pub nodes: Vec<Vec<u8>>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct RowProof {
+        #[prost(bytes = "vec", repeated, tag = "1")]
+        pub row_roots: Vec<Vec<u8>>,
+        #[prost(message, repeated, tag = "2")]
+        pub proofs: Vec<BinaryMerkleProof>,
+        #[prost(uint32, tag = "3")]
+        pub start_row: u32,
+        #[prost(uint32, tag = "4")]
+        pub end_row: u32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct BinaryMerkleProof {
+        #[prost(uint64, tag = "1")]
+        pub total: u64,
+        #[prost(uint64, tag = "2")]
+        pub index: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        pub leaf_hash: Vec<u8>,
+        #[prost(bytes = "vec", repeated, tag = "4")]
+        pub aunts: Vec<Vec<u8>>,
+    }
+}
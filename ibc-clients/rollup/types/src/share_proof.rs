@@ -0,0 +1,520 @@
+//! Defines the proofs of the inclusion of shares in the data root of a
+//! Celestia block, along with the decoding of the blobs they carry.
+//!
+//! The shares of a DA block are laid out in a square whose rows are extended
+//! with parity shares and committed to by namespaced Merkle trees. The data
+//! root of the block is the root of the binary Merkle tree of the roots of the
+//! rows and columns of the extended square.
+
+use ibc_core_commitment_types::host_functions::HostFunctions;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::namespace::{
+    split_point, Namespace, NamespaceProof, NamespacedHash, DIGEST_SIZE, NAMESPACE_SIZE,
+};
+use crate::proto::v1::{
+    BinaryMerkleProof as RawBinaryMerkleProof, RowProof as RawRowProof, ShareProof as RawShareProof,
+};
+
+/// The size of the shares.
+pub const SHARE_SIZE: usize = 512;
+
+const SHARE_INFO_BYTES: usize = 1;
+const SEQUENCE_LEN_BYTES: usize = 4;
+const SHARE_VERSION_ZERO: u8 = 0;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// The proof of the inclusion of a leaf in a binary Merkle tree, as defined by
+/// RFC 6962.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryMerkleProof {
+    pub total: u64,
+    pub index: u64,
+    pub leaf_hash: [u8; DIGEST_SIZE],
+    pub aunts: Vec<[u8; DIGEST_SIZE]>,
+}
+
+impl BinaryMerkleProof {
+    /// Verifies that the given leaf is the leaf at `index` of the tree of the
+    /// given root.
+    pub fn verify<H: HostFunctions>(
+        &self,
+        root: &[u8; DIGEST_SIZE],
+        leaf: &[u8],
+    ) -> Result<(), Error> {
+        if leaf_hash::<H>(leaf) != self.leaf_hash {
+            return Err(Error::InvalidRowProof {
+                reason: "the leaf does not match the leaf hash".to_string(),
+            });
+        }
+
+        let computed_root =
+            compute_hash_from_aunts::<H>(self.index, self.total, self.leaf_hash, &self.aunts)
+                .ok_or_else(|| Error::InvalidRowProof {
+                    reason: format!(
+                        "malformed proof of the leaf {} of {}",
+                        self.index, self.total
+                    ),
+                })?;
+
+        if computed_root != *root {
+            return Err(Error::InvalidRowProof {
+                reason: "the leaf does not match the root".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn leaf_hash<H: HostFunctions>(leaf: &[u8]) -> [u8; DIGEST_SIZE] {
+    let mut preimage = Vec::with_capacity(1 + leaf.len());
+    preimage.push(LEAF_PREFIX);
+    preimage.extend_from_slice(leaf);
+
+    H::sha2_256(&preimage)
+}
+
+fn inner_hash<H: HostFunctions>(
+    left: &[u8; DIGEST_SIZE],
+    right: &[u8; DIGEST_SIZE],
+) -> [u8; DIGEST_SIZE] {
+    let mut preimage = Vec::with_capacity(1 + 2 * DIGEST_SIZE);
+    preimage.push(NODE_PREFIX);
+    preimage.extend_from_slice(left);
+    preimage.extend_from_slice(right);
+
+    H::sha2_256(&preimage)
+}
+
+fn compute_hash_from_aunts<H: HostFunctions>(
+    index: u64,
+    total: u64,
+    leaf_hash: [u8; DIGEST_SIZE],
+    aunts: &[[u8; DIGEST_SIZE]],
+) -> Option<[u8; DIGEST_SIZE]> {
+    if index >= total {
+        return None;
+    }
+
+    if total == 1 {
+        return aunts.is_empty().then_some(leaf_hash);
+    }
+
+    let (last_aunt, aunts) = aunts.split_last()?;
+    let left_size = split_point(usize::try_from(total).ok()?) as u64;
+
+    if index < left_size {
+        let left = compute_hash_from_aunts::<H>(index, left_size, leaf_hash, aunts)?;
+        Some(inner_hash::<H>(&left, last_aunt))
+    } else {
+        let right =
+            compute_hash_from_aunts::<H>(index - left_size, total - left_size, leaf_hash, aunts)?;
+        Some(inner_hash::<H>(last_aunt, &right))
+    }
+}
+
+/// The proof of the inclusion of the roots of the rows `start_row..=end_row`
+/// in the data root of a block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowProof {
+    pub row_roots: Vec<NamespacedHash>,
+    pub proofs: Vec<BinaryMerkleProof>,
+    pub start_row: u32,
+    pub end_row: u32,
+}
+
+impl RowProof {
+    /// Verifies that the row roots are the roots of the rows
+    /// `start_row..=end_row` of the block of the given data root.
+    pub fn verify<H: HostFunctions>(&self, data_root: &[u8; DIGEST_SIZE]) -> Result<(), Error> {
+        let rows = self
+            .end_row
+            .checked_sub(self.start_row)
+            .map(|rows| rows as usize + 1);
+
+        if rows != Some(self.row_roots.len()) || self.row_roots.len() != self.proofs.len() {
+            return Err(Error::InvalidRowProof {
+                reason: format!(
+                    "{} row roots and {} proofs for the rows {}..={}",
+                    self.row_roots.len(),
+                    self.proofs.len(),
+                    self.start_row,
+                    self.end_row
+                ),
+            });
+        }
+
+        for (row, (row_root, proof)) in
+            (u64::from(self.start_row)..).zip(self.row_roots.iter().zip(&self.proofs))
+        {
+            if proof.index != row {
+                return Err(Error::InvalidRowProof {
+                    reason: format!("proof of the row {} for the row {row}", proof.index),
+                });
+            }
+
+            proof.verify::<H>(data_root, &row_root.to_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The proof of the inclusion of consecutive shares of a namespace, spanning
+/// one or more rows, in the data root of a block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareProof {
+    /// The raw shares, each starting with its namespace.
+    pub data: Vec<Vec<u8>>,
+    /// The proofs of the shares of each row against the row root.
+    pub share_proofs: Vec<NamespaceProof>,
+    pub namespace: Namespace,
+    pub row_proof: RowProof,
+}
+
+impl ShareProof {
+    /// Verifies that the shares are included in the block of the given data
+    /// root.
+    pub fn verify<H: HostFunctions>(&self, data_root: &[u8; DIGEST_SIZE]) -> Result<(), Error> {
+        if self.share_proofs.len() != self.row_proof.row_roots.len() {
+            return Err(Error::InvalidNamespaceProof {
+                reason: format!(
+                    "{} share proofs for {} rows",
+                    self.share_proofs.len(),
+                    self.row_proof.row_roots.len()
+                ),
+            });
+        }
+
+        self.row_proof.verify::<H>(data_root)?;
+
+        let mut shares = self.data.as_slice();
+        for (share_proof, row_root) in self.share_proofs.iter().zip(&self.row_proof.row_roots) {
+            let row_shares = share_proof.end.saturating_sub(share_proof.start) as usize;
+            if row_shares > shares.len() {
+                return Err(Error::InvalidNamespaceProof {
+                    reason: "more proven shares than shares".to_string(),
+                });
+            }
+
+            let (row, rest) = shares.split_at(row_shares);
+            share_proof.verify_inclusion::<H>(row_root, &self.namespace, row)?;
+            shares = rest;
+        }
+
+        if !shares.is_empty() {
+            return Err(Error::InvalidNamespaceProof {
+                reason: format!("{} unproven shares", shares.len()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the blob carried by the shares, which must form a single
+    /// sequence of sparse shares of version zero.
+    pub fn blob(&self) -> Result<Vec<u8>, Error> {
+        let mut blob = Vec::new();
+        let mut sequence_len = 0;
+
+        for (index, share) in self.data.iter().enumerate() {
+            if share.len() != SHARE_SIZE {
+                return Err(Error::InvalidShare {
+                    reason: format!("share of {} bytes", share.len()),
+                });
+            }
+
+            let info = share[NAMESPACE_SIZE];
+            if info >> 1 != SHARE_VERSION_ZERO {
+                return Err(Error::InvalidShare {
+                    reason: format!("unsupported share version {}", info >> 1),
+                });
+            }
+
+            let is_sequence_start = info & 1 == 1;
+            if is_sequence_start != (index == 0) {
+                return Err(Error::InvalidShare {
+                    reason: "the shares do not form a single sequence".to_string(),
+                });
+            }
+
+            let mut payload = &share[NAMESPACE_SIZE + SHARE_INFO_BYTES..];
+            if is_sequence_start {
+                let (len, rest) = payload.split_at(SEQUENCE_LEN_BYTES);
+                sequence_len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+                payload = rest;
+            } else if blob.len() >= sequence_len {
+                return Err(Error::InvalidShare {
+                    reason: "trailing shares after the end of the sequence".to_string(),
+                });
+            }
+
+            blob.extend_from_slice(payload);
+        }
+
+        if blob.len() < sequence_len || self.data.is_empty() {
+            return Err(Error::InvalidShare {
+                reason: format!(
+                    "sequence of {sequence_len} bytes truncated to {} bytes",
+                    blob.len()
+                ),
+            });
+        }
+
+        blob.truncate(sequence_len);
+
+        Ok(blob)
+    }
+}
+
+fn digest_from_bytes(bytes: &[u8]) -> Result<[u8; DIGEST_SIZE], Error> {
+    bytes.try_into().map_err(|_| Error::InvalidRowProof {
+        reason: format!("invalid digest of {} bytes", bytes.len()),
+    })
+}
+
+impl Protobuf<RawBinaryMerkleProof> for BinaryMerkleProof {}
+
+impl TryFrom<RawBinaryMerkleProof> for BinaryMerkleProof {
+    type Error = Error;
+
+    fn try_from(raw: RawBinaryMerkleProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            total: raw.total,
+            index: raw.index,
+            leaf_hash: digest_from_bytes(&raw.leaf_hash)?,
+            aunts: raw
+                .aunts
+                .iter()
+                .map(|aunt| digest_from_bytes(aunt))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<BinaryMerkleProof> for RawBinaryMerkleProof {
+    fn from(value: BinaryMerkleProof) -> Self {
+        Self {
+            total: value.total,
+            index: value.index,
+            leaf_hash: value.leaf_hash.to_vec(),
+            aunts: value.aunts.iter().map(|aunt| aunt.to_vec()).collect(),
+        }
+    }
+}
+
+impl Protobuf<RawRowProof> for RowProof {}
+
+impl TryFrom<RawRowProof> for RowProof {
+    type Error = Error;
+
+    fn try_from(raw: RawRowProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            row_roots: raw
+                .row_roots
+                .iter()
+                .map(|row_root| NamespacedHash::from_bytes(row_root))
+                .collect::<Result<_, _>>()?,
+            proofs: raw
+                .proofs
+                .into_iter()
+                .map(BinaryMerkleProof::try_from)
+                .collect::<Result<_, _>>()?,
+            start_row: raw.start_row,
+            end_row: raw.end_row,
+        })
+    }
+}
+
+impl From<RowProof> for RawRowProof {
+    fn from(value: RowProof) -> Self {
+        Self {
+            row_roots: value
+                .row_roots
+                .iter()
+                .map(NamespacedHash::to_bytes)
+                .collect(),
+            proofs: value.proofs.into_iter().map(Into::into).collect(),
+            start_row: value.start_row,
+            end_row: value.end_row,
+        }
+    }
+}
+
+impl Protobuf<RawShareProof> for ShareProof {}
+
+impl TryFrom<RawShareProof> for ShareProof {
+    type Error = Error;
+
+    fn try_from(raw: RawShareProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data: raw.data,
+            share_proofs: raw
+                .share_proofs
+                .into_iter()
+                .map(NamespaceProof::try_from)
+                .collect::<Result<_, _>>()?,
+            namespace: Namespace::from_bytes(&raw.namespace)?,
+            row_proof: raw
+                .row_proof
+                .ok_or_else(|| Error::InvalidRowProof {
+                    reason: "missing row proof".to_string(),
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<ShareProof> for RawShareProof {
+    fn from(value: ShareProof) -> Self {
+        Self {
+            data: value.data,
+            share_proofs: value.share_proofs.into_iter().map(Into::into).collect(),
+            namespace: value.namespace.as_bytes().to_vec(),
+            row_proof: Some(value.row_proof.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_commitment_types::host_functions::DefaultHostFunctions as H;
+
+    use super::*;
+
+    fn namespace(id: u8) -> Namespace {
+        let mut bytes = [0; NAMESPACE_SIZE];
+        bytes[NAMESPACE_SIZE - 1] = id;
+        Namespace::new(bytes)
+    }
+
+    /// Returns the shares of a sequence of version zero carrying the blob.
+    fn shares(namespace: &Namespace, blob: &[u8]) -> Vec<Vec<u8>> {
+        let mut shares = Vec::new();
+        let mut rest = blob;
+
+        while shares.is_empty() || !rest.is_empty() {
+            let mut share = namespace.as_bytes().to_vec();
+            if shares.is_empty() {
+                share.push(1);
+                share.extend_from_slice(&(blob.len() as u32).to_be_bytes());
+            } else {
+                share.push(0);
+            }
+
+            let len = rest.len().min(SHARE_SIZE - share.len());
+            share.extend_from_slice(&rest[..len]);
+            share.resize(SHARE_SIZE, 0);
+            rest = &rest[len..];
+
+            shares.push(share);
+        }
+
+        shares
+    }
+
+    /// A row of four shares, the two of the blob of the namespace `2` being
+    /// surrounded by the shares of the namespaces `1` and `3`, and the proof
+    /// of the shares of the blob against the data root of the square.
+    fn share_proof(blob: &[u8]) -> (ShareProof, [u8; DIGEST_SIZE]) {
+        let blob_shares = shares(&namespace(2), blob);
+        assert_eq!(blob_shares.len(), 2);
+
+        let first = NamespacedHash::leaf::<H>(&shares(&namespace(1), b"first")[0]).unwrap();
+        let last = NamespacedHash::leaf::<H>(&shares(&namespace(3), b"last")[0]).unwrap();
+        let leaves: Vec<_> = blob_shares
+            .iter()
+            .map(|share| NamespacedHash::leaf::<H>(share).unwrap())
+            .collect();
+
+        let row_root = NamespacedHash::node::<H>(
+            &NamespacedHash::node::<H>(&first, &leaves[0]).unwrap(),
+            &NamespacedHash::node::<H>(&leaves[1], &last).unwrap(),
+        )
+        .unwrap();
+
+        // The data root commits to the roots of the row and of a column.
+        let column_root = leaf_hash::<H>(b"column");
+        let data_root = inner_hash::<H>(&leaf_hash::<H>(&row_root.to_bytes()), &column_root);
+
+        let share_proof = ShareProof {
+            data: blob_shares,
+            share_proofs: vec![NamespaceProof {
+                start: 1,
+                end: 3,
+                nodes: vec![first, last],
+            }],
+            namespace: namespace(2),
+            row_proof: RowProof {
+                row_roots: vec![row_root],
+                proofs: vec![BinaryMerkleProof {
+                    total: 2,
+                    index: 0,
+                    leaf_hash: leaf_hash::<H>(&row_root.to_bytes()),
+                    aunts: vec![column_root],
+                }],
+                start_row: 0,
+                end_row: 0,
+            },
+        };
+
+        (share_proof, data_root)
+    }
+
+    #[test]
+    fn test_share_proof() {
+        let blob = vec![7; 600];
+        let (share_proof, data_root) = share_proof(&blob);
+
+        share_proof.verify::<H>(&data_root).unwrap();
+        assert_eq!(share_proof.blob().unwrap(), blob);
+
+        let raw = RawShareProof::from(share_proof.clone());
+        assert_eq!(ShareProof::try_from(raw).unwrap(), share_proof);
+    }
+
+    #[test]
+    fn test_share_proof_rejections() {
+        let (share_proof, data_root) = share_proof(&[7; 600]);
+
+        assert!(share_proof.verify::<H>(&[0; DIGEST_SIZE]).is_err());
+
+        let mut tampered = share_proof.clone();
+        tampered.data[1][NAMESPACE_SIZE + 1] ^= 1;
+        assert!(matches!(
+            tampered.verify::<H>(&data_root),
+            Err(Error::InvalidNamespaceProof { .. })
+        ));
+
+        let mut tampered = share_proof.clone();
+        tampered.namespace = namespace(3);
+        assert!(matches!(
+            tampered.verify::<H>(&data_root),
+            Err(Error::MismatchNamespace { .. })
+        ));
+
+        let mut tampered = share_proof.clone();
+        tampered.share_proofs[0].nodes.swap(0, 1);
+        assert!(tampered.verify::<H>(&data_root).is_err());
+
+        let mut truncated = share_proof;
+        truncated.data.pop();
+        assert!(matches!(truncated.blob(), Err(Error::InvalidShare { .. })));
+    }
+
+    #[test]
+    fn test_split_point() {
+        assert_eq!(
+            [1, 2, 3, 4, 5, 8, 9].map(split_point),
+            [0, 1, 2, 2, 4, 4, 8]
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Defines ready-made `AnyClientState` and `AnyConsensusState` enums wrapping
+//! all the light clients implemented by ibc-rs.
+//!
+//! Hosts that do not implement their own light clients can use these types
+//! directly as the `AnyClientState` and `AnyConsensusState` associated types
+//! of their contexts, instead of writing the wrapping enums and the
+//! `IbcClientState`/`IbcConsensusState` derive invocations themselves.
+//!
+//! Note that, unlike the derive macros, these types are generic over the host
+//! contexts: any context implementing the Tendermint client contexts of
+//! [`ibc_client_tendermint::context`] is supported.
+
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::context::{
+    ExecutionContext as TmExecutionContext, ValidationContext as TmValidationContext,
+};
+use ibc_client_tendermint::types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+    TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_core_client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::events::UpdateClientTelemetry;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// The client state of any light client implemented by ibc-rs.
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub enum AnyClientState {
+    Tendermint(TmClientState),
+}
+
+impl Protobuf<Any> for AnyClientState {}
+
+impl TryFrom<Any> for AnyClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(TmClientState::try_from(raw)?.into()),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<AnyClientState> for Any {
+    fn from(client_state: AnyClientState) -> Self {
+        match client_state {
+            AnyClientState::Tendermint(cs) => cs.into(),
+        }
+    }
+}
+
+impl From<ClientStateType> for AnyClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self::Tendermint(client_state.into())
+    }
+}
+
+impl ClientStateCommon for AnyClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_consensus_state(consensus_state),
+        }
+    }
+
+    fn client_type(&self) -> ClientType {
+        match self {
+            Self::Tendermint(cs) => cs.client_type(),
+        }
+    }
+
+    fn latest_height(&self) -> Height {
+        match self {
+            Self::Tendermint(cs) => cs.latest_height(),
+        }
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.validate_proof_height(proof_height),
+        }
+    }
+
+    fn is_past_height(&self, height: Height, timeout_height: Height) -> bool {
+        match self {
+            Self::Tendermint(cs) => cs.is_past_height(height, timeout_height),
+        }
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_upgrade_client(
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                root,
+            ),
+        }
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_membership(prefix, proof, root, path, value),
+        }
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_non_membership(prefix, proof, root, path),
+        }
+    }
+}
+
+impl<V> ClientStateValidation<V> for AnyClientState
+where
+    V: TmValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_client_message(ctx, client_id, client_message),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.check_for_misbehaviour(ctx, client_id, client_message),
+        }
+    }
+
+    fn check_for_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.check_for_redundant_update(ctx, client_id, header),
+        }
+    }
+
+    fn update_telemetry(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Option<UpdateClientTelemetry>, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_telemetry(ctx, client_id, header),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.status(ctx, client_id),
+        }
+    }
+}
+
+impl<E> ClientStateExecution<E> for AnyClientState
+where
+    E: TmExecutionContext,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.initialise(ctx, client_id, consensus_state),
+        }
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state(ctx, client_id, header),
+        }
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state_on_misbehaviour(ctx, client_id, client_message),
+        }
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state_on_upgrade(
+                ctx,
+                client_id,
+                upgraded_client_state,
+                upgraded_consensus_state,
+            ),
+        }
+    }
+}
+
+/// The consensus state of any light client implemented by ibc-rs.
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub enum AnyConsensusState {
+    Tendermint(TmConsensusState),
+}
+
+impl Protobuf<Any> for AnyConsensusState {}
+
+impl TryFrom<Any> for AnyConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(TmConsensusState::try_from(raw)?.into()),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<AnyConsensusState> for Any {
+    fn from(consensus_state: AnyConsensusState) -> Self {
+        match consensus_state {
+            AnyConsensusState::Tendermint(cs) => cs.into(),
+        }
+    }
+}
+
+impl From<ConsensusStateType> for AnyConsensusState {
+    fn from(consensus_state: ConsensusStateType) -> Self {
+        Self::Tendermint(consensus_state.into())
+    }
+}
+
+impl TryFrom<AnyConsensusState> for TmConsensusState {
+    type Error = ClientError;
+
+    fn try_from(consensus_state: AnyConsensusState) -> Result<Self, Self::Error> {
+        match consensus_state {
+            AnyConsensusState::Tendermint(cs) => Ok(cs),
+        }
+    }
+}
+
+impl ConsensusState for AnyConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        match self {
+            Self::Tendermint(cs) => cs.root(),
+        }
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        match self {
+            Self::Tendermint(cs) => ConsensusState::timestamp(cs),
+        }
+    }
+
+    fn encode_vec(self) -> Vec<u8> {
+        match self {
+            Self::Tendermint(cs) => ConsensusState::encode_vec(cs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_client_tendermint::types::{AllowUpdate, TrustThreshold};
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_core_host::types::identifiers::ChainId;
+
+    use super::*;
+
+    fn dummy_client_state() -> AnyClientState {
+        ClientStateType::new(
+            ChainId::new("ibc-1").expect("Never fails"),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).expect("Never fails"),
+            ProofSpecs::default(),
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .expect("Never fails")
+        .into()
+    }
+
+    #[test]
+    fn client_state_any_round_trip() {
+        let client_state = dummy_client_state();
+
+        let any = Any::from(client_state.clone());
+
+        assert_eq!(any.type_url, TENDERMINT_CLIENT_STATE_TYPE_URL);
+        assert_eq!(AnyClientState::try_from(any).unwrap(), client_state);
+        assert_eq!(client_state.latest_height(), Height::new(1, 10).unwrap());
+    }
+
+    #[test]
+    fn unknown_type_url_is_rejected() {
+        let mut any = Any::from(dummy_client_state());
+        any.type_url = "/unknown.ClientState".to_string();
+
+        assert!(matches!(
+            AnyClientState::try_from(any.clone()),
+            Err(ClientError::UnknownClientStateType { .. })
+        ));
+        assert!(matches!(
+            AnyConsensusState::try_from(any),
+            Err(ClientError::UnknownConsensusStateType { .. })
+        ));
+    }
+}
@@ -11,12 +11,28 @@
     rust_2018_idioms
 )]
 
+#[cfg(feature = "clients-all")]
+pub mod any;
+pub mod router;
+
+/// Re-exports implementations of the rollup light client.
+pub mod rollup {
+    #[doc(inline)]
+    pub use ibc_client_rollup::*;
+}
+
 /// Re-exports implementations of ICS-07 Tendermint light client.
 pub mod tendermint {
     #[doc(inline)]
     pub use ibc_client_tendermint::*;
 }
 
+/// Re-exports implementations of ICS-08 Wasm light client.
+pub mod wasm {
+    #[doc(inline)]
+    pub use ibc_client_wasm::*;
+}
+
 /// Re-exports implementations of ICS-08 Wasm light client types.
 pub mod wasm_types {
     #[doc(inline)]
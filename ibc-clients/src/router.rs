@@ -0,0 +1,472 @@
+//! Provides a ready-made client router, which stores the states of the
+//! clients of several light client implementations in a single key-value
+//! store of the host, each client type under its own key prefix.
+//!
+//! [`ClientRouter`] implements the client contexts of the light clients on top
+//! of any [`ClientStore`]. Hosts supporting several client types thus
+//! implement the storage of the clients once, e.g.
+//! ```rust,ignore
+//! let router = ClientRouter::<_, AnyClientState, AnyConsensusState>::new(store, height, timestamp)
+//!     .with_client_type(tm_client_type(), "tendermint")
+//!     .with_client_type(wasm_client_type(), "wasm");
+//! ```
+//! and delegate to it from their `ValidationContext` and `ExecutionContext`.
+//!
+//! `ClientExecutionContext` is implemented for the Tendermint states, and for
+//! the ready-made `AnyClientState` and `AnyConsensusState` enums under the
+//! `clients-all` feature. Hosts defining their own enums implement it for
+//! their context by delegating to the methods of the router.
+//!
+//! The states of the clients are stored under their ICS-24 paths, preceded by
+//! the prefix of their client type, e.g.
+//! `tendermint/clients/07-tendermint-0/clientState`.
+
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::context::ValidationContext as TmValidationContext;
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Key-value store of the host backing a [`ClientRouter`].
+pub trait ClientStore {
+    /// Returns the value stored under the given key, if any.
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ContextError>;
+
+    /// Stores the given value under the given key.
+    fn set(&mut self, key: String, value: Vec<u8>) -> Result<(), ContextError>;
+
+    /// Deletes the value stored under the given key, if any.
+    fn delete(&mut self, key: &str) -> Result<(), ContextError>;
+
+    /// Returns all the keys starting with the given prefix.
+    fn keys_with_prefix(&self, prefix: &str) -> Result<Vec<String>, ContextError>;
+}
+
+/// Stores the client states `C` and the consensus states `S` of the clients
+/// of the registered client types in the store `B`, and dispatches the calls
+/// of the light clients to them.
+///
+/// `C` and `S` are typically the enums of all the client and consensus states
+/// supported by the host, e.g. the ones of the `any` module.
+pub struct ClientRouter<B, C, S> {
+    store: B,
+    prefixes: BTreeMap<ClientType, String>,
+    host_height: Height,
+    host_timestamp: Timestamp,
+    _states: PhantomData<fn() -> (C, S)>,
+}
+
+impl<B, C, S> ClientRouter<B, C, S>
+where
+    B: ClientStore,
+{
+    /// Creates a router without any client type, over the given store and at
+    /// the given host height and timestamp.
+    pub fn new(store: B, host_height: Height, host_timestamp: Timestamp) -> Self {
+        Self {
+            store,
+            prefixes: BTreeMap::new(),
+            host_height,
+            host_timestamp,
+            _states: PhantomData,
+        }
+    }
+
+    /// Registers the given client type, whose client states are stored under
+    /// the given key prefix, replacing the prefix already registered for it,
+    /// if any.
+    pub fn with_client_type(mut self, client_type: ClientType, prefix: impl Into<String>) -> Self {
+        self.prefixes.insert(client_type, prefix.into());
+        self
+    }
+
+    /// Returns the registered client types.
+    pub fn client_types(&self) -> impl Iterator<Item = &ClientType> {
+        self.prefixes.keys()
+    }
+
+    /// Moves the router to the given host height and timestamp, e.g. at the
+    /// beginning of every block.
+    pub fn set_host(&mut self, host_height: Height, host_timestamp: Timestamp) {
+        self.host_height = host_height;
+        self.host_timestamp = host_timestamp;
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &B {
+        &self.store
+    }
+
+    /// Returns the underlying store, mutably.
+    pub fn store_mut(&mut self) -> &mut B {
+        &mut self.store
+    }
+
+    /// Returns the key prefix of the client type of the given client.
+    pub fn prefix(&self, client_id: &ClientId) -> Result<&str, ContextError> {
+        self.prefixes
+            .iter()
+            .find(|(client_type, _)| client_id.is_of_type(client_type))
+            .map(|(_, prefix)| prefix.as_str())
+            .ok_or_else(|| {
+                ClientError::UnroutableClientId {
+                    client_id: client_id.clone(),
+                }
+                .into()
+            })
+    }
+
+    /// Returns the heights of all the consensus states of the given client,
+    /// in ascending order.
+    pub fn consensus_state_heights(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<Height>, ContextError> {
+        let prefix = self.key(client_id, format!("clients/{client_id}/consensusStates/"))?;
+
+        let mut heights = Vec::new();
+
+        for key in self.store.keys_with_prefix(&prefix)? {
+            let height = key
+                .strip_prefix(prefix.as_str())
+                .and_then(|height| Height::from_str(height).ok())
+                .ok_or_else(|| ClientError::InvalidConsensusStateKey { key: key.clone() })?;
+
+            heights.push(height);
+        }
+
+        heights.sort();
+
+        Ok(heights)
+    }
+
+    /// Deletes the consensus state at the given path.
+    pub fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let key = self.key(&consensus_state_path.client_id, &consensus_state_path)?;
+
+        self.store.delete(&key)
+    }
+
+    /// Stores the host timestamp and height at which the given client was
+    /// updated to the given height.
+    pub fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        let time_key = self.processed_time_key(&client_id, &height)?;
+        let height_key = self.processed_height_key(&client_id, &height)?;
+
+        self.store.set(
+            time_key,
+            host_timestamp.nanoseconds().to_be_bytes().to_vec(),
+        )?;
+        self.store.set(height_key, host_height.encode_vec())
+    }
+
+    /// Deletes the update metadata of the given client at the given height.
+    pub fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        let time_key = self.processed_time_key(&client_id, &height)?;
+        let height_key = self.processed_height_key(&client_id, &height)?;
+
+        self.store.delete(&time_key)?;
+        self.store.delete(&height_key)
+    }
+
+    fn key(&self, client_id: &ClientId, path: impl Display) -> Result<String, ContextError> {
+        Ok(format!("{}/{path}", self.prefix(client_id)?))
+    }
+
+    fn processed_time_key(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<String, ContextError> {
+        self.key(
+            client_id,
+            format!("clients/{client_id}/processedTime/{height}"),
+        )
+    }
+
+    fn processed_height_key(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<String, ContextError> {
+        self.key(
+            client_id,
+            format!("clients/{client_id}/processedHeight/{height}"),
+        )
+    }
+}
+
+impl<B, C, S> ClientRouter<B, C, S>
+where
+    B: ClientStore,
+    C: Protobuf<Any>,
+    S: Protobuf<Any>,
+    Any: From<C> + From<S>,
+    <C as TryFrom<Any>>::Error: Display,
+    <S as TryFrom<Any>>::Error: Display,
+{
+    /// Returns the client state of the given client.
+    pub fn client_state(&self, client_id: &ClientId) -> Result<C, ContextError> {
+        let key = self.key(client_id, ClientStatePath::new(client_id.clone()))?;
+
+        let value = self
+            .store
+            .get(&key)?
+            .ok_or_else(|| ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?;
+
+        Ok(<C as Protobuf<Any>>::decode_vec(&value).map_err(decoding_error)?)
+    }
+
+    /// Returns the consensus state at the given path.
+    pub fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<S, ContextError> {
+        let client_id = &client_cons_state_path.client_id;
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )?;
+        let key = self.key(client_id, client_cons_state_path)?;
+
+        let value = self
+            .store
+            .get(&key)?
+            .ok_or_else(|| ClientError::ConsensusStateNotFound {
+                client_id: client_id.clone(),
+                height,
+            })?;
+
+        Ok(<S as Protobuf<Any>>::decode_vec(&value).map_err(decoding_error)?)
+    }
+
+    /// Stores the client state at the given path.
+    pub fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: C,
+    ) -> Result<(), ContextError> {
+        let key = self.key(&client_state_path.0, &client_state_path)?;
+
+        self.store
+            .set(key, <C as Protobuf<Any>>::encode_vec(client_state))
+    }
+
+    /// Stores the consensus state at the given path.
+    pub fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: S,
+    ) -> Result<(), ContextError> {
+        let key = self.key(&consensus_state_path.client_id, &consensus_state_path)?;
+
+        self.store
+            .set(key, <S as Protobuf<Any>>::encode_vec(consensus_state))
+    }
+
+    fn consensus_state_at(&self, client_id: &ClientId, height: &Height) -> Result<S, ContextError> {
+        self.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+    }
+}
+
+impl<B, C, S> ClientValidationContext for ClientRouter<B, C, S>
+where
+    B: ClientStore,
+{
+    fn update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let not_found = || ClientError::UpdateMetaDataNotFound {
+            client_id: client_id.clone(),
+            height: *height,
+        };
+
+        let time = self
+            .store
+            .get(&self.processed_time_key(client_id, height)?)?
+            .ok_or_else(not_found)?;
+        let time = <[u8; 8]>::try_from(time.as_slice()).map_err(|_| {
+            ClientError::InvalidProcessedTime {
+                client_id: client_id.clone(),
+            }
+        })?;
+        let time = Timestamp::from_nanoseconds(u64::from_be_bytes(time))
+            .map_err(ClientError::InvalidPacketTimestamp)?;
+
+        let processed_height = self
+            .store
+            .get(&self.processed_height_key(client_id, height)?)?
+            .ok_or_else(not_found)?;
+        let processed_height = Height::decode_vec(&processed_height).map_err(decoding_error)?;
+
+        Ok((time, processed_height))
+    }
+}
+
+/// Implements [`ClientExecutionContext`] for the routers of the given client
+/// and consensus state types.
+///
+/// The implementation cannot be generic over the state types, as the
+/// client states are light clients of the router itself: proving that a
+/// generic client state is one would require proving that the router is the
+/// execution context being implemented.
+macro_rules! impl_client_execution_context {
+    ($client_state:ty, $consensus_state:ty) => {
+        impl<B> ClientExecutionContext for ClientRouter<B, $client_state, $consensus_state>
+        where
+            B: ClientStore,
+        {
+            type V = Self;
+            type AnyClientState = $client_state;
+            type AnyConsensusState = $consensus_state;
+
+            fn store_client_state(
+                &mut self,
+                client_state_path: ClientStatePath,
+                client_state: $client_state,
+            ) -> Result<(), ContextError> {
+                ClientRouter::store_client_state(self, client_state_path, client_state)
+            }
+
+            fn store_consensus_state(
+                &mut self,
+                consensus_state_path: ClientConsensusStatePath,
+                consensus_state: $consensus_state,
+            ) -> Result<(), ContextError> {
+                ClientRouter::store_consensus_state(self, consensus_state_path, consensus_state)
+            }
+
+            fn delete_consensus_state(
+                &mut self,
+                consensus_state_path: ClientConsensusStatePath,
+            ) -> Result<(), ContextError> {
+                ClientRouter::delete_consensus_state(self, consensus_state_path)
+            }
+
+            fn store_update_meta(
+                &mut self,
+                client_id: ClientId,
+                height: Height,
+                host_timestamp: Timestamp,
+                host_height: Height,
+            ) -> Result<(), ContextError> {
+                ClientRouter::store_update_meta(
+                    self,
+                    client_id,
+                    height,
+                    host_timestamp,
+                    host_height,
+                )
+            }
+
+            fn delete_update_meta(
+                &mut self,
+                client_id: ClientId,
+                height: Height,
+            ) -> Result<(), ContextError> {
+                ClientRouter::delete_update_meta(self, client_id, height)
+            }
+        }
+    };
+}
+
+impl_client_execution_context!(TmClientState, TmConsensusState);
+
+#[cfg(feature = "clients-all")]
+impl_client_execution_context!(crate::any::AnyClientState, crate::any::AnyConsensusState);
+
+impl<B, C, S> TmValidationContext for ClientRouter<B, C, S>
+where
+    B: ClientStore,
+    C: Protobuf<Any>,
+    S: Protobuf<Any> + TryInto<TmConsensusState>,
+    Any: From<C> + From<S>,
+    <C as TryFrom<Any>>::Error: Display,
+    <S as TryFrom<Any>>::Error: Display,
+    <S as TryInto<TmConsensusState>>::Error: ToString,
+{
+    type ConversionError = <S as TryInto<TmConsensusState>>::Error;
+    type AnyConsensusState = S;
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(self.host_height)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<S, ContextError> {
+        ClientRouter::consensus_state(self, client_cons_state_path)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        ClientRouter::consensus_state_heights(self, client_id)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<S>, ContextError> {
+        ClientRouter::consensus_state_heights(self, client_id)?
+            .into_iter()
+            .find(|h| h > height)
+            .map(|h| self.consensus_state_at(client_id, &h))
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<S>, ContextError> {
+        ClientRouter::consensus_state_heights(self, client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|h| self.consensus_state_at(client_id, &h))
+            .transpose()
+    }
+}
+
+fn decoding_error(e: impl Display) -> ClientError {
+    ClientError::InvalidStoredState {
+        reason: e.to_string(),
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines `ClientState`, the core type to be implemented by light clients
 
 use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::UpdateClientTelemetry;
 use ibc_core_client_types::{Height, Status};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
@@ -30,6 +31,17 @@ pub trait ClientStateCommon {
     /// Validate that the client is at a sufficient height
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError>;
 
+    /// Returns whether the given height of the counterparty chain is strictly
+    /// past the given timeout height, e.g. when checking the packet timeouts.
+    ///
+    /// Heights compare as Tendermint ones by default: by revision number, and
+    /// then by revision height. Clients of chains mapping their heights onto
+    /// `Height`s otherwise, e.g. rollups using their batch number as revision
+    /// number and the L1 block as revision height, may override it.
+    fn is_past_height(&self, height: Height, timeout_height: Height) -> bool {
+        height > timeout_height
+    }
+
     /// Verify the upgraded client and consensus states and validate proofs
     /// against the given root.
     ///
@@ -116,6 +128,40 @@ where
         client_message: Any,
     ) -> Result<bool, ClientError>;
 
+    /// Checks whether applying the given header would be a no-op, e.g.
+    /// because a previous relayer already installed the consensus state at
+    /// the header's height. It assumes the header has already been verified.
+    ///
+    /// When this returns `true`, the update handler skips `update_state`
+    /// entirely, so that no store writes happen, and emits a
+    /// `RedundantUpdateClient` event instead of `UpdateClient`. Clients that
+    /// cannot detect redundant updates can rely on the default
+    /// implementation, which always returns `false`.
+    fn check_for_redundant_update(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _header: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// Collects client-specific telemetry about the update with the given
+    /// header, e.g. validator set rotation or header time lag. It assumes the
+    /// header has already been verified, and is called before `update_state`.
+    ///
+    /// The returned telemetry is attached to the emitted `UpdateClient`
+    /// event. Clients that do not provide telemetry can rely on the default
+    /// implementation, which returns `None`.
+    fn update_telemetry(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _header: Any,
+    ) -> Result<Option<UpdateClientTelemetry>, ClientError> {
+        Ok(None)
+    }
+
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
 }
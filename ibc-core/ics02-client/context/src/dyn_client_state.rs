@@ -0,0 +1,385 @@
+//! Defines an object-safe facade over the client state traits.
+//!
+//! Hosts usually dispatch client calls through a closed enum of all the
+//! client states they support. Hosts registering client types at runtime
+//! (e.g. Wasm platforms) cannot build such an enum, and can instead use
+//! `Box<dyn DynClientState<V, E>>` as their client state type: it implements
+//! all the client state traits by forwarding to the boxed client state, and
+//! [`DynClientStateRegistry`] decodes client states by type URL, or by the
+//! client type prefixing the identifiers of their clients.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::UpdateClientTelemetry;
+use ibc_core_client_types::{Height, Status};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host_types::identifiers::{ClientId, ClientType};
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::client_state::{
+    ClientState, ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use crate::context::{ClientExecutionContext, ClientValidationContext};
+
+/// Object-safe client state, usable as `Box<dyn DynClientState<V, E>>`.
+///
+/// It is automatically implemented for all client states implementing
+/// [`ClientState`], `Clone` and `Into<Any>`. The host contexts are required to
+/// be `'static`, as boxed client states are.
+pub trait DynClientState<V, E>: ClientState<V, E>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    /// Clones the client state into a new box.
+    fn clone_box(&self) -> Box<dyn DynClientState<V, E>>;
+
+    /// Encodes the client state as a protobuf `Any`.
+    fn to_any(&self) -> Any;
+}
+
+impl<V, E, T> DynClientState<V, E> for T
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+    T: ClientState<V, E> + Clone + Into<Any> + 'static,
+{
+    fn clone_box(&self) -> Box<dyn DynClientState<V, E>> {
+        Box::new(self.clone())
+    }
+
+    fn to_any(&self) -> Any {
+        self.clone().into()
+    }
+}
+
+impl<V, E> Clone for Box<dyn DynClientState<V, E>>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl<V, E> From<Box<dyn DynClientState<V, E>>> for Any
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn from(client_state: Box<dyn DynClientState<V, E>>) -> Self {
+        (*client_state).to_any()
+    }
+}
+
+impl<V, E> ClientStateCommon for Box<dyn DynClientState<V, E>>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        (**self).verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        (**self).client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        (**self).latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        (**self).validate_proof_height(proof_height)
+    }
+
+    fn is_past_height(&self, height: Height, timeout_height: Height) -> bool {
+        (**self).is_past_height(height, timeout_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        (**self).verify_upgrade_client(
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            root,
+        )
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        (**self).verify_membership(prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        (**self).verify_non_membership(prefix, proof, root, path)
+    }
+}
+
+impl<V, E> ClientStateValidation<V> for Box<dyn DynClientState<V, E>>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        (**self).verify_client_message(ctx, client_id, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        (**self).check_for_misbehaviour(ctx, client_id, client_message)
+    }
+
+    fn check_for_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        (**self).check_for_redundant_update(ctx, client_id, header)
+    }
+
+    fn update_telemetry(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Option<UpdateClientTelemetry>, ClientError> {
+        (**self).update_telemetry(ctx, client_id, header)
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        (**self).status(ctx, client_id)
+    }
+}
+
+impl<V, E> ClientStateExecution<E> for Box<dyn DynClientState<V, E>>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        (**self).initialise(ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        (**self).update_state(ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        (**self).update_state_on_misbehaviour(ctx, client_id, client_message)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        (**self).update_state_on_upgrade(
+            ctx,
+            client_id,
+            upgraded_client_state,
+            upgraded_consensus_state,
+        )
+    }
+}
+
+/// Decodes a protobuf `Any` into a boxed client state.
+pub type DynClientStateDecoder<V, E> =
+    fn(Any) -> Result<Box<dyn DynClientState<V, E>>, ClientError>;
+
+/// Runtime registry of the client state types supported by a host, keyed by
+/// the type URL of their protobuf encoding, and by the client types of their
+/// clients.
+///
+/// Hosts consult it from their `ValidationContext`, e.g. in
+/// `validate_client_type`, which the create and update client handlers call,
+/// so that client types can be registered at runtime, e.g.
+/// ```rust,ignore
+/// registry.register_client_type::<TmClientState>(tm_client_type(), TENDERMINT_CLIENT_STATE_TYPE_URL);
+/// registry.register_client_type::<WasmClientState>(wasm_client_type(), WASM_CLIENT_STATE_TYPE_URL);
+/// ```
+pub struct DynClientStateRegistry<V, E>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    decoders: BTreeMap<String, DynClientStateDecoder<V, E>>,
+    client_types: BTreeMap<ClientType, String>,
+}
+
+impl<V, E> DynClientStateRegistry<V, E>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            decoders: BTreeMap::new(),
+            client_types: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the client state type `T`, encoded under the given type
+    /// URL. Returns `false` if a client state type was already registered
+    /// under that type URL, in which case it is replaced.
+    pub fn register<T>(&mut self, type_url: impl Into<String>) -> bool
+    where
+        T: DynClientState<V, E> + TryFrom<Any, Error = ClientError> + 'static,
+    {
+        let decoder: DynClientStateDecoder<V, E> = |any| {
+            let client_state: Box<dyn DynClientState<V, E>> = Box::new(T::try_from(any)?);
+            Ok(client_state)
+        };
+
+        self.decoders.insert(type_url.into(), decoder).is_none()
+    }
+
+    /// Registers the client state type `T`, encoded under the given type
+    /// URL, as the one of the clients of the given client type. Returns
+    /// `false` if a client state type was already registered under that
+    /// client type, in which case it is replaced.
+    pub fn register_client_type<T>(
+        &mut self,
+        client_type: ClientType,
+        type_url: impl Into<String>,
+    ) -> bool
+    where
+        T: DynClientState<V, E> + TryFrom<Any, Error = ClientError> + 'static,
+    {
+        let type_url = type_url.into();
+
+        self.register::<T>(type_url.clone());
+
+        self.client_types.insert(client_type, type_url).is_none()
+    }
+
+    /// Returns `true` if a client state type is registered under the given
+    /// type URL.
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Returns `true` if a client state type is registered under the given
+    /// client type.
+    pub fn contains_client_type(&self, client_type: &ClientType) -> bool {
+        self.client_types.contains_key(client_type)
+    }
+
+    /// Returns the registered client types.
+    pub fn client_types(&self) -> impl Iterator<Item = &ClientType> {
+        self.client_types.keys()
+    }
+
+    /// Fails if no client state type is registered under the given client
+    /// type.
+    pub fn validate_client_type(&self, client_type: &ClientType) -> Result<(), ClientError> {
+        if !self.contains_client_type(client_type) {
+            return Err(ClientError::UnregisteredClientType {
+                client_type: client_type.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the registered client type prefixing the given client
+    /// identifier, as in `{client type}-{counter}`.
+    pub fn client_type_of(&self, client_id: &ClientId) -> Result<&ClientType, ClientError> {
+        self.client_types
+            .keys()
+            .find(|client_type| client_id.is_of_type(client_type))
+            .ok_or_else(|| ClientError::UnroutableClientId {
+                client_id: client_id.clone(),
+            })
+    }
+
+    /// Decodes the client state of the given client with the decoder
+    /// registered for the client type prefixing its identifier.
+    pub fn decode_for_client(
+        &self,
+        client_id: &ClientId,
+        any: Any,
+    ) -> Result<Box<dyn DynClientState<V, E>>, ClientError> {
+        let client_type = self.client_type_of(client_id)?;
+
+        if self.client_types.get(client_type) != Some(&any.type_url) {
+            return Err(ClientError::UnknownClientStateType {
+                client_state_type: any.type_url,
+            });
+        }
+
+        self.decode(any)
+    }
+
+    /// Decodes the given `Any` with the decoder registered for its type URL.
+    pub fn decode(&self, any: Any) -> Result<Box<dyn DynClientState<V, E>>, ClientError> {
+        let decoder = self.decoders.get(&any.type_url).ok_or_else(|| {
+            ClientError::UnknownClientStateType {
+                client_state_type: any.type_url.clone(),
+            }
+        })?;
+
+        decoder(any)
+    }
+}
+
+impl<V, E> Default for DynClientStateRegistry<V, E>
+where
+    V: ClientValidationContext + 'static,
+    E: ClientExecutionContext + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
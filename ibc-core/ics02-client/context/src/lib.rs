@@ -20,6 +20,7 @@ extern crate std;
 
 pub mod client_state;
 pub mod consensus_state;
+pub mod dyn_client_state;
 
 mod context;
 pub use context::*;
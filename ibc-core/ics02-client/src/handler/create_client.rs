@@ -8,6 +8,7 @@ use ibc_core_client_types::events::CreateClient;
 use ibc_core_client_types::msgs::MsgCreateClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 
@@ -23,12 +24,18 @@ where
 
     ctx.validate_message_signer(&signer)?;
 
-    // Construct this client's identifier
-    let id_counter = ctx.client_counter()?;
-
     let client_state = ctx.decode_client_state(client_state)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_type = client_state.client_type();
+
+    ctx.validate_client_type(&client_type)?;
+
+    if !ctx.client_params()?.is_allowed(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
+
+    // Allocate this client's identifier
+    let client_id = allocate_client_id(ctx, &client_state)?;
 
     let status = client_state.status(ctx.get_client_validation_context(), &client_id)?;
 
@@ -41,11 +48,40 @@ where
 
     client_state.verify_consensus_state(consensus_state)?;
 
+    Ok(())
+}
+
+/// Allocates the identifier of the new client through the host, and checks that
+/// it is prefixed with the client type and not in use already, so that a host
+/// hook returning the identifier of an existing client never overwrites it.
+fn allocate_client_id<Ctx>(
+    ctx: &Ctx,
+    client_state: &Ctx::AnyClientState,
+) -> Result<ClientId, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_id = ctx.allocate_client_id(client_state)?;
+    let client_type = client_state.client_type();
+
+    let is_prefixed = client_id
+        .as_str()
+        .strip_prefix(client_type.as_str())
+        .map_or(false, |suffix| suffix.len() > 1 && suffix.starts_with('-'));
+
+    if !is_prefixed {
+        return Err(ClientError::InvalidClientIdentifierPrefix {
+            client_id,
+            client_type,
+        }
+        .into());
+    }
+
     if ctx.client_state(&client_id).is_ok() {
         return Err(ClientError::ClientStateAlreadyExists { client_id }.into());
-    };
+    }
 
-    Ok(())
+    Ok(client_id)
 }
 
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
@@ -58,11 +94,11 @@ where
         signer: _,
     } = msg;
 
-    // Construct this client's identifier
-    let id_counter = ctx.client_counter()?;
     let client_state = ctx.decode_client_state(client_state)?;
     let client_type = client_state.client_type();
-    let client_id = client_type.build_client_id(id_counter);
+
+    // Allocate this client's identifier
+    let client_id = allocate_client_id(ctx, &client_state)?;
 
     client_state.initialise(
         ctx.get_client_execution_context(),
@@ -4,7 +4,7 @@ use ibc_core_client_context::client_state::{
     ClientStateCommon, ClientStateExecution, ClientStateValidation,
 };
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::events::{ClientMisbehaviour, UpdateClient};
+use ibc_core_client_types::events::{ClientMisbehaviour, RedundantUpdateClient, UpdateClient};
 use ibc_core_client_types::msgs::MsgUpdateOrMisbehaviour;
 use ibc_core_client_types::UpdateKind;
 use ibc_core_handler_types::error::ContextError;
@@ -24,6 +24,8 @@ where
     // Read client state from the host chain store. The client should already exist.
     let client_state = ctx.client_state(&client_id)?;
 
+    ctx.validate_client_type(&client_state.client_type())?;
+
     client_state
         .status(ctx.get_client_validation_context(), &client_id)?
         .verify_is_active()?;
@@ -44,6 +46,7 @@ where
     Ctx: ExecutionContext,
 {
     let client_id = msg.client_id().clone();
+    let signer = msg.signer().clone();
     let update_kind = match msg {
         MsgUpdateOrMisbehaviour::UpdateClient(_) => UpdateKind::UpdateClient,
         MsgUpdateOrMisbehaviour::Misbehaviour(_) => UpdateKind::SubmitMisbehaviour,
@@ -80,25 +83,62 @@ where
 
         let header = client_message;
 
+        let is_redundant_update = client_state.check_for_redundant_update(
+            ctx.get_client_validation_context(),
+            &client_id,
+            header.clone(),
+        )?;
+
+        if is_redundant_update {
+            // The header was already installed by a previous update: skip
+            // `update_state` so that nothing is written to the store.
+            let event = IbcEvent::RedundantUpdateClient(RedundantUpdateClient::new(
+                client_id,
+                client_state.client_type(),
+                header.to_vec(),
+            ));
+            ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+            ctx.emit_ibc_event(event)?;
+
+            return Ok(());
+        }
+
+        let telemetry = client_state.update_telemetry(
+            ctx.get_client_validation_context(),
+            &client_id,
+            header.clone(),
+        )?;
+
         let consensus_heights = client_state.update_state(
             ctx.get_client_execution_context(),
             &client_id,
             header.clone(),
         )?;
 
+        for consensus_height in &consensus_heights {
+            ctx.store_client_update_relayer(&client_id, *consensus_height, signer.clone())?;
+        }
+
         {
             let event = {
-                let consensus_height = consensus_heights.first().ok_or(ClientError::Other {
-                    description: "client update state returned no updated height".to_string(),
-                })?;
+                let consensus_height = consensus_heights.first().ok_or(
+                    ClientError::MissingUpdatedConsensusHeight {
+                        client_id: client_id.clone(),
+                    },
+                )?;
 
-                IbcEvent::UpdateClient(UpdateClient::new(
+                let event = UpdateClient::new(
                     client_id,
                     client_state.client_type(),
                     *consensus_height,
                     consensus_heights,
                     header.to_vec(),
-                ))
+                );
+
+                IbcEvent::UpdateClient(match telemetry {
+                    Some(telemetry) => event.with_telemetry(telemetry),
+                    None => event,
+                })
             };
             ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
             ctx.emit_ibc_event(event)?;
@@ -0,0 +1,35 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgUpdateParams`.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::MsgUpdateParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if ctx.params_authority().as_ref() != Some(&msg.signer) {
+        return Err(ClientError::UnauthorizedParamsUpdate {
+            signer: msg.signer.clone(),
+        }
+        .into());
+    }
+
+    msg.params.validate()?;
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.log_message(format!(
+        "success: client params updated, allowed clients: {:?}",
+        msg.params.allowed_clients
+    ))?;
+
+    ctx.store_client_params(msg.params)
+}
@@ -1,12 +1,14 @@
 //! Defines the client error type
 
+use core::convert::Infallible;
+
 use displaydoc::Display;
 // use ibc::core::ContextError;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::{ClientId, ClientType};
+use ibc_core_host_types::identifiers::{ChainId, ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -87,10 +89,56 @@ pub enum ClientError {
     MisbehaviourHandlingFailure { reason: String },
     /// client specific error: `{description}`
     ClientSpecific { description: String },
+    /// client identifier `{client_id}` is not prefixed with its client type `{client_type}`
+    InvalidClientIdentifierPrefix {
+        client_id: ClientId,
+        client_type: ClientType,
+    },
     /// client counter overflow error
     CounterOverflow,
     /// update client message did not contain valid header or misbehaviour
     InvalidUpdateClientMessage,
+    /// failed to convert the consensus state of client `{client_id}`: `{description}`
+    ConsensusStateConversionFailed {
+        client_id: ClientId,
+        description: String,
+    },
+    /// invalid chain identifier `{chain_id}` for the light client: `{description}`
+    InvalidLightClientChainId {
+        chain_id: ChainId,
+        description: String,
+    },
+    /// host timestamp must not be zero
+    ZeroHostTimestamp,
+    /// consensus state has an empty commitment root
+    EmptyCommitmentRoot,
+    /// failed to decode `{type_url}`: `{description}`
+    ProtoDecodingFailed {
+        type_url: String,
+        description: String,
+    },
+    /// update of client `{client_id}` returned no updated consensus height
+    MissingUpdatedConsensusHeight { client_id: ClientId },
+    /// invalid client params: `{reason}`
+    InvalidParams { reason: String },
+    /// missing raw client params
+    MissingRawParams,
+    /// client type `{client_type}` is not allowed by the client params
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// signer `{signer}` is not the authority allowed to update the client params
+    UnauthorizedParamsUpdate { signer: Signer },
+    /// the host does not store the client params
+    ParamsStorageNotSupported,
+    /// client type `{client_type}` is not registered by the host
+    UnregisteredClientType { client_type: ClientType },
+    /// client `{client_id}` is not of any registered client type
+    UnroutableClientId { client_id: ClientId },
+    /// invalid consensus state key `{key}` in the client store
+    InvalidConsensusStateKey { key: String },
+    /// invalid processed time of client `{client_id}` in the client store
+    InvalidProcessedTime { client_id: ClientId },
+    /// failed to decode the stored state: `{reason}`
+    InvalidStoredState { reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
@@ -103,6 +151,12 @@ impl From<&'static str> for ClientError {
     }
 }
 
+impl From<Infallible> for ClientError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -134,6 +188,8 @@ pub enum UpgradeClientError {
     InvalidUpgradeProposal { reason: String },
     /// invalid upgrade plan: `{reason}`
     InvalidUpgradePlan { reason: String },
+    /// failed to convert the upgraded client state into the host client state type: `{description}`
+    ClientStateConversionFailed { description: String },
     /// other upgrade client error: `{reason}`
     Other { reason: String },
 }
@@ -1,4 +1,6 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
+use core::time::Duration;
+
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
@@ -10,6 +12,7 @@ use crate::height::Height;
 /// Client event types
 pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
+pub const REDUNDANT_UPDATE_CLIENT_EVENT: &str = "redundant_update_client";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
 
@@ -28,6 +31,26 @@ pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
 /// The content of the `key` field for the header in update client event.
 pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
+/// The content of the `key` field for the number of validators that joined or
+/// left the validator set with an update.
+pub const VALIDATOR_SET_ROTATION_SIZE_ATTRIBUTE_KEY: &str = "validator_set_rotation_size";
+
+/// The content of the `key` field for the lag, in nanoseconds, between the
+/// header time and the host time at which the update was processed.
+pub const HEADER_TIME_LAG_ATTRIBUTE_KEY: &str = "header_time_lag";
+
+/// The content of the `key` field for whether the update was adjacent to its
+/// trusted height.
+pub const ADJACENT_UPDATE_ATTRIBUTE_KEY: &str = "adjacent_update";
+
+/// The content of the `key` field for the trusted height from which the
+/// header of an update was verified.
+pub const TRUSTED_HEIGHT_ATTRIBUTE_KEY: &str = "trusted_height";
+
+/// The content of the `key` field for the hash of the verified header of an
+/// update.
+pub const HEADER_HASH_ATTRIBUTE_KEY: &str = "header_hash";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -160,6 +183,132 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     }
 }
 
+/// Client-specific telemetry attached to an [`UpdateClient`] event, allowing
+/// operators to monitor client health and relayer behaviour from event
+/// streams.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClientTelemetry {
+    validator_set_rotation_size: u64,
+    header_time_lag_nanos: u64,
+    adjacent: bool,
+    trusted_height: Option<Height>,
+    header_hash: Option<Vec<u8>>,
+}
+
+impl UpdateClientTelemetry {
+    /// Constructs the telemetry of an update.
+    ///
+    /// NOTE: a `header_time_lag` that does not fit in a `u64` of nanoseconds
+    /// is saturated.
+    pub fn new(
+        validator_set_rotation_size: u64,
+        header_time_lag: Duration,
+        adjacent: bool,
+    ) -> Self {
+        Self {
+            validator_set_rotation_size,
+            header_time_lag_nanos: u64::try_from(header_time_lag.as_nanos()).unwrap_or(u64::MAX),
+            adjacent,
+            trusted_height: None,
+            header_hash: None,
+        }
+    }
+
+    /// Attaches the trusted height from which the header was verified, so
+    /// that the verification lineage of the client can be reconstructed from
+    /// events alone.
+    pub fn with_trusted_height(self, trusted_height: Height) -> Self {
+        Self {
+            trusted_height: Some(trusted_height),
+            ..self
+        }
+    }
+
+    /// Attaches the hash of the verified header, e.g. for misbehaviour
+    /// monitors to compare it with the header committed by the counterparty.
+    pub fn with_header_hash(self, header_hash: Vec<u8>) -> Self {
+        Self {
+            header_hash: Some(header_hash),
+            ..self
+        }
+    }
+
+    /// Number of validators that joined or left the validator set with the
+    /// update.
+    pub fn validator_set_rotation_size(&self) -> u64 {
+        self.validator_set_rotation_size
+    }
+
+    /// Time elapsed between the header time and the host time at which the
+    /// update was processed. Zero if the header is ahead of the host.
+    pub fn header_time_lag(&self) -> Duration {
+        Duration::from_nanos(self.header_time_lag_nanos)
+    }
+
+    /// Whether the header directly follows its trusted height.
+    pub fn is_adjacent(&self) -> bool {
+        self.adjacent
+    }
+
+    /// The trusted height from which the header was verified, if known.
+    pub fn trusted_height(&self) -> Option<&Height> {
+        self.trusted_height.as_ref()
+    }
+
+    /// The hash of the verified header, if known.
+    pub fn header_hash(&self) -> Option<&[u8]> {
+        self.header_hash.as_deref()
+    }
+}
+
+impl From<UpdateClientTelemetry> for Vec<abci::EventAttribute> {
+    fn from(t: UpdateClientTelemetry) -> Self {
+        let mut attributes = vec![
+            (
+                VALIDATOR_SET_ROTATION_SIZE_ATTRIBUTE_KEY,
+                t.validator_set_rotation_size.to_string(),
+            )
+                .into(),
+            (
+                HEADER_TIME_LAG_ATTRIBUTE_KEY,
+                t.header_time_lag_nanos.to_string(),
+            )
+                .into(),
+            (ADJACENT_UPDATE_ATTRIBUTE_KEY, t.adjacent.to_string()).into(),
+        ];
+
+        if let Some(trusted_height) = t.trusted_height {
+            attributes.push((TRUSTED_HEIGHT_ATTRIBUTE_KEY, trusted_height.to_string()).into());
+        }
+
+        if let Some(header_hash) = t.header_hash {
+            attributes.push(
+                (
+                    HEADER_HASH_ATTRIBUTE_KEY,
+                    str::from_utf8(&hex::encode(header_hash))
+                        .expect("Never fails because hexadecimal is valid UTF-8"),
+                )
+                    .into(),
+            );
+        }
+
+        attributes
+    }
+}
+
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -243,6 +392,7 @@ pub struct UpdateClient {
     consensus_height: ConsensusHeightAttribute,
     consensus_heights: ConsensusHeightsAttribute,
     header: HeaderAttribute,
+    telemetry: Option<UpdateClientTelemetry>,
 }
 
 impl UpdateClient {
@@ -263,6 +413,16 @@ impl UpdateClient {
             consensus_height: ConsensusHeightAttribute::from(consensus_height),
             consensus_heights: ConsensusHeightsAttribute::from(consensus_heights),
             header: HeaderAttribute::from(header),
+            telemetry: None,
+        }
+    }
+
+    /// Attaches client-specific telemetry to the event, which is appended to
+    /// its attributes.
+    pub fn with_telemetry(self, telemetry: UpdateClientTelemetry) -> Self {
+        Self {
+            telemetry: Some(telemetry),
+            ..self
         }
     }
 
@@ -286,6 +446,10 @@ impl UpdateClient {
         &self.header.header
     }
 
+    pub fn telemetry(&self) -> Option<&UpdateClientTelemetry> {
+        self.telemetry.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         UPDATE_CLIENT_EVENT
     }
@@ -293,15 +457,83 @@ impl UpdateClient {
 
 impl From<UpdateClient> for abci::Event {
     fn from(u: UpdateClient) -> Self {
+        let mut attributes = vec![
+            u.client_id.into(),
+            u.client_type.into(),
+            u.consensus_height.into(),
+            u.consensus_heights.into(),
+            u.header.into(),
+        ];
+
+        if let Some(telemetry) = u.telemetry {
+            attributes.extend(Vec::<abci::EventAttribute>::from(telemetry));
+        }
+
         Self {
             kind: UPDATE_CLIENT_EVENT.to_owned(),
-            attributes: vec![
-                u.client_id.into(),
-                u.client_type.into(),
-                u.consensus_height.into(),
-                u.consensus_heights.into(),
-                u.header.into(),
-            ],
+            attributes,
+        }
+    }
+}
+
+/// RedundantUpdateClient event signals that a submitted header was already
+/// installed on the client and its update was a no-op. Relayers can use it to
+/// detect wasted submissions.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedundantUpdateClient {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+    header: HeaderAttribute,
+}
+
+impl RedundantUpdateClient {
+    /// Constructs a new RedundantUpdateClient event.
+    ///
+    /// NOTE: the `header` is the encoded bytes of the
+    /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    pub fn new(client_id: ClientId, client_type: ClientType, header: Vec<u8>) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+            header: HeaderAttribute::from(header),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn header(&self) -> &Vec<u8> {
+        &self.header.header
+    }
+
+    pub fn event_type(&self) -> &str {
+        REDUNDANT_UPDATE_CLIENT_EVENT
+    }
+}
+
+impl From<RedundantUpdateClient> for abci::Event {
+    fn from(u: RedundantUpdateClient) -> Self {
+        Self {
+            kind: REDUNDANT_UPDATE_CLIENT_EVENT.to_owned(),
+            attributes: vec![u.client_id.into(), u.client_type.into(), u.header.into()],
         }
     }
 }
@@ -37,6 +37,16 @@ pub struct Height {
     revision_height: u64,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Height {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            revision_number: u64::arbitrary(u)?,
+            revision_height: u.int_in_range(1..=u64::MAX)?,
+        })
+    }
+}
+
 impl Height {
     pub fn new(revision_number: u64, revision_height: u64) -> Result<Self, ClientError> {
         if revision_height == 0 {
@@ -89,6 +99,35 @@ impl Height {
     pub fn decrement(&self) -> Result<Height, ClientError> {
         self.sub(1)
     }
+
+    /// Adds `delta` to the revision height. Returns `None` on overflow.
+    pub fn checked_add(&self, delta: u64) -> Option<Height> {
+        Some(Height {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height.checked_add(delta)?,
+        })
+    }
+
+    /// Subtracts `delta` from the revision height. Returns `None` if the
+    /// resulting revision height would be zero or would underflow.
+    pub fn checked_sub(&self, delta: u64) -> Option<Height> {
+        self.sub(delta).ok()
+    }
+
+    /// Adds `delta` to the revision height, saturating at `u64::MAX`.
+    pub fn saturating_add(&self, delta: u64) -> Height {
+        Height {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height.saturating_add(delta),
+        }
+    }
+
+    /// Subtracts `delta` from the revision height, saturating at the minimum
+    /// height of the revision, i.e. [`Height::min`].
+    pub fn saturating_sub(&self, delta: u64) -> Height {
+        self.checked_sub(delta)
+            .unwrap_or_else(|| Height::min(self.revision_number))
+    }
 }
 
 impl PartialOrd for Height {
@@ -261,3 +300,19 @@ fn test_invalid_height() {
         })
     );
 }
+
+#[test]
+fn test_height_arithmetic() {
+    let height = Height::new(1, 10).unwrap();
+    let max_height = Height::new(1, u64::MAX).unwrap();
+
+    assert_eq!(height.checked_add(5), Height::new(1, 15).ok());
+    assert_eq!(max_height.checked_add(1), None);
+    assert_eq!(height.checked_sub(9), Height::new(1, 1).ok());
+    assert_eq!(height.checked_sub(10), None);
+
+    assert_eq!(height.saturating_add(5), Height::new(1, 15).unwrap());
+    assert_eq!(max_height.saturating_add(1), max_height);
+    assert_eq!(height.saturating_sub(9), Height::min(1));
+    assert_eq!(height.saturating_sub(u64::MAX), Height::min(1));
+}
@@ -16,9 +16,12 @@ pub const CREATE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgCreateClient {
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::any))]
     pub client_state: Any,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::any))]
     pub consensus_state: Any,
     pub signer: Signer,
 }
@@ -10,11 +10,13 @@ use ibc_proto::google::protobuf::Any;
 mod create_client;
 mod misbehaviour;
 mod update_client;
+mod update_params;
 mod upgrade_client;
 
 pub use create_client::*;
 pub use misbehaviour::*;
 pub use update_client::*;
+pub use update_params::*;
 pub use upgrade_client::*;
 
 /// Encodes all the different client messages
@@ -24,12 +26,14 @@ pub use upgrade_client::*;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum ClientMsg {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
     Misbehaviour(MsgSubmitMisbehaviour),
     UpgradeClient(MsgUpgradeClient),
+    UpdateParams(MsgUpdateParams),
 }
 
 pub enum MsgUpdateOrMisbehaviour {
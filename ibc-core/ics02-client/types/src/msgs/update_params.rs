@@ -0,0 +1,52 @@
+//! Definition of domain type message `MsgUpdateParams`.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::client::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::ClientParams;
+
+pub const UPDATE_CLIENT_PARAMS_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateParams";
+
+/// A type of message that updates the parameters of the client submodule.
+///
+/// It is routed like the other client messages, but must be signed by the
+/// parameters authority of the host, i.e. usually its governance module.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateParams {
+    pub params: ClientParams,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateParams {
+            params: raw
+                .params
+                .ok_or(ClientError::MissingRawParams)?
+                .try_into()?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgUpdateParams> for RawMsgUpdateParams {
+    fn from(ics_msg: MsgUpdateParams) -> Self {
+        RawMsgUpdateParams {
+            signer: ics_msg.signer.to_string(),
+            params: Some(ics_msg.params.into()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Defines the parameters of the client submodule.
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawClientParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+/// The entry of [`ClientParams::allowed_clients`] allowing all the client types.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// The parameters of the client submodule, as in ibc-go.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientParams {
+    /// The client types allowed to be created, or [`ALLOW_ALL_CLIENTS`].
+    pub allowed_clients: Vec<String>,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientParams {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        ClientParams::new(Vec::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl ClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Result<Self, ClientError> {
+        let params = Self { allowed_clients };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Checks that the allowed client types are neither blank nor duplicated,
+    /// as ibc-go does.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        for (i, client_type) in self.allowed_clients.iter().enumerate() {
+            if client_type.trim().is_empty() {
+                return Err(ClientError::InvalidParams {
+                    reason: format!("allowed client type {i} cannot be blank"),
+                });
+            }
+
+            if self.allowed_clients[..i].contains(client_type) {
+                return Err(ClientError::InvalidParams {
+                    reason: format!("allowed client type {client_type} is duplicated"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether clients of the given type are allowed to be created.
+    pub fn is_allowed(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_CLIENTS || allowed == client_type.as_str())
+    }
+}
+
+impl Default for ClientParams {
+    /// Allows all the client types.
+    fn default() -> Self {
+        Self {
+            allowed_clients: vec![ALLOW_ALL_CLIENTS.to_string()],
+        }
+    }
+}
+
+impl Protobuf<RawClientParams> for ClientParams {}
+
+impl TryFrom<RawClientParams> for ClientParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawClientParams) -> Result<Self, Self::Error> {
+        Self::new(raw.allowed_clients)
+    }
+}
+
+impl From<ClientParams> for RawClientParams {
+    fn from(params: ClientParams) -> Self {
+        Self {
+            allowed_clients: params.allowed_clients,
+        }
+    }
+}
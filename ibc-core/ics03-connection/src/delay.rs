@@ -25,7 +25,7 @@ where
 
     // Fetch the connection delay time and height periods.
     let conn_delay_time_period = connection_end.delay_period();
-    let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period);
+    let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period)?;
 
     // Verify that the current host chain time is later than the last client update time
     let earliest_valid_time = (last_client_update.0 + conn_delay_time_period)
@@ -40,7 +40,9 @@ where
     }
 
     // Verify that the current host chain height is later than the last client update height
-    let earliest_valid_height = last_client_update.1.add(conn_delay_height_period);
+    let earliest_valid_height = last_client_update
+        .1
+        .saturating_add(conn_delay_height_period);
     if current_host_height < earliest_valid_height {
         return Err(ContextError::ConnectionError(
             ConnectionError::NotEnoughBlocksElapsed {
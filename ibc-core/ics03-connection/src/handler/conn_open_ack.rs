@@ -33,9 +33,12 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
-    let host_height = ctx_a.host_height().map_err(|_| ConnectionError::Other {
-        description: "failed to get host height".to_string(),
-    })?;
+    ctx_a
+        .identifier_policy()
+        .validate_connection_identifier(msg.conn_id_on_b.as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+
+    let host_height = ctx_a.host_height()?;
     if msg.consensus_height_of_a_on_b > host_height {
         return Err(ConnectionError::InvalidConsensusHeight {
             target_height: msg.consensus_height_of_a_on_b,
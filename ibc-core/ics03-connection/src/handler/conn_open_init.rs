@@ -1,7 +1,9 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
 use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
 use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
@@ -16,6 +18,11 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    ctx_a
+        .identifier_policy()
+        .validate_client_identifier(msg.counterparty.client_id().as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+
     // An IBC client running on the local (host) chain should exist.
     let client_state_of_b_on_a = ctx_a.client_state(&msg.client_id_on_a)?;
 
@@ -23,9 +30,7 @@ where
         .status(ctx_a.get_client_validation_context(), &msg.client_id_on_a)?
         .verify_is_active()?;
 
-    if let Some(version) = msg.version {
-        version.verify_is_supported(&ctx_a.get_compatible_versions())?;
-    }
+    versions_on_a(ctx_a, msg.version)?;
 
     Ok(())
 }
@@ -34,12 +39,7 @@ pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenInit) -> Result<(), C
 where
     Ctx: ExecutionContext,
 {
-    let versions = if let Some(version) = msg.version {
-        version.verify_is_supported(&ctx_a.get_compatible_versions())?;
-        vec![version]
-    } else {
-        ctx_a.get_compatible_versions()
-    };
+    let versions = versions_on_a(ctx_a, msg.version)?;
 
     let conn_end_on_a = ConnectionEnd::new(
         State::Init,
@@ -81,3 +81,28 @@ where
 
     Ok(())
 }
+
+/// Returns the versions of the connection end, negotiated against the versions
+/// supported by the host: the proposed version if it is supported, or all the
+/// supported versions otherwise.
+fn versions_on_a<Ctx>(
+    ctx_a: &Ctx,
+    version: Option<ConnectionVersion>,
+) -> Result<Vec<ConnectionVersion>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let supported_versions = ctx_a.get_compatible_versions();
+
+    if supported_versions.is_empty() {
+        return Err(ConnectionError::EmptyVersions.into());
+    }
+
+    match version {
+        Some(version) => {
+            version.verify_is_supported(&supported_versions)?;
+            Ok(vec![version])
+        }
+        None => Ok(supported_versions),
+    }
+}
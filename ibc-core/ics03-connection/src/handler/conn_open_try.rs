@@ -34,11 +34,19 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    let policy = ctx_b.identifier_policy();
+    policy
+        .validate_client_identifier(msg.counterparty.client_id().as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+    if let Some(conn_id_on_a) = msg.counterparty.connection_id() {
+        policy
+            .validate_connection_identifier(conn_id_on_a.as_str())
+            .map_err(ConnectionError::InvalidIdentifier)?;
+    }
+
     ctx_b.validate_self_client(msg.client_state_of_b_on_a.clone())?;
 
-    let host_height = ctx_b.host_height().map_err(|_| ConnectionError::Other {
-        description: "failed to get host height".to_string(),
-    })?;
+    let host_height = ctx_b.host_height()?;
     if msg.consensus_height_of_b_on_a > host_height {
         // Fail if the consensus height is too advanced.
         return Err(ConnectionError::InvalidConsensusHeight {
@@ -182,7 +190,8 @@ impl LocalVars {
     where
         Ctx: ValidationContext,
     {
-        let version_on_b = ctx_b.pick_version(&msg.versions_on_a)?;
+        let versions_on_a = ctx_b.filter_counterparty_versions(&msg.versions_on_a)?;
+        let version_on_b = ctx_b.pick_version(&versions_on_a)?;
 
         Ok(Self {
             conn_id_on_b: ConnectionId::new(ctx_b.connection_counter()?),
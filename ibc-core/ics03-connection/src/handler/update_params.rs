@@ -0,0 +1,33 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgUpdateParams`.
+
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::msgs::MsgUpdateParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if ctx.params_authority().as_ref() != Some(&msg.signer) {
+        return Err(ConnectionError::UnauthorizedParamsUpdate {
+            signer: msg.signer.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.log_message(format!(
+        "success: connection params updated, max expected time per block: {:?}",
+        msg.params.max_expected_time_per_block
+    ))?;
+
+    ctx.store_connection_params(msg.params)
+}
@@ -378,6 +378,7 @@ impl ConnectionEnd {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Counterparty {
     pub client_id: ClientId,
@@ -470,6 +471,23 @@ impl Counterparty {
     }
 }
 
+/// Generates an arbitrary counterparty with no connection identifier, for use
+/// with `#[arbitrary(with = ...)]` on the `ConnOpenInit` message.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_init_counterparty(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<Counterparty> {
+    use arbitrary::Arbitrary;
+
+    let counterparty = Counterparty::arbitrary(u)?;
+
+    Ok(Counterparty::new(
+        counterparty.client_id,
+        None,
+        counterparty.prefix,
+    ))
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -483,6 +501,7 @@ impl Counterparty {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum State {
     Uninitialized = 0isize,
@@ -5,7 +5,7 @@ use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Timestamp, TimestampOverflowError};
+use ibc_primitives::{Signer, Timestamp, TimestampOverflowError};
 
 use crate::version::Version;
 
@@ -83,6 +83,14 @@ pub enum ConnectionError {
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
     CounterOverflow,
+    /// invalid connection params: `{reason}`
+    InvalidParams { reason: String },
+    /// missing raw connection params
+    MissingRawParams,
+    /// signer `{signer}` is not the authority allowed to update the connection params
+    UnauthorizedParamsUpdate { signer: Signer },
+    /// the host does not store the connection params
+    ParamsStorageNotSupported,
     /// other error: `{description}`
     Other { description: String },
 }
@@ -20,6 +20,8 @@ pub use connection::*;
 pub mod error;
 pub mod events;
 pub mod msgs;
+mod params;
+pub use params::*;
 pub mod version;
 
 /// Re-exports ICS-03 proto types from the `ibc-proto` crate for added
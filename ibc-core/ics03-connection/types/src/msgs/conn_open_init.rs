@@ -14,13 +14,19 @@ pub const CONN_OPEN_INIT_TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnection
 
 /// Per our convention, this message is sent to chain A.
 /// The handler will check proofs of chain B.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MsgConnectionOpenInit {
     /// ClientId on chain A that the connection is being opened for
     pub client_id_on_a: ClientId,
+    #[cfg_attr(
+        feature = "arbitrary",
+        arbitrary(with = crate::connection::arbitrary_init_counterparty)
+    )]
     pub counterparty: Counterparty,
     pub version: Option<Version>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::duration))]
     pub delay_period: Duration,
     pub signer: Signer,
 }
@@ -18,15 +18,21 @@ pub const CONN_OPEN_TRY_TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionO
 /// Per our convention, this message is sent to chain B.
 /// The handler will check proofs of chain A.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgConnectionOpenTry {
     /// ClientId on B that the connection is being opened for
     pub client_id_on_b: ClientId,
     /// ClientState of client tracking chain B on chain A
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::any))]
     pub client_state_of_b_on_a: Any,
     /// ClientId, ConnectionId and prefix of chain A
     pub counterparty: Counterparty,
     /// Versions supported by chain A
+    #[cfg_attr(
+        feature = "arbitrary",
+        arbitrary(with = crate::version::arbitrary_versions)
+    )]
     pub versions_on_a: Vec<Version>,
     /// proof of ConnectionEnd stored on Chain A during ConnOpenInit
     pub proof_conn_end_on_a: CommitmentProofBytes,
@@ -38,6 +44,7 @@ pub struct MsgConnectionOpenTry {
     pub proofs_height_on_a: Height,
     /// height of latest header of chain A that updated the client on chain B
     pub consensus_height_of_b_on_a: Height,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::duration))]
     pub delay_period: Duration,
     pub signer: Signer,
     /// optional proof of host state machines (chain B) that are unable to
@@ -18,11 +18,13 @@ mod conn_open_ack;
 mod conn_open_confirm;
 mod conn_open_init;
 mod conn_open_try;
+mod update_params;
 
 pub use conn_open_ack::*;
 pub use conn_open_confirm::*;
 pub use conn_open_init::*;
 pub use conn_open_try::*;
+pub use update_params::*;
 
 /// Enumeration of all possible messages that the ICS3 protocol processes.
 #[cfg_attr(
@@ -30,10 +32,12 @@ pub use conn_open_try::*;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum ConnectionMsg {
     OpenInit(MsgConnectionOpenInit),
     OpenTry(MsgConnectionOpenTry),
     OpenAck(MsgConnectionOpenAck),
     OpenConfirm(MsgConnectionOpenConfirm),
+    UpdateParams(MsgUpdateParams),
 }
@@ -0,0 +1,52 @@
+//! Definition of domain type message `MsgUpdateParams`.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::connection::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ConnectionError;
+use crate::ConnectionParams;
+
+pub const UPDATE_CONNECTION_PARAMS_TYPE_URL: &str = "/ibc.core.connection.v1.MsgUpdateParams";
+
+/// A type of message that updates the parameters of the connection submodule.
+///
+/// It is routed like the handshake messages, but must be signed by the
+/// parameters authority of the host, i.e. usually its governance module.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateParams {
+    pub params: ConnectionParams,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateParams {
+    type Error = ConnectionError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateParams {
+            params: raw
+                .params
+                .ok_or(ConnectionError::MissingRawParams)?
+                .try_into()?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgUpdateParams> for RawMsgUpdateParams {
+    fn from(ics_msg: MsgUpdateParams) -> Self {
+        RawMsgUpdateParams {
+            signer: ics_msg.signer.to_string(),
+            params: Some(ics_msg.params.into()),
+        }
+    }
+}
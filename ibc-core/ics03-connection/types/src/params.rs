@@ -0,0 +1,117 @@
+//! Defines the parameters of the connection submodule.
+
+use core::time::Duration;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::connection::v1::Params as RawConnectionParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ConnectionError;
+
+/// The default maximum expected time per block, as in ibc-go.
+pub const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: Duration = Duration::from_secs(30);
+
+/// The parameters of the connection submodule, as in ibc-go.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionParams {
+    /// The maximum expected time per block of the host, used alongside the
+    /// connection delay periods to enforce block delays.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = ibc_primitives::fuzzing::duration))]
+    pub max_expected_time_per_block: Duration,
+}
+
+/// This module encapsulates the workarounds we need to do to implement
+/// `BorshSerialize` and `BorshDeserialize` on `ConnectionParams`
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+    use borsh::maybestd::io::{self, Read};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct InnerConnectionParams {
+        pub max_expected_time_per_block_nanos: u64,
+    }
+
+    impl BorshSerialize for ConnectionParams {
+        fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let max_expected_time_per_block_nanos: u64 = self
+                .max_expected_time_per_block
+                .as_nanos()
+                .try_into()
+                .map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!(
+                            "Duration too long: {} nanos",
+                            self.max_expected_time_per_block.as_nanos()
+                        ),
+                    )
+                })?;
+
+            let inner = InnerConnectionParams {
+                max_expected_time_per_block_nanos,
+            };
+
+            inner.serialize(writer)
+        }
+    }
+
+    impl BorshDeserialize for ConnectionParams {
+        fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+            let inner = InnerConnectionParams::deserialize_reader(reader)?;
+
+            Ok(ConnectionParams::new(Duration::from_nanos(
+                inner.max_expected_time_per_block_nanos,
+            )))
+        }
+    }
+}
+
+impl ConnectionParams {
+    pub fn new(max_expected_time_per_block: Duration) -> Self {
+        Self {
+            max_expected_time_per_block,
+        }
+    }
+}
+
+impl Default for ConnectionParams {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK)
+    }
+}
+
+impl Protobuf<RawConnectionParams> for ConnectionParams {}
+
+impl TryFrom<RawConnectionParams> for ConnectionParams {
+    type Error = ConnectionError;
+
+    fn try_from(raw: RawConnectionParams) -> Result<Self, Self::Error> {
+        if raw.max_expected_time_per_block == 0 {
+            return Err(ConnectionError::InvalidParams {
+                reason: "max expected time per block cannot be zero".to_string(),
+            });
+        }
+
+        // expressed in nanoseconds, as in ibc-go
+        Ok(Self::new(Duration::from_nanos(
+            raw.max_expected_time_per_block,
+        )))
+    }
+}
+
+impl From<ConnectionParams> for RawConnectionParams {
+    fn from(params: ConnectionParams) -> Self {
+        Self {
+            max_expected_time_per_block: u64::try_from(
+                params.max_expected_time_per_block.as_nanos(),
+            )
+            .unwrap_or(u64::MAX),
+        }
+    }
+}
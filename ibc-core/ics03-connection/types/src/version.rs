@@ -32,7 +32,53 @@ pub struct Version {
     features: Vec<String>,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Version {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Version::new(String::arbitrary(u)?, Vec::arbitrary(u)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Generates a non-empty list of arbitrary versions, for use with
+/// `#[arbitrary(with = ...)]` on the fields that carry the versions supported
+/// by a chain.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_versions(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<Vec<Version>> {
+    use arbitrary::Arbitrary;
+
+    let mut versions = vec![Version::arbitrary(u)?];
+    versions.extend(Vec::<Version>::arbitrary(u)?);
+
+    Ok(versions)
+}
+
 impl Version {
+    /// Builds a version out of its identifier and the features it supports,
+    /// e.g. for hosts experimenting with new channel ordering features.
+    ///
+    /// Fails if the identifier or one of the features is empty.
+    pub fn new(
+        identifier: impl Into<String>,
+        features: Vec<String>,
+    ) -> Result<Self, ConnectionError> {
+        RawVersion {
+            identifier: identifier.into(),
+            features,
+        }
+        .try_into()
+    }
+
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
     /// Checks whether the version has a matching version identifier and its
     /// feature set is a subset of the supported features
     pub fn verify_is_supported(
@@ -332,6 +378,31 @@ mod tests {
             );
         }
     }
+    #[test]
+    fn custom_features() {
+        assert!(Version::new("", vec!["ORDER_ORDERED".to_string()]).is_err());
+        assert!(Version::new("1", vec!["".to_string()]).is_err());
+
+        let custom_feature = "ORDER_ORDERED_ALLOW_TIMEOUT".to_string();
+        let supported = vec![Version::new(
+            "1",
+            vec!["ORDER_UNORDERED".to_string(), custom_feature.clone()],
+        )
+        .unwrap()];
+        let counterparty = vec![Version::new(
+            "1",
+            vec!["ORDER_ORDERED".to_string(), custom_feature.clone()],
+        )
+        .unwrap()];
+
+        let version = pick_version(&supported, &counterparty).unwrap();
+
+        assert_eq!(version.identifier(), "1");
+        assert_eq!(version.features(), &[custom_feature.clone()]);
+        assert!(version.verify_is_supported(&supported).is_ok());
+        assert!(version.verify_feature_supported(custom_feature).is_ok());
+    }
+
     #[test]
     fn pick() {
         struct Test {
@@ -1,10 +1,12 @@
 //! ICS4 (channel) context.
 
 use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::commitment::{compute_packet_commitment_with, PacketCommitment};
+use ibc_core_channel_types::timeout::TimeoutHeight;
 use ibc_core_client::context::client_state::ClientState;
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_commitment_types::host_functions::DefaultHostFunctions;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
@@ -14,6 +16,7 @@ use ibc_core_host::types::path::{
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 
 /// Methods required in send packet validation, to be implemented by the host
 pub trait SendPacketValidationContext {
@@ -42,6 +45,27 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the maximum size, in bytes, of the packet data. It is
+    /// unbounded by default.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Computes the commitment of a packet, hashing with the pure-Rust
+    /// [`DefaultHostFunctions`] by default.
+    fn compute_packet_commitment(
+        &self,
+        packet_data: &[u8],
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> PacketCommitment {
+        compute_packet_commitment_with::<DefaultHostFunctions>(
+            packet_data,
+            timeout_height,
+            timeout_timestamp,
+        )
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -82,6 +106,24 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        ValidationContext::max_packet_data_size(self)
+    }
+
+    fn compute_packet_commitment(
+        &self,
+        packet_data: &[u8],
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> PacketCommitment {
+        ValidationContext::compute_packet_commitment(
+            self,
+            packet_data,
+            timeout_height,
+            timeout_timestamp,
+        )
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -98,6 +140,15 @@ pub trait SendPacketExecutionContext: SendPacketValidationContext {
         commitment: PacketCommitment,
     ) -> Result<(), ContextError>;
 
+    /// Records the current host timestamp as the send time of the packet
+    /// committed at the given store path. Records nothing by default.
+    fn record_packet_send_timestamp(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Ibc events
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
@@ -125,6 +176,14 @@ where
         self.store_packet_commitment(commitment_path, commitment)
     }
 
+    fn record_packet_send_timestamp(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        let timestamp = self.host_timestamp()?;
+        self.store_packet_send_timestamp(commitment_path, timestamp)
+    }
+
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
         self.emit_ibc_event(event)
     }
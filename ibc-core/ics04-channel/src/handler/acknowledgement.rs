@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
@@ -14,8 +13,11 @@ use ibc_core_host::types::path::{
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
+use ibc_core_router::types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 
+use super::callback;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -26,9 +28,11 @@ where
 {
     validate(ctx_a, &msg)?;
 
-    module
-        .on_acknowledgement_packet_validate(&msg.packet, &msg.acknowledgement, &msg.signer)
-        .map_err(ContextError::PacketError)
+    callback::invoke(|| {
+        module.on_acknowledgement_packet_validate(&msg.packet, &msg.acknowledgement, &msg.signer)
+    })
+    .unwrap_or_else(|description| Err(PacketError::AppModule { description }))
+    .map_err(ContextError::PacketError)
 }
 
 pub fn acknowledgement_packet_execute<ExecCtx>(
@@ -44,21 +48,34 @@ where
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
-    // In all cases, this event is emitted
-    let event = IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
-        msg.packet.clone(),
-        chan_end_on_a.ordering,
-        conn_id_on_a.clone(),
-    ));
-    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-    ctx_a.emit_ibc_event(event)?;
-
     let commitment_path_on_a = CommitmentPath::new(
         &msg.packet.port_id_on_a,
         &msg.packet.chan_id_on_a,
         msg.packet.seq_on_a,
     );
 
+    // In all cases, this event is emitted
+    let event = {
+        let event = AcknowledgePacket::new(
+            msg.packet.clone(),
+            chan_end_on_a.ordering,
+            conn_id_on_a.clone(),
+        )
+        .with_relayer(msg.signer.clone());
+
+        // The latency is only known if the host recorded the send time
+        let latency = ctx_a
+            .get_packet_send_timestamp(&commitment_path_on_a)?
+            .and_then(|sent_at| ctx_a.host_timestamp().ok()?.duration_since(&sent_at));
+
+        IbcEvent::AcknowledgePacket(match latency {
+            Some(latency) => event.with_latency(latency),
+            None => event,
+        })
+    };
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx_a.emit_ibc_event(event)?;
+
     // check if we're in the NO-OP case
     if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
         // This error indicates that the timeout has already been relayed
@@ -68,19 +85,22 @@ where
         return Ok(());
     };
 
-    let (extras, cb_result) =
-        module.on_acknowledgement_packet_execute(&msg.packet, &msg.acknowledgement, &msg.signer);
+    let (extras, cb_result) = callback::invoke(|| {
+        module.on_acknowledgement_packet_execute(&msg.packet, &msg.acknowledgement, &msg.signer)
+    })
+    .unwrap_or_else(|description| {
+        (
+            ModuleExtras::empty(),
+            Err(PacketError::AppModule { description }),
+        )
+    });
 
     cb_result?;
 
     // apply state changes
     {
-        let commitment_path_on_a = CommitmentPath {
-            port_id: msg.packet.port_id_on_a.clone(),
-            channel_id: msg.packet.chan_id_on_a.clone(),
-            sequence: msg.packet.seq_on_a,
-        };
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.delete_packet_send_timestamp(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
@@ -148,7 +168,7 @@ where
     };
 
     if commitment_on_a
-        != compute_packet_commitment(
+        != ctx_a.compute_packet_commitment(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
@@ -188,7 +208,7 @@ where
             msg.proof_height_on_b.revision_height(),
         );
         let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
+        let ack_commitment = ctx_a.compute_ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
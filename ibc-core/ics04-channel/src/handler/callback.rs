@@ -0,0 +1,63 @@
+//! Invocation of the application module callbacks by the channel handlers.
+
+use ibc_core_channel_types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_primitives::prelude::*;
+
+/// The error written when an application module callback panics, either as
+/// the acknowledgement of the received packet or as the handler error. The
+/// panic message is left out on purpose, as it is not guaranteed to be
+/// deterministic across the nodes of the host.
+const PANIC_ACK_STATUS: &str = "application module panicked";
+
+/// Invokes an application module callback.
+///
+/// With the `catch-unwind` feature, a panic of the callback is caught and
+/// returned as an error, without its payload, so that the handler turns it
+/// into an error acknowledgement or a handler error instead of unwinding
+/// through the host transaction. Hosts which cannot unwind, e.g. built with
+/// `panic = "abort"`, leave the feature disabled and the callback is invoked
+/// as is.
+///
+/// Note that the state changes made by the callback before it panicked are
+/// not reverted by the handler. See the [`Module`] docs for what this means
+/// for the host.
+///
+/// [`Module`]: ibc_core_router::module::Module
+pub(crate) fn invoke<T>(callback: impl FnOnce() -> T) -> Result<T, String> {
+    #[cfg(feature = "catch-unwind")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback))
+            .map_err(|_| PANIC_ACK_STATUS.to_string())
+    }
+
+    #[cfg(not(feature = "catch-unwind"))]
+    {
+        Ok(callback())
+    }
+}
+
+/// Returns the error acknowledgement of a packet whose `onRecvPacket`
+/// callback panicked.
+pub(crate) fn panic_acknowledgement() -> Acknowledgement {
+    AcknowledgementStatus::error(StatusValue::new(PANIC_ACK_STATUS).expect("Never fails")).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invoke_callback() {
+        assert_eq!(invoke(|| 42), Ok(42));
+    }
+
+    #[cfg(feature = "catch-unwind")]
+    #[test]
+    fn test_invoke_panicking_callback() {
+        let result = invoke(|| -> u64 { panic!("out of gas") });
+
+        assert_eq!(result, Err(PANIC_ACK_STATUS.to_string()));
+    }
+}
@@ -15,6 +15,8 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::callback;
+
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -25,7 +27,10 @@ where
 {
     validate(ctx_b, &msg)?;
 
-    module.on_chan_close_confirm_validate(&msg.port_id_on_b, &msg.chan_id_on_b)?;
+    callback::invoke(|| {
+        module.on_chan_close_confirm_validate(&msg.port_id_on_b, &msg.chan_id_on_b)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
@@ -38,7 +43,10 @@ pub fn chan_close_confirm_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let extras = module.on_chan_close_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)?;
+    let extras = callback::invoke(|| {
+        module.on_chan_close_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
@@ -58,15 +66,12 @@ where
 
         let core_event = {
             let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
-            let chan_id_on_a = chan_end_on_b
-                .counterparty()
-                .channel_id
-                .clone()
-                .ok_or(ContextError::ChannelError(ChannelError::Other {
-                description:
-                    "internal error: ChannelEnd doesn't have a counterparty channel id in CloseInit"
-                        .to_string(),
-            }))?;
+            let chan_id_on_a = chan_end_on_b.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterpartyChannelId {
+                    port_id: msg.port_id_on_b.clone(),
+                    channel_id: msg.chan_id_on_b.clone(),
+                }),
+            )?;
             let conn_id_on_b = chan_end_on_b.connection_hops[0].clone();
 
             IbcEvent::CloseConfirmChannel(CloseConfirm::new(
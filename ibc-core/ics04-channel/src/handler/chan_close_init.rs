@@ -12,6 +12,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::callback;
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -22,7 +24,8 @@ where
 {
     validate(ctx_a, &msg)?;
 
-    module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a)?;
+    callback::invoke(|| module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
@@ -35,7 +38,10 @@ pub fn chan_close_init_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let extras = module.on_chan_close_init_execute(&msg.port_id_on_a, &msg.chan_id_on_a)?;
+    let extras = callback::invoke(|| {
+        module.on_chan_close_init_execute(&msg.port_id_on_a, &msg.chan_id_on_a)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -56,15 +62,12 @@ where
 
         let core_event = {
             let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
-            let chan_id_on_b = chan_end_on_a
-                .counterparty()
-                .channel_id
-                .clone()
-                .ok_or(ContextError::ChannelError(ChannelError::Other {
-                description:
-                    "internal error: ChannelEnd doesn't have a counterparty channel id in CloseInit"
-                        .to_string(),
-            }))?;
+            let chan_id_on_b = chan_end_on_a.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterpartyChannelId {
+                    port_id: msg.port_id_on_a.clone(),
+                    channel_id: msg.chan_id_on_a.clone(),
+                }),
+            )?;
             let conn_id_on_a = chan_end_on_a.connection_hops[0].clone();
 
             IbcEvent::CloseInitChannel(CloseInit::new(
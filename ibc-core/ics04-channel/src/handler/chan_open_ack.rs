@@ -14,6 +14,10 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::callback;
+
+/// Validates a `MsgChannelOpenAck` on chain A, including the application
+/// module callback.
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -22,13 +26,18 @@ pub fn chan_open_ack_validate<ValCtx>(
 where
     ValCtx: ValidationContext,
 {
-    validate(ctx_a, &msg)?;
+    validate_chan_open_ack(ctx_a, &msg)?;
 
-    module.on_chan_open_ack_validate(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)?;
+    callback::invoke(|| {
+        module.on_chan_open_ack_validate(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
 
+/// Executes a `MsgChannelOpenAck` on chain A, including the application
+/// module callback, and emits the resulting events.
 pub fn chan_open_ack_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -37,8 +46,38 @@ pub fn chan_open_ack_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let extras =
-        module.on_chan_open_ack_execute(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)?;
+    let extras = callback::invoke(|| {
+        module.on_chan_open_ack_execute(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.version_on_b)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
+
+    execute_chan_open_ack(ctx_a, &msg)?;
+
+    for module_event in extras.events {
+        ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_a.log_message(log_message)?;
+    }
+
+    Ok(())
+}
+
+/// Performs the core ICS-4 state changes of a `MsgChannelOpenAck` on chain A,
+/// opening the channel end, and emits the core events, without calling into
+/// the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_ack_execute`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own logic.
+pub fn execute_chan_open_ack<Ctx>(
+    ctx_a: &mut Ctx,
+    msg: &MsgChannelOpenAck,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -68,31 +107,34 @@ where
                 msg.port_id_on_a.clone(),
                 msg.chan_id_on_a.clone(),
                 port_id_on_b,
-                msg.chan_id_on_b,
+                msg.chan_id_on_b.clone(),
                 conn_id_on_a,
             ))
         };
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(core_event)?;
-
-        for module_event in extras.events {
-            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
-        }
-
-        for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
-        }
     }
 
     Ok(())
 }
 
-fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenAck) -> Result<(), ContextError>
+/// Performs the core ICS-4 checks of a `MsgChannelOpenAck` on chain A,
+/// without calling into the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_ack_validate`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own checks.
+pub fn validate_chan_open_ack<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    ctx_a
+        .identifier_policy()
+        .validate_channel_identifier(msg.chan_id_on_b.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
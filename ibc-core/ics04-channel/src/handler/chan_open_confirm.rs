@@ -15,6 +15,10 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::callback;
+
+/// Validates a `MsgChannelOpenConfirm` on chain B, including the application
+/// module callback.
 pub fn chan_open_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -23,13 +27,16 @@ pub fn chan_open_confirm_validate<ValCtx>(
 where
     ValCtx: ValidationContext,
 {
-    validate(ctx_b, &msg)?;
+    validate_chan_open_confirm(ctx_b, &msg)?;
 
-    module.on_chan_open_confirm_validate(&msg.port_id_on_b, &msg.chan_id_on_b)?;
+    callback::invoke(|| module.on_chan_open_confirm_validate(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
 
+/// Executes a `MsgChannelOpenConfirm` on chain B, including the application
+/// module callback, and emits the resulting events.
 pub fn chan_open_confirm_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -38,7 +45,38 @@ pub fn chan_open_confirm_execute<ExecCtx>(
 where
     ExecCtx: ExecutionContext,
 {
-    let extras = module.on_chan_open_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)?;
+    let extras = callback::invoke(|| {
+        module.on_chan_open_confirm_execute(&msg.port_id_on_b, &msg.chan_id_on_b)
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
+
+    execute_chan_open_confirm(ctx_b, &msg)?;
+
+    for module_event in extras.events {
+        ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_b.log_message(log_message)?;
+    }
+
+    Ok(())
+}
+
+/// Performs the core ICS-4 state changes of a `MsgChannelOpenConfirm` on
+/// chain B, opening the channel end, and emits the core events, without
+/// calling into the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_confirm_execute`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own logic.
+pub fn execute_chan_open_confirm<Ctx>(
+    ctx_b: &mut Ctx,
+    msg: &MsgChannelOpenConfirm,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
@@ -59,15 +97,17 @@ where
 
         let conn_id_on_b = chan_end_on_b.connection_hops[0].clone();
         let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
-        let chan_id_on_a = chan_end_on_b
-            .counterparty()
-            .channel_id
-            .clone()
-            .ok_or(ContextError::ChannelError(ChannelError::Other {
-            description:
-                "internal error: ChannelEnd doesn't have a counterparty channel id in OpenConfirm"
-                    .to_string(),
-        }))?;
+        let chan_id_on_a =
+            chan_end_on_b
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ContextError::ChannelError(
+                    ChannelError::MissingCounterpartyChannelId {
+                        port_id: msg.port_id_on_b.clone(),
+                        channel_id: msg.chan_id_on_b.clone(),
+                    },
+                ))?;
 
         let core_event = IbcEvent::OpenConfirmChannel(OpenConfirm::new(
             msg.port_id_on_b.clone(),
@@ -78,20 +118,21 @@ where
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(core_event)?;
-
-        for module_event in extras.events {
-            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
-        }
-
-        for log_message in extras.log {
-            ctx_b.log_message(log_message)?;
-        }
     }
 
     Ok(())
 }
 
-fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenConfirm) -> Result<(), ContextError>
+/// Performs the core ICS-4 checks of a `MsgChannelOpenConfirm` on chain B,
+/// without calling into the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_confirm_validate`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own checks.
+pub fn validate_chan_open_confirm<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelOpenConfirm,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
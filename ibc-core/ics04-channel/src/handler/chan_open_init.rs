@@ -1,8 +1,10 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenInit`.
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenInit;
 use ibc_core_channel_types::msgs::MsgChannelOpenInit;
+use ibc_core_channel_types::Version;
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
@@ -12,6 +14,10 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::callback;
+
+/// Validates a `MsgChannelOpenInit` on chain A, including the application
+/// module callback.
 pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -20,21 +26,26 @@ pub fn chan_open_init_validate<ValCtx>(
 where
     ValCtx: ValidationContext,
 {
-    validate(ctx_a, &msg)?;
+    validate_chan_open_init(ctx_a, &msg)?;
     let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
 
-    module.on_chan_open_init_validate(
-        msg.ordering,
-        &msg.connection_hops_on_a,
-        &msg.port_id_on_a,
-        &chan_id_on_a,
-        &Counterparty::new(msg.port_id_on_b.clone(), None),
-        &msg.version_proposal,
-    )?;
+    callback::invoke(|| {
+        module.on_chan_open_init_validate(
+            msg.ordering,
+            &msg.connection_hops_on_a,
+            &msg.port_id_on_a,
+            &chan_id_on_a,
+            &Counterparty::new(msg.port_id_on_b.clone(), None),
+            &msg.version_proposal,
+        )
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
 
+/// Executes a `MsgChannelOpenInit` on chain A, including the application
+/// module callback, and emits the resulting events.
 pub fn chan_open_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -44,15 +55,48 @@ where
     ExecCtx: ExecutionContext,
 {
     let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
-    let (extras, version) = module.on_chan_open_init_execute(
-        msg.ordering,
-        &msg.connection_hops_on_a,
-        &msg.port_id_on_a,
-        &chan_id_on_a,
-        &Counterparty::new(msg.port_id_on_b.clone(), None),
-        &msg.version_proposal,
-    )?;
+    let (extras, version) = callback::invoke(|| {
+        module.on_chan_open_init_execute(
+            msg.ordering,
+            &msg.connection_hops_on_a,
+            &msg.port_id_on_a,
+            &chan_id_on_a,
+            &Counterparty::new(msg.port_id_on_b.clone(), None),
+            &msg.version_proposal,
+        )
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
+
+    execute_chan_open_init(ctx_a, &msg, version)?;
+
+    for module_event in extras.events {
+        ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_a.log_message(log_message)?;
+    }
+
+    Ok(())
+}
 
+/// Performs the core ICS-4 state changes of a `MsgChannelOpenInit` on chain
+/// A, opening the channel end with the `version` negotiated by the
+/// application module, and emits the core events, without calling into the
+/// application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_init_execute`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own logic.
+pub fn execute_chan_open_init<Ctx>(
+    ctx_a: &mut Ctx,
+    msg: &MsgChannelOpenInit,
+    version: Version,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
     let conn_id_on_a = msg.connection_hops_on_a[0].clone();
 
     // state changes
@@ -87,32 +131,41 @@ where
         ))?;
         let core_event = IbcEvent::OpenInitChannel(OpenInit::new(
             msg.port_id_on_a.clone(),
-            chan_id_on_a.clone(),
-            msg.port_id_on_b,
+            chan_id_on_a,
+            msg.port_id_on_b.clone(),
             conn_id_on_a,
             version,
         ));
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(core_event)?;
-
-        for module_event in extras.events {
-            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
-        }
-
-        for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
-        }
     }
 
     Ok(())
 }
 
-fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenInit) -> Result<(), ContextError>
+/// Performs the core ICS-4 checks of a `MsgChannelOpenInit` on chain A,
+/// without calling into the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_init_validate`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own checks.
+pub fn validate_chan_open_init<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgChannelOpenInit,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    let policy = ctx_a.identifier_policy();
+    policy
+        .validate_port_identifier(msg.port_id_on_a.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+    policy
+        .validate_port_identifier(msg.port_id_on_b.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+
     msg.verify_connection_hops_length()?;
     // An IBC connection running on the local (host) chain should exist.
     let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
@@ -4,6 +4,7 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as Channel
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenTry;
 use ibc_core_channel_types::msgs::MsgChannelOpenTry;
+use ibc_core_channel_types::Version;
 use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_connection::types::State as ConnectionState;
@@ -18,6 +19,10 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::callback;
+
+/// Validates a `MsgChannelOpenTry` on chain B, including the application
+/// module callback.
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -26,22 +31,27 @@ pub fn chan_open_try_validate<ValCtx>(
 where
     ValCtx: ValidationContext,
 {
-    validate(ctx_b, &msg)?;
+    validate_chan_open_try(ctx_b, &msg)?;
 
     let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
 
-    module.on_chan_open_try_validate(
-        msg.ordering,
-        &msg.connection_hops_on_b,
-        &msg.port_id_on_b,
-        &chan_id_on_b,
-        &Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
-        &msg.version_supported_on_a,
-    )?;
+    callback::invoke(|| {
+        module.on_chan_open_try_validate(
+            msg.ordering,
+            &msg.connection_hops_on_b,
+            &msg.port_id_on_b,
+            &chan_id_on_b,
+            &Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+            &msg.version_supported_on_a,
+        )
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
 
     Ok(())
 }
 
+/// Executes a `MsgChannelOpenTry` on chain B, including the application
+/// module callback, and emits the resulting events.
 pub fn chan_open_try_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -51,15 +61,48 @@ where
     ExecCtx: ExecutionContext,
 {
     let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
-    let (extras, version) = module.on_chan_open_try_execute(
-        msg.ordering,
-        &msg.connection_hops_on_b,
-        &msg.port_id_on_b,
-        &chan_id_on_b,
-        &Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
-        &msg.version_supported_on_a,
-    )?;
+    let (extras, version) = callback::invoke(|| {
+        module.on_chan_open_try_execute(
+            msg.ordering,
+            &msg.connection_hops_on_b,
+            &msg.port_id_on_b,
+            &chan_id_on_b,
+            &Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+            &msg.version_supported_on_a,
+        )
+    })
+    .unwrap_or_else(|description| Err(ChannelError::AppModule { description }))?;
+
+    execute_chan_open_try(ctx_b, &msg, version)?;
+
+    for module_event in extras.events {
+        ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_b.log_message(log_message)?;
+    }
+
+    Ok(())
+}
 
+/// Performs the core ICS-4 state changes of a `MsgChannelOpenTry` on chain
+/// B, opening the channel end with the `version` negotiated by the
+/// application module, and emits the core events, without calling into the
+/// application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_try_execute`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own logic.
+pub fn execute_chan_open_try<Ctx>(
+    ctx_b: &mut Ctx,
+    msg: &MsgChannelOpenTry,
+    version: Version,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
     let conn_id_on_b = msg.connection_hops_on_b[0].clone();
 
     // state changes
@@ -95,7 +138,7 @@ where
 
         let core_event = IbcEvent::OpenTryChannel(OpenTry::new(
             msg.port_id_on_b.clone(),
-            chan_id_on_b.clone(),
+            chan_id_on_b,
             msg.port_id_on_a.clone(),
             msg.chan_id_on_a.clone(),
             conn_id_on_b,
@@ -103,25 +146,34 @@ where
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(core_event)?;
-
-        for module_event in extras.events {
-            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
-        }
-
-        for log_message in extras.log {
-            ctx_b.log_message(log_message)?;
-        }
     }
 
     Ok(())
 }
 
-fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextError>
+/// Performs the core ICS-4 checks of a `MsgChannelOpenTry` on chain B,
+/// without calling into the application module.
+///
+/// Note that this function is typically called as part of
+/// [`chan_open_try_validate`], but has been made a standalone function in
+/// order to let hosts wrap the channel handshake with their own checks.
+pub fn validate_chan_open_try<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    let policy = ctx_b.identifier_policy();
+    policy
+        .validate_port_identifier(msg.port_id_on_b.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+    policy
+        .validate_port_identifier(msg.port_id_on_a.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+    policy
+        .validate_channel_identifier(msg.chan_id_on_a.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+
     msg.verify_connection_hops_length()?;
 
     let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
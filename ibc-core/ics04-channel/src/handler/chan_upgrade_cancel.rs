@@ -0,0 +1,186 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeCancel;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+        })?;
+
+    // The authority cancels upgrades unconditionally.
+    if is_authority(ctx_a, &msg) {
+        return Ok(());
+    }
+
+    // The error receipt must be of the current upgrade attempt, or of a later
+    // attempt of the counterparty.
+    let upgrade_sequence_on_a = ctx_a.channel_upgrade_sequence(&chan_end_path_on_a)?;
+    if msg.error_receipt_on_b.upgrade_sequence < upgrade_sequence_on_a {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: upgrade_sequence_on_a,
+            actual: msg.error_receipt_on_b.upgrade_sequence,
+        }
+        .into());
+    }
+
+    // Once all its packets are flushed, the channel end can no longer cancel
+    // the upgrade, since the counterparty may have completed it already.
+    if chan_end_on_a.state() == &State::FlushComplete {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel not in FLUSHCOMPLETE state".to_string(),
+            actual: chan_end_on_a.state().to_string(),
+        }
+        .into());
+    }
+
+    let proof_error_receipt_on_b = msg
+        .proof_error_receipt_on_b
+        .as_ref()
+        .ok_or(ChannelError::InvalidProof)?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    let client_id_on_a = conn_end_on_a.client_id();
+    let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+    client_state_of_b_on_a
+        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        .verify_is_active()?;
+    client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        client_id_on_a.clone(),
+        msg.proof_height_on_b.revision_number(),
+        msg.proof_height_on_b.revision_height(),
+    );
+    let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+    let prefix_on_b = conn_end_on_a.counterparty().prefix();
+    let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    // Verify the proof of the error receipt written by the counterparty.
+    client_state_of_b_on_a
+        .verify_membership(
+            prefix_on_b,
+            proof_error_receipt_on_b,
+            consensus_state_of_b_on_a.root(),
+            Path::ChannelUpgradeError(ChannelUpgradeErrorPath::new(port_id_on_b, chan_id_on_b)),
+            msg.error_receipt_on_b.clone().encode_vec(),
+        )
+        .map_err(ChannelError::VerifyChannelFailed)?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The authority cancels the current upgrade attempt, whereas the
+    // counterparty error receipt fast-forwards the upgrade sequence to its own.
+    let upgrade_sequence = if is_authority(ctx_a, &msg) {
+        ctx_a.channel_upgrade_sequence(&chan_end_path_on_a)?
+    } else {
+        msg.error_receipt_on_b.upgrade_sequence
+    };
+
+    // state changes
+    {
+        ctx_a.store_channel_upgrade_sequence(&chan_end_path_on_a, upgrade_sequence)?;
+
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            chan_end_on_a.set_state(State::Open);
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.delete_channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?;
+        ctx_a.store_channel_upgrade_error(
+            &ChannelUpgradeErrorPath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            ErrorReceipt::new(upgrade_sequence, "upgrade cancelled"),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade cancel".to_string())?;
+
+        let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+        let chan_id_on_b =
+            chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ContextError::ChannelError(
+                    ChannelError::MissingCounterpartyChannelId {
+                        port_id: msg.port_id_on_a.clone(),
+                        channel_id: msg.chan_id_on_a.clone(),
+                    },
+                ))?;
+
+        let core_event = IbcEvent::UpgradeCancelChannel(UpgradeCancel::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
+
+fn is_authority<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeCancel) -> bool
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.params_authority().as_ref() == Some(&msg.signer)
+}
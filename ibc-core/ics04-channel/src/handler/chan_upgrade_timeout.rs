@@ -0,0 +1,175 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTimeout;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Only the upgrades that started flushing the packets can time out.
+    if !chan_end_on_a.state().is_upgrading() {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel in FLUSHING or FLUSHCOMPLETE state".to_string(),
+            actual: chan_end_on_a.state().to_string(),
+        }
+        .into());
+    }
+
+    let upgrade_on_a = ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+        })?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    let client_id_on_a = conn_end_on_a.client_id();
+    let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+    client_state_of_b_on_a
+        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        .verify_is_active()?;
+    client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        client_id_on_a.clone(),
+        msg.proof_height_on_b.revision_number(),
+        msg.proof_height_on_b.revision_height(),
+    );
+    let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+    // The upgrade must have timed out on the counterparty chain at the proof height.
+    let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
+    if !upgrade_on_a
+        .timeout
+        .has_elapsed(msg.proof_height_on_b, timestamp_of_b)
+    {
+        return Err(ChannelError::UpgradeTimeoutNotElapsed {
+            height: msg.proof_height_on_b,
+            timestamp: timestamp_of_b,
+        }
+        .into());
+    }
+
+    // The counterparty must not have moved past the point where it could
+    // still abort its own upgrade: once it flushed all its packets, or
+    // opened the upgraded channel, the upgrade can only complete.
+    let state_on_b = *msg.chan_end_on_b.state();
+    let upgraded_on_b = state_on_b == State::Open
+        && msg.chan_end_on_b.version() == &upgrade_on_a.fields.version
+        && msg.chan_end_on_b.ordering() == &upgrade_on_a.fields.ordering;
+    if state_on_b == State::FlushComplete || upgraded_on_b {
+        return Err(ChannelError::CounterpartyUpgradeProgressed { state: state_on_b }.into());
+    }
+
+    let prefix_on_b = conn_end_on_a.counterparty().prefix();
+    let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+    let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+    // Verify the proof of the counterparty channel end.
+    client_state_of_b_on_a
+        .verify_membership(
+            prefix_on_b,
+            &msg.proof_chan_end_on_b,
+            consensus_state_of_b_on_a.root(),
+            Path::ChannelEnd(chan_end_path_on_b),
+            msg.chan_end_on_b.encode_vec(),
+        )
+        .map_err(ChannelError::VerifyChannelFailed)?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_sequence = ctx_a.channel_upgrade_sequence(&chan_end_path_on_a)?;
+
+    // state changes
+    {
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            chan_end_on_a.set_state(State::Open);
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.delete_channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?;
+        // The receipt lets the counterparty cancel its own upgrade attempt.
+        ctx_a.store_channel_upgrade_error(
+            &ChannelUpgradeErrorPath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            ErrorReceipt::new(upgrade_sequence, "upgrade timed out"),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade timeout".to_string())?;
+
+        let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+        let chan_id_on_b =
+            chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ContextError::ChannelError(
+                    ChannelError::MissingCounterpartyChannelId {
+                        port_id: msg.port_id_on_a.clone(),
+                        channel_id: msg.chan_id_on_a.clone(),
+                    },
+                ))?;
+
+        let core_event = IbcEvent::UpgradeTimeoutChannel(UpgradeTimeout::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
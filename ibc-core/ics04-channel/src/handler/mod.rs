@@ -1,11 +1,14 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 mod acknowledgement;
+mod callback;
 mod chan_close_confirm;
 mod chan_close_init;
 mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_cancel;
+mod chan_upgrade_timeout;
 mod recv_packet;
 mod send_packet;
 mod timeout;
@@ -18,6 +21,8 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_timeout::*;
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
 use ibc_core_channel_types::msgs::MsgRecvPacket;
@@ -16,9 +15,12 @@ use ibc_core_host::types::path::{
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
+use ibc_core_router::types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
+use super::callback;
+
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
@@ -70,19 +72,23 @@ where
         }
     }
 
-    let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
+    // A panic of the application is handled as a failure to process the
+    // packet, which `onRecvPacket` reports with an error acknowledgement.
+    let (extras, acknowledgement) =
+        callback::invoke(|| module.on_recv_packet_execute(&msg.packet, &msg.signer))
+            .unwrap_or_else(|_| (ModuleExtras::empty(), callback::panic_acknowledgement()));
 
     // state changes
     {
         // `recvPacket` core handler state changes
+        let receipt_path_on_b = ReceiptPath {
+            port_id: msg.packet.port_id_on_b.clone(),
+            channel_id: msg.packet.chan_id_on_b.clone(),
+            sequence: msg.packet.seq_on_a,
+        };
+
         match chan_end_on_b.ordering {
             Order::Unordered => {
-                let receipt_path_on_b = ReceiptPath {
-                    port_id: msg.packet.port_id_on_b.clone(),
-                    channel_id: msg.packet.chan_id_on_b.clone(),
-                    sequence: msg.packet.seq_on_a,
-                };
-
                 ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
             }
             Order::Ordered => {
@@ -93,6 +99,8 @@ where
             }
             _ => {}
         }
+        ctx_b.store_packet_receipt_relayer(&receipt_path_on_b, msg.signer.clone())?;
+
         let ack_path_on_b = AckPath::new(
             &msg.packet.port_id_on_b,
             &msg.packet.chan_id_on_b,
@@ -101,7 +109,7 @@ where
         // `writeAcknowledgement` handler state changes
         ctx_b.store_packet_acknowledgement(
             &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
+            ctx_b.compute_ack_commitment(&acknowledgement),
         )?;
     }
 
@@ -111,11 +119,14 @@ where
         ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
-            msg.packet.clone(),
-            chan_end_on_b.ordering,
-            conn_id_on_b.clone(),
-        ));
+        let event = IbcEvent::ReceivePacket(
+            ReceivePacket::new(
+                msg.packet.clone(),
+                chan_end_on_b.ordering,
+                conn_id_on_b.clone(),
+            )
+            .with_relayer(msg.signer.clone()),
+        );
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
         let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
@@ -144,6 +155,8 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    msg.packet.verify_data_size(ctx_b.max_packet_data_size())?;
+
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
@@ -195,7 +208,7 @@ where
 
         let consensus_state_of_a_on_b = ctx_b.consensus_state(&client_cons_state_path_on_b)?;
 
-        let expected_commitment_on_a = compute_packet_commitment(
+        let expected_commitment_on_a = ctx_b.compute_packet_commitment(
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
             &msg.packet.timeout_timestamp_on_b,
@@ -275,7 +288,7 @@ fn validate_write_acknowledgement<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Resu
 where
     Ctx: ValidationContext,
 {
-    let packet = msg.packet.clone();
+    let packet = &msg.packet;
     let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
     if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
         return Err(PacketError::AcknowledgementExists {
@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::Counterparty;
-use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::PacketError;
 use ibc_core_channel_types::events::SendPacket;
 use ibc_core_channel_types::packet::Packet;
@@ -31,6 +30,8 @@ pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
     packet: &Packet,
 ) -> Result<(), ContextError> {
+    packet.verify_data_size(ctx_a.max_packet_data_size())?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -59,7 +60,12 @@ pub fn send_packet_validate(
 
     let latest_height_on_a = client_state_of_b_on_a.latest_height();
 
-    if packet.timeout_height_on_b.has_expired(latest_height_on_a) {
+    if packet
+        .timeout_height_on_b
+        .has_expired_with(latest_height_on_a, |height, timeout_height| {
+            client_state_of_b_on_a.is_past_height(height, timeout_height)
+        })
+    {
         return Err(PacketError::LowPacketHeight {
             chain_height: latest_height_on_a,
             timeout_height: packet.timeout_height_on_b,
@@ -107,15 +113,20 @@ pub fn send_packet_execute(
         ctx_a.store_next_sequence_send(&seq_send_path_on_a, next_seq_send_on_a.increment())?;
     }
 
+    let commitment_path_on_a =
+        CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
     ctx_a.store_packet_commitment(
-        &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
-        compute_packet_commitment(
+        &commitment_path_on_a,
+        ctx_a.compute_packet_commitment(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
         ),
     )?;
 
+    ctx_a.record_packet_send_timestamp(&commitment_path_on_a)?;
+
     // emit events and logs
     {
         let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
@@ -13,9 +12,10 @@ use ibc_core_host::types::path::{
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
+use ibc_core_router::types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 
-use super::timeout_on_close;
+use super::{callback, timeout_on_close};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -40,8 +40,8 @@ where
         TimeoutMsgType::TimeoutOnClose(msg) => (msg.packet, msg.signer),
     };
 
-    module
-        .on_timeout_packet_validate(&packet, &signer)
+    callback::invoke(|| module.on_timeout_packet_validate(&packet, &signer))
+        .unwrap_or_else(|description| Err(PacketError::AppModule { description }))
         .map_err(ContextError::PacketError)
 }
 
@@ -61,7 +61,9 @@ where
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
     // In all cases, this event is emitted
-    let event = IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
+    let event = IbcEvent::TimeoutPacket(
+        TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering).with_relayer(signer.clone()),
+    );
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
     ctx_a.emit_ibc_event(event)?;
 
@@ -77,7 +79,15 @@ where
         return Ok(());
     };
 
-    let (extras, cb_result) = module.on_timeout_packet_execute(&packet, &signer);
+    let (extras, cb_result) =
+        callback::invoke(|| module.on_timeout_packet_execute(&packet, &signer)).unwrap_or_else(
+            |description| {
+                (
+                    ModuleExtras::empty(),
+                    Err(PacketError::AppModule { description }),
+                )
+            },
+        );
 
     cb_result?;
 
@@ -89,6 +99,7 @@ where
             sequence: packet.seq_on_a,
         };
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.delete_packet_send_timestamp(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             let mut chan_end_on_a = chan_end_on_a;
@@ -171,7 +182,7 @@ where
         Err(_) => return Ok(()),
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = ctx_a.compute_packet_commitment(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
@@ -203,7 +214,13 @@ where
         let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
 
-        if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
+        let timed_out = msg.packet.timed_out_with(
+            &timestamp_of_b,
+            msg.proof_height_on_b,
+            |height, timeout_height| client_state_of_b_on_a.is_past_height(height, timeout_height),
+        );
+
+        if !timed_out {
             return Err(PacketError::PacketTimeoutNotReached {
                 timeout_height: msg.packet.timeout_height_on_b,
                 chain_height: msg.proof_height_on_b,
@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
@@ -47,7 +46,7 @@ where
         Err(_) => return Ok(()),
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = ctx_a.compute_packet_commitment(
         &packet.data,
         &packet.timeout_height_on_b,
         &packet.timeout_timestamp_on_b,
@@ -2,48 +2,49 @@
 
 use core::fmt::{Display, Error as FmtError, Formatter};
 
-use derive_more::Into;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
 use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::channel::v1::acknowledgement::Response as RawResponse;
+use ibc_proto::ibc::core::channel::v1::Acknowledgement as RawAcknowledgement;
 
 use super::error::PacketError;
 
 /// A generic Acknowledgement type that modules may interpret as they like.
 ///
+/// The acknowledgement is held in a reference-counted [`Bytes`] buffer, so
+/// that passing it from the application callback to the emitted events does
+/// not copy it.
+///
 /// NOTE: An acknowledgement cannot be empty.
-#[cfg_attr(
-    feature = "parity-scale-codec",
-    derive(
-        parity_scale_codec::Encode,
-        parity_scale_codec::Decode,
-        scale_info::TypeInfo
-    )
-)]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, Into)]
-pub struct Acknowledgement(Vec<u8>);
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acknowledgement(#[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))] Bytes);
 
 impl Acknowledgement {
     // Returns the data as a slice of bytes.
     pub fn as_bytes(&self) -> &[u8] {
-        self.0.as_slice()
+        &self.0
+    }
+
+    /// Returns the underlying reference-counted buffer without copying it.
+    pub fn into_bytes(self) -> Bytes {
+        self.0
     }
 }
 
 impl AsRef<[u8]> for Acknowledgement {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_slice()
+        &self.0
     }
 }
 
-impl TryFrom<Vec<u8>> for Acknowledgement {
+impl TryFrom<Bytes> for Acknowledgement {
     type Error = PacketError;
 
-    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
         if bytes.is_empty() {
             Err(PacketError::InvalidAcknowledgement)
         } else {
@@ -52,9 +53,105 @@ impl TryFrom<Vec<u8>> for Acknowledgement {
     }
 }
 
+impl TryFrom<Vec<u8>> for Acknowledgement {
+    type Error = PacketError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(Bytes::from(bytes))
+    }
+}
+
+impl From<Acknowledgement> for Vec<u8> {
+    fn from(ack: Acknowledgement) -> Self {
+        ack.0.into()
+    }
+}
+
+impl From<Acknowledgement> for Bytes {
+    fn from(ack: Acknowledgement) -> Self {
+        ack.0
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for Acknowledgement {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.0.as_ref(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for Acknowledgement {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = Vec::<u8>::deserialize_reader(reader)?;
+
+        Ok(Self(bytes.into()))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Acknowledgement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut bytes = Vec::<u8>::arbitrary(u)?;
+        // An acknowledgement cannot be empty.
+        bytes.insert(0, u8::arbitrary(u)?);
+        Ok(Self(bytes.into()))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for Acknowledgement {
+    fn size_hint(&self) -> usize {
+        parity_scale_codec::Encode::size_hint(self.0.as_ref())
+    }
+
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        parity_scale_codec::Encode::encode_to(self.0.as_ref(), dest)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for Acknowledgement {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes = <Vec<u8> as parity_scale_codec::Decode>::decode(input)?;
+
+        Ok(Self(bytes.into()))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for Acknowledgement {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("Acknowledgement", module_path!()))
+            .composite(
+                scale_info::build::Fields::unnamed()
+                    .field(|f| f.ty::<Vec<u8>>().type_name("Vec<u8>")),
+            )
+    }
+}
+
+/// The result of the successful acknowledgements of ICS-20 and ICS-721, which
+/// is the base64 encoding of the byte `0x01`, as in ibc-go.
+pub const ACK_SUCCESS_B64: &str = "AQ==";
+
 /// Defines a convenience type for IBC applications to construct an
 /// [`Acknowledgement`] based on the
 /// success or failure of processing a received packet.
+///
+/// This is the standard acknowledgement envelope of ibc-go, whose JSON
+/// encoding is either `{"result":"<base64 bytes>"}` or `{"error":"<message>"}`,
+/// and whose protobuf encoding is the `ibc.core.channel.v1.Acknowledgement`
+/// message. Applications should share it rather than define their own.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AcknowledgementStatus {
@@ -105,10 +202,33 @@ impl AcknowledgementStatus {
         Self::Error(value)
     }
 
+    /// Creates the success acknowledgement status of ICS-20 and ICS-721,
+    /// i.e. `{"result":"AQ=="}`.
+    pub fn success_b64() -> Self {
+        Self::Success(StatusValue(ACK_SUCCESS_B64.to_string()))
+    }
+
+    /// Creates a success acknowledgement status with the base64 encoding of
+    /// the given result bytes, which must not be empty.
+    pub fn success_bytes(result: impl AsRef<[u8]>) -> Result<Self, PacketError> {
+        StatusValue::new(BASE64_STANDARD.encode(result)).map(Self::Success)
+    }
+
     /// Returns true if the acknowledgement status is successful.
     pub fn is_successful(&self) -> bool {
         matches!(self, AcknowledgementStatus::Success(_))
     }
+
+    /// Parses the JSON encoding of an acknowledgement status, e.g. of the
+    /// [`Acknowledgement`] of a packet written by an application using it.
+    #[cfg(feature = "serde")]
+    pub fn from_json_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, PacketError> {
+        serde_json::from_slice(bytes.as_ref()).map_err(|e| {
+            PacketError::InvalidAcknowledgementStatus {
+                description: e.to_string(),
+            }
+        })
+    }
 }
 
 impl Display for AcknowledgementStatus {
@@ -126,8 +246,12 @@ impl From<AcknowledgementStatus> for Vec<u8> {
         // WARNING: Make sure all branches always return a non-empty vector.
         // Otherwise, the conversion to `Acknowledgement` will panic.
         match ack {
-            AcknowledgementStatus::Success(v) => alloc::format!(r#"{{"result":"{v}"}}"#).into(),
-            AcknowledgementStatus::Error(v) => alloc::format!(r#"{{"error":"{v}"}}"#).into(),
+            AcknowledgementStatus::Success(v) => {
+                alloc::format!(r#"{{"result":"{}"}}"#, json_escape(&v.0)).into()
+            }
+            AcknowledgementStatus::Error(v) => {
+                alloc::format!(r#"{{"error":"{}"}}"#, json_escape(&v.0)).into()
+            }
         }
     }
 }
@@ -140,3 +264,110 @@ impl From<AcknowledgementStatus> for Acknowledgement {
             .expect("token transfer internal error: ack is never supposed to be empty")
     }
 }
+
+impl TryFrom<RawAcknowledgement> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(raw_ack: RawAcknowledgement) -> Result<Self, PacketError> {
+        match raw_ack.response {
+            Some(RawResponse::Result(result)) => Self::success_bytes(result),
+            Some(RawResponse::Error(error)) => StatusValue::new(error).map(Self::Error),
+            None => Err(PacketError::EmptyAcknowledgementStatus),
+        }
+    }
+}
+
+impl TryFrom<AcknowledgementStatus> for RawAcknowledgement {
+    type Error = PacketError;
+
+    fn try_from(ack_status: AcknowledgementStatus) -> Result<Self, Self::Error> {
+        let response = match ack_status {
+            AcknowledgementStatus::Success(v) => {
+                let result = BASE64_STANDARD.decode(v.0).map_err(|e| {
+                    PacketError::InvalidAcknowledgementStatus {
+                        description: e.to_string(),
+                    }
+                })?;
+
+                RawResponse::Result(result)
+            }
+            AcknowledgementStatus::Error(v) => RawResponse::Error(v.0),
+        };
+
+        Ok(RawAcknowledgement {
+            response: Some(response),
+        })
+    }
+}
+
+/// Escapes a string to be embedded in a JSON string literal.
+fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&alloc::format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ack_status_json_encoding() {
+        let success: Vec<u8> = AcknowledgementStatus::success_b64().into();
+        assert_eq!(success, br#"{"result":"AQ=="}"#);
+
+        let error: Vec<u8> =
+            AcknowledgementStatus::error(StatusValue::new("invalid \"denom\"\n").unwrap()).into();
+        assert_eq!(error, br#"{"error":"invalid \"denom\"\u000a"}"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ack_status_json_roundtrip() {
+        let ack_statuses = [
+            AcknowledgementStatus::success_b64(),
+            AcknowledgementStatus::error(StatusValue::new("invalid \"denom\"\n").unwrap()),
+        ];
+
+        for ack_status in ack_statuses {
+            let ack = Acknowledgement::from(ack_status.clone());
+            assert_eq!(
+                AcknowledgementStatus::from_json_bytes(ack).unwrap(),
+                ack_status
+            );
+        }
+
+        assert!(AcknowledgementStatus::from_json_bytes(br#"{"success":"AQ=="}"#).is_err());
+    }
+
+    #[test]
+    fn test_ack_status_proto_roundtrip() {
+        let raw_ack = RawAcknowledgement::try_from(AcknowledgementStatus::success_b64()).unwrap();
+        assert_eq!(raw_ack.response, Some(RawResponse::Result(vec![1])));
+        assert_eq!(
+            AcknowledgementStatus::try_from(raw_ack).unwrap(),
+            AcknowledgementStatus::success_b64()
+        );
+
+        let error = AcknowledgementStatus::error(StatusValue::new("failure").unwrap());
+        let raw_ack = RawAcknowledgement::try_from(error.clone()).unwrap();
+        assert_eq!(
+            raw_ack.response,
+            Some(RawResponse::Error("failure".to_string()))
+        );
+        assert_eq!(AcknowledgementStatus::try_from(raw_ack).unwrap(), error);
+
+        let invalid = AcknowledgementStatus::success(StatusValue::new("not base64!").unwrap());
+        assert!(RawAcknowledgement::try_from(invalid).is_err());
+        assert!(AcknowledgementStatus::try_from(RawAcknowledgement { response: None }).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Typed application versions carrying JSON metadata, such as the ones of
+//! the fee middleware (ICS-29) and of interchain accounts (ICS-27), along with
+//! the utilities to negotiate versions wrapped by a middleware.
+//!
+//! A middleware wraps the version of the application it is stacked on into
+//! its own metadata, e.g. `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+//! Since enabling a middleware is optional, a version which cannot be decoded
+//! as the middleware metadata is handed as is to the underlying application.
+
+use ibc_core_host_types::identifiers::ConnectionId;
+use ibc_primitives::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ChannelError;
+use crate::Version;
+
+/// The version of the fee middleware supported by this implementation.
+pub const FEE_VERSION: &str = "ics29-1";
+
+/// The version of interchain accounts supported by this implementation.
+pub const ICA_VERSION: &str = "ics27-1";
+
+/// An application version encoded in the version field of a `ChannelEnd`.
+pub trait AppVersion: Sized {
+    /// Decodes the application version from the given channel version.
+    fn parse(version: &Version) -> Result<Self, ChannelError>;
+
+    /// Encodes the application version into a channel version.
+    fn to_version(&self) -> Version;
+}
+
+/// The metadata of a middleware, wrapping the version of the application it
+/// is stacked on.
+pub trait MiddlewareVersion: AppVersion {
+    /// Wraps the given application version into the metadata of the
+    /// middleware.
+    fn wrap(app_version: Version) -> Self;
+
+    /// Returns the wrapped application version.
+    fn app_version(&self) -> Version;
+
+    /// Validates the metadata proposed by the counterparty, e.g. that it is
+    /// for a supported version of the middleware.
+    fn validate(&self) -> Result<(), ChannelError> {
+        Ok(())
+    }
+}
+
+/// Splits the given channel version into the metadata of the middleware `M`,
+/// if the middleware is enabled, and the version of the underlying
+/// application.
+pub fn unwrap_version<M>(version: &Version) -> (Option<M>, Version)
+where
+    M: MiddlewareVersion,
+{
+    match M::parse(version) {
+        Ok(metadata) => {
+            let app_version = metadata.app_version();
+            (Some(metadata), app_version)
+        }
+        Err(_) => (None, version.clone()),
+    }
+}
+
+/// Negotiates the channel version of an application stacked under the
+/// middleware `M`.
+///
+/// The proposed version is unwrapped, the underlying application version is
+/// negotiated with `negotiate_app`, and the result is wrapped again only if
+/// the middleware was enabled in the proposed version.
+pub fn negotiate_version<M, F, E>(proposed: &Version, negotiate_app: F) -> Result<Version, E>
+where
+    M: MiddlewareVersion,
+    F: FnOnce(&Version) -> Result<Version, E>,
+    E: From<ChannelError>,
+{
+    let (metadata, app_version) = unwrap_version::<M>(proposed);
+
+    match metadata {
+        Some(metadata) => {
+            metadata.validate()?;
+            let app_version = negotiate_app(&app_version)?;
+
+            Ok(M::wrap(app_version).to_version())
+        }
+        None => negotiate_app(&app_version),
+    }
+}
+
+/// The metadata of the fee middleware (ICS-29).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeVersion {
+    pub fee_version: String,
+    pub app_version: String,
+}
+
+impl AppVersion for FeeVersion {
+    fn parse(version: &Version) -> Result<Self, ChannelError> {
+        parse_json(version)
+    }
+
+    fn to_version(&self) -> Version {
+        to_json(self)
+    }
+}
+
+impl MiddlewareVersion for FeeVersion {
+    fn wrap(app_version: Version) -> Self {
+        Self {
+            fee_version: FEE_VERSION.to_string(),
+            app_version: app_version.as_str().to_string(),
+        }
+    }
+
+    fn app_version(&self) -> Version {
+        Version::new(self.app_version.clone())
+    }
+
+    fn validate(&self) -> Result<(), ChannelError> {
+        Version::new(self.fee_version.clone())
+            .verify_is_expected(Version::new(FEE_VERSION.to_string()))
+    }
+}
+
+/// The version metadata of interchain accounts (ICS-27).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcaVersion {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    /// The interchain account address, which is empty until the host chain
+    /// registers the account.
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl AppVersion for IcaVersion {
+    fn parse(version: &Version) -> Result<Self, ChannelError> {
+        parse_json(version)
+    }
+
+    fn to_version(&self) -> Version {
+        to_json(self)
+    }
+}
+
+fn parse_json<T>(version: &Version) -> Result<T, ChannelError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(version.as_str()).map_err(|e| ChannelError::InvalidVersionMetadata {
+        version: version.clone(),
+        description: e.to_string(),
+    })
+}
+
+fn to_json<T>(metadata: &T) -> Version
+where
+    T: Serialize,
+{
+    Version::new(
+        serde_json::to_string(metadata)
+            .expect("Never fails because the metadata only contains strings"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_wrapped(app_version: &str) -> Version {
+        Version::new(format!(
+            r#"{{"fee_version":"ics29-1","app_version":"{app_version}"}}"#
+        ))
+    }
+
+    #[test]
+    fn fee_version_roundtrip() {
+        let version = fee_wrapped("ics20-1");
+        let metadata = FeeVersion::parse(&version).unwrap();
+
+        assert_eq!(
+            metadata,
+            FeeVersion::wrap(Version::new("ics20-1".to_string()))
+        );
+        assert_eq!(metadata.to_version(), version);
+    }
+
+    #[test]
+    fn unwrap_without_middleware() {
+        let version = Version::new("ics20-1".to_string());
+        let (metadata, app_version) = unwrap_version::<FeeVersion>(&version);
+
+        assert!(metadata.is_none());
+        assert_eq!(app_version, version);
+    }
+
+    #[test]
+    fn negotiate_fee_wrapped_version() {
+        let negotiated = negotiate_version::<FeeVersion, _, ChannelError>(
+            &fee_wrapped("ics20-1"),
+            |app_version| {
+                assert_eq!(app_version.as_str(), "ics20-1");
+                Ok(app_version.clone())
+            },
+        )
+        .unwrap();
+        assert_eq!(negotiated, fee_wrapped("ics20-1"));
+
+        let negotiated = negotiate_version::<FeeVersion, _, ChannelError>(
+            &Version::new("ics20-1".to_string()),
+            |app_version| Ok(app_version.clone()),
+        )
+        .unwrap();
+        assert_eq!(negotiated.as_str(), "ics20-1");
+    }
+
+    #[test]
+    fn negotiate_unsupported_fee_version() {
+        let proposed =
+            Version::new(r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.to_string());
+        let res = negotiate_version::<FeeVersion, _, ChannelError>(&proposed, |app_version| {
+            Ok(app_version.clone())
+        });
+
+        assert!(matches!(res, Err(ChannelError::VersionNotSupported { .. })));
+    }
+
+    #[test]
+    fn ica_version_wrapped_by_fee() {
+        let ica_version = IcaVersion {
+            version: ICA_VERSION.to_string(),
+            controller_connection_id: ConnectionId::new(0),
+            host_connection_id: ConnectionId::new(1),
+            address: String::new(),
+            encoding: "proto3".to_string(),
+            tx_type: "sdk_multi_msg".to_string(),
+        };
+
+        let version = FeeVersion::wrap(ica_version.to_version()).to_version();
+        let (metadata, app_version) = unwrap_version::<FeeVersion>(&version);
+
+        assert!(metadata.is_some());
+        assert_eq!(IcaVersion::parse(&app_version).unwrap(), ica_version);
+    }
+}
@@ -101,6 +101,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ChannelEnd {
     pub state: State,
@@ -120,6 +121,20 @@ impl Display for ChannelEnd {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChannelEnd {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        ChannelEnd::new(
+            State::arbitrary(u)?,
+            arbitrary_order(u)?,
+            Counterparty::arbitrary(u)?,
+            Vec::arbitrary(u)?,
+            Version::arbitrary(u)?,
+        )
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl Protobuf<RawChannel> for ChannelEnd {}
 
 impl TryFrom<RawChannel> for ChannelEnd {
@@ -342,6 +357,8 @@ pub(crate) fn verify_connection_hops_length(
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Counterparty {
     pub port_id: PortId,
@@ -439,6 +456,7 @@ impl From<Counterparty> for RawCounterparty {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Order {
     None = 0isize,
@@ -447,6 +465,14 @@ pub enum Order {
     Ordered = 2isize,
 }
 
+/// Generates an arbitrary order other than [`Order::None`], for use with
+/// `#[arbitrary(with = ...)]` on the fields that carry the ordering of a
+/// channel.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_order(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Order> {
+    u.choose(&[Order::Unordered, Order::Ordered]).copied()
+}
+
 impl Display for Order {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(f, "{}", self.as_str())
@@ -508,6 +534,8 @@ impl FromStr for Order {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum State {
     Uninitialized = 0isize,
@@ -515,6 +543,10 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    /// The channel end is upgrading, and flushes its in-flight packets.
+    Flushing = 5isize,
+    /// The channel end is upgrading, and flushed its in-flight packets.
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -526,6 +558,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -537,8 +571,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "Must be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -549,6 +585,12 @@ impl State {
         self == State::Open
     }
 
+    /// Returns whether or not this channel state is one of the states of an
+    /// upgrading channel, i.e. `Flushing` or `FlushComplete`.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
@@ -1,5 +1,6 @@
 //! Types and utilities related to packet commitments.
 
+use ibc_core_commitment_types::host_functions::{DefaultHostFunctions, HostFunctions};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 
@@ -86,30 +87,45 @@ pub fn compute_packet_commitment(
     packet_data: &[u8],
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    compute_packet_commitment_with::<DefaultHostFunctions>(
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    )
+}
+
+/// Same as [`compute_packet_commitment`], but hashes with the given host
+/// functions.
+///
+/// Note that computing commitments with anything other than SHA256 will
+/// break the Merkle proofs of the IBC provable store.
+pub fn compute_packet_commitment_with<H: HostFunctions>(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
 ) -> PacketCommitment {
     let mut hash_input = [0; 8 * 3 + 32];
 
     hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
     hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
     hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
+    hash_input[24..].copy_from_slice(&H::sha2_256(packet_data));
 
-    hash(&hash_input).to_vec().into()
+    H::sha2_256(&hash_input).to_vec().into()
 }
 
 /// Compute the commitment for an acknowledgement.
 pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    compute_ack_commitment_with::<DefaultHostFunctions>(ack)
 }
 
-/// Helper function to hash a byte slice using SHA256.
-///
-/// Note that computing commitments with anything other than SHA256 will
-/// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
-
-    sha2::Sha256::digest(data).into()
+/// Same as [`compute_ack_commitment`], but hashes with the given host
+/// functions.
+pub fn compute_ack_commitment_with<H: HostFunctions>(
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    H::sha2_256(ack.as_ref()).to_vec().into()
 }
 
 #[cfg(test)]
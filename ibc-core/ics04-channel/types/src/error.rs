@@ -35,6 +35,11 @@ pub enum ChannelError {
     MissingCounterparty,
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
+    /// invalid version metadata `{version}`: `{description}`
+    InvalidVersionMetadata {
+        version: Version,
+        description: String,
+    },
     /// missing channel end
     MissingChannel,
     /// the channel end (`{port_id}`, `{channel_id}`) does not exist
@@ -69,6 +74,37 @@ pub enum ChannelError {
     InvalidIdentifier(IdentifierError),
     /// channel counter overflow error
     CounterOverflow,
+    /// the channel end (`{port_id}`, `{channel_id}`) has no counterparty channel id
+    MissingCounterpartyChannelId {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// missing upgrade fields
+    MissingUpgradeFields,
+    /// missing upgrade timeout
+    MissingUpgradeTimeout,
+    /// invalid upgrade timeout height
+    InvalidUpgradeTimeoutHeight,
+    /// invalid upgrade timeout timestamp: `{0}`
+    InvalidUpgradeTimeoutTimestamp(ParseTimestampError),
+    /// missing error receipt
+    MissingErrorReceipt,
+    /// no upgrade is in progress on the channel end (`{port_id}`, `{channel_id}`)
+    MissingUpgrade {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// the upgrade timeout has not elapsed on the counterparty at height `{height}` and timestamp `{timestamp}`
+    UpgradeTimeoutNotElapsed {
+        height: Height,
+        timestamp: Timestamp,
+    },
+    /// invalid upgrade sequence: expected at least `{expected}`, actual `{actual}`
+    InvalidUpgradeSequence { expected: u64, actual: u64 },
+    /// the counterparty channel end progressed in the upgrade to state `{state}`, which cannot time out
+    CounterpartyUpgradeProgressed { state: State },
+    /// the host does not support channel upgrades
+    UpgradeNotSupported,
     /// other error: `{description}`
     Other { description: String },
 }
@@ -118,6 +154,8 @@ pub enum PacketError {
     InvalidAcknowledgement,
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
+    /// invalid acknowledgement status: `{description}`
+    InvalidAcknowledgementStatus { description: String },
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
     /// invalid proof: missing height
@@ -134,6 +172,8 @@ pub enum PacketError {
     ZeroPacketSequence,
     /// packet data bytes cannot be empty
     ZeroPacketData,
+    /// packet data of `{size}` bytes exceeds the maximum size of `{max_size}` bytes
+    PacketDataTooLarge { size: usize, max_size: usize },
     /// invalid timeout height for the packet
     InvalidTimeoutHeight,
     /// Invalid packet timeout timestamp value error: `{0}`
@@ -164,6 +204,10 @@ pub enum PacketError {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// packet receipt range starts at `{start}` after its end `{end}`
+    InvalidReceiptRange { start: u64, end: u64 },
+    /// packet receipt range ending at `{end}` is not separated from the one starting at `{next_start}`
+    UnmergedReceiptRanges { end: u64, next_start: u64 },
     /// other error: `{description}`
     Other { description: String },
 }
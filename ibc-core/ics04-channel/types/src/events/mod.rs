@@ -3,20 +3,23 @@
 mod channel_attributes;
 mod packet_attributes;
 
+use core::time::Duration;
+
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 use tendermint::abci;
 
 use self::channel_attributes::{
     ChannelIdAttribute, ConnectionIdAttribute, CounterpartyChannelIdAttribute,
-    CounterpartyPortIdAttribute, PortIdAttribute, VersionAttribute,
+    CounterpartyPortIdAttribute, PortIdAttribute, UpgradeSequenceAttribute, VersionAttribute,
     COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
 use self::packet_attributes::{
     AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
     PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
     SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
+    PKT_LATENCY_ATTRIBUTE_KEY, PKT_RELAYER_ATTRIBUTE_KEY,
 };
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
@@ -32,6 +35,8 @@ const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
 const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
 const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+const CHANNEL_UPGRADE_CANCELLED_EVENT: &str = "channel_upgrade_cancelled";
 /// Packet event types
 const SEND_PACKET_EVENT: &str = "send_packet";
 const RECEIVE_PACKET_EVENT: &str = "receive_packet";
@@ -492,6 +497,158 @@ impl From<CloseConfirm> for abci::Event {
     }
 }
 
+/// An `UpgradeTimeout` event is emitted when the upgrade of a channel is
+/// aborted because it timed out on the counterparty chain.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeTimeout {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
+    }
+}
+
+impl From<UpgradeTimeout> for abci::Event {
+    fn from(o: UpgradeTimeout) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+/// An `UpgradeCancel` event is emitted when the upgrade of a channel is
+/// cancelled, after failing on the counterparty chain or by the authority.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeCancel {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeCancel {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CANCELLED_EVENT
+    }
+}
+
+impl From<UpgradeCancel> for abci::Event {
+    fn from(o: UpgradeCancel) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CANCELLED_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
 /// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
 /// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
 /// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
@@ -716,6 +873,7 @@ pub struct ReceivePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    relayer: Option<Signer>,
 }
 
 impl ReceivePacket {
@@ -731,6 +889,16 @@ impl ReceivePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_b: dst_connection_id.into(),
+            relayer: None,
+        }
+    }
+
+    /// Attaches the relayer, i.e. the signer of the message that delivered the packet,
+    /// which is appended to the attributes of the event.
+    pub fn with_relayer(self, relayer: Signer) -> Self {
+        Self {
+            relayer: Some(relayer),
+            ..self
         }
     }
 
@@ -774,6 +942,11 @@ impl ReceivePacket {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    /// The relayer that submitted the message, if attached to the event.
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         RECEIVE_PACKET_EVENT
     }
@@ -783,7 +956,7 @@ impl TryFrom<ReceivePacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: ReceivePacket) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
+        let mut attributes = Vec::with_capacity(12);
         attributes.append(&mut v.packet_data_attr.try_into()?);
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
@@ -795,6 +968,10 @@ impl TryFrom<ReceivePacket> for abci::Event {
         attributes.push(v.channel_ordering_attr.into());
         attributes.push(v.conn_id_attr_on_b.into());
 
+        if let Some(relayer) = v.relayer {
+            attributes.push((PKT_RELAYER_ATTRIBUTE_KEY, relayer.to_string()).into());
+        }
+
         Ok(abci::Event {
             kind: RECEIVE_PACKET_EVENT.to_string(),
             attributes,
@@ -941,6 +1118,8 @@ pub struct AcknowledgePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_a: PacketConnectionIdAttribute,
+    latency_nanos: Option<u64>,
+    relayer: Option<Signer>,
 }
 
 impl AcknowledgePacket {
@@ -955,6 +1134,30 @@ impl AcknowledgePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_a: src_connection_id.into(),
+            latency_nanos: None,
+            relayer: None,
+        }
+    }
+
+    /// Attaches the time elapsed between the sending of the packet and its
+    /// acknowledgement, which is appended to the attributes of the event as
+    /// a number of nanoseconds.
+    ///
+    /// NOTE: a `latency` that does not fit in a `u64` of nanoseconds is
+    /// saturated.
+    pub fn with_latency(self, latency: Duration) -> Self {
+        Self {
+            latency_nanos: Some(u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX)),
+            ..self
+        }
+    }
+
+    /// Attaches the relayer, i.e. the signer of the message that acknowledged the packet,
+    /// which is appended to the attributes of the event.
+    pub fn with_relayer(self, relayer: Signer) -> Self {
+        Self {
+            relayer: Some(relayer),
+            ..self
         }
     }
 
@@ -994,6 +1197,17 @@ impl AcknowledgePacket {
         &self.conn_id_attr_on_a.connection_id
     }
 
+    /// The time elapsed between the sending of the packet and its
+    /// acknowledgement, if the host recorded the send time of the packet.
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency_nanos.map(Duration::from_nanos)
+    }
+
+    /// The relayer that submitted the message, if attached to the event.
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         ACK_PACKET_EVENT
     }
@@ -1003,19 +1217,29 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: AcknowledgePacket) -> Result<Self, Self::Error> {
+        let mut attributes = vec![
+            v.timeout_height_attr_on_b.into(),
+            v.timeout_timestamp_attr_on_b.into(),
+            v.seq_on_a.into(),
+            v.port_id_attr_on_a.into(),
+            v.chan_id_attr_on_a.into(),
+            v.port_id_attr_on_b.into(),
+            v.chan_id_attr_on_b.into(),
+            v.channel_ordering_attr.into(),
+            v.conn_id_attr_on_a.into(),
+        ];
+
+        if let Some(latency_nanos) = v.latency_nanos {
+            attributes.push((PKT_LATENCY_ATTRIBUTE_KEY, latency_nanos.to_string()).into());
+        }
+
+        if let Some(relayer) = v.relayer {
+            attributes.push((PKT_RELAYER_ATTRIBUTE_KEY, relayer.to_string()).into());
+        }
+
         Ok(abci::Event {
             kind: ACK_PACKET_EVENT.to_string(),
-            attributes: vec![
-                v.timeout_height_attr_on_b.into(),
-                v.timeout_timestamp_attr_on_b.into(),
-                v.seq_on_a.into(),
-                v.port_id_attr_on_a.into(),
-                v.chan_id_attr_on_a.into(),
-                v.port_id_attr_on_b.into(),
-                v.chan_id_attr_on_b.into(),
-                v.channel_ordering_attr.into(),
-                v.conn_id_attr_on_a.into(),
-            ],
+            attributes,
         })
     }
 }
@@ -1043,6 +1267,7 @@ pub struct TimeoutPacket {
     port_id_attr_on_b: DstPortIdAttribute,
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
+    relayer: Option<Signer>,
 }
 
 impl TimeoutPacket {
@@ -1056,6 +1281,16 @@ impl TimeoutPacket {
             port_id_attr_on_b: packet.port_id_on_b.into(),
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
+            relayer: None,
+        }
+    }
+
+    /// Attaches the relayer, i.e. the signer of the message that timed out the packet,
+    /// which is appended to the attributes of the event.
+    pub fn with_relayer(self, relayer: Signer) -> Self {
+        Self {
+            relayer: Some(relayer),
+            ..self
         }
     }
 
@@ -1091,6 +1326,11 @@ impl TimeoutPacket {
         &self.channel_ordering_attr.order
     }
 
+    /// The relayer that submitted the message, if attached to the event.
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         TIMEOUT_EVENT
     }
@@ -1100,18 +1340,24 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: TimeoutPacket) -> Result<Self, Self::Error> {
+        let mut attributes = vec![
+            v.timeout_height_attr_on_b.into(),
+            v.timeout_timestamp_attr_on_b.into(),
+            v.seq_attr_on_a.into(),
+            v.port_id_attr_on_a.into(),
+            v.chan_id_attr_on_a.into(),
+            v.port_id_attr_on_b.into(),
+            v.chan_id_attr_on_b.into(),
+            v.channel_ordering_attr.into(),
+        ];
+
+        if let Some(relayer) = v.relayer {
+            attributes.push((PKT_RELAYER_ATTRIBUTE_KEY, relayer.to_string()).into());
+        }
+
         Ok(abci::Event {
             kind: TIMEOUT_EVENT.to_string(),
-            attributes: vec![
-                v.timeout_height_attr_on_b.into(),
-                v.timeout_timestamp_attr_on_b.into(),
-                v.seq_attr_on_a.into(),
-                v.port_id_attr_on_a.into(),
-                v.chan_id_attr_on_a.into(),
-                v.port_id_attr_on_b.into(),
-                v.chan_id_attr_on_b.into(),
-                v.channel_ordering_attr.into(),
-            ],
+            attributes,
         })
     }
 }
@@ -1228,15 +1474,41 @@ mod tests {
             Test {
                 kind: CHANNEL_CLOSE_CONFIRM_EVENT,
                 event: CloseConfirm::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    counterparty_port_id.clone(),
+                    counterparty_channel_id.clone(),
+                    connection_id,
+                )
+                .into(),
+                expected_keys: expected_keys[0..5].to_vec(),
+                expected_values: expected_values[0..5].to_vec(),
+            },
+            Test {
+                kind: CHANNEL_UPGRADE_TIMEOUT_EVENT,
+                event: UpgradeTimeout::new(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    counterparty_port_id.clone(),
+                    counterparty_channel_id.clone(),
+                    1,
+                )
+                .into(),
+                expected_keys: [&expected_keys[0..4], &["upgrade_sequence"]].concat(),
+                expected_values: [&expected_values[0..4], &["1"]].concat(),
+            },
+            Test {
+                kind: CHANNEL_UPGRADE_CANCELLED_EVENT,
+                event: UpgradeCancel::new(
                     port_id,
                     channel_id,
                     counterparty_port_id,
                     counterparty_channel_id,
-                    connection_id,
+                    2,
                 )
                 .into(),
-                expected_keys: expected_keys[0..5].to_vec(),
-                expected_values: expected_values[0..5].to_vec(),
+                expected_keys: [&expected_keys[0..4], &["upgrade_sequence"]].concat(),
+                expected_values: [&expected_values[0..4], &["2"]].concat(),
             },
         ];
 
@@ -3,6 +3,7 @@
 //!
 use core::str;
 
+use bytes::Bytes;
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
@@ -28,23 +29,70 @@ const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
 const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+pub(super) const PKT_LATENCY_ATTRIBUTE_KEY: &str = "packet_latency";
+pub(super) const PKT_RELAYER_ATTRIBUTE_KEY: &str = "packet_relayer";
 
-#[cfg_attr(
-    feature = "parity-scale-codec",
-    derive(
-        parity_scale_codec::Encode,
-        parity_scale_codec::Decode,
-        scale_info::TypeInfo
-    )
-)]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct PacketDataAttribute {
-    pub packet_data: Vec<u8>,
+    pub packet_data: Bytes,
+}
+
+impl From<Vec<u8>> for PacketDataAttribute {
+    fn from(packet_data: Vec<u8>) -> Self {
+        Self {
+            packet_data: packet_data.into(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for PacketDataAttribute {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.packet_data.as_ref(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for PacketDataAttribute {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        Ok(Vec::<u8>::deserialize_reader(reader)?.into())
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for PacketDataAttribute {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        parity_scale_codec::Encode::encode_to(self.packet_data.as_ref(), dest)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for PacketDataAttribute {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        Ok(<Vec<u8> as parity_scale_codec::Decode>::decode(input)?.into())
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for PacketDataAttribute {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("PacketDataAttribute", module_path!()))
+            .composite(
+                scale_info::build::Fields::named()
+                    .field(|f| f.ty::<Vec<u8>>().name("packet_data").type_name("Vec<u8>")),
+            )
+    }
 }
 
 impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
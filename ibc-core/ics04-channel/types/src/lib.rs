@@ -22,14 +22,22 @@ pub mod events;
 
 pub mod msgs;
 pub mod packet;
+pub mod receipts;
 pub mod timeout;
+pub mod upgrade;
 
 pub mod acknowledgement;
 pub mod commitment;
+
+#[cfg(feature = "serde")]
+pub mod app_version;
 mod version;
 pub use version::Version;
 
-/// Re-exports ICS-04 proto types from the `ibc-proto` crate
+/// Re-exports ICS-04 proto types from the `ibc-proto` crate, along with the
+/// channel upgrade messages it does not provide yet.
 pub mod proto {
     pub use ibc_proto::ibc::core::channel::*;
+
+    pub mod upgrade;
 }
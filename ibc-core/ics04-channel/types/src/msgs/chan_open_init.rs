@@ -19,11 +19,13 @@ pub const CHAN_OPEN_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenIn
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelOpenInit {
     pub port_id_on_a: PortId,
     pub connection_hops_on_a: Vec<ConnectionId>,
     pub port_id_on_b: PortId,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::channel::arbitrary_order))]
     pub ordering: Order,
     pub signer: Signer,
     /// Allow a relayer to specify a particular version by providing a non-empty version string
@@ -21,6 +21,7 @@ pub const CHAN_OPEN_TRY_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenTry
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelOpenTry {
     pub port_id_on_b: PortId,
@@ -30,6 +31,7 @@ pub struct MsgChannelOpenTry {
     pub version_supported_on_a: Version,
     pub proof_chan_end_on_a: CommitmentProofBytes,
     pub proof_height_on_a: Height,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::channel::arbitrary_order))]
     pub ordering: Order,
     pub signer: Signer,
 
@@ -8,6 +8,8 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_cancel;
+mod chan_upgrade_timeout;
 mod recv_packet;
 mod timeout;
 mod timeout_on_close;
@@ -22,6 +24,9 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+// Upgrade handshake messages.
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_timeout::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
 pub use recv_packet::*;
@@ -34,6 +39,7 @@ pub use timeout_on_close::*;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum ChannelMsg {
     OpenInit(MsgChannelOpenInit),
@@ -42,6 +48,8 @@ pub enum ChannelMsg {
     OpenConfirm(MsgChannelOpenConfirm),
     CloseInit(MsgChannelCloseInit),
     CloseConfirm(MsgChannelCloseConfirm),
+    UpgradeTimeout(MsgChannelUpgradeTimeout),
+    UpgradeCancel(MsgChannelUpgradeCancel),
 }
 
 /// All packet messages
@@ -50,6 +58,7 @@ pub enum ChannelMsg {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum PacketMsg {
     Recv(MsgRecvPacket),
@@ -66,6 +75,8 @@ pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
         ChannelMsg::OpenConfirm(msg) => &msg.port_id_on_b,
         ChannelMsg::CloseInit(msg) => &msg.port_id_on_a,
         ChannelMsg::CloseConfirm(msg) => &msg.port_id_on_b,
+        ChannelMsg::UpgradeTimeout(msg) => &msg.port_id_on_a,
+        ChannelMsg::UpgradeCancel(msg) => &msg.port_id_on_a,
     }
 }
 
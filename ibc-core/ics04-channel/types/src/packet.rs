@@ -1,4 +1,5 @@
 //! Defines the packet type
+use bytes::Bytes;
 use ibc_core_client_types::Height;
 use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_primitives::prelude::*;
@@ -52,19 +53,9 @@ impl core::fmt::Display for PacketMsgType {
 
 /// The packet type; this is what applications send to one another.
 ///
-/// Each application defines the structure of the `data` field.
-#[cfg_attr(
-    feature = "parity-scale-codec",
-    derive(
-        parity_scale_codec::Encode,
-        parity_scale_codec::Decode,
-        scale_info::TypeInfo
-    )
-)]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
+/// Each application defines the structure of the `data` field, which is held
+/// in a reference-counted [`Bytes`] buffer so that handing the packet to the
+/// application callbacks and to the emitted events does not copy it.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -78,11 +69,134 @@ pub struct Packet {
         feature = "serde",
         serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
     )]
-    pub data: Vec<u8>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub data: Bytes,
     pub timeout_height_on_b: TimeoutHeight,
     pub timeout_timestamp_on_b: Timestamp,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Packet {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let packet = Packet {
+            seq_on_a: Sequence::arbitrary(u)?,
+            port_id_on_a: PortId::arbitrary(u)?,
+            chan_id_on_a: ChannelId::arbitrary(u)?,
+            port_id_on_b: PortId::arbitrary(u)?,
+            chan_id_on_b: ChannelId::arbitrary(u)?,
+            data: Vec::<u8>::arbitrary(u)?.into(),
+            timeout_height_on_b: TimeoutHeight::arbitrary(u)?,
+            timeout_timestamp_on_b: Timestamp::arbitrary(u)?,
+        };
+
+        Packet::try_from(RawPacket::from(packet)).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for Packet {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        self.seq_on_a.serialize(writer)?;
+        self.port_id_on_a.serialize(writer)?;
+        self.chan_id_on_a.serialize(writer)?;
+        self.port_id_on_b.serialize(writer)?;
+        self.chan_id_on_b.serialize(writer)?;
+        borsh::BorshSerialize::serialize(self.data.as_ref(), writer)?;
+        self.timeout_height_on_b.serialize(writer)?;
+        self.timeout_timestamp_on_b.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for Packet {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        Ok(Self {
+            seq_on_a: Sequence::deserialize_reader(reader)?,
+            port_id_on_a: PortId::deserialize_reader(reader)?,
+            chan_id_on_a: ChannelId::deserialize_reader(reader)?,
+            port_id_on_b: PortId::deserialize_reader(reader)?,
+            chan_id_on_b: ChannelId::deserialize_reader(reader)?,
+            data: Vec::<u8>::deserialize_reader(reader)?.into(),
+            timeout_height_on_b: TimeoutHeight::deserialize_reader(reader)?,
+            timeout_timestamp_on_b: Timestamp::deserialize_reader(reader)?,
+        })
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for Packet {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        self.seq_on_a.encode_to(dest);
+        self.port_id_on_a.encode_to(dest);
+        self.chan_id_on_a.encode_to(dest);
+        self.port_id_on_b.encode_to(dest);
+        self.chan_id_on_b.encode_to(dest);
+        parity_scale_codec::Encode::encode_to(self.data.as_ref(), dest);
+        self.timeout_height_on_b.encode_to(dest);
+        self.timeout_timestamp_on_b.encode_to(dest);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for Packet {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        Ok(Self {
+            seq_on_a: Sequence::decode(input)?,
+            port_id_on_a: PortId::decode(input)?,
+            chan_id_on_a: ChannelId::decode(input)?,
+            port_id_on_b: PortId::decode(input)?,
+            chan_id_on_b: ChannelId::decode(input)?,
+            data: <Vec<u8> as parity_scale_codec::Decode>::decode(input)?.into(),
+            timeout_height_on_b: TimeoutHeight::decode(input)?,
+            timeout_timestamp_on_b: Timestamp::decode(input)?,
+        })
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for Packet {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("Packet", module_path!()))
+            .composite(
+                scale_info::build::Fields::named()
+                    .field(|f| f.ty::<Sequence>().name("seq_on_a").type_name("Sequence"))
+                    .field(|f| f.ty::<PortId>().name("port_id_on_a").type_name("PortId"))
+                    .field(|f| {
+                        f.ty::<ChannelId>()
+                            .name("chan_id_on_a")
+                            .type_name("ChannelId")
+                    })
+                    .field(|f| f.ty::<PortId>().name("port_id_on_b").type_name("PortId"))
+                    .field(|f| {
+                        f.ty::<ChannelId>()
+                            .name("chan_id_on_b")
+                            .type_name("ChannelId")
+                    })
+                    .field(|f| f.ty::<Vec<u8>>().name("data").type_name("Vec<u8>"))
+                    .field(|f| {
+                        f.ty::<TimeoutHeight>()
+                            .name("timeout_height_on_b")
+                            .type_name("TimeoutHeight")
+                    })
+                    .field(|f| {
+                        f.ty::<Timestamp>()
+                            .name("timeout_timestamp_on_b")
+                            .type_name("Timestamp")
+                    }),
+            )
+    }
+}
+
 struct PacketData<'a>(&'a [u8]);
 
 impl<'a> core::fmt::Debug for PacketData<'a> {
@@ -138,13 +252,45 @@ impl Packet {
     /// instead of the common-case where it results in
     /// [`MsgRecvPacket`](crate::msgs::MsgRecvPacket).
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
-        let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
+        self.timed_out_with(dst_chain_ts, dst_chain_height, |height, timeout_height| {
+            height > timeout_height
+        })
+    }
+
+    /// Checks whether a packet from a
+    /// [`SendPacket`](crate::events::SendPacket)
+    /// event is timed-out relative to the current state of the
+    /// destination chain, comparing its heights by the given comparison,
+    /// e.g. the one of the client tracking the destination chain.
+    pub fn timed_out_with(
+        &self,
+        dst_chain_ts: &Timestamp,
+        dst_chain_height: Height,
+        is_past: impl FnOnce(Height, Height) -> bool,
+    ) -> bool {
+        let height_timed_out = self
+            .timeout_height_on_b
+            .has_expired_with(dst_chain_height, is_past);
 
         let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
             && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Checks that the packet data does not exceed the given maximum size, in
+    /// bytes, if any.
+    pub fn verify_data_size(&self, max_data_size: Option<usize>) -> Result<(), PacketError> {
+        match max_data_size {
+            Some(max_data_size) if self.data.len() > max_data_size => {
+                Err(PacketError::PacketDataTooLarge {
+                    size: self.data.len(),
+                    max_size: max_data_size,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -203,7 +349,7 @@ impl TryFrom<RawPacket> for Packet {
             chan_id_on_a: raw_pkt.source_channel.parse()?,
             port_id_on_b: raw_pkt.destination_port.parse()?,
             chan_id_on_b: raw_pkt.destination_channel.parse()?,
-            data: raw_pkt.data,
+            data: raw_pkt.data.into(),
             timeout_height_on_b: packet_timeout_height,
             timeout_timestamp_on_b,
         })
@@ -218,7 +364,7 @@ impl From<Packet> for RawPacket {
             source_channel: packet.chan_id_on_a.to_string(),
             destination_port: packet.port_id_on_b.to_string(),
             destination_channel: packet.chan_id_on_b.to_string(),
-            data: packet.data,
+            data: packet.data.into(),
             timeout_height: packet.timeout_height_on_b.into(),
             timeout_timestamp: packet.timeout_timestamp_on_b.nanoseconds(),
         }
@@ -227,19 +373,8 @@ impl From<Packet> for RawPacket {
 
 /// The packet state type.
 ///
-/// Each application defines the structure of the `data` field.
-#[cfg_attr(
-    feature = "parity-scale-codec",
-    derive(
-        parity_scale_codec::Encode,
-        parity_scale_codec::Decode,
-        scale_info::TypeInfo
-    )
-)]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
+/// Each application defines the structure of the `data` field, which is held
+/// in a reference-counted [`Bytes`] buffer like the one of [`Packet`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -251,8 +386,10 @@ pub struct PacketState {
         feature = "serde",
         serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
     )]
-    pub data: Vec<u8>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub data: Bytes,
 }
+
 impl core::fmt::Debug for PacketState {
     fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         let data_wrapper = PacketData(&self.data);
@@ -267,6 +404,74 @@ impl core::fmt::Debug for PacketState {
     }
 }
 
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for PacketState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        self.port_id.serialize(writer)?;
+        self.chan_id.serialize(writer)?;
+        self.seq.serialize(writer)?;
+        borsh::BorshSerialize::serialize(self.data.as_ref(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for PacketState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        Ok(Self {
+            port_id: PortId::deserialize_reader(reader)?,
+            chan_id: ChannelId::deserialize_reader(reader)?,
+            seq: Sequence::deserialize_reader(reader)?,
+            data: Vec::<u8>::deserialize_reader(reader)?.into(),
+        })
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for PacketState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        self.port_id.encode_to(dest);
+        self.chan_id.encode_to(dest);
+        self.seq.encode_to(dest);
+        parity_scale_codec::Encode::encode_to(self.data.as_ref(), dest);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for PacketState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        Ok(Self {
+            port_id: PortId::decode(input)?,
+            chan_id: ChannelId::decode(input)?,
+            seq: Sequence::decode(input)?,
+            data: <Vec<u8> as parity_scale_codec::Decode>::decode(input)?.into(),
+        })
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for PacketState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("PacketState", module_path!()))
+            .composite(
+                scale_info::build::Fields::named()
+                    .field(|f| f.ty::<PortId>().name("port_id").type_name("PortId"))
+                    .field(|f| f.ty::<ChannelId>().name("chan_id").type_name("ChannelId"))
+                    .field(|f| f.ty::<Sequence>().name("seq").type_name("Sequence"))
+                    .field(|f| f.ty::<Vec<u8>>().name("data").type_name("Vec<u8>")),
+            )
+    }
+}
+
 /// Custom debug output to omit the packet data
 impl core::fmt::Display for PacketState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
@@ -294,7 +499,7 @@ impl TryFrom<RawPacketState> for PacketState {
             seq: Sequence::from(raw_pkt.sequence),
             port_id: raw_pkt.port_id.parse()?,
             chan_id: raw_pkt.channel_id.parse()?,
-            data: raw_pkt.data,
+            data: raw_pkt.data.into(),
         })
     }
 }
@@ -305,7 +510,7 @@ impl From<PacketState> for RawPacketState {
             sequence: packet.seq.value(),
             port_id: packet.port_id.to_string(),
             channel_id: packet.chan_id.to_string(),
-            data: packet.data,
+            data: packet.data.into(),
         }
     }
 }
@@ -0,0 +1,151 @@
+//! Defines the protobuf messages of the channel upgrades, under the
+//! `ibc.core.channel.v1` package, which `ibc-proto` does not provide yet.
+//!
+//! With the `serde` feature, the messages follow the proto3 JSON mapping like
+//! those of `ibc-proto`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::channel::v1::{Channel, Timeout};
+use ibc_proto::ibc::core::client::v1::Height;
+
+const PACKAGE: &str = "ibc.core.channel.v1";
+
+macro_rules! impl_name {
+    ($($message:ident),+) => {
+        $(
+            impl prost::Name for $message {
+                const NAME: &'static str = stringify!($message);
+                const PACKAGE: &'static str = PACKAGE;
+
+                fn full_name() -> String {
+                    format!("{}.{}", PACKAGE, stringify!($message))
+                }
+            }
+        )+
+    };
+}
+
+impl_name!(
+    UpgradeFields,
+    Upgrade,
+    ErrorReceipt,
+    MsgChannelUpgradeTimeout,
+    MsgChannelUpgradeCancel
+);
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpgradeFields {
+    #[prost(int32, tag = "1")]
+    pub ordering: i32,
+    #[prost(string, repeated, tag = "2")]
+    pub connection_hops: Vec<String>,
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Upgrade {
+    #[prost(message, optional, tag = "1")]
+    pub fields: Option<UpgradeFields>,
+    #[prost(message, optional, tag = "2")]
+    pub timeout: Option<Timeout>,
+    #[prost(uint64, tag = "3")]
+    pub next_sequence_send: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
+pub struct ErrorReceipt {
+    #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "json::u64_string"))]
+    pub sequence: u64,
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
+pub struct MsgChannelUpgradeTimeout {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub counterparty_channel: Option<Channel>,
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "json::base64_bytes"))]
+    pub proof_channel: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "6")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
+pub struct MsgChannelUpgradeCancel {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub error_receipt: Option<ErrorReceipt>,
+    #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "json::base64_bytes"))]
+    pub proof_error_receipt: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "6")]
+    pub signer: String,
+}
+
+/// The proto3 JSON mapping of the scalar fields that `serde` does not derive.
+#[cfg(feature = "serde")]
+mod json {
+    pub mod u64_string {
+        use ibc_primitives::prelude::*;
+        use serde::de::Error;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Number(u64),
+            String(String),
+        }
+
+        pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+            match Value::deserialize(deserializer)? {
+                Value::Number(value) => Ok(value),
+                Value::String(value) => value.parse().map_err(D::Error::custom),
+            }
+        }
+    }
+
+    pub mod base64_bytes {
+        use base64::prelude::BASE64_STANDARD;
+        use base64::Engine;
+        use ibc_primitives::prelude::*;
+        use serde::de::Error;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&BASE64_STANDARD.encode(value))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            BASE64_STANDARD
+                .decode(String::deserialize(deserializer)?)
+                .map_err(D::Error::custom)
+        }
+    }
+}
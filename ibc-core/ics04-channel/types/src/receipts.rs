@@ -0,0 +1,339 @@
+//! Defines a compact representation of the packet receipts of an unordered
+//! channel.
+//!
+//! Unordered channels record one receipt per received sequence, and these
+//! receipts are never pruned. Since relayers mostly deliver packets in
+//! increasing sequence order, the set of received sequences is in practice a
+//! handful of contiguous ranges. [`PacketReceiptRanges`] stores that set as
+//! an interval set, allowing hosts to back the receipt methods of their
+//! context in `O(ranges)` rather than `O(packets)` space.
+
+use ibc_core_host_types::identifiers::Sequence;
+use ibc_primitives::prelude::*;
+
+use crate::error::PacketError;
+use crate::packet::Receipt;
+
+/// An interval set of the sequences for which a packet receipt exists.
+///
+/// Ranges are inclusive on both ends, never overlap and are never adjacent:
+/// inserting a sequence next to an existing range extends it, and ranges
+/// that end up touching are merged. The decoding of the serialized ranges
+/// checks these invariants as well, through [`TryFrom<BTreeMap<u64, u64>>`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, scale_info::TypeInfo)
+)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "BTreeMap<u64, u64>", into = "BTreeMap<u64, u64>")
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PacketReceiptRanges {
+    /// Maps the start of every range to its (inclusive) end.
+    ranges: BTreeMap<u64, u64>,
+}
+
+impl PacketReceiptRanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the interval set out of receipts stored with the per-sequence
+    /// key layout, e.g. while migrating a host store to the compact layout.
+    pub fn from_receipts(receipts: impl IntoIterator<Item = (Sequence, Receipt)>) -> Self {
+        receipts.into_iter().map(|(seq, _)| seq).collect()
+    }
+
+    /// Returns `true` if a receipt exists for the given sequence.
+    pub fn contains(&self, seq: Sequence) -> bool {
+        let seq = seq.value();
+
+        self.ranges
+            .range(..=seq)
+            .next_back()
+            .map_or(false, |(_, end)| seq <= *end)
+    }
+
+    /// Returns the receipt for the given sequence, if any.
+    ///
+    /// Provided with the same shape as `ValidationContext::get_packet_receipt`
+    /// for hosts backing that method with this type.
+    pub fn get(&self, seq: Sequence) -> Option<Receipt> {
+        self.contains(seq).then_some(Receipt::Ok)
+    }
+
+    /// Records a receipt for the given sequence. Returns `false` if the
+    /// receipt was already present.
+    pub fn insert(&mut self, seq: Sequence) -> bool {
+        if self.contains(seq) {
+            return false;
+        }
+
+        let seq = seq.value();
+
+        // The range directly preceding `seq`, if `seq` extends it.
+        let prev_start = self
+            .ranges
+            .range(..seq)
+            .next_back()
+            .filter(|(_, end)| end.checked_add(1) == Some(seq))
+            .map(|(start, _)| *start);
+
+        // The range directly following `seq`, if `seq` extends it.
+        let next_end = seq
+            .checked_add(1)
+            .and_then(|next| self.ranges.remove(&next));
+
+        let start = prev_start.unwrap_or(seq);
+        let end = next_end.unwrap_or(seq);
+
+        self.ranges.insert(start, end);
+
+        true
+    }
+
+    /// Iterates over the inclusive `(start, end)` sequence ranges in
+    /// ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = (Sequence, Sequence)> + '_ {
+        self.ranges
+            .iter()
+            .map(|(start, end)| (Sequence::from(*start), Sequence::from(*end)))
+    }
+
+    /// Iterates over all the sequences for which a receipt exists, in
+    /// ascending order. This allows hosts to migrate back to the
+    /// per-sequence key layout.
+    pub fn sequences(&self) -> impl Iterator<Item = Sequence> + '_ {
+        self.ranges
+            .iter()
+            .flat_map(|(start, end)| (*start..=*end).map(Sequence::from))
+    }
+
+    /// Returns the number of stored ranges.
+    pub fn num_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns the number of sequences for which a receipt exists, saturating
+    /// at `u64::MAX` as the receipts of all the `u64::MAX + 1` sequences do
+    /// not fit in a `u64`.
+    pub fn num_receipts(&self) -> u64 {
+        self.ranges.iter().fold(0u64, |num_receipts, (start, end)| {
+            num_receipts.saturating_add((end - start).saturating_add(1))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl FromIterator<Sequence> for PacketReceiptRanges {
+    fn from_iter<I: IntoIterator<Item = Sequence>>(iter: I) -> Self {
+        let mut receipts = Self::new();
+        receipts.extend(iter);
+        receipts
+    }
+}
+
+impl TryFrom<BTreeMap<u64, u64>> for PacketReceiptRanges {
+    type Error = PacketError;
+
+    /// Checks that no range ends before it starts, and that the ranges
+    /// neither overlap nor touch, i.e. that each of them ends at least two
+    /// sequences before the next one starts.
+    fn try_from(ranges: BTreeMap<u64, u64>) -> Result<Self, Self::Error> {
+        let mut prev_end: Option<u64> = None;
+
+        for (&start, &end) in &ranges {
+            if start > end {
+                return Err(PacketError::InvalidReceiptRange { start, end });
+            }
+
+            if let Some(prev_end) = prev_end {
+                if prev_end.saturating_add(1) >= start {
+                    return Err(PacketError::UnmergedReceiptRanges {
+                        end: prev_end,
+                        next_start: start,
+                    });
+                }
+            }
+
+            prev_end = Some(end);
+        }
+
+        Ok(Self { ranges })
+    }
+}
+
+impl From<PacketReceiptRanges> for BTreeMap<u64, u64> {
+    fn from(receipts: PacketReceiptRanges) -> Self {
+        receipts.ranges
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for PacketReceiptRanges {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let ranges = BTreeMap::<u64, u64>::deserialize_reader(reader)?;
+
+        Self::try_from(ranges).map_err(|e| {
+            borsh::maybestd::io::Error::new(
+                borsh::maybestd::io::ErrorKind::InvalidData,
+                e.to_string(),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for PacketReceiptRanges {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let ranges = <BTreeMap<u64, u64> as parity_scale_codec::Decode>::decode(input)?;
+
+        Self::try_from(ranges)
+            .map_err(|_| parity_scale_codec::Error::from("invalid packet receipt ranges"))
+    }
+}
+
+impl Extend<Sequence> for PacketReceiptRanges {
+    fn extend<I: IntoIterator<Item = Sequence>>(&mut self, iter: I) {
+        for seq in iter {
+            self.insert(seq);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ranges_of(receipts: &PacketReceiptRanges) -> Vec<(u64, u64)> {
+        receipts
+            .ranges()
+            .map(|(start, end)| (start.value(), end.value()))
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![1], vec![(1, 1)])]
+    #[case(vec![1, 2, 3], vec![(1, 3)])]
+    #[case(vec![3, 2, 1], vec![(1, 3)])]
+    #[case(vec![1, 3], vec![(1, 1), (3, 3)])]
+    #[case(vec![1, 3, 2], vec![(1, 3)])]
+    #[case(vec![1, 2, 5, 6, 9], vec![(1, 2), (5, 6), (9, 9)])]
+    #[case(vec![5, 1, 5, 1], vec![(1, 1), (5, 5)])]
+    #[case(vec![u64::MAX, u64::MAX - 1], vec![(u64::MAX - 1, u64::MAX)])]
+    fn test_insert_merges_ranges(#[case] seqs: Vec<u64>, #[case] expected: Vec<(u64, u64)>) {
+        let receipts: PacketReceiptRanges = seqs.into_iter().map(Sequence::from).collect();
+
+        assert_eq!(ranges_of(&receipts), expected);
+    }
+
+    #[test]
+    fn test_contains() {
+        let receipts: PacketReceiptRanges =
+            [1, 2, 3, 7, 8].into_iter().map(Sequence::from).collect();
+
+        for seq in [1, 2, 3, 7, 8] {
+            assert!(receipts.contains(seq.into()));
+            assert!(receipts.get(seq.into()).is_some());
+        }
+        for seq in [0, 4, 6, 9] {
+            assert!(!receipts.contains(seq.into()));
+            assert!(receipts.get(seq.into()).is_none());
+        }
+
+        assert_eq!(receipts.num_ranges(), 2);
+        assert_eq!(receipts.num_receipts(), 5);
+    }
+
+    #[test]
+    fn test_num_receipts_saturates() {
+        let all_sequences = PacketReceiptRanges {
+            ranges: [(0, u64::MAX)].into_iter().collect(),
+        };
+        assert_eq!(all_sequences.num_receipts(), u64::MAX);
+
+        let two_halves = PacketReceiptRanges {
+            ranges: [(0, u64::MAX / 2), (u64::MAX / 2 + 2, u64::MAX)]
+                .into_iter()
+                .collect(),
+        };
+        assert_eq!(two_halves.num_receipts(), u64::MAX);
+    }
+
+    #[rstest]
+    #[case(vec![(5, 3)])]
+    #[case(vec![(1, 3), (3, 5)])]
+    #[case(vec![(1, 3), (4, 5)])]
+    #[case(vec![(1, u64::MAX), (u64::MAX, u64::MAX)])]
+    fn test_malformed_ranges_rejected(#[case] ranges: Vec<(u64, u64)>) {
+        assert!(
+            PacketReceiptRanges::try_from(ranges.into_iter().collect::<BTreeMap<_, _>>()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_well_formed_ranges_accepted() {
+        let ranges: BTreeMap<u64, u64> = [(1, 3), (5, 5), (7, u64::MAX)].into_iter().collect();
+
+        let receipts = PacketReceiptRanges::try_from(ranges.clone()).expect("well-formed ranges");
+
+        assert_eq!(BTreeMap::from(receipts), ranges);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_malformed_ranges() {
+        let receipts: PacketReceiptRanges = [1, 2, 5].into_iter().map(Sequence::from).collect();
+        let json = serde_json::to_string(&receipts).expect("serializable");
+
+        assert_eq!(
+            serde_json::from_str::<PacketReceiptRanges>(&json).ok(),
+            Some(receipts)
+        );
+        assert!(serde_json::from_str::<PacketReceiptRanges>(r#"{"5":3}"#).is_err());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_borsh_rejects_malformed_ranges() {
+        use borsh::{BorshDeserialize, BorshSerialize};
+
+        let malformed: BTreeMap<u64, u64> = [(5, 3)].into_iter().collect();
+        let bytes = malformed.try_to_vec().expect("serializable");
+
+        assert!(PacketReceiptRanges::try_from_slice(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_migration_round_trip() {
+        let per_key: BTreeMap<Sequence, Receipt> = [1, 2, 4, 5, 6, 10]
+            .into_iter()
+            .map(|seq| (Sequence::from(seq), Receipt::Ok))
+            .collect();
+
+        let mut receipts = PacketReceiptRanges::from_receipts(per_key.clone());
+
+        assert_eq!(ranges_of(&receipts), vec![(1, 2), (4, 6), (10, 10)]);
+        assert_eq!(
+            receipts.sequences().collect::<Vec<_>>(),
+            per_key.keys().copied().collect::<Vec<_>>()
+        );
+
+        assert!(!receipts.insert(5u64.into()));
+        assert!(receipts.insert(3u64.into()));
+        assert_eq!(ranges_of(&receipts), vec![(1, 6), (10, 10)]);
+    }
+}
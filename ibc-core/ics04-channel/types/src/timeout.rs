@@ -30,6 +30,7 @@ use ibc_proto::ibc::core::client::v1::Height as RawHeight;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 pub enum TimeoutHeight {
     Never,
@@ -68,8 +69,19 @@ impl TimeoutHeight {
     /// Check if a height is *strictly past* the timeout height, and thus is
     /// deemed expired.
     pub fn has_expired(&self, height: Height) -> bool {
+        self.has_expired_with(height, |height, timeout_height| height > timeout_height)
+    }
+
+    /// Check if a height is past the timeout height by the given comparison,
+    /// e.g. the one of the client tracking the chain of the height, and thus
+    /// is deemed expired.
+    pub fn has_expired_with(
+        &self,
+        height: Height,
+        is_past: impl FnOnce(Height, Height) -> bool,
+    ) -> bool {
         match self {
-            Self::At(timeout_height) => height > *timeout_height,
+            Self::At(timeout_height) => is_past(height, *timeout_height),
             // When there's no timeout, heights are never expired
             Self::Never => false,
         }
@@ -0,0 +1,258 @@
+//! Defines the types of the channel upgrades, through which both ends of an
+//! open channel renegotiate its ordering, connection hops or version without
+//! closing it.
+//!
+//! These types mirror the `Upgrade` and `ErrorReceipt` messages stored by
+//! ibc-go under the
+//! [`ChannelUpgradePath`](ibc_core_host_types::path::ChannelUpgradePath) and
+//! [`ChannelUpgradeErrorPath`](ibc_core_host_types::path::ChannelUpgradeErrorPath)
+//! of a channel end, whose protobuf messages are defined in
+//! [`proto::upgrade`](crate::proto::upgrade).
+
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::Timeout as RawTimeout;
+use ibc_proto::Protobuf;
+
+use crate::channel::Order;
+use crate::error::ChannelError;
+use crate::proto::upgrade::{
+    ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
+};
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The parameters of a channel end that an upgrade renegotiates.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawUpgradeFields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ordering: Order::from_i32(raw.ordering)?,
+            connection_hops: raw
+                .connection_hops
+                .iter()
+                .map(|conn_id| ConnectionId::from_str(conn_id))
+                .collect::<Result<_, _>>()?,
+            version: raw.version.into(),
+        })
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(fields: UpgradeFields) -> Self {
+        Self {
+            ordering: fields.ordering as i32,
+            connection_hops: fields
+                .connection_hops
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            version: fields.version.to_string(),
+        }
+    }
+}
+
+/// The height and timestamp on the counterparty chain after which the
+/// upgrade attempt is aborted.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    /// Returns whether the timeout has elapsed on the counterparty chain at
+    /// the given height and timestamp, i.e. whether either of them reached
+    /// the timeout.
+    pub fn has_elapsed(&self, height: Height, timestamp: Timestamp) -> bool {
+        let height_elapsed = self
+            .height
+            .has_expired_with(height, |height, timeout_height| height >= timeout_height);
+
+        let timestamp_elapsed = self.timestamp.is_set() && timestamp >= self.timestamp;
+
+        height_elapsed || timestamp_elapsed
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawTimeout) -> Result<Self, Self::Error> {
+        let height: TimeoutHeight = raw
+            .height
+            .try_into()
+            .map_err(|_| ChannelError::InvalidUpgradeTimeoutHeight)?;
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+            .map_err(ChannelError::InvalidUpgradeTimeoutTimestamp)?;
+
+        if !height.is_set() && !timestamp.is_set() {
+            return Err(ChannelError::MissingUpgradeTimeout);
+        }
+
+        Ok(Self { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(timeout: UpgradeTimeout) -> Self {
+        Self {
+            height: timeout.height.into(),
+            timestamp: timeout.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade attempt in progress on a channel end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    /// The parameters proposed for the upgraded channel end.
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    /// The next send sequence of the channel end when the upgrade attempt
+    /// started, up to which the in-flight packets must be flushed.
+    pub next_sequence_send: Sequence,
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fields: raw
+                .fields
+                .ok_or(ChannelError::MissingUpgradeFields)?
+                .try_into()?,
+            timeout: raw
+                .timeout
+                .ok_or(ChannelError::MissingUpgradeTimeout)?
+                .try_into()?,
+            next_sequence_send: raw.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(upgrade: Upgrade) -> Self {
+        Self {
+            fields: Some(upgrade.fields.into()),
+            timeout: Some(upgrade.timeout.into()),
+            next_sequence_send: upgrade.next_sequence_send.value(),
+        }
+    }
+}
+
+/// The receipt of a failed upgrade attempt, letting the counterparty abort
+/// its own side of the attempt.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    /// The upgrade sequence of the channel end at the failed attempt.
+    pub upgrade_sequence: u64,
+    /// The reason of the failure.
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(upgrade_sequence: u64, message: impl Into<String>) -> Self {
+        Self {
+            upgrade_sequence,
+            message: message.into(),
+        }
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl From<RawErrorReceipt> for ErrorReceipt {
+    fn from(raw: RawErrorReceipt) -> Self {
+        Self {
+            upgrade_sequence: raw.sequence,
+            message: raw.message,
+        }
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(error_receipt: ErrorReceipt) -> Self {
+        Self {
+            sequence: error_receipt.upgrade_sequence,
+            message: error_receipt.message,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Defines a cache of already verified membership proofs.
+//!
+//! Within a single block, relayers frequently submit several messages whose
+//! proofs are checked against the same consensus state root. Once a value has
+//! been successfully verified at a path under a root, with given proof specs,
+//! verifying it again is redundant, so hosts may plug in a
+//! [`ProofVerificationCache`] to skip the repeated ICS-23 computations.
+
+use alloc::collections::BTreeSet;
+use core::cell::RefCell;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleRoot};
+use ibc_proto::ics23::ProofSpec as RawProofSpec;
+use prost::Message;
+
+use crate::host_functions::{DefaultHostFunctions, HostFunctions};
+use crate::specs::ProofSpecs;
+
+/// Key identifying a successfully verified membership proof.
+///
+/// The key is made of the SHA-256 hash of the proof specs, the index of the
+/// first spec the proof is checked against, the commitment root, the full
+/// merkle path and the SHA-256 hash of the verified value. The proof bytes
+/// themselves are not part of the key: any valid proof of the same value at
+/// the same path under the same root and specs leads to the same verification
+/// outcome. Clients with different proof specs thus never share an entry,
+/// even when they track the same root.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VerifiedProofKey {
+    specs_hash: [u8; 32],
+    start_index: u64,
+    root: Vec<u8>,
+    path: Vec<String>,
+    value_hash: [u8; 32],
+}
+
+impl VerifiedProofKey {
+    pub fn new(
+        specs: &ProofSpecs,
+        start_index: u64,
+        root: &MerkleRoot,
+        path: &MerklePath,
+        value: &[u8],
+    ) -> Self {
+        Self::new_with::<DefaultHostFunctions>(specs, start_index, root, path, value)
+    }
+
+    /// Same as [`Self::new`], but hashes the specs and the value with the
+    /// given host functions.
+    pub fn new_with<H: HostFunctions>(
+        specs: &ProofSpecs,
+        start_index: u64,
+        root: &MerkleRoot,
+        path: &MerklePath,
+        value: &[u8],
+    ) -> Self {
+        let encoded_specs: Vec<u8> = Vec::<RawProofSpec>::from(specs.clone())
+            .iter()
+            .flat_map(Message::encode_length_delimited_to_vec)
+            .collect();
+
+        Self {
+            specs_hash: H::sha2_256(&encoded_specs),
+            start_index,
+            root: root.hash.clone(),
+            path: path.key_path.clone(),
+            value_hash: H::sha2_256(value),
+        }
+    }
+
+    pub fn specs_hash(&self) -> &[u8; 32] {
+        &self.specs_hash
+    }
+
+    pub fn start_index(&self) -> u64 {
+        self.start_index
+    }
+
+    pub fn root(&self) -> &[u8] {
+        &self.root
+    }
+
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    pub fn value_hash(&self) -> &[u8; 32] {
+        &self.value_hash
+    }
+}
+
+/// Cache of membership proofs that have already been verified.
+///
+/// Only successful verifications are recorded. Since validation entrypoints
+/// only have shared access to the host context, the methods take `&self` and
+/// implementations are expected to rely on interior mutability. Hosts are
+/// responsible for scoping the cache (e.g. clearing it at the end of every
+/// block).
+pub trait ProofVerificationCache {
+    /// Returns `true` if the proof identified by `key` was already verified.
+    fn is_verified(&self, key: &VerifiedProofKey) -> bool;
+
+    /// Records the proof identified by `key` as successfully verified.
+    fn mark_verified(&self, key: VerifiedProofKey);
+}
+
+/// A simple in-memory [`ProofVerificationCache`] backed by a `BTreeSet`.
+#[derive(Debug, Default)]
+pub struct InMemoryProofCache {
+    entries: RefCell<BTreeSet<VerifiedProofKey>>,
+}
+
+impl InMemoryProofCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached verified proofs.
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Removes all the cached entries. Typically called at the end of a block.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+impl ProofVerificationCache for InMemoryProofCache {
+    fn is_verified(&self, key: &VerifiedProofKey) -> bool {
+        self.entries.borrow().contains(key)
+    }
+
+    fn mark_verified(&self, key: VerifiedProofKey) {
+        self.entries.borrow_mut().insert(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::MerkleProof;
+
+    fn dummy_inputs() -> (MerkleRoot, MerklePath, Vec<u8>) {
+        let root = MerkleRoot {
+            hash: vec![1, 2, 3],
+        };
+        let path = MerklePath {
+            key_path: vec!["ibc".to_string(), "clients/07-tendermint-0".to_string()],
+        };
+        (root, path, b"value".to_vec())
+    }
+
+    /// The Cosmos SDK specs, with the IAVL tree allowed to be one level
+    /// shallower.
+    fn other_specs() -> ProofSpecs {
+        let mut iavl_spec = ics23::iavl_spec();
+        iavl_spec.min_depth += 1;
+        vec![iavl_spec, ics23::tendermint_spec()].into()
+    }
+
+    #[test]
+    fn cache_key_depends_on_all_components() {
+        let specs = ProofSpecs::default();
+        let (root, path, value) = dummy_inputs();
+        let key = VerifiedProofKey::new(&specs, 0, &root, &path, &value);
+
+        assert_eq!(key, VerifiedProofKey::new(&specs, 0, &root, &path, &value));
+        assert_ne!(
+            key,
+            VerifiedProofKey::new(&specs, 0, &root, &path, b"other")
+        );
+        assert_ne!(
+            key,
+            VerifiedProofKey::new(&specs, 0, &MerkleRoot { hash: vec![4] }, &path, &value)
+        );
+        assert_ne!(
+            key,
+            VerifiedProofKey::new(&specs, 0, &root, &MerklePath { key_path: vec![] }, &value)
+        );
+        assert_ne!(key, VerifiedProofKey::new(&specs, 1, &root, &path, &value));
+        assert_ne!(
+            key,
+            VerifiedProofKey::new(&other_specs(), 0, &root, &path, &value)
+        );
+    }
+
+    #[test]
+    fn cached_verification_is_skipped() {
+        let specs = ProofSpecs::default();
+        let (root, path, value) = dummy_inputs();
+        let cache = InMemoryProofCache::new();
+        // An empty proof always fails to verify.
+        let proof = MerkleProof { proofs: vec![] };
+
+        assert!(proof
+            .verify_membership_cached::<DefaultHostFunctions>(
+                &specs,
+                root.clone(),
+                path.clone(),
+                value.clone(),
+                0,
+                &cache,
+            )
+            .is_err());
+        assert!(cache.is_empty());
+
+        cache.mark_verified(VerifiedProofKey::new(&specs, 0, &root, &path, &value));
+
+        assert!(proof
+            .verify_membership_cached::<DefaultHostFunctions>(&specs, root, path, value, 0, &cache)
+            .is_ok());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn cached_verification_is_scoped_to_specs() {
+        let (root, path, value) = dummy_inputs();
+        let cache = InMemoryProofCache::new();
+        let proof = MerkleProof { proofs: vec![] };
+
+        // A proof verified for a client with the default specs ...
+        cache.mark_verified(VerifiedProofKey::new(
+            &ProofSpecs::default(),
+            0,
+            &root,
+            &path,
+            &value,
+        ));
+
+        // ... is not verified for a client with other specs sharing the cache,
+        // the root, the path and the value.
+        assert!(proof
+            .verify_membership_cached::<DefaultHostFunctions>(
+                &other_specs(),
+                root.clone(),
+                path.clone(),
+                value.clone(),
+                0,
+                &cache,
+            )
+            .is_err());
+
+        // Nor when checked from another spec on.
+        assert!(proof
+            .verify_membership_cached::<DefaultHostFunctions>(
+                &ProofSpecs::default(),
+                root,
+                path,
+                value,
+                1,
+                &cache,
+            )
+            .is_err());
+
+        assert_eq!(cache.len(), 1);
+    }
+}
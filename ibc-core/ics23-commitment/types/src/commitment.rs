@@ -2,6 +2,7 @@
 
 use core::fmt;
 
+use bytes::Bytes;
 use ibc_primitives::prelude::*;
 use ibc_primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
@@ -14,6 +15,7 @@ use crate::error::CommitmentError;
 /// Encodes a commitment root; most often a Merkle tree root hash.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, PartialEq, Eq)]
 pub struct CommitmentRoot {
     #[cfg_attr(
@@ -63,21 +65,22 @@ impl From<Vec<u8>> for CommitmentRoot {
 ///
 /// For example, in the case of a proof of membership in a Merkle tree,
 /// this encodes a Merkle proof.
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
+///
+/// The proof bytes are held in a reference-counted [`Bytes`] buffer, so that
+/// cloning a proof while it travels through the validation and execution
+/// paths does not copy it.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, PartialEq, Eq, derive_more::AsRef, derive_more::Into)]
+#[derive(Clone, PartialEq, Eq, derive_more::AsRef)]
 #[as_ref(forward)]
 pub struct CommitmentProofBytes {
     #[cfg_attr(
         feature = "serde",
         serde(serialize_with = "crate::serializer::ser_hex_upper")
     )]
-    bytes: Vec<u8>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    bytes: Bytes,
 }
 
 impl fmt::Debug for CommitmentProofBytes {
@@ -89,10 +92,21 @@ impl fmt::Debug for CommitmentProofBytes {
     }
 }
 
-impl TryFrom<Vec<u8>> for CommitmentProofBytes {
+impl CommitmentProofBytes {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the underlying reference-counted buffer without copying it.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl TryFrom<Bytes> for CommitmentProofBytes {
     type Error = CommitmentError;
 
-    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
         if bytes.is_empty() {
             Err(Self::Error::EmptyMerkleProof)
         } else {
@@ -101,6 +115,62 @@ impl TryFrom<Vec<u8>> for CommitmentProofBytes {
     }
 }
 
+impl TryFrom<Vec<u8>> for CommitmentProofBytes {
+    type Error = CommitmentError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(Bytes::from(bytes))
+    }
+}
+
+impl From<CommitmentProofBytes> for Vec<u8> {
+    fn from(proof: CommitmentProofBytes) -> Self {
+        proof.bytes.into()
+    }
+}
+
+impl From<CommitmentProofBytes> for Bytes {
+    fn from(proof: CommitmentProofBytes) -> Self {
+        proof.bytes
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for CommitmentProofBytes {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.bytes.as_ref(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for CommitmentProofBytes {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = Vec::<u8>::deserialize_reader(reader)?;
+
+        Ok(Self {
+            bytes: bytes.into(),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CommitmentProofBytes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut bytes = Vec::<u8>::arbitrary(u)?;
+        // Proofs are never empty; the first byte is drawn separately so that
+        // short fuzzer inputs still produce a proof.
+        bytes.insert(0, u8::arbitrary(u)?);
+        Ok(Self {
+            bytes: bytes.into(),
+        })
+    }
+}
+
 impl TryFrom<RawMerkleProof> for CommitmentProofBytes {
     type Error = CommitmentError;
 
@@ -170,6 +240,15 @@ impl TryFrom<Vec<u8>> for CommitmentPrefix {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CommitmentPrefix {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut bytes = Vec::<u8>::arbitrary(u)?;
+        bytes.insert(0, u8::arbitrary(u)?);
+        Ok(Self { bytes })
+    }
+}
+
 impl fmt::Debug for CommitmentPrefix {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let converted = core::str::from_utf8(self.as_bytes());
@@ -25,6 +25,10 @@ pub enum CommitmentError {
     InvalidMerkleProof,
     /// proof verification failed
     VerificationFailure,
+    /// empty batch of verified items
+    EmptyBatchItems,
+    /// batch merkle paths must be distinct and only differ by their last key
+    MismatchedBatchPaths,
     /// encoded commitment prefix is not a valid hex string: `{0}`
     EncodingFailure(String),
     /// decoding commitment proof bytes failed: `{0}`
@@ -0,0 +1,20 @@
+//! Defines the cryptographic functions that hosts provide to ibc-rs.
+//!
+//! All the hashing of the commitment proofs and the packet commitments goes
+//! through [`HostFunctions`], so that the hosts running in environments that
+//! require deterministic, host-provided crypto, e.g. zkVMs or enclave
+//! runtimes, plug in their own. [`DefaultHostFunctions`] is the pure Rust
+//! implementation, used by ibc-rs unless stated otherwise.
+
+pub use ics23::HostFunctionsProvider;
+
+/// The hashing functions provided by the host.
+///
+/// Implemented for all the ICS-23 [`HostFunctionsProvider`]s, which hosts
+/// implement instead.
+pub trait HostFunctions: HostFunctionsProvider {}
+
+impl<T> HostFunctions for T where T: HostFunctionsProvider {}
+
+/// The pure Rust implementation of the [`HostFunctions`].
+pub type DefaultHostFunctions = ics23::HostFunctionsManager;
@@ -12,11 +12,15 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod cache;
 pub mod commitment;
 pub mod error;
+pub mod host_functions;
 pub mod merkle;
 pub mod specs;
 
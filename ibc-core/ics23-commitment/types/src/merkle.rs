@@ -1,16 +1,21 @@
 //! Merkle proof utilities
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+
 use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof, MerkleRoot};
 use ibc_proto::ics23::commitment_proof::Proof;
 use ibc_proto::ics23::{
-    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
-    NonExistenceProof,
+    batch_entry, calculate_existence_root, decompress, is_compressed, verify_batch_membership,
+    verify_membership, verify_non_membership, CommitmentProof, ExistenceProof, NonExistenceProof,
 };
 use ibc_proto::Protobuf;
 
-use crate::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::cache::{ProofVerificationCache, VerifiedProofKey};
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::error::CommitmentError;
+use crate::host_functions::HostFunctions;
 use crate::specs::ProofSpecs;
 
 pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
@@ -53,7 +58,7 @@ impl From<MerkleProof> for RawMerkleProof {
 }
 
 impl MerkleProof {
-    pub fn verify_membership(
+    pub fn verify_membership<H: HostFunctions>(
         &self,
         specs: &ProofSpecs,
         root: MerkleRoot,
@@ -94,25 +99,17 @@ impl MerkleProof {
                     .expect("safe because if u64 is more than usize it will skip all anyway"),
             )
         {
-            match &proof.proof {
-                Some(Proof::Exist(existence_proof)) => {
-                    subroot =
-                        calculate_existence_root::<ics23::HostFunctionsManager>(existence_proof)
-                            .map_err(|_| CommitmentError::InvalidMerkleProof)?;
-
-                    if !verify_membership::<ics23::HostFunctionsManager>(
-                        proof,
-                        spec,
-                        &subroot,
-                        key.as_bytes(),
-                        &value,
-                    ) {
-                        return Err(CommitmentError::VerificationFailure);
-                    }
-                    value = subroot.clone();
-                }
-                _ => return Err(CommitmentError::InvalidMerkleProof),
+            let proof = decompressed(proof)?;
+            let existence_proof = existence_proof(&proof, key.as_bytes())
+                .ok_or(CommitmentError::InvalidMerkleProof)?;
+
+            subroot = calculate_existence_root::<H>(existence_proof)
+                .map_err(|_| CommitmentError::InvalidMerkleProof)?;
+
+            if !verify_membership::<H>(&proof, spec, &subroot, key.as_bytes(), &value) {
+                return Err(CommitmentError::VerificationFailure);
             }
+            value = subroot.clone();
         }
 
         if root.hash != subroot {
@@ -122,7 +119,136 @@ impl MerkleProof {
         Ok(())
     }
 
-    pub fn verify_non_membership(
+    /// Same as [`Self::verify_membership`], but skips the ICS-23 computations
+    /// if the given `cache` reports the value as already verified at `keys`
+    /// under `root`, with the same `specs` and `start_index`. Successful
+    /// verifications are recorded in the cache.
+    pub fn verify_membership_cached<H: HostFunctions>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+        start_index: u64,
+        cache: &impl ProofVerificationCache,
+    ) -> Result<(), CommitmentError> {
+        let cache_key = VerifiedProofKey::new_with::<H>(specs, start_index, &root, &keys, &value);
+
+        if cache.is_verified(&cache_key) {
+            return Ok(());
+        }
+
+        self.verify_membership::<H>(specs, root, keys, value, start_index)?;
+
+        cache.mark_verified(cache_key);
+
+        Ok(())
+    }
+
+    /// Verifies the membership of all the given `(path, value)` items with a
+    /// single proof, whose lowest proof is an ICS-23 batch proof, possibly
+    /// compressed, of the items in their common subtree, and whose other
+    /// proofs are the existence proofs of that subtree up to `root`.
+    ///
+    /// The paths of the items must be distinct and only differ by their last
+    /// key, e.g. the commitments of the packets of a channel under the same
+    /// store prefix.
+    pub fn verify_batch_membership<H: HostFunctions>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        items: &[(MerklePath, Vec<u8>)],
+    ) -> Result<(), CommitmentError> {
+        // validate arguments
+        if self.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+        if root.hash.is_empty() {
+            return Err(CommitmentError::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        if ics23_specs.len() != num {
+            return Err(CommitmentError::NumberOfSpecsMismatch);
+        }
+        let (keys, _) = items.first().ok_or(CommitmentError::EmptyBatchItems)?;
+
+        // keys are represented from root-to-leaf
+        let mut leaves = BTreeMap::new();
+        for (path, value) in items {
+            if path.key_path.len() != num {
+                return Err(CommitmentError::NumberOfKeysMismatch);
+            }
+            if value.is_empty() {
+                return Err(CommitmentError::EmptyVerifiedValue);
+            }
+            let (key, subtree) = path
+                .key_path
+                .split_last()
+                .ok_or(CommitmentError::NumberOfKeysMismatch)?;
+            if subtree != &keys.key_path[..num - 1]
+                || leaves.insert(key.as_bytes(), value.as_slice()).is_some()
+            {
+                return Err(CommitmentError::MismatchedBatchPaths);
+            }
+        }
+
+        // verify the items in the lowest subtree
+        let proof = self
+            .proofs
+            .first()
+            .ok_or(CommitmentError::InvalidMerkleProof)
+            .and_then(decompressed)?;
+        let spec = ics23_specs
+            .first()
+            .ok_or(CommitmentError::InvalidMerkleProof)?;
+        let key = keys.key_path[num - 1].as_bytes();
+        let existence_proof =
+            existence_proof(&proof, key).ok_or(CommitmentError::InvalidMerkleProof)?;
+        let subroot = calculate_existence_root::<H>(existence_proof)
+            .map_err(|_| CommitmentError::InvalidMerkleProof)?;
+
+        if !verify_batch_membership::<H>(&proof, spec, &subroot, leaves) {
+            return Err(CommitmentError::VerificationFailure);
+        }
+
+        // verify membership proofs starting from index 1 with value = subroot
+        self.verify_membership::<H>(specs, root, keys.clone(), subroot, 1)
+    }
+
+    /// Same as [`Self::verify_batch_membership`], but skips the ICS-23
+    /// computations if the given `cache` reports all the items as already
+    /// verified. Successful verifications record every item in the cache, so
+    /// that the subsequent verifications of the items one by one, e.g. of the
+    /// packets a relayer proved with a single batch proof, are cache hits.
+    pub fn verify_batch_membership_cached<H: HostFunctions>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        items: &[(MerklePath, Vec<u8>)],
+        cache: &impl ProofVerificationCache,
+    ) -> Result<(), CommitmentError> {
+        let cache_keys: Vec<_> = items
+            .iter()
+            // The items are verified from their leaves up to the root, as
+            // their membership from the first spec on.
+            .map(|(path, value)| VerifiedProofKey::new_with::<H>(specs, 0, &root, path, value))
+            .collect();
+
+        if !cache_keys.is_empty() && cache_keys.iter().all(|key| cache.is_verified(key)) {
+            return Ok(());
+        }
+
+        self.verify_batch_membership::<H>(specs, root, items)?;
+
+        cache_keys
+            .into_iter()
+            .for_each(|key| cache.mark_verified(key));
+
+        Ok(())
+    }
+
+    pub fn verify_non_membership<H: HostFunctions>(
         &self,
         specs: &ProofSpecs,
         root: MerkleRoot,
@@ -157,36 +283,501 @@ impl MerkleProof {
             .key_path
             .get(num - 1)
             .ok_or(CommitmentError::InvalidMerkleProof)?;
-        match &proof.proof {
-            Some(Proof::Nonexist(non_existence_proof)) => {
-                let subroot = calculate_non_existence_root(non_existence_proof)?;
-
-                if !verify_non_membership::<ics23::HostFunctionsManager>(
-                    proof,
-                    spec,
-                    &subroot,
-                    key.as_bytes(),
-                ) {
+        let proof = decompressed(proof)?;
+        match non_existence_proof(&proof, key.as_bytes()) {
+            Some(non_existence_proof) => {
+                let subroot = calculate_non_existence_root::<H>(non_existence_proof)?;
+
+                if !verify_non_membership::<H>(&proof, spec, &subroot, key.as_bytes()) {
                     return Err(CommitmentError::VerificationFailure);
                 }
 
                 // verify membership proofs starting from index 1 with value = subroot
-                self.verify_membership(specs, root, keys, subroot, 1)
+                self.verify_membership::<H>(specs, root, keys, subroot, 1)
             }
-            _ => Err(CommitmentError::InvalidMerkleProof),
+            None => Err(CommitmentError::InvalidMerkleProof),
         }
     }
 }
 
+/// Returns the given proof, with its compressed batch proof, if any,
+/// decompressed, since some counterparty chains emit compressed proofs.
+fn decompressed(proof: &CommitmentProof) -> Result<Cow<'_, CommitmentProof>, CommitmentError> {
+    if is_compressed(proof) {
+        decompress(proof)
+            .map(Cow::Owned)
+            .map_err(|e| CommitmentError::DecodingFailure(e.to_string()))
+    } else {
+        Ok(Cow::Borrowed(proof))
+    }
+}
+
+/// Returns the existence proof of `key` held by the given proof, which is
+/// either an existence proof or a decompressed batch proof.
+fn existence_proof<'a>(proof: &'a CommitmentProof, key: &[u8]) -> Option<&'a ExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Exist(existence_proof)) => Some(existence_proof),
+        Some(Proof::Batch(batch_proof)) => {
+            batch_proof
+                .entries
+                .iter()
+                .find_map(|entry| match &entry.proof {
+                    Some(batch_entry::Proof::Exist(existence_proof))
+                        if existence_proof.key == key =>
+                    {
+                        Some(existence_proof)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the non-existence proof of `key` held by the given proof, which is
+/// either a non-existence proof or a decompressed batch proof.
+fn non_existence_proof<'a>(
+    proof: &'a CommitmentProof,
+    key: &[u8],
+) -> Option<&'a NonExistenceProof> {
+    match &proof.proof {
+        Some(Proof::Nonexist(non_existence_proof)) => Some(non_existence_proof),
+        Some(Proof::Batch(batch_proof)) => {
+            batch_proof
+                .entries
+                .iter()
+                .find_map(|entry| match &entry.proof {
+                    Some(batch_entry::Proof::Nonexist(non_existence_proof))
+                        if non_existence_proof.key == key =>
+                    {
+                        Some(non_existence_proof)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Verifies that `proof` proves the membership of `value` at `path` in the
+/// store of `prefix`, against the commitment `root` of a counterparty chain.
+///
+/// This is the verification performed by the light clients for the handlers,
+/// made available to relayers and applications which verify proofs on their
+/// own, given the proof specs of the counterparty chain.
+pub fn verify_membership_proof<H: HostFunctions>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: impl Into<String>,
+    value: Vec<u8>,
+) -> Result<(), CommitmentError> {
+    let merkle_path = apply_prefix(prefix, vec![path.into()]);
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_membership::<H>(specs, root.clone().into(), merkle_path, value, 0)
+}
+
+/// Verifies that `proof` proves the absence of any value at `path` in the
+/// store of `prefix`, against the commitment `root` of a counterparty chain,
+/// e.g. the absence of the receipt of a packet which timed out.
+///
+/// This is the verification performed by the light clients for the handlers,
+/// made available to relayers and applications which verify proofs on their
+/// own, given the proof specs of the counterparty chain.
+pub fn verify_non_membership_proof<H: HostFunctions>(
+    specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: impl Into<String>,
+) -> Result<(), CommitmentError> {
+    let merkle_path = apply_prefix(prefix, vec![path.into()]);
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_non_membership::<H>(specs, root.clone().into(), merkle_path)
+}
+
 // TODO move to ics23
-fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, CommitmentError> {
+fn calculate_non_existence_root<H: HostFunctions>(
+    proof: &NonExistenceProof,
+) -> Result<Vec<u8>, CommitmentError> {
     if let Some(left) = &proof.left {
-        calculate_existence_root::<ics23::HostFunctionsManager>(left)
-            .map_err(|_| CommitmentError::InvalidMerkleProof)
+        calculate_existence_root::<H>(left).map_err(|_| CommitmentError::InvalidMerkleProof)
     } else if let Some(right) = &proof.right {
-        calculate_existence_root::<ics23::HostFunctionsManager>(right)
-            .map_err(|_| CommitmentError::InvalidMerkleProof)
+        calculate_existence_root::<H>(right).map_err(|_| CommitmentError::InvalidMerkleProof)
     } else {
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::{
+        compress, BatchEntry, BatchProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp,
+    };
+
+    use super::*;
+    use crate::cache::InMemoryProofCache;
+    use crate::host_functions::DefaultHostFunctions;
+
+    const RECEIPT_PATH: &str = "receipts/ports/transfer/channels/channel-0/sequences/1";
+    const ACK_PATH: &str = "acks/ports/transfer/channels/channel-0/sequences/1";
+
+    fn leaf_op(prefix: Vec<u8>) -> LeafOp {
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        }
+    }
+
+    /// Returns the proofs of an IBC store made of a single leaf at `ACK_PATH`,
+    /// namely the proof of the existence of the leaf and the proof of the
+    /// absence of `RECEIPT_PATH`, along with the root of the chain, whose
+    /// state is made of the IBC store only.
+    fn single_leaf_store_proofs(
+        prefix: &CommitmentPrefix,
+    ) -> (CommitmentProofBytes, CommitmentProofBytes, CommitmentRoot) {
+        // An IAVL leaf at height 0, of size 1, and at version 1.
+        let leaf = ExistenceProof {
+            key: ACK_PATH.as_bytes().to_vec(),
+            value: b"ack".to_vec(),
+            leaf: Some(leaf_op(vec![0, 2, 2])),
+            path: vec![],
+        };
+        let (store_proof, root) = store_proof(prefix, root_of(&leaf));
+
+        let existence_proof = CommitmentProof {
+            proof: Some(Proof::Exist(leaf.clone())),
+        };
+        let non_existence_proof = CommitmentProof {
+            proof: Some(Proof::Nonexist(NonExistenceProof {
+                key: RECEIPT_PATH.as_bytes().to_vec(),
+                left: Some(leaf),
+                right: None,
+            })),
+        };
+
+        let into_bytes = |proof: CommitmentProof| {
+            CommitmentProofBytes::try_from(RawMerkleProof::from(MerkleProof {
+                proofs: vec![proof, store_proof.clone()],
+            }))
+            .expect("non-empty proof")
+        };
+
+        (
+            into_bytes(existence_proof),
+            into_bytes(non_existence_proof),
+            root,
+        )
+    }
+
+    fn root_of(existence_proof: &ExistenceProof) -> Vec<u8> {
+        calculate_existence_root::<DefaultHostFunctions>(existence_proof)
+            .expect("valid existence proof")
+    }
+
+    /// Returns the proof of the IBC store of `prefix` with the given root, in
+    /// a chain whose state is made of the IBC store only, along with the root
+    /// of the chain.
+    fn store_proof(
+        prefix: &CommitmentPrefix,
+        store_root: Vec<u8>,
+    ) -> (CommitmentProof, CommitmentRoot) {
+        let store = ExistenceProof {
+            key: prefix.as_bytes().to_vec(),
+            value: store_root,
+            leaf: Some(leaf_op(vec![0])),
+            path: vec![],
+        };
+        let root = root_of(&store).into();
+
+        (
+            CommitmentProof {
+                proof: Some(Proof::Exist(store)),
+            },
+            root,
+        )
+    }
+
+    /// Returns the proof of the packet commitments of the given sequences,
+    /// stored as the two leaves of an IAVL store, with a batch proof of both
+    /// leaves, along with the root of the chain and the items of the batch.
+    fn two_leaf_store_batch_proof(
+        prefix: &CommitmentPrefix,
+    ) -> (MerkleProof, CommitmentRoot, Vec<(MerklePath, Vec<u8>)>) {
+        let path =
+            |seq: u64| format!("commitments/ports/transfer/channels/channel-0/sequences/{seq}");
+        // IAVL leaves at height 0, of size 1, and at version 1.
+        let leaf = |seq: u64| ExistenceProof {
+            key: path(seq).into_bytes(),
+            value: format!("commitment-{seq}").into_bytes(),
+            leaf: Some(leaf_op(vec![0, 2, 2])),
+            path: vec![],
+        };
+        let (mut left, mut right) = (leaf(1), leaf(2));
+        let (left_hash, right_hash) = (root_of(&left), root_of(&right));
+
+        // The IAVL inner node at height 1, of size 2, and at version 1.
+        left.path = vec![InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: vec![2, 4, 2, 32],
+            suffix: [vec![32], right_hash].concat(),
+        }];
+        right.path = vec![InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: [vec![2, 4, 2, 32], left_hash, vec![32]].concat(),
+            suffix: vec![],
+        }];
+        let (store_proof, root) = store_proof(prefix, root_of(&left));
+
+        let batch_proof = CommitmentProof {
+            proof: Some(Proof::Batch(BatchProof {
+                entries: vec![left, right]
+                    .into_iter()
+                    .map(|existence_proof| BatchEntry {
+                        proof: Some(batch_entry::Proof::Exist(existence_proof)),
+                    })
+                    .collect(),
+            })),
+        };
+        let items = [1, 2]
+            .into_iter()
+            .map(|seq| {
+                (
+                    apply_prefix(prefix, vec![path(seq)]),
+                    format!("commitment-{seq}").into_bytes(),
+                )
+            })
+            .collect();
+
+        (
+            MerkleProof {
+                proofs: vec![batch_proof, store_proof],
+            },
+            root,
+            items,
+        )
+    }
+
+    #[test]
+    fn test_verify_batch_membership() {
+        let specs = ProofSpecs::default();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix");
+        let (proof, root, items) = two_leaf_store_batch_proof(&prefix);
+        let compressed_proof = MerkleProof {
+            proofs: vec![
+                compress(&proof.proofs[0]).expect("valid batch proof"),
+                proof.proofs[1].clone(),
+            ],
+        };
+
+        for proof in [&proof, &compressed_proof] {
+            assert!(proof
+                .verify_batch_membership::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    &items
+                )
+                .is_ok());
+            assert!(proof
+                .verify_batch_membership::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    &items[1..]
+                )
+                .is_ok());
+
+            let mut wrong_value = items.clone();
+            wrong_value[1].1 = b"commitment-3".to_vec();
+            assert!(matches!(
+                proof.verify_batch_membership::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    &wrong_value
+                ),
+                Err(CommitmentError::VerificationFailure)
+            ));
+
+            let mut wrong_prefix = items.clone();
+            wrong_prefix[1].0.key_path[0] = "other".to_string();
+            assert!(matches!(
+                proof.verify_batch_membership::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    &wrong_prefix
+                ),
+                Err(CommitmentError::MismatchedBatchPaths)
+            ));
+
+            assert!(matches!(
+                proof.verify_batch_membership::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    &[]
+                ),
+                Err(CommitmentError::EmptyBatchItems)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_membership_with_compressed_proof() {
+        let specs = ProofSpecs::default();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix");
+        let (proof, root, items) = two_leaf_store_batch_proof(&prefix);
+        let compressed_proof = MerkleProof {
+            proofs: vec![
+                compress(&proof.proofs[0]).expect("valid batch proof"),
+                proof.proofs[1].clone(),
+            ],
+        };
+        assert!(is_compressed(&compressed_proof.proofs[0]));
+
+        for proof in [proof, compressed_proof] {
+            let proof_bytes = CommitmentProofBytes::try_from(proof).expect("non-empty proof");
+
+            for (path, value) in &items {
+                let path = path.key_path[1].clone();
+
+                assert!(verify_membership_proof::<DefaultHostFunctions>(
+                    &specs,
+                    &prefix,
+                    &proof_bytes,
+                    &root,
+                    path.clone(),
+                    value.clone()
+                )
+                .is_ok());
+                assert!(verify_membership_proof::<DefaultHostFunctions>(
+                    &specs,
+                    &prefix,
+                    &proof_bytes,
+                    &root,
+                    path,
+                    b"other".to_vec()
+                )
+                .is_err());
+            }
+
+            // The batch holds no proof of other paths.
+            assert!(matches!(
+                verify_membership_proof::<DefaultHostFunctions>(
+                    &specs,
+                    &prefix,
+                    &proof_bytes,
+                    &root,
+                    ACK_PATH,
+                    b"ack".to_vec()
+                ),
+                Err(CommitmentError::InvalidMerkleProof)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_batch_membership_cached() {
+        let specs = ProofSpecs::default();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix");
+        let (proof, root, items) = two_leaf_store_batch_proof(&prefix);
+        let cache = InMemoryProofCache::new();
+
+        assert!(proof
+            .verify_batch_membership_cached::<DefaultHostFunctions>(
+                &specs,
+                root.clone().into(),
+                &items,
+                &cache
+            )
+            .is_ok());
+        assert_eq!(cache.len(), items.len());
+
+        // The items are then verified one by one without any valid proof.
+        let empty_proof = MerkleProof {
+            proofs: vec![CommitmentProof { proof: None }; 2],
+        };
+        for (path, value) in items {
+            assert!(empty_proof
+                .verify_membership_cached::<DefaultHostFunctions>(
+                    &specs,
+                    root.clone().into(),
+                    path,
+                    value,
+                    0,
+                    &cache
+                )
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_verify_membership_proof() {
+        let specs = ProofSpecs::default();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix");
+        let (proof, _, root) = single_leaf_store_proofs(&prefix);
+
+        assert!(verify_membership_proof::<DefaultHostFunctions>(
+            &specs,
+            &prefix,
+            &proof,
+            &root,
+            ACK_PATH,
+            b"ack".to_vec()
+        )
+        .is_ok());
+        assert!(verify_membership_proof::<DefaultHostFunctions>(
+            &specs,
+            &prefix,
+            &proof,
+            &root,
+            ACK_PATH,
+            b"other".to_vec()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_non_membership_proof() {
+        let specs = ProofSpecs::default();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix");
+        let (existence_proof, proof, root) = single_leaf_store_proofs(&prefix);
+
+        assert!(verify_non_membership_proof::<DefaultHostFunctions>(
+            &specs,
+            &prefix,
+            &proof,
+            &root,
+            RECEIPT_PATH
+        )
+        .is_ok());
+
+        // The proof does not prove the absence of a path ordered before its
+        // left neighbour.
+        assert!(verify_non_membership_proof::<DefaultHostFunctions>(
+            &specs, &prefix, &proof, &root, "aaa"
+        )
+        .is_err());
+        // An existence proof does not prove an absence.
+        assert!(verify_non_membership_proof::<DefaultHostFunctions>(
+            &specs,
+            &prefix,
+            &existence_proof,
+            &root,
+            RECEIPT_PATH
+        )
+        .is_err());
+        // The proof is not valid against another root.
+        assert!(verify_non_membership_proof::<DefaultHostFunctions>(
+            &specs,
+            &prefix,
+            &proof,
+            &CommitmentRoot::from_bytes(&[1; 32]),
+            RECEIPT_PATH
+        )
+        .is_err());
+    }
+}
@@ -51,6 +51,15 @@ impl Default for ProofSpecs {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ProofSpecs {
+    fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Proof specs are chain constants rather than attacker-controlled
+        // input, so fuzzing them only yields states no host would accept.
+        Ok(Self::cosmos())
+    }
+}
+
 impl From<Vec<RawProofSpec>> for ProofSpecs {
     fn from(ics23_specs: Vec<RawProofSpec>) -> Self {
         Self(
@@ -0,0 +1,44 @@
+use ibc_client_tendermint::consensus_state::ConsensusState;
+use ibc_core_client_types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::HostConsensusContext;
+use ibc_proto::google::protobuf::Any;
+use tendermint::block::Header;
+
+use crate::validate_self_client::ValidateSelfClientContext;
+
+/// Context to be implemented by Tendermint-based hosts to get the Tendermint
+/// implementation of [`HostConsensusContext`] through [`TendermintHost`].
+pub trait TendermintHostContext: ValidateSelfClientContext {
+    /// Returns the header of the host block at the given height.
+    fn host_header(&self, height: &Height) -> Result<Header, ContextError>;
+}
+
+/// The Tendermint implementation of [`HostConsensusContext`], which validates
+/// the clients of the host with
+/// [`ValidateSelfClientContext::validate_self_tendermint_client`] and derives
+/// the consensus states of the host from its block headers.
+///
+/// Hosts delegate to it from their own `HostConsensusContext`
+/// implementation, e.g. with
+/// `TendermintHost(self).validate_host_client_state(client_state)`.
+pub struct TendermintHost<'a, C>(pub &'a C);
+
+impl<C> HostConsensusContext for TendermintHost<'_, C>
+where
+    C: TendermintHostContext,
+{
+    type HostConsensusState = ConsensusState;
+
+    fn validate_host_client_state(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError> {
+        self.0
+            .validate_self_tendermint_client(client_state_of_host_on_counterparty)
+    }
+
+    fn consensus_state_of_host(&self, height: &Height) -> Result<ConsensusState, ContextError> {
+        Ok(ConsensusState::from(self.0.host_header(height)?))
+    }
+}
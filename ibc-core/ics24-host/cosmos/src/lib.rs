@@ -14,8 +14,11 @@ pub mod upgrade_proposal;
 
 pub mod utils;
 
+mod host_consensus;
+pub use host_consensus::{TendermintHost, TendermintHostContext};
+
 mod validate_self_client;
-pub use validate_self_client::ValidateSelfClientContext;
+pub use validate_self_client::{SelfClientRule, ValidateSelfClientContext};
 
 /// Re-exports necessary proto types for implementing the tendermint client
 /// upgradeability feature.
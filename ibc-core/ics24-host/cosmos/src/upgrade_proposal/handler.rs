@@ -1,5 +1,5 @@
 use ibc_client_tendermint::types::ClientState as TmClientState;
-use ibc_core_client_types::error::UpgradeClientError;
+use ibc_core_client_types::error::{ClientError, UpgradeClientError};
 use ibc_core_host_types::path::UpgradeClientPath;
 use ibc_primitives::prelude::*;
 use tendermint::abci::Event as TmEvent;
@@ -17,7 +17,8 @@ pub fn upgrade_client_proposal_handler<Ctx>(
 ) -> Result<TmEvent, UpgradeClientError>
 where
     Ctx: UpgradeExecutionContext,
-    Ctx::AnyClientState: From<TmClientState>,
+    Ctx::AnyClientState: TryFrom<TmClientState>,
+    <Ctx::AnyClientState as TryFrom<TmClientState>>::Error: Into<ClientError>,
 {
     let plan = proposal.plan;
 
@@ -38,7 +39,13 @@ where
 
     let upgraded_client_state_path = UpgradeClientPath::UpgradedClientState(plan.height);
 
-    ctx.store_upgraded_client_state(upgraded_client_state_path, client_state.into())?;
+    let client_state = Ctx::AnyClientState::try_from(client_state).map_err(|e| {
+        UpgradeClientError::ClientStateConversionFailed {
+            description: e.into().to_string(),
+        }
+    })?;
+
+    ctx.store_upgraded_client_state(upgraded_client_state_path, client_state)?;
 
     let event = TmEvent::from(UpgradeClientProposal::new(proposal.title, plan.height));
 
@@ -1,6 +1,7 @@
 use core::time::Duration;
 
 use ibc_client_tendermint::client_state::ClientState;
+use ibc_client_tendermint::types::ClientState as ClientStateType;
 use ibc_core_client_context::client_state::ClientStateCommon;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
@@ -12,8 +13,50 @@ use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 
+/// The rules checked by
+/// [`ValidateSelfClientContext::validate_self_tendermint_client`] on the
+/// client state of the host created by a counterparty, on top of its validity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfClientRule {
+    /// The chain identifier is the one of the host.
+    ChainId,
+    /// The latest height is in the revision of the host.
+    RevisionNumber,
+    /// The latest height is lower than the current height of the host.
+    LatestHeight,
+    /// The proof specs are the ones of the host.
+    ProofSpecs,
+    /// The trust level is a valid Tendermint trust threshold.
+    TrustLevel,
+    /// The unbonding period is the one of the host.
+    UnbondingPeriod,
+    /// The unbonding period is not shorter than the trusting period.
+    TrustingPeriod,
+    /// The upgrade path, if any, is the one of the host.
+    UpgradePath,
+}
+
+impl SelfClientRule {
+    /// All the rules, as checked by ibc-go.
+    pub const ALL: [Self; 8] = [
+        Self::ChainId,
+        Self::RevisionNumber,
+        Self::LatestHeight,
+        Self::ProofSpecs,
+        Self::TrustLevel,
+        Self::UnbondingPeriod,
+        Self::TrustingPeriod,
+        Self::UpgradePath,
+    ];
+}
+
 /// Provides an implementation of `ValidationContext::validate_self_client` for
 /// Tendermint-based hosts.
+///
+/// Hosts whose conventions differ from the ones of the Cosmos SDK, e.g. on the
+/// unbonding period, can opt out of some rules with
+/// [`self_client_rules`](Self::self_client_rules), and check their own with
+/// [`validate_self_client_custom`](Self::validate_self_client_custom).
 pub trait ValidateSelfClientContext {
     fn validate_self_tendermint_client(
         &self,
@@ -38,102 +81,143 @@ pub trait ValidateSelfClientContext {
             .into());
         }
 
-        let self_chain_id = self.chain_id();
-        if self_chain_id != &tm_client_state_inner.chain_id {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "invalid chain-id. expected: {}, got: {}",
-                        self_chain_id, tm_client_state_inner.chain_id
-                    ),
-                },
-            ));
+        for rule in self.self_client_rules() {
+            self.check_self_client_rule(*rule, &tm_client_state)?;
         }
 
-        let latest_height = tm_client_state.latest_height();
-        let self_revision_number = self_chain_id.revision_number();
-        if self_revision_number != latest_height.revision_number() {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client is not in the same revision as the chain. expected: {}, got: {}",
-                        self_revision_number,
-                        latest_height.revision_number()
-                    ),
-                },
-            ));
-        }
-
-        if latest_height >= self.host_current_height() {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client has latest height {} greater than or equal to chain height {}",
-                        latest_height,
-                        self.host_current_height()
-                    ),
-                },
-            ));
-        }
+        self.validate_self_client_custom(tm_client_state_inner)
+    }
 
-        if self.proof_specs() != &tm_client_state_inner.proof_specs {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client has invalid proof specs. expected: {:?}, got: {:?}",
-                        self.proof_specs(),
-                        tm_client_state_inner.proof_specs
-                    ),
-                },
-            ));
-        }
+    /// Returns the rules checked on the client states of the host, which are
+    /// all the [`SelfClientRule`]s by default.
+    fn self_client_rules(&self) -> &[SelfClientRule] {
+        &SelfClientRule::ALL
+    }
 
-        let _ = {
-            let trust_level = tm_client_state_inner.trust_level;
+    /// Checks the given rule on the client state of the host.
+    fn check_self_client_rule(
+        &self,
+        rule: SelfClientRule,
+        tm_client_state: &ClientState,
+    ) -> Result<(), ContextError> {
+        let tm_client_state_inner = tm_client_state.inner();
 
-            TendermintTrustThresholdFraction::new(
-                trust_level.numerator(),
-                trust_level.denominator(),
-            )
-            .map_err(|_| ConnectionError::InvalidClientState {
-                reason: "invalid trust level".to_string(),
-            })?
-        };
+        match rule {
+            SelfClientRule::ChainId => {
+                let self_chain_id = self.chain_id();
+                if self_chain_id != &tm_client_state_inner.chain_id {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "invalid chain-id. expected: {}, got: {}",
+                                self_chain_id, tm_client_state_inner.chain_id
+                            ),
+                        },
+                    ));
+                }
+            }
+            SelfClientRule::RevisionNumber => {
+                let latest_height = tm_client_state.latest_height();
+                let self_revision_number = self.chain_id().revision_number();
+                if self_revision_number != latest_height.revision_number() {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "client is not in the same revision as the chain. expected: {}, got: {}",
+                                self_revision_number,
+                                latest_height.revision_number()
+                            ),
+                        },
+                    ));
+                }
+            }
+            SelfClientRule::LatestHeight => {
+                let latest_height = tm_client_state.latest_height();
+                if latest_height >= self.host_current_height() {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "client has latest height {} greater than or equal to chain height {}",
+                                latest_height,
+                                self.host_current_height()
+                            ),
+                        },
+                    ));
+                }
+            }
+            SelfClientRule::ProofSpecs => {
+                if self.proof_specs() != &tm_client_state_inner.proof_specs {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "client has invalid proof specs. expected: {:?}, got: {:?}",
+                                self.proof_specs(),
+                                tm_client_state_inner.proof_specs
+                            ),
+                        },
+                    ));
+                }
+            }
+            SelfClientRule::TrustLevel => {
+                let trust_level = tm_client_state_inner.trust_level;
 
-        if self.unbonding_period() != tm_client_state_inner.unbonding_period {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "invalid unbonding period. expected: {:?}, got: {:?}",
-                        self.unbonding_period(),
+                TendermintTrustThresholdFraction::new(
+                    trust_level.numerator(),
+                    trust_level.denominator(),
+                )
+                .map_err(|_| ConnectionError::InvalidClientState {
+                    reason: "invalid trust level".to_string(),
+                })?;
+            }
+            SelfClientRule::UnbondingPeriod => {
+                if self.unbonding_period() != tm_client_state_inner.unbonding_period {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "invalid unbonding period. expected: {:?}, got: {:?}",
+                                self.unbonding_period(),
+                                tm_client_state_inner.unbonding_period,
+                            ),
+                        },
+                    ));
+                }
+            }
+            SelfClientRule::TrustingPeriod => {
+                if tm_client_state_inner.unbonding_period < tm_client_state_inner.trusting_period {
+                    return Err(ContextError::ConnectionError(ConnectionError::InvalidClientState{ reason: format!(
+                        "unbonding period must be greater than trusting period. unbonding period ({:?}) < trusting period ({:?})",
                         tm_client_state_inner.unbonding_period,
-                    ),
-                },
-            ));
-        }
-
-        if tm_client_state_inner.unbonding_period < tm_client_state_inner.trusting_period {
-            return Err(ContextError::ConnectionError(ConnectionError::InvalidClientState{ reason: format!(
-                "unbonding period must be greater than trusting period. unbonding period ({:?}) < trusting period ({:?})",
-                tm_client_state_inner.unbonding_period,
-                tm_client_state_inner.trusting_period
-            )}));
+                        tm_client_state_inner.trusting_period
+                    )}));
+                }
+            }
+            SelfClientRule::UpgradePath => {
+                if !tm_client_state_inner.upgrade_path.is_empty()
+                    && self.upgrade_path() != tm_client_state_inner.upgrade_path
+                {
+                    return Err(ContextError::ConnectionError(
+                        ConnectionError::InvalidClientState {
+                            reason: format!(
+                                "invalid upgrade path. expected: {:?}, got: {:?}",
+                                self.upgrade_path(),
+                                tm_client_state_inner.upgrade_path
+                            ),
+                        },
+                    ));
+                }
+            }
         }
 
-        if !tm_client_state_inner.upgrade_path.is_empty()
-            && self.upgrade_path() != tm_client_state_inner.upgrade_path
-        {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "invalid upgrade path. expected: {:?}, got: {:?}",
-                        self.upgrade_path(),
-                        tm_client_state_inner.upgrade_path
-                    ),
-                },
-            ));
-        }
+        Ok(())
+    }
 
+    /// Checks the host-specific rules on the client state of the host, once
+    /// the [`self_client_rules`](Self::self_client_rules) are checked. The
+    /// default implementation checks none.
+    fn validate_self_client_custom(
+        &self,
+        _tm_client_state: &ClientStateType,
+    ) -> Result<(), ContextError> {
         Ok(())
     }
 
@@ -152,3 +236,101 @@ pub trait ValidateSelfClientContext {
     /// Returns the host upgrade path. May be empty.
     fn upgrade_path(&self) -> &[String];
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_tendermint::types::{AllowUpdate, TrustThreshold};
+
+    use super::*;
+
+    /// A host accepting the client states with an unbonding period not
+    /// shorter than its own, when configured without the unbonding rule.
+    struct Host {
+        rules: Vec<SelfClientRule>,
+        chain_id: ChainId,
+        proof_specs: ProofSpecs,
+    }
+
+    impl ValidateSelfClientContext for Host {
+        fn self_client_rules(&self) -> &[SelfClientRule] {
+            &self.rules
+        }
+
+        fn validate_self_client_custom(
+            &self,
+            tm_client_state: &ClientStateType,
+        ) -> Result<(), ContextError> {
+            if tm_client_state.unbonding_period < self.unbonding_period() {
+                return Err(ConnectionError::InvalidClientState {
+                    reason: "unbonding period shorter than the host one".to_string(),
+                }
+                .into());
+            }
+            Ok(())
+        }
+
+        fn chain_id(&self) -> &ChainId {
+            &self.chain_id
+        }
+
+        fn host_current_height(&self) -> Height {
+            Height::new(0, 100).expect("valid height")
+        }
+
+        fn proof_specs(&self) -> &ProofSpecs {
+            &self.proof_specs
+        }
+
+        fn unbonding_period(&self) -> Duration {
+            Duration::from_secs(64_000)
+        }
+
+        fn upgrade_path(&self) -> &[String] {
+            &[]
+        }
+    }
+
+    fn client_state_of_host(unbonding_period: Duration) -> Any {
+        ClientStateType::new(
+            ChainId::new("host-0").expect("valid chain id"),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(32_000),
+            unbonding_period,
+            Duration::from_secs(3),
+            Height::new(0, 10).expect("valid height"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .expect("valid client state")
+        .into()
+    }
+
+    #[test]
+    fn test_self_client_rules() {
+        let mut host = Host {
+            rules: SelfClientRule::ALL.to_vec(),
+            chain_id: ChainId::new("host-0").expect("valid chain id"),
+            proof_specs: ProofSpecs::cosmos(),
+        };
+
+        let longer_unbonding = client_state_of_host(Duration::from_secs(128_000));
+
+        assert!(host
+            .validate_self_tendermint_client(longer_unbonding.clone())
+            .is_err());
+
+        host.rules
+            .retain(|rule| rule != &SelfClientRule::UnbondingPeriod);
+
+        assert!(host
+            .validate_self_tendermint_client(longer_unbonding)
+            .is_ok());
+        assert!(host
+            .validate_self_tendermint_client(client_state_of_host(Duration::from_secs(48_000)))
+            .is_err());
+    }
+}
@@ -1,37 +1,55 @@
 use core::time::Duration;
 
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
 use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::commitment::{
+    compute_ack_commitment_with, compute_packet_commitment_with, AcknowledgementCommitment,
+    PacketCommitment,
+};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::packet::Receipt;
-use ibc_core_client_context::client_state::ClientState;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client_context::client_state::{ClientState, ClientStateCommon};
 use ibc_core_client_context::consensus_state::ConsensusState;
 use ibc_core_client_context::{ClientExecutionContext, ClientValidationContext};
-use ibc_core_client_types::Height;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::{ClientParams, Height};
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_commitment_types::host_functions::DefaultHostFunctions;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::version::{
     get_compatible_versions, pick_version, Version as ConnectionVersion,
 };
-use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_connection_types::{ConnectionEnd, ConnectionParams};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ClientId, ClientType, ConnectionId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    ClientConsensusStatePath, CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
+use ibc_core_host_types::validate::{Ics24IdentifierPolicy, IdentifierPolicy};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-use ibc_primitives::{Signer, Timestamp};
+use ibc_primitives::{Signer, SignerValidationMode, Timestamp};
 
 use crate::utils::calculate_block_delay;
+use crate::HostConsensusContext;
 
 /// Context to be implemented by the host that provides all "read-only" methods.
 ///
 /// Trait used for the top-level `validate` entrypoint in the `ibc-core` crate.
-pub trait ValidationContext {
+///
+/// The checks depending on the consensus of the host are provided by its
+/// [`HostConsensusContext`], to which
+/// [`validate_self_client`](Self::validate_self_client) and
+/// [`host_consensus_state`](Self::host_consensus_state) delegate by default.
+pub trait ValidationContext: HostConsensusContext {
     type V: ClientValidationContext;
     type E: ClientExecutionContext;
-    type AnyConsensusState: ConsensusState;
+    type AnyConsensusState: ConsensusState + From<Self::HostConsensusState>;
     type AnyClientState: ClientState<Self::V, Self::E>;
 
     /// Retrieve the context that implements all clients' `ValidationContext`.
@@ -45,6 +63,19 @@ pub trait ValidationContext {
     /// Tries to decode the given `client_state` into a concrete light client state.
     fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError>;
 
+    /// Checks that the host supports the clients of the given type, which
+    /// the create and update client handlers consult before operating on a
+    /// client.
+    ///
+    /// Hosts registering their client types at runtime, e.g. in a
+    /// [`DynClientStateRegistry`], look them up there. The default
+    /// implementation supports all the client types the host decodes.
+    ///
+    /// [`DynClientStateRegistry`]: ibc_core_client_context::dyn_client_state::DynClientStateRegistry
+    fn validate_client_type(&self, _client_type: &ClientType) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Retrieve the consensus state for the given client ID at the specified
     /// height.
     ///
@@ -63,16 +94,52 @@ pub trait ValidationContext {
     fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
 
     /// Returns the `ConsensusState` of the host (local) chain at a specific height.
+    ///
+    /// Defaults to [`HostConsensusContext::consensus_state_of_host`].
     fn host_consensus_state(
         &self,
         height: &Height,
-    ) -> Result<Self::AnyConsensusState, ContextError>;
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.consensus_state_of_host(height).map(Into::into)
+    }
 
     /// Returns a natural number, counting how many clients have been created
     /// thus far. The value of this counter should increase only via method
     /// `ExecutionContext::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, ContextError>;
 
+    /// Allocates the identifier of the client being created with the given
+    /// client state.
+    ///
+    /// By default, client identifiers are formed from the client type and the
+    /// client counter, e.g. `07-tendermint-0`. Hosts may override this method,
+    /// e.g. to derive identifiers from the counterparty chain ID or to namespace
+    /// them per module. The allocated identifier must be prefixed with
+    /// `{client-type}-`, must not be in use already, and must be the same
+    /// during the validation and the execution of a given `MsgCreateClient`.
+    /// The client counter is increased on every client creation regardless.
+    fn allocate_client_id(
+        &self,
+        client_state: &Self::AnyClientState,
+    ) -> Result<ClientId, ContextError> {
+        Ok(client_state
+            .client_type()
+            .build_client_id(self.client_counter()?))
+    }
+
+    /// Returns the relayer that updated the client of the given identifier
+    /// with a header for the given height, as recorded by
+    /// [`ExecutionContext::store_client_update_relayer`].
+    ///
+    /// The default implementation records nothing and returns `None`.
+    fn client_update_relayer(
+        &self,
+        _client_id: &ClientId,
+        _height: &Height,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(None)
+    }
+
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
@@ -82,12 +149,14 @@ pub trait ValidationContext {
     /// client state of a host chain, please refer to the [ICS24 host
     /// requirements](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#client-state-validation)
     ///
-    /// Additionally, implementations specific to individual chains can be found
-    /// in the `ibc-core-hostkit` crate.
+    /// Defaults to [`HostConsensusContext::validate_host_client_state`], e.g.
+    /// to the Tendermint implementation of the `ibc-core-host-cosmos` crate.
     fn validate_self_client(
         &self,
         client_state_of_host_on_counterparty: Any,
-    ) -> Result<(), ContextError>;
+    ) -> Result<(), ContextError> {
+        self.validate_host_client_state(client_state_of_host_on_counterparty)
+    }
 
     /// Returns the prefix that the local chain uses in the KV store.
     fn commitment_prefix(&self) -> CommitmentPrefix;
@@ -97,10 +166,29 @@ pub trait ValidationContext {
 
     /// Function required by ICS-03. Returns the list of all possible versions that the connection
     /// handshake protocol supports.
+    ///
+    /// It is consulted by the validation and the execution of `ConnOpenInit` to check the
+    /// proposed version, and of `ConnOpenTry` through `pick_version`. Hosts may override it, and build their versions
+    /// with `Version::new`, to support feature sets beyond `ORDER_ORDERED` and
+    /// `ORDER_UNORDERED`.
     fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
         get_compatible_versions()
     }
 
+    /// Filters or reorders the versions proposed by the counterparty during
+    /// `ConnOpenTry`, before `pick_version` selects one of them.
+    ///
+    /// Hosts may override it to forbid specific versions or features, e.g. by
+    /// removing a feature from the proposed versions, without modifying
+    /// `get_compatible_versions`. The proposed versions are kept as is by
+    /// default.
+    fn filter_counterparty_versions(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<Vec<ConnectionVersion>, ContextError> {
+        Ok(counterparty_candidate_versions.to_vec())
+    }
+
     /// Function required by ICS-03. Returns one version out of the supplied list of versions, which the
     /// connection handshake protocol prefers.
     fn pick_version(
@@ -117,6 +205,42 @@ pub trait ValidationContext {
     /// Returns the `ChannelEnd` for the given `port_id` and `chan_id`.
     fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
 
+    /// Returns the upgrade attempt in progress on the given channel end, if
+    /// any.
+    ///
+    /// The default implementation stores no upgrades and returns `None`.
+    fn channel_upgrade(
+        &self,
+        _channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the receipt of the last failed upgrade attempt of the given
+    /// channel end, if any.
+    ///
+    /// The default implementation stores no receipts and returns `None`.
+    fn channel_upgrade_error(
+        &self,
+        _channel_upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<Option<ErrorReceipt>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the upgrade sequence of the given channel end, i.e. the number
+    /// of upgrade attempts it went through.
+    ///
+    /// The channel ends of `ibc-proto` do not carry the upgrade sequence yet,
+    /// so hosts supporting channel upgrades track it alongside the channel end,
+    /// as stored by [`ExecutionContext::store_channel_upgrade_sequence`]. It is
+    /// `0` by default.
+    fn channel_upgrade_sequence(
+        &self,
+        _channel_end_path: &ChannelEndPath,
+    ) -> Result<u64, ContextError> {
+        Ok(0)
+    }
+
     /// Returns the sequence number for the next packet to be sent for the given store path
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
@@ -135,6 +259,9 @@ pub trait ValidationContext {
     ) -> Result<PacketCommitment, ContextError>;
 
     /// Returns the packet receipt for the given store path
+    ///
+    /// Hosts that prefer not to store one key per received sequence can back
+    /// this method with a `PacketReceiptRanges` per channel.
     fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError>;
 
     /// Returns the packet acknowledgement for the given store path
@@ -143,23 +270,152 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
+    /// Returns the host timestamp at which the packet committed at the given
+    /// store path was sent, as recorded by
+    /// [`ExecutionContext::store_packet_send_timestamp`].
+    ///
+    /// The default implementation records nothing and returns `None`, in which
+    /// case no latency is reported in the `acknowledge_packet` events.
+    fn get_packet_send_timestamp(
+        &self,
+        _commitment_path: &CommitmentPath,
+    ) -> Result<Option<Timestamp>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the relayer that delivered the packet received at the given
+    /// store path, as recorded by
+    /// [`ExecutionContext::store_packet_receipt_relayer`].
+    ///
+    /// The default implementation records nothing and returns `None`.
+    fn get_packet_receipt_relayer(
+        &self,
+        _receipt_path: &ReceiptPath,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(None)
+    }
+
+    /// Computes the commitment of a packet, with the host functions of the
+    /// host.
+    ///
+    /// The default implementation hashes with the pure-Rust
+    /// [`DefaultHostFunctions`]. Hosts with accelerated hashing, e.g. zkVM
+    /// precompiles, may override it with
+    /// [`compute_packet_commitment_with`].
+    fn compute_packet_commitment(
+        &self,
+        packet_data: &[u8],
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> PacketCommitment {
+        compute_packet_commitment_with::<DefaultHostFunctions>(
+            packet_data,
+            timeout_height,
+            timeout_timestamp,
+        )
+    }
+
+    /// Computes the commitment of an acknowledgement, with the host functions
+    /// of the host.
+    ///
+    /// The default implementation hashes with the pure-Rust
+    /// [`DefaultHostFunctions`], see
+    /// [`compute_packet_commitment`](Self::compute_packet_commitment).
+    fn compute_ack_commitment(&self, ack: &Acknowledgement) -> AcknowledgementCommitment {
+        compute_ack_commitment_with::<DefaultHostFunctions>(ack)
+    }
+
+    /// Returns the maximum size, in bytes, of the data of the packets sent and
+    /// received by the host.
+    ///
+    /// Hosts bounding the memory and gas spent on the packets relayed to them
+    /// override this method. The packet data size is unbounded by default.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
     fn channel_counter(&self) -> Result<u64, ContextError>;
 
-    /// Returns the maximum expected time per block
-    fn max_expected_time_per_block(&self) -> Duration;
-
     /// Calculates the block delay period using the connection's delay period and the maximum
-    /// expected time per block.
-    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
-        calculate_block_delay(delay_period_time, &self.max_expected_time_per_block())
+    /// expected time per block of the stored [`connection_params`](Self::connection_params).
+    ///
+    /// Hosts with variable block times may override this method to compute the
+    /// block delay from their own block time estimates.
+    fn block_delay(&self, delay_period_time: &Duration) -> Result<u64, ContextError> {
+        let max_expected_time_per_block = self.connection_params()?.max_expected_time_per_block;
+
+        Ok(calculate_block_delay(
+            delay_period_time,
+            &max_expected_time_per_block,
+        ))
+    }
+
+    /// Returns how the `signer` fields of the IBC messages are checked by the
+    /// default [`validate_message_signer`](Self::validate_message_signer)
+    /// implementation.
+    ///
+    /// Any signer is accepted by default. Hosts whose accounts are bech32
+    /// addresses may return the `Bech32` mode, behind the `bech32` feature, to
+    /// reject malformed signers before any message is handled.
+    fn signer_validation_mode(&self) -> SignerValidationMode {
+        SignerValidationMode::default()
     }
 
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
-    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+    ///
+    /// The signer is checked according to the
+    /// [`signer_validation_mode`](Self::signer_validation_mode) by default.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        signer
+            .validate(&self.signer_validation_mode())
+            .map_err(|e| ClientError::InvalidSigner {
+                reason: e.to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    /// Returns the host-specific ruleset the connection and channel handshake
+    /// handlers validate the identifiers of the incoming messages with, i.e.
+    /// the ports opening a channel and the identifiers chosen by the
+    /// counterparty.
+    ///
+    /// Hosts may override it, e.g. to forbid some port names or to accept
+    /// the longer port identifiers of an [`IdentifierLengthPolicy`]. The
+    /// ICS-24 rules are enforced by default.
+    ///
+    /// [`IdentifierLengthPolicy`]: ibc_core_host_types::validate::IdentifierLengthPolicy
+    fn identifier_policy(&self) -> &dyn IdentifierPolicy {
+        &Ics24IdentifierPolicy
+    }
+
+    /// Returns the parameters of the client submodule, which allow all the
+    /// client types by default.
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(ClientParams::default())
+    }
+
+    /// Returns the stored parameters of the connection submodule, as updated
+    /// by [`ExecutionContext::store_connection_params`].
+    ///
+    /// Their maximum expected time per block is used alongside the connection
+    /// delay periods to enforce block delays, see
+    /// [`block_delay`](Self::block_delay), and they are reported by the
+    /// connection params query. Hosts without stored parameters may return
+    /// [`ConnectionParams::default`].
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError>;
+
+    /// Returns the authority allowed to update the parameters of the IBC
+    /// submodules, usually the account of the governance module of the host.
+    ///
+    /// By default, there is none, and the parameters cannot be updated.
+    fn params_authority(&self) -> Option<Signer> {
+        None
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -174,6 +430,25 @@ pub trait ExecutionContext: ValidationContext {
     /// Should never fail.
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Records the relayer, i.e. the signer of the `MsgUpdateClient`, that
+    /// updated the client of the given identifier with a header for the given
+    /// consensus height, alongside the update meta of the client.
+    ///
+    /// Hosts wishing to reward or account for the relayers, e.g. in fee
+    /// distribution schemes, override this method along with
+    /// [`ValidationContext::client_update_relayer`]. Such hosts should delete
+    /// the recorded relayer along with the update meta in
+    /// `ClientExecutionContext::delete_update_meta`, as consensus states are
+    /// pruned. The default implementation records nothing.
+    fn store_client_update_relayer(
+        &mut self,
+        _client_id: &ClientId,
+        _height: Height,
+        _relayer: Signer,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -206,13 +481,57 @@ pub trait ExecutionContext: ValidationContext {
         commitment_path: &CommitmentPath,
     ) -> Result<(), ContextError>;
 
+    /// Records the host timestamp at which the packet committed at the given
+    /// store path was sent, from which the send to acknowledgement latency of
+    /// the packet is computed.
+    ///
+    /// Hosts wishing to monitor the latency of the packets override this
+    /// method along with [`ValidationContext::get_packet_send_timestamp`] and
+    /// [`ExecutionContext::delete_packet_send_timestamp`]. The default
+    /// implementation records nothing.
+    fn store_packet_send_timestamp(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+        _timestamp: Timestamp,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Deletes the send timestamp of the packet committed at the given store
+    /// path, once the packet is acknowledged or timed out.
+    fn delete_packet_send_timestamp(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Stores the given packet receipt at the given store path
+    ///
+    /// See `PacketReceiptRanges` in the `ibc-core-channel-types` crate for a
+    /// compact, interval-based alternative to the per-sequence key layout.
     fn store_packet_receipt(
         &mut self,
         receipt_path: &ReceiptPath,
         receipt: Receipt,
     ) -> Result<(), ContextError>;
 
+    /// Records the relayer, i.e. the signer of the `MsgRecvPacket`, that
+    /// delivered the packet received at the given store path.
+    ///
+    /// The relayer is recorded for the packets of both ordered and unordered
+    /// channels, even though only the latter store a packet receipt. Hosts
+    /// wishing to reward or account for the relayers override this method
+    /// along with [`ValidationContext::get_packet_receipt_relayer`]. The
+    /// default implementation records nothing.
+    fn store_packet_receipt_relayer(
+        &mut self,
+        _receipt_path: &ReceiptPath,
+        _relayer: Signer,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Stores the given packet acknowledgement at the given store path
     fn store_packet_acknowledgement(
         &mut self,
@@ -230,6 +549,43 @@ pub trait ExecutionContext: ValidationContext {
         channel_end: ChannelEnd,
     ) -> Result<(), ContextError>;
 
+    /// Deletes the upgrade attempt of the given channel end, once it is
+    /// aborted or completed.
+    ///
+    /// Hosts supporting channel upgrades must override this method, along
+    /// with [`channel_upgrade`](ValidationContext::channel_upgrade).
+    fn delete_channel_upgrade(
+        &mut self,
+        _channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradeNotSupported.into())
+    }
+
+    /// Stores the receipt of the failed upgrade attempt of the given channel
+    /// end, which the counterparty proves to cancel its own attempt.
+    ///
+    /// Hosts supporting channel upgrades must override this method, along
+    /// with [`channel_upgrade_error`](ValidationContext::channel_upgrade_error).
+    fn store_channel_upgrade_error(
+        &mut self,
+        _channel_upgrade_error_path: &ChannelUpgradeErrorPath,
+        _error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradeNotSupported.into())
+    }
+
+    /// Stores the upgrade sequence of the given channel end.
+    ///
+    /// Hosts supporting channel upgrades must override this method, along
+    /// with [`channel_upgrade_sequence`](ValidationContext::channel_upgrade_sequence).
+    fn store_channel_upgrade_sequence(
+        &mut self,
+        _channel_end_path: &ChannelEndPath,
+        _upgrade_sequence: u64,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradeNotSupported.into())
+    }
+
     /// Stores the given `nextSequenceSend` number at the given store path
     fn store_next_sequence_send(
         &mut self,
@@ -261,4 +617,23 @@ pub trait ExecutionContext: ValidationContext {
 
     /// Log the given message.
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Stores the parameters of the client submodule, as updated by the
+    /// [`params_authority`](ValidationContext::params_authority).
+    ///
+    /// Hosts with a params authority must override this method, along with
+    /// [`client_params`](ValidationContext::client_params).
+    fn store_client_params(&mut self, _params: ClientParams) -> Result<(), ContextError> {
+        Err(ClientError::ParamsStorageNotSupported.into())
+    }
+
+    /// Stores the parameters of the connection submodule, as updated by the
+    /// [`params_authority`](ValidationContext::params_authority).
+    ///
+    /// Hosts with a params authority must override this method, so that
+    /// [`connection_params`](ValidationContext::connection_params) returns the
+    /// updated parameters.
+    fn store_connection_params(&mut self, _params: ConnectionParams) -> Result<(), ContextError> {
+        Err(ConnectionError::ParamsStorageNotSupported.into())
+    }
 }
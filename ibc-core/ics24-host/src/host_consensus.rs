@@ -0,0 +1,198 @@
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+/// Context to be implemented by the host that provides the parts of the
+/// connection handshake depending on the consensus of the host: the validation
+/// of the clients of the host on counterparty chains, and the retrieval of the
+/// consensus states of the host they are verified against.
+///
+/// It is a supertrait of [`ValidationContext`], whose
+/// [`validate_self_client`](ValidationContext::validate_self_client) and
+/// [`host_consensus_state`](ValidationContext::host_consensus_state) delegate
+/// to it by default. The `ibc-core-host-cosmos` crate implements it for
+/// Tendermint-based hosts, which delegate to its `TendermintHost`. Hosts
+/// running another consensus, e.g. rollups or Substrate chains, implement it
+/// with their own checks instead.
+///
+/// [`ValidationContext`]: crate::ValidationContext
+pub trait HostConsensusContext {
+    /// The consensus state of the host, as tracked by its clients on
+    /// counterparty chains.
+    type HostConsensusState;
+
+    /// Validates the `ClientState` of the client (a client referring to host)
+    /// stored on the counterparty chain against the host's internal state.
+    fn validate_host_client_state(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the consensus state of the host at a specific height.
+    fn consensus_state_of_host(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+}
+
+/// The default number of host consensus states retained by a
+/// [`HostConsensusStore`], matching the default `historical_entries` of the
+/// staking module of the Cosmos SDK.
+pub const DEFAULT_HOST_CONSENSUS_RETENTION: u64 = 10_000;
+
+/// Storage of the consensus states of the host, to be implemented by hosts
+/// that cannot derive them from their block headers on demand, and thus
+/// record them at every block instead.
+///
+/// Hosts record the consensus state of each new block with
+/// [`track_host_consensus_state`](Self::track_host_consensus_state), which
+/// prunes the ones falling out of the retention window, and serve
+/// [`ValidationContext::host_consensus_state`] with
+/// [`host_consensus_state_at`](Self::host_consensus_state_at).
+///
+/// [`ValidationContext::host_consensus_state`]: crate::ValidationContext::host_consensus_state
+pub trait HostConsensusStore {
+    /// The consensus state of the host, as tracked by its clients on
+    /// counterparty chains.
+    type HostConsensusState;
+
+    /// Returns the stored consensus state of the host at the given height, if
+    /// any.
+    fn get_host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Option<Self::HostConsensusState>, ContextError>;
+
+    /// Returns the heights of all the stored consensus states of the host.
+    fn host_consensus_heights(&self) -> Result<Vec<Height>, ContextError>;
+
+    /// Stores the consensus state of the host at the given height.
+    fn store_host_consensus_state(
+        &mut self,
+        height: Height,
+        consensus_state: Self::HostConsensusState,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the consensus state of the host at the given height.
+    fn delete_host_consensus_state(&mut self, height: Height) -> Result<(), ContextError>;
+
+    /// Returns the number of the most recent consensus states of the host to
+    /// retain, which must cover the heights at which counterparties may still
+    /// prove their clients of the host during the connection handshakes.
+    fn host_consensus_retention(&self) -> u64 {
+        DEFAULT_HOST_CONSENSUS_RETENTION
+    }
+
+    /// Returns the consensus state of the host at the given height, failing
+    /// if it was never stored or already pruned.
+    fn host_consensus_state_at(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.get_host_consensus_state(height)?
+            .ok_or(ClientError::MissingLocalConsensusState { height: *height })
+            .map_err(ConnectionError::Client)
+            .map_err(ContextError::ConnectionError)
+    }
+
+    /// Stores the consensus state of the host at the given height, and prunes
+    /// the ones falling out of the retention window.
+    fn track_host_consensus_state(
+        &mut self,
+        height: Height,
+        consensus_state: Self::HostConsensusState,
+    ) -> Result<(), ContextError> {
+        self.store_host_consensus_state(height, consensus_state)?;
+        self.prune_host_consensus_states()
+    }
+
+    /// Deletes the consensus states of the host but the
+    /// [`host_consensus_retention`](Self::host_consensus_retention) most
+    /// recent ones.
+    fn prune_host_consensus_states(&mut self) -> Result<(), ContextError> {
+        let mut heights = self.host_consensus_heights()?;
+        heights.sort();
+
+        let retention = usize::try_from(self.host_consensus_retention()).unwrap_or(usize::MAX);
+        let pruned = heights.len().saturating_sub(retention);
+
+        for height in heights.into_iter().take(pruned) {
+            self.delete_host_consensus_state(height)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Store {
+        consensus_states: BTreeMap<Height, u64>,
+    }
+
+    impl HostConsensusStore for Store {
+        type HostConsensusState = u64;
+
+        fn get_host_consensus_state(&self, height: &Height) -> Result<Option<u64>, ContextError> {
+            Ok(self.consensus_states.get(height).copied())
+        }
+
+        fn host_consensus_heights(&self) -> Result<Vec<Height>, ContextError> {
+            Ok(self.consensus_states.keys().copied().collect())
+        }
+
+        fn store_host_consensus_state(
+            &mut self,
+            height: Height,
+            consensus_state: u64,
+        ) -> Result<(), ContextError> {
+            self.consensus_states.insert(height, consensus_state);
+            Ok(())
+        }
+
+        fn delete_host_consensus_state(&mut self, height: Height) -> Result<(), ContextError> {
+            self.consensus_states.remove(&height);
+            Ok(())
+        }
+
+        fn host_consensus_retention(&self) -> u64 {
+            3
+        }
+    }
+
+    #[test]
+    fn test_host_consensus_pruning() {
+        let mut store = Store::default();
+
+        for revision_height in 1..=5 {
+            let height = Height::new(0, revision_height).unwrap();
+            store
+                .track_host_consensus_state(height, revision_height)
+                .unwrap();
+        }
+
+        assert_eq!(
+            store.host_consensus_heights().unwrap(),
+            vec![
+                Height::new(0, 3).unwrap(),
+                Height::new(0, 4).unwrap(),
+                Height::new(0, 5).unwrap(),
+            ]
+        );
+        assert_eq!(
+            store
+                .host_consensus_state_at(&Height::new(0, 4).unwrap())
+                .unwrap(),
+            4
+        );
+        assert!(store
+            .host_consensus_state_at(&Height::new(0, 2).unwrap())
+            .is_err());
+    }
+}
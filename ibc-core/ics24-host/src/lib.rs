@@ -22,6 +22,13 @@ pub(crate) mod utils;
 mod context;
 pub use context::*;
 
+mod host_consensus;
+pub use host_consensus::{
+    HostConsensusContext, HostConsensusStore, DEFAULT_HOST_CONSENSUS_RETENTION,
+};
+
+pub mod migration;
+
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
 pub mod types {
     #[doc(inline)]
@@ -0,0 +1,420 @@
+//! Provides the versioning of the layout of the paths under which hosts store
+//! the IBC state, and the [`StoreMigrator`] rewriting the stored keys when
+//! this layout changes across ibc-rs releases.
+//!
+//! The version of the layout of a store is marked under the
+//! [`STORE_LAYOUT_VERSION_KEY`] key, a store without marker being at version
+//! 0. Hosts upgrading ibc-rs run the migrator once, e.g. in their upgrade
+//! handler, which applies the key migrations of every version between the
+//! marked one and [`STORE_LAYOUT_VERSION`], and then marks the store as being
+//! at the latest version.
+//!
+//! The current layout is still the one of version 0, so that the migrator has
+//! nothing to apply yet. The example below shows the kind of migration a
+//! release moving to version 1 would register.
+//!
+//! Example
+//! ```rust,ignore
+//! /// Moves the consensus states of the clients from
+//! /// `clients/{client_id}/consensusStates/{height}` to
+//! /// `clients/{client_id}/consensusStates/0-{height}`.
+//! struct ConsensusStateRevisionMigration;
+//!
+//! impl KeyMigration for ConsensusStateRevisionMigration {
+//!     fn source_version(&self) -> u64 {
+//!         0
+//!     }
+//!
+//!     fn prefix(&self) -> &str {
+//!         CLIENT_PREFIX
+//!     }
+//!
+//!     fn migrate_key(&self, key: &str) -> Option<String> {
+//!         let (parent, height) = key.rsplit_once(&format!("/{CONSENSUS_STATE_PREFIX}/"))?;
+//!         height
+//!             .parse::<u64>()
+//!             .ok()
+//!             .map(|height| format!("{parent}/{CONSENSUS_STATE_PREFIX}/0-{height}"))
+//!     }
+//! }
+//!
+//! let migrated = StoreMigrator::new()
+//!     .with_migration(ConsensusStateRevisionMigration)
+//!     .migrate(&mut store)?;
+//! ```
+
+use displaydoc::Display;
+use ibc_primitives::prelude::*;
+
+/// The version of the layout of the paths of the IBC store of this release.
+///
+/// Version 0 is the layout of the stores predating the layout versioning,
+/// which this release keeps: no key migration is shipped yet, and the version
+/// is only bumped by the release changing the layout, together with the
+/// migration from the previous one.
+pub const STORE_LAYOUT_VERSION: u64 = 0;
+
+/// The key under which the version of the layout of the IBC store is marked,
+/// encoded as a big-endian `u64`.
+pub const STORE_LAYOUT_VERSION_KEY: &str = "storeLayoutVersion";
+
+/// Errors that may occur while migrating the keys of the IBC store.
+#[derive(Debug, Display)]
+pub enum MigrationError {
+    /// store error: `{description}`
+    Store { description: String },
+    /// invalid store layout version marker: `{description}`
+    InvalidVersionMarker { description: String },
+    /// store layout version `{stored}` is newer than the supported version `{supported}`
+    UnsupportedVersion { stored: u64, supported: u64 },
+    /// migrated key `{key}` is already in use
+    KeyConflict { key: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MigrationError {}
+
+/// Raw access to the key-value store under which the host stores the IBC
+/// state, as needed to migrate its keys.
+pub trait MigrationStore {
+    type Error: ToString;
+
+    /// Returns the keys stored under the given prefix, in any order.
+    fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error>;
+
+    /// Returns the value stored under the given key, if any.
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// Stores the value under the given key.
+    fn set(&mut self, key: String, value: Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Deletes the value stored under the given key.
+    fn delete(&mut self, key: &str) -> Result<(), Self::Error>;
+}
+
+/// A rewrite of the keys of the IBC store, from a layout version to the next.
+pub trait KeyMigration {
+    /// Returns the layout version the keys are migrated from.
+    fn source_version(&self) -> u64;
+
+    /// Returns the prefix of the keys to walk, e.g. `clients`.
+    fn prefix(&self) -> &str;
+
+    /// Returns the key under which the value stored under `key` must be
+    /// moved, or `None` if it stays where it is.
+    fn migrate_key(&self, key: &str) -> Option<String>;
+}
+
+/// Walks and rewrites the keys of the IBC store of a host, from the marked
+/// layout version to [`STORE_LAYOUT_VERSION`] or to a given version.
+#[derive(Default)]
+pub struct StoreMigrator {
+    migrations: Vec<Box<dyn KeyMigration>>,
+}
+
+impl StoreMigrator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a key migration, applied after the ones registered before
+    /// for the same version.
+    pub fn with_migration(mut self, migration: impl KeyMigration + 'static) -> Self {
+        self.migrations.push(Box::new(migration));
+        self
+    }
+
+    /// Returns the layout version marked in the store.
+    pub fn stored_version<S: MigrationStore>(store: &S) -> Result<u64, MigrationError> {
+        let marker = match store.get(STORE_LAYOUT_VERSION_KEY).map_err(store_error)? {
+            Some(marker) => marker,
+            None => return Ok(0),
+        };
+
+        let bytes = <[u8; 8]>::try_from(marker.as_slice()).map_err(|_| {
+            MigrationError::InvalidVersionMarker {
+                description: format!("expected 8 bytes, got {}", marker.len()),
+            }
+        })?;
+
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Applies the migrations of every layout version from the marked one to
+    /// [`STORE_LAYOUT_VERSION`], marks the store as being at this version, and
+    /// returns the number of moved values.
+    ///
+    /// Migrating a store already at the latest version does nothing. On
+    /// error, the store may be partially migrated, and must be reverted by the
+    /// host, e.g. by failing the upgrade.
+    pub fn migrate<S: MigrationStore>(&self, store: &mut S) -> Result<usize, MigrationError> {
+        self.migrate_to(store, STORE_LAYOUT_VERSION)
+    }
+
+    /// Applies the migrations of every layout version from the marked one to
+    /// `target_version`, marks the store as being at this version, and returns
+    /// the number of moved values.
+    ///
+    /// Hosts call [`Self::migrate`] instead, unless they run their own layout
+    /// versions on top of the ones of ibc-rs.
+    pub fn migrate_to<S: MigrationStore>(
+        &self,
+        store: &mut S,
+        target_version: u64,
+    ) -> Result<usize, MigrationError> {
+        let stored = Self::stored_version(store)?;
+
+        if stored > target_version {
+            return Err(MigrationError::UnsupportedVersion {
+                stored,
+                supported: target_version,
+            });
+        }
+
+        let mut moved = 0;
+
+        for version in stored..target_version {
+            for migration in self
+                .migrations
+                .iter()
+                .filter(|migration| migration.source_version() == version)
+            {
+                moved += apply_migration(store, migration.as_ref())?;
+            }
+        }
+
+        if stored != target_version {
+            store
+                .set(
+                    STORE_LAYOUT_VERSION_KEY.to_string(),
+                    target_version.to_be_bytes().to_vec(),
+                )
+                .map_err(store_error)?;
+        }
+
+        Ok(moved)
+    }
+}
+
+/// Moves the values whose keys are rewritten by the given migration, and
+/// returns their number.
+fn apply_migration<S: MigrationStore>(
+    store: &mut S,
+    migration: &dyn KeyMigration,
+) -> Result<usize, MigrationError> {
+    let mut keys = store.keys(migration.prefix()).map_err(store_error)?;
+
+    // Walks the keys in a deterministic order, as required for the state
+    // transitions of the host.
+    keys.sort();
+
+    let mut moved = 0;
+
+    for key in keys {
+        let new_key = match migration.migrate_key(&key) {
+            Some(new_key) if new_key != key => new_key,
+            _ => continue,
+        };
+
+        if store.get(&new_key).map_err(store_error)?.is_some() {
+            return Err(MigrationError::KeyConflict { key: new_key });
+        }
+
+        let value = match store.get(&key).map_err(store_error)? {
+            Some(value) => value,
+            None => continue,
+        };
+
+        store.set(new_key, value).map_err(store_error)?;
+        store.delete(&key).map_err(store_error)?;
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+fn store_error(e: impl ToString) -> MigrationError {
+    MigrationError::Store {
+        description: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The layout version the test migration moves the keys to.
+    const TARGET_VERSION: u64 = 1;
+
+    #[derive(Default)]
+    struct MemStore(BTreeMap<String, Vec<u8>>);
+
+    impl MigrationStore for MemStore {
+        type Error = String;
+
+        fn keys(&self, prefix: &str) -> Result<Vec<String>, String> {
+            Ok(self
+                .0
+                .keys()
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect())
+        }
+
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+            Ok(self.0.get(key).cloned())
+        }
+
+        fn set(&mut self, key: String, value: Vec<u8>) -> Result<(), String> {
+            self.0.insert(key, value);
+            Ok(())
+        }
+
+        fn delete(&mut self, key: &str) -> Result<(), String> {
+            self.0.remove(key);
+            Ok(())
+        }
+    }
+
+    /// Moves `clients/{client_id}/consensusStates/{height}` keys to
+    /// `clients/{client_id}/consensusStates/0-{height}`.
+    struct ConsensusStateRevisionMigration;
+
+    impl KeyMigration for ConsensusStateRevisionMigration {
+        fn source_version(&self) -> u64 {
+            0
+        }
+
+        fn prefix(&self) -> &str {
+            "clients"
+        }
+
+        fn migrate_key(&self, key: &str) -> Option<String> {
+            let (parent, height) = key.rsplit_once("/consensusStates/")?;
+            height
+                .parse::<u64>()
+                .ok()
+                .map(|height| format!("{parent}/consensusStates/0-{height}"))
+        }
+    }
+
+    fn legacy_store() -> MemStore {
+        let mut store = MemStore::default();
+        store
+            .set("clients/07-tendermint-0/clientState".to_string(), vec![0])
+            .unwrap();
+        store
+            .set(
+                "clients/07-tendermint-0/consensusStates/10".to_string(),
+                vec![1],
+            )
+            .unwrap();
+        store
+            .set(
+                "clients/07-tendermint-0/consensusStates/20".to_string(),
+                vec![2],
+            )
+            .unwrap();
+        store
+            .set("connections/connection-0".to_string(), vec![3])
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn test_migrate_legacy_store() {
+        let mut store = legacy_store();
+        let migrator = StoreMigrator::new().with_migration(ConsensusStateRevisionMigration);
+
+        assert_eq!(migrator.migrate_to(&mut store, TARGET_VERSION).unwrap(), 2);
+        assert_eq!(
+            StoreMigrator::stored_version(&store).unwrap(),
+            TARGET_VERSION
+        );
+        assert_eq!(
+            store
+                .get("clients/07-tendermint-0/consensusStates/0-10")
+                .unwrap(),
+            Some(vec![1])
+        );
+        assert_eq!(
+            store
+                .get("clients/07-tendermint-0/consensusStates/0-20")
+                .unwrap(),
+            Some(vec![2])
+        );
+        assert_eq!(
+            store
+                .get("clients/07-tendermint-0/consensusStates/10")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            store.get("connections/connection-0").unwrap(),
+            Some(vec![3])
+        );
+
+        // Migrating an up-to-date store does nothing.
+        assert_eq!(migrator.migrate_to(&mut store, TARGET_VERSION).unwrap(), 0);
+    }
+
+    #[rstest]
+    #[case::newer_version((TARGET_VERSION + 1).to_be_bytes().to_vec())]
+    #[case::invalid_marker(vec![1])]
+    fn test_migrate_invalid_version(#[case] marker: Vec<u8>) {
+        let mut store = legacy_store();
+        store
+            .set(STORE_LAYOUT_VERSION_KEY.to_string(), marker)
+            .unwrap();
+
+        assert!(StoreMigrator::new()
+            .with_migration(ConsensusStateRevisionMigration)
+            .migrate_to(&mut store, TARGET_VERSION)
+            .is_err());
+        assert_eq!(
+            store
+                .get("clients/07-tendermint-0/consensusStates/10")
+                .unwrap(),
+            Some(vec![1])
+        );
+    }
+
+    #[test]
+    fn test_migrate_key_conflict() {
+        let mut store = legacy_store();
+        store
+            .set(
+                "clients/07-tendermint-0/consensusStates/0-10".to_string(),
+                vec![4],
+            )
+            .unwrap();
+
+        assert!(matches!(
+            StoreMigrator::new()
+                .with_migration(ConsensusStateRevisionMigration)
+                .migrate_to(&mut store, TARGET_VERSION),
+            Err(MigrationError::KeyConflict { .. })
+        ));
+    }
+
+    #[test]
+    fn test_migrate_current_layout() {
+        let mut store = legacy_store();
+        let migrator = StoreMigrator::new().with_migration(ConsensusStateRevisionMigration);
+
+        // The migrations of later layout versions are not applied.
+        assert_eq!(migrator.migrate(&mut store).unwrap(), 0);
+        assert_eq!(
+            StoreMigrator::stored_version(&store).unwrap(),
+            STORE_LAYOUT_VERSION
+        );
+        assert_eq!(
+            store
+                .get("clients/07-tendermint-0/consensusStates/10")
+                .unwrap(),
+            Some(vec![1])
+        );
+    }
+}
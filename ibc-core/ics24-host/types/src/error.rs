@@ -10,6 +10,12 @@ pub enum IdentifierError {
     InvalidCharacter { id: String },
     /// identifier prefix `{prefix}` is invalid
     InvalidPrefix { prefix: String },
+    /// identifier `{id}` is not of the form `{prefix}-{{counter}}`
+    NonCanonicalIdentifier { id: String, prefix: String },
+    /// identifier `{id}` is forbidden by the host
+    ForbiddenIdentifier { id: String },
+    /// invalid identifier length limits `{min}`-`{max}`; must be within 1-128 characters
+    InvalidLengthLimits { min: u64, max: u64 },
     /// chain identifier is not formatted with revision number
     UnformattedRevisionNumber { chain_id: String },
     /// revision number overflowed
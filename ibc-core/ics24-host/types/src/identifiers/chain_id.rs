@@ -17,7 +17,9 @@ use crate::validate::{
 /// Creating `ChainId`s not in this format will result in an error.
 ///
 /// It should be noted this format is not standardized yet, though it is widely
-/// accepted and compatible with Cosmos SDK driven chains.
+/// accepted and compatible with Cosmos SDK driven chains. Chains formatting
+/// their identifiers otherwise are supported with
+/// [`ChainId::new_with_format`].
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -35,6 +37,65 @@ pub struct ChainId {
     revision_number: u64,
 }
 
+/// The format in which a chain identifier carries the revision number of the
+/// chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevisionFormat {
+    /// The identifier is formatted as {chain name}{delimiter}{revision number},
+    /// e.g. `cosmoshub-4` with the `-` delimiter of the Cosmos SDK chains. An
+    /// identifier not in this format has a revision number of 0.
+    Delimited(char),
+    /// The identifier carries no revision number, which is always 0, even if
+    /// it ends with a number, e.g. `evmos-9001`.
+    Revisionless,
+}
+
+impl RevisionFormat {
+    /// Returns the format in which the given chain identifier carries the
+    /// given revision number, if any, preferring the default format.
+    ///
+    /// Any identifier is compatible with the revision number 0, in the
+    /// [`Revisionless`](Self::Revisionless) format if not in the default one,
+    /// so the format cannot be told from the identifier for that revision.
+    /// Parties knowing the format, e.g. the light clients of a chain, should
+    /// therefore store it rather than infer it.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::RevisionFormat;
+    ///
+    /// assert_eq!(RevisionFormat::infer("chainA-1", 1), Some(RevisionFormat::Delimited('-')));
+    /// assert_eq!(RevisionFormat::infer("chainA_1", 1), Some(RevisionFormat::Delimited('_')));
+    /// assert_eq!(RevisionFormat::infer("chainA-1", 0), Some(RevisionFormat::Revisionless));
+    /// assert_eq!(RevisionFormat::infer("chainA-1", 2), None);
+    /// ```
+    pub fn infer(chain_id: &str, revision_number: u64) -> Option<Self> {
+        let default_revision_number = parse_chain_id_string(chain_id)
+            .map(|(_, revision_number)| revision_number)
+            .unwrap_or(0);
+
+        if default_revision_number == revision_number {
+            return Some(Self::default());
+        }
+
+        if revision_number == 0 {
+            return Some(Self::Revisionless);
+        }
+
+        chain_id
+            .strip_suffix(revision_number.to_string().as_str())
+            .and_then(|chain_name| chain_name.chars().last())
+            .filter(|delimiter| !delimiter.is_ascii_alphanumeric())
+            .map(Self::Delimited)
+    }
+}
+
+impl Default for RevisionFormat {
+    fn default() -> Self {
+        Self::Delimited('-')
+    }
+}
+
 impl ChainId {
     /// Creates a new `ChainId` with the given chain identifier.
     ///
@@ -63,13 +124,117 @@ impl ChainId {
         Self::from_str(chain_id)
     }
 
+    /// Creates a new `ChainId` with the given chain identifier, whose revision
+    /// number is parsed according to the given format rather than to the
+    /// {chain name}-{revision number} format.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::{ChainId, RevisionFormat};
+    ///
+    /// let id = ChainId::new_with_format("chainA_12", RevisionFormat::Delimited('_')).unwrap();
+    /// assert_eq!(id.revision_number(), 12);
+    ///
+    /// let id = ChainId::new_with_format("chainA-12", RevisionFormat::Revisionless).unwrap();
+    /// assert_eq!(id.revision_number(), 0);
+    /// assert_eq!(id.as_str(), "chainA-12");
+    /// ```
+    pub fn new_with_format(
+        chain_id: &str,
+        format: RevisionFormat,
+    ) -> Result<Self, IdentifierError> {
+        // Validates the chain name for allowed characters according to ICS-24.
+        validate_identifier_chars(chain_id)?;
+
+        let parsed = match format {
+            RevisionFormat::Delimited(delimiter) => {
+                parse_chain_id_string_with_delimiter(chain_id, delimiter).ok()
+            }
+            RevisionFormat::Revisionless => None,
+        };
+
+        match parsed {
+            Some((chain_name, revision_number)) => {
+                // Validate if the chain name with revision number has a valid length.
+                validate_prefix_length(chain_name, 1, 64)?;
+                Ok(Self {
+                    id: chain_id.into(),
+                    revision_number,
+                })
+            }
+            None => {
+                // Validate if the identifier has a valid length.
+                validate_identifier_length(chain_id, 1, 64)?;
+                Ok(Self {
+                    id: chain_id.into(),
+                    revision_number: 0,
+                })
+            }
+        }
+    }
+
     /// Get a reference to the underlying string.
     pub fn as_str(&self) -> &str {
         &self.id
     }
 
+    /// Splits the identifier into its chain name and revision number, if it
+    /// carries its revision number, in any [`RevisionFormat`].
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::{ChainId, RevisionFormat};
+    ///
+    /// let id = ChainId::new_with_format("chainA_12", RevisionFormat::Delimited('_')).unwrap();
+    /// assert_eq!(id.split_chain_id().unwrap(), ("chainA", 12));
+    ///
+    /// let id = ChainId::new_with_format("chainA-12", RevisionFormat::Revisionless).unwrap();
+    /// assert!(id.split_chain_id().is_err());
+    /// ```
     pub fn split_chain_id(&self) -> Result<(&str, u64), IdentifierError> {
-        parse_chain_id_string(self.as_str())
+        match self.revision_delimiter() {
+            Some(delimiter) => parse_chain_id_string_with_delimiter(self.as_str(), delimiter),
+            None => Err(IdentifierError::UnformattedRevisionNumber {
+                chain_id: self.id.clone(),
+            }),
+        }
+    }
+
+    /// Returns the delimiter preceding the revision number in the identifier,
+    /// if it carries its revision number.
+    fn revision_delimiter(&self) -> Option<char> {
+        match RevisionFormat::infer(&self.id, self.revision_number)? {
+            RevisionFormat::Delimited(delimiter) => {
+                parse_chain_id_string_with_delimiter(&self.id, delimiter)
+                    .ok()
+                    .map(|_| delimiter)
+            }
+            RevisionFormat::Revisionless => None,
+        }
+    }
+
+    /// Returns the format in which the identifier carries its revision number.
+    ///
+    /// Identifiers which carry no revision number in the default format, e.g.
+    /// `chainA`, are parsed the same in all the formats, and are reported in
+    /// the default one.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::{ChainId, RevisionFormat};
+    ///
+    /// let id = ChainId::new_with_format("chainA_12", RevisionFormat::Delimited('_')).unwrap();
+    /// assert_eq!(id.revision_format(), RevisionFormat::Delimited('_'));
+    ///
+    /// let id = ChainId::new_with_format("chainA-12", RevisionFormat::Revisionless).unwrap();
+    /// assert_eq!(id.revision_format(), RevisionFormat::Revisionless);
+    ///
+    /// let id = ChainId::new_with_format("chainA", RevisionFormat::Revisionless).unwrap();
+    /// assert_eq!(id.revision_format(), RevisionFormat::default());
+    /// ```
+    pub fn revision_format(&self) -> RevisionFormat {
+        match self.revision_delimiter() {
+            Some(delimiter) => RevisionFormat::Delimited(delimiter),
+            None if parse_chain_id_string(&self.id).is_err() => RevisionFormat::default(),
+            None => RevisionFormat::Revisionless,
+        }
     }
 
     /// Extract the revision number from the chain identifier
@@ -78,9 +243,9 @@ impl ChainId {
     }
 
     /// Increases `ChainId`s revision number by one.
-    /// Fails if the chain identifier is not in
-    /// `{chain_name}-{revision_number}` format or
-    /// the revision number overflows.
+    /// Fails if the chain identifier does not carry its revision number, e.g.
+    /// `chainA` or a [`RevisionFormat::Revisionless`] one, or if the revision
+    /// number overflows.
     ///
     /// ```
     /// use ibc_core_host_types::identifiers::ChainId;
@@ -95,11 +260,12 @@ impl ChainId {
     /// ```
     pub fn increment_revision_number(&mut self) -> Result<(), IdentifierError> {
         let (chain_name, _) = self.split_chain_id()?;
+        let delimiter = self.revision_delimiter().unwrap_or('-');
         let inc_revision_number = self
             .revision_number
             .checked_add(1)
             .ok_or(IdentifierError::RevisionNumberOverflow)?;
-        self.id = format!("{}-{}", chain_name, inc_revision_number);
+        self.id = format!("{}{}{}", chain_name, delimiter, inc_revision_number);
         self.revision_number = inc_revision_number;
         Ok(())
     }
@@ -231,20 +397,11 @@ mod borsh_impls {
         fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
             let (id, revision_number) = <(String, u64)>::deserialize_reader(reader)?;
 
-            match parse_chain_id_string(&id) {
-                Ok((_, rn)) => {
-                    if revision_number != 0 && rn != revision_number {
-                        return Err(Error::new(
-                            ErrorKind::Other,
-                            "chain ID revision numbers do not match",
-                        ));
-                    }
-                }
-                _ => {
-                    if revision_number != 0 {
-                        return Err(Error::new(ErrorKind::Other, "failed to parse chain ID"));
-                    }
-                }
+            if RevisionFormat::infer(&id, revision_number).is_none() {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    "chain ID revision numbers do not match",
+                ));
             }
 
             Ok(ChainId {
@@ -261,29 +418,7 @@ impl FromStr for ChainId {
     type Err = IdentifierError;
 
     fn from_str(id: &str) -> Result<Self, Self::Err> {
-        // Identifier string must have a maximum length of 64 characters.
-
-        // Validates the chain name for allowed characters according to ICS-24.
-        validate_identifier_chars(id)?;
-        match parse_chain_id_string(id) {
-            Ok((chain_name, revision_number)) => {
-                // Validate if the chain name with revision number has a valid length.
-                validate_prefix_length(chain_name, 1, 64)?;
-                Ok(Self {
-                    id: id.into(),
-                    revision_number,
-                })
-            }
-
-            _ => {
-                // Validate if the identifier has a valid length.
-                validate_identifier_length(id, 1, 64)?;
-                Ok(Self {
-                    id: id.into(),
-                    revision_number: 0,
-                })
-            }
-        }
+        Self::new_with_format(id, RevisionFormat::default())
     }
 }
 
@@ -302,8 +437,18 @@ impl Display for ChainId {
 /// Parses a string intended to represent a `ChainId` and, if successful,
 /// returns a tuple containing the chain name and revision number.
 fn parse_chain_id_string(chain_id_str: &str) -> Result<(&str, u64), IdentifierError> {
+    parse_chain_id_string_with_delimiter(chain_id_str, '-')
+}
+
+/// Parses a string intended to represent a `ChainId` in the {chain
+/// name}{delimiter}{revision number} format and, if successful, returns a tuple
+/// containing the chain name and revision number.
+fn parse_chain_id_string_with_delimiter(
+    chain_id_str: &str,
+    delimiter: char,
+) -> Result<(&str, u64), IdentifierError> {
     chain_id_str
-        .rsplit_once('-')
+        .rsplit_once(delimiter)
         .filter(|(_, rev_number_str)| {
             // Validates the revision number not to start with leading zeros, like "01".
             // Zero is the only allowed revision number with leading zero.
@@ -393,6 +538,28 @@ mod tests {
         assert!(ChainId::new(chain_id_str).is_err());
     }
 
+    #[rstest]
+    #[case("chainA_1", RevisionFormat::Delimited('_'), 1)]
+    #[case("chainA.12", RevisionFormat::Delimited('.'), 12)]
+    #[case("chainA-1", RevisionFormat::Delimited('_'), 0)]
+    #[case("chainA_01", RevisionFormat::Delimited('_'), 0)]
+    #[case("chainA-1", RevisionFormat::Revisionless, 0)]
+    #[case("chainA", RevisionFormat::Revisionless, 0)]
+    fn test_chain_id_with_format(
+        #[case] raw_chain_id: &str,
+        #[case] format: RevisionFormat,
+        #[case] revision_number: u64,
+    ) {
+        let chain_id = ChainId::new_with_format(raw_chain_id, format).unwrap();
+        assert_eq!(chain_id.as_str(), raw_chain_id);
+        assert_eq!(chain_id.revision_number(), revision_number);
+        assert_eq!(
+            RevisionFormat::infer(raw_chain_id, revision_number)
+                .map(|format| ChainId::new_with_format(raw_chain_id, format).unwrap()),
+            Some(chain_id)
+        );
+    }
+
     #[test]
     fn test_inc_revision_number() {
         let mut chain_id = ChainId::new("chainA-1").unwrap();
@@ -407,12 +574,25 @@ mod tests {
     }
 
     #[test]
-    fn test_failed_inc_revision_number() {
-        let mut chain_id = ChainId::new("chainA").unwrap();
+    fn test_inc_revision_number_with_format() {
+        let mut chain_id =
+            ChainId::new_with_format("chainA_1", RevisionFormat::Delimited('_')).unwrap();
+
+        assert!(chain_id.increment_revision_number().is_ok());
+        assert_eq!(chain_id.revision_number(), 2);
+        assert_eq!(chain_id.as_str(), "chainA_2");
+    }
+
+    #[rstest]
+    #[case(ChainId::new("chainA").unwrap())]
+    #[case(ChainId::new_with_format("chainA-1", RevisionFormat::Revisionless).unwrap())]
+    fn test_failed_inc_revision_number(#[case] mut chain_id: ChainId) {
+        let raw_chain_id = chain_id.as_str().to_string();
 
+        assert!(chain_id.split_chain_id().is_err());
         assert!(chain_id.increment_revision_number().is_err());
         assert_eq!(chain_id.revision_number(), 0);
-        assert_eq!(chain_id.as_str(), "chainA");
+        assert_eq!(chain_id.as_str(), raw_chain_id);
     }
 
     #[cfg(feature = "serde")]
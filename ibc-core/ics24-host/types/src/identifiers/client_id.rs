@@ -3,8 +3,9 @@ use core::str::FromStr;
 use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::ClientType;
 use crate::error::IdentifierError;
-use crate::validate::{validate_client_identifier, validate_client_type};
+use crate::validate::{validate_client_identifier, validate_client_type, IdentifierPolicy};
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -66,6 +67,35 @@ impl ClientId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Returns `true` if the identifier is formed from the given client type,
+    /// as in `{client type}-{counter}`.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::{ClientId, ClientType};
+    /// # use std::str::FromStr;
+    /// let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+    /// assert!(client_id.is_of_type(&ClientType::from_str("07-tendermint").unwrap()));
+    /// assert!(!client_id.is_of_type(&ClientType::from_str("07-tender").unwrap()));
+    /// ```
+    pub fn is_of_type(&self, client_type: &ClientType) -> bool {
+        self.0
+            .strip_prefix(client_type.as_str())
+            .and_then(|suffix| suffix.strip_prefix('-'))
+            .and_then(|counter| counter.parse::<u64>().ok())
+            .is_some()
+    }
+
+    /// Parses a client identifier, validating it with the given host-specific
+    /// policy instead of the ICS-24 rules of the `FromStr` implementation.
+    pub fn from_str_with_policy(
+        s: &str,
+        policy: &(impl IdentifierPolicy + ?Sized),
+    ) -> Result<Self, IdentifierError> {
+        policy
+            .validate_client_identifier(s)
+            .map(|_| Self(s.to_string()))
+    }
 }
 
 impl FromStr for ClientId {
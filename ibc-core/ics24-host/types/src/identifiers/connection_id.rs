@@ -5,7 +5,7 @@ use derive_more::Into;
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError;
-use crate::validate::validate_connection_identifier;
+use crate::validate::{validate_connection_identifier, IdentifierPolicy};
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
@@ -56,6 +56,17 @@ impl ConnectionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Parses a connection identifier, validating it with the given host-specific
+    /// policy instead of the ICS-24 rules of the `FromStr` implementation.
+    pub fn from_str_with_policy(
+        s: &str,
+        policy: &(impl IdentifierPolicy + ?Sized),
+    ) -> Result<Self, IdentifierError> {
+        policy
+            .validate_connection_identifier(s)
+            .map(|_| Self(s.to_string()))
+    }
 }
 
 /// This implementation provides a `to_string` method.
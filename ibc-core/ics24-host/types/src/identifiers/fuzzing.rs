@@ -0,0 +1,87 @@
+//! Implements `Arbitrary` for the identifiers, generating only values that
+//! pass the ICS-24 validation so that fuzzers reach past message decoding.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use ibc_primitives::prelude::*;
+
+use super::{ChainId, ChannelId, ClientId, ClientType, ConnectionId, PortId};
+
+const ALPHANUMERIC_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+const IDENTIFIER_CHARS: &[u8] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789._+-#[]<>";
+
+/// Generates a string of `min..=max` characters drawn from `chars`.
+fn string_of(u: &mut Unstructured<'_>, chars: &[u8], min: usize, max: usize) -> Result<String> {
+    let length = u.int_in_range(min..=max)?;
+    (0..length)
+        .map(|_| u.choose(chars).map(|c| char::from(*c)))
+        .collect()
+}
+
+impl<'a> Arbitrary<'a> for ClientType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // A client type is the prefix of a client identifier, which must
+        // leave room for the `-{counter}` suffix.
+        let client_type = string_of(u, IDENTIFIER_CHARS, 7, 43)?;
+        ClientType::new(&client_type).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClientId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ClientType::arbitrary(u)?.build_client_id(u64::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ConnectionId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConnectionId::new(u64::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ChannelId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ChannelId::new(u64::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PortId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let port_id = string_of(u, IDENTIFIER_CHARS, 2, 128)?;
+        PortId::new(port_id).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ChainId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let chain_name = string_of(u, ALPHANUMERIC_CHARS, 1, 32)?;
+        let chain_id = match Option::<u64>::arbitrary(u)? {
+            Some(revision_number) => format!("{chain_name}-{revision_number}"),
+            None => chain_name,
+        };
+        ChainId::new(&chain_id).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary_identifiers_are_valid() {
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..16 {
+            let client_id = ClientId::arbitrary(&mut u).unwrap();
+            assert!(client_id.as_str().parse::<ClientId>().is_ok());
+
+            let port_id = PortId::arbitrary(&mut u).unwrap();
+            assert!(port_id.as_str().parse::<PortId>().is_ok());
+
+            let chain_id = ChainId::arbitrary(&mut u).unwrap();
+            assert_eq!(chain_id.as_str().parse::<ChainId>().unwrap(), chain_id);
+        }
+    }
+}
@@ -8,10 +8,13 @@ mod connection_id;
 mod port_id;
 mod sequence;
 
-pub use chain_id::ChainId;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+pub use chain_id::{ChainId, RevisionFormat};
 pub use channel_id::ChannelId;
 pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
-pub use port_id::PortId;
+pub use port_id::{PortId, PortPrefix};
 pub use sequence::Sequence;
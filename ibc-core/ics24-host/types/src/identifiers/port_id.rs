@@ -5,9 +5,11 @@ use derive_more::Into;
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError;
-use crate::validate::validate_port_identifier;
+use crate::validate::{validate_identifier_chars, validate_port_identifier, IdentifierPolicy};
 
 const TRANSFER_PORT_ID: &str = "transfer";
+const WASM_PORT_PREFIX: &str = "wasm.";
+const ICA_CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -49,6 +51,23 @@ impl PortId {
     pub fn validate(&self) -> Result<(), IdentifierError> {
         validate_port_identifier(self.as_str())
     }
+
+    /// Checks whether the port identifier belongs to the namespace of the
+    /// given prefix, e.g. whether it is the port of a CosmWasm contract.
+    pub fn has_prefix(&self, prefix: &PortPrefix) -> bool {
+        prefix.owner_of(self).is_some()
+    }
+
+    /// Parses a port identifier, validating it with the given host-specific
+    /// policy instead of the ICS-24 rules of the `FromStr` implementation.
+    pub fn from_str_with_policy(
+        s: &str,
+        policy: &(impl IdentifierPolicy + ?Sized),
+    ) -> Result<Self, IdentifierError> {
+        policy
+            .validate_port_identifier(s)
+            .map(|_| Self(s.to_string()))
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -71,3 +90,108 @@ impl AsRef<str> for PortId {
         self.0.as_str()
     }
 }
+
+/// The prefix of a namespace of port identifiers derived from their owners,
+/// such as `wasm.{contract_address}` for the ports of CosmWasm contracts, or
+/// `icacontroller-{owner}` for the ports of interchain account controllers.
+///
+/// The prefix includes the separator between the namespace and the owner,
+/// e.g. `wasm.`, so that the port identifiers in the namespace are the ones
+/// starting with it and followed by a non-empty owner.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortPrefix(String);
+
+impl PortPrefix {
+    /// Returns the given port prefix, which must only contain the characters
+    /// allowed in identifiers.
+    pub fn new(prefix: String) -> Result<Self, IdentifierError> {
+        if prefix.is_empty() || validate_identifier_chars(&prefix).is_err() {
+            return Err(IdentifierError::InvalidPrefix { prefix });
+        }
+
+        Ok(Self(prefix))
+    }
+
+    /// The prefix of the ports assigned to CosmWasm contracts by `wasmd`.
+    pub fn wasm() -> Self {
+        Self(WASM_PORT_PREFIX.to_string())
+    }
+
+    /// The prefix of the ports of interchain account controllers.
+    pub fn ica_controller() -> Self {
+        Self(ICA_CONTROLLER_PORT_PREFIX.to_string())
+    }
+
+    /// Get this prefix as a borrowed `&str`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Derives the port identifier of `owner` in this namespace, e.g.
+    /// `wasm.{contract_address}`.
+    pub fn port_id(&self, owner: &str) -> Result<PortId, IdentifierError> {
+        if owner.is_empty() {
+            return Err(IdentifierError::InvalidPrefix {
+                prefix: self.0.clone(),
+            });
+        }
+
+        PortId::from_str(&format!("{}{owner}", self.0))
+    }
+
+    /// Returns the owner of the given port identifier if it belongs to this
+    /// namespace, e.g. the contract address of a `wasm.{contract_address}`
+    /// port.
+    pub fn owner_of<'a>(&self, port_id: &'a PortId) -> Option<&'a str> {
+        port_id
+            .as_str()
+            .strip_prefix(self.as_str())
+            .filter(|owner| !owner.is_empty())
+    }
+}
+
+impl Display for PortPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for PortPrefix {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+impl AsRef<str> for PortPrefix {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_prefix() {
+        let prefix = PortPrefix::wasm();
+        let port_id = prefix.port_id("cosmos1contract").unwrap();
+
+        assert_eq!(port_id.as_str(), "wasm.cosmos1contract");
+        assert_eq!(prefix.owner_of(&port_id), Some("cosmos1contract"));
+        assert!(port_id.has_prefix(&prefix));
+        assert!(!port_id.has_prefix(&PortPrefix::ica_controller()));
+        assert!(!PortId::transfer().has_prefix(&prefix));
+
+        let bare_prefix_port_id = PortId::from_str("wasm.").unwrap();
+        assert_eq!(prefix.owner_of(&bare_prefix_port_id), None);
+
+        assert!(prefix.port_id("").is_err());
+        assert!(prefix.port_id("invalid/owner").is_err());
+        assert!(PortPrefix::from_str("").is_err());
+        assert!(PortPrefix::from_str("wasm/").is_err());
+    }
+}
@@ -29,6 +29,9 @@ pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+pub const CHANNEL_UPGRADE_PREFIX: &str = "channelUpgrades";
+pub const UPGRADES: &str = "upgrades";
+pub const UPGRADE_ERROR: &str = "upgradeError";
 
 pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
@@ -62,6 +65,8 @@ pub enum Path {
     Commitment(CommitmentPath),
     Ack(AckPath),
     Receipt(ReceiptPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
     UpgradeClient(UpgradeClientPath),
 }
 
@@ -643,6 +648,54 @@ impl ReceiptPath {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADES}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_ERROR}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -723,6 +776,7 @@ impl FromStr for Path {
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .or_else(|| parse_upgrades(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
@@ -1133,6 +1187,35 @@ fn parse_receipts(components: &[&str]) -> Option<Path> {
     )
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if *components.first()? != CHANNEL_UPGRADE_PREFIX {
+        return None;
+    }
+
+    let port_id = if let Some(Path::Ports(PortPath(port_id))) = parse_ports(&components[2..=3]) {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = parse_channels(&components[4..=5])
+    {
+        channel_id
+    } else {
+        return None;
+    };
+
+    match components[1] {
+        UPGRADES => Some(ChannelUpgradePath(port_id, channel_id).into()),
+        UPGRADE_ERROR => Some(ChannelUpgradeErrorPath(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
 fn parse_upgrades(components: &[&str]) -> Option<Path> {
     if components.len() != 3 {
         return None;
@@ -1221,6 +1304,17 @@ mod tests {
         "channelEnds/ports/transfer/channels/channel-0",
         Path::ChannelEnd(ChannelEndPath(PortId::transfer(), ChannelId::default()))
     )]
+    #[case(
+        "channelUpgrades/upgrades/ports/transfer/channels/channel-0",
+        Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::default()))
+    )]
+    #[case(
+        "channelUpgrades/upgradeError/ports/transfer/channels/channel-0",
+        Path::ChannelUpgradeError(ChannelUpgradeErrorPath(
+            PortId::transfer(),
+            ChannelId::default()
+        ))
+    )]
     #[case(
         "nextSequenceSend/ports/transfer/channels/channel-0",
         Path::SeqSend(SeqSendPath(PortId::transfer(), ChannelId::default()))
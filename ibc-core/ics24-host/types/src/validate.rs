@@ -103,6 +103,151 @@ pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_length(id, 8, 64)
 }
 
+/// Checks that the identifier is of the canonical form `{prefix}-{counter}`,
+/// as the identifiers generated by the handlers, e.g. `channel-0`.
+pub fn validate_canonical_identifier(id: &str, prefix: &str) -> Result<(), Error> {
+    let counter = id
+        .strip_prefix(prefix)
+        .and_then(|suffix| suffix.strip_prefix('-'));
+
+    match counter {
+        // Reject the representations of the counter which the handlers do not
+        // generate, e.g. with a sign or leading zeros.
+        Some(counter)
+            if counter
+                .parse::<u64>()
+                .map_or(false, |n| n.to_string() == counter) =>
+        {
+            Ok(())
+        }
+        _ => Err(Error::NonCanonicalIdentifier {
+            id: id.into(),
+            prefix: prefix.into(),
+        }),
+    }
+}
+
+/// A host-specific ruleset for the validation of identifiers, consulted by the
+/// `from_str_with_policy` constructors of the identifiers, e.g.
+/// [`ChannelId::from_str_with_policy`], and by the handshake handlers through
+/// the `identifier_policy` method of the host's `ValidationContext`.
+///
+/// Each method defaults to the ICS-24 rules enforced by the `FromStr`
+/// implementations, so that hosts only override the checks they customize,
+/// e.g. to restrict the allowed characters, to enforce the canonical
+/// `channel-{counter}` form with [`validate_canonical_identifier`], or to
+/// forbid some port names.
+///
+/// [`ChannelId::from_str_with_policy`]: crate::identifiers::ChannelId::from_str_with_policy
+pub trait IdentifierPolicy {
+    /// Validates a client identifier.
+    fn validate_client_identifier(&self, id: &str) -> Result<(), Error> {
+        validate_client_identifier(id)
+    }
+
+    /// Validates a connection identifier.
+    fn validate_connection_identifier(&self, id: &str) -> Result<(), Error> {
+        validate_connection_identifier(id)
+    }
+
+    /// Validates a channel identifier.
+    fn validate_channel_identifier(&self, id: &str) -> Result<(), Error> {
+        validate_channel_identifier(id)
+    }
+
+    /// Validates a port identifier.
+    fn validate_port_identifier(&self, id: &str) -> Result<(), Error> {
+        validate_port_identifier(id)
+    }
+}
+
+/// The ICS-24 rules, as enforced by the `FromStr` implementations of the
+/// identifiers.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Ics24IdentifierPolicy;
+
+impl IdentifierPolicy for Ics24IdentifierPolicy {}
+
+/// The longest identifiers allowed by ICS-24, i.e. the maximum length of the
+/// port identifiers.
+pub const MAX_IDENTIFIER_LENGTH: u64 = 128;
+
+/// The minimum and maximum lengths of a kind of identifiers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthLimits {
+    min: u64,
+    max: u64,
+}
+
+impl LengthLimits {
+    /// Returns the given length limits, which must satisfy
+    /// `1 <= min <= max <= MAX_IDENTIFIER_LENGTH`.
+    pub fn new(min: u64, max: u64) -> Result<Self, Error> {
+        if min == 0 || min > max || max > MAX_IDENTIFIER_LENGTH {
+            return Err(Error::InvalidLengthLimits { min, max });
+        }
+
+        Ok(Self { min, max })
+    }
+
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+}
+
+/// An [`IdentifierPolicy`] checking the characters of the identifiers as
+/// ICS-24 does, but with host-configured length limits, e.g. to accept the
+/// longer port identifiers derived from contract addresses.
+///
+/// The default policy uses the ICS-24 length limits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierLengthPolicy {
+    pub client: LengthLimits,
+    pub connection: LengthLimits,
+    pub channel: LengthLimits,
+    pub port: LengthLimits,
+}
+
+impl Default for IdentifierLengthPolicy {
+    fn default() -> Self {
+        Self {
+            client: LengthLimits { min: 9, max: 64 },
+            connection: LengthLimits { min: 10, max: 64 },
+            channel: LengthLimits { min: 8, max: 64 },
+            port: LengthLimits { min: 2, max: 128 },
+        }
+    }
+}
+
+impl IdentifierLengthPolicy {
+    fn validate(id: &str, limits: &LengthLimits) -> Result<(), Error> {
+        validate_identifier_chars(id)?;
+        validate_identifier_length(id, limits.min, limits.max)
+    }
+}
+
+impl IdentifierPolicy for IdentifierLengthPolicy {
+    fn validate_client_identifier(&self, id: &str) -> Result<(), Error> {
+        Self::validate(id, &self.client)
+    }
+
+    fn validate_connection_identifier(&self, id: &str) -> Result<(), Error> {
+        Self::validate(id, &self.connection)
+    }
+
+    fn validate_channel_identifier(&self, id: &str) -> Result<(), Error> {
+        Self::validate(id, &self.channel)
+    }
+
+    fn validate_port_identifier(&self, id: &str) -> Result<(), Error> {
+        Self::validate(id, &self.port)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -223,6 +368,89 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[rstest]
+    #[case::canonical("channel-0", true)]
+    #[case::max_counter("channel-18446744073709551615", true)]
+    #[case::counter_overflow("channel-18446744073709551616", false)]
+    #[case::signed_counter("channel-+1", false)]
+    #[case::leading_zero("channel-01", false)]
+    #[case::missing_counter("channel-", false)]
+    #[case::missing_separator("channel0", false)]
+    #[case::other_prefix("chan-0", false)]
+    fn test_canonical_identifier_validation(#[case] id: &str, #[case] success: bool) {
+        let result = validate_canonical_identifier(id, "channel");
+        assert_eq!(result.is_ok(), success);
+    }
+
+    struct StrictPolicy;
+
+    impl IdentifierPolicy for StrictPolicy {
+        fn validate_channel_identifier(&self, id: &str) -> Result<(), Error> {
+            validate_channel_identifier(id)?;
+            validate_canonical_identifier(id, "channel")
+        }
+
+        fn validate_port_identifier(&self, id: &str) -> Result<(), Error> {
+            validate_port_identifier(id)?;
+
+            if id == "forbidden" {
+                return Err(Error::ForbiddenIdentifier { id: id.into() });
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_custom_identifier_policy() {
+        assert!(Ics24IdentifierPolicy
+            .validate_channel_identifier("mychannel")
+            .is_ok());
+        assert!(StrictPolicy
+            .validate_channel_identifier("mychannel")
+            .is_err());
+        assert!(StrictPolicy
+            .validate_channel_identifier("channel-7")
+            .is_ok());
+
+        assert!(StrictPolicy.validate_port_identifier("transfer").is_ok());
+        assert!(StrictPolicy.validate_port_identifier("forbidden").is_err());
+
+        assert!(StrictPolicy
+            .validate_client_identifier("07-tendermint-0")
+            .is_ok());
+    }
+
+    #[rstest]
+    #[case::ics24_port(2, 128, true)]
+    #[case::single_char(1, 1, true)]
+    #[case::zero_min(0, 64, false)]
+    #[case::min_greater_than_max(10, 9, false)]
+    #[case::max_too_long(2, 129, false)]
+    fn test_length_limits(#[case] min: u64, #[case] max: u64, #[case] success: bool) {
+        assert_eq!(LengthLimits::new(min, max).is_ok(), success);
+    }
+
+    #[test]
+    fn test_identifier_length_policy() {
+        let default_policy = IdentifierLengthPolicy::default();
+        let channel_id = "channel-with-a-long-name-derived-from-a-contract-address-0123456789";
+
+        assert!(default_policy
+            .validate_channel_identifier(channel_id)
+            .is_err());
+        assert!(validate_channel_identifier(channel_id).is_err());
+
+        let policy = IdentifierLengthPolicy {
+            channel: LengthLimits::new(8, 128).unwrap(),
+            ..Default::default()
+        };
+        assert!(policy.validate_channel_identifier(channel_id).is_ok());
+        assert!(policy.validate_channel_identifier("channel").is_err());
+        assert!(policy.validate_channel_identifier("channel@0").is_err());
+        assert!(policy.validate_client_identifier("client").is_err());
+    }
+
     #[rstest]
     #[case::zero_min_length("", 0, 64, false)]
     #[case::empty_prefix("", 1, 64, false)]
@@ -3,16 +3,19 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_cancel_execute,
+    chan_upgrade_cancel_validate, chan_upgrade_timeout_execute, chan_upgrade_timeout_validate,
+    recv_packet_execute, recv_packet_validate, timeout_packet_execute, timeout_packet_validate,
+    TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
     channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
 };
-use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client::handler::{create_client, update_client, update_params, upgrade_client};
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
+    update_params as update_connection_params,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
 use ibc_core_handler_types::error::ContextError;
@@ -20,6 +23,8 @@ use ibc_core_handler_types::msgs::MsgEnvelope;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::router::Router;
 use ibc_core_router::types::error::RouterError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
 
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch(
@@ -31,6 +36,27 @@ pub fn dispatch(
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which decodes a raw protobuf message, identified by its type
+/// URL, e.g. `/ibc.core.client.v1.MsgCreateClient`, and then performs both its
+/// validation and execution as [`dispatch`] does.
+///
+/// This lets hosts feeding messages from outside of Rust, e.g. Wasm runtimes
+/// or FFI embeddings, do without the `ibc-proto` and domain message types.
+/// Unknown type URLs and malformed bytes are reported as [`RouterError`]s.
+pub fn dispatch_raw(
+    ctx: &mut impl ExecutionContext,
+    router: &mut impl Router,
+    type_url: &str,
+    bytes: &[u8],
+) -> Result<(), ContextError> {
+    let msg = MsgEnvelope::try_from(Any {
+        type_url: type_url.to_string(),
+        value: bytes.to_vec(),
+    })?;
+
+    dispatch(ctx, router, msg)
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -53,12 +79,14 @@ where
                 update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::validate(ctx, msg),
+            ClientMsg::UpdateParams(ref msg) => update_params::validate(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::validate(ctx, msg),
             ConnectionMsg::OpenTry(msg) => conn_open_try::validate(ctx, msg),
             ConnectionMsg::OpenAck(msg) => conn_open_ack::validate(ctx, msg),
             ConnectionMsg::OpenConfirm(ref msg) => conn_open_confirm::validate(ctx, msg),
+            ConnectionMsg::UpdateParams(ref msg) => update_connection_params::validate(ctx, msg),
         },
         MsgEnvelope::Channel(msg) => {
             let port_id = channel_msg_to_port_id(&msg);
@@ -78,6 +106,8 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -124,12 +154,14 @@ where
                 update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::execute(ctx, msg),
+            ClientMsg::UpdateParams(msg) => update_params::execute(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::execute(ctx, msg),
             ConnectionMsg::OpenTry(msg) => conn_open_try::execute(ctx, msg),
             ConnectionMsg::OpenAck(msg) => conn_open_ack::execute(ctx, msg),
             ConnectionMsg::OpenConfirm(ref msg) => conn_open_confirm::execute(ctx, msg),
+            ConnectionMsg::UpdateParams(msg) => update_connection_params::execute(ctx, msg),
         },
         MsgEnvelope::Channel(msg) => {
             let port_id = channel_msg_to_port_id(&msg);
@@ -149,6 +181,8 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -0,0 +1,251 @@
+//! Defines [`IbcEventFilter`], a builder to match [`IbcEvent`]s by type,
+//! channel, client or packet sequence, e.g. when streaming events to relayers
+//! or when a relayer selects the parsed events it handles.
+
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+
+/// A filter matching the [`IbcEvent`]s which satisfy all its criteria.
+///
+/// An empty filter matches all events. Criteria on a field only match the
+/// events carrying that field, e.g. a client criterion never matches packet
+/// events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IbcEventFilter {
+    event_types: Vec<String>,
+    channel: Option<(PortId, ChannelId)>,
+    client_id: Option<ClientId>,
+    sequences: Option<(Sequence, Sequence)>,
+}
+
+impl IbcEventFilter {
+    /// Creates a filter matching all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches the events of the given type, e.g. `send_packet`. Can be
+    /// called several times to match any of the given types.
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_types.push(event_type.into());
+        self
+    }
+
+    /// Matches the channel and packet events of the given channel end, on
+    /// either side of the channel.
+    pub fn channel(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.channel = Some((port_id, channel_id));
+        self
+    }
+
+    /// Matches the client and connection events of the given client, on
+    /// either side of the connection.
+    pub fn client(mut self, client_id: ClientId) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Matches the packet events whose sequence is within the given inclusive
+    /// range.
+    pub fn sequence_range(mut self, start: Sequence, end: Sequence) -> Self {
+        self.sequences = Some((start, end));
+        self
+    }
+
+    /// Returns `true` if the given event satisfies all the criteria of the
+    /// filter.
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        if !self.event_types.is_empty()
+            && !self
+                .event_types
+                .iter()
+                .any(|event_type| event_type == event.event_type())
+        {
+            return false;
+        }
+
+        if let Some((port_id, channel_id)) = &self.channel {
+            if !channel_ends(event)
+                .into_iter()
+                .flatten()
+                .any(|(port, channel)| port == port_id && channel == channel_id)
+            {
+                return false;
+            }
+        }
+
+        if let Some(client_id) = &self.client_id {
+            if !client_ids(event)
+                .into_iter()
+                .flatten()
+                .any(|c| c == client_id)
+            {
+                return false;
+            }
+        }
+
+        if let Some((start, end)) = &self.sequences {
+            match sequence(event) {
+                Some(sequence) if start <= sequence && sequence <= end => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns the events matching the filter.
+    pub fn filter<'a>(
+        &'a self,
+        events: impl IntoIterator<Item = &'a IbcEvent>,
+    ) -> impl Iterator<Item = &'a IbcEvent> {
+        events.into_iter().filter(|event| self.matches(event))
+    }
+}
+
+type ChannelEnd<'a> = Option<(&'a PortId, &'a ChannelId)>;
+
+/// Returns the channel ends carried by the event, on both sides of the
+/// channel.
+fn channel_ends(event: &IbcEvent) -> [ChannelEnd<'_>; 2] {
+    match event {
+        IbcEvent::OpenInitChannel(e) => [Some((e.port_id_on_a(), e.chan_id_on_a())), None],
+        IbcEvent::OpenTryChannel(e) => [
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+        ],
+        IbcEvent::OpenAckChannel(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::OpenConfirmChannel(e) => [
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+        ],
+        IbcEvent::CloseInitChannel(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::CloseConfirmChannel(e) => [
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+        ],
+        IbcEvent::ChannelClosed(e) => [
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+            e.chan_id_on_a().map(|chan_id| (e.port_id_on_a(), chan_id)),
+        ],
+        IbcEvent::SendPacket(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::ReceivePacket(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::WriteAcknowledgement(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::AcknowledgePacket(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        IbcEvent::TimeoutPacket(e) => [
+            Some((e.port_id_on_a(), e.chan_id_on_a())),
+            Some((e.port_id_on_b(), e.chan_id_on_b())),
+        ],
+        _ => [None, None],
+    }
+}
+
+/// Returns the client identifiers carried by the event, on both sides of the
+/// connection.
+fn client_ids(event: &IbcEvent) -> [Option<&ClientId>; 2] {
+    match event {
+        IbcEvent::CreateClient(e) => [Some(e.client_id()), None],
+        IbcEvent::UpdateClient(e) => [Some(e.client_id()), None],
+        IbcEvent::RedundantUpdateClient(e) => [Some(e.client_id()), None],
+        IbcEvent::UpgradeClient(e) => [Some(e.client_id()), None],
+        IbcEvent::ClientMisbehaviour(e) => [Some(e.client_id()), None],
+        IbcEvent::OpenInitConnection(e) => [Some(e.client_id_on_a()), Some(e.client_id_on_b())],
+        IbcEvent::OpenTryConnection(e) => [Some(e.client_id_on_b()), Some(e.client_id_on_a())],
+        IbcEvent::OpenAckConnection(e) => [Some(e.client_id_on_a()), Some(e.client_id_on_b())],
+        IbcEvent::OpenConfirmConnection(e) => [Some(e.client_id_on_b()), Some(e.client_id_on_a())],
+        _ => [None, None],
+    }
+}
+
+/// Returns the sequence of the packet carried by the event, if any.
+fn sequence(event: &IbcEvent) -> Option<&Sequence> {
+    match event {
+        IbcEvent::SendPacket(e) => Some(e.seq_on_a()),
+        IbcEvent::ReceivePacket(e) => Some(e.seq_on_b()),
+        IbcEvent::WriteAcknowledgement(e) => Some(e.seq_on_a()),
+        IbcEvent::AcknowledgePacket(e) => Some(e.seq_on_a()),
+        IbcEvent::TimeoutPacket(e) => Some(e.seq_on_a()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::events::SendPacket;
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_client_types::events::CreateClient;
+    use ibc_core_client_types::Height;
+    use ibc_core_host_types::identifiers::{ClientType, ConnectionId};
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    fn send_packet(sequence: u64) -> IbcEvent {
+        let packet = Packet {
+            seq_on_a: sequence.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: Vec::new().into(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        IbcEvent::SendPacket(SendPacket::new(
+            packet,
+            Order::Unordered,
+            ConnectionId::new(0),
+        ))
+    }
+
+    #[test]
+    fn filter_events() {
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+        let create_client = IbcEvent::CreateClient(CreateClient::new(
+            client_id.clone(),
+            ClientType::new("07-tendermint").unwrap(),
+            Height::new(0, 1).unwrap(),
+        ));
+        let events = [create_client.clone(), send_packet(1), send_packet(5)];
+
+        assert_eq!(IbcEventFilter::new().filter(&events).count(), 3);
+
+        let filter = IbcEventFilter::new().client(client_id);
+        assert_eq!(filter.filter(&events).collect::<Vec<_>>(), [&create_client]);
+
+        let filter = IbcEventFilter::new()
+            .event_type("send_packet")
+            .channel(PortId::transfer(), ChannelId::new(1))
+            .sequence_range(2.into(), 10.into());
+        assert_eq!(
+            filter.filter(&events).collect::<Vec<_>>(),
+            [&send_packet(5)]
+        );
+
+        let filter = IbcEventFilter::new().channel(PortId::transfer(), ChannelId::new(2));
+        assert_eq!(filter.filter(&events).count(), 0);
+    }
+}
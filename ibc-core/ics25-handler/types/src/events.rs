@@ -66,6 +66,7 @@ const MESSAGE_EVENT: &str = "message";
 pub enum IbcEvent {
     CreateClient(ClientEvents::CreateClient),
     UpdateClient(ClientEvents::UpdateClient),
+    RedundantUpdateClient(ClientEvents::RedundantUpdateClient),
     UpgradeClient(ClientEvents::UpgradeClient),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
 
@@ -80,6 +81,8 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimeout),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancel),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -99,6 +102,7 @@ impl TryFrom<IbcEvent> for abci::Event {
         Ok(match event {
             IbcEvent::CreateClient(event) => event.into(),
             IbcEvent::UpdateClient(event) => event.into(),
+            IbcEvent::RedundantUpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
@@ -111,6 +115,8 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
@@ -127,10 +133,20 @@ impl TryFrom<IbcEvent> for abci::Event {
 }
 
 impl IbcEvent {
+    /// Converts the event into an ABCI event, whose attributes are indexed
+    /// according to the given configuration.
+    pub fn try_into_abci_event(self, indexing: &EventIndexing) -> Result<abci::Event, Error> {
+        let mut event = abci::Event::try_from(self)?;
+        indexing.apply(&mut event);
+
+        Ok(event)
+    }
+
     pub fn event_type(&self) -> &str {
         match self {
             IbcEvent::CreateClient(event) => event.event_type(),
             IbcEvent::UpdateClient(event) => event.event_type(),
+            IbcEvent::RedundantUpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
@@ -143,6 +159,8 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
@@ -155,6 +173,80 @@ impl IbcEvent {
     }
 }
 
+/// Controls which attributes of the ABCI events emitted by the host are
+/// indexed by CometBFT, i.e. the `index` flag of their `EventAttribute`s.
+///
+/// Attributes can be configured per event type, or per event type and
+/// attribute key, the latter taking precedence. All the attributes are
+/// indexed by default, as in the plain `abci::Event` conversion.
+#[derive(Clone, Debug)]
+pub struct EventIndexing {
+    index_by_default: bool,
+    events: BTreeMap<String, bool>,
+    attributes: BTreeMap<(String, String), bool>,
+}
+
+impl EventIndexing {
+    /// Indexes all the attributes unless configured otherwise.
+    pub fn index_all() -> Self {
+        Self {
+            index_by_default: true,
+            events: BTreeMap::new(),
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    /// Indexes none of the attributes unless configured otherwise.
+    pub fn index_none() -> Self {
+        Self {
+            index_by_default: false,
+            ..Self::index_all()
+        }
+    }
+
+    /// Sets whether the attributes of events of the given type are indexed.
+    pub fn with_event(mut self, event_type: impl Into<String>, index: bool) -> Self {
+        self.events.insert(event_type.into(), index);
+        self
+    }
+
+    /// Sets whether the attribute with the given key of events of the given
+    /// type is indexed.
+    pub fn with_attribute(
+        mut self,
+        event_type: impl Into<String>,
+        key: impl Into<String>,
+        index: bool,
+    ) -> Self {
+        self.attributes
+            .insert((event_type.into(), key.into()), index);
+        self
+    }
+
+    /// Returns `true` if the attribute with the given key of events of the
+    /// given type is indexed.
+    pub fn is_indexed(&self, event_type: &str, key: &str) -> bool {
+        self.attributes
+            .get(&(event_type.to_string(), key.to_string()))
+            .or_else(|| self.events.get(event_type))
+            .copied()
+            .unwrap_or(self.index_by_default)
+    }
+
+    /// Sets the `index` flag of all the attributes of the given event.
+    pub fn apply(&self, event: &mut abci::Event) {
+        for attribute in &mut event.attributes {
+            attribute.index = self.is_indexed(&event.kind, &attribute.key);
+        }
+    }
+}
+
+impl Default for EventIndexing {
+    fn default() -> Self {
+        Self::index_all()
+    }
+}
+
 /// An event type that is emitted by the Cosmos SDK.
 ///
 /// We need to emit it as well, as currently [hermes] relies on it.
@@ -206,3 +298,31 @@ impl From<ModuleEvent> for IbcEvent {
         IbcEvent::Module(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_indexing() {
+        let event = IbcEvent::Module(ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![("receiver", "cosmos1").into(), ("memo", "").into()],
+        });
+
+        let indexing = EventIndexing::index_none()
+            .with_event("fungible_token_packet", true)
+            .with_attribute("fungible_token_packet", "memo", false);
+        let abci_event = event.try_into_abci_event(&indexing).unwrap();
+
+        let index: Vec<_> = abci_event
+            .attributes
+            .iter()
+            .map(|attribute| attribute.index)
+            .collect();
+        assert_eq!(index, vec![true, false]);
+
+        assert!(EventIndexing::default().is_indexed("send_packet", "packet_sequence"));
+        assert!(!indexing.is_indexed("send_packet", "packet_sequence"));
+    }
+}
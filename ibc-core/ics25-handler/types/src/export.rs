@@ -0,0 +1,191 @@
+//! Provides the export of [`IbcEvent`]s into a stable JSON form, optionally
+//! wrapped into [CloudEvents](https://cloudevents.io) envelopes, so that
+//! off-chain pipelines can consume the IBC activity of a chain without mapping
+//! each event type themselves.
+//!
+//! An event is exported as its ABCI event, whose type and attributes are the
+//! ones of ibc-go, e.g.
+//! ```json
+//! {
+//!   "type": "send_packet",
+//!   "attributes": {
+//!     "packet_sequence": "1",
+//!     "packet_src_port": "transfer",
+//!     ...
+//!   }
+//! }
+//! ```
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use serde::{Deserialize, Serialize};
+use tendermint::abci;
+
+use crate::events::{Error, IbcEvent};
+
+/// The version of the CloudEvents specification of the exported envelopes.
+pub const CLOUD_EVENTS_SPEC_VERSION: &str = "1.0";
+
+/// The prefix of the type of the exported CloudEvents, followed by the type of
+/// the IBC event, e.g. `ibc.send_packet`.
+pub const CLOUD_EVENT_TYPE_PREFIX: &str = "ibc.";
+
+/// An [`IbcEvent`] in the form of its ABCI event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl TryFrom<IbcEvent> for ExportedEvent {
+    type Error = Error;
+
+    fn try_from(event: IbcEvent) -> Result<Self, Self::Error> {
+        let abci_event = abci::Event::try_from(event)?;
+
+        Ok(Self {
+            event_type: abci_event.kind,
+            attributes: abci_event
+                .attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect(),
+        })
+    }
+}
+
+/// Exports the given events, in order.
+pub fn export_events(
+    events: impl IntoIterator<Item = IbcEvent>,
+) -> Result<Vec<ExportedEvent>, Error> {
+    events.into_iter().map(ExportedEvent::try_from).collect()
+}
+
+/// An [`ExportedEvent`] wrapped into a CloudEvents envelope, in the JSON event
+/// format of the specification.
+///
+/// Besides the attributes of the specification, the envelope carries the
+/// chain identifier and the height of the block in which the event was
+/// emitted, as the `chainid` and `blockheight` extension attributes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudEvent {
+    pub specversion: String,
+    pub id: String,
+    pub source: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    pub datacontenttype: String,
+    pub chainid: String,
+    pub blockheight: String,
+    pub data: ExportedEvent,
+}
+
+/// The emitter of the exported CloudEvents, i.e. the chain and the URI
+/// identifying its node in the pipeline, e.g. `https://node.example.com`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloudEventSource {
+    pub source: String,
+    pub chain_id: ChainId,
+}
+
+impl CloudEventSource {
+    pub fn new(source: impl Into<String>, chain_id: ChainId) -> Self {
+        Self {
+            source: source.into(),
+            chain_id,
+        }
+    }
+
+    /// Wraps the events emitted in the block at the given height and time
+    /// into CloudEvents envelopes.
+    ///
+    /// The events must be all the IBC events of the block, in the order they
+    /// were emitted, as their identifiers are made of the height of the block
+    /// and their index within the block.
+    pub fn export_block(
+        &self,
+        height: Height,
+        time: Timestamp,
+        events: impl IntoIterator<Item = IbcEvent>,
+    ) -> Result<Vec<CloudEvent>, Error> {
+        let time = time.into_tm_time().map(|time| time.to_rfc3339());
+
+        events
+            .into_iter()
+            .enumerate()
+            .map(|(index, event)| {
+                let data = ExportedEvent::try_from(event)?;
+
+                Ok(CloudEvent {
+                    specversion: CLOUD_EVENTS_SPEC_VERSION.to_string(),
+                    id: format!("{}/{height}/{index}", self.chain_id),
+                    source: self.source.clone(),
+                    event_type: format!("{CLOUD_EVENT_TYPE_PREFIX}{}", data.event_type),
+                    time: time.clone(),
+                    datacontenttype: "application/json".to_string(),
+                    chainid: self.chain_id.to_string(),
+                    blockheight: height.to_string(),
+                    data,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_router_types::event::ModuleEvent;
+
+    use super::*;
+    use crate::events::MessageEvent;
+
+    fn dummy_events() -> Vec<IbcEvent> {
+        vec![
+            IbcEvent::Message(MessageEvent::Channel),
+            IbcEvent::Module(ModuleEvent {
+                kind: "fungible_token_packet".to_string(),
+                attributes: vec![("receiver", "cosmos1").into(), ("amount", "10").into()],
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_export_events() {
+        let exported = export_events(dummy_events()).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&exported).unwrap(),
+            r#"[{"type":"message","attributes":{"module":"ibc_channel"}},{"type":"fungible_token_packet","attributes":{"amount":"10","receiver":"cosmos1"}}]"#
+        );
+    }
+
+    #[test]
+    fn test_export_cloud_events() {
+        let source =
+            CloudEventSource::new("https://node.example.com", ChainId::new("ibc-0").unwrap());
+        let height = Height::new(0, 10).unwrap();
+
+        let cloud_events = source
+            .export_block(height, Timestamp::none(), dummy_events())
+            .unwrap();
+
+        assert_eq!(cloud_events.len(), 2);
+        assert_eq!(cloud_events[1].id, "ibc-0/0-10/1");
+        assert_eq!(cloud_events[1].event_type, "ibc.fungible_token_packet");
+
+        let json = serde_json::to_string(&cloud_events[0]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"specversion":"1.0","id":"ibc-0/0-10/0","source":"https://node.example.com","type":"ibc.message","datacontenttype":"application/json","chainid":"ibc-0","blockheight":"0-10","data":{"type":"message","attributes":{"module":"ibc_channel"}}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<CloudEvent>(&json).unwrap(),
+            cloud_events[0]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Provides the (de)serialization of [`MsgEnvelope`] following the proto3 JSON
+//! mapping, so that IBC messages can be expressed in readable JSON, e.g. by CLI
+//! tools and test fixtures, and round-tripped into the dispatcher.
+//!
+//! A message is encoded as a JSON `Any`: an object holding its type URL under
+//! the `@type` key along with the proto3 JSON fields of the message, e.g.
+//! ```json
+//! {
+//!   "@type": "/ibc.core.connection.v1.MsgConnectionOpenConfirm",
+//!   "connectionId": "connection-0",
+//!   "proofAck": "AQID",
+//!   "proofHeight": { "revisionNumber": "0", "revisionHeight": "10" },
+//!   "signer": "cosmos1..."
+//! }
+//! ```
+//!
+//! The client states, consensus states and headers nested in the messages are
+//! defined by the light clients, and are therefore kept in the `typeUrl` and
+//! base64 `value` form of `ibc-proto`.
+
+use core::fmt::Display;
+
+use ibc_core_channel_types::msgs::{ChannelMsg, PacketMsg};
+use ibc_core_channel_types::proto::upgrade::{
+    MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel,
+    MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout,
+};
+use ibc_core_client_types::msgs::ClientMsg;
+use ibc_core_connection_types::msgs::ConnectionMsg;
+use ibc_primitives::prelude::*;
+#[allow(deprecated)]
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement as RawMsgAcknowledgement,
+    MsgChannelCloseConfirm as RawMsgChannelCloseConfirm,
+    MsgChannelCloseInit as RawMsgChannelCloseInit, MsgChannelOpenAck as RawMsgChannelOpenAck,
+    MsgChannelOpenConfirm as RawMsgChannelOpenConfirm, MsgChannelOpenInit as RawMsgChannelOpenInit,
+    MsgChannelOpenTry as RawMsgChannelOpenTry, MsgRecvPacket as RawMsgRecvPacket,
+    MsgTimeout as RawMsgTimeout, MsgTimeoutOnClose as RawMsgTimeoutOnClose,
+};
+use ibc_proto::ibc::core::client::v1::{
+    MsgCreateClient as RawMsgCreateClient, MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour,
+    MsgUpdateClient as RawMsgUpdateClient, MsgUpdateParams as RawMsgUpdateClientParams,
+    MsgUpgradeClient as RawMsgUpgradeClient,
+};
+use ibc_proto::ibc::core::connection::v1::{
+    MsgConnectionOpenAck as RawMsgConnectionOpenAck,
+    MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm,
+    MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+    MsgConnectionOpenTry as RawMsgConnectionOpenTry,
+    MsgUpdateParams as RawMsgUpdateConnectionParams,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::msgs::MsgEnvelope;
+
+/// The raw messages of [`MsgEnvelope`], tagged with their type URL as in the
+/// proto3 JSON mapping of `Any`.
+///
+/// The tags must match the `*_TYPE_URL` constants of the messages.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "@type")]
+enum RawMsgEnvelope {
+    // ICS2 messages
+    #[serde(rename = "/ibc.core.client.v1.MsgCreateClient")]
+    CreateClient(RawMsgCreateClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgUpdateClient")]
+    UpdateClient(RawMsgUpdateClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgUpgradeClient")]
+    UpgradeClient(RawMsgUpgradeClient),
+    #[serde(rename = "/ibc.core.client.v1.MsgSubmitMisbehaviour")]
+    Misbehaviour(RawMsgSubmitMisbehaviour),
+    #[serde(rename = "/ibc.core.client.v1.MsgUpdateParams")]
+    UpdateClientParams(RawMsgUpdateClientParams),
+    // ICS03
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenInit")]
+    ConnectionOpenInit(RawMsgConnectionOpenInit),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenTry")]
+    ConnectionOpenTry(RawMsgConnectionOpenTry),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenAck")]
+    ConnectionOpenAck(RawMsgConnectionOpenAck),
+    #[serde(rename = "/ibc.core.connection.v1.MsgConnectionOpenConfirm")]
+    ConnectionOpenConfirm(RawMsgConnectionOpenConfirm),
+    #[serde(rename = "/ibc.core.connection.v1.MsgUpdateParams")]
+    UpdateConnectionParams(RawMsgUpdateConnectionParams),
+    // ICS04 channel messages
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenInit")]
+    ChannelOpenInit(RawMsgChannelOpenInit),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenTry")]
+    ChannelOpenTry(RawMsgChannelOpenTry),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenAck")]
+    ChannelOpenAck(RawMsgChannelOpenAck),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelOpenConfirm")]
+    ChannelOpenConfirm(RawMsgChannelOpenConfirm),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelCloseInit")]
+    ChannelCloseInit(RawMsgChannelCloseInit),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelCloseConfirm")]
+    ChannelCloseConfirm(RawMsgChannelCloseConfirm),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelUpgradeTimeout")]
+    ChannelUpgradeTimeout(RawMsgChannelUpgradeTimeout),
+    #[serde(rename = "/ibc.core.channel.v1.MsgChannelUpgradeCancel")]
+    ChannelUpgradeCancel(RawMsgChannelUpgradeCancel),
+    // ICS04 packet messages
+    #[serde(rename = "/ibc.core.channel.v1.MsgRecvPacket")]
+    RecvPacket(RawMsgRecvPacket),
+    #[serde(rename = "/ibc.core.channel.v1.MsgAcknowledgement")]
+    Acknowledgement(RawMsgAcknowledgement),
+    #[serde(rename = "/ibc.core.channel.v1.MsgTimeout")]
+    Timeout(RawMsgTimeout),
+    #[serde(rename = "/ibc.core.channel.v1.MsgTimeoutOnClose")]
+    TimeoutOnClose(RawMsgTimeoutOnClose),
+}
+
+#[allow(deprecated)]
+impl From<MsgEnvelope> for RawMsgEnvelope {
+    fn from(msg: MsgEnvelope) -> Self {
+        match msg {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(msg) => Self::CreateClient(msg.into()),
+                ClientMsg::UpdateClient(msg) => Self::UpdateClient(msg.into()),
+                ClientMsg::UpgradeClient(msg) => Self::UpgradeClient(msg.into()),
+                ClientMsg::Misbehaviour(msg) => Self::Misbehaviour(msg.into()),
+                ClientMsg::UpdateParams(msg) => Self::UpdateClientParams(msg.into()),
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(msg) => Self::ConnectionOpenInit(msg.into()),
+                ConnectionMsg::OpenTry(msg) => Self::ConnectionOpenTry(msg.into()),
+                ConnectionMsg::OpenAck(msg) => Self::ConnectionOpenAck(msg.into()),
+                ConnectionMsg::OpenConfirm(msg) => Self::ConnectionOpenConfirm(msg.into()),
+                ConnectionMsg::UpdateParams(msg) => Self::UpdateConnectionParams(msg.into()),
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(msg) => Self::ChannelOpenInit(msg.into()),
+                ChannelMsg::OpenTry(msg) => Self::ChannelOpenTry(msg.into()),
+                ChannelMsg::OpenAck(msg) => Self::ChannelOpenAck(msg.into()),
+                ChannelMsg::OpenConfirm(msg) => Self::ChannelOpenConfirm(msg.into()),
+                ChannelMsg::CloseInit(msg) => Self::ChannelCloseInit(msg.into()),
+                ChannelMsg::CloseConfirm(msg) => Self::ChannelCloseConfirm(msg.into()),
+                ChannelMsg::UpgradeTimeout(msg) => Self::ChannelUpgradeTimeout(msg.into()),
+                ChannelMsg::UpgradeCancel(msg) => Self::ChannelUpgradeCancel(msg.into()),
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(msg) => Self::RecvPacket(msg.into()),
+                PacketMsg::Ack(msg) => Self::Acknowledgement(msg.into()),
+                PacketMsg::Timeout(msg) => Self::Timeout(msg.into()),
+                PacketMsg::TimeoutOnClose(msg) => Self::TimeoutOnClose(msg.into()),
+            },
+        }
+    }
+}
+
+impl Serialize for MsgEnvelope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawMsgEnvelope::from(self.clone()).serialize(serializer)
+    }
+}
+
+#[allow(deprecated)]
+impl<'de> Deserialize<'de> for MsgEnvelope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let msg = match RawMsgEnvelope::deserialize(deserializer)? {
+            RawMsgEnvelope::CreateClient(msg) => ClientMsg::CreateClient(domain(msg)?).into(),
+            RawMsgEnvelope::UpdateClient(msg) => ClientMsg::UpdateClient(domain(msg)?).into(),
+            RawMsgEnvelope::UpgradeClient(msg) => ClientMsg::UpgradeClient(domain(msg)?).into(),
+            RawMsgEnvelope::Misbehaviour(msg) => ClientMsg::Misbehaviour(domain(msg)?).into(),
+            RawMsgEnvelope::UpdateClientParams(msg) => ClientMsg::UpdateParams(domain(msg)?).into(),
+            RawMsgEnvelope::ConnectionOpenInit(msg) => ConnectionMsg::OpenInit(domain(msg)?).into(),
+            RawMsgEnvelope::ConnectionOpenTry(msg) => ConnectionMsg::OpenTry(domain(msg)?).into(),
+            RawMsgEnvelope::ConnectionOpenAck(msg) => ConnectionMsg::OpenAck(domain(msg)?).into(),
+            RawMsgEnvelope::ConnectionOpenConfirm(msg) => {
+                ConnectionMsg::OpenConfirm(domain(msg)?).into()
+            }
+            RawMsgEnvelope::UpdateConnectionParams(msg) => {
+                ConnectionMsg::UpdateParams(domain(msg)?).into()
+            }
+            RawMsgEnvelope::ChannelOpenInit(msg) => ChannelMsg::OpenInit(domain(msg)?).into(),
+            RawMsgEnvelope::ChannelOpenTry(msg) => ChannelMsg::OpenTry(domain(msg)?).into(),
+            RawMsgEnvelope::ChannelOpenAck(msg) => ChannelMsg::OpenAck(domain(msg)?).into(),
+            RawMsgEnvelope::ChannelOpenConfirm(msg) => ChannelMsg::OpenConfirm(domain(msg)?).into(),
+            RawMsgEnvelope::ChannelCloseInit(msg) => ChannelMsg::CloseInit(domain(msg)?).into(),
+            RawMsgEnvelope::ChannelCloseConfirm(msg) => {
+                ChannelMsg::CloseConfirm(domain(msg)?).into()
+            }
+            RawMsgEnvelope::ChannelUpgradeTimeout(msg) => {
+                ChannelMsg::UpgradeTimeout(domain(msg)?).into()
+            }
+            RawMsgEnvelope::ChannelUpgradeCancel(msg) => {
+                ChannelMsg::UpgradeCancel(domain(msg)?).into()
+            }
+            RawMsgEnvelope::RecvPacket(msg) => PacketMsg::Recv(domain(msg)?).into(),
+            RawMsgEnvelope::Acknowledgement(msg) => PacketMsg::Ack(domain(msg)?).into(),
+            RawMsgEnvelope::Timeout(msg) => PacketMsg::Timeout(domain(msg)?).into(),
+            RawMsgEnvelope::TimeoutOnClose(msg) => PacketMsg::TimeoutOnClose(domain(msg)?).into(),
+        };
+
+        Ok(msg)
+    }
+}
+
+/// Converts a raw message into its domain type, as validated when decoding its
+/// protobuf encoding.
+fn domain<R, M, E>(raw: R) -> Result<M, E>
+where
+    M: TryFrom<R>,
+    M::Error: Display,
+    E: serde::de::Error,
+{
+    M::try_from(raw).map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_connection_types::msgs::MsgConnectionOpenConfirm;
+    use ibc_primitives::Signer;
+
+    use super::*;
+
+    fn dummy_msg() -> MsgEnvelope {
+        let msg = MsgConnectionOpenConfirm {
+            conn_id_on_b: "connection-0".parse().expect("Never fails"),
+            proof_conn_end_on_a: vec![1, 2, 3].try_into().expect("Never fails"),
+            proof_height_on_a: ibc_core_client_types::Height::new(0, 10).expect("Never fails"),
+            signer: Signer::from("cosmos1signer".to_string()),
+        };
+
+        ConnectionMsg::OpenConfirm(msg).into()
+    }
+
+    #[test]
+    fn test_msg_envelope_json_roundtrip() {
+        let msg = dummy_msg();
+
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"@type":"/ibc.core.connection.v1.MsgConnectionOpenConfirm","connectionId":"connection-0","proofAck":"AQID","proofHeight":{"revisionNumber":"0","revisionHeight":"10"},"signer":"cosmos1signer"}"#
+        );
+
+        let decoded: MsgEnvelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_msg_envelope_json_type_anywhere() {
+        let json = r#"{"connectionId":"connection-0","proofAck":"AQID","proofHeight":{"revisionNumber":"0","revisionHeight":"10"},"@type":"/ibc.core.connection.v1.MsgConnectionOpenConfirm","signer":"cosmos1signer"}"#;
+
+        let decoded: MsgEnvelope = serde_json::from_str(json).unwrap();
+        assert_eq!(decoded, dummy_msg());
+    }
+
+    #[test]
+    fn test_msg_envelope_json_invalid() {
+        // Unknown type URL
+        assert!(serde_json::from_str::<MsgEnvelope>(
+            r#"{"@type":"/ibc.core.client.v1.MsgRecoverClient","signer":"cosmos1signer"}"#
+        )
+        .is_err());
+
+        // Invalid connection identifier
+        assert!(serde_json::from_str::<MsgEnvelope>(
+            r#"{"@type":"/ibc.core.connection.v1.MsgConnectionOpenConfirm","connectionId":"","proofAck":"AQID","proofHeight":{"revisionNumber":"0","revisionHeight":"10"},"signer":"cosmos1signer"}"#
+        )
+        .is_err());
+    }
+}
@@ -17,5 +17,11 @@
 extern crate std;
 
 pub mod error;
+pub mod event_filter;
 pub mod events;
+#[cfg(feature = "serde")]
+pub mod export;
 pub mod msgs;
+
+#[cfg(feature = "serde")]
+mod json;
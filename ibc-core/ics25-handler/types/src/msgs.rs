@@ -1,21 +1,24 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgChannelUpgradeCancel,
+    MsgChannelUpgradeTimeout, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, PacketMsg,
+    ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL,
+    CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL,
+    CHAN_OPEN_TRY_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
-    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
+    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+    MsgUpdateParams as MsgUpdateClientParams, MsgUpgradeClient, CREATE_CLIENT_TYPE_URL,
+    SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_PARAMS_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
     UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
-    MsgConnectionOpenTry, CONN_OPEN_ACK_TYPE_URL, CONN_OPEN_CONFIRM_TYPE_URL,
-    CONN_OPEN_INIT_TYPE_URL, CONN_OPEN_TRY_TYPE_URL,
+    MsgConnectionOpenTry, MsgUpdateParams as MsgUpdateConnectionParams, CONN_OPEN_ACK_TYPE_URL,
+    CONN_OPEN_CONFIRM_TYPE_URL, CONN_OPEN_INIT_TYPE_URL, CONN_OPEN_TRY_TYPE_URL,
+    UPDATE_CONNECTION_PARAMS_TYPE_URL,
 };
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
@@ -23,11 +26,16 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
+///
+/// With the `serde` feature, messages are (de)serialized following the proto3
+/// JSON mapping of `Any`, i.e. as the JSON fields of the raw message along
+/// with its type URL under the `@type` key.
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum MsgEnvelope {
     Client(ClientMsg),
@@ -77,6 +85,15 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
             }
+            UPDATE_CLIENT_PARAMS_TYPE_URL => {
+                let domain_msg =
+                    MsgUpdateClientParams::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::UpdateParams(domain_msg)))
+            }
 
             // ICS03
             CONN_OPEN_INIT_TYPE_URL => {
@@ -115,6 +132,17 @@ impl TryFrom<Any> for MsgEnvelope {
                     domain_msg,
                 )))
             }
+            UPDATE_CONNECTION_PARAMS_TYPE_URL => {
+                let domain_msg =
+                    MsgUpdateConnectionParams::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Connection(ConnectionMsg::UpdateParams(
+                    domain_msg,
+                )))
+            }
 
             // ICS04 channel messages
             CHAN_OPEN_INIT_TYPE_URL => {
@@ -167,6 +195,24 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            CHAN_UPGRADE_TIMEOUT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTimeout(domain_msg)))
+            }
+            CHAN_UPGRADE_CANCEL_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeCancel::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeCancel(domain_msg)))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
                 let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
@@ -206,3 +252,73 @@ impl TryFrom<Any> for MsgEnvelope {
         }
     }
 }
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use ibc_primitives::ToProto;
+
+    use super::*;
+
+    #[allow(deprecated)]
+    fn to_any(msg: MsgEnvelope) -> Any {
+        match msg {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(msg) => msg.to_any(),
+                ClientMsg::UpdateClient(msg) => msg.to_any(),
+                ClientMsg::UpgradeClient(msg) => msg.to_any(),
+                ClientMsg::Misbehaviour(msg) => msg.to_any(),
+                ClientMsg::UpdateParams(msg) => msg.to_any(),
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(msg) => msg.to_any(),
+                ConnectionMsg::OpenTry(msg) => msg.to_any(),
+                ConnectionMsg::OpenAck(msg) => msg.to_any(),
+                ConnectionMsg::OpenConfirm(msg) => msg.to_any(),
+                ConnectionMsg::UpdateParams(msg) => msg.to_any(),
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(msg) => msg.to_any(),
+                ChannelMsg::OpenTry(msg) => msg.to_any(),
+                ChannelMsg::OpenAck(msg) => msg.to_any(),
+                ChannelMsg::OpenConfirm(msg) => msg.to_any(),
+                ChannelMsg::CloseInit(msg) => msg.to_any(),
+                ChannelMsg::CloseConfirm(msg) => msg.to_any(),
+                ChannelMsg::UpgradeTimeout(msg) => msg.to_any(),
+                ChannelMsg::UpgradeCancel(msg) => msg.to_any(),
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(msg) => msg.to_any(),
+                PacketMsg::Ack(msg) => msg.to_any(),
+                PacketMsg::Timeout(msg) => msg.to_any(),
+                PacketMsg::TimeoutOnClose(msg) => msg.to_any(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_msg_envelope_any_roundtrip() {
+        // A xorshift generator, filling the input of each message.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        };
+
+        // The generated messages are within the validation bounds of their
+        // domain types, so they decode back from their raw form.
+        let mut generated = 0;
+        for _ in 0..1024 {
+            let data: Vec<u8> = (0..1024).map(|_| next_byte()).collect();
+
+            if let Ok(msg) = MsgEnvelope::arbitrary(&mut Unstructured::new(&data)) {
+                assert_eq!(MsgEnvelope::try_from(to_any(msg.clone())).unwrap(), msg);
+                generated += 1;
+            }
+        }
+
+        assert!(generated > 0);
+    }
+}
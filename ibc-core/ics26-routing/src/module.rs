@@ -11,6 +11,20 @@ use ibc_core_router_types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 
+/// The callbacks of an IBC application, invoked by the channel handlers.
+///
+/// # Panicking callbacks
+///
+/// With the `catch-unwind` feature of `ibc-core`, a panic of a callback is
+/// caught by the handler and turned into an error acknowledgement of the
+/// received packet, or into a handler error. The handlers have no way to
+/// roll back the state changes made by the callback before it panicked, so
+/// these partial writes are kept. The feature is therefore only sound for
+/// hosts which either run each message against a cached (branched) store
+/// that is discarded when the handler errors, or whose applications never
+/// write to their state before a point at which they may panic. In
+/// particular, a panic in `on_recv_packet_execute` still commits the error
+/// acknowledgement, along with whatever the callback wrote.
 pub trait Module: Debug {
     fn on_chan_open_init_validate(
         &self,
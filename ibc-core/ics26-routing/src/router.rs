@@ -1,7 +1,8 @@
 //! Defines the `Router`, which binds modules to ports
 
-use ibc_core_host_types::identifiers::PortId;
+use ibc_core_host_types::identifiers::{PortId, PortPrefix};
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
 
@@ -14,5 +15,95 @@ pub trait Router {
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module>;
 
     /// Return the module_id associated with a given port_id
+    ///
+    /// Routers binding modules to prefixes of ports can resolve the module
+    /// with [`PortBindings::lookup_module`].
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
 }
+
+/// The bindings of ports to modules, which routers can use to implement
+/// [`Router::lookup_module`].
+///
+/// A module is bound either to a single port, or to all the ports of a
+/// namespace, e.g. all the `icacontroller-*` ports or all the `wasm.*` ports.
+/// The lookup of a port resolves to the module bound to the port itself if
+/// any, and otherwise to the module bound to its longest matching prefix.
+#[derive(Clone, Debug, Default)]
+pub struct PortBindings {
+    ports: BTreeMap<PortId, ModuleId>,
+    prefixes: BTreeMap<PortPrefix, ModuleId>,
+}
+
+impl PortBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `port_id` to `module_id`, returning the module previously bound
+    /// to the port, if any.
+    pub fn bind_port(&mut self, port_id: PortId, module_id: ModuleId) -> Option<ModuleId> {
+        self.ports.insert(port_id, module_id)
+    }
+
+    /// Binds all the ports of `prefix` to `module_id`, returning the module
+    /// previously bound to the prefix, if any.
+    pub fn bind_prefix(&mut self, prefix: PortPrefix, module_id: ModuleId) -> Option<ModuleId> {
+        self.prefixes.insert(prefix, module_id)
+    }
+
+    /// Returns the module bound to `port_id`, either directly or through
+    /// its longest matching prefix.
+    pub fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        if let Some(module_id) = self.ports.get(port_id) {
+            return Some(module_id.clone());
+        }
+
+        self.prefixes
+            .iter()
+            .filter(|(prefix, _)| port_id.has_prefix(prefix))
+            .max_by_key(|(prefix, _)| prefix.as_str().len())
+            .map(|(_, module_id)| module_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(bindings: &PortBindings, port_id: &str) -> Option<ModuleId> {
+        bindings.lookup_module(&port_id.parse().expect("valid port"))
+    }
+
+    #[test]
+    fn test_port_bindings_lookup() {
+        let transfer_module = ModuleId::new("transfer".to_string());
+        let wasm_module = ModuleId::new("wasm".to_string());
+        let contract_module = ModuleId::new("contract".to_string());
+        let override_module = ModuleId::new("override".to_string());
+
+        let mut bindings = PortBindings::new();
+        bindings.bind_port(PortId::transfer(), transfer_module.clone());
+        bindings.bind_prefix(PortPrefix::wasm(), wasm_module.clone());
+        bindings.bind_prefix(
+            "wasm.cosmos1".parse().expect("valid prefix"),
+            contract_module.clone(),
+        );
+        bindings.bind_port(
+            "wasm.cosmos1override".parse().expect("valid port"),
+            override_module.clone(),
+        );
+
+        assert_eq!(lookup(&bindings, "transfer"), Some(transfer_module));
+        assert_eq!(lookup(&bindings, "wasm.osmo1contract"), Some(wasm_module));
+        assert_eq!(
+            lookup(&bindings, "wasm.cosmos1contract"),
+            Some(contract_module)
+        );
+        assert_eq!(
+            lookup(&bindings, "wasm.cosmos1override"),
+            Some(override_module)
+        );
+        assert_eq!(lookup(&bindings, "wasm."), None);
+        assert_eq!(lookup(&bindings, "icacontroller-owner"), None);
+    }
+}
@@ -81,6 +81,20 @@ pub mod derive {
     /// - `#[validation(Context<S>)]`
     /// - `#[validation(Context<'a, S>)]`
     /// - `#[validation(Context<'a, S: Clone>)]`
+    ///
+    /// Variants wrapping a Tendermint client state may also be annotated with
+    /// `#[verifier(<YourTmVerifier>)]` to have their client messages verified
+    /// with the given `TmVerifier` expression instead of the `DefaultVerifier`:
+    ///
+    /// ```ignore
+    /// #[derive(ClientState)]
+    /// #[validation(MyContext)]
+    /// #[execution(MyContext)]
+    /// enum AnyClientState {
+    ///     #[verifier(MyVerifier::new())]
+    ///     Tendermint(TmClientState),
+    /// }
+    /// ```
     pub use ibc_derive::IbcCoreClientState as ClientState;
     /// A derive macro for implementing the
     /// [`ConsensusState`](crate::client::context::consensus_state::ConsensusState) trait for
@@ -88,4 +102,28 @@ pub mod derive {
     /// [`ConsensusState`](crate::client::context::consensus_state::ConsensusState) trait can
     /// leverage this macro for automatic implementation.
     pub use ibc_derive::IbcCoreConsensusState as ConsensusState;
+    /// A derive macro for implementing the
+    /// [`Module`](crate::router::module::Module) trait by delegating
+    /// every callback to the inherent method of the same name, e.g.
+    /// `on_recv_packet_execute`, sparing applications the trait plumbing.
+    ///
+    /// The callbacks with a default implementation are only delegated when
+    /// listed in the `#[module(...)]` attribute, among `chan_open_ack`,
+    /// `chan_open_confirm`, `chan_close_init` and `chan_close_confirm`:
+    ///
+    /// ```ignore
+    /// #[derive(Debug, Module)]
+    /// #[module(chan_close_init)]
+    /// struct MyModule;
+    ///
+    /// impl MyModule {
+    ///     fn on_chan_open_init_validate(/* ... */) -> Result<Version, ChannelError> { /* ... */ }
+    ///     fn on_chan_close_init_validate(/* ... */) -> Result<(), ChannelError> { /* ... */ }
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// A missing inherent callback fails the compilation with an ambiguity
+    /// between the `Module` and `MissingInherentCallback` traits.
+    pub use ibc_derive::IbcCoreModule as Module;
 }
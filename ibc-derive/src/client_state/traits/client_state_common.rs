@@ -35,6 +35,12 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {validate_proof_height(cs, proof_height)},
         imports,
     );
+    let is_past_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {is_past_height(cs, height, timeout_height)},
+        imports,
+    );
     let verify_upgrade_client_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -91,6 +97,12 @@ pub(crate) fn impl_ClientStateCommon(
                 }
             }
 
+            fn is_past_height(&self, height: #Height, timeout_height: #Height) -> bool {
+                match self {
+                    #(#is_past_height_impl),*
+                }
+            }
+
             fn verify_upgrade_client(
                 &self,
                 upgraded_client_state: #Any,
@@ -2,30 +2,44 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
-use syn::Variant;
+use syn::{Error, Variant};
 
 use crate::client_state::Opts;
 use crate::utils::{get_enum_variant_type_path, Imports};
 
+const INVALID_VERIFIER_ATTR: &str =
+    "expected a single #[verifier(<your TmVerifier expression>)] attribute";
+
 pub(crate) fn impl_ClientStateValidation(
     client_state_enum_name: &Ident,
     enum_variants: &Punctuated<Variant, Comma>,
     opts: &Opts,
     imports: &Imports,
 ) -> TokenStream {
-    let verify_client_message_impl = delegate_call_in_match(
+    let verify_client_message_impl =
+        verify_client_message_in_match(client_state_enum_name, enum_variants.iter(), opts, imports);
+
+    let check_for_misbehaviour_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
         opts,
-        quote! { verify_client_message(cs, ctx, client_id, client_message) },
+        quote! { check_for_misbehaviour(cs, ctx, client_id, client_message) },
         imports,
     );
 
-    let check_for_misbehaviour_impl = delegate_call_in_match(
+    let check_for_redundant_update_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
         opts,
-        quote! { check_for_misbehaviour(cs, ctx, client_id, client_message) },
+        quote! { check_for_redundant_update(cs, ctx, client_id, header) },
+        imports,
+    );
+
+    let update_telemetry_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { update_telemetry(cs, ctx, client_id, header) },
         imports,
     );
 
@@ -43,6 +57,7 @@ pub(crate) fn impl_ClientStateValidation(
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
     let Status = imports.status();
+    let UpdateClientTelemetry = imports.update_client_telemetry();
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
@@ -79,6 +94,28 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn check_for_redundant_update(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+                header: #Any,
+            ) -> core::result::Result<bool, #ClientError> {
+                match self {
+                    #(#check_for_redundant_update_impl),*
+                }
+            }
+
+            fn update_telemetry(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+                header: #Any,
+            ) -> core::result::Result<core::option::Option<#UpdateClientTelemetry>, #ClientError> {
+                match self {
+                    #(#update_telemetry_impl),*
+                }
+            }
+
             fn status(
                 &self,
                 ctx: &#ClientValidationContext,
@@ -94,10 +131,68 @@ pub(crate) fn impl_ClientStateValidation(
     }
 }
 
-fn delegate_call_in_match(
+/// Generates the match arms of `verify_client_message`. Variants annotated with
+/// `#[verifier(<your TmVerifier>)]` are verified with the given verifier
+/// expression through their `verify_client_message_with` method, while the
+/// other variants delegate to their `ClientStateValidation` implementation.
+fn verify_client_message_in_match(
     enum_name: &Ident,
     enum_variants: Iter<'_, Variant>,
     opts: &Opts,
+    imports: &Imports,
+) -> Vec<TokenStream> {
+    enum_variants
+        .map(|variant| match get_verifier_attr(variant) {
+            Ok(Some(verifier)) => {
+                let HostClientState = enum_name;
+                let Tendermint = &variant.ident;
+
+                quote! {
+                    #HostClientState::#Tendermint(cs) => cs.verify_client_message_with(ctx, client_id, client_message, &(#verifier))
+                }
+            }
+            Ok(None) => delegate_call_in_match(
+                enum_name,
+                core::iter::once(variant),
+                opts,
+                quote! { verify_client_message(cs, ctx, client_id, client_message) },
+                imports,
+            )
+            .remove(0),
+            Err(e) => e.to_compile_error(),
+        })
+        .collect()
+}
+
+/// Returns the verifier expression of the `#[verifier(...)]` attribute of the
+/// given variant, if any.
+fn get_verifier_attr(variant: &Variant) -> Result<Option<TokenStream>, Error> {
+    let mut verifier = None;
+
+    for attr in variant.attrs.iter() {
+        if !attr.path().is_ident("verifier") {
+            continue;
+        }
+
+        let meta_list = attr
+            .meta
+            .require_list()
+            .map_err(|_| Error::new_spanned(attr, INVALID_VERIFIER_ATTR))?;
+
+        if meta_list.tokens.is_empty() || verifier.is_some() {
+            return Err(Error::new_spanned(attr, INVALID_VERIFIER_ATTR));
+        }
+
+        verifier = Some(meta_list.tokens.clone());
+    }
+
+    Ok(verifier)
+}
+
+fn delegate_call_in_match<'a>(
+    enum_name: &Ident,
+    enum_variants: impl Iterator<Item = &'a Variant>,
+    opts: &Opts,
     fn_call: TokenStream,
     imports: &Imports,
 ) -> Vec<TokenStream> {
@@ -10,20 +10,22 @@
 
 mod client_state;
 mod consensus_state;
+mod module;
 mod utils;
 
 use client_state::client_state_derive_impl;
 use consensus_state::consensus_state_derive_impl;
+use module::module_derive_impl;
 use proc_macro::TokenStream as RawTokenStream;
 use syn::{parse_macro_input, DeriveInput};
 use utils::{Imports, SupportedCrate};
 
-#[proc_macro_derive(IbcClientState, attributes(validation, execution))]
+#[proc_macro_derive(IbcClientState, attributes(validation, execution, verifier))]
 pub fn ibc_client_state_macro_derive(input: RawTokenStream) -> RawTokenStream {
     generate_client_state_derive(input, SupportedCrate::Ibc)
 }
 
-#[proc_macro_derive(IbcCoreClientState, attributes(validation, execution))]
+#[proc_macro_derive(IbcCoreClientState, attributes(validation, execution, verifier))]
 pub fn ibc_core_client_state_macro_derive(input: RawTokenStream) -> RawTokenStream {
     generate_client_state_derive(input, SupportedCrate::IbcCore)
 }
@@ -60,3 +62,23 @@ fn generate_consensus_state_derive(
 
     RawTokenStream::from(output)
 }
+
+#[proc_macro_derive(IbcModule, attributes(module))]
+pub fn ibc_module_macro_derive(input: RawTokenStream) -> RawTokenStream {
+    generate_module_derive(input, SupportedCrate::Ibc)
+}
+
+#[proc_macro_derive(IbcCoreModule, attributes(module))]
+pub fn ibc_core_module_macro_derive(input: RawTokenStream) -> RawTokenStream {
+    generate_module_derive(input, SupportedCrate::IbcCore)
+}
+
+fn generate_module_derive(input: RawTokenStream, source: SupportedCrate) -> RawTokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+
+    let imports = Imports::new(source);
+
+    let output = module_derive_impl(ast, &imports);
+
+    RawTokenStream::from(output)
+}
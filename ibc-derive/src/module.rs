@@ -0,0 +1,421 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Error, Ident, Token};
+
+use crate::utils::Imports;
+
+const INVALID_ATTR: &str = "invalid attribute annotation";
+const UNKNOWN_CALLBACK: &str = "unknown optional callback, expected one of `chan_open_ack`, `chan_open_confirm`, `chan_close_init` or `chan_close_confirm`";
+
+/// The callbacks of the `Module` trait that come with a default
+/// implementation, and are only delegated when listed in the
+/// `#[module(...)]` attribute.
+const OPTIONAL_CALLBACKS: [&str; 4] = [
+    "chan_open_ack",
+    "chan_open_confirm",
+    "chan_close_init",
+    "chan_close_confirm",
+];
+
+pub fn module_derive_impl(ast: DeriveInput, imports: &Imports) -> TokenStream {
+    let optional_callbacks = match optional_callbacks(&ast) {
+        Ok(callbacks) => callbacks,
+        Err(e) => return e.into_compile_error(),
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let Module = imports.module();
+    let ModuleExtras = imports.module_extras();
+    let Order = imports.order();
+    let Counterparty = imports.counterparty();
+    let Version = imports.version();
+    let Packet = imports.packet();
+    let Acknowledgement = imports.acknowledgement();
+    let ChannelError = imports.channel_error();
+    let PacketError = imports.packet_error();
+    let ConnectionId = imports.connection_id();
+    let PortId = imports.port_id();
+    let ChannelId = imports.channel_id();
+    let Signer = imports.signer();
+
+    let mut callbacks = Vec::new();
+
+    if optional_callbacks.contains(&"chan_open_ack") {
+        callbacks.push(quote! {
+            fn on_chan_open_ack_validate(
+                &self,
+                port_id: &#PortId,
+                channel_id: &#ChannelId,
+                counterparty_version: &#Version,
+            ) -> Result<(), #ChannelError> {
+                Self::on_chan_open_ack_validate(self, port_id, channel_id, counterparty_version)
+            }
+
+            fn on_chan_open_ack_execute(
+                &mut self,
+                port_id: &#PortId,
+                channel_id: &#ChannelId,
+                counterparty_version: &#Version,
+            ) -> Result<#ModuleExtras, #ChannelError> {
+                Self::on_chan_open_ack_execute(self, port_id, channel_id, counterparty_version)
+            }
+        });
+    }
+
+    for callback in ["chan_open_confirm", "chan_close_init", "chan_close_confirm"] {
+        if !optional_callbacks.contains(&callback) {
+            continue;
+        }
+
+        let validate = Ident::new(&format!("on_{callback}_validate"), name.span());
+        let execute = Ident::new(&format!("on_{callback}_execute"), name.span());
+
+        callbacks.push(quote! {
+            fn #validate(
+                &self,
+                port_id: &#PortId,
+                channel_id: &#ChannelId,
+            ) -> Result<(), #ChannelError> {
+                Self::#validate(self, port_id, channel_id)
+            }
+
+            fn #execute(
+                &mut self,
+                port_id: &#PortId,
+                channel_id: &#ChannelId,
+            ) -> Result<#ModuleExtras, #ChannelError> {
+                Self::#execute(self, port_id, channel_id)
+            }
+        });
+    }
+
+    let mut delegated = vec![
+        "chan_open_init",
+        "chan_open_try",
+        "recv_packet",
+        "acknowledgement_packet",
+        "timeout_packet",
+    ];
+    delegated.extend(optional_callbacks);
+
+    // The delegations resolve to the trait methods themselves if the
+    // inherent methods are missing, recursing indefinitely. Having another
+    // trait with the same methods in scope turns this into an ambiguity
+    // error instead.
+    let shadowed = delegated.iter().flat_map(|callback| {
+        let validate = Ident::new(&format!("on_{callback}_validate"), name.span());
+        let execute = Ident::new(&format!("on_{callback}_execute"), name.span());
+
+        match *callback {
+            "recv_packet" => vec![quote! { fn #execute(&self) {} }],
+            _ => vec![
+                quote! { fn #validate(&self) {} },
+                quote! { fn #execute(&self) {} },
+            ],
+        }
+    });
+
+    quote! {
+        const _: () = {
+            use #Module as _;
+
+            #[allow(dead_code)]
+            trait MissingInherentCallback {
+                #(#shadowed)*
+            }
+
+            impl #impl_generics MissingInherentCallback for #name #ty_generics #where_clause {}
+
+            impl #impl_generics #Module for #name #ty_generics #where_clause {
+                fn on_chan_open_init_validate(
+                    &self,
+                    order: #Order,
+                    connection_hops: &[#ConnectionId],
+                    port_id: &#PortId,
+                    channel_id: &#ChannelId,
+                    counterparty: &#Counterparty,
+                    version: &#Version,
+                ) -> Result<#Version, #ChannelError> {
+                    Self::on_chan_open_init_validate(
+                        self,
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        version,
+                    )
+                }
+
+                fn on_chan_open_init_execute(
+                    &mut self,
+                    order: #Order,
+                    connection_hops: &[#ConnectionId],
+                    port_id: &#PortId,
+                    channel_id: &#ChannelId,
+                    counterparty: &#Counterparty,
+                    version: &#Version,
+                ) -> Result<(#ModuleExtras, #Version), #ChannelError> {
+                    Self::on_chan_open_init_execute(
+                        self,
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        version,
+                    )
+                }
+
+                fn on_chan_open_try_validate(
+                    &self,
+                    order: #Order,
+                    connection_hops: &[#ConnectionId],
+                    port_id: &#PortId,
+                    channel_id: &#ChannelId,
+                    counterparty: &#Counterparty,
+                    counterparty_version: &#Version,
+                ) -> Result<#Version, #ChannelError> {
+                    Self::on_chan_open_try_validate(
+                        self,
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        counterparty_version,
+                    )
+                }
+
+                fn on_chan_open_try_execute(
+                    &mut self,
+                    order: #Order,
+                    connection_hops: &[#ConnectionId],
+                    port_id: &#PortId,
+                    channel_id: &#ChannelId,
+                    counterparty: &#Counterparty,
+                    counterparty_version: &#Version,
+                ) -> Result<(#ModuleExtras, #Version), #ChannelError> {
+                    Self::on_chan_open_try_execute(
+                        self,
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        counterparty_version,
+                    )
+                }
+
+                #(#callbacks)*
+
+                fn on_recv_packet_execute(
+                    &mut self,
+                    packet: &#Packet,
+                    relayer: &#Signer,
+                ) -> (#ModuleExtras, #Acknowledgement) {
+                    Self::on_recv_packet_execute(self, packet, relayer)
+                }
+
+                fn on_acknowledgement_packet_validate(
+                    &self,
+                    packet: &#Packet,
+                    acknowledgement: &#Acknowledgement,
+                    relayer: &#Signer,
+                ) -> Result<(), #PacketError> {
+                    Self::on_acknowledgement_packet_validate(self, packet, acknowledgement, relayer)
+                }
+
+                fn on_acknowledgement_packet_execute(
+                    &mut self,
+                    packet: &#Packet,
+                    acknowledgement: &#Acknowledgement,
+                    relayer: &#Signer,
+                ) -> (#ModuleExtras, Result<(), #PacketError>) {
+                    Self::on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer)
+                }
+
+                fn on_timeout_packet_validate(
+                    &self,
+                    packet: &#Packet,
+                    relayer: &#Signer,
+                ) -> Result<(), #PacketError> {
+                    Self::on_timeout_packet_validate(self, packet, relayer)
+                }
+
+                fn on_timeout_packet_execute(
+                    &mut self,
+                    packet: &#Packet,
+                    relayer: &#Signer,
+                ) -> (#ModuleExtras, Result<(), #PacketError>) {
+                    Self::on_timeout_packet_execute(self, packet, relayer)
+                }
+            }
+        };
+    }
+}
+
+/// Returns the optional callbacks listed in the `#[module(...)]` attributes,
+/// e.g. `#[module(chan_open_ack, chan_close_init)]`.
+fn optional_callbacks(ast: &DeriveInput) -> Result<Vec<&'static str>, Error> {
+    let mut callbacks = Vec::new();
+
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("module"))
+    {
+        let meta_list = match attr.meta {
+            syn::Meta::List(ref meta_list) => meta_list,
+            _ => return Err(Error::new_spanned(attr, INVALID_ATTR)),
+        };
+
+        let idents = meta_list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+
+        for ident in idents {
+            let callback = OPTIONAL_CALLBACKS
+                .iter()
+                .find(|callback| ident == callback)
+                .ok_or_else(|| Error::new_spanned(&ident, UNKNOWN_CALLBACK))?;
+
+            callbacks.push(*callback);
+        }
+    }
+
+    Ok(callbacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+    use crate::utils::SupportedCrate;
+
+    fn expand(ast: DeriveInput) -> String {
+        module_derive_impl(ast, &Imports::new(SupportedCrate::Ibc)).to_string()
+    }
+
+    #[test]
+    fn test_optional_callbacks() {
+        let ast: DeriveInput = parse_quote! {
+            #[module(chan_open_ack, chan_close_init)]
+            #[module(chan_open_confirm)]
+            struct DummyModule;
+        };
+
+        assert_eq!(
+            optional_callbacks(&ast).unwrap(),
+            ["chan_open_ack", "chan_close_init", "chan_open_confirm"]
+        );
+
+        let ast: DeriveInput = parse_quote! {
+            struct DummyModule;
+        };
+
+        assert!(optional_callbacks(&ast).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_optional_callbacks_errors() {
+        let unknown: DeriveInput = parse_quote! {
+            #[module(chan_open_ack, chan_open_init)]
+            struct DummyModule;
+        };
+
+        let err = optional_callbacks(&unknown).unwrap_err();
+        assert_eq!(err.to_string(), UNKNOWN_CALLBACK);
+
+        let invalid: [DeriveInput; 2] = [
+            parse_quote! {
+                #[module]
+                struct DummyModule;
+            },
+            parse_quote! {
+                #[module = "chan_open_ack"]
+                struct DummyModule;
+            },
+        ];
+
+        for ast in invalid {
+            let err = optional_callbacks(&ast).unwrap_err();
+            assert_eq!(err.to_string(), INVALID_ATTR);
+
+            assert!(expand(ast).contains("compile_error"));
+        }
+
+        let not_idents: DeriveInput = parse_quote! {
+            #[module("chan_open_ack")]
+            struct DummyModule;
+        };
+
+        assert!(optional_callbacks(&not_idents).is_err());
+        assert!(expand(not_idents).contains("compile_error"));
+    }
+
+    #[test]
+    fn test_module_derive_delegates_callbacks() {
+        let required = [
+            "on_chan_open_init_validate",
+            "on_chan_open_init_execute",
+            "on_chan_open_try_validate",
+            "on_chan_open_try_execute",
+            "on_recv_packet_execute",
+            "on_acknowledgement_packet_validate",
+            "on_acknowledgement_packet_execute",
+            "on_timeout_packet_validate",
+            "on_timeout_packet_execute",
+        ];
+
+        let optional: Vec<_> = OPTIONAL_CALLBACKS
+            .iter()
+            .flat_map(|callback| {
+                [
+                    format!("on_{callback}_validate"),
+                    format!("on_{callback}_execute"),
+                ]
+            })
+            .collect();
+
+        let delegation = |callback: &str| format!("Self :: {callback} (self");
+
+        let expanded = expand(parse_quote! {
+            #[module(chan_open_ack, chan_open_confirm, chan_close_init, chan_close_confirm)]
+            struct DummyModule;
+        });
+
+        assert!(!expanded.contains("compile_error"));
+
+        for callback in required
+            .iter()
+            .copied()
+            .chain(optional.iter().map(String::as_str))
+        {
+            assert!(
+                expanded.contains(&delegation(callback)),
+                "`{callback}` is not delegated"
+            );
+        }
+
+        let expanded = expand(parse_quote! {
+            struct DummyModule;
+        });
+
+        for callback in required {
+            assert!(
+                expanded.contains(&delegation(callback)),
+                "`{callback}` is not delegated"
+            );
+        }
+
+        for callback in &optional {
+            assert!(
+                !expanded.contains(callback.as_str()),
+                "`{callback}` is delegated without being listed"
+            );
+        }
+    }
+}
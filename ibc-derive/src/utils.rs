@@ -106,6 +106,76 @@ impl Imports {
         let prefix = self.prefix();
         quote! {#prefix::client::types::Status}
     }
+
+    pub fn update_client_telemetry(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::client::types::events::UpdateClientTelemetry}
+    }
+
+    pub fn module(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::router::module::Module}
+    }
+
+    pub fn module_extras(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::router::types::module::ModuleExtras}
+    }
+
+    pub fn order(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::channel::Order}
+    }
+
+    pub fn counterparty(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::channel::Counterparty}
+    }
+
+    pub fn version(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::Version}
+    }
+
+    pub fn packet(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::packet::Packet}
+    }
+
+    pub fn acknowledgement(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::acknowledgement::Acknowledgement}
+    }
+
+    pub fn channel_error(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::error::ChannelError}
+    }
+
+    pub fn packet_error(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::channel::types::error::PacketError}
+    }
+
+    pub fn connection_id(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::host::types::identifiers::ConnectionId}
+    }
+
+    pub fn port_id(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::host::types::identifiers::PortId}
+    }
+
+    pub fn channel_id(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::host::types::identifiers::ChannelId}
+    }
+
+    pub fn signer(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::primitives::Signer}
+    }
 }
 
 /// Retrieves the field of a given enum variant. Outputs an error message if the enum variant
@@ -0,0 +1,22 @@
+use core::time::Duration;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use ibc_proto::google::protobuf::Any;
+
+use crate::prelude::*;
+
+/// Generates an arbitrary `Any`, for use with `#[arbitrary(with = ...)]` on
+/// the message fields that carry an encoded client state, consensus state,
+/// header or misbehaviour.
+pub fn any(u: &mut Unstructured<'_>) -> Result<Any> {
+    Ok(Any {
+        type_url: String::arbitrary(u)?,
+        value: Vec::arbitrary(u)?,
+    })
+}
+
+/// Generates an arbitrary `Duration` of at most `u64::MAX` nanoseconds, for
+/// use with `#[arbitrary(with = ...)]` on the fields encoded as nanoseconds.
+pub fn duration(u: &mut Unstructured<'_>) -> Result<Duration> {
+    Ok(Duration::from_nanos(u64::arbitrary(u)?))
+}
@@ -30,6 +30,11 @@ pub use types::*;
 #[cfg(feature = "serde")]
 pub mod serializers;
 
+// Helpers for generating, from fuzzer input, the foreign types embedded in IBC
+// messages.
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+
 pub mod proto {
     pub use ibc_proto::google::protobuf::{Any, Duration, Timestamp};
     pub use ibc_proto::Protobuf;
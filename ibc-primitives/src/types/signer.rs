@@ -1,4 +1,5 @@
 use derive_more::Display;
+use displaydoc::Display as DisplayDoc;
 
 use crate::prelude::*;
 
@@ -17,9 +18,61 @@ use crate::prelude::*;
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 pub struct Signer(String);
 
+/// How the signers of the IBC messages are checked by the host, see
+/// `ValidationContext::signer_validation_mode`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SignerValidationMode {
+    /// Accepts any signer, leaving its checks to the application logic.
+    #[default]
+    Any,
+    /// Accepts only the bech32 addresses with the given human-readable part,
+    /// e.g. `cosmos`.
+    #[cfg(feature = "bech32")]
+    Bech32 { prefix: String },
+}
+
+impl Signer {
+    /// Checks the signer according to the given validation mode.
+    pub fn validate(&self, mode: &SignerValidationMode) -> Result<(), SignerError> {
+        match mode {
+            SignerValidationMode::Any => Ok(()),
+            #[cfg(feature = "bech32")]
+            SignerValidationMode::Bech32 { prefix } => self.decode_bech32(prefix).map(|_| ()),
+        }
+    }
+
+    /// Checks that the signer is a bech32 address with the human-readable
+    /// part `prefix`, e.g. `cosmos`, and returns the decoded address bytes.
+    ///
+    /// This is the check of the [`SignerValidationMode::Bech32`] mode.
+    #[cfg(feature = "bech32")]
+    pub fn decode_bech32(&self, prefix: &str) -> Result<Vec<u8>, SignerError> {
+        if self.0.is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        let (actual_prefix, bytes) =
+            subtle_encoding::bech32::decode(&self.0).map_err(|e| SignerError::InvalidBech32 {
+                signer: self.0.clone(),
+                description: e.to_string(),
+            })?;
+
+        if actual_prefix != prefix {
+            return Err(SignerError::MismatchedPrefix {
+                expected: prefix.to_string(),
+                actual: actual_prefix,
+            });
+        }
+
+        Ok(bytes)
+    }
+}
+
 impl From<String> for Signer {
     fn from(s: String) -> Self {
         Self(s)
@@ -31,3 +84,55 @@ impl AsRef<str> for Signer {
         self.0.as_str()
     }
 }
+
+#[derive(Debug, DisplayDoc)]
+pub enum SignerError {
+    /// signer cannot be empty
+    EmptySigner,
+    /// signer `{signer}` is not a valid bech32 address: `{description}`
+    InvalidBech32 { signer: String, description: String },
+    /// mismatched bech32 prefix: expected `{expected}`, actual `{actual}`
+    MismatchedPrefix { expected: String, actual: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+#[cfg(all(test, feature = "bech32"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bech32_signer() {
+        let address = [7u8; 20];
+        let signer = Signer::from(subtle_encoding::bech32::encode("cosmos", address));
+
+        assert_eq!(signer.decode_bech32("cosmos").unwrap(), address.to_vec());
+        assert!(matches!(
+            signer.decode_bech32("osmo"),
+            Err(SignerError::MismatchedPrefix { .. })
+        ));
+        assert!(matches!(
+            Signer::from("cosmos1invalid".to_string()).decode_bech32("cosmos"),
+            Err(SignerError::InvalidBech32 { .. })
+        ));
+        assert!(matches!(
+            Signer::from(String::new()).decode_bech32("cosmos"),
+            Err(SignerError::EmptySigner)
+        ));
+    }
+
+    #[test]
+    fn test_validate_signer() {
+        let signer = Signer::from("not a bech32 address".to_string());
+        let bech32 = SignerValidationMode::Bech32 {
+            prefix: "cosmos".to_string(),
+        };
+
+        assert!(signer.validate(&SignerValidationMode::Any).is_ok());
+        assert!(signer.validate(&bech32).is_err());
+
+        let signer = Signer::from(subtle_encoding::bech32::encode("cosmos", [7u8; 20]));
+        assert!(signer.validate(&bech32).is_ok());
+    }
+}
@@ -53,6 +53,16 @@ impl borsh::BorshDeserialize for Timestamp {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Every `u64` is a valid number of nanoseconds, with 0 standing for
+        // the absence of a timestamp.
+        Timestamp::from_nanoseconds(u64::arbitrary(u)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(feature = "parity-scale-codec")]
 impl parity_scale_codec::Encode for Timestamp {
     fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
@@ -185,7 +195,12 @@ impl Timestamp {
         })
     }
 
-    /// Convert a `Timestamp` to an optional [`OffsetDateTime`]
+    /// Convert a `Timestamp` to an optional [`OffsetDateTime`] in UTC.
+    ///
+    /// Both types have a nanosecond precision, so that the conversion is
+    /// lossless, and converting the result back with
+    /// [`Timestamp::try_from`] yields the same timestamp. Returns `None` if
+    /// the timestamp is not set.
     pub fn into_datetime(self) -> Option<OffsetDateTime> {
         self.time.map(Into::into)
     }
@@ -214,6 +229,79 @@ impl Timestamp {
     pub fn is_set(&self) -> bool {
         self.time.is_some()
     }
+
+    /// Returns the latest timestamp representable in IBC, i.e. `u64::MAX`
+    /// nanoseconds since the Unix epoch.
+    pub fn max_value() -> Self {
+        Timestamp::from_nanoseconds(u64::MAX).expect("Never fails")
+    }
+
+    /// Returns the earliest timestamp representable in IBC, i.e. one
+    /// nanosecond since the Unix epoch, as zero denotes an unset timestamp.
+    pub fn min_value() -> Self {
+        Timestamp::from_nanoseconds(1).expect("Never fails")
+    }
+
+    /// Adds `duration` to the timestamp. Returns `None` if the timestamp is
+    /// not set, or if the result is not representable in IBC.
+    pub fn checked_add(self, duration: Duration) -> Option<Timestamp> {
+        let time = (self.time? + duration).ok()?;
+
+        Self::representable(time)
+    }
+
+    /// Subtracts `duration` from the timestamp. Returns `None` if the
+    /// timestamp is not set, or if the result is not representable in IBC.
+    pub fn checked_sub(self, duration: Duration) -> Option<Timestamp> {
+        let time = (self.time? - duration).ok()?;
+
+        Self::representable(time)
+    }
+
+    /// Adds `duration` to the timestamp, saturating at
+    /// [`Timestamp::max_value`]. An unset timestamp is left unset.
+    pub fn saturating_add(self, duration: Duration) -> Timestamp {
+        if !self.is_set() {
+            return self;
+        }
+
+        self.checked_add(duration).unwrap_or_else(Self::max_value)
+    }
+
+    /// Subtracts `duration` from the timestamp, saturating at
+    /// [`Timestamp::min_value`]. An unset timestamp is left unset.
+    pub fn saturating_sub(self, duration: Duration) -> Timestamp {
+        if !self.is_set() {
+            return self;
+        }
+
+        self.checked_sub(duration).unwrap_or_else(Self::min_value)
+    }
+
+    /// Computes the duration elapsed since `earlier`, as
+    /// [`Timestamp::duration_since`], but returns a zero duration if
+    /// `earlier` is more advanced or if either of the timestamps is not set.
+    pub fn saturating_duration_since(&self, earlier: &Timestamp) -> Duration {
+        self.duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Checks whether at least `duration` has elapsed since `earlier`, e.g.
+    /// whether a consensus state issued at `earlier` has outlived a trusting
+    /// period. Returns `false` if `earlier` is more advanced or if either of
+    /// the timestamps is not set.
+    pub fn has_elapsed_since(&self, earlier: &Timestamp, duration: Duration) -> bool {
+        self.duration_since(earlier)
+            .map_or(false, |elapsed| elapsed >= duration)
+    }
+
+    /// Returns the timestamp of `time` if it can be represented in IBC as a
+    /// positive `u64` number of nanoseconds.
+    fn representable(time: Time) -> Option<Timestamp> {
+        let nanoseconds = OffsetDateTime::from(time).unix_timestamp_nanos();
+
+        (nanoseconds > 0 && nanoseconds <= i128::from(u64::MAX))
+            .then_some(Timestamp { time: Some(time) })
+    }
 }
 
 impl Display for Timestamp {
@@ -302,6 +390,30 @@ impl From<Time> for Timestamp {
     }
 }
 
+/// Converts an [`OffsetDateTime`] to a `Timestamp`, keeping its nanosecond
+/// precision. The offset of the date-time is irrelevant, as both types denote
+/// an instant.
+///
+/// Fails if the date-time is not representable as a positive `u64` number of
+/// nanoseconds since the Unix epoch, e.g. if it is the Unix epoch itself,
+/// which IBC uses to denote an unset timestamp.
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = ParseTimestampError;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        let nanoseconds = u64::try_from(date_time.unix_timestamp_nanos())
+            .map_err(|e| ParseTimestampError::DataOutOfRange(e.to_string()))?;
+
+        if nanoseconds == 0 {
+            return Err(ParseTimestampError::DataOutOfRange(String::from(
+                "the Unix epoch denotes an unset timestamp",
+            )));
+        }
+
+        Timestamp::from_nanoseconds(nanoseconds)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::time::Duration;
@@ -363,6 +475,85 @@ mod tests {
         assert_eq!(time0, (time0 - duration).unwrap());
     }
 
+    #[test]
+    fn test_timestamp_checked_arithmetic() {
+        let time0 = Timestamp::none();
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let duration = Duration::from_nanos(50);
+
+        assert_eq!(
+            time1.checked_add(duration),
+            Timestamp::from_nanoseconds(150).ok()
+        );
+        assert_eq!(
+            time1.checked_sub(duration),
+            Timestamp::from_nanoseconds(50).ok()
+        );
+        assert_eq!(time1.checked_sub(Duration::from_nanos(100)), None);
+        assert_eq!(
+            Timestamp::max_value().checked_add(Duration::from_nanos(1)),
+            None
+        );
+        assert_eq!(time0.checked_add(duration), None);
+        assert_eq!(time0.checked_sub(duration), None);
+
+        assert_eq!(
+            time1.saturating_add(Duration::from_nanos(u64::MAX)),
+            Timestamp::max_value()
+        );
+        assert_eq!(
+            time1.saturating_sub(Duration::from_nanos(200)),
+            Timestamp::min_value()
+        );
+        assert_eq!(time0.saturating_add(duration), time0);
+        assert_eq!(time0.saturating_sub(duration), time0);
+    }
+
+    #[test]
+    fn test_timestamp_duration_comparisons() {
+        let time0 = Timestamp::none();
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let time2 = Timestamp::from_nanoseconds(150).unwrap();
+        let duration = Duration::from_nanos(50);
+
+        assert_eq!(time2.saturating_duration_since(&time1), duration);
+        assert_eq!(time1.saturating_duration_since(&time2), ZERO_DURATION);
+        assert_eq!(time2.saturating_duration_since(&time0), ZERO_DURATION);
+
+        assert!(time2.has_elapsed_since(&time1, duration));
+        assert!(!time2.has_elapsed_since(&time1, Duration::from_nanos(51)));
+        assert!(!time1.has_elapsed_since(&time2, ZERO_DURATION));
+        assert!(!time0.has_elapsed_since(&time1, ZERO_DURATION));
+    }
+
+    #[test]
+    fn test_timestamp_datetime_conversions() {
+        let nanoseconds = 1_700_000_000_123_456_789;
+        let timestamp = Timestamp::from_nanoseconds(nanoseconds).unwrap();
+
+        let date_time = timestamp.into_datetime().unwrap();
+        assert_eq!(date_time.unix_timestamp_nanos(), i128::from(nanoseconds));
+        assert_eq!(Timestamp::try_from(date_time).unwrap(), timestamp);
+
+        let offset = time::UtcOffset::from_hms(2, 0, 0).unwrap();
+        assert_eq!(
+            Timestamp::try_from(date_time.to_offset(offset)).unwrap(),
+            timestamp
+        );
+
+        assert_eq!(Timestamp::none().into_datetime(), None);
+        assert!(Timestamp::try_from(OffsetDateTime::UNIX_EPOCH).is_err());
+        assert!(Timestamp::try_from(OffsetDateTime::UNIX_EPOCH - Duration::from_nanos(1)).is_err());
+        assert_eq!(
+            Timestamp::try_from(Timestamp::max_value().into_datetime().unwrap()).unwrap(),
+            Timestamp::max_value()
+        );
+        assert!(Timestamp::try_from(
+            Timestamp::max_value().into_datetime().unwrap() + Duration::from_nanos(1)
+        )
+        .is_err());
+    }
+
     #[test]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
@@ -0,0 +1,230 @@
+//! Provides [`query_abci_store`], which answers the ABCI store queries of the
+//! IBC store, as sent by relayers querying the states of the host and their
+//! proofs through the ABCI `abci_query` endpoint of the node rather than
+//! through the gRPC services, e.g. Hermes by default.
+//!
+//! As with the Cosmos SDK, a request queries the value stored under the IBC
+//! path encoded in its `data` at the path `store/ibc/key`, and a missing value
+//! is returned as an empty one, along with the proof of its absence if a
+//! proof is requested.
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc_query::abci::{query_abci_store, AbciQueryRequest};
+//!
+//! // In the `query` method of the ABCI application:
+//! let response = query_abci_store(
+//!     &ibc,
+//!     &AbciQueryRequest {
+//!         path: request.path,
+//!         data: request.data.to_vec(),
+//!         height: request.height,
+//!         prove: request.prove,
+//!     },
+//! )?;
+//! ```
+
+use core::str::FromStr;
+
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::host::types::path::Path;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::ToVec;
+use ibc::cosmos_host::IBC_QUERY_PATH;
+use ibc_proto::google::protobuf::Any;
+
+use crate::core::context::ProvableContext;
+use crate::error::QueryError;
+
+/// The name of the IBC store, which keys the proof of the IBC store in the
+/// multistore of the host.
+pub const IBC_STORE_NAME: &str = "ibc";
+
+/// An ABCI query of the IBC store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbciQueryRequest {
+    /// Must be [`IBC_QUERY_PATH`].
+    pub path: String,
+    /// The IBC path of the queried value, e.g. `connections/connection-0`.
+    pub data: Vec<u8>,
+    /// The queried height, or 0 for the latest height. Only the latest height
+    /// can be queried.
+    pub height: u64,
+    /// Whether the proof of the value, or of its absence, is returned.
+    pub prove: bool,
+}
+
+/// A proof operation of an [`AbciQueryResponse`], in the format of the
+/// `ProofOp` of Tendermint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbciProofOp {
+    pub field_type: String,
+    pub key: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+/// The answer to an [`AbciQueryRequest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbciQueryResponse {
+    pub key: Vec<u8>,
+    /// The protobuf encoding of the value, or an empty value if it is not
+    /// stored.
+    pub value: Vec<u8>,
+    /// The proof of the IBC store and of the multistore, from the leaf to the
+    /// root, if requested.
+    pub proof_ops: Vec<AbciProofOp>,
+    pub height: u64,
+}
+
+/// Answers an ABCI query of the IBC store, with the value stored under the
+/// queried path and, if requested, its proof as returned by the host.
+pub fn query_abci_store<I>(
+    ibc_ctx: &I,
+    request: &AbciQueryRequest,
+) -> Result<AbciQueryResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    if request.path != IBC_QUERY_PATH {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "unsupported ABCI query path `{}`: only `{IBC_QUERY_PATH}` is supported",
+                request.path
+            ),
+        });
+    }
+
+    let host_height = ibc_ctx.host_height()?;
+
+    if request.height != 0 && request.height != host_height.revision_height() {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "cannot query the IBC store at height {}: only the latest height {} can be queried",
+                request.height,
+                host_height.revision_height()
+            ),
+        });
+    }
+
+    let path = core::str::from_utf8(&request.data)
+        .ok()
+        .and_then(|path| Path::from_str(path).ok())
+        .ok_or_else(|| QueryError::InvalidRequest {
+            description: "the queried key is not an IBC path".to_string(),
+        })?;
+
+    let value = stored_value(ibc_ctx, &path)?;
+
+    let proof_ops = if request.prove {
+        let proof =
+            ibc_ctx
+                .get_proof(host_height, &path)
+                .ok_or_else(|| QueryError::ProofNotFound {
+                    description: format!("Proof not found for path: {path}"),
+                })?;
+
+        proof_ops(&request.data, proof)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(AbciQueryResponse {
+        key: request.data.clone(),
+        value,
+        proof_ops,
+        height: host_height.revision_height(),
+    })
+}
+
+/// Returns the protobuf encoding of the value stored under `path`, as stored
+/// by the Cosmos SDK, or an empty value if there is none.
+fn stored_value<I>(ibc_ctx: &I, path: &Path) -> Result<Vec<u8>, QueryError>
+where
+    I: ValidationContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    // Missing values are not errors, but are proven absent.
+    let value = match path {
+        Path::ClientState(path) => ibc_ctx
+            .client_state(&path.0)
+            .ok()
+            .map(|client_state| client_state.into().to_vec()),
+        Path::ClientConsensusState(path) => ibc_ctx
+            .consensus_state(path)
+            .ok()
+            .map(|consensus_state| consensus_state.into().to_vec()),
+        Path::Connection(path) => ibc_ctx
+            .connection_end(&path.0)
+            .ok()
+            .map(|connection_end| connection_end.encode_vec()),
+        Path::ChannelEnd(path) => ibc_ctx
+            .channel_end(path)
+            .ok()
+            .map(|channel_end| channel_end.encode_vec()),
+        Path::SeqSend(path) => ibc_ctx
+            .get_next_sequence_send(path)
+            .ok()
+            .map(|sequence| sequence.value().to_be_bytes().to_vec()),
+        Path::SeqRecv(path) => ibc_ctx
+            .get_next_sequence_recv(path)
+            .ok()
+            .map(|sequence| sequence.value().to_be_bytes().to_vec()),
+        Path::SeqAck(path) => ibc_ctx
+            .get_next_sequence_ack(path)
+            .ok()
+            .map(|sequence| sequence.value().to_be_bytes().to_vec()),
+        Path::Commitment(path) => ibc_ctx
+            .get_packet_commitment(path)
+            .ok()
+            .map(|commitment| commitment.into_vec()),
+        Path::Ack(path) => ibc_ctx
+            .get_packet_acknowledgement(path)
+            .ok()
+            .map(|acknowledgement| acknowledgement.into_vec()),
+        // Receipt only has one enum, stored as a single byte
+        Path::Receipt(path) => ibc_ctx.get_packet_receipt(path).ok().map(|_| vec![1]),
+        _ => {
+            return Err(QueryError::InvalidRequest {
+                description: format!("querying the value of `{path}` is not supported"),
+            })
+        }
+    };
+
+    Ok(value.unwrap_or_default())
+}
+
+/// Splits the Merkle proof returned by the host into the proof operations of
+/// the IBC store and of the multistore, as expected by the relayers.
+fn proof_ops(key: &[u8], proof: Vec<u8>) -> Result<Vec<AbciProofOp>, QueryError> {
+    let merkle_proof = CommitmentProofBytes::try_from(proof)
+        .ok()
+        .and_then(|proof| MerkleProof::try_from(&proof).ok())
+        .ok_or_else(|| QueryError::ProofNotFound {
+            description: "the proof returned by the host is not a Merkle proof".to_string(),
+        })?;
+
+    Ok(merkle_proof
+        .proofs
+        .iter()
+        .enumerate()
+        .map(|(i, proof)| {
+            let (field_type, key) = if i == 0 {
+                ("ics23:iavl", key.to_vec())
+            } else {
+                ("ics23:simple", IBC_STORE_NAME.as_bytes().to_vec())
+            };
+
+            AbciProofOp {
+                field_type: field_type.to_string(),
+                key,
+                data: proof.to_vec(),
+            }
+        })
+        .collect())
+}
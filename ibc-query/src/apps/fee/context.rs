@@ -0,0 +1,69 @@
+//! Required trait for the blanket implementation of the
+//! [`FeeQueryService`](super::FeeQueryService).
+
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::{IdentifiedPacketFees, PacketFee, PacketId};
+
+/// Context to be implemented by the host running the fee middleware (ICS-29)
+/// to provide the fee queries.
+pub trait FeeQueryContext {
+    /// Returns the fees escrowed for all the incentivized packets, i.e. the
+    /// packets whose fees are not distributed yet.
+    fn incentivized_packets(&self) -> Result<Vec<IdentifiedPacketFees>, ContextError>;
+
+    /// Returns the fees escrowed for the incentivized packets sent on the
+    /// given channel.
+    fn incentivized_packets_for_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, ContextError> {
+        Ok(self
+            .incentivized_packets()?
+            .into_iter()
+            .filter(|packet_fees| {
+                &packet_fees.packet_id.port_id == port_id
+                    && &packet_fees.packet_id.channel_id == channel_id
+            })
+            .collect())
+    }
+
+    /// Returns the fees escrowed for the given packet, or `None` if the
+    /// packet is not incentivized.
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Option<Vec<PacketFee>>, ContextError>;
+
+    /// Returns the payee registered by the given relayer on the given channel
+    /// to receive the fees, if any.
+    fn payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, ContextError>;
+
+    /// Returns the address registered by the given relayer on the given
+    /// channel to receive the fees on the counterparty chain, if any.
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, ContextError>;
+
+    /// Returns all the channels on which the fee middleware is enabled.
+    fn fee_enabled_channels(&self) -> Result<Vec<(PortId, ChannelId)>, ContextError>;
+
+    /// Returns whether the fee middleware is enabled on the given channel.
+    fn is_fee_enabled(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<bool, ContextError> {
+        Ok(self
+            .fee_enabled_channels()?
+            .iter()
+            .any(|(p, c)| p == port_id && c == channel_id))
+    }
+}
@@ -0,0 +1,22 @@
+//! Provides the gRPC query service of the fee middleware (ICS-29), through
+//! which relayers discover the fees escrowed for the packets they relay and
+//! the payees registered to receive them.
+//!
+//! The fees are kept by the fee middleware of the host, which exposes them to
+//! the [`FeeQueryService`] by implementing [`FeeQueryContext`], e.g.
+//! ```rust,ignore
+//! use ibc_proto::ibc::applications::fee::v1::query_server::QueryServer as FeeQueryServer;
+//! use ibc_query::apps::fee::FeeQueryService;
+//!
+//! let fee_service = FeeQueryServer::new(FeeQueryService::new(fee_middleware));
+//! ```
+
+mod context;
+mod query;
+mod service;
+mod types;
+
+pub use context::*;
+pub use query::*;
+pub use service::*;
+pub use types::*;
@@ -0,0 +1,311 @@
+//! Provides utility functions for querying the fees of the incentivized
+//! packets.
+
+use core::str::FromStr;
+
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+use ibc_proto::ibc::applications::fee::v1::{
+    FeeEnabledChannel, IdentifiedPacketFees as RawIdentifiedPacketFees,
+    QueryCounterpartyPayeeRequest, QueryCounterpartyPayeeResponse, QueryFeeEnabledChannelRequest,
+    QueryFeeEnabledChannelResponse, QueryFeeEnabledChannelsRequest,
+    QueryFeeEnabledChannelsResponse, QueryIncentivizedPacketRequest,
+    QueryIncentivizedPacketResponse, QueryIncentivizedPacketsForChannelRequest,
+    QueryIncentivizedPacketsForChannelResponse, QueryIncentivizedPacketsRequest,
+    QueryIncentivizedPacketsResponse, QueryPayeeRequest, QueryPayeeResponse,
+    QueryTotalAckFeesRequest, QueryTotalAckFeesResponse, QueryTotalRecvFeesRequest,
+    QueryTotalRecvFeesResponse, QueryTotalTimeoutFeesRequest, QueryTotalTimeoutFeesResponse,
+};
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+
+use super::{Fee, FeeQueryContext, IdentifiedPacketFees, PacketFee, PacketId};
+use crate::error::QueryError;
+use crate::pagination::{paginate, with_total};
+
+/// Queries for the fees of all the incentivized packets.
+pub fn query_incentivized_packets<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketsRequest,
+) -> Result<QueryIncentivizedPacketsResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let (incentivized_packets, pagination) =
+        paginate_packet_fees(fee_ctx.incentivized_packets()?, request.pagination.as_ref())?;
+
+    Ok(QueryIncentivizedPacketsResponse {
+        incentivized_packets,
+        pagination,
+    })
+}
+
+/// Queries for the fees of the given incentivized packet.
+pub fn query_incentivized_packet<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketRequest,
+) -> Result<QueryIncentivizedPacketResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let packet_id = parse_packet_id(request.packet_id.clone())?;
+
+    let packet_fees = incentivized_packet_fees(fee_ctx, &packet_id)?;
+
+    Ok(QueryIncentivizedPacketResponse {
+        incentivized_packet: Some(
+            IdentifiedPacketFees {
+                packet_id,
+                packet_fees,
+            }
+            .into(),
+        ),
+    })
+}
+
+/// Queries for the fees of the incentivized packets sent on the given channel.
+pub fn query_incentivized_packets_for_channel<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketsForChannelRequest,
+) -> Result<QueryIncentivizedPacketsForChannelResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let (incentivized_packets, pagination) = paginate_packet_fees(
+        fee_ctx.incentivized_packets_for_channel(&port_id, &channel_id)?,
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryIncentivizedPacketsForChannelResponse {
+        incentivized_packets,
+        pagination,
+    })
+}
+
+/// Queries for the total of the receipt fees escrowed for the given packet.
+pub fn query_total_recv_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalRecvFeesRequest,
+) -> Result<QueryTotalRecvFeesResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    Ok(QueryTotalRecvFeesResponse {
+        recv_fees: total_fees(fee_ctx, request.packet_id.clone(), |fee| fee.recv_fee)?,
+    })
+}
+
+/// Queries for the total of the acknowledgement fees escrowed for the given
+/// packet.
+pub fn query_total_ack_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalAckFeesRequest,
+) -> Result<QueryTotalAckFeesResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    Ok(QueryTotalAckFeesResponse {
+        ack_fees: total_fees(fee_ctx, request.packet_id.clone(), |fee| fee.ack_fee)?,
+    })
+}
+
+/// Queries for the total of the timeout fees escrowed for the given packet.
+pub fn query_total_timeout_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalTimeoutFeesRequest,
+) -> Result<QueryTotalTimeoutFeesResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    Ok(QueryTotalTimeoutFeesResponse {
+        timeout_fees: total_fees(fee_ctx, request.packet_id.clone(), |fee| fee.timeout_fee)?,
+    })
+}
+
+/// Queries for the payee registered by a relayer on a channel.
+pub fn query_payee<I>(
+    fee_ctx: &I,
+    request: &QueryPayeeRequest,
+) -> Result<QueryPayeeResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let relayer = Signer::from(request.relayer.clone());
+
+    let payee = fee_ctx
+        .payee(&channel_id, &relayer)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!("payee of relayer {relayer} on channel {channel_id}"),
+        })?;
+
+    Ok(QueryPayeeResponse {
+        payee_address: payee.to_string(),
+    })
+}
+
+/// Queries for the counterparty payee registered by a relayer on a channel.
+pub fn query_counterparty_payee<I>(
+    fee_ctx: &I,
+    request: &QueryCounterpartyPayeeRequest,
+) -> Result<QueryCounterpartyPayeeResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let relayer = Signer::from(request.relayer.clone());
+
+    let counterparty_payee = fee_ctx
+        .counterparty_payee(&channel_id, &relayer)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!("counterparty payee of relayer {relayer} on channel {channel_id}"),
+        })?;
+
+    Ok(QueryCounterpartyPayeeResponse {
+        counterparty_payee: counterparty_payee.to_string(),
+    })
+}
+
+/// Queries for all the channels on which the fee middleware is enabled.
+pub fn query_fee_enabled_channels<I>(
+    fee_ctx: &I,
+    request: &QueryFeeEnabledChannelsRequest,
+) -> Result<QueryFeeEnabledChannelsResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let channels = fee_ctx.fee_enabled_channels()?;
+    let total = channels.len() as u64;
+
+    let (channels, pagination) = paginate(
+        channels,
+        |(port_id, channel_id)| format!("feeEnabled/{port_id}/{channel_id}").into_bytes(),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryFeeEnabledChannelsResponse {
+        fee_enabled_channels: channels
+            .into_iter()
+            .map(|(port_id, channel_id)| FeeEnabledChannel {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            })
+            .collect(),
+        pagination: with_total(pagination, request.pagination.as_ref(), || Ok(total))?,
+    })
+}
+
+/// Queries for whether the fee middleware is enabled on a channel.
+pub fn query_fee_enabled_channel<I>(
+    fee_ctx: &I,
+    request: &QueryFeeEnabledChannelRequest,
+) -> Result<QueryFeeEnabledChannelResponse, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    Ok(QueryFeeEnabledChannelResponse {
+        fee_enabled: fee_ctx.is_fee_enabled(&port_id, &channel_id)?,
+    })
+}
+
+fn parse_packet_id(packet_id: Option<RawPacketId>) -> Result<PacketId, QueryError> {
+    packet_id
+        .ok_or_else(|| QueryError::InvalidRequest {
+            description: "missing packet id".to_string(),
+        })?
+        .try_into()
+}
+
+fn incentivized_packet_fees<I>(
+    fee_ctx: &I,
+    packet_id: &PacketId,
+) -> Result<Vec<PacketFee>, QueryError>
+where
+    I: FeeQueryContext,
+{
+    fee_ctx
+        .packet_fees(packet_id)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!(
+                "fees of packet {} on channel {}/{}",
+                packet_id.sequence, packet_id.port_id, packet_id.channel_id
+            ),
+        })
+}
+
+/// Paginates the fees of the incentivized packets, keyed as in the escrow
+/// store of ibc-go.
+fn paginate_packet_fees(
+    packet_fees: Vec<IdentifiedPacketFees>,
+    page_request: Option<&PageRequest>,
+) -> Result<(Vec<RawIdentifiedPacketFees>, Option<PageResponse>), QueryError> {
+    let total = packet_fees.len() as u64;
+
+    let (packet_fees, pagination) = paginate(
+        packet_fees,
+        |packet_fees| {
+            let packet_id = &packet_fees.packet_id;
+            format!(
+                "feesInEscrow/{}/{}/{}",
+                packet_id.port_id, packet_id.channel_id, packet_id.sequence
+            )
+            .into_bytes()
+        },
+        page_request,
+    )?;
+
+    Ok((
+        packet_fees.into_iter().map(Into::into).collect(),
+        with_total(pagination, page_request, || Ok(total))?,
+    ))
+}
+
+/// Sums, per denomination, the fees selected by `fee_of` among all the fees
+/// escrowed for the given packet, sorted by denomination as the Cosmos SDK
+/// does.
+fn total_fees<I>(
+    fee_ctx: &I,
+    packet_id: Option<RawPacketId>,
+    fee_of: impl Fn(Fee) -> Vec<PrefixedCoin>,
+) -> Result<Vec<RawCoin>, QueryError>
+where
+    I: FeeQueryContext,
+{
+    let packet_id = parse_packet_id(packet_id)?;
+
+    let mut totals: BTreeMap<String, PrefixedCoin> = BTreeMap::new();
+
+    for coin in incentivized_packet_fees(fee_ctx, &packet_id)?
+        .into_iter()
+        .flat_map(|packet_fee| fee_of(packet_fee.fee))
+    {
+        let denom = coin.denom.to_string();
+
+        match totals.get_mut(&denom) {
+            Some(total) => {
+                total.amount = total
+                    .amount
+                    .checked_add(coin.amount)
+                    .ok_or(QueryError::AmountOverflow { denom })?;
+            }
+            None => {
+                totals.insert(denom, coin);
+            }
+        }
+    }
+
+    Ok(totals.into_values().map(RawCoin::from).collect())
+}
@@ -0,0 +1,144 @@
+//! [`FeeQueryService`](FeeQueryService) takes a generic `I` to store `fee_context` that implements [`FeeQueryContext`](FeeQueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::applications::fee::v1::query_server::Query as FeeQuery;
+use ibc_proto::ibc::applications::fee::v1::{
+    QueryCounterpartyPayeeRequest, QueryCounterpartyPayeeResponse, QueryFeeEnabledChannelRequest,
+    QueryFeeEnabledChannelResponse, QueryFeeEnabledChannelsRequest,
+    QueryFeeEnabledChannelsResponse, QueryIncentivizedPacketRequest,
+    QueryIncentivizedPacketResponse, QueryIncentivizedPacketsForChannelRequest,
+    QueryIncentivizedPacketsForChannelResponse, QueryIncentivizedPacketsRequest,
+    QueryIncentivizedPacketsResponse, QueryPayeeRequest, QueryPayeeResponse,
+    QueryTotalAckFeesRequest, QueryTotalAckFeesResponse, QueryTotalRecvFeesRequest,
+    QueryTotalRecvFeesResponse, QueryTotalTimeoutFeesRequest, QueryTotalTimeoutFeesResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_counterparty_payee, query_fee_enabled_channel, query_fee_enabled_channels,
+    query_incentivized_packet, query_incentivized_packets, query_incentivized_packets_for_channel,
+    query_payee, query_total_ack_fees, query_total_recv_fees, query_total_timeout_fees,
+    FeeQueryContext,
+};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+///
+/// The `query_height` of the requests is ignored, as in ibc-go: hosts serve
+/// the queries at past heights by routing them to the matching state.
+pub struct FeeQueryService<I>
+where
+    I: FeeQueryContext + Send + Sync + 'static,
+{
+    fee_context: I,
+}
+
+impl<I> FeeQueryService<I>
+where
+    I: FeeQueryContext + Send + Sync + 'static,
+{
+    /// The parameter `fee_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(fee_context: I) -> Self {
+        Self { fee_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> FeeQuery for FeeQueryService<I>
+where
+    I: FeeQueryContext + Send + Sync + 'static,
+{
+    async fn incentivized_packets(
+        &self,
+        request: Request<QueryIncentivizedPacketsRequest>,
+    ) -> Result<Response<QueryIncentivizedPacketsResponse>, Status> {
+        let response = query_incentivized_packets(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn incentivized_packet(
+        &self,
+        request: Request<QueryIncentivizedPacketRequest>,
+    ) -> Result<Response<QueryIncentivizedPacketResponse>, Status> {
+        let response = query_incentivized_packet(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn incentivized_packets_for_channel(
+        &self,
+        request: Request<QueryIncentivizedPacketsForChannelRequest>,
+    ) -> Result<Response<QueryIncentivizedPacketsForChannelResponse>, Status> {
+        let response =
+            query_incentivized_packets_for_channel(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn total_recv_fees(
+        &self,
+        request: Request<QueryTotalRecvFeesRequest>,
+    ) -> Result<Response<QueryTotalRecvFeesResponse>, Status> {
+        let response = query_total_recv_fees(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn total_ack_fees(
+        &self,
+        request: Request<QueryTotalAckFeesRequest>,
+    ) -> Result<Response<QueryTotalAckFeesResponse>, Status> {
+        let response = query_total_ack_fees(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn total_timeout_fees(
+        &self,
+        request: Request<QueryTotalTimeoutFeesRequest>,
+    ) -> Result<Response<QueryTotalTimeoutFeesResponse>, Status> {
+        let response = query_total_timeout_fees(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn payee(
+        &self,
+        request: Request<QueryPayeeRequest>,
+    ) -> Result<Response<QueryPayeeResponse>, Status> {
+        let response = query_payee(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn counterparty_payee(
+        &self,
+        request: Request<QueryCounterpartyPayeeRequest>,
+    ) -> Result<Response<QueryCounterpartyPayeeResponse>, Status> {
+        let response = query_counterparty_payee(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn fee_enabled_channels(
+        &self,
+        request: Request<QueryFeeEnabledChannelsRequest>,
+    ) -> Result<Response<QueryFeeEnabledChannelsResponse>, Status> {
+        let response = query_fee_enabled_channels(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn fee_enabled_channel(
+        &self,
+        request: Request<QueryFeeEnabledChannelRequest>,
+    ) -> Result<Response<QueryFeeEnabledChannelResponse>, Status> {
+        let response = query_fee_enabled_channel(&self.fee_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+}
@@ -0,0 +1,109 @@
+//! Defines the fees of the incentivized packets, as exposed by the fee
+//! middleware (ICS-29) to the fee queries.
+
+use core::str::FromStr;
+
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+use ibc_proto::ibc::applications::fee::v1::{
+    Fee as RawFee, IdentifiedPacketFees as RawIdentifiedPacketFees, PacketFee as RawPacketFee,
+};
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+
+use crate::error::QueryError;
+
+/// The identifier of a packet sent by the host, under which its fees are
+/// escrowed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl TryFrom<RawPacketId> for PacketId {
+    type Error = QueryError;
+
+    fn try_from(raw: RawPacketId) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: PortId::from_str(&raw.port_id)?,
+            channel_id: ChannelId::from_str(&raw.channel_id)?,
+            sequence: Sequence::from(raw.sequence),
+        })
+    }
+}
+
+impl From<PacketId> for RawPacketId {
+    fn from(packet_id: PacketId) -> Self {
+        Self {
+            port_id: packet_id.port_id.to_string(),
+            channel_id: packet_id.channel_id.to_string(),
+            sequence: packet_id.sequence.value(),
+        }
+    }
+}
+
+/// The fees paid to the relayers of a packet on its receipt, on its
+/// acknowledgement and on its timeout.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    pub recv_fee: Vec<PrefixedCoin>,
+    pub ack_fee: Vec<PrefixedCoin>,
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        Self {
+            recv_fee: fee.recv_fee.into_iter().map(RawCoin::from).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(RawCoin::from).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(RawCoin::from).collect(),
+        }
+    }
+}
+
+/// A fee escrowed for a packet, along with the account refunded with the
+/// unspent fees and the relayers allowed to relay the packet, if restricted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    pub relayers: Vec<Signer>,
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        Self {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .into_iter()
+                .map(|relayer| relayer.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// All the fees escrowed for a packet, in the order they were paid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedPacketFees {
+    pub packet_id: PacketId,
+    pub packet_fees: Vec<PacketFee>,
+}
+
+impl From<IdentifiedPacketFees> for RawIdentifiedPacketFees {
+    fn from(packet_fees: IdentifiedPacketFees) -> Self {
+        Self {
+            packet_id: Some(packet_fees.packet_id.into()),
+            packet_fees: packet_fees
+                .packet_fees
+                .into_iter()
+                .map(RawPacketFee::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Provides [`QueryCache`], an opt-in cache for the responses of the
+//! expensive list queries, such as `channels` or `packet_commitments`.
+//!
+//! Responses are cached for the host height at which they were computed, and
+//! the whole cache is dropped as soon as a query observes a different host
+//! height. Since the host height may not change across every state
+//! transition, hosts must also call [`QueryCache::invalidate`] on every
+//! commit.
+//!
+//! The cache is only available with the `std` feature.
+//!
+//! Example
+//! ```rust,ignore
+//! use alloc::sync::Arc;
+//!
+//! use ibc_query::cache::QueryCache;
+//! use ibc_query::server::QueryServerBuilder;
+//!
+//! let cache = Arc::new(QueryCache::new());
+//!
+//! let grpc_server = QueryServerBuilder::new(ibc, upgrade)
+//!     .cache(cache.clone())
+//!     .add_to(&mut tonic::transport::Server::builder())
+//!     .serve(addr);
+//!
+//! // On every commit:
+//! cache.invalidate();
+//! ```
+
+#[cfg(feature = "std")]
+use core::any::Any;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use ibc::core::client::types::Height;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+
+use crate::error::QueryError;
+
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct CacheEntries {
+    height: Option<Height>,
+    responses: BTreeMap<String, Box<dyn Any + Send + Sync>>,
+}
+
+/// Cache of list query responses, shared by the query services it is
+/// attached to.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct QueryCache {
+    entries: Mutex<CacheEntries>,
+}
+
+/// Without the standard library no cache can be built, so that the services
+/// never hold one.
+#[cfg(not(feature = "std"))]
+pub enum QueryCache {}
+
+#[cfg(not(feature = "std"))]
+impl QueryCache {
+    fn get_or_compute<R, F>(
+        &self,
+        _height: Height,
+        _key: String,
+        _compute: F,
+    ) -> Result<R, QueryError> {
+        match *self {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl QueryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops all the cached responses. Must be called by the host on every
+    /// commit.
+    pub fn invalidate(&self) {
+        let mut entries = self.lock();
+
+        entries.height = None;
+        entries.responses.clear();
+    }
+
+    /// Returns the number of cached responses.
+    pub fn len(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().responses.is_empty()
+    }
+
+    /// Returns the response cached under `key` for the given height, or
+    /// computes and caches it. Errors are not cached.
+    pub fn get_or_compute<R, F>(
+        &self,
+        height: Height,
+        key: String,
+        compute: F,
+    ) -> Result<R, QueryError>
+    where
+        R: Clone + Send + Sync + 'static,
+        F: FnOnce() -> Result<R, QueryError>,
+    {
+        {
+            let mut entries = self.lock();
+
+            if entries.height != Some(height) {
+                entries.height = Some(height);
+                entries.responses.clear();
+            }
+
+            if let Some(response) = entries
+                .responses
+                .get(&key)
+                .and_then(|response| response.downcast_ref::<R>())
+            {
+                return Ok(response.clone());
+            }
+        }
+
+        // The lock is released while computing the response, so that other
+        // queries are not blocked by a store traversal.
+        let response = compute()?;
+
+        let mut entries = self.lock();
+
+        if entries.height == Some(height) {
+            entries.responses.insert(key, Box::new(response.clone()));
+        }
+
+        Ok(response)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheEntries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Runs the given query through the cache, if any, keyed by the current host
+/// height.
+pub(crate) fn cached_query<I, R, F>(
+    cache: Option<&QueryCache>,
+    ibc_ctx: &I,
+    key: impl FnOnce() -> String,
+    compute: F,
+) -> Result<R, QueryError>
+where
+    I: ValidationContext,
+    R: Clone + Send + Sync + 'static,
+    F: FnOnce() -> Result<R, QueryError>,
+{
+    match cache {
+        Some(cache) => cache.get_or_compute(ibc_ctx.host_height()?, key(), compute),
+        None => compute(),
+    }
+}
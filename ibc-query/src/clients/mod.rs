@@ -0,0 +1,3 @@
+//! Provides the gRPC query services of the light client modules.
+
+pub mod wasm;
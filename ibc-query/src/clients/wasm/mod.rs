@@ -0,0 +1,20 @@
+//! Provides the gRPC query service of the Wasm light client module (ICS-08),
+//! through which the checksums and the codes of the stored light client
+//! contracts are discovered.
+//!
+//! The codes are kept by the host, which exposes them to the
+//! [`WasmQueryService`] by implementing
+//! [`WasmValidationContext`](ibc::clients::wasm::context::WasmValidationContext),
+//! e.g.
+//! ```rust,ignore
+//! use ibc_proto::ibc::lightclients::wasm::v1::query_server::QueryServer as WasmQueryServer;
+//! use ibc_query::clients::wasm::WasmQueryService;
+//!
+//! let wasm_service = WasmQueryServer::new(WasmQueryService::new(ibc));
+//! ```
+
+mod query;
+mod service;
+
+pub use query::*;
+pub use service::*;
@@ -0,0 +1,57 @@
+//! Provides utility functions for querying the codes of the Wasm light
+//! clients.
+
+use ibc::clients::wasm::checksum::{checksum_from_hex, checksum_to_hex};
+use ibc::clients::wasm::context::WasmValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::lightclients::wasm::v1::{
+    QueryChecksumsRequest, QueryChecksumsResponse, QueryCodeRequest, QueryCodeResponse,
+};
+
+use crate::error::QueryError;
+use crate::pagination::{paginate, with_total};
+
+/// Queries for the hex encoded checksums of all the stored Wasm codes.
+pub fn query_checksums<I>(
+    wasm_ctx: &I,
+    request: &QueryChecksumsRequest,
+) -> Result<QueryChecksumsResponse, QueryError>
+where
+    I: WasmValidationContext,
+{
+    let checksums = wasm_ctx.checksums()?;
+
+    let total = checksums.len() as u64;
+
+    let (checksums, pagination) = paginate(checksums, Clone::clone, request.pagination.as_ref())?;
+
+    Ok(QueryChecksumsResponse {
+        checksums: checksums
+            .iter()
+            .map(|checksum| checksum_to_hex(checksum))
+            .collect(),
+        pagination: with_total(pagination, request.pagination.as_ref(), || Ok(total))?,
+    })
+}
+
+/// Queries for the Wasm code stored under the given hex encoded checksum.
+pub fn query_code<I>(
+    wasm_ctx: &I,
+    request: &QueryCodeRequest,
+) -> Result<QueryCodeResponse, QueryError>
+where
+    I: WasmValidationContext,
+{
+    let checksum =
+        checksum_from_hex(&request.checksum).map_err(|e| QueryError::InvalidRequest {
+            description: e.to_string(),
+        })?;
+
+    let data = wasm_ctx
+        .wasm_code(&checksum)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!("Wasm code with checksum {} not found", request.checksum),
+        })?;
+
+    Ok(QueryCodeResponse { data })
+}
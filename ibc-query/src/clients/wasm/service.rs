@@ -0,0 +1,57 @@
+//! [`WasmQueryService`](WasmQueryService) takes a generic `I` to store `wasm_context` that implements [`WasmValidationContext`](WasmValidationContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use ibc::clients::wasm::context::WasmValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::lightclients::wasm::v1::query_server::Query as WasmQuery;
+use ibc_proto::ibc::lightclients::wasm::v1::{
+    QueryChecksumsRequest, QueryChecksumsResponse, QueryCodeRequest, QueryCodeResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{query_checksums, query_code};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct WasmQueryService<I>
+where
+    I: WasmValidationContext + Send + Sync + 'static,
+{
+    wasm_context: I,
+}
+
+impl<I> WasmQueryService<I>
+where
+    I: WasmValidationContext + Send + Sync + 'static,
+{
+    /// The parameter `wasm_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(wasm_context: I) -> Self {
+        Self { wasm_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> WasmQuery for WasmQueryService<I>
+where
+    I: WasmValidationContext + Send + Sync + 'static,
+{
+    async fn checksums(
+        &self,
+        request: Request<QueryChecksumsRequest>,
+    ) -> Result<Response<QueryChecksumsResponse>, Status> {
+        let response = query_checksums(&self.wasm_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn code(
+        &self,
+        request: Request<QueryCodeRequest>,
+    ) -> Result<Response<QueryCodeResponse>, Status> {
+        let response = query_code(&self.wasm_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+}
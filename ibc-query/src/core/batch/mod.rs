@@ -0,0 +1,5 @@
+mod query;
+mod types;
+
+pub use query::*;
+pub use types::*;
@@ -0,0 +1,109 @@
+//! Provides the batch query, answering heterogeneous sub-queries at a single
+//! height.
+
+use ibc::core::client::types::Height;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+
+use super::{
+    BatchQuery, BatchQueryResponse, QueryBatchRequest, QueryBatchResponse, MAX_QUERIES_PER_BATCH,
+};
+use crate::core::channel::{
+    query_channel, query_next_sequence_receive, query_packet_acknowledgement,
+    query_packet_commitment, query_packet_receipt,
+};
+use crate::core::client::{query_client_state, query_consensus_state};
+use crate::core::connection::query_connection;
+use crate::core::context::QueryContext;
+use crate::error::QueryError;
+
+/// Answers the sub-queries of the `request` in order, at most
+/// [`MAX_QUERIES_PER_BATCH`] at once.
+///
+/// Fails if any sub-query fails, or if the host height changes while the
+/// sub-queries are answered, in which case the batch may be retried, so that
+/// all the returned proofs are at the same height.
+pub fn query_batch<I>(
+    ibc_ctx: &I,
+    request: &QueryBatchRequest,
+) -> Result<QueryBatchResponse, QueryError>
+where
+    I: QueryContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    if request.queries.len() > MAX_QUERIES_PER_BATCH {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "cannot answer more than {MAX_QUERIES_PER_BATCH} queries in a batch"
+            ),
+        });
+    }
+
+    let height = ibc_ctx.host_height()?;
+
+    let responses = request
+        .queries
+        .iter()
+        .map(|query| {
+            let response = match query {
+                BatchQuery::ClientState(request) => {
+                    BatchQueryResponse::ClientState(query_client_state(ibc_ctx, request)?)
+                }
+                BatchQuery::ConsensusState(request) => {
+                    BatchQueryResponse::ConsensusState(query_consensus_state(ibc_ctx, request)?)
+                }
+                BatchQuery::Connection(request) => {
+                    BatchQueryResponse::Connection(query_connection(ibc_ctx, request)?)
+                }
+                BatchQuery::Channel(request) => {
+                    BatchQueryResponse::Channel(query_channel(ibc_ctx, request)?)
+                }
+                BatchQuery::PacketCommitment(request) => {
+                    BatchQueryResponse::PacketCommitment(query_packet_commitment(ibc_ctx, request)?)
+                }
+                BatchQuery::PacketReceipt(request) => {
+                    BatchQueryResponse::PacketReceipt(query_packet_receipt(ibc_ctx, request)?)
+                }
+                BatchQuery::PacketAcknowledgement(request) => {
+                    BatchQueryResponse::PacketAcknowledgement(query_packet_acknowledgement(
+                        ibc_ctx, request,
+                    )?)
+                }
+                BatchQuery::NextSequenceReceive(request) => {
+                    BatchQueryResponse::NextSequenceReceive(query_next_sequence_receive(
+                        ibc_ctx, request,
+                    )?)
+                }
+            };
+
+            check_proof_height(&response, height)?;
+
+            Ok(response)
+        })
+        .collect::<Result<Vec<_>, QueryError>>()?;
+
+    Ok(QueryBatchResponse {
+        responses,
+        height: Some(height.into()),
+    })
+}
+
+/// Checks that the proof of the answer to a sub-query is at the height of the
+/// batch.
+fn check_proof_height(response: &BatchQueryResponse, height: Height) -> Result<(), QueryError> {
+    let proof_height = response
+        .proof_height()
+        .cloned()
+        .map(Height::try_from)
+        .transpose()?;
+
+    match proof_height {
+        Some(proof_height) if proof_height != height => Err(QueryError::InconsistentHeight {
+            expected: height,
+            actual: proof_height,
+        }),
+        _ => Ok(()),
+    }
+}
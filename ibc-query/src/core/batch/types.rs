@@ -0,0 +1,173 @@
+//! Defines the request and response types of the batch query, which is not
+//! part of the `ibc-proto` gRPC query services.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use ibc_proto::ibc::core::channel::v1::{
+    QueryChannelRequest, QueryChannelResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementResponse, QueryPacketCommitmentRequest,
+    QueryPacketCommitmentResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+};
+use ibc_proto::ibc::core::client::v1::{
+    Height as RawHeight, QueryClientStateRequest, QueryClientStateResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse,
+};
+use ibc_proto::ibc::core::connection::v1::{QueryConnectionRequest, QueryConnectionResponse};
+
+use crate::error::QueryError;
+
+/// Maximum number of sub-queries a single [`QueryBatchRequest`] may contain.
+pub const MAX_QUERIES_PER_BATCH: usize = 100;
+
+/// A sub-query of a [`QueryBatchRequest`].
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum BatchQuery {
+    #[prost(message, tag = "1")]
+    ClientState(QueryClientStateRequest),
+    #[prost(message, tag = "2")]
+    ConsensusState(QueryConsensusStateRequest),
+    #[prost(message, tag = "3")]
+    Connection(QueryConnectionRequest),
+    #[prost(message, tag = "4")]
+    Channel(QueryChannelRequest),
+    #[prost(message, tag = "5")]
+    PacketCommitment(QueryPacketCommitmentRequest),
+    #[prost(message, tag = "6")]
+    PacketReceipt(QueryPacketReceiptRequest),
+    #[prost(message, tag = "7")]
+    PacketAcknowledgement(QueryPacketAcknowledgementRequest),
+    #[prost(message, tag = "8")]
+    NextSequenceReceive(QueryNextSequenceReceiveRequest),
+}
+
+/// The raw, protobuf-encoded form of a [`BatchQuery`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawBatchQuery {
+    #[prost(oneof = "BatchQuery", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub query: Option<BatchQuery>,
+}
+
+/// The answer to a [`BatchQuery`].
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum BatchQueryResponse {
+    #[prost(message, tag = "1")]
+    ClientState(QueryClientStateResponse),
+    #[prost(message, tag = "2")]
+    ConsensusState(QueryConsensusStateResponse),
+    #[prost(message, tag = "3")]
+    Connection(QueryConnectionResponse),
+    #[prost(message, tag = "4")]
+    Channel(QueryChannelResponse),
+    #[prost(message, tag = "5")]
+    PacketCommitment(QueryPacketCommitmentResponse),
+    #[prost(message, tag = "6")]
+    PacketReceipt(QueryPacketReceiptResponse),
+    #[prost(message, tag = "7")]
+    PacketAcknowledgement(QueryPacketAcknowledgementResponse),
+    #[prost(message, tag = "8")]
+    NextSequenceReceive(QueryNextSequenceReceiveResponse),
+}
+
+/// The raw, protobuf-encoded form of a [`BatchQueryResponse`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawBatchQueryResponse {
+    #[prost(oneof = "BatchQueryResponse", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub response: Option<BatchQueryResponse>,
+}
+
+impl BatchQueryResponse {
+    /// Returns the height of the proof of the answer.
+    pub fn proof_height(&self) -> Option<&RawHeight> {
+        match self {
+            Self::ClientState(response) => response.proof_height.as_ref(),
+            Self::ConsensusState(response) => response.proof_height.as_ref(),
+            Self::Connection(response) => response.proof_height.as_ref(),
+            Self::Channel(response) => response.proof_height.as_ref(),
+            Self::PacketCommitment(response) => response.proof_height.as_ref(),
+            Self::PacketReceipt(response) => response.proof_height.as_ref(),
+            Self::PacketAcknowledgement(response) => response.proof_height.as_ref(),
+            Self::NextSequenceReceive(response) => response.proof_height.as_ref(),
+        }
+    }
+}
+
+/// Request for several heterogeneous sub-queries at once, e.g. for relayers
+/// gathering the states and proofs of a multi-message transaction in one
+/// round trip.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryBatchRequest {
+    pub queries: Vec<BatchQuery>,
+}
+
+/// The raw, protobuf-encoded form of a [`QueryBatchRequest`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawQueryBatchRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub queries: Vec<RawBatchQuery>,
+}
+
+impl TryFrom<RawQueryBatchRequest> for QueryBatchRequest {
+    type Error = QueryError;
+
+    fn try_from(raw: RawQueryBatchRequest) -> Result<Self, Self::Error> {
+        let queries = raw
+            .queries
+            .into_iter()
+            .map(|query| {
+                query.query.ok_or_else(|| QueryError::InvalidRequest {
+                    description: "missing sub-query".to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { queries })
+    }
+}
+
+impl From<QueryBatchRequest> for RawQueryBatchRequest {
+    fn from(request: QueryBatchRequest) -> Self {
+        Self {
+            queries: request
+                .queries
+                .into_iter()
+                .map(|query| RawBatchQuery { query: Some(query) })
+                .collect(),
+        }
+    }
+}
+
+/// Response to a [`QueryBatchRequest`].
+///
+/// The answers are returned in the order of the sub-queries, and all their
+/// proofs are at `height`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryBatchResponse {
+    pub responses: Vec<BatchQueryResponse>,
+    pub height: Option<RawHeight>,
+}
+
+/// The raw, protobuf-encoded form of a [`QueryBatchResponse`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawQueryBatchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub responses: Vec<RawBatchQueryResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+impl From<QueryBatchResponse> for RawQueryBatchResponse {
+    fn from(response: QueryBatchResponse) -> Self {
+        Self {
+            responses: response
+                .responses
+                .into_iter()
+                .map(|response| RawBatchQueryResponse {
+                    response: Some(response),
+                })
+                .collect(),
+            height: response.height,
+        }
+    }
+}
@@ -1,15 +1,13 @@
 //! Provides utility functions for querying IBC channel states.
 
-use alloc::format;
 use core::str::FromStr;
 
+use ibc::core::channel::types::channel::IdentifiedChannelEnd;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
-use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
-};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath};
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
@@ -26,8 +24,17 @@ use ibc_proto::ibc::core::channel::v1::{
 };
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 
+use super::{
+    typed, QueryChannelUpgradeErrorRequest, QueryChannelUpgradeErrorResponse,
+    QueryChannelUpgradeRequest, QueryChannelUpgradeResponse,
+    QueryFilteredPacketAcknowledgementsRequest, QueryFilteredPacketAcknowledgementsResponse,
+    QueryFilteredPacketCommitmentsRequest, QueryFilteredPacketCommitmentsResponse,
+    QueryNextChannelSequenceRequest, QueryNextChannelSequenceResponse, QueryPacketReceiptsRequest,
+    QueryPacketReceiptsResponse, QueryPacketStatusRequest, QueryPacketStatusResponse,
+};
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::{paginate, paginate_from, with_total};
 
 /// Queries for a specific IBC channel by the given channel and port ids and
 /// returns the channel end with the associated proof.
@@ -42,42 +49,36 @@ where
 
     let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::ChannelEnd(channel_end_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for channel end path {:?}",
-                channel_end_path
-            ),
-        })?;
+    let channel_end = typed::channel(ibc_ctx, &port_id, &channel_id)?;
 
     Ok(QueryChannelResponse {
-        channel: Some(channel_end.into()),
-        proof,
-        proof_height: Some(current_height.into()),
+        channel: Some(channel_end.value.into()),
+        proof: channel_end.proof,
+        proof_height: Some(channel_end.proof_height.into()),
     })
 }
 
 /// Queries for all existing IBC channels and returns the corresponding channel ends
 pub fn query_channels<I>(
     ibc_ctx: &I,
-    _request: &QueryChannelsRequest,
+    request: &QueryChannelsRequest,
 ) -> Result<QueryChannelsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let channel_ends = ibc_ctx.channel_ends()?;
+    let height = ibc_ctx.host_height()?;
+
+    let (channels, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.channel_ends_from(start_key, reverse)
+        })?;
 
     Ok(QueryChannelsResponse {
-        channels: channel_ends.into_iter().map(Into::into).collect(),
-        height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        channels: channels.into_iter().map(Into::into).collect(),
+        height: Some(height.into()),
+        pagination: with_total(pagination, request.pagination.as_ref(), || {
+            ibc_ctx.channel_count()
+        })?,
     })
 }
 
@@ -91,25 +92,18 @@ where
 {
     let connection_id = ConnectionId::from_str(request.connection.as_str())?;
 
-    let all_channel_ends = ibc_ctx.channel_ends()?;
+    let channel_ends = typed::connection_channels(ibc_ctx, &connection_id)?;
 
-    let connection_channel_ends = all_channel_ends
-        .into_iter()
-        .filter(|channel_end| {
-            channel_end
-                .channel_end
-                .connection_hops()
-                .iter()
-                .any(|connection_hop| connection_hop == &connection_id)
-        })
-        .map(Into::into)
-        .collect();
+    let (channels, pagination) = paginate(
+        channel_ends.value,
+        channel_end_key,
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryConnectionChannelsResponse {
-        channels: connection_channel_ends,
-        height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        channels: channels.into_iter().map(Into::into).collect(),
+        height: Some(channel_ends.height.into()),
+        pagination,
     })
 }
 
@@ -127,41 +121,15 @@ where
 
     let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
-
-    let connection_end = channel_end
-        .connection_hops()
-        .first()
-        .map(|connection_id| ibc_ctx.connection_end(connection_id))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Channel {} does not have a connection", channel_id),
-        })??;
-
-    let client_state = ibc_ctx.client_state(connection_end.client_id())?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let client_state = typed::channel_client_state(ibc_ctx, &port_id, &channel_id)?;
 
     Ok(QueryChannelClientStateResponse {
         identified_client_state: Some(IdentifiedClientState {
-            client_id: connection_end.client_id().as_str().into(),
-            client_state: Some(client_state.into()),
+            client_id: client_state.value.client_id.as_str().into(),
+            client_state: Some(client_state.value.client_state.into()),
         }),
-        proof,
-        proof_height: Some(current_height.into()),
+        proof: client_state.proof,
+        proof_height: Some(client_state.proof_height.into()),
     })
 }
 
@@ -181,45 +149,14 @@ where
 
     let height = Height::new(request.revision_number, request.revision_height)?;
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
-
-    let connection_end = channel_end
-        .connection_hops()
-        .first()
-        .map(|connection_id| ibc_ctx.connection_end(connection_id))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Channel {} does not have a connection", channel_id),
-        })??;
-
-    let consensus_path = ClientConsensusStatePath::new(
-        connection_end.client_id().clone(),
-        height.revision_number(),
-        height.revision_height(),
-    );
-
-    let consensus_state = ibc_ctx.consensus_state(&consensus_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConsensusState(consensus_path.clone()),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client consensus state path: {:?}",
-                consensus_path
-            ),
-        })?;
+    let consensus_state = typed::channel_consensus_state(ibc_ctx, &port_id, &channel_id, height)?;
+    let (client_id, state) = consensus_state.value;
 
     Ok(QueryChannelConsensusStateResponse {
-        client_id: connection_end.client_id().as_str().into(),
-        consensus_state: Some(consensus_state.into()),
-        proof,
-        proof_height: Some(current_height.into()),
+        client_id: client_id.as_str().into(),
+        consensus_state: Some(state.into()),
+        proof: consensus_state.proof,
+        proof_height: Some(consensus_state.proof_height.into()),
     })
 }
 
@@ -238,25 +175,12 @@ where
 
     let sequence = Sequence::from(request.sequence);
 
-    let commitment_path = CommitmentPath::new(&port_id, &channel_id, sequence);
-
-    let packet_commitment_data = ibc_ctx.get_packet_commitment(&commitment_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Commitment(commitment_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet commitment path: {:?}",
-                commitment_path
-            ),
-        })?;
+    let commitment = typed::packet_commitment(ibc_ctx, &port_id, &channel_id, sequence)?;
 
     Ok(QueryPacketCommitmentResponse {
-        commitment: packet_commitment_data.into_vec(),
-        proof,
-        proof_height: Some(current_height.into()),
+        commitment: commitment.value.into_vec(),
+        proof: commitment.proof,
+        proof_height: Some(commitment.proof_height.into()),
     })
 }
 
@@ -274,17 +198,43 @@ where
 
     let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
 
-    let commitments = ibc_ctx
-        .packet_commitments(&channel_end_path)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let height = ibc_ctx.host_height()?;
+
+    let (commitments_page, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.packet_commitments_from(&channel_end_path, start_key, reverse)
+        })?;
 
     Ok(QueryPacketCommitmentsResponse {
-        commitments,
-        height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        commitments: commitments_page.into_iter().map(Into::into).collect(),
+        height: Some(height.into()),
+        pagination: with_total(pagination, request.pagination.as_ref(), || {
+            ibc_ctx.packet_commitment_count(&channel_end_path)
+        })?,
+    })
+}
+
+/// Queries for the packet commitments associated with a channel, restricted to
+/// the requested list or range of sequences if any.
+pub fn query_filtered_packet_commitments<I>(
+    ibc_ctx: &I,
+    request: &QueryFilteredPacketCommitmentsRequest,
+) -> Result<QueryFilteredPacketCommitmentsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let commitments = match &request.sequences {
+        Some(filter) => typed::filtered_packet_commitments(ibc_ctx, &port_id, &channel_id, filter)?,
+        None => typed::packet_commitments(ibc_ctx, &port_id, &channel_id)?,
+    };
+
+    Ok(QueryFilteredPacketCommitmentsResponse {
+        commitments: commitments.value.into_iter().map(Into::into).collect(),
+        height: Some(commitments.height.into()),
     })
 }
 
@@ -303,27 +253,35 @@ where
 
     let sequence = Sequence::from(request.sequence);
 
-    let receipt_path = ReceiptPath::new(&port_id, &channel_id, sequence);
+    let receipt = typed::packet_receipt(ibc_ctx, &port_id, &channel_id, sequence)?;
 
-    // Receipt only has one enum
-    // Unreceived packets are not stored
-    let packet_receipt_data = ibc_ctx.get_packet_receipt(&receipt_path);
+    Ok(QueryPacketReceiptResponse {
+        received: receipt.value,
+        proof: receipt.proof,
+        proof_height: Some(receipt.proof_height.into()),
+    })
+}
 
-    let current_height = ibc_ctx.host_height()?;
+/// Queries for the receipt status of a list or range of packets associated
+/// with a channel by the given channel and port ids, at most
+/// [`MAX_PACKET_RECEIPTS_PER_QUERY`](super::MAX_PACKET_RECEIPTS_PER_QUERY) at
+/// once.
+pub fn query_packet_receipts<I>(
+    ibc_ctx: &I,
+    request: &QueryPacketReceiptsRequest,
+) -> Result<QueryPacketReceiptsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Receipt(receipt_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet receipt path: {:?}",
-                receipt_path
-            ),
-        })?;
+    let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    Ok(QueryPacketReceiptResponse {
-        received: packet_receipt_data.is_ok(),
-        proof,
-        proof_height: Some(current_height.into()),
+    let receipts = typed::packet_receipts(ibc_ctx, &port_id, &channel_id, &request.sequences)?;
+
+    Ok(QueryPacketReceiptsResponse {
+        receipts: receipts.value,
+        height: Some(receipts.height.into()),
     })
 }
 
@@ -342,25 +300,12 @@ where
 
     let sequence = Sequence::from(request.sequence);
 
-    let acknowledgement_path = AckPath::new(&port_id, &channel_id, sequence);
-
-    let packet_acknowledgement_data = ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Ack(acknowledgement_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet acknowledgement path: {:?}",
-                acknowledgement_path
-            ),
-        })?;
+    let acknowledgement = typed::packet_acknowledgement(ibc_ctx, &port_id, &channel_id, sequence)?;
 
     Ok(QueryPacketAcknowledgementResponse {
-        acknowledgement: packet_acknowledgement_data.into_vec(),
-        proof,
-        proof_height: Some(current_height.into()),
+        acknowledgement: acknowledgement.value.into_vec(),
+        proof: acknowledgement.proof,
+        proof_height: Some(acknowledgement.proof_height.into()),
     })
 }
 
@@ -382,19 +327,55 @@ where
         .copied()
         .map(Sequence::from);
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let acknowledgements = ibc_ctx
-        .packet_acknowledgements(&channel_end_path, commitment_sequences)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let acknowledgements =
+        typed::packet_acknowledgements(ibc_ctx, &port_id, &channel_id, commitment_sequences)?;
+
+    let (acknowledgements_page, pagination) = paginate(
+        acknowledgements.value,
+        |acknowledgement| {
+            AckPath::new(
+                &acknowledgement.port_id,
+                &acknowledgement.chan_id,
+                acknowledgement.seq,
+            )
+            .to_string()
+            .into_bytes()
+        },
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryPacketAcknowledgementsResponse {
-        acknowledgements,
-        height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        acknowledgements: acknowledgements_page.into_iter().map(Into::into).collect(),
+        height: Some(acknowledgements.height.into()),
+        pagination,
+    })
+}
+
+/// Queries for the packet acknowledgements associated with a channel,
+/// restricted to the requested list or range of sequences if any.
+pub fn query_filtered_packet_acknowledgements<I>(
+    ibc_ctx: &I,
+    request: &QueryFilteredPacketAcknowledgementsRequest,
+) -> Result<QueryFilteredPacketAcknowledgementsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let acknowledgements = match &request.sequences {
+        Some(filter) => {
+            typed::filtered_packet_acknowledgements(ibc_ctx, &port_id, &channel_id, filter)?
+        }
+        None => {
+            typed::packet_acknowledgements(ibc_ctx, &port_id, &channel_id, core::iter::empty())?
+        }
+    };
+
+    Ok(QueryFilteredPacketAcknowledgementsResponse {
+        acknowledgements: acknowledgements.value.into_iter().map(Into::into).collect(),
+        height: Some(acknowledgements.height.into()),
     })
 }
 
@@ -416,16 +397,55 @@ where
         .copied()
         .map(Sequence::from);
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let unreceived_packets = ibc_ctx.unreceived_packets(&channel_end_path, sequences)?;
+    let unreceived_packets = typed::unreceived_packets(ibc_ctx, &port_id, &channel_id, sequences)?;
 
     Ok(QueryUnreceivedPacketsResponse {
-        sequences: unreceived_packets.into_iter().map(Into::into).collect(),
-        height: Some(ibc_ctx.host_height()?.into()),
+        sequences: unreceived_packets
+            .value
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        height: Some(unreceived_packets.height.into()),
     })
 }
 
+/// Queries for all unreceived packets associated with a channel, split into
+/// responses of at most `chunk_size` sequences each, so that large result sets
+/// fit within the message size limits of gRPC.
+///
+/// The chunks are looked up as the returned iterator advances, each against
+/// the current state of the host, which is why the context is taken by value,
+/// e.g. as a clone of a shared handle.
+pub fn query_unreceived_packets_chunked<I>(
+    ibc_ctx: I,
+    request: &QueryUnreceivedPacketsRequest,
+    chunk_size: usize,
+) -> Result<impl Iterator<Item = Result<QueryUnreceivedPacketsResponse, QueryError>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let sequences = request
+        .packet_commitment_sequences
+        .iter()
+        .copied()
+        .map(Sequence::from)
+        .collect();
+
+    let chunks =
+        typed::unreceived_packets_chunked(ibc_ctx, &port_id, &channel_id, sequences, chunk_size)?;
+
+    Ok(chunks.map(|chunk| {
+        chunk.map(|chunk| QueryUnreceivedPacketsResponse {
+            sequences: chunk.value.into_iter().map(Into::into).collect(),
+            height: Some(chunk.height.into()),
+        })
+    }))
+}
+
 /// Queries for all unreceived acknowledgements associated with a channel
 pub fn query_unreceived_acks<I>(
     ibc_ctx: &I,
@@ -444,16 +464,58 @@ where
         .copied()
         .map(Sequence::from);
 
-    let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
-
-    let unreceived_acks = ibc_ctx.unreceived_acks(&channel_end_path, sequences)?;
+    let unreceived_acks = typed::unreceived_acks(ibc_ctx, &port_id, &channel_id, sequences)?;
 
     Ok(QueryUnreceivedAcksResponse {
-        sequences: unreceived_acks.into_iter().map(Into::into).collect(),
-        height: Some(ibc_ctx.host_height()?.into()),
+        sequences: unreceived_acks.value.into_iter().map(Into::into).collect(),
+        height: Some(unreceived_acks.height.into()),
     })
 }
 
+/// Queries for all unreceived acknowledgements associated with a channel,
+/// split into responses of at most `chunk_size` sequences each, so that large
+/// result sets fit within the message size limits of gRPC.
+///
+/// As for [`query_unreceived_packets_chunked`], the chunks are looked up as
+/// the returned iterator advances.
+pub fn query_unreceived_acks_chunked<I>(
+    ibc_ctx: I,
+    request: &QueryUnreceivedAcksRequest,
+    chunk_size: usize,
+) -> Result<impl Iterator<Item = Result<QueryUnreceivedAcksResponse, QueryError>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let sequences = request
+        .packet_ack_sequences
+        .iter()
+        .copied()
+        .map(Sequence::from)
+        .collect();
+
+    let chunks =
+        typed::unreceived_acks_chunked(ibc_ctx, &port_id, &channel_id, sequences, chunk_size)?;
+
+    Ok(chunks.map(|chunk| {
+        chunk.map(|chunk| QueryUnreceivedAcksResponse {
+            sequences: chunk.value.into_iter().map(Into::into).collect(),
+            height: Some(chunk.height.into()),
+        })
+    }))
+}
+
+/// Returns the store key of the given channel end, by which the channel ends
+/// are paginated.
+fn channel_end_key(channel_end: &IdentifiedChannelEnd) -> Vec<u8> {
+    ChannelEndPath::new(&channel_end.port_id, &channel_end.channel_id)
+        .to_string()
+        .into_bytes()
+}
+
 /// Queries for the next sequence to send for the channel specified
 /// in the `request`.
 pub fn query_next_sequence_send<I>(
@@ -467,25 +529,12 @@ where
 
     let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    let next_seq_send_path = SeqSendPath::new(&port_id, &channel_id);
-
-    let next_sequence_send = ibc_ctx.get_next_sequence_send(&next_seq_send_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::SeqSend(next_seq_send_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Next sequence send proof not found for channel {}",
-                channel_id
-            ),
-        })?;
+    let next_sequence_send = typed::next_sequence_send(ibc_ctx, &port_id, &channel_id)?;
 
     Ok(QueryNextSequenceSendResponse {
-        next_sequence_send: next_sequence_send.into(),
-        proof,
-        proof_height: Some(current_height.into()),
+        next_sequence_send: next_sequence_send.value.into(),
+        proof: next_sequence_send.proof,
+        proof_height: Some(next_sequence_send.proof_height.into()),
     })
 }
 
@@ -501,24 +550,97 @@ where
 
     let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    let next_seq_recv_path = SeqRecvPath::new(&port_id, &channel_id);
+    let next_sequence_recv = typed::next_sequence_receive(ibc_ctx, &port_id, &channel_id)?;
 
-    let next_sequence_recv = ibc_ctx.get_next_sequence_recv(&next_seq_recv_path)?;
+    Ok(QueryNextSequenceReceiveResponse {
+        next_sequence_receive: next_sequence_recv.value.into(),
+        proof: next_sequence_recv.proof,
+        proof_height: Some(next_sequence_recv.proof_height.into()),
+    })
+}
 
-    let current_height = ibc_ctx.host_height()?;
+/// Queries for the lifecycle status of the packets of a channel by the given
+/// sequence, channel and port ids, derived from its commitments, receipts and
+/// acknowledgements.
+pub fn query_packet_status<I>(
+    ibc_ctx: &I,
+    request: &QueryPacketStatusRequest,
+) -> Result<QueryPacketStatusResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::SeqRecv(next_seq_recv_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Next sequence receive proof not found for channel {}",
-                channel_id
-            ),
-        })?;
+    let port_id = PortId::from_str(request.port_id.as_str())?;
 
-    Ok(QueryNextSequenceReceiveResponse {
-        next_sequence_receive: next_sequence_recv.into(),
-        proof,
-        proof_height: Some(current_height.into()),
+    let sequence = Sequence::from(request.sequence);
+
+    let status = typed::packet_status(ibc_ctx, &port_id, &channel_id, sequence)?;
+
+    Ok(QueryPacketStatusResponse {
+        sent: status.value.sent.into(),
+        received: status.value.received.into(),
+        height: Some(status.height.into()),
+    })
+}
+
+/// Queries for the sequence of the next channel identifier, i.e. the number of
+/// channels created so far.
+pub fn query_next_channel_sequence<I>(
+    ibc_ctx: &I,
+    _request: &QueryNextChannelSequenceRequest,
+) -> Result<QueryNextChannelSequenceResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let next_channel_sequence = typed::next_channel_sequence(ibc_ctx)?;
+
+    Ok(QueryNextChannelSequenceResponse {
+        next_channel_sequence: next_channel_sequence.value,
+        height: Some(next_channel_sequence.height.into()),
+    })
+}
+
+/// Queries for the upgrade attempt in progress on the given channel and
+/// returns it with the associated proof.
+pub fn query_channel_upgrade<I>(
+    ibc_ctx: &I,
+    request: &QueryChannelUpgradeRequest,
+) -> Result<QueryChannelUpgradeResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let upgrade = typed::channel_upgrade(ibc_ctx, &port_id, &channel_id)?;
+
+    Ok(QueryChannelUpgradeResponse {
+        upgrade: Some(upgrade.value.into()),
+        proof: upgrade.proof,
+        proof_height: Some(upgrade.proof_height.into()),
+    })
+}
+
+/// Queries for the receipt of the last failed upgrade attempt of the given
+/// channel and returns it with the associated proof.
+pub fn query_channel_upgrade_error<I>(
+    ibc_ctx: &I,
+    request: &QueryChannelUpgradeErrorRequest,
+) -> Result<QueryChannelUpgradeErrorResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let error_receipt = typed::channel_upgrade_error(ibc_ctx, &port_id, &channel_id)?;
+
+    Ok(QueryChannelUpgradeErrorResponse {
+        error_receipt: Some(error_receipt.value.into()),
+        proof: error_receipt.proof,
+        proof_height: Some(error_receipt.proof_height.into()),
     })
 }
@@ -2,6 +2,8 @@
 //! `I` must be a type where writes from one thread are readable from another.
 //! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 
+use alloc::sync::Arc;
+
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
@@ -28,9 +30,9 @@ use super::{
     query_packet_commitments, query_packet_receipt, query_unreceived_acks,
     query_unreceived_packets,
 };
+use crate::cache::{cached_query, QueryCache};
 use crate::core::context::QueryContext;
-
-// TODO(rano): currently the services don't support pagination, so we return all the results.
+use crate::pagination::page_cache_key;
 
 /// The generic `I` must be a type where writes from one thread are readable from another.
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
@@ -41,6 +43,7 @@ where
     <I as ValidationContext>::AnyConsensusState: Into<Any>,
 {
     ibc_context: I,
+    cache: Option<Arc<QueryCache>>,
 }
 
 impl<I> ChannelQueryService<I>
@@ -52,7 +55,17 @@ where
     /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
     /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
     pub fn new(ibc_context: I) -> Self {
-        Self { ibc_context }
+        Self {
+            ibc_context,
+            cache: None,
+        }
+    }
+
+    /// Caches the responses of the expensive list queries in the given cache.
+    #[cfg(feature = "std")]
+    pub fn with_cache(mut self, cache: Arc<QueryCache>) -> Self {
+        self.cache = Some(cache);
+        self
     }
 }
 
@@ -76,7 +89,14 @@ where
         &self,
         request: Request<QueryChannelsRequest>,
     ) -> Result<Response<QueryChannelsResponse>, Status> {
-        let response = query_channels(&self.ibc_context, request.get_ref())?;
+        let request = request.get_ref();
+
+        let response = cached_query(
+            self.cache.as_deref(),
+            &self.ibc_context,
+            || format!("channels/{}", page_cache_key(request.pagination.as_ref())),
+            || query_channels(&self.ibc_context, request),
+        )?;
 
         Ok(Response::new(response))
     }
@@ -85,7 +105,20 @@ where
         &self,
         request: Request<QueryConnectionChannelsRequest>,
     ) -> Result<Response<QueryConnectionChannelsResponse>, Status> {
-        let response = query_connection_channels(&self.ibc_context, request.get_ref())?;
+        let request = request.get_ref();
+
+        let response = cached_query(
+            self.cache.as_deref(),
+            &self.ibc_context,
+            || {
+                format!(
+                    "connection_channels/{}/{}",
+                    request.connection,
+                    page_cache_key(request.pagination.as_ref())
+                )
+            },
+            || query_connection_channels(&self.ibc_context, request),
+        )?;
 
         Ok(Response::new(response))
     }
@@ -121,7 +154,21 @@ where
         &self,
         request: Request<QueryPacketCommitmentsRequest>,
     ) -> Result<Response<QueryPacketCommitmentsResponse>, Status> {
-        let response = query_packet_commitments(&self.ibc_context, request.get_ref())?;
+        let request = request.get_ref();
+
+        let response = cached_query(
+            self.cache.as_deref(),
+            &self.ibc_context,
+            || {
+                format!(
+                    "packet_commitments/{}/{}/{}",
+                    request.port_id,
+                    request.channel_id,
+                    page_cache_key(request.pagination.as_ref())
+                )
+            },
+            || query_packet_commitments(&self.ibc_context, request),
+        )?;
 
         Ok(Response::new(response))
     }
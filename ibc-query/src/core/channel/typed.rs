@@ -0,0 +1,836 @@
+//! Provides typed functions for querying IBC channel and packet states,
+//! returning domain types instead of `ibc-proto` responses.
+
+use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd, Order};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::PacketState;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, CommitmentPath, Path, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+
+use super::{
+    PacketLifecycleStatus, PacketReceiptSequences, PacketReceiptStatus, PacketSequenceFilter,
+    PacketStatus, MAX_PACKET_RECEIPTS_PER_QUERY,
+};
+use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::typed::{prove, AtHeight, IdentifiedClientState, Proven};
+use crate::error::QueryError;
+
+/// Returns the channel end of the given channel, with its proof.
+pub fn channel<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<ChannelEnd>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ChannelEnd(channel_end_path.clone()),
+        channel_end,
+        || {
+            format!(
+                "Proof not found for channel end path {:?}",
+                channel_end_path
+            )
+        },
+    )
+}
+
+/// Returns all the existing channel ends.
+pub fn channels<I>(ibc_ctx: &I) -> Result<AtHeight<Vec<IdentifiedChannelEnd>>, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(AtHeight {
+        value: ibc_ctx.channel_ends()?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the upgrade attempt in progress on the given channel, with its
+/// proof.
+pub fn channel_upgrade<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<Upgrade>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_upgrade_path = ChannelUpgradePath::new(port_id, channel_id);
+
+    let upgrade = ibc_ctx
+        .channel_upgrade(&channel_upgrade_path)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!(
+                "No upgrade in progress on channel {channel_id} of port {port_id}"
+            ),
+        })?;
+
+    prove(
+        ibc_ctx,
+        &Path::ChannelUpgrade(channel_upgrade_path.clone()),
+        upgrade,
+        || {
+            format!(
+                "Proof not found for channel upgrade path {:?}",
+                channel_upgrade_path
+            )
+        },
+    )
+}
+
+/// Returns the receipt of the last failed upgrade attempt of the given
+/// channel, with its proof.
+pub fn channel_upgrade_error<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<ErrorReceipt>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_upgrade_error_path = ChannelUpgradeErrorPath::new(port_id, channel_id);
+
+    let error_receipt = ibc_ctx
+        .channel_upgrade_error(&channel_upgrade_error_path)?
+        .ok_or_else(|| QueryError::NotFound {
+            description: format!(
+                "No upgrade error receipt for channel {channel_id} of port {port_id}"
+            ),
+        })?;
+
+    prove(
+        ibc_ctx,
+        &Path::ChannelUpgradeError(channel_upgrade_error_path.clone()),
+        error_receipt,
+        || {
+            format!(
+                "Proof not found for channel upgrade error path {:?}",
+                channel_upgrade_error_path
+            )
+        },
+    )
+}
+
+/// Returns the channel ends of all the channels over the given connection.
+pub fn connection_channels<I>(
+    ibc_ctx: &I,
+    connection_id: &ConnectionId,
+) -> Result<AtHeight<Vec<IdentifiedChannelEnd>>, QueryError>
+where
+    I: QueryContext,
+{
+    let connection_channel_ends = ibc_ctx
+        .channel_ends()?
+        .into_iter()
+        .filter(|channel_end| {
+            channel_end
+                .channel_end
+                .connection_hops()
+                .iter()
+                .any(|connection_hop| connection_hop == connection_id)
+        })
+        .collect();
+
+    Ok(AtHeight {
+        value: connection_channel_ends,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the connection end of the first hop of the given channel.
+fn channel_connection_end<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ConnectionEnd, QueryError>
+where
+    I: ValidationContext,
+{
+    let channel_end = ibc_ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+
+    let connection_end = channel_end
+        .connection_hops()
+        .first()
+        .map(|connection_id| ibc_ctx.connection_end(connection_id))
+        .ok_or(QueryError::ProofNotFound {
+            description: format!("Channel {} does not have a connection", channel_id),
+        })??;
+
+    Ok(connection_end)
+}
+
+/// Returns the client state of the client of the given channel, with its
+/// proof.
+pub fn channel_client_state<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<IdentifiedClientState<I::AnyClientState>>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let connection_end = channel_connection_end(ibc_ctx, port_id, channel_id)?;
+    let client_id = connection_end.client_id();
+
+    let client_state = ibc_ctx.client_state(client_id)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientState(ClientStatePath::new(client_id.clone())),
+        IdentifiedClientState {
+            client_id: client_id.clone(),
+            client_state,
+        },
+        || format!("Proof not found for client state path: {:?}", client_id),
+    )
+}
+
+/// Returns the consensus state at `height` of the client of the given
+/// channel, along with the identifier of the client, with its proof.
+pub fn channel_consensus_state<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    height: Height,
+) -> Result<Proven<(ClientId, I::AnyConsensusState)>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let connection_end = channel_connection_end(ibc_ctx, port_id, channel_id)?;
+    let client_id = connection_end.client_id();
+
+    let consensus_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    );
+
+    let consensus_state = ibc_ctx.consensus_state(&consensus_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientConsensusState(consensus_path.clone()),
+        (client_id.clone(), consensus_state),
+        || {
+            format!(
+                "Proof not found for client consensus state path: {:?}",
+                consensus_path
+            )
+        },
+    )
+}
+
+/// Returns the commitment of the given packet, with its proof.
+pub fn packet_commitment<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<Proven<PacketCommitment>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let commitment_path = CommitmentPath::new(port_id, channel_id, sequence);
+
+    let packet_commitment = ibc_ctx.get_packet_commitment(&commitment_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::Commitment(commitment_path.clone()),
+        packet_commitment,
+        || {
+            format!(
+                "Proof not found for packet commitment path: {:?}",
+                commitment_path
+            )
+        },
+    )
+}
+
+/// Returns all the packet commitments of the given channel.
+pub fn packet_commitments<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<AtHeight<Vec<PacketState>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    Ok(AtHeight {
+        value: ibc_ctx.packet_commitments(&channel_end_path)?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the packet commitments of the given channel whose sequences pass
+/// `filter`.
+pub fn filtered_packet_commitments<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    filter: &PacketSequenceFilter,
+) -> Result<AtHeight<Vec<PacketState>>, QueryError>
+where
+    I: QueryContext,
+{
+    validate_sequence_filter(filter)?;
+
+    let mut commitments = packet_commitments(ibc_ctx, port_id, channel_id)?;
+
+    commitments
+        .value
+        .retain(|commitment| filter.contains(commitment.seq.value()));
+
+    Ok(commitments)
+}
+
+/// Returns whether the given packet has been received, with the proof of its
+/// receipt, or of its absence.
+pub fn packet_receipt<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<Proven<bool>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let receipt_path = ReceiptPath::new(port_id, channel_id, sequence);
+
+    // Receipt only has one enum
+    // Unreceived packets are not stored
+    let received = ibc_ctx.get_packet_receipt(&receipt_path).is_ok();
+
+    prove(
+        ibc_ctx,
+        &Path::Receipt(receipt_path.clone()),
+        received,
+        || {
+            format!(
+                "Proof not found for packet receipt path: {:?}",
+                receipt_path
+            )
+        },
+    )
+}
+
+/// Returns the receipt status of a list or range of packets of the given
+/// channel, at most [`MAX_PACKET_RECEIPTS_PER_QUERY`] at once.
+pub fn packet_receipts<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: &PacketReceiptSequences,
+) -> Result<AtHeight<Vec<PacketReceiptStatus>>, QueryError>
+where
+    I: ValidationContext,
+{
+    let sequences: Vec<u64> = match sequences {
+        PacketReceiptSequences::List(sequences) => {
+            if sequences.len() as u64 > MAX_PACKET_RECEIPTS_PER_QUERY {
+                return Err(QueryError::InvalidRequest {
+                    description: format!(
+                        "cannot query more than {MAX_PACKET_RECEIPTS_PER_QUERY} packet receipts at once"
+                    ),
+                });
+            }
+
+            sequences.clone()
+        }
+        PacketReceiptSequences::Range { start, end } => {
+            if start > end || end - start >= MAX_PACKET_RECEIPTS_PER_QUERY {
+                return Err(QueryError::InvalidRequest {
+                    description: format!(
+                        "invalid packet receipt sequence range [{start}, {end}]: it must be non-empty and cover at most {MAX_PACKET_RECEIPTS_PER_QUERY} sequences"
+                    ),
+                });
+            }
+
+            (*start..=*end).collect()
+        }
+    };
+
+    // Receipt only has one enum
+    // Unreceived packets are not stored
+    let receipts = sequences
+        .into_iter()
+        .map(|sequence| {
+            let receipt_path = ReceiptPath::new(port_id, channel_id, Sequence::from(sequence));
+
+            PacketReceiptStatus {
+                sequence,
+                received: ibc_ctx.get_packet_receipt(&receipt_path).is_ok(),
+            }
+        })
+        .collect();
+
+    Ok(AtHeight {
+        value: receipts,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the acknowledgement commitment of the given packet, with its
+/// proof.
+pub fn packet_acknowledgement<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<Proven<AcknowledgementCommitment>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let acknowledgement_path = AckPath::new(port_id, channel_id, sequence);
+
+    let packet_acknowledgement = ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::Ack(acknowledgement_path.clone()),
+        packet_acknowledgement,
+        || {
+            format!(
+                "Proof not found for packet acknowledgement path: {:?}",
+                acknowledgement_path
+            )
+        },
+    )
+}
+
+/// Returns the acknowledgements of the given packets of the channel, or all
+/// its acknowledgements if `sequences` is empty.
+pub fn packet_acknowledgements<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: impl ExactSizeIterator<Item = Sequence>,
+) -> Result<AtHeight<Vec<PacketState>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    Ok(AtHeight {
+        value: ibc_ctx.packet_acknowledgements(&channel_end_path, sequences)?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the packet acknowledgements of the given channel whose sequences
+/// pass `filter`.
+pub fn filtered_packet_acknowledgements<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    filter: &PacketSequenceFilter,
+) -> Result<AtHeight<Vec<PacketState>>, QueryError>
+where
+    I: QueryContext,
+{
+    validate_sequence_filter(filter)?;
+
+    match filter {
+        // An empty list of sequences would return all the acknowledgements.
+        PacketSequenceFilter::List(sequences) if sequences.is_empty() => Ok(AtHeight {
+            value: Vec::new(),
+            height: ibc_ctx.host_height()?,
+        }),
+        PacketSequenceFilter::List(sequences) => packet_acknowledgements(
+            ibc_ctx,
+            port_id,
+            channel_id,
+            sequences.iter().copied().map(Sequence::from),
+        ),
+        PacketSequenceFilter::Range { .. } => {
+            let mut acknowledgements =
+                packet_acknowledgements(ibc_ctx, port_id, channel_id, core::iter::empty())?;
+
+            acknowledgements
+                .value
+                .retain(|acknowledgement| filter.contains(acknowledgement.seq.value()));
+
+            Ok(acknowledgements)
+        }
+    }
+}
+
+/// Returns the sequences among `sequences` of the packets of the channel which
+/// have not been received.
+pub fn unreceived_packets<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: impl ExactSizeIterator<Item = Sequence>,
+) -> Result<AtHeight<Vec<Sequence>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    Ok(AtHeight {
+        value: ibc_ctx.unreceived_packets(&channel_end_path, sequences)?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the sequences among `sequences` of the packets of the channel whose
+/// acknowledgement has not been received, or of all of them if `sequences` is
+/// empty.
+pub fn unreceived_acks<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: impl ExactSizeIterator<Item = Sequence>,
+) -> Result<AtHeight<Vec<Sequence>>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    Ok(AtHeight {
+        value: ibc_ctx.unreceived_acks(&channel_end_path, sequences)?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Whether an [`UnreceivedSequenceChunks`] looks up unreceived packets or
+/// unreceived acknowledgements.
+#[derive(Clone, Copy, Debug)]
+enum Unreceived {
+    Packets,
+    Acks,
+}
+
+/// Looks up the unreceived packets or acknowledgements among some sequences of
+/// a channel lazily, one chunk of at most `chunk_size` sequences each time the
+/// iterator advances, so that the lookups of a large request are spread over
+/// the responses streamed back.
+///
+/// Each chunk is looked up against the state of the host when it is reached,
+/// and reports the height of that state. The chunks without any unreceived
+/// sequence are skipped, but a single empty one is yielded if all of them are,
+/// so that the height of the query is always returned.
+pub struct UnreceivedSequenceChunks<I> {
+    ibc_ctx: I,
+    channel_end_path: ChannelEndPath,
+    unreceived: Unreceived,
+    sequences: Vec<Sequence>,
+    chunk_size: usize,
+    next: usize,
+    done: bool,
+    yielded: bool,
+}
+
+impl<I> UnreceivedSequenceChunks<I>
+where
+    I: QueryContext,
+{
+    fn new(
+        ibc_ctx: I,
+        channel_end_path: ChannelEndPath,
+        unreceived: Unreceived,
+        sequences: Vec<Sequence>,
+        chunk_size: usize,
+    ) -> Result<Self, QueryError> {
+        if chunk_size == 0 {
+            return Err(QueryError::InvalidRequest {
+                description: "the chunk size must be positive".to_string(),
+            });
+        }
+
+        Ok(Self {
+            ibc_ctx,
+            channel_end_path,
+            unreceived,
+            sequences,
+            chunk_size,
+            next: 0,
+            done: false,
+            yielded: false,
+        })
+    }
+}
+
+impl<I> Iterator for UnreceivedSequenceChunks<I>
+where
+    I: QueryContext,
+{
+    type Item = Result<AtHeight<Vec<Sequence>>, QueryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let end = self
+                .next
+                .saturating_add(self.chunk_size)
+                .min(self.sequences.len());
+            let chunk = self.sequences[self.next..end].iter().copied();
+
+            self.next = end;
+            self.done = end == self.sequences.len();
+
+            let value = if chunk.len() == 0 {
+                // Unlike the other sequences, an empty chunk is not looked up,
+                // as the host returns all the unreceived acknowledgements for
+                // it.
+                Ok(Vec::new())
+            } else {
+                match self.unreceived {
+                    Unreceived::Packets => self
+                        .ibc_ctx
+                        .unreceived_packets(&self.channel_end_path, chunk),
+                    Unreceived::Acks => self.ibc_ctx.unreceived_acks(&self.channel_end_path, chunk),
+                }
+            };
+
+            let chunk = value.and_then(|value| {
+                Ok(AtHeight {
+                    value,
+                    height: self.ibc_ctx.host_height()?,
+                })
+            });
+
+            match chunk {
+                // Skip the chunks without any unreceived sequence, but the
+                // last one if none was yielded yet.
+                Ok(chunk) if chunk.value.is_empty() && (self.yielded || !self.done) => {}
+                Ok(chunk) => {
+                    self.yielded = true;
+
+                    return Some(Ok(chunk));
+                }
+                // Stop at the first failed lookup.
+                Err(e) => {
+                    self.done = true;
+
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the sequences among `sequences` of the packets of the channel which
+/// have not been received, looked up in chunks of at most `chunk_size`
+/// sequences as the returned iterator advances.
+pub fn unreceived_packets_chunked<I>(
+    ibc_ctx: I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: Vec<Sequence>,
+    chunk_size: usize,
+) -> Result<UnreceivedSequenceChunks<I>, QueryError>
+where
+    I: QueryContext,
+{
+    UnreceivedSequenceChunks::new(
+        ibc_ctx,
+        ChannelEndPath::new(port_id, channel_id),
+        Unreceived::Packets,
+        sequences,
+        chunk_size,
+    )
+}
+
+/// Returns the sequences among `sequences` of the packets of the channel whose
+/// acknowledgement has not been received, looked up in chunks of at most
+/// `chunk_size` sequences as the returned iterator advances.
+///
+/// If `sequences` is empty, the sequences of all the packets of the channel
+/// whose acknowledgement has not been received are listed upfront, and only
+/// their chunks are streamed lazily.
+pub fn unreceived_acks_chunked<I>(
+    ibc_ctx: I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequences: Vec<Sequence>,
+    chunk_size: usize,
+) -> Result<UnreceivedSequenceChunks<I>, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(port_id, channel_id);
+
+    let sequences = if sequences.is_empty() {
+        ibc_ctx.unreceived_acks(&channel_end_path, sequences.into_iter())?
+    } else {
+        sequences
+    };
+
+    UnreceivedSequenceChunks::new(
+        ibc_ctx,
+        channel_end_path,
+        Unreceived::Acks,
+        sequences,
+        chunk_size,
+    )
+}
+
+/// Returns the sequence of the next packet to be sent on the given channel,
+/// with its proof.
+pub fn next_sequence_send<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<Sequence>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let next_seq_send_path = SeqSendPath::new(port_id, channel_id);
+
+    let next_sequence_send = ibc_ctx.get_next_sequence_send(&next_seq_send_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::SeqSend(next_seq_send_path),
+        next_sequence_send,
+        || {
+            format!(
+                "Next sequence send proof not found for channel {}",
+                channel_id
+            )
+        },
+    )
+}
+
+/// Returns the sequence of the next packet to be received on the given
+/// channel, with its proof.
+pub fn next_sequence_receive<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Proven<Sequence>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let next_seq_recv_path = SeqRecvPath::new(port_id, channel_id);
+
+    let next_sequence_recv = ibc_ctx.get_next_sequence_recv(&next_seq_recv_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::SeqRecv(next_seq_recv_path),
+        next_sequence_recv,
+        || {
+            format!(
+                "Next sequence receive proof not found for channel {}",
+                channel_id
+            )
+        },
+    )
+}
+
+/// Returns the lifecycle status of the packets of the given sequence sent and
+/// received on the given channel.
+pub fn packet_status<I>(
+    ibc_ctx: &I,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<AtHeight<PacketLifecycleStatus>, QueryError>
+where
+    I: ValidationContext,
+{
+    if sequence.value() == 0 {
+        return Err(QueryError::InvalidRequest {
+            description: "packet sequences start at 1".to_string(),
+        });
+    }
+
+    let channel_end = ibc_ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+    let ordered = channel_end.ordering == Order::Ordered;
+
+    let next_sequence_send =
+        ibc_ctx.get_next_sequence_send(&SeqSendPath::new(port_id, channel_id))?;
+
+    let sent = if sequence >= next_sequence_send {
+        PacketStatus::Unsent
+    } else if ibc_ctx
+        .get_packet_commitment(&CommitmentPath::new(port_id, channel_id, sequence))
+        .is_ok()
+    {
+        PacketStatus::Committed
+    } else if ordered {
+        // Packets of ordered channels are acknowledged in order, while a
+        // timeout closes the channel without moving the next sequence to
+        // acknowledge.
+        if sequence < ibc_ctx.get_next_sequence_ack(&SeqAckPath::new(port_id, channel_id))? {
+            PacketStatus::Acknowledged
+        } else {
+            PacketStatus::TimedOut
+        }
+    } else {
+        PacketStatus::AcknowledgedOrTimedOut
+    };
+
+    let received = if ibc_ctx
+        .get_packet_acknowledgement(&AckPath::new(port_id, channel_id, sequence))
+        .is_ok()
+    {
+        PacketStatus::Acknowledged
+    } else if ordered {
+        // Ordered channels store no receipts, but the next sequence to receive.
+        if sequence < ibc_ctx.get_next_sequence_recv(&SeqRecvPath::new(port_id, channel_id))? {
+            PacketStatus::Received
+        } else {
+            PacketStatus::Unsent
+        }
+    } else if ibc_ctx
+        .get_packet_receipt(&ReceiptPath::new(port_id, channel_id, sequence))
+        .is_ok()
+    {
+        PacketStatus::Received
+    } else {
+        PacketStatus::Unsent
+    };
+
+    Ok(AtHeight {
+        value: PacketLifecycleStatus { sent, received },
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the sequence of the next channel identifier, i.e. the number of
+/// channels created so far.
+pub fn next_channel_sequence<I>(ibc_ctx: &I) -> Result<AtHeight<u64>, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(AtHeight {
+        value: ibc_ctx.channel_counter()?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Rejects the sequence ranges which do not cover any sequence.
+fn validate_sequence_filter(filter: &PacketSequenceFilter) -> Result<(), QueryError> {
+    match filter {
+        PacketSequenceFilter::Range { start, end } if start > end => {
+            Err(QueryError::InvalidRequest {
+                description: format!(
+                    "invalid packet sequence range [{start}, {end}]: it must be non-empty"
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,537 @@
+//! Defines the request and response types of the channel queries that are not
+//! part of the `ibc-proto` gRPC query service.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use ibc::core::channel::types::proto::upgrade::{
+    ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade,
+};
+use ibc_proto::ibc::core::channel::v1::PacketState as RawPacketState;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::error::QueryError;
+
+/// Maximum number of sequences a single [`QueryPacketReceiptsRequest`] may
+/// cover.
+pub const MAX_PACKET_RECEIPTS_PER_QUERY: u64 = 10_000;
+
+/// Maximum number of sequences in each of the responses streamed by the
+/// unreceived packets and acknowledgements streaming queries, keeping every
+/// message well below the default 4 MiB limit of gRPC.
+pub const UNRECEIVED_SEQUENCES_PER_CHUNK: usize = 10_000;
+
+/// The sequences covered by a [`QueryPacketReceiptsRequest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketReceiptSequences {
+    /// An arbitrary list of sequences.
+    List(Vec<u64>),
+    /// All the sequences from `start` to `end`, both inclusive.
+    Range { start: u64, end: u64 },
+}
+
+/// Request for the receipt status of several packets of a channel at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryPacketReceiptsRequest {
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequences: PacketReceiptSequences,
+}
+
+/// The raw, protobuf-encoded form of a [`QueryPacketReceiptsRequest`].
+///
+/// The sequences are given either as a list or as a range, but not both.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct RawQueryPacketReceiptsRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(uint64, repeated, tag = "3")]
+    pub sequences: Vec<u64>,
+    #[prost(message, optional, tag = "4")]
+    pub range: Option<RawPacketSequenceRange>,
+}
+
+/// The raw, protobuf-encoded form of [`PacketReceiptSequences::Range`].
+#[derive(Clone, Copy, PartialEq, Eq, prost::Message)]
+pub struct RawPacketSequenceRange {
+    #[prost(uint64, tag = "1")]
+    pub start: u64,
+    #[prost(uint64, tag = "2")]
+    pub end: u64,
+}
+
+impl TryFrom<RawQueryPacketReceiptsRequest> for QueryPacketReceiptsRequest {
+    type Error = QueryError;
+
+    fn try_from(raw: RawQueryPacketReceiptsRequest) -> Result<Self, Self::Error> {
+        let sequences = match raw.range {
+            Some(_) if !raw.sequences.is_empty() => {
+                return Err(QueryError::InvalidRequest {
+                    description: "both a list and a range of sequences given".to_string(),
+                })
+            }
+            Some(RawPacketSequenceRange { start, end }) => {
+                PacketReceiptSequences::Range { start, end }
+            }
+            None => PacketReceiptSequences::List(raw.sequences),
+        };
+
+        Ok(Self {
+            port_id: raw.port_id,
+            channel_id: raw.channel_id,
+            sequences,
+        })
+    }
+}
+
+impl From<QueryPacketReceiptsRequest> for RawQueryPacketReceiptsRequest {
+    fn from(request: QueryPacketReceiptsRequest) -> Self {
+        let (sequences, range) = match request.sequences {
+            PacketReceiptSequences::List(sequences) => (sequences, None),
+            PacketReceiptSequences::Range { start, end } => {
+                (Vec::new(), Some(RawPacketSequenceRange { start, end }))
+            }
+        };
+
+        Self {
+            port_id: request.port_id,
+            channel_id: request.channel_id,
+            sequences,
+            range,
+        }
+    }
+}
+
+/// The receipt status of a single packet.
+#[derive(Clone, Copy, PartialEq, Eq, prost::Message)]
+pub struct PacketReceiptStatus {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(bool, tag = "2")]
+    pub received: bool,
+}
+
+/// Response to a [`QueryPacketReceiptsRequest`].
+///
+/// Receipts are returned in the order of the requested sequences and without
+/// proofs; relayers needing a proof for a given packet should issue a
+/// `PacketReceipt` query for it.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryPacketReceiptsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub receipts: Vec<PacketReceiptStatus>,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+/// The sequences a [`QueryFilteredPacketCommitmentsRequest`] or a
+/// [`QueryFilteredPacketAcknowledgementsRequest`] is restricted to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketSequenceFilter {
+    /// An arbitrary list of sequences.
+    List(Vec<u64>),
+    /// All the sequences from `start` to `end`, both inclusive.
+    Range { start: u64, end: u64 },
+}
+
+impl PacketSequenceFilter {
+    /// Returns whether the given sequence passes the filter.
+    pub fn contains(&self, sequence: u64) -> bool {
+        match self {
+            Self::List(sequences) => sequences.contains(&sequence),
+            Self::Range { start, end } => (*start..=*end).contains(&sequence),
+        }
+    }
+}
+
+/// The raw, protobuf-encoded form of [`PacketSequenceFilter::List`].
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct RawPacketSequenceList {
+    #[prost(uint64, repeated, tag = "1")]
+    pub sequences: Vec<u64>,
+}
+
+/// Converts the raw list and range of a filtered query into the filter they
+/// stand for, rejecting requests giving both.
+fn sequence_filter_from_raw(
+    list: Option<RawPacketSequenceList>,
+    range: Option<RawPacketSequenceRange>,
+) -> Result<Option<PacketSequenceFilter>, QueryError> {
+    match (list, range) {
+        (Some(_), Some(_)) => Err(QueryError::InvalidRequest {
+            description: "both a list and a range of sequences given".to_string(),
+        }),
+        (Some(RawPacketSequenceList { sequences }), None) => {
+            Ok(Some(PacketSequenceFilter::List(sequences)))
+        }
+        (None, Some(RawPacketSequenceRange { start, end })) => {
+            Ok(Some(PacketSequenceFilter::Range { start, end }))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Converts a filter into the raw list and range of a filtered query.
+fn sequence_filter_to_raw(
+    filter: Option<PacketSequenceFilter>,
+) -> (
+    Option<RawPacketSequenceList>,
+    Option<RawPacketSequenceRange>,
+) {
+    match filter {
+        Some(PacketSequenceFilter::List(sequences)) => {
+            (Some(RawPacketSequenceList { sequences }), None)
+        }
+        Some(PacketSequenceFilter::Range { start, end }) => {
+            (None, Some(RawPacketSequenceRange { start, end }))
+        }
+        None => (None, None),
+    }
+}
+
+/// Request for the packet commitments of a channel, optionally restricted to
+/// some sequences.
+///
+/// Unlike the `ibc-proto` `PacketCommitments` query, only the matching
+/// commitments are returned, e.g. for relayers resuming from a checkpoint on a
+/// busy channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryFilteredPacketCommitmentsRequest {
+    pub port_id: String,
+    pub channel_id: String,
+    /// Returns all the commitments of the channel if omitted.
+    pub sequences: Option<PacketSequenceFilter>,
+}
+
+/// The raw, protobuf-encoded form of a [`QueryFilteredPacketCommitmentsRequest`].
+///
+/// The sequences are given either as a list or as a range, but not both.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct RawQueryFilteredPacketCommitmentsRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub list: Option<RawPacketSequenceList>,
+    #[prost(message, optional, tag = "4")]
+    pub range: Option<RawPacketSequenceRange>,
+}
+
+impl TryFrom<RawQueryFilteredPacketCommitmentsRequest> for QueryFilteredPacketCommitmentsRequest {
+    type Error = QueryError;
+
+    fn try_from(raw: RawQueryFilteredPacketCommitmentsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: raw.port_id,
+            channel_id: raw.channel_id,
+            sequences: sequence_filter_from_raw(raw.list, raw.range)?,
+        })
+    }
+}
+
+impl From<QueryFilteredPacketCommitmentsRequest> for RawQueryFilteredPacketCommitmentsRequest {
+    fn from(request: QueryFilteredPacketCommitmentsRequest) -> Self {
+        let (list, range) = sequence_filter_to_raw(request.sequences);
+
+        Self {
+            port_id: request.port_id,
+            channel_id: request.channel_id,
+            list,
+            range,
+        }
+    }
+}
+
+/// Response to a [`QueryFilteredPacketCommitmentsRequest`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryFilteredPacketCommitmentsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub commitments: Vec<RawPacketState>,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+/// Request for the packet acknowledgements of a channel, optionally
+/// restricted to some sequences.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryFilteredPacketAcknowledgementsRequest {
+    pub port_id: String,
+    pub channel_id: String,
+    /// Returns all the acknowledgements of the channel if omitted.
+    pub sequences: Option<PacketSequenceFilter>,
+}
+
+/// The raw, protobuf-encoded form of a
+/// [`QueryFilteredPacketAcknowledgementsRequest`].
+///
+/// The sequences are given either as a list or as a range, but not both.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct RawQueryFilteredPacketAcknowledgementsRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub list: Option<RawPacketSequenceList>,
+    #[prost(message, optional, tag = "4")]
+    pub range: Option<RawPacketSequenceRange>,
+}
+
+impl TryFrom<RawQueryFilteredPacketAcknowledgementsRequest>
+    for QueryFilteredPacketAcknowledgementsRequest
+{
+    type Error = QueryError;
+
+    fn try_from(raw: RawQueryFilteredPacketAcknowledgementsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: raw.port_id,
+            channel_id: raw.channel_id,
+            sequences: sequence_filter_from_raw(raw.list, raw.range)?,
+        })
+    }
+}
+
+impl From<QueryFilteredPacketAcknowledgementsRequest>
+    for RawQueryFilteredPacketAcknowledgementsRequest
+{
+    fn from(request: QueryFilteredPacketAcknowledgementsRequest) -> Self {
+        let (list, range) = sequence_filter_to_raw(request.sequences);
+
+        Self {
+            port_id: request.port_id,
+            channel_id: request.channel_id,
+            list,
+            range,
+        }
+    }
+}
+
+/// Response to a [`QueryFilteredPacketAcknowledgementsRequest`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryFilteredPacketAcknowledgementsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub acknowledgements: Vec<RawPacketState>,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+/// The stage of its lifecycle a packet is at, as told by the state of one of
+/// the ends of its channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum PacketStatus {
+    /// The packet was not sent, or not received, yet.
+    Unsent = 0,
+    /// The packet was sent, and waits to be acknowledged or to time out.
+    Committed = 1,
+    /// The packet was received, and its acknowledgement is not written yet.
+    Received = 2,
+    /// The acknowledgement of the packet was written, by the receiving end, or
+    /// processed, by the sending end.
+    Acknowledged = 3,
+    /// The packet timed out, and the ordered channel it was sent on closed.
+    TimedOut = 4,
+    /// The packet was acknowledged or timed out, which the state of unordered
+    /// channels does not tell apart.
+    AcknowledgedOrTimedOut = 5,
+}
+
+/// The lifecycle status of the packets of a given sequence on both directions
+/// of a channel end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketLifecycleStatus {
+    /// The status of the packet sent from the channel end, which is
+    /// [`Unsent`](PacketStatus::Unsent), [`Committed`](PacketStatus::Committed),
+    /// or acknowledged or timed out.
+    pub sent: PacketStatus,
+    /// The status of the packet received by the channel end, which is
+    /// [`Unsent`](PacketStatus::Unsent), [`Received`](PacketStatus::Received),
+    /// or [`Acknowledged`](PacketStatus::Acknowledged).
+    pub received: PacketStatus,
+}
+
+/// Request for the lifecycle status of a packet of a channel.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryPacketStatusRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
+}
+
+/// Response to a [`QueryPacketStatusRequest`].
+///
+/// The status is derived from the packet commitments, receipts and
+/// acknowledgements of the channel end, e.g. for wallets showing the progress
+/// of a transfer by querying both chains of the channel. The `sent` and
+/// `received` fields hold the [`PacketStatus`] of either direction, as
+/// returned by the [`sent`](Self::sent) and [`received`](Self::received)
+/// accessors.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryPacketStatusResponse {
+    #[prost(enumeration = "PacketStatus", tag = "1")]
+    pub sent: i32,
+    #[prost(enumeration = "PacketStatus", tag = "2")]
+    pub received: i32,
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<RawHeight>,
+}
+
+impl QueryPacketStatusResponse {
+    /// Returns the lifecycle status of the packet on both directions of the
+    /// channel end.
+    pub fn status(&self) -> PacketLifecycleStatus {
+        PacketLifecycleStatus {
+            sent: self.sent(),
+            received: self.received(),
+        }
+    }
+}
+
+/// Request for the sequence of the next channel identifier.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextChannelSequenceRequest {}
+
+/// Response to a [`QueryNextChannelSequenceRequest`].
+///
+/// The next channel identifier is formed from this sequence, e.g. for
+/// predicting identifiers in pre-signed governance proposals.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextChannelSequenceResponse {
+    #[prost(uint64, tag = "1")]
+    pub next_channel_sequence: u64,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+/// Request for the upgrade attempt in progress on a channel.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryChannelUpgradeRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+/// Response to a [`QueryChannelUpgradeRequest`].
+///
+/// Relayers use the proven upgrade to relay the upgrade handshake to the
+/// counterparty.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryChannelUpgradeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub upgrade: Option<RawUpgrade>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<RawHeight>,
+}
+
+/// Request for the receipt of the last failed upgrade attempt of a channel.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryChannelUpgradeErrorRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+/// Response to a [`QueryChannelUpgradeErrorRequest`].
+///
+/// The proven receipt lets the counterparty cancel its side of the failed
+/// attempt.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryChannelUpgradeErrorResponse {
+    #[prost(message, optional, tag = "1")]
+    pub error_receipt: Option<RawErrorReceipt>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<RawHeight>,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_raw_packet_receipts_request_round_trip() {
+        for sequences in [
+            PacketReceiptSequences::List(vec![1, 3, 5]),
+            PacketReceiptSequences::Range { start: 2, end: 9 },
+        ] {
+            let request = QueryPacketReceiptsRequest {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                sequences,
+            };
+
+            let raw = RawQueryPacketReceiptsRequest::from(request.clone());
+
+            assert_eq!(
+                QueryPacketReceiptsRequest::try_from(raw).ok(),
+                Some(request)
+            );
+        }
+    }
+
+    #[test]
+    fn test_raw_packet_receipts_request_with_list_and_range() {
+        let raw = RawQueryPacketReceiptsRequest {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+            sequences: vec![1],
+            range: Some(RawPacketSequenceRange { start: 2, end: 9 }),
+        };
+
+        assert!(matches!(
+            QueryPacketReceiptsRequest::try_from(raw),
+            Err(QueryError::InvalidRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn test_raw_filtered_packet_commitments_request_round_trip() {
+        for sequences in [
+            None,
+            Some(PacketSequenceFilter::List(vec![])),
+            Some(PacketSequenceFilter::List(vec![1, 3, 5])),
+            Some(PacketSequenceFilter::Range { start: 2, end: 9 }),
+        ] {
+            let request = QueryFilteredPacketCommitmentsRequest {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                sequences,
+            };
+
+            let raw = RawQueryFilteredPacketCommitmentsRequest::from(request.clone());
+
+            assert_eq!(
+                QueryFilteredPacketCommitmentsRequest::try_from(raw).ok(),
+                Some(request)
+            );
+        }
+    }
+
+    #[test]
+    fn test_raw_filtered_packet_acknowledgements_request_with_list_and_range() {
+        let raw = RawQueryFilteredPacketAcknowledgementsRequest {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+            list: Some(RawPacketSequenceList { sequences: vec![1] }),
+            range: Some(RawPacketSequenceRange { start: 2, end: 9 }),
+        };
+
+        assert!(matches!(
+            QueryFilteredPacketAcknowledgementsRequest::try_from(raw),
+            Err(QueryError::InvalidRequest { .. })
+        ));
+    }
+}
@@ -1,5 +1,8 @@
 mod query;
 mod service;
+pub mod typed;
+mod types;
 
 pub use query::*;
 pub use service::*;
+pub use types::*;
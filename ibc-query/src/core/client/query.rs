@@ -1,30 +1,32 @@
 //! Provides utility functions for querying IBC client states.
 
-use alloc::format;
 use core::str::FromStr;
 
-use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::types::error::ClientError;
+use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::types::path::{
-    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
-};
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
 use ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateRequest,
-    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatesResponse,
-    QueryClientStatusRequest, QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
     QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
     QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
     QueryUpgradedConsensusStateResponse,
 };
 
+use super::{
+    typed, QueryClientExpiryRequest, QueryClientExpiryResponse, QueryNextClientSequenceRequest,
+    QueryNextClientSequenceResponse,
+};
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::{paginate_from, with_total};
 
 /// Queries for the client state of a given client id.
 pub fn query_client_state<I>(
@@ -37,47 +39,40 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let client_state = ibc_ctx.client_state(&client_id)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(client_id.clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for client state path: {client_id:?}"),
-        })?;
+    let client_state = typed::client_state(ibc_ctx, &client_id)?;
 
     Ok(QueryClientStateResponse {
-        client_state: Some(client_state.into()),
-        proof,
-        proof_height: Some(current_height.into()),
+        client_state: Some(client_state.value.into()),
+        proof: client_state.proof,
+        proof_height: Some(client_state.proof_height.into()),
     })
 }
 
 /// Queries for all the existing client states.
 pub fn query_client_states<I>(
     ibc_ctx: &I,
-    _request: &QueryClientStatesRequest,
+    request: &QueryClientStatesRequest,
 ) -> Result<QueryClientStatesResponse, QueryError>
 where
     I: QueryContext,
     <I as ValidationContext>::AnyClientState: Into<Any>,
 {
-    let client_states = ibc_ctx.client_states()?;
+    let (client_states, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.client_states_from(start_key, reverse)
+        })?;
 
     Ok(QueryClientStatesResponse {
         client_states: client_states
             .into_iter()
-            .map(|(id, state)| IdentifiedClientState {
-                client_id: id.into(),
-                client_state: Some(state.into()),
+            .map(|(client_id, client_state)| IdentifiedClientState {
+                client_id: client_id.into(),
+                client_state: Some(client_state.into()),
             })
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination: with_total(pagination, request.pagination.as_ref(), || {
+            ibc_ctx.client_count()
+        })?,
     })
 }
 
@@ -92,45 +87,18 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let (height, consensus_state) = if request.latest_height {
-        ibc_ctx
-            .consensus_states(&client_id)?
-            .into_iter()
-            .max_by_key(|(h, _)| *h)
-            .ok_or(QueryError::ProofNotFound {
-                description: format!("No consensus state found for client: {client_id:?}"),
-            })?
+    let consensus_state = if request.latest_height {
+        typed::latest_consensus_state(ibc_ctx, &client_id)?
     } else {
         let height = Height::new(request.revision_number, request.revision_height)?;
 
-        let consensus_state = ibc_ctx.consensus_state(&ClientConsensusStatePath::new(
-            client_id.clone(),
-            height.revision_number(),
-            height.revision_height(),
-        ))?;
-
-        (height, consensus_state)
+        typed::consensus_state(ibc_ctx, &client_id, height)?
     };
 
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConsensusState(ClientConsensusStatePath::new(
-                client_id.clone(),
-                height.revision_number(),
-                height.revision_height(),
-            )),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for consensus state path: {client_id:?}"),
-        })?;
-
     Ok(QueryConsensusStateResponse {
-        consensus_state: Some(consensus_state.into()),
-        proof,
-        proof_height: Some(current_height.into()),
+        consensus_state: Some(consensus_state.value.consensus_state.into()),
+        proof: consensus_state.proof,
+        proof_height: Some(consensus_state.proof_height.into()),
     })
 }
 
@@ -145,18 +113,20 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_states = ibc_ctx.consensus_states(&client_id)?;
+    let (consensus_states, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.consensus_states_from(&client_id, start_key, reverse)
+        })?;
 
     Ok(QueryConsensusStatesResponse {
         consensus_states: consensus_states
             .into_iter()
-            .map(|(height, state)| ConsensusStateWithHeight {
+            .map(|(height, consensus_state)| ConsensusStateWithHeight {
                 height: Some(height.into()),
-                consensus_state: Some(state.into()),
+                consensus_state: Some(consensus_state.into()),
             })
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination,
     })
 }
 
@@ -170,15 +140,17 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&client_id)?;
+    let (consensus_state_heights, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.consensus_state_heights_from(&client_id, start_key, reverse)
+        })?;
 
     Ok(QueryConsensusStateHeightsResponse {
         consensus_state_heights: consensus_state_heights
             .into_iter()
             .map(|height| height.into())
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination,
     })
 }
 
@@ -192,15 +164,50 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let client_state = ibc_ctx.client_state(&client_id)?;
-    let client_validation_ctx = ibc_ctx.get_client_validation_context();
-    let client_status = client_state.status(client_validation_ctx, &client_id)?;
+    let client_status = typed::client_status(ibc_ctx, &client_id)?;
 
     Ok(QueryClientStatusResponse {
         status: format!("{client_status}"),
     })
 }
 
+/// Queries for the parameters of the client submodule.
+pub fn query_client_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryClientParamsRequest,
+) -> Result<QueryClientParamsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(QueryClientParamsResponse {
+        params: Some(typed::client_params(ibc_ctx)?.into()),
+    })
+}
+
+/// Queries for the expiry of a Tendermint client.
+pub fn query_client_expiry<I>(
+    ibc_ctx: &I,
+    request: &QueryClientExpiryRequest,
+) -> Result<QueryClientExpiryResponse, QueryError>
+where
+    I: ValidationContext,
+    I::V: TmValidationContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+{
+    let client_id = ClientId::from_str(request.client_id.as_str())?;
+
+    let client_expiry = typed::tendermint_client_expiry(ibc_ctx, &client_id)?;
+
+    Ok(QueryClientExpiryResponse {
+        expires_at: client_expiry
+            .value
+            .expires_at
+            .map_or(0, |expires_at| expires_at.nanoseconds()),
+        time_until_expiry: Some(client_expiry.value.time_until_expiry.into()),
+        height: Some(client_expiry.height.into()),
+    })
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<U>(
     upgrade_ctx: &U,
@@ -210,13 +217,7 @@ where
     U: UpgradeValidationContext,
     <U as UpgradeValidationContext>::AnyClientState: Into<Any>,
 {
-    let plan = upgrade_ctx.upgrade_plan().map_err(ClientError::from)?;
-
-    let upgraded_client_state_path = UpgradeClientPath::UpgradedClientState(plan.height);
-
-    let upgraded_client_state = upgrade_ctx
-        .upgraded_client_state(&upgraded_client_state_path)
-        .map_err(ClientError::from)?;
+    let upgraded_client_state = typed::upgraded_client_state(upgrade_ctx)?;
 
     Ok(QueryUpgradedClientStateResponse {
         upgraded_client_state: Some(upgraded_client_state.into()),
@@ -232,16 +233,26 @@ where
     U: UpgradeValidationContext,
     <U as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
 {
-    let plan = upgrade_ctx.upgrade_plan().map_err(ClientError::from)?;
-
-    let upgraded_consensus_state_path =
-        UpgradeClientPath::UpgradedClientConsensusState(plan.height);
-
-    let upgraded_consensus_state = upgrade_ctx
-        .upgraded_consensus_state(&upgraded_consensus_state_path)
-        .map_err(ClientError::from)?;
+    let upgraded_consensus_state = typed::upgraded_consensus_state(upgrade_ctx)?;
 
     Ok(QueryUpgradedConsensusStateResponse {
         upgraded_consensus_state: Some(upgraded_consensus_state.into()),
     })
 }
+
+/// Queries for the sequence of the next client identifier, i.e. the number of
+/// clients created so far.
+pub fn query_next_client_sequence<I>(
+    ibc_ctx: &I,
+    _request: &QueryNextClientSequenceRequest,
+) -> Result<QueryNextClientSequenceResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let next_client_sequence = typed::next_client_sequence(ibc_ctx)?;
+
+    Ok(QueryNextClientSequenceResponse {
+        next_client_sequence: next_client_sequence.value,
+        height: Some(next_client_sequence.height.into()),
+    })
+}
@@ -2,6 +2,8 @@
 //! `I` must be a type where writes from one thread are readable from another.
 //! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 
+use alloc::sync::Arc;
+
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext;
@@ -19,13 +21,13 @@ use ibc_proto::ibc::core::client::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_client_state, query_client_states, query_client_status, query_consensus_state,
-    query_consensus_state_heights, query_consensus_states, query_upgraded_client_state,
-    query_upgraded_consensus_state,
+    query_client_params, query_client_state, query_client_states, query_client_status,
+    query_consensus_state, query_consensus_state_heights, query_consensus_states,
+    query_upgraded_client_state, query_upgraded_consensus_state,
 };
+use crate::cache::{cached_query, QueryCache};
 use crate::core::context::QueryContext;
-
-// TODO(rano): currently the services don't support pagination, so we return all the results.
+use crate::pagination::page_cache_key;
 
 /// Generics `I` and `U` must be a type where writes from one thread are readable from another.
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
@@ -40,6 +42,7 @@ where
 {
     ibc_context: I,
     upgrade_context: U,
+    cache: Option<Arc<QueryCache>>,
 }
 
 impl<I, U> ClientQueryService<I, U>
@@ -57,8 +60,16 @@ where
         Self {
             ibc_context,
             upgrade_context,
+            cache: None,
         }
     }
+
+    /// Caches the responses of the expensive list queries in the given cache.
+    #[cfg(feature = "std")]
+    pub fn with_cache(mut self, cache: Arc<QueryCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
 }
 
 #[tonic::async_trait]
@@ -84,7 +95,19 @@ where
         &self,
         request: Request<QueryClientStatesRequest>,
     ) -> Result<Response<QueryClientStatesResponse>, Status> {
-        let response = query_client_states(&self.ibc_context, request.get_ref())?;
+        let request = request.get_ref();
+
+        let response = cached_query(
+            self.cache.as_deref(),
+            &self.ibc_context,
+            || {
+                format!(
+                    "client_states/{}",
+                    page_cache_key(request.pagination.as_ref())
+                )
+            },
+            || query_client_states(&self.ibc_context, request),
+        )?;
 
         Ok(Response::new(response))
     }
@@ -127,11 +150,11 @@ where
 
     async fn client_params(
         &self,
-        _request: Request<QueryClientParamsRequest>,
+        request: Request<QueryClientParamsRequest>,
     ) -> Result<Response<QueryClientParamsResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying ClientParams is not supported yet",
-        ))
+        let response = query_client_params(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
     }
 
     async fn upgraded_client_state(
@@ -0,0 +1,240 @@
+//! Provides typed functions for querying IBC client states, returning domain
+//! types instead of `ibc-proto` responses.
+
+use ibc::clients::tendermint::client_state::expires_at;
+use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
+use ibc::clients::tendermint::types::ClientState as TmClientStateType;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::{ClientParams, Height, Status};
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext;
+
+use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::typed::{
+    prove, AtHeight, ClientExpiry, ConsensusStateWithHeight, IdentifiedClientState, Proven,
+};
+use crate::error::QueryError;
+
+/// Returns the client state of the given client, with its proof.
+pub fn client_state<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Proven<I::AnyClientState>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let client_state = ibc_ctx.client_state(client_id)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientState(ClientStatePath::new(client_id.clone())),
+        client_state,
+        || format!("Proof not found for client state path: {client_id:?}"),
+    )
+}
+
+/// Returns all the existing client states.
+pub fn client_states<I>(
+    ibc_ctx: &I,
+) -> Result<Vec<IdentifiedClientState<I::AnyClientState>>, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(ibc_ctx
+        .client_states()?
+        .into_iter()
+        .map(|(client_id, client_state)| IdentifiedClientState {
+            client_id,
+            client_state,
+        })
+        .collect())
+}
+
+/// Returns the consensus state of the given client at `height`, with its
+/// proof.
+pub fn consensus_state<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<Proven<ConsensusStateWithHeight<I::AnyConsensusState>>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let consensus_state = ibc_ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?;
+
+    prove_consensus_state(ibc_ctx, client_id, height, consensus_state)
+}
+
+/// Returns the consensus state of the given client at its latest height,
+/// with its proof.
+pub fn latest_consensus_state<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Proven<ConsensusStateWithHeight<I::AnyConsensusState>>, QueryError>
+where
+    I: QueryContext,
+{
+    let (height, consensus_state) = ibc_ctx
+        .consensus_states(client_id)?
+        .into_iter()
+        .max_by_key(|(h, _)| *h)
+        .ok_or(QueryError::ProofNotFound {
+            description: format!("No consensus state found for client: {client_id:?}"),
+        })?;
+
+    prove_consensus_state(ibc_ctx, client_id, height, consensus_state)
+}
+
+fn prove_consensus_state<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+    height: Height,
+    consensus_state: I::AnyConsensusState,
+) -> Result<Proven<ConsensusStateWithHeight<I::AnyConsensusState>>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    prove(
+        ibc_ctx,
+        &Path::ClientConsensusState(ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        )),
+        ConsensusStateWithHeight {
+            height,
+            consensus_state,
+        },
+        || format!("Proof not found for consensus state path: {client_id:?}"),
+    )
+}
+
+/// Returns all the consensus states of the given client.
+pub fn consensus_states<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Vec<ConsensusStateWithHeight<I::AnyConsensusState>>, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(ibc_ctx
+        .consensus_states(client_id)?
+        .into_iter()
+        .map(|(height, consensus_state)| ConsensusStateWithHeight {
+            height,
+            consensus_state,
+        })
+        .collect())
+}
+
+/// Returns the heights of all the consensus states of the given client.
+pub fn consensus_state_heights<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Vec<Height>, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(ibc_ctx.consensus_state_heights(client_id)?)
+}
+
+/// Returns the status of the given client.
+pub fn client_status<I>(ibc_ctx: &I, client_id: &ClientId) -> Result<Status, QueryError>
+where
+    I: ValidationContext,
+{
+    let client_state = ibc_ctx.client_state(client_id)?;
+    let client_validation_ctx = ibc_ctx.get_client_validation_context();
+
+    Ok(client_state.status(client_validation_ctx, client_id)?)
+}
+
+/// Returns the parameters of the client submodule.
+pub fn client_params<I>(ibc_ctx: &I) -> Result<ClientParams, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(ibc_ctx.client_params()?)
+}
+
+/// Returns the expiry of the given Tendermint client at the current height of
+/// the host, e.g. for monitoring systems to alert before the client expires.
+pub fn tendermint_client_expiry<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<AtHeight<ClientExpiry>, QueryError>
+where
+    I: ValidationContext,
+    I::V: TmValidationContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+{
+    let client_state = TmClientStateType::try_from(ibc_ctx.client_state(client_id)?.into())?;
+    let expires_at = expires_at(
+        &client_state,
+        ibc_ctx.get_client_validation_context(),
+        client_id,
+    )?;
+    let now = ibc_ctx.host_timestamp()?;
+
+    Ok(AtHeight {
+        value: ClientExpiry {
+            expires_at,
+            time_until_expiry: expires_at
+                .map(|expires_at| expires_at.saturating_duration_since(&now))
+                .unwrap_or_default(),
+        },
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the client state committed by the planned upgrade.
+pub fn upgraded_client_state<U>(upgrade_ctx: &U) -> Result<U::AnyClientState, QueryError>
+where
+    U: UpgradeValidationContext,
+{
+    let plan = upgrade_ctx.upgrade_plan().map_err(ClientError::from)?;
+
+    let upgraded_client_state_path = UpgradeClientPath::UpgradedClientState(plan.height);
+
+    Ok(upgrade_ctx
+        .upgraded_client_state(&upgraded_client_state_path)
+        .map_err(ClientError::from)?)
+}
+
+/// Returns the consensus state committed by the planned upgrade.
+pub fn upgraded_consensus_state<U>(upgrade_ctx: &U) -> Result<U::AnyConsensusState, QueryError>
+where
+    U: UpgradeValidationContext,
+{
+    let plan = upgrade_ctx.upgrade_plan().map_err(ClientError::from)?;
+
+    let upgraded_consensus_state_path =
+        UpgradeClientPath::UpgradedClientConsensusState(plan.height);
+
+    Ok(upgrade_ctx
+        .upgraded_consensus_state(&upgraded_consensus_state_path)
+        .map_err(ClientError::from)?)
+}
+
+/// Returns the sequence of the next client identifier, i.e. the number of
+/// clients created so far.
+pub fn next_client_sequence<I>(ibc_ctx: &I) -> Result<AtHeight<u64>, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(AtHeight {
+        value: ibc_ctx.client_counter()?,
+        height: ibc_ctx.host_height()?,
+    })
+}
@@ -0,0 +1,45 @@
+//! Defines the request and response types of the client queries that are not
+//! part of the `ibc-proto` gRPC query service.
+
+use ibc::core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Duration as RawDuration;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+/// Request for the sequence of the next client identifier.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextClientSequenceRequest {}
+
+/// Response to a [`QueryNextClientSequenceRequest`].
+///
+/// The next client identifier is formed from this sequence, e.g. for
+/// predicting identifiers in pre-signed governance proposals, unless the host
+/// overrides `ValidationContext::allocate_client_id`.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextClientSequenceResponse {
+    #[prost(uint64, tag = "1")]
+    pub next_client_sequence: u64,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
+
+/// Request for the expiry of a Tendermint client.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryClientExpiryRequest {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+}
+
+/// Response to a [`QueryClientExpiryRequest`].
+///
+/// The expiry is given in nanoseconds since the Unix epoch, and is zero if the
+/// client has no consensus state at its latest height, i.e. is already
+/// expired, in which case no time is left until the expiry either.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryClientExpiryResponse {
+    #[prost(uint64, tag = "1")]
+    pub expires_at: u64,
+    #[prost(message, optional, tag = "2")]
+    pub time_until_expiry: Option<RawDuration>,
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<RawHeight>,
+}
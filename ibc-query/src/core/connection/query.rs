@@ -1,27 +1,25 @@
 //! Provides utility functions for querying IBC connection states.
 
-use alloc::format;
-use alloc::vec::Vec;
 use core::str::FromStr;
 
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
-use ibc::core::host::types::path::{
-    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
-};
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 use ibc_proto::ibc::core::connection::v1::{
-    Params as ConnectionParams, QueryClientConnectionsRequest, QueryClientConnectionsResponse,
+    QueryClientConnectionsRequest, QueryClientConnectionsResponse,
     QueryConnectionClientStateRequest, QueryConnectionClientStateResponse,
     QueryConnectionConsensusStateRequest, QueryConnectionConsensusStateResponse,
     QueryConnectionParamsRequest, QueryConnectionParamsResponse, QueryConnectionRequest,
     QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
 };
 
+use super::{typed, QueryNextConnectionSequenceRequest, QueryNextConnectionSequenceResponse};
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::{paginate_from, with_total};
 
 /// Queries for the connection end of a given connection id.
 pub fn query_connection<I>(
@@ -33,41 +31,36 @@ where
 {
     let connection_id = ConnectionId::from_str(request.connection_id.as_str())?;
 
-    let connection_end = ibc_ctx.connection_end(&connection_id)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::Connection(ConnectionPath::new(&connection_id)),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for connection path: {connection_id:?}"),
-        })?;
+    let connection_end = typed::connection(ibc_ctx, &connection_id)?;
 
     Ok(QueryConnectionResponse {
-        connection: Some(connection_end.into()),
-        proof,
-        proof_height: Some(current_height.into()),
+        connection: Some(connection_end.value.into()),
+        proof: connection_end.proof,
+        proof_height: Some(connection_end.proof_height.into()),
     })
 }
 
 /// Queries for all the existing connection ends.
 pub fn query_connections<I>(
     ibc_ctx: &I,
-    _request: &QueryConnectionsRequest,
+    request: &QueryConnectionsRequest,
 ) -> Result<QueryConnectionsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.connection_ends()?;
+    let height = ibc_ctx.host_height()?;
+
+    let (connections_page, pagination) =
+        paginate_from(request.pagination.as_ref(), |start_key, reverse| {
+            ibc_ctx.connection_ends_from(start_key, reverse)
+        })?;
 
     Ok(QueryConnectionsResponse {
-        connections: connections.into_iter().map(Into::into).collect(),
-        height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        connections: connections_page.into_iter().map(Into::into).collect(),
+        height: Some(height.into()),
+        pagination: with_total(pagination, request.pagination.as_ref(), || {
+            ibc_ctx.connection_count()
+        })?,
     })
 }
 
@@ -82,23 +75,16 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let connections = ibc_ctx.client_connection_ends(&client_id)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof: Vec<u8> = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConnection(ClientConnectionPath::new(client_id.clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for client connection path: {client_id:?}"),
-        })?;
+    let connections = typed::client_connections(ibc_ctx, &client_id)?;
 
     Ok(QueryClientConnectionsResponse {
-        connection_paths: connections.into_iter().map(|x| x.as_str().into()).collect(),
-        proof,
-        proof_height: Some(current_height.into()),
+        connection_paths: connections
+            .value
+            .into_iter()
+            .map(|x| x.as_str().into())
+            .collect(),
+        proof: connections.proof,
+        proof_height: Some(connections.proof_height.into()),
     })
 }
 
@@ -113,31 +99,15 @@ where
 {
     let connection_id = ConnectionId::from_str(request.connection_id.as_str())?;
 
-    let connection_end = ibc_ctx.connection_end(&connection_id)?;
-
-    let client_state = ibc_ctx.client_state(connection_end.client_id())?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let client_state = typed::connection_client_state(ibc_ctx, &connection_id)?;
 
     Ok(QueryConnectionClientStateResponse {
         identified_client_state: Some(IdentifiedClientState {
-            client_id: connection_end.client_id().as_str().into(),
-            client_state: Some(client_state.into()),
+            client_id: client_state.value.client_id.as_str().into(),
+            client_state: Some(client_state.value.client_state.into()),
         }),
-        proof,
-        proof_height: Some(current_height.into()),
+        proof: client_state.proof,
+        proof_height: Some(client_state.proof_height.into()),
     })
 }
 
@@ -152,34 +122,16 @@ where
 {
     let connection_id = ConnectionId::from_str(request.connection_id.as_str())?;
 
-    let connection_end = ibc_ctx.connection_end(&connection_id)?;
-
     let height = Height::new(request.revision_number, request.revision_height)?;
 
-    let consensus_path = ClientConsensusStatePath::new(
-        connection_end.client_id().clone(),
-        height.revision_number(),
-        height.revision_height(),
-    );
-
-    let consensus_state = ibc_ctx.consensus_state(&consensus_path)?;
-
-    let current_height = ibc_ctx.host_height()?;
-
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::ClientConsensusState(consensus_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for consensus state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let consensus_state = typed::connection_consensus_state(ibc_ctx, &connection_id, height)?;
+    let (client_id, state) = consensus_state.value;
 
     Ok(QueryConnectionConsensusStateResponse {
-        consensus_state: Some(consensus_state.into()),
-        client_id: connection_end.client_id().as_str().into(),
-        proof,
-        proof_height: Some(current_height.into()),
+        consensus_state: Some(state.into()),
+        client_id: client_id.as_str().into(),
+        proof: consensus_state.proof,
+        proof_height: Some(consensus_state.proof_height.into()),
     })
 }
 
@@ -192,8 +144,23 @@ where
     I: QueryContext,
 {
     Ok(QueryConnectionParamsResponse {
-        params: Some(ConnectionParams {
-            max_expected_time_per_block: ibc_ctx.max_expected_time_per_block().as_secs(),
-        }),
+        params: Some(typed::connection_params(ibc_ctx)?.into()),
+    })
+}
+
+/// Queries for the sequence of the next connection identifier, i.e. the number of
+/// connections created so far.
+pub fn query_next_connection_sequence<I>(
+    ibc_ctx: &I,
+    _request: &QueryNextConnectionSequenceRequest,
+) -> Result<QueryNextConnectionSequenceResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let next_connection_sequence = typed::next_connection_sequence(ibc_ctx)?;
+
+    Ok(QueryNextConnectionSequenceResponse {
+        next_connection_sequence: next_connection_sequence.value,
+        height: Some(next_connection_sequence.height.into()),
     })
 }
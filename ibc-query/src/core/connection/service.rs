@@ -2,6 +2,8 @@
 //! `I` must be a type where writes from one thread are readable from another.
 //! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 
+use alloc::sync::Arc;
+
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
@@ -19,9 +21,9 @@ use super::{
     query_client_connections, query_connection, query_connection_client_state,
     query_connection_consensus_state, query_connection_params, query_connections,
 };
+use crate::cache::{cached_query, QueryCache};
 use crate::core::context::QueryContext;
-
-// TODO(rano): currently the services don't support pagination, so we return all the results.
+use crate::pagination::page_cache_key;
 
 /// The generic `I` must be a type where writes from one thread are readable from another.
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
@@ -32,6 +34,7 @@ where
     <I as ValidationContext>::AnyConsensusState: Into<Any>,
 {
     ibc_context: I,
+    cache: Option<Arc<QueryCache>>,
 }
 
 impl<I> ConnectionQueryService<I>
@@ -43,7 +46,17 @@ where
     /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
     /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
     pub fn new(ibc_context: I) -> Self {
-        Self { ibc_context }
+        Self {
+            ibc_context,
+            cache: None,
+        }
+    }
+
+    /// Caches the responses of the expensive list queries in the given cache.
+    #[cfg(feature = "std")]
+    pub fn with_cache(mut self, cache: Arc<QueryCache>) -> Self {
+        self.cache = Some(cache);
+        self
     }
 }
 
@@ -67,7 +80,19 @@ where
         &self,
         request: Request<QueryConnectionsRequest>,
     ) -> Result<Response<QueryConnectionsResponse>, Status> {
-        let response = query_connections(&self.ibc_context, request.get_ref())?;
+        let request = request.get_ref();
+
+        let response = cached_query(
+            self.cache.as_deref(),
+            &self.ibc_context,
+            || {
+                format!(
+                    "connections/{}",
+                    page_cache_key(request.pagination.as_ref())
+                )
+            },
+            || query_connections(&self.ibc_context, request),
+        )?;
 
         Ok(Response::new(response))
     }
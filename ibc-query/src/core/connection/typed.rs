@@ -0,0 +1,148 @@
+//! Provides typed functions for querying IBC connection states, returning
+//! domain types instead of `ibc-proto` responses.
+
+use core::time::Duration;
+
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::{ConnectionEnd, ConnectionParams, IdentifiedConnectionEnd};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{
+    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+
+use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::typed::{prove, AtHeight, IdentifiedClientState, Proven};
+use crate::error::QueryError;
+
+/// Returns the connection end of the given connection, with its proof.
+pub fn connection<I>(
+    ibc_ctx: &I,
+    connection_id: &ConnectionId,
+) -> Result<Proven<ConnectionEnd>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let connection_end = ibc_ctx.connection_end(connection_id)?;
+
+    prove(
+        ibc_ctx,
+        &Path::Connection(ConnectionPath::new(connection_id)),
+        connection_end,
+        || format!("Proof not found for connection path: {connection_id:?}"),
+    )
+}
+
+/// Returns all the existing connection ends.
+pub fn connections<I>(ibc_ctx: &I) -> Result<AtHeight<Vec<IdentifiedConnectionEnd>>, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(AtHeight {
+        value: ibc_ctx.connection_ends()?,
+        height: ibc_ctx.host_height()?,
+    })
+}
+
+/// Returns the identifiers of all the connections of the given client, with
+/// their proof.
+pub fn client_connections<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Proven<Vec<ConnectionId>>, QueryError>
+where
+    I: QueryContext,
+{
+    let connections = ibc_ctx.client_connection_ends(client_id)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientConnection(ClientConnectionPath::new(client_id.clone())),
+        connections,
+        || format!("Proof not found for client connection path: {client_id:?}"),
+    )
+}
+
+/// Returns the client state of the client of the given connection, with its
+/// proof.
+pub fn connection_client_state<I>(
+    ibc_ctx: &I,
+    connection_id: &ConnectionId,
+) -> Result<Proven<IdentifiedClientState<I::AnyClientState>>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let connection_end = ibc_ctx.connection_end(connection_id)?;
+    let client_id = connection_end.client_id();
+
+    let client_state = ibc_ctx.client_state(client_id)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientState(ClientStatePath::new(client_id.clone())),
+        IdentifiedClientState {
+            client_id: client_id.clone(),
+            client_state,
+        },
+        || format!("Proof not found for client state path: {client_id:?}"),
+    )
+}
+
+/// Returns the consensus state at `height` of the client of the given
+/// connection, along with the identifier of the client, with its proof.
+pub fn connection_consensus_state<I>(
+    ibc_ctx: &I,
+    connection_id: &ConnectionId,
+    height: Height,
+) -> Result<Proven<(ClientId, I::AnyConsensusState)>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let connection_end = ibc_ctx.connection_end(connection_id)?;
+    let client_id = connection_end.client_id();
+
+    let consensus_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    );
+
+    let consensus_state = ibc_ctx.consensus_state(&consensus_path)?;
+
+    prove(
+        ibc_ctx,
+        &Path::ClientConsensusState(consensus_path),
+        (client_id.clone(), consensus_state),
+        || format!("Proof not found for consensus state path: {client_id:?}"),
+    )
+}
+
+/// Returns the maximum expected time per block of the host, as used to
+/// compute the delay period of the connections in blocks.
+pub fn max_expected_time_per_block<I>(ibc_ctx: &I) -> Result<Duration, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(ibc_ctx.connection_params()?.max_expected_time_per_block)
+}
+
+/// Returns the parameters of the connection submodule.
+pub fn connection_params<I>(ibc_ctx: &I) -> Result<ConnectionParams, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(ibc_ctx.connection_params()?)
+}
+
+/// Returns the sequence of the next connection identifier, i.e. the number of
+/// connections created so far.
+pub fn next_connection_sequence<I>(ibc_ctx: &I) -> Result<AtHeight<u64>, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(AtHeight {
+        value: ibc_ctx.connection_counter()?,
+        height: ibc_ctx.host_height()?,
+    })
+}
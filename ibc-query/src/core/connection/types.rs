@@ -0,0 +1,20 @@
+//! Defines the request and response types of the connection queries that are not
+//! part of the `ibc-proto` gRPC query service.
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+/// Request for the sequence of the next connection identifier.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextConnectionSequenceRequest {}
+
+/// Response to a [`QueryNextConnectionSequenceRequest`].
+///
+/// The next connection identifier is formed from this sequence, e.g. for
+/// predicting identifiers in pre-signed governance proposals.
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct QueryNextConnectionSequenceResponse {
+    #[prost(uint64, tag = "1")]
+    pub next_connection_sequence: u64,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+}
@@ -6,10 +6,26 @@ use ibc::core::client::types::Height;
 use ibc::core::connection::types::IdentifiedConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
-use ibc::core::host::types::path::{ChannelEndPath, Path};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath, Path,
+};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 
+use crate::pagination::{keyed_items_from, KeyedItems};
+
+/// Returns the store key of the consensus state of the given client at
+/// `height`, by which the consensus states are paginated.
+fn consensus_state_key(client_id: &ClientId, height: &Height) -> Vec<u8> {
+    ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    )
+    .to_string()
+    .into_bytes()
+}
+
 /// Context to be implemented by the host to provide proofs in query responses
 pub trait ProvableContext {
     /// Returns the proof for the given path at the given height.
@@ -35,11 +51,114 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all heights at which consensus states for the given client are.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Iterates over the clients, keyed by the paths of their client states,
+    /// from `start_key` as [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// Hosts should iterate over the `clients/` prefix of their store, since
+    /// the default implementation loads and sorts all the client states.
+    fn client_states_from(
+        &self,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<KeyedItems<'_, (ClientId, <Self as ValidationContext>::AnyClientState)>, ContextError>
+    {
+        Ok(keyed_items_from(
+            self.client_states()?,
+            |(client_id, _)| {
+                ClientStatePath::new(client_id.clone())
+                    .to_string()
+                    .into_bytes()
+            },
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Iterates over the consensus states of the given client, keyed by their
+    /// paths, from `start_key` as [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// The default implementation loads and sorts all the consensus states.
+    fn consensus_states_from(
+        &self,
+        client_id: &ClientId,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<
+        KeyedItems<'_, (Height, <Self as ValidationContext>::AnyConsensusState)>,
+        ContextError,
+    > {
+        Ok(keyed_items_from(
+            self.consensus_states(client_id)?,
+            |(height, _)| consensus_state_key(client_id, height),
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Iterates over the heights of the consensus states of the given client,
+    /// keyed by the paths of the consensus states, from `start_key` as
+    /// [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// The default implementation loads and sorts all the heights.
+    fn consensus_state_heights_from(
+        &self,
+        client_id: &ClientId,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<KeyedItems<'_, Height>, ContextError> {
+        Ok(keyed_items_from(
+            self.consensus_state_heights(client_id)?,
+            |height| consensus_state_key(client_id, height),
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Returns the number of clients, e.g. for the total of a paginated
+    /// response requested with `count_total`.
+    ///
+    /// The default implementation loads and decodes every client state on
+    /// each such request, so hosts should override it with a counter kept in
+    /// their store, e.g. the next client sequence if no client is ever
+    /// removed.
+    fn client_count(&self) -> Result<u64, ContextError> {
+        Ok(self.client_states()?.len() as u64)
+    }
+
     // Connection queries
 
     /// Returns the list of all connection ends.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
 
+    /// Iterates over the connection ends, keyed by their paths, from
+    /// `start_key` as [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// The default implementation loads and sorts all the connection ends.
+    fn connection_ends_from(
+        &self,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<KeyedItems<'_, IdentifiedConnectionEnd>, ContextError> {
+        Ok(keyed_items_from(
+            self.connection_ends()?,
+            |connection_end| {
+                ConnectionPath::new(&connection_end.connection_id)
+                    .to_string()
+                    .into_bytes()
+            },
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Returns the number of connection ends.
+    ///
+    /// The default implementation loads every connection end, so hosts
+    /// should override it with a counter kept in their store.
+    fn connection_count(&self) -> Result<u64, ContextError> {
+        Ok(self.connection_ends()?.len() as u64)
+    }
+
     /// Returns the list of all connection ids of the given client.
     fn client_connection_ends(
         &self,
@@ -51,6 +170,35 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all channel ends.
     fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
 
+    /// Iterates over the channel ends, keyed by their paths, from `start_key`
+    /// as [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// The default implementation loads and sorts all the channel ends.
+    fn channel_ends_from(
+        &self,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<KeyedItems<'_, IdentifiedChannelEnd>, ContextError> {
+        Ok(keyed_items_from(
+            self.channel_ends()?,
+            |channel_end| {
+                ChannelEndPath::new(&channel_end.port_id, &channel_end.channel_id)
+                    .to_string()
+                    .into_bytes()
+            },
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Returns the number of channel ends.
+    ///
+    /// The default implementation loads every channel end, so hosts should
+    /// override it with a counter kept in their store.
+    fn channel_count(&self) -> Result<u64, ContextError> {
+        Ok(self.channel_ends()?.len() as u64)
+    }
+
     // Packet queries
 
     /// Returns the list of all packet commitments for the given channel end.
@@ -59,6 +207,40 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         channel_end_path: &ChannelEndPath,
     ) -> Result<Vec<PacketState>, ContextError>;
 
+    /// Iterates over the packet commitments of the given channel end, keyed
+    /// by their paths, from `start_key` as [`paginate_from`](crate::pagination::paginate_from) expects.
+    ///
+    /// The default implementation loads and sorts all the packet commitments.
+    fn packet_commitments_from(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start_key: &[u8],
+        reverse: bool,
+    ) -> Result<KeyedItems<'_, PacketState>, ContextError> {
+        Ok(keyed_items_from(
+            self.packet_commitments(channel_end_path)?,
+            |commitment| {
+                CommitmentPath::new(&commitment.port_id, &commitment.chan_id, commitment.seq)
+                    .to_string()
+                    .into_bytes()
+            },
+            start_key,
+            reverse,
+        ))
+    }
+
+    /// Returns the number of packet commitments for the given channel end.
+    ///
+    /// The default implementation loads every packet commitment of the
+    /// channel end, so hosts should override it with a counter kept in their
+    /// store.
+    fn packet_commitment_count(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<u64, ContextError> {
+        Ok(self.packet_commitments(channel_end_path)?.len() as u64)
+    }
+
     /// Filters the list of packet sequences for the given channel end that are acknowledged.
     /// Returns all the packet acknowledgements if `sequences` is empty.
     fn packet_acknowledgements(
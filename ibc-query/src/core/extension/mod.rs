@@ -0,0 +1,9 @@
+//! Serves the queries of this crate that are not part of the `ibc-proto`
+//! gRPC query services, under the [`EXTENSION_QUERY_SERVICE_NAME`] gRPC
+//! service.
+
+mod server;
+mod service;
+
+pub use server::*;
+pub use service::*;
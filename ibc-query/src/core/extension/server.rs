@@ -0,0 +1,401 @@
+//! A tonic server for the [`ExtensionQuery`] service, written after the
+//! servers `tonic-build` generates for the `ibc-proto` query services.
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::convert::Infallible;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use ibc_proto::ibc::core::channel::v1::{
+    QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse,
+};
+use tonic::body::BoxBody;
+use tonic::codec::{CompressionEncoding, EnabledCompressionEncodings, ProstCodec};
+use tonic::codegen::tokio_stream::Stream;
+use tonic::codegen::{empty_body, http, Body, BoxFuture, Service, StdError};
+use tonic::server::{Grpc, NamedService, ServerStreamingService, UnaryService};
+use tonic::{Request, Response, Status};
+
+use crate::core::batch::{RawQueryBatchRequest, RawQueryBatchResponse};
+use crate::core::channel::{
+    QueryChannelUpgradeErrorRequest, QueryChannelUpgradeErrorResponse, QueryChannelUpgradeRequest,
+    QueryChannelUpgradeResponse, QueryFilteredPacketAcknowledgementsResponse,
+    QueryFilteredPacketCommitmentsResponse, QueryNextChannelSequenceRequest,
+    QueryNextChannelSequenceResponse, QueryPacketReceiptsResponse, QueryPacketStatusRequest,
+    QueryPacketStatusResponse, RawQueryFilteredPacketAcknowledgementsRequest,
+    RawQueryFilteredPacketCommitmentsRequest, RawQueryPacketReceiptsRequest,
+};
+use crate::core::client::{
+    QueryClientExpiryRequest, QueryClientExpiryResponse, QueryNextClientSequenceRequest,
+    QueryNextClientSequenceResponse,
+};
+use crate::core::connection::{
+    QueryNextConnectionSequenceRequest, QueryNextConnectionSequenceResponse,
+};
+
+/// The fully qualified name of the [`ExtensionQuery`] gRPC service.
+pub const EXTENSION_QUERY_SERVICE_NAME: &str = "ibc_rs.query.v1.ExtensionQuery";
+
+/// The stream of responses returned by the server-streaming methods of an
+/// [`ExtensionQuery`] service.
+pub type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
+/// The gRPC methods of the queries that are not part of the `ibc-proto`
+/// query services.
+#[tonic::async_trait]
+pub trait ExtensionQuery: Send + Sync + 'static {
+    /// Returns the receipt status of a list or range of packet sequences of a
+    /// channel.
+    async fn packet_receipts(
+        &self,
+        request: Request<RawQueryPacketReceiptsRequest>,
+    ) -> Result<Response<QueryPacketReceiptsResponse>, Status>;
+
+    /// Returns the sequence of the next client identifier.
+    async fn next_client_sequence(
+        &self,
+        request: Request<QueryNextClientSequenceRequest>,
+    ) -> Result<Response<QueryNextClientSequenceResponse>, Status>;
+
+    /// Returns the sequence of the next connection identifier.
+    async fn next_connection_sequence(
+        &self,
+        request: Request<QueryNextConnectionSequenceRequest>,
+    ) -> Result<Response<QueryNextConnectionSequenceResponse>, Status>;
+
+    /// Returns the sequence of the next channel identifier.
+    async fn next_channel_sequence(
+        &self,
+        request: Request<QueryNextChannelSequenceRequest>,
+    ) -> Result<Response<QueryNextChannelSequenceResponse>, Status>;
+
+    /// Returns the upgrade attempt in progress on a channel, with its proof.
+    async fn channel_upgrade(
+        &self,
+        request: Request<QueryChannelUpgradeRequest>,
+    ) -> Result<Response<QueryChannelUpgradeResponse>, Status>;
+
+    /// Returns the receipt of the last failed upgrade attempt of a channel,
+    /// with its proof.
+    async fn channel_upgrade_error(
+        &self,
+        request: Request<QueryChannelUpgradeErrorRequest>,
+    ) -> Result<Response<QueryChannelUpgradeErrorResponse>, Status>;
+
+    /// Returns the lifecycle status of a packet of a channel.
+    async fn packet_status(
+        &self,
+        request: Request<QueryPacketStatusRequest>,
+    ) -> Result<Response<QueryPacketStatusResponse>, Status>;
+
+    /// Returns the packet commitments of a channel, restricted to a list or
+    /// range of sequences.
+    async fn filtered_packet_commitments(
+        &self,
+        request: Request<RawQueryFilteredPacketCommitmentsRequest>,
+    ) -> Result<Response<QueryFilteredPacketCommitmentsResponse>, Status>;
+
+    /// Returns the packet acknowledgements of a channel, restricted to a list
+    /// or range of sequences.
+    async fn filtered_packet_acknowledgements(
+        &self,
+        request: Request<RawQueryFilteredPacketAcknowledgementsRequest>,
+    ) -> Result<Response<QueryFilteredPacketAcknowledgementsResponse>, Status>;
+
+    /// Returns the expiry of a Tendermint client.
+    async fn client_expiry(
+        &self,
+        request: Request<QueryClientExpiryRequest>,
+    ) -> Result<Response<QueryClientExpiryResponse>, Status>;
+
+    /// Answers several client, connection, channel and packet queries at
+    /// once, with all their proofs at the same height.
+    async fn batch(
+        &self,
+        request: Request<RawQueryBatchRequest>,
+    ) -> Result<Response<RawQueryBatchResponse>, Status>;
+
+    /// Streams the unreceived packets of a channel, in responses of at most
+    /// [`UNRECEIVED_SEQUENCES_PER_CHUNK`](crate::core::channel::UNRECEIVED_SEQUENCES_PER_CHUNK)
+    /// sequences each, so that channels with many outstanding packets do not
+    /// exceed the message size limit of gRPC.
+    async fn unreceived_packets_stream(
+        &self,
+        request: Request<QueryUnreceivedPacketsRequest>,
+    ) -> Result<Response<ResponseStream<QueryUnreceivedPacketsResponse>>, Status>;
+
+    /// Streams the unreceived acknowledgements of a channel, in responses of
+    /// at most
+    /// [`UNRECEIVED_SEQUENCES_PER_CHUNK`](crate::core::channel::UNRECEIVED_SEQUENCES_PER_CHUNK)
+    /// sequences each.
+    async fn unreceived_acks_stream(
+        &self,
+        request: Request<QueryUnreceivedAcksRequest>,
+    ) -> Result<Response<ResponseStream<QueryUnreceivedAcksResponse>>, Status>;
+}
+
+/// Serves an [`ExtensionQuery`] implementation over gRPC.
+#[derive(Debug)]
+pub struct ExtensionQueryServer<T: ExtensionQuery> {
+    inner: Arc<T>,
+    accept_compression_encodings: EnabledCompressionEncodings,
+    send_compression_encodings: EnabledCompressionEncodings,
+    max_decoding_message_size: Option<usize>,
+    max_encoding_message_size: Option<usize>,
+}
+
+impl<T: ExtensionQuery> ExtensionQueryServer<T> {
+    pub fn new(inner: T) -> Self {
+        Self::from_arc(Arc::new(inner))
+    }
+
+    pub fn from_arc(inner: Arc<T>) -> Self {
+        Self {
+            inner,
+            accept_compression_encodings: Default::default(),
+            send_compression_encodings: Default::default(),
+            max_decoding_message_size: None,
+            max_encoding_message_size: None,
+        }
+    }
+
+    /// Enable decompressing requests with the given encoding.
+    #[must_use]
+    pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.accept_compression_encodings.enable(encoding);
+        self
+    }
+
+    /// Compress responses with the given encoding, if the client supports it.
+    #[must_use]
+    pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+        self.send_compression_encodings.enable(encoding);
+        self
+    }
+
+    /// Limits the maximum size of a decoded message.
+    ///
+    /// Default: `4MB`
+    #[must_use]
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.max_decoding_message_size = Some(limit);
+        self
+    }
+
+    /// Limits the maximum size of an encoded message.
+    ///
+    /// Default: `usize::MAX`
+    #[must_use]
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.max_encoding_message_size = Some(limit);
+        self
+    }
+
+    /// Answers a unary gRPC request with the given method of the service.
+    fn unary<B, Req, Res>(
+        &self,
+        request: http::Request<B>,
+        method: UnaryMethod<T, Req, Res>,
+    ) -> BoxFuture<http::Response<BoxBody>, Infallible>
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+        Req: prost::Message + Default + Send + 'static,
+        Res: prost::Message + Send + 'static,
+    {
+        let method = UnarySvc {
+            inner: self.inner.clone(),
+            method,
+        };
+        let mut grpc = Grpc::new(ProstCodec::<Res, Req>::default())
+            .apply_compression_config(
+                self.accept_compression_encodings,
+                self.send_compression_encodings,
+            )
+            .apply_max_message_size_config(
+                self.max_decoding_message_size,
+                self.max_encoding_message_size,
+            );
+
+        Box::pin(async move { Ok(grpc.unary(method, request).await) })
+    }
+
+    /// Answers a server-streaming gRPC request with the given method of the
+    /// service.
+    fn server_streaming<B, Req, Res>(
+        &self,
+        request: http::Request<B>,
+        method: ServerStreamingMethod<T, Req, Res>,
+    ) -> BoxFuture<http::Response<BoxBody>, Infallible>
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+        Req: prost::Message + Default + Send + 'static,
+        Res: prost::Message + Send + 'static,
+    {
+        let method = ServerStreamingSvc {
+            inner: self.inner.clone(),
+            method,
+        };
+        let mut grpc = Grpc::new(ProstCodec::<Res, Req>::default())
+            .apply_compression_config(
+                self.accept_compression_encodings,
+                self.send_compression_encodings,
+            )
+            .apply_max_message_size_config(
+                self.max_decoding_message_size,
+                self.max_encoding_message_size,
+            );
+
+        Box::pin(async move { Ok(grpc.server_streaming(method, request).await) })
+    }
+}
+
+impl<T, B> Service<http::Request<B>> for ExtensionQueryServer<T>
+where
+    T: ExtensionQuery,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        match request.uri().path() {
+            "/ibc_rs.query.v1.ExtensionQuery/PacketReceipts" => self
+                .unary(request, |inner, request| {
+                    Box::pin(async move { inner.packet_receipts(request).await })
+                }),
+            "/ibc_rs.query.v1.ExtensionQuery/NextClientSequence" => self
+                .unary(request, |inner, request| {
+                    Box::pin(async move { inner.next_client_sequence(request).await })
+                }),
+            "/ibc_rs.query.v1.ExtensionQuery/NextConnectionSequence" => {
+                self.unary(request, |inner, request| {
+                    Box::pin(async move { inner.next_connection_sequence(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/NextChannelSequence" => {
+                self.unary(request, |inner, request| {
+                    Box::pin(async move { inner.next_channel_sequence(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/ChannelUpgrade" => self
+                .unary(request, |inner, request| {
+                    Box::pin(async move { inner.channel_upgrade(request).await })
+                }),
+            "/ibc_rs.query.v1.ExtensionQuery/ChannelUpgradeError" => {
+                self.unary(request, |inner, request| {
+                    Box::pin(async move { inner.channel_upgrade_error(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/PacketStatus" => self
+                .unary(request, |inner, request| {
+                    Box::pin(async move { inner.packet_status(request).await })
+                }),
+            "/ibc_rs.query.v1.ExtensionQuery/FilteredPacketCommitments" => {
+                self.unary(request, |inner, request| {
+                    Box::pin(async move { inner.filtered_packet_commitments(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/FilteredPacketAcknowledgements" => {
+                self.unary(request, |inner, request| {
+                    Box::pin(async move { inner.filtered_packet_acknowledgements(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/ClientExpiry" => self
+                .unary(request, |inner, request| {
+                    Box::pin(async move { inner.client_expiry(request).await })
+                }),
+            "/ibc_rs.query.v1.ExtensionQuery/Batch" => self.unary(request, |inner, request| {
+                Box::pin(async move { inner.batch(request).await })
+            }),
+            "/ibc_rs.query.v1.ExtensionQuery/UnreceivedPacketsStream" => {
+                self.server_streaming(request, |inner, request| {
+                    Box::pin(async move { inner.unreceived_packets_stream(request).await })
+                })
+            }
+            "/ibc_rs.query.v1.ExtensionQuery/UnreceivedAcksStream" => {
+                self.server_streaming(request, |inner, request| {
+                    Box::pin(async move { inner.unreceived_acks_stream(request).await })
+                })
+            }
+            _ => Box::pin(async move {
+                // Answer with the `UNIMPLEMENTED` gRPC status, as the
+                // generated servers do.
+                let response = http::Response::builder()
+                    .status(200)
+                    .header("grpc-status", "12")
+                    .header("content-type", "application/grpc")
+                    .body(empty_body())
+                    .unwrap_or_else(|_| http::Response::new(empty_body()));
+
+                Ok(response)
+            }),
+        }
+    }
+}
+
+impl<T: ExtensionQuery> Clone for ExtensionQueryServer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            accept_compression_encodings: self.accept_compression_encodings,
+            send_compression_encodings: self.send_compression_encodings,
+            max_decoding_message_size: self.max_decoding_message_size,
+            max_encoding_message_size: self.max_encoding_message_size,
+        }
+    }
+}
+
+impl<T: ExtensionQuery> NamedService for ExtensionQueryServer<T> {
+    const NAME: &'static str = EXTENSION_QUERY_SERVICE_NAME;
+}
+
+/// A method of an [`ExtensionQuery`] service, called with the service.
+type UnaryMethod<T, Req, Res> = fn(Arc<T>, Request<Req>) -> BoxFuture<Response<Res>, Status>;
+
+/// Adapts a method of an [`ExtensionQuery`] service to the tonic
+/// [`UnaryService`] trait.
+struct UnarySvc<T, Req, Res> {
+    inner: Arc<T>,
+    method: UnaryMethod<T, Req, Res>,
+}
+
+impl<T, Req, Res> UnaryService<Req> for UnarySvc<T, Req, Res> {
+    type Response = Res;
+    type Future = BoxFuture<Response<Res>, Status>;
+
+    fn call(&mut self, request: Request<Req>) -> Self::Future {
+        (self.method)(self.inner.clone(), request)
+    }
+}
+
+/// A server-streaming method of an [`ExtensionQuery`] service, called with the
+/// service.
+type ServerStreamingMethod<T, Req, Res> =
+    fn(Arc<T>, Request<Req>) -> BoxFuture<Response<ResponseStream<Res>>, Status>;
+
+/// Adapts a server-streaming method of an [`ExtensionQuery`] service to the
+/// tonic [`ServerStreamingService`] trait.
+struct ServerStreamingSvc<T, Req, Res> {
+    inner: Arc<T>,
+    method: ServerStreamingMethod<T, Req, Res>,
+}
+
+impl<T, Req, Res> ServerStreamingService<Req> for ServerStreamingSvc<T, Req, Res> {
+    type Response = Res;
+    type ResponseStream = ResponseStream<Res>;
+    type Future = BoxFuture<Response<ResponseStream<Res>>, Status>;
+
+    fn call(&mut self, request: Request<Req>) -> Self::Future {
+        (self.method)(self.inner.clone(), request)
+    }
+}
@@ -0,0 +1,211 @@
+//! [`ExtensionQueryService`](ExtensionQueryService) takes a generic `I` to store `ibc_context` that implements [`QueryContext`](QueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse,
+};
+use tonic::codegen::tokio_stream;
+use tonic::{Request, Response, Status};
+
+use super::{ExtensionQuery, ResponseStream};
+use crate::core::batch::{
+    query_batch, QueryBatchRequest, RawQueryBatchRequest, RawQueryBatchResponse,
+};
+use crate::core::channel::{
+    query_channel_upgrade, query_channel_upgrade_error, query_filtered_packet_acknowledgements,
+    query_filtered_packet_commitments, query_next_channel_sequence, query_packet_receipts,
+    query_packet_status, query_unreceived_acks_chunked, query_unreceived_packets_chunked,
+    QueryChannelUpgradeErrorRequest, QueryChannelUpgradeErrorResponse, QueryChannelUpgradeRequest,
+    QueryChannelUpgradeResponse, QueryFilteredPacketAcknowledgementsRequest,
+    QueryFilteredPacketAcknowledgementsResponse, QueryFilteredPacketCommitmentsRequest,
+    QueryFilteredPacketCommitmentsResponse, QueryNextChannelSequenceRequest,
+    QueryNextChannelSequenceResponse, QueryPacketReceiptsRequest, QueryPacketReceiptsResponse,
+    QueryPacketStatusRequest, QueryPacketStatusResponse,
+    RawQueryFilteredPacketAcknowledgementsRequest, RawQueryFilteredPacketCommitmentsRequest,
+    RawQueryPacketReceiptsRequest, UNRECEIVED_SEQUENCES_PER_CHUNK,
+};
+use crate::core::client::{
+    query_client_expiry, query_next_client_sequence, QueryClientExpiryRequest,
+    QueryClientExpiryResponse, QueryNextClientSequenceRequest, QueryNextClientSequenceResponse,
+};
+use crate::core::connection::{
+    query_next_connection_sequence, QueryNextConnectionSequenceRequest,
+    QueryNextConnectionSequenceResponse,
+};
+use crate::core::context::QueryContext;
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct ExtensionQueryService<I>
+where
+    I: QueryContext + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    ibc_context: I,
+}
+
+impl<I> ExtensionQueryService<I>
+where
+    I: QueryContext + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ibc_context: I) -> Self {
+        Self { ibc_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> ExtensionQuery for ExtensionQueryService<I>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    I::V: TmValidationContext,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+{
+    async fn packet_receipts(
+        &self,
+        request: Request<RawQueryPacketReceiptsRequest>,
+    ) -> Result<Response<QueryPacketReceiptsResponse>, Status> {
+        let request = QueryPacketReceiptsRequest::try_from(request.into_inner())?;
+
+        let response = query_packet_receipts(&self.ibc_context, &request)?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn next_client_sequence(
+        &self,
+        request: Request<QueryNextClientSequenceRequest>,
+    ) -> Result<Response<QueryNextClientSequenceResponse>, Status> {
+        let response = query_next_client_sequence(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn next_connection_sequence(
+        &self,
+        request: Request<QueryNextConnectionSequenceRequest>,
+    ) -> Result<Response<QueryNextConnectionSequenceResponse>, Status> {
+        let response = query_next_connection_sequence(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn next_channel_sequence(
+        &self,
+        request: Request<QueryNextChannelSequenceRequest>,
+    ) -> Result<Response<QueryNextChannelSequenceResponse>, Status> {
+        let response = query_next_channel_sequence(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn channel_upgrade(
+        &self,
+        request: Request<QueryChannelUpgradeRequest>,
+    ) -> Result<Response<QueryChannelUpgradeResponse>, Status> {
+        let response = query_channel_upgrade(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn channel_upgrade_error(
+        &self,
+        request: Request<QueryChannelUpgradeErrorRequest>,
+    ) -> Result<Response<QueryChannelUpgradeErrorResponse>, Status> {
+        let response = query_channel_upgrade_error(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn packet_status(
+        &self,
+        request: Request<QueryPacketStatusRequest>,
+    ) -> Result<Response<QueryPacketStatusResponse>, Status> {
+        let response = query_packet_status(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn filtered_packet_commitments(
+        &self,
+        request: Request<RawQueryFilteredPacketCommitmentsRequest>,
+    ) -> Result<Response<QueryFilteredPacketCommitmentsResponse>, Status> {
+        let request = QueryFilteredPacketCommitmentsRequest::try_from(request.into_inner())?;
+
+        let response = query_filtered_packet_commitments(&self.ibc_context, &request)?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn filtered_packet_acknowledgements(
+        &self,
+        request: Request<RawQueryFilteredPacketAcknowledgementsRequest>,
+    ) -> Result<Response<QueryFilteredPacketAcknowledgementsResponse>, Status> {
+        let request = QueryFilteredPacketAcknowledgementsRequest::try_from(request.into_inner())?;
+
+        let response = query_filtered_packet_acknowledgements(&self.ibc_context, &request)?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn client_expiry(
+        &self,
+        request: Request<QueryClientExpiryRequest>,
+    ) -> Result<Response<QueryClientExpiryResponse>, Status> {
+        let response = query_client_expiry(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn batch(
+        &self,
+        request: Request<RawQueryBatchRequest>,
+    ) -> Result<Response<RawQueryBatchResponse>, Status> {
+        let request = QueryBatchRequest::try_from(request.into_inner())?;
+
+        let response = query_batch(&self.ibc_context, &request)?;
+
+        Ok(Response::new(response.into()))
+    }
+
+    async fn unreceived_packets_stream(
+        &self,
+        request: Request<QueryUnreceivedPacketsRequest>,
+    ) -> Result<Response<ResponseStream<QueryUnreceivedPacketsResponse>>, Status> {
+        let responses = query_unreceived_packets_chunked(
+            self.ibc_context.clone(),
+            request.get_ref(),
+            UNRECEIVED_SEQUENCES_PER_CHUNK,
+        )?;
+
+        Ok(Response::new(Box::pin(tokio_stream::iter(
+            responses.map(|response| response.map_err(Into::into)),
+        ))))
+    }
+
+    async fn unreceived_acks_stream(
+        &self,
+        request: Request<QueryUnreceivedAcksRequest>,
+    ) -> Result<Response<ResponseStream<QueryUnreceivedAcksResponse>>, Status> {
+        let responses = query_unreceived_acks_chunked(
+            self.ibc_context.clone(),
+            request.get_ref(),
+            UNRECEIVED_SEQUENCES_PER_CHUNK,
+        )?;
+
+        Ok(Response::new(Box::pin(tokio_stream::iter(
+            responses.map(|response| response.map_err(Into::into)),
+        ))))
+    }
+}
@@ -0,0 +1,300 @@
+//! Provides the invariants of the core IBC state, checking its structural
+//! consistency, along with a registry through which hosts run them on demand,
+//! e.g. from a query or a CLI command, or at the end of every block, as the
+//! `x/crisis` module of the Cosmos SDK does with the invariants registered by
+//! ibc-go.
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc_query::core::invariants::InvariantRegistry;
+//!
+//! let registry = InvariantRegistry::with_core_invariants();
+//!
+//! // At the end of every block
+//! registry.assert_invariants(&ibc)?;
+//! ```
+
+use ibc::core::channel::types::channel::{IdentifiedChannelEnd, Order, State};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::path::{
+    ChannelEndPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc::core::primitives::prelude::*;
+
+use super::context::QueryContext;
+use crate::error::QueryError;
+
+/// The route of the invariant checking that every channel references an
+/// existing connection, itself referencing an existing client.
+pub const CHANNEL_CONNECTIONS_INVARIANT_ROUTE: &str = "ibc/channel-connections";
+
+/// The route of the invariant checking that the next sequences of every
+/// channel are ahead of the packets they numbered.
+pub const PACKET_SEQUENCES_INVARIANT_ROUTE: &str = "ibc/packet-sequences";
+
+/// The route of the invariant checking that every acknowledgement written on
+/// an unordered channel matches a packet receipt.
+pub const ACKNOWLEDGEMENT_RECEIPTS_INVARIANT_ROUTE: &str = "ibc/acknowledgement-receipts";
+
+/// An invariant, returning the descriptions of its violations, if any.
+pub type Invariant<I> = fn(&I) -> Result<Vec<String>, ContextError>;
+
+/// The outcome of an invariant, along with its route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvariantReport {
+    pub route: String,
+    pub message: String,
+    pub broken: bool,
+}
+
+/// Registry of the invariants run by the host, in their order of
+/// registration.
+pub struct InvariantRegistry<I> {
+    invariants: Vec<(String, Invariant<I>)>,
+}
+
+impl<I> Default for InvariantRegistry<I> {
+    fn default() -> Self {
+        Self {
+            invariants: Vec::new(),
+        }
+    }
+}
+
+impl<I> InvariantRegistry<I>
+where
+    I: QueryContext,
+{
+    /// Creates a registry without any invariant.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the invariants of the core IBC state.
+    pub fn with_core_invariants() -> Self {
+        Self::new()
+            .register(
+                CHANNEL_CONNECTIONS_INVARIANT_ROUTE,
+                channel_connections_invariant,
+            )
+            .register(PACKET_SEQUENCES_INVARIANT_ROUTE, packet_sequences_invariant)
+            .register(
+                ACKNOWLEDGEMENT_RECEIPTS_INVARIANT_ROUTE,
+                acknowledgement_receipts_invariant,
+            )
+    }
+
+    /// Registers an invariant under the given route, replacing the invariant
+    /// already registered under it, if any.
+    pub fn register(mut self, route: impl Into<String>, invariant: Invariant<I>) -> Self {
+        let route = route.into();
+
+        match self.invariants.iter_mut().find(|(r, _)| *r == route) {
+            Some((_, registered)) => *registered = invariant,
+            None => self.invariants.push((route, invariant)),
+        }
+
+        self
+    }
+
+    /// Returns the routes of the registered invariants.
+    pub fn routes(&self) -> impl Iterator<Item = &str> {
+        self.invariants.iter().map(|(route, _)| route.as_str())
+    }
+
+    /// Runs the invariant registered under the given route, if any.
+    ///
+    /// The invariant is reported as broken if the state cannot be read.
+    pub fn run(&self, ctx: &I, route: &str) -> Option<InvariantReport> {
+        self.invariants
+            .iter()
+            .find(|(r, _)| r == route)
+            .map(|(route, invariant)| report(ctx, route, *invariant))
+    }
+
+    /// Runs all the registered invariants.
+    pub fn run_all(&self, ctx: &I) -> Vec<InvariantReport> {
+        self.invariants
+            .iter()
+            .map(|(route, invariant)| report(ctx, route, *invariant))
+            .collect()
+    }
+
+    /// Runs all the registered invariants, and fails on the first broken one,
+    /// e.g. to halt the chain from its end-blocker.
+    pub fn assert_invariants(&self, ctx: &I) -> Result<(), QueryError> {
+        for (route, invariant) in &self.invariants {
+            let report = report(ctx, route, *invariant);
+
+            if report.broken {
+                return Err(QueryError::InvariantBroken {
+                    route: report.route,
+                    message: report.message,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn report<I>(ctx: &I, route: &str, invariant: Invariant<I>) -> InvariantReport {
+    let (message, broken) = match invariant(ctx) {
+        Ok(violations) if violations.is_empty() => ("no violation\n".to_string(), false),
+        Ok(violations) => {
+            let mut message = String::new();
+
+            for violation in violations {
+                message.push_str(&violation);
+                message.push('\n');
+            }
+
+            (message, true)
+        }
+        Err(e) => (format!("failed to read the state: {e}\n"), true),
+    };
+
+    InvariantReport {
+        route: route.to_string(),
+        message: format!("{route} invariant\n{message}"),
+        broken,
+    }
+}
+
+/// Checks that every channel references an existing connection, and that
+/// every connection references an existing client.
+pub fn channel_connections_invariant<I>(ctx: &I) -> Result<Vec<String>, ContextError>
+where
+    I: QueryContext,
+{
+    let mut violations = Vec::new();
+
+    for channel in ctx.channel_ends()? {
+        let IdentifiedChannelEnd {
+            port_id,
+            channel_id,
+            channel_end,
+        } = channel;
+
+        let conn_id = match channel_end.connection_hops().first() {
+            Some(conn_id) => conn_id,
+            None => {
+                violations.push(format!("channel {port_id}/{channel_id} has no connection"));
+                continue;
+            }
+        };
+
+        if ctx.connection_end(conn_id).is_err() {
+            violations.push(format!(
+                "channel {port_id}/{channel_id} references missing connection {conn_id}"
+            ));
+        }
+    }
+
+    for connection in ctx.connection_ends()? {
+        let client_id = connection.connection_end.client_id();
+
+        if ctx.client_state(client_id).is_err() {
+            violations.push(format!(
+                "connection {} references missing client {client_id}",
+                connection.connection_id
+            ));
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Checks that the next sequences of every channel are ahead of the packets
+/// they numbered: no packet commitment at or beyond the next sequence to send,
+/// no acknowledgement at or beyond the next sequence to receive on ordered
+/// channels, and no sequence to be acknowledged beyond the next one to send.
+pub fn packet_sequences_invariant<I>(ctx: &I) -> Result<Vec<String>, ContextError>
+where
+    I: QueryContext,
+{
+    let mut violations = Vec::new();
+
+    for channel in ctx.channel_ends()? {
+        let IdentifiedChannelEnd {
+            port_id,
+            channel_id,
+            channel_end,
+        } = channel;
+
+        if channel_end.state() == &State::Uninitialized {
+            continue;
+        }
+
+        let next_seq_send = ctx.get_next_sequence_send(&SeqSendPath::new(&port_id, &channel_id))?;
+        let next_seq_recv = ctx.get_next_sequence_recv(&SeqRecvPath::new(&port_id, &channel_id))?;
+        let next_seq_ack = ctx.get_next_sequence_ack(&SeqAckPath::new(&port_id, &channel_id))?;
+
+        let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
+
+        for commitment in ctx.packet_commitments(&channel_end_path)? {
+            if commitment.seq >= next_seq_send {
+                violations.push(format!(
+                    "channel {port_id}/{channel_id} has a commitment for packet {} while its next sequence to send is {next_seq_send}",
+                    commitment.seq
+                ));
+            }
+        }
+
+        if channel_end.ordering() == &Order::Ordered {
+            for ack in ctx.packet_acknowledgements(&channel_end_path, core::iter::empty())? {
+                if ack.seq >= next_seq_recv {
+                    violations.push(format!(
+                        "channel {port_id}/{channel_id} has an acknowledgement for packet {} while its next sequence to receive is {next_seq_recv}",
+                        ack.seq
+                    ));
+                }
+            }
+
+            if next_seq_ack > next_seq_send {
+                violations.push(format!(
+                    "channel {port_id}/{channel_id} has a next sequence to acknowledge {next_seq_ack} beyond its next sequence to send {next_seq_send}"
+                ));
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Checks that every acknowledgement written on an unordered channel matches
+/// a packet receipt.
+pub fn acknowledgement_receipts_invariant<I>(ctx: &I) -> Result<Vec<String>, ContextError>
+where
+    I: QueryContext,
+{
+    let mut violations = Vec::new();
+
+    for channel in ctx.channel_ends()? {
+        if channel.channel_end.ordering() != &Order::Unordered {
+            continue;
+        }
+
+        let IdentifiedChannelEnd {
+            port_id,
+            channel_id,
+            ..
+        } = channel;
+
+        let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
+
+        for ack in ctx.packet_acknowledgements(&channel_end_path, core::iter::empty())? {
+            let receipt_path = ReceiptPath::new(&port_id, &channel_id, ack.seq);
+
+            if !matches!(ctx.get_packet_receipt(&receipt_path), Ok(Receipt::Ok)) {
+                violations.push(format!(
+                    "channel {port_id}/{channel_id} has an acknowledgement without receipt for packet {}",
+                    ack.seq
+                ));
+            }
+        }
+    }
+
+    Ok(violations)
+}
@@ -1,4 +1,8 @@
+pub mod batch;
 pub mod channel;
 pub mod client;
 pub mod connection;
 pub mod context;
+pub mod extension;
+pub mod invariants;
+pub mod typed;
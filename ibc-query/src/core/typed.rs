@@ -0,0 +1,95 @@
+//! Defines the domain types returned by the typed query functions of each
+//! layer, e.g. [`client::typed`](crate::core::client::typed).
+//!
+//! The typed query functions run the same queries as the functions returning
+//! `ibc-proto` responses, which are implemented on top of them, but take and
+//! return domain types, e.g. for Rust consumers embedding the query layer.
+
+use core::time::Duration;
+
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::Path;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+
+use crate::core::context::ProvableContext;
+use crate::error::QueryError;
+
+/// A value stored by the host, along with its proof at `proof_height`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proven<T> {
+    pub value: T,
+    pub proof: Vec<u8>,
+    pub proof_height: Height,
+}
+
+impl<T> Proven<T> {
+    /// Applies `f` to the proven value, keeping its proof.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Proven<U> {
+        Proven {
+            value: f(self.value),
+            proof: self.proof,
+            proof_height: self.proof_height,
+        }
+    }
+}
+
+/// A value read from the host at `height`, without a proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtHeight<T> {
+    pub value: T,
+    pub height: Height,
+}
+
+/// The expiry of a client, i.e. the time at which its trusting period elapses
+/// since the timestamp of its latest consensus state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientExpiry {
+    /// The time at which the client expires, or `None` if the client has no
+    /// consensus state at its latest height, i.e. is already expired.
+    pub expires_at: Option<Timestamp>,
+    /// The time left until the client expires, zero if it is expired.
+    pub time_until_expiry: Duration,
+}
+
+/// A client state, along with the identifier of its client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedClientState<C> {
+    pub client_id: ClientId,
+    pub client_state: C,
+}
+
+/// A consensus state, along with its height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusStateWithHeight<C> {
+    pub height: Height,
+    pub consensus_state: C,
+}
+
+/// Returns `value` along with the proof of `path` at the current height of
+/// the host, or a [`QueryError::ProofNotFound`] with the given description.
+pub(crate) fn prove<I, T>(
+    ibc_ctx: &I,
+    path: &Path,
+    value: T,
+    description: impl FnOnce() -> String,
+) -> Result<Proven<T>, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let proof_height = ibc_ctx.host_height()?;
+
+    let proof = ibc_ctx
+        .get_proof(proof_height, path)
+        .ok_or_else(|| QueryError::ProofNotFound {
+            description: description(),
+        })?;
+
+    Ok(Proven {
+        value,
+        proof,
+        proof_height,
+    })
+}
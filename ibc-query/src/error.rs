@@ -2,6 +2,7 @@ use alloc::string::{String, ToString};
 
 use displaydoc::Display;
 use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::error::IdentifierError;
 use tonic::Status;
@@ -16,6 +17,16 @@ pub enum QueryError {
     IdentifierError(IdentifierError),
     /// Proof not found: {description}
     ProofNotFound { description: String },
+    /// Invalid request: {description}
+    InvalidRequest { description: String },
+    /// Not found: {description}
+    NotFound { description: String },
+    /// Total amount of `{denom}` overflows
+    AmountOverflow { denom: String },
+    /// Host height changed from `{expected}` to `{actual}` while answering the query
+    InconsistentHeight { expected: Height, actual: Height },
+    /// Invariant `{route}` is broken: {message}
+    InvariantBroken { route: String, message: String },
 }
 
 impl From<QueryError> for Status {
@@ -25,6 +36,11 @@ impl From<QueryError> for Status {
             QueryError::ClientError(e) => Status::internal(e.to_string()),
             QueryError::IdentifierError(e) => Status::internal(e.to_string()),
             QueryError::ProofNotFound { description } => Status::not_found(description),
+            QueryError::InvalidRequest { description } => Status::invalid_argument(description),
+            QueryError::NotFound { description } => Status::not_found(description),
+            e @ QueryError::AmountOverflow { .. } => Status::out_of_range(e.to_string()),
+            e @ QueryError::InconsistentHeight { .. } => Status::aborted(e.to_string()),
+            e @ QueryError::InvariantBroken { .. } => Status::internal(e.to_string()),
         }
     }
 }
@@ -51,6 +51,46 @@
 //!       .serve(addr);
 //! ```
 //!
+//! Hosts whose context implements both `QueryContext` and
+//! `UpgradeValidationContext` can replace the above wiring with
+//! [`IbcQueryServer`](crate::server::IbcQueryServer):
+//! ```rust,ignore
+//! use ibc_query::server::IbcQueryServer;
+//!
+//! let grpc_server = IbcQueryServer::new(ibc).into_router().serve(addr);
+//! ```
+//!
+//! Alternatively, [`QueryServerBuilder`](crate::server::QueryServerBuilder)
+//! builds all the services at once and lets hosts attach tonic interceptors
+//! to them, e.g. for authentication.
+//!
+//! Both also serve the queries of this crate that are not part of the
+//! `ibc-proto` services, e.g. the bulk packet receipts query, through the
+//! [`ExtensionQueryService`](crate::core::extension::ExtensionQueryService).
+//! The fee and Wasm query services, which are backed by contexts of their
+//! own, are registered through
+//! [`QueryServerBuilder::with_fee_context`](crate::server::QueryServerBuilder::with_fee_context)
+//! and
+//! [`QueryServerBuilder::with_wasm_context`](crate::server::QueryServerBuilder::with_wasm_context).
+//!
+//! Rust consumers embedding the query layer, e.g. indexers or test tools, can
+//! use the `typed` module of each layer instead, e.g.
+//! [`client::typed`](crate::core::client::typed), whose functions take and
+//! return domain types rather than `ibc-proto` requests and responses:
+//! ```rust,ignore
+//! use ibc_query::core::channel::typed::channel;
+//!
+//! let channel_end = channel(&ibc, &port_id, &channel_id)?;
+//! assert_eq!(channel_end.value.state(), &State::Open);
+//! ```
+//!
+//! Hosts running the fee middleware (ICS-29) can also serve its queries with
+//! the [`FeeQueryService`](crate::apps::fee::FeeQueryService).
+//!
+//! Hosts can check the structural consistency of their IBC state, on demand
+//! or at the end of every block, with the
+//! [`InvariantRegistry`](crate::core::invariants::InvariantRegistry).
+//!
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![no_std]
@@ -66,5 +106,17 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod abci;
+pub mod apps;
+pub mod cache;
+pub mod clients;
 pub mod core;
 pub mod error;
+pub mod pagination;
+#[cfg(feature = "rate-limit")]
+pub mod rate_limit;
+pub mod server;
+pub mod tx_builder;
@@ -0,0 +1,343 @@
+//! Provides the key-based pagination of the list queries, following the
+//! semantics of the Cosmos SDK.
+//!
+//! Every item of a list query is keyed by the store path it is stored under.
+//! A page starts either at the `offset`-th item, or at the first item whose
+//! key is not before the `key` of the request, and its response carries the
+//! key of the item following the page as `next_key`. Since this cursor is
+//! derived from the store keys rather than from positions, resuming the
+//! iteration with it neither skips nor repeats items when others are added or
+//! removed between the queries.
+//!
+//! The cursors are opaque to clients, which must only pass back the
+//! `next_key` of the previous page.
+//!
+//! Hosts page the list queries from the prefix iterators of their stores by
+//! overriding the `*_from` methods of
+//! [`QueryContext`](crate::core::context::QueryContext), which otherwise load
+//! and sort all the items in memory.
+//!
+//! The `total` of a page response is only set if the request asks for it with
+//! `count_total`, in which case the items are counted with the counting
+//! methods of [`QueryContext`](crate::core::context::QueryContext). Unless
+//! overridden by the host, these load every item of the list, so that
+//! counting the total costs as much as answering the query without
+//! pagination.
+
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+
+use crate::error::QueryError;
+
+/// Number of items in a page when the request does not set a limit.
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// The items of a list query along with their store keys, as yielded by the
+/// prefix iterator of the host's store.
+pub type KeyedItems<'a, T> = Box<dyn Iterator<Item = (Vec<u8>, T)> + 'a>;
+
+/// Returns the page of the items yielded by `items_from`, selected by
+/// `page_request`, along with the page response.
+///
+/// `items_from(start_key, reverse)` iterates over the items in the order of
+/// their store keys, from the first item whose key is not before `start_key`,
+/// or, if `reverse` is set, in the reverse order from the last item whose key
+/// is not after `start_key`. An empty `start_key` starts at the first, or the
+/// last, item. Only the items of the page, those skipped by the `offset` and
+/// the one following the page are pulled from the iterator, so that hosts
+/// paging from their store never load the other ones.
+///
+/// All the items are returned, without any page response, if there is no
+/// page request.
+pub fn paginate_from<'a, T>(
+    page_request: Option<&PageRequest>,
+    items_from: impl FnOnce(&[u8], bool) -> Result<KeyedItems<'a, T>, ContextError>,
+) -> Result<(Vec<T>, Option<PageResponse>), QueryError> {
+    let page_request = match page_request {
+        Some(page_request) => page_request,
+        None => {
+            let items = items_from(&[], false)?.map(|(_, item)| item).collect();
+            return Ok((items, None));
+        }
+    };
+
+    if !page_request.key.is_empty() && page_request.offset > 0 {
+        return Err(QueryError::InvalidRequest {
+            description: "either the offset or the key of a page is expected, got both".to_string(),
+        });
+    }
+
+    let offset = if page_request.key.is_empty() {
+        usize::try_from(page_request.offset).unwrap_or(usize::MAX)
+    } else {
+        0
+    };
+
+    let limit = match page_request.limit {
+        0 => DEFAULT_PAGE_LIMIT,
+        limit => limit,
+    };
+
+    let mut remaining = items_from(&page_request.key, page_request.reverse)?.skip(offset);
+
+    let page = remaining
+        .by_ref()
+        .take(usize::try_from(limit).unwrap_or(usize::MAX))
+        .map(|(_, item)| item)
+        .collect();
+
+    let next_key = remaining.next().map(|(key, _)| key).unwrap_or_default();
+
+    Ok((page, Some(PageResponse { next_key, total: 0 })))
+}
+
+/// Returns the page of `items` selected by `page_request`, where `key_of`
+/// returns the store key of an item, along with the page response.
+///
+/// Unlike [`paginate_from`], all the items are loaded and sorted in memory,
+/// which only suits the lists without a prefix in the store, e.g. the ones
+/// filtered by the query.
+pub fn paginate<T>(
+    items: Vec<T>,
+    key_of: impl Fn(&T) -> Vec<u8>,
+    page_request: Option<&PageRequest>,
+) -> Result<(Vec<T>, Option<PageResponse>), QueryError> {
+    paginate_from(page_request, |start_key, reverse| {
+        Ok(keyed_items_from(items, key_of, start_key, reverse))
+    })
+}
+
+/// Sorts `items` by their store keys, as returned by `key_of`, and iterates
+/// over them from `start_key` as [`paginate_from`] expects.
+///
+/// This is the in-memory fallback of the hosts which cannot iterate over the
+/// items in their store, used by the default methods of
+/// [`QueryContext`](crate::core::context::QueryContext).
+pub fn keyed_items_from<'a, T: 'a>(
+    items: Vec<T>,
+    key_of: impl Fn(&T) -> Vec<u8>,
+    start_key: &[u8],
+    reverse: bool,
+) -> KeyedItems<'a, T> {
+    let mut keyed_items: Vec<(Vec<u8>, T)> = items
+        .into_iter()
+        .map(|item| (key_of(&item), item))
+        .collect();
+
+    keyed_items.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    if !reverse {
+        let start = keyed_items.partition_point(|(key, _)| key.as_slice() < start_key);
+        return Box::new(keyed_items.into_iter().skip(start));
+    }
+
+    if !start_key.is_empty() {
+        let end = keyed_items.partition_point(|(key, _)| key.as_slice() <= start_key);
+        keyed_items.truncate(end);
+    }
+
+    Box::new(keyed_items.into_iter().rev())
+}
+
+/// Sets the total number of items, as returned by `count`, in the page
+/// response if the page request asks for it.
+///
+/// `count` is only called in that case, since it may have to load every item
+/// of the list.
+pub fn with_total(
+    page_response: Option<PageResponse>,
+    page_request: Option<&PageRequest>,
+    count: impl FnOnce() -> Result<u64, ContextError>,
+) -> Result<Option<PageResponse>, QueryError> {
+    match (page_response, page_request) {
+        (Some(page_response), Some(page_request)) if page_request.count_total => {
+            Ok(Some(PageResponse {
+                total: count()?,
+                ..page_response
+            }))
+        }
+        (page_response, _) => Ok(page_response),
+    }
+}
+
+/// Returns the part of a cache key identifying the page requested by
+/// `page_request`, so that different pages of a list query are cached apart.
+pub(crate) fn page_cache_key(page_request: Option<&PageRequest>) -> String {
+    match page_request {
+        Some(page_request) => format!(
+            "{:?}/{}/{}/{}/{}",
+            page_request.key,
+            page_request.offset,
+            page_request.limit,
+            page_request.count_total,
+            page_request.reverse
+        ),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    fn keys() -> Vec<Vec<u8>> {
+        ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|key| key.as_bytes().to_vec())
+            .collect()
+    }
+
+    fn page_request(key: &str, offset: u64, limit: u64, reverse: bool) -> PageRequest {
+        PageRequest {
+            key: key.as_bytes().to_vec(),
+            offset,
+            limit,
+            count_total: false,
+            reverse,
+        }
+    }
+
+    fn page_of(items: Vec<Vec<u8>>, page_request: &PageRequest) -> (Vec<String>, Vec<u8>) {
+        let (page, page_response) = paginate(items, Clone::clone, Some(page_request)).unwrap();
+        let page = page
+            .into_iter()
+            .map(|key| String::from_utf8(key).unwrap())
+            .collect();
+
+        (page, page_response.unwrap().next_key)
+    }
+
+    #[test]
+    fn test_paginate_next_key() {
+        // The keys are sorted, whatever the order of the items.
+        let mut items = keys();
+        items.reverse();
+
+        let (first, next_key) = page_of(items.clone(), &page_request("", 0, 2, false));
+        assert_eq!(first, ["a", "b"]);
+        assert_eq!(next_key, b"c");
+
+        let (second, next_key) = page_of(items.clone(), &page_request("c", 0, 2, false));
+        assert_eq!(second, ["c", "d"]);
+        assert_eq!(next_key, b"e");
+
+        let (last, next_key) = page_of(items.clone(), &page_request("e", 0, 2, false));
+        assert_eq!(last, ["e"]);
+        assert!(next_key.is_empty());
+
+        let (offset, next_key) = page_of(items, &page_request("", 3, 2, false));
+        assert_eq!(offset, ["d", "e"]);
+        assert!(next_key.is_empty());
+    }
+
+    #[test]
+    fn test_paginate_reverse() {
+        let (first, next_key) = page_of(keys(), &page_request("", 0, 2, true));
+        assert_eq!(first, ["e", "d"]);
+        assert_eq!(next_key, b"c");
+
+        let (second, next_key) = page_of(keys(), &page_request("c", 0, 2, true));
+        assert_eq!(second, ["c", "b"]);
+        assert_eq!(next_key, b"a");
+
+        // A key between two items starts at the item before it.
+        let (between, next_key) = page_of(keys(), &page_request("bb", 0, 5, true));
+        assert_eq!(between, ["b", "a"]);
+        assert!(next_key.is_empty());
+    }
+
+    #[test]
+    fn test_paginate_default_limit() {
+        let items: Vec<Vec<u8>> = (0..DEFAULT_PAGE_LIMIT + 1)
+            .map(|i| format!("{i:04}").into_bytes())
+            .collect();
+
+        let (page, next_key) = page_of(items, &page_request("", 0, 0, false));
+        assert_eq!(page.len() as u64, DEFAULT_PAGE_LIMIT);
+        assert_eq!(next_key, format!("{DEFAULT_PAGE_LIMIT:04}").into_bytes());
+    }
+
+    #[test]
+    fn test_paginate_empty() {
+        let (page, next_key) = page_of(Vec::new(), &page_request("", 0, 10, false));
+        assert!(page.is_empty());
+        assert!(next_key.is_empty());
+
+        let (page, next_key) = page_of(keys(), &page_request("f", 0, 10, false));
+        assert!(page.is_empty());
+        assert!(next_key.is_empty());
+
+        let (page, next_key) = page_of(keys(), &page_request("", 10, 10, false));
+        assert!(page.is_empty());
+        assert!(next_key.is_empty());
+    }
+
+    #[test]
+    fn test_paginate_without_page_request() {
+        let (items, page_response) = paginate(keys(), Clone::clone, None).unwrap();
+        assert_eq!(items, keys());
+        assert!(page_response.is_none());
+    }
+
+    #[test]
+    fn test_paginate_key_and_offset() {
+        let result = paginate(keys(), Clone::clone, Some(&page_request("b", 1, 2, false)));
+        assert!(matches!(result, Err(QueryError::InvalidRequest { .. })));
+    }
+
+    #[test]
+    fn test_paginate_from_pulls_the_page_only() {
+        let pulled = Cell::new(0);
+
+        let (page, page_response) =
+            paginate_from(Some(&page_request("b", 0, 2, false)), |start_key, _| {
+                let start_key = start_key.to_vec();
+                let items: KeyedItems<'_, Vec<u8>> = Box::new(
+                    keys()
+                        .into_iter()
+                        .filter(move |key| key >= &start_key)
+                        .inspect(|_| pulled.set(pulled.get() + 1))
+                        .map(|key| (key.clone(), key)),
+                );
+                Ok(items)
+            })
+            .unwrap();
+
+        assert_eq!(page, [b"b".to_vec(), b"c".to_vec()]);
+        assert_eq!(page_response.unwrap().next_key, b"d");
+        // The items of the page and the one following it.
+        assert_eq!(pulled.get(), 3);
+    }
+
+    #[test]
+    fn test_with_total() {
+        let page_response = Some(PageResponse {
+            next_key: b"c".to_vec(),
+            total: 0,
+        });
+
+        let mut counting_request = page_request("", 0, 2, false);
+        counting_request.count_total = true;
+
+        let counted = with_total(page_response.clone(), Some(&counting_request), || Ok(5))
+            .unwrap()
+            .unwrap();
+        assert_eq!(counted.total, 5);
+        assert_eq!(counted.next_key, b"c");
+
+        // The items are not counted unless requested.
+        let not_counted = with_total(
+            page_response,
+            Some(&page_request("", 0, 2, false)),
+            || -> Result<u64, ContextError> { panic!("counted without a request") },
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(not_counted.total, 0);
+
+        assert!(with_total(None, None, || Ok(5)).unwrap().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! Provides [`RateLimitLayer`], a tower layer rate limiting the IBC query
+//! services per gRPC method, so that operators can expose them publicly
+//! without a separate gateway.
+//!
+//! Quotas are enforced with the GCRA algorithm of the `governor` crate.
+//! Methods are identified by their gRPC path, e.g.
+//! `/ibc.core.channel.v1.Query/PacketCommitments`, and requests exceeding
+//! their quota are rejected with a `RESOURCE_EXHAUSTED` status.
+//!
+//! Example
+//! ```rust,ignore
+//! use core::num::NonZeroU32;
+//!
+//! use ibc_query::rate_limit::{Quota, RateLimitConfig, RateLimitLayer};
+//! use ibc_query::server::QueryServerBuilder;
+//!
+//! let config = RateLimitConfig::new()
+//!     .with_default_quota(Quota::per_second(NonZeroU32::new(100).unwrap()))
+//!     .with_method_quota(
+//!         "/ibc.core.channel.v1.Query/PacketCommitments",
+//!         Quota::per_minute(NonZeroU32::new(10).unwrap()),
+//!     );
+//!
+//! let grpc_server = QueryServerBuilder::new(ibc, upgrade)
+//!     .add_to(&mut tonic::transport::Server::builder().layer(RateLimitLayer::new(config)))
+//!     .serve(addr);
+//! ```
+
+use alloc::sync::Arc;
+use core::task::{Context, Poll};
+
+use governor::DefaultDirectRateLimiter;
+pub use governor::Quota;
+use ibc::core::primitives::prelude::*;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture};
+use tonic::Status;
+use tower::{Layer, Service};
+
+/// The quotas enforced by a [`RateLimitLayer`].
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitConfig {
+    default_quota: Option<Quota>,
+    method_quotas: BTreeMap<String, Quota>,
+}
+
+impl RateLimitConfig {
+    /// Creates a configuration without any quota.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the quota shared by all the methods without a dedicated quota.
+    /// Without it, such methods are not rate limited.
+    pub fn with_default_quota(mut self, quota: Quota) -> Self {
+        self.default_quota = Some(quota);
+        self
+    }
+
+    /// Sets a dedicated quota for the method with the given gRPC path, e.g.
+    /// a stricter one for full-scan endpoints like `PacketCommitments`.
+    pub fn with_method_quota(mut self, method: impl Into<String>, quota: Quota) -> Self {
+        self.method_quotas.insert(method.into(), quota);
+        self
+    }
+}
+
+struct RateLimiters {
+    default_limiter: Option<DefaultDirectRateLimiter>,
+    method_limiters: BTreeMap<String, DefaultDirectRateLimiter>,
+}
+
+impl RateLimiters {
+    fn new(config: RateLimitConfig) -> Self {
+        Self {
+            default_limiter: config.default_quota.map(DefaultDirectRateLimiter::direct),
+            method_limiters: config
+                .method_quotas
+                .into_iter()
+                .map(|(method, quota)| (method, DefaultDirectRateLimiter::direct(quota)))
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if a call to the given method is within its quota.
+    fn check(&self, method: &str) -> bool {
+        self.method_limiters
+            .get(method)
+            .or(self.default_limiter.as_ref())
+            .map_or(true, |limiter| limiter.check().is_ok())
+    }
+}
+
+/// A tower layer rate limiting the wrapped gRPC services per method.
+///
+/// Clones of the layer share their quotas.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiters: Arc<RateLimiters>,
+}
+
+impl RateLimitLayer {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            limiters: Arc::new(RateLimiters::new(config)),
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiters: self.limiters.clone(),
+        }
+    }
+}
+
+/// The service produced by a [`RateLimitLayer`].
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiters: Arc<RateLimiters>,
+}
+
+impl<S, ReqBody> Service<http::Request<ReqBody>> for RateLimitService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let method = request.uri().path();
+
+        if !self.limiters.check(method) {
+            let response =
+                Status::resource_exhausted(format!("rate limit exceeded for {method}")).to_http();
+
+            return Box::pin(async move { Ok(response) });
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
@@ -0,0 +1,687 @@
+//! Provides [`QueryServerBuilder`], which bundles the client, connection,
+//! channel and extension query services and lets hosts protect them with tonic
+//! interceptors, e.g. to check API keys or mTLS identities, or to enforce
+//! per-organization quotas on heavy endpoints.
+//!
+//! The fee and Wasm query services are backed by contexts of their own, and
+//! are only registered once the host provides them, through
+//! [`QueryServerBuilder::with_fee_context`] and
+//! [`QueryServerBuilder::with_wasm_context`].
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc_query::server::QueryServerBuilder;
+//! use tonic::{Request, Status};
+//!
+//! let check_api_key = |request: Request<()>| match request.metadata().get("x-api-key") {
+//!     Some(key) if key == "secret" => Ok(request),
+//!     _ => Err(Status::unauthenticated("missing or invalid API key")),
+//! };
+//!
+//! let grpc_server = QueryServerBuilder::new(ibc, upgrade)
+//!     .channel_interceptor(check_api_key)
+//!     .add_to(&mut tonic::transport::Server::builder())
+//!     .serve(addr);
+//! ```
+//!
+//! With the `tls` feature enabled, [`QueryServerBuilder::add_to_with_tls`]
+//! serves the query services over TLS, optionally requiring clients to
+//! authenticate with a certificate signed by a given CA:
+//! ```rust,ignore
+//! use ibc_query::server::{QueryServerBuilder, QueryServerTlsConfig};
+//!
+//! let tls = QueryServerTlsConfig::new(cert_pem, key_pem).with_client_ca(ca_pem);
+//!
+//! let grpc_server = QueryServerBuilder::new(ibc, upgrade)
+//!     .add_to_with_tls(tonic::transport::Server::builder(), tls)?
+//!     .serve(addr);
+//! ```
+//!
+//! The message size limits of each service, and with the `gzip` feature
+//! enabled, the compression of its messages, are configured with
+//! [`QueryServiceConfig`], e.g. for hosts whose full dumps of channels or
+//! packet commitments exceed the default 4 MiB limit of tonic:
+//! ```rust,ignore
+//! use ibc_query::server::{QueryServerBuilder, QueryServiceConfig};
+//!
+//! let grpc_server = QueryServerBuilder::new(ibc, upgrade)
+//!     .channel_service_config(
+//!         QueryServiceConfig::default()
+//!             .with_max_encoding_message_size(64 * 1024 * 1024)
+//!             .with_gzip(),
+//!     )
+//!     .add_to(&mut tonic::transport::Server::builder())
+//!     .serve(addr);
+//! ```
+
+use alloc::sync::Arc;
+
+use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
+use ibc::clients::wasm::context::WasmValidationContext;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::query_server::QueryServer as FeeQueryServer;
+use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
+use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
+use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
+use ibc_proto::ibc::lightclients::wasm::v1::query_server::QueryServer as WasmQueryServer;
+#[cfg(feature = "gzip")]
+use tonic::codec::CompressionEncoding;
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::transport::server::{Router, Routes};
+use tonic::transport::Server;
+#[cfg(feature = "tls")]
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+use tonic::{Request, Status};
+
+use crate::apps::fee::{FeeQueryContext, FeeQueryService};
+#[cfg(feature = "std")]
+use crate::cache::QueryCache;
+use crate::clients::wasm::WasmQueryService;
+use crate::core::channel::ChannelQueryService;
+use crate::core::client::ClientQueryService;
+use crate::core::connection::ConnectionQueryService;
+use crate::core::context::QueryContext;
+use crate::core::extension::{ExtensionQueryServer, ExtensionQueryService};
+
+/// The rustls-based TLS configuration of the query server, made of
+/// PEM-encoded certificates and keys.
+#[cfg(feature = "tls")]
+#[derive(Clone, Debug)]
+pub struct QueryServerTlsConfig {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    client_ca: Option<Vec<u8>>,
+}
+
+#[cfg(feature = "tls")]
+impl QueryServerTlsConfig {
+    /// Creates a configuration from the PEM-encoded certificate chain of the
+    /// server and its private key.
+    pub fn new(cert: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            cert: cert.into(),
+            key: key.into(),
+            client_ca: None,
+        }
+    }
+
+    /// Requires clients to authenticate with a certificate signed by the
+    /// given PEM-encoded CA certificate (i.e. mutual TLS).
+    pub fn with_client_ca(mut self, client_ca: impl Into<Vec<u8>>) -> Self {
+        self.client_ca = Some(client_ca.into());
+        self
+    }
+}
+
+#[cfg(feature = "tls")]
+impl From<QueryServerTlsConfig> for ServerTlsConfig {
+    fn from(config: QueryServerTlsConfig) -> Self {
+        let tls_config =
+            ServerTlsConfig::new().identity(Identity::from_pem(config.cert, config.key));
+
+        match config.client_ca {
+            Some(client_ca) => tls_config.client_ca_root(Certificate::from_pem(client_ca)),
+            None => tls_config,
+        }
+    }
+}
+
+/// The configuration of the messages of a query service. Unset limits default
+/// to the ones of tonic, i.e. 4 MiB for decoded messages and no limit for
+/// encoded ones.
+#[derive(Clone, Debug, Default)]
+pub struct QueryServiceConfig {
+    max_decoding_message_size: Option<usize>,
+    max_encoding_message_size: Option<usize>,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
+}
+
+impl QueryServiceConfig {
+    /// Limits the size of the requests the service accepts, in bytes.
+    pub fn with_max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.max_decoding_message_size = Some(limit);
+        self
+    }
+
+    /// Limits the size of the responses the service sends, in bytes.
+    pub fn with_max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.max_encoding_message_size = Some(limit);
+        self
+    }
+
+    /// Accepts gzip-compressed requests, and compresses the responses with
+    /// gzip for the clients accepting it.
+    #[cfg(feature = "gzip")]
+    pub fn with_gzip(mut self) -> Self {
+        self.gzip = true;
+        self
+    }
+}
+
+/// Applies a [`QueryServiceConfig`] to one of the generated tonic servers,
+/// which share the same configuration methods but no trait.
+macro_rules! configure_server {
+    ($server:expr, $config:expr) => {{
+        let config: &QueryServiceConfig = $config;
+        let mut server = $server;
+
+        if let Some(limit) = config.max_decoding_message_size {
+            server = server.max_decoding_message_size(limit);
+        }
+
+        if let Some(limit) = config.max_encoding_message_size {
+            server = server.max_encoding_message_size(limit);
+        }
+
+        #[cfg(feature = "gzip")]
+        if config.gzip {
+            server = server
+                .accept_compressed(CompressionEncoding::Gzip)
+                .send_compressed(CompressionEncoding::Gzip);
+        }
+
+        server
+    }};
+}
+
+type InterceptorFn = dyn Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync;
+
+/// A chain of interceptors, run in the order they were added to the
+/// [`QueryServerBuilder`]. The request is rejected as soon as one of them
+/// returns an error.
+#[derive(Clone, Default)]
+pub struct QueryInterceptor {
+    interceptors: Vec<Arc<InterceptorFn>>,
+}
+
+impl QueryInterceptor {
+    fn push(&mut self, interceptor: Arc<InterceptorFn>) {
+        self.interceptors.push(interceptor);
+    }
+}
+
+impl Interceptor for QueryInterceptor {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        self.interceptors
+            .iter()
+            .try_fold(request, |request, interceptor| interceptor(request))
+    }
+}
+
+/// Adds a query service backed by a context of its own, configured and
+/// wrapped with its interceptors, to the routes of the query server.
+type AddServiceFn =
+    dyn FnOnce(Routes, QueryInterceptor, &QueryServiceConfig) -> Routes + Send + Sync;
+
+/// The client query service, wrapped with its interceptors.
+pub type InterceptedClientQueryService<I, U> =
+    InterceptedService<ClientQueryServer<ClientQueryService<I, U>>, QueryInterceptor>;
+
+/// The connection query service, wrapped with its interceptors.
+pub type InterceptedConnectionQueryService<I> =
+    InterceptedService<ConnectionQueryServer<ConnectionQueryService<I>>, QueryInterceptor>;
+
+/// The channel query service, wrapped with its interceptors.
+pub type InterceptedChannelQueryService<I> =
+    InterceptedService<ChannelQueryServer<ChannelQueryService<I>>, QueryInterceptor>;
+
+/// The extension query service, wrapped with its interceptors.
+pub type InterceptedExtensionQueryService<I> =
+    InterceptedService<ExtensionQueryServer<ExtensionQueryService<I>>, QueryInterceptor>;
+
+/// The fee query service, wrapped with its interceptors.
+pub type InterceptedFeeQueryService<F> =
+    InterceptedService<FeeQueryServer<FeeQueryService<F>>, QueryInterceptor>;
+
+/// The Wasm query service, wrapped with its interceptors.
+pub type InterceptedWasmQueryService<W> =
+    InterceptedService<WasmQueryServer<WasmQueryService<W>>, QueryInterceptor>;
+
+/// Builds the IBC query services, optionally protected by interceptors.
+///
+/// Generics `I` and `U` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct QueryServerBuilder<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + Clone + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    ibc_context: I,
+    upgrade_context: U,
+    client_interceptor: QueryInterceptor,
+    connection_interceptor: QueryInterceptor,
+    channel_interceptor: QueryInterceptor,
+    extension_interceptor: QueryInterceptor,
+    fee_interceptor: QueryInterceptor,
+    wasm_interceptor: QueryInterceptor,
+    client_config: QueryServiceConfig,
+    connection_config: QueryServiceConfig,
+    channel_config: QueryServiceConfig,
+    extension_config: QueryServiceConfig,
+    fee_config: QueryServiceConfig,
+    wasm_config: QueryServiceConfig,
+    fee_service: Option<Box<AddServiceFn>>,
+    wasm_service: Option<Box<AddServiceFn>>,
+    #[cfg(feature = "std")]
+    cache: Option<Arc<QueryCache>>,
+}
+
+impl<I, U> QueryServerBuilder<I, U>
+where
+    I: QueryContext + Clone + Send + Sync + 'static,
+    U: UpgradeValidationContext + Clone + Send + Sync + 'static,
+    <I as ValidationContext>::AnyClientState: Into<Any>,
+    <I as ValidationContext>::AnyConsensusState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <U as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    /// Parameters `ibc_context` and `upgrade_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ibc_context: I, upgrade_context: U) -> Self {
+        Self {
+            ibc_context,
+            upgrade_context,
+            client_interceptor: QueryInterceptor::default(),
+            connection_interceptor: QueryInterceptor::default(),
+            channel_interceptor: QueryInterceptor::default(),
+            extension_interceptor: QueryInterceptor::default(),
+            fee_interceptor: QueryInterceptor::default(),
+            wasm_interceptor: QueryInterceptor::default(),
+            client_config: QueryServiceConfig::default(),
+            connection_config: QueryServiceConfig::default(),
+            channel_config: QueryServiceConfig::default(),
+            extension_config: QueryServiceConfig::default(),
+            fee_config: QueryServiceConfig::default(),
+            wasm_config: QueryServiceConfig::default(),
+            fee_service: None,
+            wasm_service: None,
+            #[cfg(feature = "std")]
+            cache: None,
+        }
+    }
+
+    /// Caches the responses of the expensive list queries of all the query
+    /// services in the given cache.
+    #[cfg(feature = "std")]
+    pub fn cache(mut self, cache: Arc<QueryCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Registers the fee query service, backed by the given fee middleware
+    /// context, along with the other query services.
+    ///
+    /// The parameter `fee_context` must be a type where writes from one thread are readable from another.
+    pub fn with_fee_context<F>(mut self, fee_context: F) -> Self
+    where
+        F: FeeQueryContext + Send + Sync + 'static,
+    {
+        self.fee_service = Some(Box::new(move |routes, interceptor, config| {
+            routes.add_service(fee_service(fee_context, interceptor, config))
+        }));
+        self
+    }
+
+    /// Registers the Wasm query service, backed by the given Wasm light client
+    /// context, along with the other query services.
+    ///
+    /// The parameter `wasm_context` must be a type where writes from one thread are readable from another.
+    pub fn with_wasm_context<W>(mut self, wasm_context: W) -> Self
+    where
+        W: WasmValidationContext + Send + Sync + 'static,
+    {
+        self.wasm_service = Some(Box::new(move |routes, interceptor, config| {
+            routes.add_service(wasm_service(wasm_context, interceptor, config))
+        }));
+        self
+    }
+
+    /// Adds an interceptor to all the query services.
+    pub fn interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        let interceptor: Arc<InterceptorFn> = Arc::new(interceptor);
+
+        self.client_interceptor.push(interceptor.clone());
+        self.connection_interceptor.push(interceptor.clone());
+        self.channel_interceptor.push(interceptor.clone());
+        self.extension_interceptor.push(interceptor.clone());
+        self.fee_interceptor.push(interceptor.clone());
+        self.wasm_interceptor.push(interceptor);
+
+        self
+    }
+
+    /// Adds an interceptor to the client query service only.
+    pub fn client_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.client_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Adds an interceptor to the connection query service only.
+    pub fn connection_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.connection_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Adds an interceptor to the channel query service only.
+    pub fn channel_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.channel_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Adds an interceptor to the extension query service only.
+    pub fn extension_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.extension_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Adds an interceptor to the fee query service only.
+    pub fn fee_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.fee_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Adds an interceptor to the Wasm query service only.
+    pub fn wasm_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.wasm_interceptor.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Configures the messages of all the query services.
+    pub fn service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.client_config = config.clone();
+        self.connection_config = config.clone();
+        self.channel_config = config.clone();
+        self.extension_config = config.clone();
+        self.fee_config = config.clone();
+        self.wasm_config = config;
+        self
+    }
+
+    /// Configures the messages of the client query service only.
+    pub fn client_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.client_config = config;
+        self
+    }
+
+    /// Configures the messages of the connection query service only.
+    pub fn connection_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.connection_config = config;
+        self
+    }
+
+    /// Configures the messages of the channel query service only.
+    pub fn channel_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.channel_config = config;
+        self
+    }
+
+    /// Configures the messages of the extension query service only.
+    pub fn extension_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.extension_config = config;
+        self
+    }
+
+    /// Configures the messages of the fee query service only.
+    pub fn fee_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.fee_config = config;
+        self
+    }
+
+    /// Configures the messages of the Wasm query service only.
+    pub fn wasm_service_config(mut self, config: QueryServiceConfig) -> Self {
+        self.wasm_config = config;
+        self
+    }
+
+    /// Returns the client query service, configured and wrapped with its
+    /// interceptors.
+    pub fn client_service(&self) -> InterceptedClientQueryService<I, U> {
+        let service =
+            ClientQueryService::new(self.ibc_context.clone(), self.upgrade_context.clone());
+
+        #[cfg(feature = "std")]
+        let service = match &self.cache {
+            Some(cache) => service.with_cache(cache.clone()),
+            None => service,
+        };
+
+        InterceptedService::new(
+            configure_server!(ClientQueryServer::new(service), &self.client_config),
+            self.client_interceptor.clone(),
+        )
+    }
+
+    /// Returns the connection query service, configured and wrapped with its
+    /// interceptors.
+    pub fn connection_service(&self) -> InterceptedConnectionQueryService<I> {
+        let service = ConnectionQueryService::new(self.ibc_context.clone());
+
+        #[cfg(feature = "std")]
+        let service = match &self.cache {
+            Some(cache) => service.with_cache(cache.clone()),
+            None => service,
+        };
+
+        InterceptedService::new(
+            configure_server!(ConnectionQueryServer::new(service), &self.connection_config),
+            self.connection_interceptor.clone(),
+        )
+    }
+
+    /// Returns the channel query service, configured and wrapped with its
+    /// interceptors.
+    pub fn channel_service(&self) -> InterceptedChannelQueryService<I> {
+        let service = ChannelQueryService::new(self.ibc_context.clone());
+
+        #[cfg(feature = "std")]
+        let service = match &self.cache {
+            Some(cache) => service.with_cache(cache.clone()),
+            None => service,
+        };
+
+        InterceptedService::new(
+            configure_server!(ChannelQueryServer::new(service), &self.channel_config),
+            self.channel_interceptor.clone(),
+        )
+    }
+
+    /// Returns the extension query service, serving the queries that are not
+    /// part of the `ibc-proto` query services, configured and wrapped with
+    /// its interceptors.
+    ///
+    /// The client validation context of the host must support Tendermint
+    /// clients, for the client expiry query.
+    pub fn extension_service(&self) -> InterceptedExtensionQueryService<I>
+    where
+        I::V: TmValidationContext,
+    {
+        let service = ExtensionQueryService::new(self.ibc_context.clone());
+
+        InterceptedService::new(
+            configure_server!(ExtensionQueryServer::new(service), &self.extension_config),
+            self.extension_interceptor.clone(),
+        )
+    }
+
+    /// Adds all the query services to the given tonic server, including the
+    /// fee and Wasm query services if their contexts were provided.
+    pub fn add_to<L>(self, server: &mut Server<L>) -> Router<L>
+    where
+        L: Clone,
+        I::V: TmValidationContext,
+    {
+        let mut routes = Routes::new(self.client_service())
+            .add_service(self.connection_service())
+            .add_service(self.channel_service())
+            .add_service(self.extension_service());
+
+        if let Some(add_fee_service) = self.fee_service {
+            routes = add_fee_service(routes, self.fee_interceptor, &self.fee_config);
+        }
+
+        if let Some(add_wasm_service) = self.wasm_service {
+            routes = add_wasm_service(routes, self.wasm_interceptor, &self.wasm_config);
+        }
+
+        server.add_routes(routes)
+    }
+
+    /// Configures the given tonic server with TLS and adds all the query
+    /// services to it.
+    ///
+    /// Fails if the certificate or key of the TLS configuration are invalid.
+    #[cfg(feature = "tls")]
+    pub fn add_to_with_tls<L>(
+        self,
+        server: Server<L>,
+        tls: QueryServerTlsConfig,
+    ) -> Result<Router<L>, tonic::transport::Error>
+    where
+        L: Clone,
+        I::V: TmValidationContext,
+    {
+        let mut server = server.tls_config(tls.into())?;
+
+        Ok(self.add_to(&mut server))
+    }
+}
+
+/// Returns the fee query service, configured and wrapped with its interceptors.
+fn fee_service<F>(
+    fee_context: F,
+    interceptor: QueryInterceptor,
+    config: &QueryServiceConfig,
+) -> InterceptedFeeQueryService<F>
+where
+    F: FeeQueryContext + Send + Sync + 'static,
+{
+    InterceptedService::new(
+        configure_server!(
+            FeeQueryServer::new(FeeQueryService::new(fee_context)),
+            config
+        ),
+        interceptor,
+    )
+}
+
+/// Returns the Wasm query service, configured and wrapped with its
+/// interceptors.
+fn wasm_service<W>(
+    wasm_context: W,
+    interceptor: QueryInterceptor,
+    config: &QueryServiceConfig,
+) -> InterceptedWasmQueryService<W>
+where
+    W: WasmValidationContext + Send + Sync + 'static,
+{
+    InterceptedService::new(
+        configure_server!(
+            WasmQueryServer::new(WasmQueryService::new(wasm_context)),
+            config
+        ),
+        interceptor,
+    )
+}
+
+/// Registers all the IBC query services on a single tonic router, backed by a
+/// single host context implementing both [`QueryContext`] and
+/// [`UpgradeValidationContext`].
+///
+/// The context is cloned once per registered service, and all the services
+/// must observe the same state. The generic `C` must therefore be a handle
+/// whose clones share the underlying store, which means using
+/// `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+///
+/// The fee and Wasm query services are backed by contexts of their own, and
+/// are therefore not registered by this server. Hosts serving them, or
+/// needing interceptors or separate contexts, can use the underlying
+/// [`QueryServerBuilder`] instead, e.g. through [`Self::into_builder`]:
+/// ```rust,ignore
+/// let grpc_server = IbcQueryServer::new(ibc)
+///     .into_builder()
+///     .with_fee_context(fee_middleware)
+///     .add_to(&mut tonic::transport::Server::builder())
+///     .serve(addr);
+/// ```
+pub struct IbcQueryServer<C>
+where
+    C: QueryContext + UpgradeValidationContext + Clone + Send + Sync + 'static,
+    <C as ValidationContext>::AnyClientState: Into<Any>,
+    <C as ValidationContext>::AnyConsensusState: Into<Any>,
+    <C as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <C as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    builder: QueryServerBuilder<C, C>,
+}
+
+impl<C> IbcQueryServer<C>
+where
+    C: QueryContext + UpgradeValidationContext + Clone + Send + Sync + 'static,
+    <C as ValidationContext>::AnyClientState: Into<Any>,
+    <C as ValidationContext>::AnyConsensusState: Into<Any>,
+    <C as UpgradeValidationContext>::AnyClientState: Into<Any>,
+    <C as UpgradeValidationContext>::AnyConsensusState: Into<Any>,
+{
+    pub fn new(ctx: C) -> Self {
+        Self {
+            builder: QueryServerBuilder::new(ctx.clone(), ctx),
+        }
+    }
+
+    /// Returns the underlying builder, e.g. to attach interceptors.
+    pub fn into_builder(self) -> QueryServerBuilder<C, C> {
+        self.builder
+    }
+
+    /// Returns a tonic router serving all the query services.
+    pub fn into_router(self) -> Router
+    where
+        <C as ValidationContext>::V: TmValidationContext,
+    {
+        self.builder.add_to(&mut Server::builder())
+    }
+
+    /// Returns a tonic router serving all the query services over TLS.
+    ///
+    /// Fails if the certificate or key of the TLS configuration are invalid.
+    #[cfg(feature = "tls")]
+    pub fn into_router_with_tls(
+        self,
+        tls: QueryServerTlsConfig,
+    ) -> Result<Router, tonic::transport::Error>
+    where
+        <C as ValidationContext>::V: TmValidationContext,
+    {
+        self.builder.add_to_with_tls(Server::builder(), tls)
+    }
+}
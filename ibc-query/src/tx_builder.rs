@@ -0,0 +1,404 @@
+//! Provides functions assembling the IBC messages relayed to a chain from the
+//! results of the [`typed`](crate::core::connection::typed) queries of its
+//! counterparty, for lightweight relayers and scripts built on `ibc-rs`.
+//!
+//! Following the convention of the handlers, messages of a handshake step
+//! are sent to the chain whose end is moved forward, and carry the proofs of
+//! the state of the other chain. The proofs of a message must all be taken at
+//! the same height, to which the client of the receiving chain must have been
+//! updated beforehand.
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc_query::core::client::typed::consensus_state;
+//! use ibc_query::core::connection::typed::{connection, connection_client_state};
+//! use ibc_query::tx_builder::conn_open_try;
+//!
+//! // Queries of chain A, whose connection end `conn_id_on_a` is in `Init`.
+//! let connection_on_a = connection(&ibc_a, &conn_id_on_a)?;
+//! let client_state_of_b_on_a = connection_client_state(&ibc_a, &conn_id_on_a)?;
+//! let consensus_state_of_b_on_a = consensus_state(
+//!     &ibc_a,
+//!     &client_state_of_b_on_a.value.client_id,
+//!     client_state_of_b_on_a.value.client_state.latest_height(),
+//! )?;
+//!
+//! // Message to be sent to chain B.
+//! let msg = conn_open_try(
+//!     conn_id_on_a,
+//!     prefix_on_a,
+//!     connection_on_a,
+//!     client_state_of_b_on_a,
+//!     consensus_state_of_b_on_a,
+//!     signer,
+//! )?;
+//! ```
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::msgs::{
+    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelOpenAck, MsgChannelOpenConfirm,
+    MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::client::types::msgs::{MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::connection::types::msgs::{
+    MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenTry,
+};
+use ibc::core::connection::types::{ConnectionEnd, Counterparty as ConnectionCounterparty};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+
+use crate::core::typed::{ConsensusStateWithHeight, IdentifiedClientState, Proven};
+use crate::error::QueryError;
+
+/// Builds the message creating a client with the given states, e.g. the self
+/// client state and consensus state of the tracked chain.
+pub fn create_client(
+    client_state: impl Into<Any>,
+    consensus_state: impl Into<Any>,
+    signer: Signer,
+) -> MsgCreateClient {
+    MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
+}
+
+/// Builds the message updating the client `client_id` with the given client
+/// message, e.g. a header of the tracked chain.
+pub fn update_client(
+    client_id: ClientId,
+    client_message: impl Into<Any>,
+    signer: Signer,
+) -> MsgUpdateClient {
+    MsgUpdateClient {
+        client_id,
+        client_message: client_message.into(),
+        signer,
+    }
+}
+
+/// Builds the `ConnOpenTry` message sent to chain B from the connection end
+/// `conn_id_on_a` of chain A and the states of the client of chain A tracking
+/// chain B.
+pub fn conn_open_try<C, S>(
+    conn_id_on_a: ConnectionId,
+    prefix_on_a: CommitmentPrefix,
+    connection_on_a: Proven<ConnectionEnd>,
+    client_state_of_b_on_a: Proven<IdentifiedClientState<C>>,
+    consensus_state_of_b_on_a: Proven<ConsensusStateWithHeight<S>>,
+    signer: Signer,
+) -> Result<MsgConnectionOpenTry, QueryError>
+where
+    C: Into<Any>,
+    S: Into<Any>,
+{
+    let proofs_height_on_a = same_proof_height(&[
+        connection_on_a.proof_height,
+        client_state_of_b_on_a.proof_height,
+        consensus_state_of_b_on_a.proof_height,
+    ])?;
+
+    let conn_end_on_a = connection_on_a.value;
+
+    #[allow(deprecated)]
+    Ok(MsgConnectionOpenTry {
+        client_id_on_b: conn_end_on_a.counterparty().client_id().clone(),
+        client_state_of_b_on_a: client_state_of_b_on_a.value.client_state.into(),
+        counterparty: ConnectionCounterparty::new(
+            conn_end_on_a.client_id().clone(),
+            Some(conn_id_on_a),
+            prefix_on_a,
+        ),
+        versions_on_a: conn_end_on_a.versions().to_vec(),
+        proof_conn_end_on_a: proof_bytes(connection_on_a.proof)?,
+        proof_client_state_of_b_on_a: proof_bytes(client_state_of_b_on_a.proof)?,
+        proof_consensus_state_of_b_on_a: proof_bytes(consensus_state_of_b_on_a.proof)?,
+        proofs_height_on_a,
+        consensus_height_of_b_on_a: consensus_state_of_b_on_a.value.height,
+        delay_period: conn_end_on_a.delay_period(),
+        signer,
+        proof_consensus_state_of_b: None,
+        previous_connection_id: String::new(),
+    })
+}
+
+/// Builds the `ConnOpenAck` message sent to chain A from the connection end
+/// `conn_id_on_b` of chain B and the states of the client of chain B tracking
+/// chain A.
+pub fn conn_open_ack<C, S>(
+    conn_id_on_b: ConnectionId,
+    connection_on_b: Proven<ConnectionEnd>,
+    client_state_of_a_on_b: Proven<IdentifiedClientState<C>>,
+    consensus_state_of_a_on_b: Proven<ConsensusStateWithHeight<S>>,
+    signer: Signer,
+) -> Result<MsgConnectionOpenAck, QueryError>
+where
+    C: Into<Any>,
+    S: Into<Any>,
+{
+    let proofs_height_on_b = same_proof_height(&[
+        connection_on_b.proof_height,
+        client_state_of_a_on_b.proof_height,
+        consensus_state_of_a_on_b.proof_height,
+    ])?;
+
+    let conn_end_on_b = connection_on_b.value;
+
+    let version =
+        conn_end_on_b
+            .versions()
+            .first()
+            .cloned()
+            .ok_or_else(|| QueryError::InvalidRequest {
+                description: format!("connection `{conn_id_on_b}` has no version"),
+            })?;
+
+    Ok(MsgConnectionOpenAck {
+        conn_id_on_a: counterparty_connection_id(&conn_id_on_b, &conn_end_on_b)?,
+        conn_id_on_b,
+        client_state_of_a_on_b: client_state_of_a_on_b.value.client_state.into(),
+        proof_conn_end_on_b: proof_bytes(connection_on_b.proof)?,
+        proof_client_state_of_a_on_b: proof_bytes(client_state_of_a_on_b.proof)?,
+        proof_consensus_state_of_a_on_b: proof_bytes(consensus_state_of_a_on_b.proof)?,
+        proofs_height_on_b,
+        consensus_height_of_a_on_b: consensus_state_of_a_on_b.value.height,
+        version,
+        signer,
+        proof_consensus_state_of_a: None,
+    })
+}
+
+/// Builds the `ConnOpenConfirm` message sent to chain B from the open
+/// connection end `conn_id_on_a` of chain A.
+pub fn conn_open_confirm(
+    conn_id_on_a: &ConnectionId,
+    connection_on_a: Proven<ConnectionEnd>,
+    signer: Signer,
+) -> Result<MsgConnectionOpenConfirm, QueryError> {
+    Ok(MsgConnectionOpenConfirm {
+        conn_id_on_b: counterparty_connection_id(conn_id_on_a, &connection_on_a.value)?,
+        proof_conn_end_on_a: proof_bytes(connection_on_a.proof)?,
+        proof_height_on_a: connection_on_a.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `ChanOpenTry` message sent to chain B from the channel end
+/// `port_id_on_a`/`chan_id_on_a` of chain A, to be opened on the connection
+/// `conn_id_on_b` of chain B.
+pub fn chan_open_try(
+    port_id_on_a: PortId,
+    chan_id_on_a: ChannelId,
+    channel_on_a: Proven<ChannelEnd>,
+    conn_id_on_b: ConnectionId,
+    signer: Signer,
+) -> Result<MsgChannelOpenTry, QueryError> {
+    let chan_end_on_a = channel_on_a.value;
+
+    #[allow(deprecated)]
+    Ok(MsgChannelOpenTry {
+        port_id_on_b: chan_end_on_a.counterparty().port_id().clone(),
+        connection_hops_on_b: vec![conn_id_on_b],
+        port_id_on_a,
+        chan_id_on_a,
+        version_supported_on_a: chan_end_on_a.version().clone(),
+        proof_chan_end_on_a: proof_bytes(channel_on_a.proof)?,
+        proof_height_on_a: channel_on_a.proof_height,
+        ordering: *chan_end_on_a.ordering(),
+        signer,
+        version_proposal: chan_end_on_a.version().clone(),
+    })
+}
+
+/// Builds the `ChanOpenAck` message sent to chain A from the channel end
+/// `port_id_on_b`/`chan_id_on_b` of chain B.
+pub fn chan_open_ack(
+    port_id_on_b: &PortId,
+    chan_id_on_b: ChannelId,
+    channel_on_b: Proven<ChannelEnd>,
+    signer: Signer,
+) -> Result<MsgChannelOpenAck, QueryError> {
+    let chan_end_on_b = channel_on_b.value;
+
+    Ok(MsgChannelOpenAck {
+        port_id_on_a: chan_end_on_b.counterparty().port_id().clone(),
+        chan_id_on_a: counterparty_channel_id(port_id_on_b, &chan_id_on_b, &chan_end_on_b)?,
+        chan_id_on_b,
+        version_on_b: chan_end_on_b.version().clone(),
+        proof_chan_end_on_b: proof_bytes(channel_on_b.proof)?,
+        proof_height_on_b: channel_on_b.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `ChanOpenConfirm` message sent to chain B from the open channel
+/// end `port_id_on_a`/`chan_id_on_a` of chain A.
+pub fn chan_open_confirm(
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    channel_on_a: Proven<ChannelEnd>,
+    signer: Signer,
+) -> Result<MsgChannelOpenConfirm, QueryError> {
+    Ok(MsgChannelOpenConfirm {
+        port_id_on_b: channel_on_a.value.counterparty().port_id().clone(),
+        chan_id_on_b: counterparty_channel_id(port_id_on_a, chan_id_on_a, &channel_on_a.value)?,
+        proof_chan_end_on_a: proof_bytes(channel_on_a.proof)?,
+        proof_height_on_a: channel_on_a.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `ChanCloseConfirm` message sent to chain B from the closed
+/// channel end `port_id_on_a`/`chan_id_on_a` of chain A.
+pub fn chan_close_confirm(
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    channel_on_a: Proven<ChannelEnd>,
+    signer: Signer,
+) -> Result<MsgChannelCloseConfirm, QueryError> {
+    Ok(MsgChannelCloseConfirm {
+        port_id_on_b: channel_on_a.value.counterparty().port_id().clone(),
+        chan_id_on_b: counterparty_channel_id(port_id_on_a, chan_id_on_a, &channel_on_a.value)?,
+        proof_chan_end_on_a: proof_bytes(channel_on_a.proof)?,
+        proof_height_on_a: channel_on_a.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `RecvPacket` message sent to chain B from the commitment of the
+/// packet on chain A.
+pub fn recv_packet(
+    packet: Packet,
+    commitment_on_a: Proven<PacketCommitment>,
+    signer: Signer,
+) -> Result<MsgRecvPacket, QueryError> {
+    Ok(MsgRecvPacket {
+        packet,
+        proof_commitment_on_a: proof_bytes(commitment_on_a.proof)?,
+        proof_height_on_a: commitment_on_a.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `Acknowledgement` message sent to chain A from the
+/// acknowledgement written by chain B, e.g. as emitted in its
+/// `write_acknowledgement` event, and its commitment on chain B.
+pub fn acknowledgement(
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+    ack_commitment_on_b: Proven<AcknowledgementCommitment>,
+    signer: Signer,
+) -> Result<MsgAcknowledgement, QueryError> {
+    Ok(MsgAcknowledgement {
+        packet,
+        acknowledgement,
+        proof_acked_on_b: proof_bytes(ack_commitment_on_b.proof)?,
+        proof_height_on_b: ack_commitment_on_b.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `Timeout` message sent to chain A for a packet of an unordered
+/// channel, from the proof of absence of its receipt on chain B.
+pub fn timeout_unordered(
+    packet: Packet,
+    receipt_on_b: Proven<bool>,
+    signer: Signer,
+) -> Result<MsgTimeout, QueryError> {
+    if receipt_on_b.value {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "packet with the sequence {} has been received and cannot time out",
+                packet.seq_on_a
+            ),
+        });
+    }
+
+    Ok(MsgTimeout {
+        next_seq_recv_on_b: packet.seq_on_a,
+        packet,
+        proof_unreceived_on_b: proof_bytes(receipt_on_b.proof)?,
+        proof_height_on_b: receipt_on_b.proof_height,
+        signer,
+    })
+}
+
+/// Builds the `Timeout` message sent to chain A for a packet of an ordered
+/// channel, from the next sequence to be received on chain B.
+pub fn timeout_ordered(
+    packet: Packet,
+    next_seq_recv_on_b: Proven<Sequence>,
+    signer: Signer,
+) -> Result<MsgTimeout, QueryError> {
+    if next_seq_recv_on_b.value > packet.seq_on_a {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "packet with the sequence {} has been received and cannot time out",
+                packet.seq_on_a
+            ),
+        });
+    }
+
+    Ok(MsgTimeout {
+        packet,
+        next_seq_recv_on_b: next_seq_recv_on_b.value,
+        proof_unreceived_on_b: proof_bytes(next_seq_recv_on_b.proof)?,
+        proof_height_on_b: next_seq_recv_on_b.proof_height,
+        signer,
+    })
+}
+
+fn proof_bytes(proof: Vec<u8>) -> Result<CommitmentProofBytes, QueryError> {
+    CommitmentProofBytes::try_from(proof).map_err(|e| QueryError::ProofNotFound {
+        description: e.to_string(),
+    })
+}
+
+/// Returns the height at which all the given proofs were taken, as required by
+/// the messages carrying several proofs.
+fn same_proof_height(proof_heights: &[Height]) -> Result<Height, QueryError> {
+    let (first, rest) = proof_heights
+        .split_first()
+        .expect("Never fails: messages carry at least one proof");
+
+    if let Some(other) = rest.iter().find(|height| *height != first) {
+        return Err(QueryError::InvalidRequest {
+            description: format!(
+                "proofs must be taken at the same height, got heights {first} and {other}"
+            ),
+        });
+    }
+
+    Ok(*first)
+}
+
+fn counterparty_connection_id(
+    conn_id: &ConnectionId,
+    conn_end: &ConnectionEnd,
+) -> Result<ConnectionId, QueryError> {
+    conn_end
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| QueryError::InvalidRequest {
+            description: format!("connection `{conn_id}` has no counterparty connection"),
+        })
+}
+
+fn counterparty_channel_id(
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    chan_end: &ChannelEnd,
+) -> Result<ChannelId, QueryError> {
+    chan_end
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or_else(|| QueryError::InvalidRequest {
+            description: format!("channel `{port_id}/{chan_id}` has no counterparty channel"),
+        })
+}
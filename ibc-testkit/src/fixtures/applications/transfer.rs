@@ -48,7 +48,7 @@ pub fn extract_transfer_packet(msg: &MsgTransfer, sequence: Sequence) -> Packet
         chan_id_on_a: msg.chan_id_on_a.clone(),
         port_id_on_b: PortId::transfer(),
         chan_id_on_b: ChannelId::default(),
-        data,
+        data: data.into(),
         timeout_height_on_b: msg.timeout_height_on_b,
         timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
     }
@@ -0,0 +1,89 @@
+use core::time::Duration;
+
+use ibc::clients::rollup::types::proto::v1::RollupBlock as RawRollupBlock;
+use ibc::clients::rollup::types::{
+    BinaryMerkleProof, ClientState as ClientStateType, Header, Namespace, NamespaceProof,
+    NamespacedHash, RollupBlock, RowProof, ShareProof, NAMESPACE_SIZE, SHARE_SIZE,
+};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::host_functions::{DefaultHostFunctions, HostFunctionsProvider};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::Protobuf;
+
+/// Returns the namespace of the given identifier, for testing purposes only!
+pub fn dummy_namespace(id: u8) -> Namespace {
+    let mut namespace = [0; NAMESPACE_SIZE];
+    namespace[NAMESPACE_SIZE - 1] = id;
+    Namespace::new(namespace)
+}
+
+/// Returns a dummy rollup `ClientState` of the rollup `rollup-1` publishing
+/// its blocks under the namespace `1` of the DA layer tracked by the given
+/// client, for testing purposes only!
+pub fn dummy_rollup_client_state(
+    latest_height: Height,
+    da_client_id: ClientId,
+    fraud_proof_window: Duration,
+) -> ClientStateType {
+    ClientStateType::new(
+        ChainId::new("rollup-1").expect("Never fails"),
+        latest_height,
+        da_client_id,
+        dummy_namespace(1),
+        fraud_proof_window,
+        ProofSpecs::cosmos(),
+    )
+    .expect("Never fails")
+}
+
+/// Returns a rollup `Header` publishing the given block alone, in a square of
+/// a single share, under the given namespace at the given DA height, along
+/// with the data root of the square, for testing purposes only!
+pub fn dummy_published_rollup_header(
+    namespace: &Namespace,
+    block: RollupBlock,
+    da_height: Height,
+) -> (Header, [u8; 32]) {
+    let blob = Protobuf::<RawRollupBlock>::encode_vec(block.clone());
+
+    let mut share = namespace.as_bytes().to_vec();
+    share.push(1);
+    share.extend_from_slice(&(blob.len() as u32).to_be_bytes());
+    share.extend_from_slice(&blob);
+    share.resize(SHARE_SIZE, 0);
+
+    let row_root = NamespacedHash::leaf::<DefaultHostFunctions>(&share).expect("Never fails");
+    // The data root of a square of a single row is the hash of the leaf of
+    // the root of that row.
+    let data_root =
+        DefaultHostFunctions::sha2_256(&[&[0], row_root.to_bytes().as_slice()].concat());
+
+    let header = Header {
+        block,
+        da_height,
+        share_proof: ShareProof {
+            data: vec![share],
+            share_proofs: vec![NamespaceProof {
+                start: 0,
+                end: 1,
+                nodes: vec![],
+            }],
+            namespace: *namespace,
+            row_proof: RowProof {
+                row_roots: vec![row_root],
+                proofs: vec![BinaryMerkleProof {
+                    total: 1,
+                    index: 0,
+                    leaf_hash: data_root,
+                    aunts: vec![],
+                }],
+                start_row: 0,
+                end_row: 0,
+            },
+        },
+    };
+
+    (header, data_root)
+}
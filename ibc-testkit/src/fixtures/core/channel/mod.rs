@@ -5,6 +5,8 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_cancel;
+mod chan_upgrade_timeout;
 mod packet;
 mod recv_packet;
 mod timeout;
@@ -17,6 +19,8 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_timeout::*;
 use ibc::core::channel::types::proto::v1::{
     Channel as RawChannel, Counterparty as RawCounterparty,
 };
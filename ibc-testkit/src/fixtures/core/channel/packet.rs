@@ -37,7 +37,7 @@ impl From<PacketConfig> for Packet {
             chan_id_on_a: config.chan_id_on_a,
             port_id_on_b: config.port_id_on_b,
             chan_id_on_b: config.chan_id_on_b,
-            data: config.data,
+            data: config.data.into(),
             timeout_height_on_b: config.timeout_height_on_b,
             timeout_timestamp_on_b: config.timeout_timestamp_on_b,
         }
@@ -262,7 +262,7 @@ mod tests {
     /// See issue [#199](https://github.com/cosmos/ibc-rs/issues/199)
     pub fn test_packet_data_non_utf8() {
         let mut packet = Packet::try_from(dummy_raw_packet(1, 1)).unwrap();
-        packet.data = vec![128];
+        packet.data = vec![128].into();
 
         let ibc_event = IbcEvent::SendPacket(SendPacket::new(
             packet,
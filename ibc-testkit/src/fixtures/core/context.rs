@@ -121,7 +121,8 @@ impl From<MockContextConfig> for MockContext {
             max_history_size: params.max_history_size,
             history,
             block_time: params.block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::with_block_time(params.block_time))),
+            identifier_policy: Default::default(),
         }
     }
 }
@@ -173,10 +173,7 @@ impl HostBlock {
         height: u64,
         timestamp: Timestamp,
     ) -> SyntheticTmBlock {
-        let validators = [
-            TestgenValidator::new("1").voting_power(50),
-            TestgenValidator::new("2").voting_power(50),
-        ];
+        let validators = default_tm_validators();
 
         let header = TestgenHeader::new(&validators)
             .height(height)
@@ -203,6 +200,14 @@ impl HostBlock {
     }
 }
 
+/// Returns the validators signing the synthetically-generated Tendermint blocks.
+pub fn default_tm_validators() -> [TestgenValidator; 2] {
+    [
+        TestgenValidator::new("1").voting_power(50),
+        TestgenValidator::new("2").voting_power(50),
+    ]
+}
+
 impl From<SyntheticTmBlock> for AnyConsensusState {
     fn from(light_block: SyntheticTmBlock) -> Self {
         let cs = TmConsensusState::from(light_block.header().clone());
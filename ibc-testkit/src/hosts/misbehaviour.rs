@@ -0,0 +1,157 @@
+//! Builders of misbehaviour evidence against a mock context emulating a
+//! (synthetic) Tendermint chain, in order to test the freezing of light clients
+//! without hand-crafting signed headers.
+//!
+//! Each builder pairs a block from the history of the chain with a block
+//! generated on purpose and signed by the same validators, so that both headers
+//! pass the light client verification on their own.
+
+use core::time::Duration;
+
+use ibc::clients::tendermint::types::Misbehaviour as TmMisbehaviour;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use tendermint::AppHash;
+use tendermint_testgen::{Generator, Header as TestgenHeader, LightBlock as TestgenLightBlock};
+
+use super::block::{default_tm_validators, SyntheticTmBlock};
+use crate::testapp::ibc::core::types::MockContext;
+
+/// Returns a misbehaviour made of the block of `ctx` at `height` and of a
+/// conflicting block at the same height and time, committing to a different
+/// application state, i.e. the evidence of a fork of the chain.
+///
+/// Panics if `ctx` is not a synthetic Tendermint chain or if the block at
+/// `height` is not in its history.
+pub fn conflicting_headers_misbehaviour(
+    ctx: &MockContext,
+    client_id: &ClientId,
+    height: &Height,
+    trusted_height: Height,
+) -> TmMisbehaviour {
+    let block = host_tm_block(ctx, height, trusted_height);
+    let conflicting_block = generate_tm_block(
+        &ctx.host_chain_id,
+        height.revision_height(),
+        block.header().time.into(),
+        Some(AppHash::try_from(b"conflicting_app_hash".to_vec()).expect("Never fails")),
+        trusted_height,
+    );
+
+    TmMisbehaviour::new(client_id.clone(), block.into(), conflicting_block.into())
+}
+
+/// Returns a misbehaviour made of the block of `ctx` at `height` and of a
+/// block at the next height which is not later than it, i.e. the evidence of
+/// a violation of the monotonicity of the BFT time.
+///
+/// Panics if `ctx` is not a synthetic Tendermint chain or if the block at
+/// `height` is not in its history.
+pub fn time_travel_misbehaviour(
+    ctx: &MockContext,
+    client_id: &ClientId,
+    height: &Height,
+    trusted_height: Height,
+) -> TmMisbehaviour {
+    let block = host_tm_block(ctx, height, trusted_height);
+    let time_travel_block = generate_tm_block(
+        &ctx.host_chain_id,
+        height.increment().revision_height(),
+        block.header().time.into(),
+        None,
+        trusted_height,
+    );
+
+    TmMisbehaviour::new(client_id.clone(), time_travel_block.into(), block.into())
+}
+
+/// Returns a misbehaviour made of the block of `ctx` at `height` and of
+/// another block at the same height with a later timestamp, i.e. the
+/// evidence of two blocks committed at the same height.
+///
+/// Panics if `ctx` is not a synthetic Tendermint chain or if the block at
+/// `height` is not in its history.
+pub fn duplicate_height_misbehaviour(
+    ctx: &MockContext,
+    client_id: &ClientId,
+    height: &Height,
+    trusted_height: Height,
+) -> TmMisbehaviour {
+    let block = host_tm_block(ctx, height, trusted_height);
+    let timestamp =
+        (Timestamp::from(block.header().time) + Duration::from_secs(1)).expect("Never fails");
+    let duplicate_block = generate_tm_block(
+        &ctx.host_chain_id,
+        height.revision_height(),
+        timestamp,
+        None,
+        trusted_height,
+    );
+
+    TmMisbehaviour::new(client_id.clone(), block.into(), duplicate_block.into())
+}
+
+/// Returns the block of `ctx` at `height`, trusting the block at `trusted_height`.
+fn host_tm_block(ctx: &MockContext, height: &Height, trusted_height: Height) -> SyntheticTmBlock {
+    let mut block = ctx
+        .host_block(height)
+        .expect("the block must be in the history of the chain")
+        .clone();
+    block.set_trusted_height(trusted_height);
+
+    block
+        .try_into_tm_block()
+        .expect("the chain must be a synthetic Tendermint chain")
+}
+
+/// Generates a block signed by the default validators of the synthetic
+/// Tendermint chains.
+fn generate_tm_block(
+    chain_id: &ChainId,
+    height: u64,
+    timestamp: Timestamp,
+    app_hash: Option<AppHash>,
+    trusted_height: Height,
+) -> SyntheticTmBlock {
+    let validators = default_tm_validators();
+
+    let mut header = TestgenHeader::new(&validators)
+        .height(height)
+        .chain_id(chain_id.as_str())
+        .next_validators(&validators)
+        .time(timestamp.into_tm_time().expect("Never fails"));
+
+    if let Some(app_hash) = app_hash {
+        header = header.app_hash(app_hash);
+    }
+
+    let light_block = TestgenLightBlock::new_default_with_header(header)
+        .generate()
+        .expect("Never fails");
+
+    SyntheticTmBlock {
+        trusted_height,
+        trusted_next_validators: light_block.next_validators.clone(),
+        light_block,
+    }
+}
+
+/// Returns a misbehaviour made of the blocks at `height` of the chain of `ctx`
+/// and of its fork `fork`, e.g. as returned by `MockContext::fork_at`.
+///
+/// Panics if the contexts are not synthetic Tendermint chains or if the
+/// blocks at `height` are not in their histories.
+pub fn fork_misbehaviour(
+    ctx: &MockContext,
+    fork: &MockContext,
+    client_id: &ClientId,
+    height: &Height,
+    trusted_height: Height,
+) -> TmMisbehaviour {
+    let block = host_tm_block(ctx, height, trusted_height);
+    let fork_block = host_tm_block(fork, height, trusted_height);
+
+    TmMisbehaviour::new(client_id.clone(), block.into(), fork_block.into())
+}
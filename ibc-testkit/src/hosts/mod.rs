@@ -1 +1,3 @@
 pub mod block;
+pub mod misbehaviour;
+pub mod validators;
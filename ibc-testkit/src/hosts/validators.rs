@@ -0,0 +1,134 @@
+//! Generators of validator sets and of partially signed blocks for synthetic
+//! Tendermint chains, in order to exercise the voting power thresholds of the
+//! Tendermint light client verification, e.g. commits signed by exactly 2/3
+//! of the voting power, or trusted validators holding exactly the trust
+//! threshold of the voting power of a new validator set.
+//!
+//! The keys of the generated validators are derived from their identifiers,
+//! so that validators with the same identifier in different sets share the
+//! same keys.
+
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use tendermint_testgen::{
+    Commit as TestgenCommit, Generator, Header as TestgenHeader, LightBlock as TestgenLightBlock,
+    Validator as TestgenValidator, Vote as TestgenVote,
+};
+
+use super::block::SyntheticTmBlock;
+
+/// Returns validators with the given voting powers, identified by
+/// `{prefix}-{index}`.
+pub fn validators_with_powers(prefix: &str, powers: &[u64]) -> Vec<TestgenValidator> {
+    powers
+        .iter()
+        .enumerate()
+        .map(|(i, power)| TestgenValidator::new(&format!("{prefix}-{i}")).voting_power(*power))
+        .collect()
+}
+
+/// Returns `count` validators with the same voting `power`, identified by
+/// `{prefix}-{index}`.
+pub fn equal_power_validators(prefix: &str, count: usize, power: u64) -> Vec<TestgenValidator> {
+    validators_with_powers(prefix, &vec![power; count])
+}
+
+/// Returns a validator set with the same voting powers as `validators`, which
+/// shares the keys of its first `overlap` validators, and whose other
+/// validators are new ones identified by `{prefix}-{index}`.
+pub fn overlapping_validators(
+    validators: &[TestgenValidator],
+    overlap: usize,
+    prefix: &str,
+) -> Vec<TestgenValidator> {
+    validators
+        .iter()
+        .enumerate()
+        .map(|(i, validator)| {
+            if i < overlap {
+                validator.clone()
+            } else {
+                TestgenValidator::new(&format!("{prefix}-{i}"))
+                    .voting_power(validator.voting_power.unwrap_or_default())
+            }
+        })
+        .collect()
+}
+
+/// Returns the total voting power of the given validators.
+pub fn total_voting_power(validators: &[TestgenValidator]) -> u64 {
+    validators
+        .iter()
+        .map(|validator| validator.voting_power.unwrap_or_default())
+        .sum()
+}
+
+/// Returns the longest prefix of `validators` whose total voting power does
+/// not exceed `max_power`, e.g. the signers of a commit which misses a voting
+/// power threshold by the smallest margin.
+pub fn signers_up_to_power(
+    validators: &[TestgenValidator],
+    max_power: u64,
+) -> Vec<TestgenValidator> {
+    let mut power = 0;
+
+    validators
+        .iter()
+        .take_while(|validator| {
+            power += validator.voting_power.unwrap_or_default();
+            power <= max_power
+        })
+        .cloned()
+        .collect()
+}
+
+/// Generates a block at `height` for the given chain identifier, whose commit
+/// only contains the signatures of `signers`, the other validators being
+/// absent.
+///
+/// The signers need not be part of `validators`, e.g. to build commits with
+/// signatures of unknown validators. As for the other generated blocks, the
+/// trusted next validators are set to the next validators of the block, and
+/// must be overridden when updating a client from a non-adjacent height.
+pub fn generate_partially_signed_block(
+    chain_id: &ChainId,
+    height: u64,
+    timestamp: Timestamp,
+    validators: &[TestgenValidator],
+    next_validators: &[TestgenValidator],
+    signers: &[TestgenValidator],
+    trusted_height: Height,
+) -> SyntheticTmBlock {
+    let header = TestgenHeader::new(validators)
+        .height(height)
+        .chain_id(chain_id.as_str())
+        .next_validators(next_validators)
+        .time(timestamp.into_tm_time().expect("Never fails"));
+
+    let votes = signers
+        .iter()
+        .enumerate()
+        .map(|(i, signer)| {
+            TestgenVote::new(signer.clone(), header.clone())
+                .index(i as u16)
+                .round(1)
+        })
+        .collect();
+
+    let light_block = TestgenLightBlock::new(
+        header.clone(),
+        TestgenCommit::new_with_votes(header, 1, votes),
+    )
+    .validators(validators)
+    .next_validators(next_validators)
+    .generate()
+    .expect("Never fails");
+
+    SyntheticTmBlock {
+        trusted_height,
+        trusted_next_validators: light_block.next_validators.clone(),
+        light_block,
+    }
+}
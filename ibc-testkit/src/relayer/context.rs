@@ -45,7 +45,6 @@ impl RelayerContext for MockContext {
 mod tests {
     use ibc::clients::tendermint::types::client_type as tm_client_type;
     use ibc::core::client::context::client_state::ClientStateCommon;
-    use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
     use ibc::core::client::types::Height;
     use ibc::core::handler::types::msgs::MsgEnvelope;
     use ibc::core::host::types::identifiers::ChainId;
@@ -54,57 +53,12 @@ mod tests {
 
     use super::RelayerContext;
     use crate::fixtures::core::context::MockContextConfig;
-    use crate::hosts::block::{HostBlock, HostType};
-    use crate::relayer::context::ClientId;
-    use crate::relayer::error::RelayerError;
+    use crate::hosts::block::HostType;
+    use crate::relayer::utils::build_client_update_datagram;
     use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
     use crate::testapp::ibc::core::router::MockRouter;
     use crate::testapp::ibc::core::types::MockClientConfig;
 
-    /// Builds a `ClientMsg::UpdateClient` for a client with id `client_id` running on the `dest`
-    /// context, assuming that the latest header on the source context is `src_header`.
-    pub(crate) fn build_client_update_datagram<Ctx>(
-        dest: &Ctx,
-        client_id: &ClientId,
-        src_header: &HostBlock,
-    ) -> Result<ClientMsg, RelayerError>
-    where
-        Ctx: RelayerContext,
-    {
-        // Check if client for ibc0 on ibc1 has been updated to latest height:
-        // - query client state on destination chain
-        let dest_client_state = dest.query_client_full_state(client_id).ok_or_else(|| {
-            RelayerError::ClientStateNotFound {
-                client_id: client_id.clone(),
-            }
-        })?;
-
-        let dest_client_latest_height = dest_client_state.latest_height();
-
-        if src_header.height() == dest_client_latest_height {
-            return Err(RelayerError::ClientAlreadyUpToDate {
-                client_id: client_id.clone(),
-                source_height: src_header.height(),
-                destination_height: dest_client_latest_height,
-            });
-        };
-
-        if dest_client_latest_height > src_header.height() {
-            return Err(RelayerError::ClientAtHigherHeight {
-                client_id: client_id.clone(),
-                source_height: src_header.height(),
-                destination_height: dest_client_latest_height,
-            });
-        };
-
-        // Client on destination chain can be updated.
-        Ok(ClientMsg::UpdateClient(MsgUpdateClient {
-            client_id: client_id.clone(),
-            client_message: (*src_header).clone().into(),
-            signer: dest.signer(),
-        }))
-    }
-
     #[test]
     /// Serves to test both ICS-26 `dispatch` & `build_client_update_datagram` functions.
     /// Implements a "ping pong" of client update messages, so that two chains repeatedly
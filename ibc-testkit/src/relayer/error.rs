@@ -2,7 +2,7 @@ use displaydoc::Display;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::identifiers::{ClientId, Sequence};
 
 #[derive(Debug, Display)]
 pub enum RelayerError {
@@ -20,6 +20,8 @@ pub enum RelayerError {
         source_height: Height,
         destination_height: Height,
     },
+    /// no acknowledgement was written for the packet with sequence `{sequence}`
+    MissingAcknowledgement { sequence: Sequence },
     /// transaction processing by modules failed error: `{0}`
     TransactionFailed(ContextError),
     /// connection error: `{0}`
@@ -0,0 +1,523 @@
+use core::time::Duration;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::State as ChannelState;
+use ibc::core::channel::types::events::SendPacket;
+use ibc::core::channel::types::msgs::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, PacketMsg,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::event_filter::IbcEventFilter;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ChannelEndPath, SeqRecvPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::router::router::Router;
+
+use super::context::RelayerContext;
+use super::error::RelayerError;
+use crate::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use crate::hosts::block::HostBlock;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// Builds a `ClientMsg::UpdateClient` for a client with id `client_id` running on the `dest`
+/// context, assuming that the latest header on the source context is `src_header`.
+pub fn build_client_update_datagram<Ctx>(
+    dest: &Ctx,
+    client_id: &ClientId,
+    src_header: &HostBlock,
+) -> Result<ClientMsg, RelayerError>
+where
+    Ctx: RelayerContext,
+{
+    // Check if client for ibc0 on ibc1 has been updated to latest height:
+    // - query client state on destination chain
+    let dest_client_state = dest.query_client_full_state(client_id).ok_or_else(|| {
+        RelayerError::ClientStateNotFound {
+            client_id: client_id.clone(),
+        }
+    })?;
+
+    let dest_client_latest_height = dest_client_state.latest_height();
+
+    if src_header.height() == dest_client_latest_height {
+        return Err(RelayerError::ClientAlreadyUpToDate {
+            client_id: client_id.clone(),
+            source_height: src_header.height(),
+            destination_height: dest_client_latest_height,
+        });
+    };
+
+    if dest_client_latest_height > src_header.height() {
+        return Err(RelayerError::ClientAtHigherHeight {
+            client_id: client_id.clone(),
+            source_height: src_header.height(),
+            destination_height: dest_client_latest_height,
+        });
+    };
+
+    // Client on destination chain can be updated.
+    Ok(ClientMsg::UpdateClient(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: (*src_header).clone().into(),
+        signer: dest.signer(),
+    }))
+}
+
+/// Updates the client with id `client_id` on the `dest` context to the latest
+/// height of the `src` context, unless it is already up-to-date. Returns the
+/// height of the client, which proofs from `src` can be verified at.
+///
+/// The `dest` chain is first advanced until its clock reaches the timestamp of
+/// the header, as a relayer would wait for before submitting it.
+pub fn update_client_to_latest(
+    dest: &mut MockContext,
+    dest_router: &mut impl Router,
+    client_id: &ClientId,
+    src: &MockContext,
+) -> Result<Height, RelayerError> {
+    let mut src_header = src
+        .query_latest_header()
+        .expect("the latest block is always in the history");
+
+    let dest_client_latest_height = dest
+        .query_client_full_state(client_id)
+        .ok_or_else(|| RelayerError::ClientStateNotFound {
+            client_id: client_id.clone(),
+        })?
+        .latest_height();
+
+    if dest_client_latest_height == src_header.height() {
+        return Ok(dest_client_latest_height);
+    }
+
+    while dest.host_timestamp().expect("Never fails") < src_header.timestamp() {
+        dest.advance_host_chain_height();
+    }
+
+    src_header.set_trusted_height(dest_client_latest_height);
+
+    let client_msg = build_client_update_datagram(dest, client_id, &src_header)?;
+    dest.deliver(dest_router, MsgEnvelope::Client(client_msg))?;
+
+    Ok(src_header.height())
+}
+
+/// The delay of a relayer between the send of a packet on chain A and its
+/// delivery to chain B, during which chain B keeps producing blocks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RelayDelay {
+    /// The number of blocks produced by chain B.
+    pub blocks: u64,
+    /// The minimum time elapsed on chain B, after the blocks above were produced.
+    pub time: Duration,
+}
+
+/// The outcome of relaying a packet with `relay_packet_with_delay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RelayOutcome {
+    /// The packet was received on chain B, and its acknowledgement was relayed
+    /// back to chain A.
+    Acknowledged(Acknowledgement),
+    /// The packet timed out on chain B, and the timeout was relayed to chain A.
+    TimedOut,
+    /// The channel was closed on chain B, and the timeout was relayed to
+    /// chain A.
+    TimedOutOnClose,
+}
+
+/// Relays the packet of the given `SendPacket` event from chain A to chain B,
+/// and its acknowledgement back to chain A, updating the client of the
+/// counterparty chain before delivering each message. Returns the
+/// acknowledgement written on chain B.
+///
+/// The mock clients accept any proof, so that no actual proof is built.
+pub fn relay_packet_to_completion(
+    ctx_a: &mut MockContext,
+    router_a: &mut impl Router,
+    client_on_a_for_b: &ClientId,
+    ctx_b: &mut MockContext,
+    router_b: &mut impl Router,
+    client_on_b_for_a: &ClientId,
+    send_packet_event: &SendPacket,
+) -> Result<Acknowledgement, RelayerError> {
+    let packet = packet_from_event(send_packet_event);
+
+    recv_and_acknowledge(
+        ctx_a,
+        router_a,
+        client_on_a_for_b,
+        ctx_b,
+        router_b,
+        client_on_b_for_a,
+        packet,
+    )
+}
+
+/// Same as `relay_packet_to_completion`, except that the packet is delivered
+/// to chain B only after the given `delay`. If the packet has timed out on
+/// chain B by then, or if its channel was closed on chain B, the timeout is
+/// relayed back to chain A instead.
+#[allow(clippy::too_many_arguments)]
+pub fn relay_packet_with_delay(
+    ctx_a: &mut MockContext,
+    router_a: &mut impl Router,
+    client_on_a_for_b: &ClientId,
+    ctx_b: &mut MockContext,
+    router_b: &mut impl Router,
+    client_on_b_for_a: &ClientId,
+    send_packet_event: &SendPacket,
+    delay: RelayDelay,
+) -> Result<RelayOutcome, RelayerError> {
+    let packet = packet_from_event(send_packet_event);
+
+    for _ in 0..delay.blocks {
+        ctx_b.advance_host_chain_height();
+    }
+
+    let deadline =
+        (ctx_b.host_timestamp().expect("Never fails") + delay.time).expect("Never fails");
+    while ctx_b.host_timestamp().expect("Never fails") < deadline {
+        ctx_b.advance_host_chain_height();
+    }
+
+    let chan_end_on_b = ctx_b
+        .channel_end(&ChannelEndPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ))
+        .map_err(RelayerError::TransactionFailed)?;
+
+    if chan_end_on_b.state() == &ChannelState::Closed {
+        timeout_packet(ctx_a, router_a, client_on_a_for_b, ctx_b, packet, true)?;
+
+        return Ok(RelayOutcome::TimedOutOnClose);
+    }
+
+    // Update the client on B first, since the update produces the block on
+    // which the packet would be received.
+    update_client_to_latest(ctx_b, router_b, client_on_b_for_a, ctx_a)?;
+
+    let host_timestamp_on_b = ctx_b.host_timestamp().expect("Never fails");
+    if packet.timed_out(&host_timestamp_on_b, ctx_b.latest_height()) {
+        timeout_packet(ctx_a, router_a, client_on_a_for_b, ctx_b, packet, false)?;
+
+        return Ok(RelayOutcome::TimedOut);
+    }
+
+    recv_and_acknowledge(
+        ctx_a,
+        router_a,
+        client_on_a_for_b,
+        ctx_b,
+        router_b,
+        client_on_b_for_a,
+        packet,
+    )
+    .map(RelayOutcome::Acknowledged)
+}
+
+fn packet_from_event(send_packet_event: &SendPacket) -> Packet {
+    Packet {
+        seq_on_a: *send_packet_event.seq_on_a(),
+        port_id_on_a: send_packet_event.port_id_on_a().clone(),
+        chan_id_on_a: send_packet_event.chan_id_on_a().clone(),
+        port_id_on_b: send_packet_event.port_id_on_b().clone(),
+        chan_id_on_b: send_packet_event.chan_id_on_b().clone(),
+        data: send_packet_event.packet_data().to_vec().into(),
+        timeout_height_on_b: *send_packet_event.timeout_height_on_b(),
+        timeout_timestamp_on_b: *send_packet_event.timeout_timestamp_on_b(),
+    }
+}
+
+fn recv_and_acknowledge(
+    ctx_a: &mut MockContext,
+    router_a: &mut impl Router,
+    client_on_a_for_b: &ClientId,
+    ctx_b: &mut MockContext,
+    router_b: &mut impl Router,
+    client_on_b_for_a: &ClientId,
+    packet: Packet,
+) -> Result<Acknowledgement, RelayerError> {
+    // Receive the packet on B, which writes the acknowledgement.
+    let proof_height_on_a = update_client_to_latest(ctx_b, router_b, client_on_b_for_a, ctx_a)?;
+
+    let msg_recv_packet = MsgRecvPacket {
+        packet: packet.clone(),
+        proof_commitment_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a,
+        signer: ctx_b.signer(),
+    };
+    ctx_b.deliver(
+        router_b,
+        MsgEnvelope::Packet(PacketMsg::Recv(msg_recv_packet)),
+    )?;
+
+    let acknowledgement = IbcEventFilter::new()
+        .event_type("write_acknowledgement")
+        .channel(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone())
+        .sequence_range(packet.seq_on_a, packet.seq_on_a)
+        .filter(&ctx_b.get_events())
+        .last()
+        .and_then(|event| match event {
+            IbcEvent::WriteAcknowledgement(event) => Some(event.acknowledgement().clone()),
+            _ => None,
+        })
+        .ok_or(RelayerError::MissingAcknowledgement {
+            sequence: packet.seq_on_a,
+        })?;
+
+    // Relay the acknowledgement back to A.
+    let proof_height_on_b = update_client_to_latest(ctx_a, router_a, client_on_a_for_b, ctx_b)?;
+
+    let msg_ack_packet = MsgAcknowledgement {
+        packet,
+        acknowledgement: acknowledgement.clone(),
+        proof_acked_on_b: dummy_commitment_proof_bytes(),
+        proof_height_on_b,
+        signer: ctx_a.signer(),
+    };
+    ctx_a.deliver(
+        router_a,
+        MsgEnvelope::Packet(PacketMsg::Ack(msg_ack_packet)),
+    )?;
+
+    Ok(acknowledgement)
+}
+
+/// Relays the timeout of the packet, which was not received on B, back to A.
+fn timeout_packet(
+    ctx_a: &mut MockContext,
+    router_a: &mut impl Router,
+    client_on_a_for_b: &ClientId,
+    ctx_b: &MockContext,
+    packet: Packet,
+    on_close: bool,
+) -> Result<(), RelayerError> {
+    let proof_height_on_b = update_client_to_latest(ctx_a, router_a, client_on_a_for_b, ctx_b)?;
+
+    let next_seq_recv_on_b = ctx_b
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ))
+        .unwrap_or(packet.seq_on_a);
+
+    let packet_msg = if on_close {
+        PacketMsg::TimeoutOnClose(MsgTimeoutOnClose {
+            packet,
+            next_seq_recv_on_b,
+            proof_unreceived_on_b: dummy_commitment_proof_bytes(),
+            proof_close_on_b: dummy_commitment_proof_bytes(),
+            proof_height_on_b,
+            signer: ctx_a.signer(),
+        })
+    } else {
+        PacketMsg::Timeout(MsgTimeout {
+            packet,
+            next_seq_recv_on_b,
+            proof_unreceived_on_b: dummy_commitment_proof_bytes(),
+            proof_height_on_b,
+            signer: ctx_a.signer(),
+        })
+    };
+    ctx_a.deliver(router_a, MsgEnvelope::Packet(packet_msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::handler::send_packet;
+    use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+    use ibc::core::channel::types::timeout::TimeoutHeight;
+    use ibc::core::channel::types::Version;
+    use ibc::core::connection::types::version::get_compatible_versions;
+    use ibc::core::connection::types::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc::core::host::types::identifiers::{ChainId, ChannelId, ConnectionId, PortId};
+    use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fixtures::core::context::MockContextConfig;
+    use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+    use crate::testapp::ibc::core::router::MockRouter;
+    use crate::testapp::ibc::core::types::MockClientConfig;
+
+    /// Builds a chain with a transfer channel in the given state to the
+    /// counterparty chain with the given identifier.
+    fn chain_with_channel(
+        chain_id: &str,
+        counterparty_chain_id: &str,
+        timestamp: Timestamp,
+        chan_state: State,
+    ) -> MockContext {
+        let client_id = mock_client_type().build_client_id(0);
+        let conn_id = ConnectionId::new(0);
+        let chan_id = ChannelId::new(0);
+
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                client_id.clone(),
+                Some(conn_id.clone()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .unwrap();
+
+        let chan_end = ChannelEnd::new(
+            chan_state,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(chan_id.clone())),
+            vec![conn_id.clone()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+        MockContextConfig::builder()
+            .host_id(ChainId::new(chain_id).unwrap())
+            .latest_height(Height::new(1, 5).unwrap())
+            .latest_timestamp(timestamp)
+            .build()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_chain_id(ChainId::new(counterparty_chain_id).unwrap())
+                    .client_id(client_id)
+                    .latest_height(Height::new(1, 2).unwrap())
+                    .latest_timestamp(timestamp)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(PortId::transfer(), chan_id.clone(), chan_end)
+            .with_send_sequence(PortId::transfer(), chan_id, 1.into())
+    }
+
+    /// Sends a packet on chain A and returns its `SendPacket` event.
+    fn send_packet_on_a(
+        ctx_a: &mut MockContext,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+    ) -> SendPacket {
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(0),
+            data: b"packet data".to_vec().into(),
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        };
+        send_packet(ctx_a, packet).unwrap();
+
+        ctx_a
+            .get_events()
+            .into_iter()
+            .find_map(|event| match event {
+                IbcEvent::SendPacket(event) => Some(event),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn relay_packet() {
+        let client_id = mock_client_type().build_client_id(0);
+        let timestamp = Timestamp::now();
+
+        let mut ctx_a = chain_with_channel("mockgaiaA-1", "mockgaiaB-1", timestamp, State::Open);
+        let mut router_a = MockRouter::new_with_transfer();
+        let mut ctx_b = chain_with_channel("mockgaiaB-1", "mockgaiaA-1", timestamp, State::Open);
+        let mut router_b = MockRouter::new_with_transfer();
+
+        let send_packet_event =
+            send_packet_on_a(&mut ctx_a, TimeoutHeight::Never, Timestamp::none());
+
+        let acknowledgement = relay_packet_to_completion(
+            &mut ctx_a,
+            &mut router_a,
+            &client_id,
+            &mut ctx_b,
+            &mut router_b,
+            &client_id,
+            &send_packet_event,
+        )
+        .unwrap();
+
+        assert_eq!(acknowledgement.as_bytes(), [1]);
+        assert!(ctx_a
+            .get_events()
+            .iter()
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+    }
+
+    #[rstest]
+    #[case::no_timeout(TimeoutHeight::Never, None, State::Open, RelayDelay { blocks: 10, time: Duration::from_secs(60) }, false)]
+    #[case::timeout_height(TimeoutHeight::At(Height::new(1, 8).unwrap()), None, State::Open, RelayDelay { blocks: 3, time: ZERO_DURATION }, true)]
+    #[case::timeout_timestamp(TimeoutHeight::Never, Some(Duration::from_secs(10)), State::Open, RelayDelay { blocks: 0, time: Duration::from_secs(30) }, true)]
+    #[case::timeout_on_close(
+        TimeoutHeight::Never,
+        None,
+        State::Closed,
+        RelayDelay::default(),
+        true
+    )]
+    fn relay_packet_delayed(
+        #[case] timeout_height_on_b: TimeoutHeight,
+        #[case] timeout_after: Option<Duration>,
+        #[case] chan_state_on_b: State,
+        #[case] delay: RelayDelay,
+        #[case] expect_timeout: bool,
+    ) {
+        let client_id = mock_client_type().build_client_id(0);
+        let timestamp = Timestamp::now();
+
+        let mut ctx_a = chain_with_channel("mockgaiaA-1", "mockgaiaB-1", timestamp, State::Open);
+        let mut router_a = MockRouter::new_with_transfer();
+        let mut ctx_b =
+            chain_with_channel("mockgaiaB-1", "mockgaiaA-1", timestamp, chan_state_on_b);
+        let mut router_b = MockRouter::new_with_transfer();
+
+        let timeout_timestamp_on_b = timeout_after
+            .map(|timeout_after| (timestamp + timeout_after).unwrap())
+            .unwrap_or_else(Timestamp::none);
+        let send_packet_event =
+            send_packet_on_a(&mut ctx_a, timeout_height_on_b, timeout_timestamp_on_b);
+
+        let outcome = relay_packet_with_delay(
+            &mut ctx_a,
+            &mut router_a,
+            &client_id,
+            &mut ctx_b,
+            &mut router_b,
+            &client_id,
+            &send_packet_event,
+            delay,
+        )
+        .unwrap();
+
+        let expected_outcome = match (expect_timeout, chan_state_on_b) {
+            (false, _) => RelayOutcome::Acknowledged(vec![1u8].try_into().unwrap()),
+            (true, State::Closed) => RelayOutcome::TimedOutOnClose,
+            (true, _) => RelayOutcome::TimedOut,
+        };
+        assert_eq!(outcome, expected_outcome);
+
+        assert_eq!(
+            ctx_a
+                .get_events()
+                .iter()
+                .any(|event| matches!(event, IbcEvent::TimeoutPacket(_))),
+            expect_timeout
+        );
+    }
+}
@@ -6,13 +6,12 @@ use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
-use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleExtras;
 
 use super::types::DummyNftTransferModule;
 
-impl Module for DummyNftTransferModule {
-    fn on_chan_open_init_validate(
+impl DummyNftTransferModule {
+    pub fn on_chan_open_init_validate(
         &self,
         _order: Order,
         _connection_hops: &[ConnectionId],
@@ -24,7 +23,7 @@ impl Module for DummyNftTransferModule {
         Ok(version.clone())
     }
 
-    fn on_chan_open_init_execute(
+    pub fn on_chan_open_init_execute(
         &mut self,
         _order: Order,
         _connection_hops: &[ConnectionId],
@@ -36,7 +35,7 @@ impl Module for DummyNftTransferModule {
         Ok((ModuleExtras::empty(), version.clone()))
     }
 
-    fn on_chan_open_try_validate(
+    pub fn on_chan_open_try_validate(
         &self,
         _order: Order,
         _connection_hops: &[ConnectionId],
@@ -48,7 +47,7 @@ impl Module for DummyNftTransferModule {
         Ok(counterparty_version.clone())
     }
 
-    fn on_chan_open_try_execute(
+    pub fn on_chan_open_try_execute(
         &mut self,
         _order: Order,
         _connection_hops: &[ConnectionId],
@@ -60,7 +59,7 @@ impl Module for DummyNftTransferModule {
         Ok((ModuleExtras::empty(), counterparty_version.clone()))
     }
 
-    fn on_recv_packet_execute(
+    pub fn on_recv_packet_execute(
         &mut self,
         _packet: &Packet,
         _relayer: &Signer,
@@ -71,7 +70,7 @@ impl Module for DummyNftTransferModule {
         )
     }
 
-    fn on_timeout_packet_validate(
+    pub fn on_timeout_packet_validate(
         &self,
         _packet: &Packet,
         _relayer: &Signer,
@@ -79,7 +78,7 @@ impl Module for DummyNftTransferModule {
         Ok(())
     }
 
-    fn on_timeout_packet_execute(
+    pub fn on_timeout_packet_execute(
         &mut self,
         _packet: &Packet,
         _relayer: &Signer,
@@ -87,7 +86,7 @@ impl Module for DummyNftTransferModule {
         (ModuleExtras::empty(), Ok(()))
     }
 
-    fn on_acknowledgement_packet_validate(
+    pub fn on_acknowledgement_packet_validate(
         &self,
         _packet: &Packet,
         _acknowledgement: &Acknowledgement,
@@ -96,7 +95,7 @@ impl Module for DummyNftTransferModule {
         Ok(())
     }
 
-    fn on_acknowledgement_packet_execute(
+    pub fn on_acknowledgement_packet_execute(
         &mut self,
         _packet: &Packet,
         _acknowledgement: &Acknowledgement,
@@ -1,6 +1,7 @@
 use ibc::apps::nft_transfer::types::{ClassData, ClassId, ClassUri, TokenData, TokenId, TokenUri};
+use ibc::derive::Module;
 
-#[derive(Debug)]
+#[derive(Debug, Module)]
 pub struct DummyNftTransferModule;
 
 #[derive(Debug)]
@@ -102,10 +102,12 @@ impl TryFrom<Any> for MockClientState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_client_state(value: &[u8]) -> Result<MockClientState, ClientError> {
-            let client_state =
-                Protobuf::<RawMockClientState>::decode(value).map_err(|e| ClientError::Other {
+            let client_state = Protobuf::<RawMockClientState>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
                     description: e.to_string(),
-                })?;
+                }
+            })?;
             Ok(client_state)
         }
         match raw.type_url.as_str() {
@@ -283,8 +285,9 @@ where
         let now = ctx.host_timestamp()?;
         let elapsed_since_latest_consensus_state = now
             .duration_since(&latest_consensus_state.timestamp())
-            .ok_or(ClientError::Other {
-                description: format!("latest consensus state is in the future. now: {now}, latest consensus state: {}", latest_consensus_state.timestamp()),
+            .ok_or(ClientError::InvalidConsensusStateTimestamp {
+                time1: latest_consensus_state.timestamp(),
+                time2: now,
             })?;
 
         if self.expired(elapsed_since_latest_consensus_state) {
@@ -298,8 +301,13 @@ where
 impl<E> ClientStateExecution<E> for MockClientState
 where
     E: ClientExecutionContext + MockClientContext,
-    <E as ClientExecutionContext>::AnyClientState: From<MockClientState>,
-    <E as ClientExecutionContext>::AnyConsensusState: From<MockConsensusState>,
+    <E as ClientExecutionContext>::AnyClientState: TryFrom<MockClientState>,
+    ClientError:
+        From<<<E as ClientExecutionContext>::AnyClientState as TryFrom<MockClientState>>::Error>,
+    <E as ClientExecutionContext>::AnyConsensusState: TryFrom<MockConsensusState>,
+    ClientError: From<
+        <<E as ClientExecutionContext>::AnyConsensusState as TryFrom<MockConsensusState>>::Error,
+    >,
 {
     fn initialise(
         &self,
@@ -309,14 +317,14 @@ where
     ) -> Result<(), ClientError> {
         let mock_consensus_state = MockConsensusState::try_from(consensus_state)?;
 
-        ctx.store_client_state(ClientStatePath::new(client_id.clone()), (*self).into())?;
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), (*self).try_into()?)?;
         ctx.store_consensus_state(
             ClientConsensusStatePath::new(
                 client_id.clone(),
                 self.latest_height().revision_number(),
                 self.latest_height().revision_height(),
             ),
-            mock_consensus_state.into(),
+            mock_consensus_state.try_into()?,
         )?;
 
         Ok(())
@@ -340,11 +348,11 @@ where
                 new_client_state.latest_height().revision_number(),
                 new_client_state.latest_height().revision_height(),
             ),
-            new_consensus_state.into(),
+            new_consensus_state.try_into()?,
         )?;
         ctx.store_client_state(
             ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
+            new_client_state.try_into()?,
         )?;
         ctx.store_update_meta(
             client_id.clone(),
@@ -366,7 +374,7 @@ where
 
         ctx.store_client_state(
             ClientStatePath::new(client_id.clone()),
-            frozen_client_state.into(),
+            frozen_client_state.try_into()?,
         )?;
 
         Ok(())
@@ -390,11 +398,11 @@ where
                 latest_height.revision_number(),
                 latest_height.revision_height(),
             ),
-            new_consensus_state.into(),
+            new_consensus_state.try_into()?,
         )?;
         ctx.store_client_state(
             ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
+            new_client_state.try_into()?,
         )?;
 
         let host_timestamp = ctx.host_timestamp()?;
@@ -66,7 +66,8 @@ impl TryFrom<Any> for MockConsensusState {
         fn decode_consensus_state(value: &[u8]) -> Result<MockConsensusState, ClientError> {
             let mock_consensus_state =
                 Protobuf::<RawMockConsensusState>::decode(value).map_err(|e| {
-                    ClientError::Other {
+                    ClientError::ProtoDecodingFailed {
+                        type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
                         description: e.to_string(),
                     }
                 })?;
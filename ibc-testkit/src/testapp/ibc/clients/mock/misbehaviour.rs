@@ -53,10 +53,12 @@ impl TryFrom<Any> for Misbehaviour {
 
     fn try_from(raw: Any) -> Result<Self, ClientError> {
         fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
-            let raw_misbehaviour =
-                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+            let raw_misbehaviour = Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::ProtoDecodingFailed {
+                    type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
                     description: e.to_string(),
-                })?;
+                }
+            })?;
             Ok(raw_misbehaviour)
         }
         match raw.type_url.as_str() {
@@ -1,8 +1,15 @@
 pub mod mock;
 
 use derive_more::{From, TryInto};
+use ibc::clients::rollup::client_state::ClientState as RollupClientState;
+use ibc::clients::rollup::consensus_state::ConsensusState as RollupConsensusState;
+use ibc::clients::rollup::types::{
+    ClientState as RollupClientStateType, ConsensusState as RollupConsensusStateType,
+    ROLLUP_CLIENT_STATE_TYPE_URL, ROLLUP_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::context::DefaultVerifier;
 use ibc::clients::tendermint::types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType,
     TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
@@ -24,7 +31,9 @@ use crate::testapp::ibc::core::types::MockContext;
 #[validation(MockContext)]
 #[execution(MockContext)]
 pub enum AnyClientState {
+    #[verifier(DefaultVerifier)]
     Tendermint(TmClientState),
+    Rollup(RollupClientState),
     Mock(MockClientState),
 }
 
@@ -36,11 +45,13 @@ impl TryFrom<Any> for AnyClientState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL {
             Ok(TmClientState::try_from(raw)?.into())
+        } else if raw.type_url == ROLLUP_CLIENT_STATE_TYPE_URL {
+            Ok(RollupClientState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
             MockClientState::try_from(raw).map(Into::into)
         } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
+            Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
             })
         }
     }
@@ -50,6 +61,7 @@ impl From<AnyClientState> for Any {
     fn from(host_client_state: AnyClientState) -> Self {
         match host_client_state {
             AnyClientState::Tendermint(cs) => cs.into(),
+            AnyClientState::Rollup(cs) => cs.into(),
             AnyClientState::Mock(cs) => cs.into(),
         }
     }
@@ -67,9 +79,22 @@ impl From<ConsensusStateType> for AnyConsensusState {
     }
 }
 
+impl From<RollupClientStateType> for AnyClientState {
+    fn from(client_state: RollupClientStateType) -> Self {
+        AnyClientState::Rollup(client_state.into())
+    }
+}
+
+impl From<RollupConsensusStateType> for AnyConsensusState {
+    fn from(consensus_state: RollupConsensusStateType) -> Self {
+        AnyConsensusState::Rollup(consensus_state.into())
+    }
+}
+
 #[derive(Debug, Clone, From, TryInto, PartialEq, ConsensusState)]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Rollup(RollupConsensusState),
     Mock(MockConsensusState),
 }
 
@@ -81,11 +106,13 @@ impl TryFrom<Any> for AnyConsensusState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
             Ok(TmConsensusState::try_from(raw)?.into())
+        } else if raw.type_url == ROLLUP_CONSENSUS_STATE_TYPE_URL {
+            Ok(RollupConsensusState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
             MockConsensusState::try_from(raw).map(Into::into)
         } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
+            Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
             })
         }
     }
@@ -95,6 +122,7 @@ impl From<AnyConsensusState> for Any {
     fn from(host_consensus_state: AnyConsensusState) -> Self {
         match host_consensus_state {
             AnyConsensusState::Tendermint(cs) => cs.into(),
+            AnyConsensusState::Rollup(cs) => cs.into(),
             AnyConsensusState::Mock(cs) => cs.into(),
         }
     }
@@ -1,12 +1,15 @@
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use core::time::Duration;
 
-use ibc::clients::tendermint::context::{
-    CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
-};
+use ibc::clients::rollup::context::{DaCommitment, ValidationContext as RollupValidationContext};
+use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
+use ibc::clients::tendermint::types::ClientState as TmClientStateType;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
@@ -50,7 +53,7 @@ impl MockClientContext for MockContext {
     }
 }
 
-impl TmCommonContext for MockContext {
+impl TmValidationContext for MockContext {
     type ConversionError = &'static str;
     type AnyConsensusState = AnyConsensusState;
 
@@ -83,9 +86,7 @@ impl TmCommonContext for MockContext {
 
         Ok(heights)
     }
-}
 
-impl TmValidationContext for MockContext {
     fn next_consensus_state(
         &self,
         client_id: &ClientId,
@@ -153,6 +154,88 @@ impl TmValidationContext for MockContext {
         }
         Ok(None)
     }
+
+    fn max_clock_drift(
+        &self,
+        client_id: &ClientId,
+        client_state: &TmClientStateType,
+    ) -> Result<Duration, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .max_clock_drift_overrides
+            .get(client_id)
+            .copied()
+            .unwrap_or(client_state.max_clock_drift))
+    }
+
+    fn provider_client_id(&self, client_id: &ClientId) -> Result<Option<ClientId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .provider_clients
+            .get(client_id)
+            .cloned())
+    }
+}
+
+impl RollupValidationContext for MockContext {
+    type ConversionError = &'static str;
+    type AnyConsensusState = AnyConsensusState;
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ValidationContext::host_height(self)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        ValidationContext::consensus_state(self, client_cons_state_path)
+    }
+
+    fn da_client_status(&self, da_client_id: &ClientId) -> Result<Status, ContextError> {
+        let da_client_state = ValidationContext::client_state(self, da_client_id)?;
+
+        Ok(da_client_state.status(self, da_client_id)?)
+    }
+
+    /// Returns the data root recorded for the DA block, along with the
+    /// timestamp of the consensus state of the DA client at its height.
+    fn da_commitment(
+        &self,
+        da_client_id: &ClientId,
+        da_height: &Height,
+    ) -> Result<DaCommitment, ContextError> {
+        let data_root = self
+            .ibc_store
+            .lock()
+            .da_data_roots
+            .get(&(da_client_id.clone(), *da_height))
+            .copied()
+            .ok_or_else(|| ClientError::ConsensusStateNotFound {
+                client_id: da_client_id.clone(),
+                height: *da_height,
+            })?;
+
+        let da_consensus_state = ValidationContext::consensus_state(
+            self,
+            &ClientConsensusStatePath::new(
+                da_client_id.clone(),
+                da_height.revision_number(),
+                da_height.revision_height(),
+            ),
+        )?;
+
+        Ok(DaCommitment {
+            data_root,
+            timestamp: da_consensus_state.timestamp(),
+        })
+    }
 }
 
 impl ClientValidationContext for MockContext {
@@ -263,6 +346,7 @@ impl ClientExecutionContext for MockContext {
         let mut ibc_store = self.ibc_store.lock();
         ibc_store.client_processed_times.remove(&key);
         ibc_store.client_processed_heights.remove(&key);
+        ibc_store.client_update_relayers.remove(&key);
         Ok(())
     }
 
@@ -1,28 +1,35 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
 use core::ops::Add;
-use core::time::Duration;
 
+use ibc::clients::rollup::client_state::ClientState as RollupClientState;
 use ibc::clients::tendermint::client_state::ClientState;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{ClientParams, Height};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::connection::types::error::ConnectionError;
-use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::connection::types::version::{
+    get_compatible_versions, Version as ConnectionVersion,
+};
+use ibc::core::connection::types::{ConnectionEnd, ConnectionParams};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    ClientConsensusStatePath, CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
-use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::host::types::validate::IdentifierPolicy;
+use ibc::core::host::{ExecutionContext, HostConsensusContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::primitives::{Signer, SignerValidationMode, Timestamp};
 use ibc::primitives::proto::Any;
 
 use super::types::MockContext;
@@ -56,6 +63,9 @@ impl ValidationContext for MockContext {
         if let Ok(client_state) = ClientState::try_from(client_state.clone()) {
             client_state.inner().validate().map_err(ClientError::from)?;
             Ok(client_state.into())
+        } else if let Ok(client_state) = RollupClientState::try_from(client_state.clone()) {
+            client_state.inner().validate().map_err(ClientError::from)?;
+            Ok(client_state.into())
         } else if let Ok(client_state) = MockClientState::try_from(client_state.clone()) {
             Ok(client_state.into())
         } else {
@@ -105,17 +115,35 @@ impl ValidationContext for MockContext {
             .expect("Never fails"))
     }
 
-    fn host_consensus_state(&self, height: &Height) -> Result<AnyConsensusState, ContextError> {
-        match self.host_block(height) {
-            Some(block_ref) => Ok(block_ref.clone().into()),
-            None => Err(ClientError::MissingLocalConsensusState { height: *height }),
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.ibc_store.lock().client_ids_counter)
+    }
+
+    fn allocate_client_id(
+        &self,
+        client_state: &Self::AnyClientState,
+    ) -> Result<ClientId, ContextError> {
+        let next_client_id = self.ibc_store.lock().next_client_id.clone();
+
+        match next_client_id {
+            Some(client_id) => Ok(client_id),
+            None => Ok(client_state
+                .client_type()
+                .build_client_id(self.client_counter()?)),
         }
-        .map_err(ConnectionError::Client)
-        .map_err(ContextError::ConnectionError)
     }
 
-    fn client_counter(&self) -> Result<u64, ContextError> {
-        Ok(self.ibc_store.lock().client_ids_counter)
+    fn client_update_relayer(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .client_update_relayers
+            .get(&(client_id.clone(), *height))
+            .cloned())
     }
 
     fn connection_end(&self, cid: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
@@ -128,53 +156,6 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ConnectionError)
     }
 
-    fn validate_self_client(
-        &self,
-        client_state_of_host_on_counterparty: Any,
-    ) -> Result<(), ContextError> {
-        let mock_client_state = MockClientState::try_from(client_state_of_host_on_counterparty)
-            .map_err(|_| ConnectionError::InvalidClientState {
-                reason: "client must be a mock client".to_string(),
-            })
-            .map_err(ContextError::ConnectionError)?;
-
-        if mock_client_state.is_frozen() {
-            return Err(ClientError::ClientFrozen {
-                description: String::new(),
-            }
-            .into());
-        }
-
-        let self_chain_id = &self.host_chain_id;
-        let self_revision_number = self_chain_id.revision_number();
-        if self_revision_number != mock_client_state.latest_height().revision_number() {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client is not in the same revision as the chain. expected: {}, got: {}",
-                        self_revision_number,
-                        mock_client_state.latest_height().revision_number()
-                    ),
-                },
-            ));
-        }
-
-        let host_current_height = self.latest_height().increment();
-        if mock_client_state.latest_height() >= host_current_height {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client has latest height {} greater than or equal to chain height {}",
-                        mock_client_state.latest_height(),
-                        host_current_height
-                    ),
-                },
-            ));
-        }
-
-        Ok(())
-    }
-
     fn commitment_prefix(&self) -> CommitmentPrefix {
         CommitmentPrefix::try_from(b"mock".to_vec()).expect("Never fails")
     }
@@ -183,6 +164,38 @@ impl ValidationContext for MockContext {
         Ok(self.ibc_store.lock().connection_ids_counter)
     }
 
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ibc_store
+            .lock()
+            .connection_versions
+            .clone()
+            .unwrap_or_else(get_compatible_versions)
+    }
+
+    fn filter_counterparty_versions(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<Vec<ConnectionVersion>, ContextError> {
+        let forbidden_features = &self.ibc_store.lock().forbidden_connection_features;
+
+        let versions = counterparty_candidate_versions
+            .iter()
+            .map(|version| {
+                ConnectionVersion::new(
+                    version.identifier(),
+                    version
+                        .features()
+                        .iter()
+                        .filter(|feature| !forbidden_features.contains(feature))
+                        .cloned()
+                        .collect(),
+                )
+            })
+            .collect::<Result<_, ConnectionError>>()?;
+
+        Ok(versions)
+    }
+
     fn channel_end(&self, chan_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
         let port_id = &chan_end_path.0;
         let channel_id = &chan_end_path.1;
@@ -203,6 +216,46 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::ChannelError)
     }
 
+    fn channel_upgrade(
+        &self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .channel_upgrades
+            .get(&channel_upgrade_path.0)
+            .and_then(|map| map.get(&channel_upgrade_path.1))
+            .cloned())
+    }
+
+    fn channel_upgrade_error(
+        &self,
+        channel_upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<Option<ErrorReceipt>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .get(&channel_upgrade_error_path.0)
+            .and_then(|map| map.get(&channel_upgrade_error_path.1))
+            .cloned())
+    }
+
+    fn channel_upgrade_sequence(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<u64, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .channel_upgrade_sequences
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .copied()
+            .unwrap_or_default())
+    }
+
     fn get_next_sequence_send(
         &self,
         seq_send_path: &SeqSendPath,
@@ -332,16 +385,60 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::PacketError)
     }
 
+    fn get_packet_send_timestamp(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<Option<Timestamp>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_send_timestamps
+            .get(&commitment_path.port_id)
+            .and_then(|map| map.get(&commitment_path.channel_id))
+            .and_then(|map| map.get(&commitment_path.sequence))
+            .copied())
+    }
+
+    fn get_packet_receipt_relayer(
+        &self,
+        receipt_path: &ReceiptPath,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_receipt_relayers
+            .get(&receipt_path.port_id)
+            .and_then(|map| map.get(&receipt_path.channel_id))
+            .and_then(|map| map.get(&receipt_path.sequence))
+            .cloned())
+    }
+
     fn channel_counter(&self) -> Result<u64, ContextError> {
         Ok(self.ibc_store.lock().channel_ids_counter)
     }
 
-    fn max_expected_time_per_block(&self) -> Duration {
-        self.block_time
+    fn identifier_policy(&self) -> &dyn IdentifierPolicy {
+        &self.identifier_policy
     }
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
-        Ok(())
+    fn signer_validation_mode(&self) -> SignerValidationMode {
+        self.ibc_store.lock().signer_validation_mode.clone()
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(self.ibc_store.lock().client_params.clone())
+    }
+
+    fn connection_params(&self) -> Result<ConnectionParams, ContextError> {
+        Ok(self.ibc_store.lock().connection_params.clone())
+    }
+
+    fn params_authority(&self) -> Option<Signer> {
+        self.ibc_store.lock().params_authority.clone()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ibc_store.lock().max_packet_data_size
     }
 
     fn get_client_validation_context(&self) -> &Self::V {
@@ -349,6 +446,68 @@ impl ValidationContext for MockContext {
     }
 }
 
+/// The mock host validates mock clients only, and its consensus states are
+/// derived from its blocks, whether mock or synthetic Tendermint ones.
+impl HostConsensusContext for MockContext {
+    type HostConsensusState = AnyConsensusState;
+
+    fn validate_host_client_state(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError> {
+        let mock_client_state = MockClientState::try_from(client_state_of_host_on_counterparty)
+            .map_err(|_| ConnectionError::InvalidClientState {
+                reason: "client must be a mock client".to_string(),
+            })
+            .map_err(ContextError::ConnectionError)?;
+
+        if mock_client_state.is_frozen() {
+            return Err(ClientError::ClientFrozen {
+                description: String::new(),
+            }
+            .into());
+        }
+
+        let self_chain_id = &self.host_chain_id;
+        let self_revision_number = self_chain_id.revision_number();
+        if self_revision_number != mock_client_state.latest_height().revision_number() {
+            return Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState {
+                    reason: format!(
+                        "client is not in the same revision as the chain. expected: {}, got: {}",
+                        self_revision_number,
+                        mock_client_state.latest_height().revision_number()
+                    ),
+                },
+            ));
+        }
+
+        let host_current_height = self.latest_height().increment();
+        if mock_client_state.latest_height() >= host_current_height {
+            return Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState {
+                    reason: format!(
+                        "client has latest height {} greater than or equal to chain height {}",
+                        mock_client_state.latest_height(),
+                        host_current_height
+                    ),
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn consensus_state_of_host(&self, height: &Height) -> Result<AnyConsensusState, ContextError> {
+        match self.host_block(height) {
+            Some(block_ref) => Ok(block_ref.clone().into()),
+            None => Err(ClientError::MissingLocalConsensusState { height: *height }),
+        }
+        .map_err(ConnectionError::Client)
+        .map_err(ContextError::ConnectionError)
+    }
+}
+
 impl ExecutionContext for MockContext {
     fn get_client_execution_context(&mut self) -> &mut Self::E {
         self
@@ -359,6 +518,19 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_client_update_relayer(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+        relayer: Signer,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .client_update_relayers
+            .insert((client_id.clone(), height), relayer);
+        Ok(())
+    }
+
     fn store_connection(
         &mut self,
         connection_path: &ConnectionPath,
@@ -419,6 +591,35 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_packet_send_timestamp(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        timestamp: Timestamp,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_send_timestamps
+            .entry(commitment_path.port_id.clone())
+            .or_default()
+            .entry(commitment_path.channel_id.clone())
+            .or_default()
+            .insert(commitment_path.sequence, timestamp);
+        Ok(())
+    }
+
+    fn delete_packet_send_timestamp(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_send_timestamps
+            .get_mut(&commitment_path.port_id)
+            .and_then(|map| map.get_mut(&commitment_path.channel_id))
+            .and_then(|map| map.remove(&commitment_path.sequence));
+        Ok(())
+    }
+
     fn store_packet_receipt(
         &mut self,
         path: &ReceiptPath,
@@ -435,6 +636,22 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_packet_receipt_relayer(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        relayer: Signer,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_receipt_relayers
+            .entry(receipt_path.port_id.clone())
+            .or_default()
+            .entry(receipt_path.channel_id.clone())
+            .or_default()
+            .insert(receipt_path.sequence, relayer);
+        Ok(())
+    }
+
     fn store_packet_acknowledgement(
         &mut self,
         ack_path: &AckPath,
@@ -486,6 +703,46 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn delete_channel_upgrade(
+        &mut self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .get_mut(&channel_upgrade_path.0)
+            .and_then(|map| map.remove(&channel_upgrade_path.1));
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        channel_upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .entry(channel_upgrade_error_path.0.clone())
+            .or_default()
+            .insert(channel_upgrade_error_path.1.clone(), error_receipt);
+        Ok(())
+    }
+
+    fn store_channel_upgrade_sequence(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        upgrade_sequence: u64,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrade_sequences
+            .entry(channel_end_path.0.clone())
+            .or_default()
+            .insert(channel_end_path.1.clone(), upgrade_sequence);
+        Ok(())
+    }
+
     fn store_next_sequence_send(
         &mut self,
         seq_send_path: &SeqSendPath,
@@ -551,4 +808,14 @@ impl ExecutionContext for MockContext {
         self.ibc_store.lock().logs.push(message);
         Ok(())
     }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ibc_store.lock().client_params = params;
+        Ok(())
+    }
+
+    fn store_connection_params(&mut self, params: ConnectionParams) -> Result<(), ContextError> {
+        self.ibc_store.lock().connection_params = params;
+        Ok(())
+    }
 }
@@ -0,0 +1,234 @@
+//! Implementation of the contexts of the `ibc-query` services for the mock
+//! context, used in testing the query services.
+
+use ibc::core::channel::types::channel::IdentifiedChannelEnd;
+use ibc::core::channel::types::packet::PacketState;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{CommitmentProof, ExistenceProof};
+use ibc::core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc::core::connection::types::IdentifiedConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, Path};
+use ibc::core::primitives::prelude::*;
+use ibc_query::core::context::{ProvableContext, QueryContext};
+
+use super::types::MockContext;
+use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+
+/// The mock host keeps no commitment tree, so its proofs are Merkle proofs
+/// holding a single existence proof of the path, bound to no root, which the
+/// mock clients accept as any other proof.
+impl ProvableContext for MockContext {
+    fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
+        if height > self.latest_height() {
+            return None;
+        }
+
+        let existence_proof = ExistenceProof {
+            key: path.to_string().into_bytes(),
+            ..Default::default()
+        };
+
+        let raw_merkle_proof = RawMerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+
+        CommitmentProofBytes::try_from(raw_merkle_proof)
+            .ok()
+            .map(Into::into)
+    }
+}
+
+impl QueryContext for MockContext {
+    fn client_states(&self) -> Result<Vec<(ClientId, AnyClientState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .clients
+            .iter()
+            .filter_map(|(client_id, client_record)| {
+                client_record
+                    .client_state
+                    .clone()
+                    .map(|client_state| (client_id.clone(), client_state))
+            })
+            .collect())
+    }
+
+    fn consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(Height, AnyConsensusState)>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .clients
+            .get(client_id)
+            .map(|client_record| {
+                client_record
+                    .consensus_states
+                    .iter()
+                    .map(|(height, consensus_state)| (*height, consensus_state.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .clients
+            .get(client_id)
+            .map(|client_record| client_record.consensus_states.keys().copied().collect())
+            .unwrap_or_default())
+    }
+
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .connections
+            .iter()
+            .map(|(connection_id, connection_end)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), connection_end.clone())
+            })
+            .collect())
+    }
+
+    fn client_connection_ends(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .client_connections
+            .get(client_id)
+            .cloned()
+            .into_iter()
+            .collect())
+    }
+
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .channels
+            .iter()
+            .flat_map(|(port_id, channels)| {
+                channels.iter().map(move |(channel_id, channel_end)| {
+                    IdentifiedChannelEnd::new(
+                        port_id.clone(),
+                        channel_id.clone(),
+                        channel_end.clone(),
+                    )
+                })
+            })
+            .collect())
+    }
+
+    fn packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_commitment
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .map(|commitments| {
+                commitments
+                    .iter()
+                    .map(|(seq, commitment)| PacketState {
+                        port_id: channel_end_path.0.clone(),
+                        chan_id: channel_end_path.1.clone(),
+                        seq: *seq,
+                        data: commitment.as_ref().to_vec().into(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+
+        let acks = match ibc_store
+            .packet_acknowledgement
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+        {
+            Some(acks) => acks,
+            None => return Ok(Vec::new()),
+        };
+
+        let ack_state = |(seq, ack): (&Sequence, _)| PacketState {
+            port_id: channel_end_path.0.clone(),
+            chan_id: channel_end_path.1.clone(),
+            seq: *seq,
+            data: AsRef::<[u8]>::as_ref(ack).to_vec().into(),
+        };
+
+        if sequences.len() == 0 {
+            return Ok(acks.iter().map(ack_state).collect());
+        }
+
+        Ok(sequences
+            .filter_map(|seq| acks.get_key_value(&seq))
+            .map(ack_state)
+            .collect())
+    }
+
+    fn unreceived_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+
+        let receipts = ibc_store
+            .packet_receipt
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1));
+
+        Ok(sequences
+            .filter(|seq| !receipts.map_or(false, |receipts| receipts.contains_key(seq)))
+            .collect())
+    }
+
+    fn unreceived_acks(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+
+        let commitments = match ibc_store
+            .packet_commitment
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+        {
+            Some(commitments) => commitments,
+            None => return Ok(Vec::new()),
+        };
+
+        if sequences.len() == 0 {
+            return Ok(commitments.keys().copied().collect());
+        }
+
+        Ok(sequences
+            .filter(|seq| commitments.contains_key(seq))
+            .collect())
+    }
+}
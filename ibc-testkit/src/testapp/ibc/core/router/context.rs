@@ -28,6 +28,6 @@ impl Router for MockRouter {
     }
 
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.port_to_module.get(port_id).cloned()
+        self.port_bindings.lookup_module(port_id)
     }
 }
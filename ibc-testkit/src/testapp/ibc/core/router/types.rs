@@ -1,9 +1,10 @@
 use alloc::sync::Arc;
 
 use ibc::apps::transfer::types::MODULE_ID_STR;
-use ibc::core::host::types::identifiers::PortId;
+use ibc::core::host::types::identifiers::{PortId, PortPrefix};
 use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
+use ibc::core::router::router::PortBindings;
 use ibc::core::router::types::module::ModuleId;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
@@ -12,8 +13,8 @@ use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 pub struct MockRouter {
     pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
 
-    /// Maps ports to the the module that owns it
-    pub port_to_module: BTreeMap<PortId, ModuleId>,
+    /// Maps ports, or prefixes of ports, to the module that owns them
+    pub port_bindings: PortBindings,
 }
 
 impl MockRouter {
@@ -45,6 +46,11 @@ impl MockRouter {
     }
 
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
-        self.port_to_module.insert(port_id, module_id);
+        self.port_bindings.bind_port(port_id, module_id);
+    }
+
+    /// Binds all the ports of the given prefix, e.g. `wasm.`, to the module.
+    pub fn scope_port_prefix_to_module(&mut self, prefix: PortPrefix, module_id: ModuleId) {
+        self.port_bindings.bind_prefix(prefix, module_id);
     }
 }
@@ -11,17 +11,21 @@ use ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::Receipt;
-use ibc::core::client::types::Height;
-use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::client::types::{ClientParams, Height};
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{ConnectionEnd, ConnectionParams};
 use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::error::IdentifierError;
 use ibc::core::host::types::identifiers::{
     ChainId, ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
 };
+use ibc::core::host::types::validate::{IdentifierLengthPolicy, IdentifierPolicy};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, SignerValidationMode, Timestamp};
 use ibc::core::router::router::Router;
 use parking_lot::Mutex;
 use tendermint_testgen::Validator as TestgenValidator;
@@ -52,10 +56,29 @@ pub struct MockIbcStore {
     /// Tracks the processed height for the clients
     pub client_processed_heights: BTreeMap<(ClientId, Height), Height>,
 
+    /// Tracks the relayers that updated the clients
+    pub client_update_relayers: BTreeMap<(ClientId, Height), Signer>,
+
+    /// Maximum clock drifts tolerated for the updates of the Tendermint
+    /// clients, when tighter than the ones of their client states
+    pub max_clock_drift_overrides: BTreeMap<ClientId, Duration>,
+
+    /// Clients of the provider chains of the Tendermint clients tracking
+    /// Interchain Security consumer chains
+    pub provider_clients: BTreeMap<ClientId, ClientId>,
+
+    /// Data roots of the DA blocks known to the clients of the data
+    /// availability layers the rollup clients depend on
+    pub da_data_roots: BTreeMap<(ClientId, Height), [u8; 32]>,
+
     /// Counter for the client identifiers, necessary for `increase_client_counter` and the
     /// `client_counter` methods.
     pub client_ids_counter: u64,
 
+    /// Identifier allocated to the next created client instead of the default
+    /// `{client-type}-{counter}` one, as a custom host would
+    pub next_client_id: Option<ClientId>,
+
     /// Association between client ids and connection ids.
     pub client_connections: BTreeMap<ClientId, ConnectionId>,
 
@@ -65,6 +88,14 @@ pub struct MockIbcStore {
     /// Counter for connection identifiers (see `increase_connection_counter`).
     pub connection_ids_counter: u64,
 
+    /// Connection versions supported by the host, or the default ones of
+    /// `get_compatible_versions` if `None`
+    pub connection_versions: Option<Vec<ConnectionVersion>>,
+
+    /// Connection features removed from the versions proposed by the
+    /// counterparties during `ConnOpenTry`
+    pub forbidden_connection_features: Vec<String>,
+
     /// Association between connection ids and channel ids.
     pub connection_channels: BTreeMap<ConnectionId, Vec<(PortId, ChannelId)>>,
 
@@ -91,11 +122,52 @@ pub struct MockIbcStore {
     /// Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
 
+    /// Host timestamps at which the committed packets were sent
+    pub packet_send_timestamps: PortChannelIdMap<BTreeMap<Sequence, Timestamp>>,
+
+    /// Relayers that delivered the received packets
+    pub packet_receipt_relayers: PortChannelIdMap<BTreeMap<Sequence, Signer>>,
+
+    /// Upgrade attempts in progress on the channels
+    pub channel_upgrades: PortChannelIdMap<Upgrade>,
+
+    /// Receipts of the last failed upgrade attempts of the channels
+    pub channel_upgrade_errors: PortChannelIdMap<ErrorReceipt>,
+
+    /// Upgrade sequences of the channels
+    pub channel_upgrade_sequences: PortChannelIdMap<u64>,
+
     /// Emitted IBC events in order
     pub events: Vec<IbcEvent>,
 
     /// Logs of the IBC module
     pub logs: Vec<String>,
+
+    /// Parameters of the client submodule
+    pub client_params: ClientParams,
+
+    /// Parameters of the connection submodule
+    pub connection_params: ConnectionParams,
+
+    /// Authority allowed to update the parameters of the IBC submodules
+    pub params_authority: Option<Signer>,
+
+    /// Checks of the signers of the handled messages
+    pub signer_validation_mode: SignerValidationMode,
+
+    /// Maximum size of the packet data, unbounded if unset
+    pub max_packet_data_size: Option<usize>,
+}
+
+impl MockIbcStore {
+    /// Creates an empty store, whose connection params expect the given time
+    /// per block.
+    pub fn with_block_time(block_time: Duration) -> Self {
+        Self {
+            connection_params: ConnectionParams::new(block_time),
+            ..Default::default()
+        }
+    }
 }
 
 /// A context implementing the dependencies necessary for testing any IBC module.
@@ -119,6 +191,46 @@ pub struct MockContext {
 
     /// An object that stores all IBC related data.
     pub ibc_store: Arc<Mutex<MockIbcStore>>,
+
+    /// The rules the handlers validate the identifiers of the incoming
+    /// messages with.
+    pub identifier_policy: MockIdentifierPolicy,
+}
+
+/// The identifier rules of the mock host, i.e. the ICS-24 rules with the
+/// configured length limits, except for the forbidden port identifiers.
+#[derive(Clone, Debug, Default)]
+pub struct MockIdentifierPolicy {
+    pub lengths: IdentifierLengthPolicy,
+    pub forbidden_port_ids: Vec<PortId>,
+}
+
+impl IdentifierPolicy for MockIdentifierPolicy {
+    fn validate_client_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.lengths.validate_client_identifier(id)
+    }
+
+    fn validate_connection_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.lengths.validate_connection_identifier(id)
+    }
+
+    fn validate_channel_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.lengths.validate_channel_identifier(id)
+    }
+
+    fn validate_port_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.lengths.validate_port_identifier(id)?;
+
+        if self
+            .forbidden_port_ids
+            .iter()
+            .any(|port_id| port_id.as_str() == id)
+        {
+            return Err(IdentifierError::ForbiddenIdentifier { id: id.into() });
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, TypedBuilder)]
@@ -168,6 +280,7 @@ impl Clone for MockContext {
             history: self.history.clone(),
             block_time: self.block_time,
             ibc_store,
+            identifier_policy: self.identifier_policy.clone(),
         }
     }
 }
@@ -231,7 +344,8 @@ impl MockContext {
                 })
                 .collect(),
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::with_block_time(block_time))),
+            identifier_policy: Default::default(),
         }
     }
 
@@ -300,7 +414,8 @@ impl MockContext {
             max_history_size,
             history,
             block_time,
-            ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
+            ibc_store: Arc::new(Mutex::new(MockIbcStore::with_block_time(block_time))),
+            identifier_policy: Default::default(),
         }
     }
 
@@ -613,6 +728,57 @@ impl MockContext {
         self
     }
 
+    /// Associates an upgrade attempt in progress to the given channel, along
+    /// with its upgrade sequence.
+    pub fn with_channel_upgrade(
+        self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+        upgrade_sequence: u64,
+    ) -> Self {
+        {
+            let mut ibc_store = self.ibc_store.lock();
+            ibc_store
+                .channel_upgrades
+                .entry(port_id.clone())
+                .or_default()
+                .insert(chan_id.clone(), upgrade);
+            ibc_store
+                .channel_upgrade_sequences
+                .entry(port_id)
+                .or_default()
+                .insert(chan_id, upgrade_sequence);
+        }
+        self
+    }
+
+    pub fn with_params_authority(self, authority: Signer) -> Self {
+        self.ibc_store.lock().params_authority = Some(authority);
+        self
+    }
+
+    /// Bounds the size, in bytes, of the data of the packets sent and
+    /// received by this context.
+    pub fn with_max_packet_data_size(self, max_packet_data_size: usize) -> Self {
+        self.ibc_store.lock().max_packet_data_size = Some(max_packet_data_size);
+        self
+    }
+
+    /// Forbids the handlers of this context to open channels on, or with,
+    /// the given port.
+    pub fn with_forbidden_port_id(mut self, port_id: PortId) -> Self {
+        self.identifier_policy.forbidden_port_ids.push(port_id);
+        self
+    }
+
+    /// Validates the identifiers of the incoming messages with the given
+    /// length limits instead of the ICS-24 ones.
+    pub fn with_identifier_lengths(mut self, lengths: IdentifierLengthPolicy) -> Self {
+        self.identifier_policy.lengths = lengths;
+        self
+    }
+
     pub fn with_height(self, target_height: Height) -> Self {
         let latest_height = self.latest_height();
         if target_height.revision_number() > latest_height.revision_number() {
@@ -690,6 +856,41 @@ impl MockContext {
         }
     }
 
+    /// Forks the host chain at `fork_height`. The returned context shares the history of this
+    /// context up to `fork_height`, and has divergent blocks from there up to the latest height,
+    /// which are signed by the same validators but have their timestamps shifted by `time_offset`.
+    /// The IBC store of the fork is a copy of the current one.
+    pub fn fork_at(&self, fork_height: &Height, time_offset: Duration) -> Self {
+        assert!(
+            self.host_block(fork_height).is_some(),
+            "The fork height must be in the history of the chain"
+        );
+        assert_ne!(
+            time_offset,
+            Duration::ZERO,
+            "The fork must have a non-zero time offset"
+        );
+
+        let mut fork = self.clone();
+        let fork_len = fork
+            .history
+            .iter()
+            .position(|block| &block.height() == fork_height)
+            .expect("Never fails")
+            + 1;
+
+        for block in fork.history.iter_mut().skip(fork_len) {
+            *block = HostBlock::generate_block(
+                self.host_chain_id.clone(),
+                self.host_chain_type,
+                block.height().revision_height(),
+                block.timestamp().add(time_offset).expect("Never fails"),
+            );
+        }
+
+        fork
+    }
+
     /// A datagram passes from the relayer to the IBC module (on host chain).
     /// Alternative method to `Ics18Context::send` that does not exercise any serialization.
     /// Used in testing the Ics18 algorithms, hence this may return a Ics18Error.
@@ -1,13 +1,29 @@
+use ibc::apps::transfer::accounting::{record_escrow, TransferAccountingContext};
+use ibc::apps::transfer::context::{
+    RefundReason, TokenTransferExecutionContext, TokenTransferValidationContext,
+};
 use ibc::apps::transfer::module::{
-    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_acknowledgement_packet_execute, on_chan_open_init_execute, on_chan_open_init_validate,
+    on_chan_open_try_execute, on_chan_open_try_validate, on_recv_packet_execute,
+    on_timeout_packet_execute,
+};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{
+    Amount, BaseCoin, Memo, PrefixedCoin, PrefixedDenom, U256, VERSION,
 };
-use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::fixtures::applications::transfer::{
+    extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use subtle_encoding::bech32;
 
@@ -166,3 +182,300 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// A transfer context recording the refunded transfers, and if enabled, the
+/// accounting of the tokens it moves.
+#[derive(Default)]
+struct RefundRecorder {
+    refunds: Vec<(Sequence, RefundReason)>,
+    accounting: Option<Accounting>,
+}
+
+type EscrowKey = (PortId, ChannelId, PrefixedDenom);
+
+/// The accounted amounts, kept in memory.
+#[derive(Default)]
+struct Accounting {
+    supplies: BTreeMap<PrefixedDenom, Amount>,
+    escrows: BTreeMap<EscrowKey, Amount>,
+}
+
+impl TransferAccountingContext for Accounting {
+    fn voucher_supply(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(self
+            .supplies
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| 0u64.into()))
+    }
+
+    fn store_voucher_supply(
+        &mut self,
+        denom: &PrefixedDenom,
+        supply: Amount,
+    ) -> Result<(), TokenTransferError> {
+        self.supplies.insert(denom.clone(), supply);
+        Ok(())
+    }
+
+    fn escrow_balance(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError> {
+        Ok(self
+            .escrows
+            .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(|| 0u64.into()))
+    }
+
+    fn store_escrow_balance(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        balance: Amount,
+    ) -> Result<(), TokenTransferError> {
+        self.escrows.insert(
+            (port_id.clone(), channel_id.clone(), denom.clone()),
+            balance,
+        );
+        Ok(())
+    }
+
+    fn voucher_denoms(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError> {
+        Ok(self.supplies.keys().cloned().collect())
+    }
+
+    fn escrowed_denoms(&self) -> Result<Vec<EscrowKey>, TokenTransferError> {
+        Ok(self.escrows.keys().cloned().collect())
+    }
+}
+
+impl TokenTransferValidationContext for RefundRecorder {
+    type AccountId = Signer;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for RefundRecorder {
+    fn escrow_coins_execute(
+        &mut self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn transfer_accounting_mut(&mut self) -> Option<&mut dyn TransferAccountingContext> {
+        match &mut self.accounting {
+            Some(accounting) => Some(accounting),
+            None => None,
+        }
+    }
+
+    fn on_refund_execute(
+        &mut self,
+        packet: &Packet,
+        _data: &PacketData,
+        reason: &RefundReason,
+    ) -> Result<(), TokenTransferError> {
+        self.refunds.push((packet.seq_on_a, reason.clone()));
+        Ok(())
+    }
+}
+
+fn dummy_transfer_packet(sequence: u64) -> Packet {
+    let packet_data = PacketDataConfig::builder()
+        .token(
+            BaseCoin {
+                denom: "uatom".parse().expect("parse denom"),
+                amount: U256::from(10).into(),
+            }
+            .into(),
+        )
+        .build();
+
+    let msg = MsgTransferConfig::builder()
+        .packet_data(packet_data)
+        .build();
+
+    extract_transfer_packet(&msg, sequence.into())
+}
+
+#[test]
+fn test_refund_hook() {
+    let mut ctx = RefundRecorder::default();
+    let relayer = dummy_account_id();
+
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &dummy_transfer_packet(1), &relayer);
+    assert!(res.is_ok());
+
+    let success_ack = AcknowledgementStatus::success_b64();
+    let (_, res) = on_acknowledgement_packet_execute(
+        &mut ctx,
+        &dummy_transfer_packet(2),
+        &success_ack.into(),
+        &relayer,
+    );
+    assert!(res.is_ok());
+
+    let error_ack = AcknowledgementStatus::error(StatusValue::new("insufficient funds").unwrap());
+    let (_, res) = on_acknowledgement_packet_execute(
+        &mut ctx,
+        &dummy_transfer_packet(3),
+        &error_ack.into(),
+        &relayer,
+    );
+    assert!(res.is_ok());
+
+    assert_eq!(
+        ctx.refunds,
+        vec![
+            (Sequence::from(1), RefundReason::Timeout),
+            (
+                Sequence::from(3),
+                RefundReason::ErrorAcknowledgement {
+                    error: "insufficient funds".to_string()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_accounting_hook() {
+    let mut ctx = RefundRecorder {
+        accounting: Some(Accounting::default()),
+        ..Default::default()
+    };
+    let relayer = dummy_account_id();
+
+    // The received tokens of the counterparty are minted as vouchers.
+    let packet = dummy_transfer_packet(1);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(ack, AcknowledgementStatus::success_b64().into());
+
+    let voucher_denom: PrefixedDenom =
+        format!("{}/{}/uatom", packet.port_id_on_b, packet.chan_id_on_b)
+            .parse()
+            .expect("parse denom");
+    let accounting = ctx.accounting.as_mut().expect("accounting enabled");
+    assert_eq!(
+        accounting.voucher_supply(&voucher_denom).unwrap(),
+        Amount::from(10u64)
+    );
+
+    // The refund of sent tokens is unescrowed, which fails while nothing
+    // was accounted as escrowed.
+    let uatom: PrefixedDenom = "uatom".parse().expect("parse denom");
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &dummy_transfer_packet(2), &relayer);
+    assert!(matches!(
+        res,
+        Err(TokenTransferError::TrackedAmountUnderflow { .. })
+    ));
+
+    let accounting = ctx.accounting.as_mut().expect("accounting enabled");
+    let coin = PrefixedCoin {
+        denom: uatom.clone(),
+        amount: Amount::from(10u64),
+    };
+    record_escrow(
+        accounting,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        &coin,
+    )
+    .unwrap();
+
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &dummy_transfer_packet(3), &relayer);
+    assert!(res.is_ok());
+
+    let accounting = ctx.accounting.as_ref().expect("accounting enabled");
+    assert_eq!(
+        accounting
+            .escrow_balance(&packet.port_id_on_a, &packet.chan_id_on_a, &uatom)
+            .unwrap(),
+        Amount::from(0u64)
+    );
+}
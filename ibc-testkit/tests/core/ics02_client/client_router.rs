@@ -0,0 +1,118 @@
+use core::time::Duration;
+use std::collections::BTreeMap;
+
+use ibc::clients::router::{ClientRouter, ClientStore};
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::types::{
+    client_type as tm_client_type, AllowUpdate, ClientState as TmClientStateType,
+    ConsensusState as TmConsensusStateType, TrustThreshold,
+};
+use ibc::core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use tendermint::{Hash, Time};
+
+#[derive(Default)]
+struct Store(BTreeMap<String, Vec<u8>>);
+
+impl ClientStore for Store {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(self.0.get(key).cloned())
+    }
+
+    fn set(&mut self, key: String, value: Vec<u8>) -> Result<(), ContextError> {
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &str) -> Result<(), ContextError> {
+        self.0.remove(key);
+        Ok(())
+    }
+
+    fn keys_with_prefix(&self, prefix: &str) -> Result<Vec<String>, ContextError> {
+        Ok(self
+            .0
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+type Router = ClientRouter<Store, TmClientState, TmConsensusState>;
+
+#[test]
+fn test_client_router() {
+    let client_id = tm_client_type().build_client_id(0);
+    let latest_height = Height::new(0, 10).unwrap();
+
+    let client_state = TmClientState::from(
+        TmClientStateType::new(
+            ChainId::new("counterparty-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(64_000),
+            Duration::from_secs(128_000),
+            Duration::from_millis(3_000),
+            latest_height,
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap(),
+    );
+    let consensus_state = TmConsensusState::from(TmConsensusStateType::new(
+        CommitmentRoot::from_bytes(&[1; 32]),
+        Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+        Hash::Sha256([0; 32]),
+    ));
+
+    let mut router = Router::new(
+        Store::default(),
+        Height::new(0, 100).unwrap(),
+        Timestamp::from_nanoseconds(1_700_000_010_000_000_000).unwrap(),
+    )
+    .with_client_type(tm_client_type(), "tendermint");
+
+    client_state
+        .initialise(&mut router, &client_id, consensus_state.clone().into())
+        .unwrap();
+
+    assert!(router
+        .store()
+        .0
+        .keys()
+        .all(|key| key.starts_with("tendermint/clients/07-tendermint-0/")));
+
+    assert_eq!(router.client_state(&client_id).unwrap(), client_state);
+    assert_eq!(
+        router.consensus_state_heights(&client_id).unwrap(),
+        vec![latest_height]
+    );
+    assert!(router.update_meta(&client_id, &latest_height).is_ok());
+    assert!(client_state
+        .status(&router, &client_id)
+        .unwrap()
+        .is_active());
+
+    // The clients of the unregistered client types are not routed.
+    let mock_client_id = mock_client_type().build_client_id(0);
+
+    assert!(matches!(
+        router.client_state(&mock_client_id),
+        Err(ContextError::ClientError(
+            ClientError::UnroutableClientId { .. }
+        ))
+    ));
+}
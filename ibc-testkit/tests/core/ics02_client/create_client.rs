@@ -1,3 +1,5 @@
+use core::str::FromStr;
+
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ConsensusState as TmConsensusState,
 };
@@ -8,11 +10,14 @@ use ibc::core::client::types::Height;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::SignerValidationMode;
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
 };
-use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::fixtures::core::signer::{dummy_account_id, dummy_bech32_account};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
@@ -119,3 +124,158 @@ fn test_invalid_frozen_tm_client_creation() {
         Err(ContextError::ClientError(ClientError::ClientFrozen { .. }))
     ))
 }
+
+#[test]
+fn test_create_client_custom_client_id() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let client_id = ClientId::from_str("9999-mock-tenant-7").unwrap();
+    ctx.ibc_store.lock().next_client_id = Some(client_id.clone());
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "validation happy path");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "execution happy path");
+
+    let expected_client_state = ctx.decode_client_state(msg.client_state).unwrap();
+    assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
+    // The client counter is increased regardless of the allocated identifier.
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+
+    // The allocated identifier is now in use.
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(res.is_err(), "validation must fail: identifier in use");
+}
+
+#[test]
+fn test_create_client_existing_client_id() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let res = execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg.clone())),
+    );
+
+    assert!(res.is_ok(), "execution happy path");
+
+    let client_id = mock_client_type().build_client_id(0);
+    let client_state = ctx.client_state(&client_id).unwrap();
+
+    // The host hook then returns the identifier of the existing client.
+    ctx.ibc_store.lock().next_client_id = Some(client_id.clone());
+
+    let new_height = Height::new(0, 84).unwrap();
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(new_height)).into(),
+        MockConsensusState::new(MockHeader::new(new_height)).into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientStateAlreadyExists { client_id: ref id }))
+                if *id == client_id
+        ),
+        "validation must fail: identifier in use, got {res:?}"
+    );
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_err(), "execution must fail: identifier in use");
+    assert_eq!(ctx.client_state(&client_id).unwrap(), client_state);
+    assert!(ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id,
+            new_height.revision_number(),
+            new_height.revision_height(),
+        ))
+        .is_err());
+}
+
+#[test]
+fn test_create_client_invalid_client_id_prefix() {
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    for client_id in ["07-tendermint-0", "9999-mockery-0", "9999-mock-"] {
+        let ctx = MockContext::default();
+        ctx.ibc_store.lock().next_client_id = Some(ClientId::from_str(client_id).unwrap());
+
+        let res = validate(
+            &ctx,
+            &router,
+            MsgEnvelope::from(ClientMsg::from(msg.clone())),
+        );
+
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ClientError(
+                    ClientError::InvalidClientIdentifierPrefix { .. }
+                ))
+            ),
+            "client identifier `{client_id}` must be rejected, got {res:?}"
+        );
+    }
+}
+
+#[test]
+fn test_create_client_bech32_signer() {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    ctx.ibc_store.lock().signer_validation_mode = SignerValidationMode::Bech32 {
+        prefix: "cosmos".to_string(),
+    };
+
+    let msg_envelope = |signer| {
+        MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+            MockClientState::new(MockHeader::new(height)).into(),
+            MockConsensusState::new(MockHeader::new(height)).into(),
+            signer,
+        )))
+    };
+
+    let res = validate(&ctx, &router, msg_envelope(dummy_bech32_account().into()));
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = validate(&ctx, &router, msg_envelope(dummy_account_id()));
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::InvalidSigner { .. }))
+    ));
+}
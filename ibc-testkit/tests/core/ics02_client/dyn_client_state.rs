@@ -0,0 +1,83 @@
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::dyn_client_state::{DynClientState, DynClientStateRegistry};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ClientId, ClientType};
+use ibc::primitives::proto::Any;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+type DynMockClientState = Box<dyn DynClientState<MockContext, MockContext>>;
+
+#[test]
+fn test_dyn_client_state_registry() {
+    let client_id = ClientId::default();
+    let height = Height::new(0, 42).unwrap();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(height)
+            .build(),
+    );
+
+    let mut registry = DynClientStateRegistry::<MockContext, MockContext>::new();
+    assert!(registry.register::<MockClientState>(MOCK_CLIENT_STATE_TYPE_URL));
+    assert!(registry.contains(MOCK_CLIENT_STATE_TYPE_URL));
+
+    let client_state = MockClientState::new(MockHeader::new(height));
+
+    let dyn_client_state: DynMockClientState = registry.decode(client_state.into()).unwrap();
+
+    assert_eq!(dyn_client_state.client_type(), mock_client_type());
+    assert_eq!(dyn_client_state.latest_height(), height);
+    assert!(dyn_client_state
+        .status(&ctx, &client_id)
+        .unwrap()
+        .is_active());
+    assert_eq!(Any::from(dyn_client_state.clone()), client_state.into());
+
+    let mut unknown: Any = client_state.into();
+    unknown.type_url = "/unknown.ClientState".to_string();
+
+    assert!(matches!(
+        registry.decode(unknown),
+        Err(ClientError::UnknownClientStateType { .. })
+    ));
+}
+
+#[test]
+fn test_dyn_client_state_registry_client_types() {
+    let height = Height::new(0, 42).unwrap();
+    let client_state: Any = MockClientState::new(MockHeader::new(height)).into();
+
+    let mut registry = DynClientStateRegistry::<MockContext, MockContext>::new();
+    assert!(registry
+        .register_client_type::<MockClientState>(mock_client_type(), MOCK_CLIENT_STATE_TYPE_URL));
+    assert!(registry.contains(MOCK_CLIENT_STATE_TYPE_URL));
+    assert!(registry.validate_client_type(&mock_client_type()).is_ok());
+
+    let client_id = ClientId::new(mock_client_type().as_str(), 3).unwrap();
+    assert_eq!(
+        registry.client_type_of(&client_id).unwrap(),
+        &mock_client_type()
+    );
+
+    let dyn_client_state: DynMockClientState = registry
+        .decode_for_client(&client_id, client_state.clone())
+        .unwrap();
+    assert_eq!(dyn_client_state.latest_height(), height);
+
+    let unregistered = ClientType::new("08-wasm").unwrap();
+    assert!(matches!(
+        registry.validate_client_type(&unregistered),
+        Err(ClientError::UnregisteredClientType { .. })
+    ));
+    assert!(matches!(
+        registry.decode_for_client(&ClientId::new("08-wasm", 0).unwrap(), client_state),
+        Err(ClientError::UnroutableClientId { .. })
+    ));
+}
@@ -1,5 +1,9 @@
+pub mod client_router;
 #[cfg(feature = "serde")]
 pub mod create_client;
+pub mod dyn_client_state;
+pub mod rollup_client;
 pub mod update_client;
+pub mod update_params;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
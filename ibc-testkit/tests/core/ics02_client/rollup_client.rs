@@ -0,0 +1,244 @@
+use core::ops::Sub;
+use core::time::Duration;
+
+use ibc::clients::rollup::types::{
+    client_type as rollup_client_type, ClientState as RollupClientStateType,
+    ConsensusState as RollupConsensusStateType, Header as RollupHeader, RollupBlock,
+};
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::CommitmentRoot;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::clients::rollup::{
+    dummy_namespace, dummy_published_rollup_header, dummy_rollup_client_state,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+/// A host with a mock client of the DA layer, whose DA blocks at heights `5`
+/// and `6` were published an hour ago.
+struct Fixture {
+    ctx: MockContext,
+    router: MockRouter,
+    da_client_id: ClientId,
+}
+
+fn fixture() -> Fixture {
+    let da_client_id = ClientId::new("9999-mock", 0).unwrap();
+    let da_timestamp = Timestamp::now().sub(Duration::from_secs(3600)).unwrap();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(da_client_id.clone())
+            .latest_height(Height::new(0, 6).unwrap())
+            .consensus_state_heights(vec![Height::new(0, 5).unwrap(), Height::new(0, 6).unwrap()])
+            .latest_timestamp(da_timestamp)
+            .build(),
+    );
+
+    Fixture {
+        ctx,
+        router: MockRouter::new_with_transfer(),
+        da_client_id,
+    }
+}
+
+fn block(height: u64, state_root: u8) -> RollupBlock {
+    RollupBlock {
+        height: Height::new(1, height).unwrap(),
+        timestamp: Timestamp::from_nanoseconds(1_000_000_000).unwrap(),
+        state_root: CommitmentRoot::from_bytes(&[state_root; 32]),
+    }
+}
+
+/// Publishes the block at the given DA height, recording the data root of the
+/// DA block in the host.
+fn publish(
+    ctx: &MockContext,
+    da_client_id: &ClientId,
+    block: RollupBlock,
+    da_height: u64,
+) -> RollupHeader {
+    let da_height = Height::new(0, da_height).unwrap();
+    let (header, data_root) = dummy_published_rollup_header(&dummy_namespace(1), block, da_height);
+
+    ctx.ibc_store
+        .lock()
+        .da_data_roots
+        .insert((da_client_id.clone(), da_height), data_root);
+
+    header
+}
+
+/// Creates a rollup client at height `1-10`, and returns its identifier.
+fn create_rollup_client(fixture: &mut Fixture, fraud_proof_window: Duration) -> ClientId {
+    let client_state = dummy_rollup_client_state(
+        Height::new(1, 10).unwrap(),
+        fixture.da_client_id.clone(),
+        fraud_proof_window,
+    );
+    let consensus_state = RollupConsensusStateType::new(
+        CommitmentRoot::from_bytes(&[1; 32]),
+        Timestamp::from_nanoseconds(1).unwrap(),
+        Height::new(0, 4).unwrap(),
+    );
+
+    let client_id = rollup_client_type().build_client_id(fixture.ctx.client_counter().unwrap());
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        client_state.into(),
+        consensus_state.into(),
+        dummy_account_id(),
+    )));
+
+    validate(&fixture.ctx, &fixture.router, msg_envelope.clone()).expect("validation happy path");
+    execute(&mut fixture.ctx, &mut fixture.router, msg_envelope).expect("execution happy path");
+
+    client_id
+}
+
+fn update(client_id: &ClientId, header: RollupHeader) -> MsgEnvelope {
+    MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    }))
+}
+
+fn rollup_client_state(ctx: &MockContext, client_id: &ClientId) -> RollupClientStateType {
+    match ctx.client_state(client_id).unwrap() {
+        AnyClientState::Rollup(client_state) => client_state.inner().clone(),
+        _ => panic!("unexpected client state type"),
+    }
+}
+
+#[test]
+fn test_rollup_create_update_verify() {
+    let mut fixture = fixture();
+    let client_id = create_rollup_client(&mut fixture, Duration::from_secs(600));
+
+    assert_eq!(
+        ValidationContext::client_state(&fixture.ctx, &client_id)
+            .unwrap()
+            .status(&fixture.ctx, &client_id)
+            .unwrap(),
+        Status::Active
+    );
+
+    let header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 2), 5);
+    let msg_envelope = update(&client_id, header.clone());
+
+    validate(&fixture.ctx, &fixture.router, msg_envelope.clone()).expect("validation happy path");
+    execute(&mut fixture.ctx, &mut fixture.router, msg_envelope.clone())
+        .expect("execution happy path");
+
+    let client_state = rollup_client_state(&fixture.ctx, &client_id);
+    assert_eq!(client_state.latest_height, Height::new(1, 11).unwrap());
+    assert!(!client_state.is_frozen());
+
+    let consensus_state = fixture
+        .ctx
+        .consensus_state(&ClientConsensusStatePath::new(client_id.clone(), 1, 11))
+        .unwrap();
+    assert_eq!(
+        consensus_state,
+        RollupConsensusStateType::from(header).into()
+    );
+
+    // Submitting the same header again is a no-op.
+    validate(&fixture.ctx, &fixture.router, msg_envelope.clone()).expect("redundant update");
+    execute(&mut fixture.ctx, &mut fixture.router, msg_envelope).expect("redundant update");
+    assert!(!rollup_client_state(&fixture.ctx, &client_id).is_frozen());
+}
+
+#[test]
+fn test_rollup_update_before_fraud_proof_window() {
+    let mut fixture = fixture();
+    // The DA blocks were published an hour ago.
+    let client_id = create_rollup_client(&mut fixture, Duration::from_secs(7200));
+
+    let header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 2), 5);
+
+    assert!(validate(&fixture.ctx, &fixture.router, update(&client_id, header)).is_err());
+}
+
+#[test]
+fn test_rollup_update_unpublished_block() {
+    let mut fixture = fixture();
+    let client_id = create_rollup_client(&mut fixture, Duration::ZERO);
+
+    let mut header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 2), 5);
+    header.block.state_root = CommitmentRoot::from_bytes(&[3; 32]);
+
+    assert!(validate(&fixture.ctx, &fixture.router, update(&client_id, header)).is_err());
+}
+
+#[test]
+fn test_rollup_conflicting_header_freezes_client() {
+    let mut fixture = fixture();
+    let client_id = create_rollup_client(&mut fixture, Duration::ZERO);
+
+    let header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 2), 5);
+    let msg_envelope = update(&client_id, header);
+    validate(&fixture.ctx, &fixture.router, msg_envelope.clone()).unwrap();
+    execute(&mut fixture.ctx, &mut fixture.router, msg_envelope).unwrap();
+
+    // The rollup published another block at the same height.
+    let conflicting_header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 3), 6);
+    let msg_envelope = update(&client_id, conflicting_header);
+    validate(&fixture.ctx, &fixture.router, msg_envelope.clone()).unwrap();
+    execute(&mut fixture.ctx, &mut fixture.router, msg_envelope).unwrap();
+
+    assert!(rollup_client_state(&fixture.ctx, &client_id).is_frozen());
+    assert_eq!(
+        ValidationContext::client_state(&fixture.ctx, &client_id)
+            .unwrap()
+            .status(&fixture.ctx, &client_id)
+            .unwrap(),
+        Status::Frozen
+    );
+}
+
+#[test]
+fn test_rollup_update_with_frozen_da_client() {
+    let mut fixture = fixture();
+    let client_id = create_rollup_client(&mut fixture, Duration::ZERO);
+
+    let da_client_state =
+        match ValidationContext::client_state(&fixture.ctx, &fixture.da_client_id).unwrap() {
+            AnyClientState::Mock(client_state) => client_state,
+            _ => panic!("unexpected client state type"),
+        };
+    fixture
+        .ctx
+        .ibc_store
+        .lock()
+        .clients
+        .get_mut(&fixture.da_client_id)
+        .unwrap()
+        .client_state = Some(
+        MockClientState::with_frozen_height(da_client_state, Height::new(0, 1).unwrap()).into(),
+    );
+
+    // The rollup client shares the status of its DA client.
+    assert_eq!(
+        ValidationContext::client_state(&fixture.ctx, &client_id)
+            .unwrap()
+            .status(&fixture.ctx, &client_id)
+            .unwrap(),
+        Status::Frozen
+    );
+
+    let header = publish(&fixture.ctx, &fixture.da_client_id, block(11, 2), 5);
+    assert!(validate(&fixture.ctx, &fixture.router, update(&client_id, header)).is_err());
+}
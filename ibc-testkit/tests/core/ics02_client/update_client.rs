@@ -1,40 +1,63 @@
 use core::str::FromStr;
 use core::time::Duration;
 
-use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::client_state::{
+    expires_at, is_duplicate_header, ClientState, PROVIDER_VALIDATOR_SET_WINDOW,
+};
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::context::{
+    DefaultVerifier, FallbackSignatureVerifier, HostFunctionsVerifier, SignatureSchemeVerifier,
+    TmVerifier,
+};
+use ibc::clients::tendermint::host_functions::TmHostFunctions;
+use ibc::clients::tendermint::light_client::{verify_header, TrustedState};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    client_type as tm_client_type, ClientState as TmClientState,
+    ConsensusState as TmConsensusStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
 };
 use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::host_functions::{DefaultHostFunctions, HostFunctionsProvider};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
+use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType, RevisionFormat};
 use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::{downcast, Timestamp};
+use ibc::derive::ClientState;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
-use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::hosts::block::{default_tm_validators, HostBlock, HostType};
+use ibc_testkit::hosts::misbehaviour::{
+    conflicting_headers_misbehaviour, duplicate_height_misbehaviour, fork_misbehaviour,
+    time_travel_misbehaviour,
+};
+use ibc_testkit::hosts::validators::{
+    generate_partially_signed_block, overlapping_validators, signers_up_to_power,
+    total_voting_power, validators_with_powers,
+};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
-use tendermint_testgen::Validator as TestgenValidator;
+use tendermint::crypto::default::signature::Verifier as Ed25519Verifier;
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::{Hash, PublicKey, Signature};
+use tendermint_testgen::{Generator, Validator as TestgenValidator};
 
 struct Fixture {
     ctx: MockContext,
@@ -329,6 +352,10 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
 
     let mut block = ctx_b.host_block(&update_height).unwrap().clone();
     block.set_trusted_height(client_height);
+    let header_hash = downcast!(block.clone() => HostBlock::SyntheticTendermint)
+        .unwrap()
+        .header()
+        .hash();
 
     let latest_header_height = block.height();
     let msg = MsgUpdateClient {
@@ -352,6 +379,316 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
         .is_active());
 
     assert_eq!(client_state.latest_height(), latest_header_height);
+
+    let ibc_events = ctx.get_events();
+    let update_client_event = downcast!(&ibc_events[1] => IbcEvent::UpdateClient).unwrap();
+    let telemetry = update_client_event.telemetry().unwrap();
+
+    assert_eq!(telemetry.validator_set_rotation_size(), 0);
+    assert!(telemetry.is_adjacent());
+    assert_eq!(telemetry.trusted_height(), Some(&client_height));
+    assert_eq!(telemetry.header_hash(), Some(header_hash.as_bytes()));
+}
+
+/// Tests the update of clients of chains whose identifiers do not carry their
+/// revision number in the {chain name}-{revision number} format.
+#[rstest]
+#[case::other_delimiter("mockgaiaB_1", RevisionFormat::Delimited('_'), 1)]
+#[case::revisionless("mockgaiaB-9001", RevisionFormat::Revisionless, 0)]
+fn test_update_synthetic_tendermint_client_relaxed_chain_id(
+    #[case] chain_id: &str,
+    #[case] format: RevisionFormat,
+    #[case] revision_number: u64,
+) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(revision_number, 20).unwrap();
+    let update_height = Height::new(revision_number, 21).unwrap();
+    let chain_id_b = ChainId::new_with_format(chain_id, format).unwrap();
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+
+    // The revision format and number survive the encoding of the client state.
+    let tm_client_state = match client_state {
+        AnyClientState::Tendermint(tm_client_state) => tm_client_state,
+        _ => panic!("unexpected client state type"),
+    };
+    assert_eq!(tm_client_state.inner().revision_format, format);
+
+    let decoded = TmClientState::try_from(Any::from(tm_client_state.inner().clone())).unwrap();
+    assert_eq!(decoded.revision_format, format);
+    assert_eq!(decoded.chain_id, chain_id_b);
+}
+
+/// Tests the off-chain verification of a header of a synthetic Tendermint
+/// chain, against the trusted state of an on-chain client.
+#[rstest]
+#[case::within_trusting_period(Duration::ZERO, true)]
+#[case::after_trusting_period(Duration::from_secs(64000), false)]
+fn test_verify_synthetic_tendermint_header_off_chain(
+    #[case] time_offset: Duration,
+    #[case] expect_ok: bool,
+) {
+    let res = verify_synthetic_tendermint_header(time_offset, &DefaultVerifier);
+
+    assert_eq!(res.is_ok(), expect_ok, "result: {res:?}");
+}
+
+/// A stand-in for an alternative signature scheme, e.g. secp256k1, used by
+/// the first of the default validators only, and verifying its signatures as
+/// ed25519 ones.
+struct FirstValidatorScheme;
+
+/// Same as [`FirstValidatorScheme`], but rejecting all the signatures.
+struct RejectingFirstValidatorScheme;
+
+fn is_first_validator(pubkey: PublicKey) -> bool {
+    default_tm_validators()[0].generate().unwrap().pub_key == pubkey
+}
+
+impl SignatureVerifier for FirstValidatorScheme {
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        if !is_first_validator(pubkey) {
+            return Err(SignatureError::UnsupportedKeyType);
+        }
+        Ed25519Verifier::verify(pubkey, msg, signature)
+    }
+}
+
+impl SignatureVerifier for RejectingFirstValidatorScheme {
+    fn verify(
+        pubkey: PublicKey,
+        _msg: &[u8],
+        _signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        if !is_first_validator(pubkey) {
+            return Err(SignatureError::UnsupportedKeyType);
+        }
+        Err(SignatureError::VerificationFailed)
+    }
+}
+
+/// Tests the verification of a header signed by a validator set mixing two
+/// signature schemes.
+#[rstest]
+fn test_verify_header_mixed_signature_schemes() {
+    // Each scheme verifies the signatures of its own validators.
+    let verifier = SignatureSchemeVerifier::<
+        FallbackSignatureVerifier<FirstValidatorScheme, Ed25519Verifier>,
+    >::default();
+    let res = verify_synthetic_tendermint_header(Duration::ZERO, &verifier);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    // The signatures of the first validator are not verified as ed25519 ones.
+    let verifier = SignatureSchemeVerifier::<
+        FallbackSignatureVerifier<RejectingFirstValidatorScheme, Ed25519Verifier>,
+    >::default();
+    assert!(verify_synthetic_tendermint_header(Duration::ZERO, &verifier).is_err());
+
+    // The scheme of the first validator alone does not support the keys of
+    // the other ones.
+    let verifier = SignatureSchemeVerifier::<FirstValidatorScheme>::default();
+    assert!(verify_synthetic_tendermint_header(Duration::ZERO, &verifier).is_err());
+}
+
+/// Host functions hashing as the default ones, but rejecting all the
+/// signatures.
+struct RejectingHostFunctions;
+
+impl HostFunctionsProvider for RejectingHostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::sha2_256(message)
+    }
+
+    fn sha2_512(message: &[u8]) -> [u8; 64] {
+        DefaultHostFunctions::sha2_512(message)
+    }
+
+    fn sha2_512_truncated(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::sha2_512_truncated(message)
+    }
+
+    fn keccak_256(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::keccak_256(message)
+    }
+
+    fn ripemd160(message: &[u8]) -> [u8; 20] {
+        DefaultHostFunctions::ripemd160(message)
+    }
+
+    fn blake2b_512(message: &[u8]) -> [u8; 64] {
+        DefaultHostFunctions::blake2b_512(message)
+    }
+
+    fn blake2s_256(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::blake2s_256(message)
+    }
+
+    fn blake3(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::blake3(message)
+    }
+}
+
+impl TmHostFunctions for RejectingHostFunctions {
+    fn ed25519_verify(_public_key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Tests that the signatures of the votes are verified with the host
+/// functions of the verifier.
+#[rstest]
+fn test_verify_header_with_host_functions() {
+    let verifier = HostFunctionsVerifier::<DefaultHostFunctions>::default();
+    let res = verify_synthetic_tendermint_header(Duration::ZERO, &verifier);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let verifier = HostFunctionsVerifier::<RejectingHostFunctions>::default();
+    assert!(verify_synthetic_tendermint_header(Duration::ZERO, &verifier).is_err());
+}
+
+/// A host client state verifying the headers of its Tendermint clients with
+/// the [`RejectingHostFunctions`], injected through the derive macro.
+#[derive(Debug, Clone, ClientState)]
+#[validation(MockContext)]
+#[execution(MockContext)]
+enum RejectingClientState {
+    #[verifier(HostFunctionsVerifier::<RejectingHostFunctions>::default())]
+    Tendermint(ClientState),
+}
+
+/// Tests that the client messages of a variant annotated with `#[verifier]`
+/// are verified with the given verifier rather than the default one.
+#[rstest]
+fn test_verify_client_message_with_derived_verifier() {
+    let (ctx, client_id, header) = synthetic_tendermint_update();
+
+    let client_state = match ctx.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state,
+        _ => panic!("the client must be a Tendermint client"),
+    };
+
+    let res = AnyClientState::Tendermint(client_state.clone()).verify_client_message(
+        &ctx,
+        &client_id,
+        header.clone().into(),
+    );
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = RejectingClientState::Tendermint(client_state).verify_client_message(
+        &ctx,
+        &client_id,
+        header.into(),
+    );
+    assert!(res.is_err());
+}
+
+/// Sets up an on-chain Tendermint client of a synthetic Tendermint chain,
+/// along with a header of that chain, signed by the default validators, to
+/// update the client with.
+fn synthetic_tendermint_update() -> (MockContext, ClientId, TmHeader) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+    let header = TmHeader::from(block.try_into_tm_block().unwrap());
+
+    (ctx, client_id, header)
+}
+
+/// Verifies a header of a synthetic Tendermint chain, signed by the default
+/// validators, against the trusted state of an on-chain client.
+fn verify_synthetic_tendermint_header(
+    time_offset: Duration,
+    verifier: &impl TmVerifier,
+) -> Result<(), ClientError> {
+    let (ctx, client_id, header) = synthetic_tendermint_update();
+    let client_height = header.trusted_height;
+
+    let client_state = match ctx.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state,
+        _ => panic!("the client must be a Tendermint client"),
+    };
+    let consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id,
+            client_height.revision_number(),
+            client_height.revision_height(),
+        ))
+        .unwrap();
+    let consensus_state = match consensus_state {
+        AnyConsensusState::Tendermint(consensus_state) => consensus_state,
+        _ => panic!("the consensus state must be a Tendermint consensus state"),
+    };
+
+    let trusted_state = TrustedState {
+        chain_id: &client_state.inner().chain_id,
+        revision_format: client_state.inner().revision_format,
+        consensus_state: consensus_state.inner(),
+    };
+    let options = client_state.inner().as_light_client_options().unwrap();
+    let now = (ctx.host_timestamp().unwrap() + time_offset).unwrap();
+
+    verify_header(trusted_state, &header, &options, verifier, now)
 }
 
 #[rstest]
@@ -591,6 +928,100 @@ fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
         .is_active());
 
     assert_eq!(client_state.latest_height(), latest_header_height);
+
+    let ibc_events = ctx.get_events();
+    let update_client_event = downcast!(&ibc_events[1] => IbcEvent::UpdateClient).unwrap();
+    let telemetry = update_client_event.telemetry().unwrap();
+
+    assert_eq!(telemetry.validator_set_rotation_size(), 0);
+    assert!(!telemetry.is_adjacent());
+}
+
+/// Tests the voting power thresholds of the synthetic Tendermint client
+/// verification, with headers at a non-adjacent height of the trusted one.
+#[rstest]
+// the trusted validators and a new one sign all the voting power
+#[case::full_commit(
+    [default_tm_validators().to_vec(), validators_with_powers("val", &[50])].concat(),
+    None,
+    true
+)]
+// the trusted validators only sign exactly 2/3 of the voting power
+#[case::two_thirds_commit(
+    [default_tm_validators().to_vec(), validators_with_powers("val", &[50])].concat(),
+    Some(100),
+    false
+)]
+// the signing trusted validators hold 1/2 of the trusted voting power
+#[case::trusted_overlap(overlapping_validators(&default_tm_validators(), 1, "new"), None, true)]
+// none of the trusted validators sign
+#[case::no_trusted_overlap(
+    overlapping_validators(&default_tm_validators(), 0, "new"),
+    None,
+    false
+)]
+fn test_update_synthetic_tendermint_client_voting_power_thresholds(
+    #[case] validators: Vec<TestgenValidator>,
+    #[case] signed_power: Option<u64>,
+    #[case] expect_ok: bool,
+) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 22).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .latest_height(client_height)
+                .client_type(tm_client_type())
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let signers = match signed_power {
+        Some(power) => signers_up_to_power(&validators, power),
+        None => validators.clone(),
+    };
+    assert!(total_voting_power(&signers) <= total_voting_power(&validators));
+
+    let mut block = generate_partially_signed_block(
+        &chain_id_b,
+        update_height.revision_height(),
+        Timestamp::now(),
+        &validators,
+        &validators,
+        &signers,
+        client_height,
+    );
+    block.trusted_next_validators = HostBlock::generate_tm_block(
+        chain_id_b,
+        client_height.revision_height(),
+        Timestamp::now(),
+    )
+    .light_block
+    .next_validators;
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: TmHeader::from(block).into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert_eq!(res.is_ok(), expect_ok, "result: {res:?}");
+
+    if expect_ok {
+        let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
+    }
 }
 
 #[rstest]
@@ -702,6 +1133,38 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
         client_record.client_state = Some(client_state);
     }
 
+    // The redundant header is flagged for the ante handlers, but is still
+    // fully verified by the update: it is rejected if its trusted consensus
+    // state is missing.
+    let mut untrusted_block = block.clone();
+    untrusted_block.set_trusted_height(Height::new(1, 5).unwrap());
+    let untrusted_header: Any = untrusted_block.into();
+
+    let tm_client_state = match ctx_a.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state,
+        _ => panic!("unexpected client state type"),
+    };
+    assert!(is_duplicate_header(
+        tm_client_state.inner(),
+        &ctx_a,
+        &client_id,
+        &TmHeader::try_from(untrusted_header.clone()).unwrap(),
+    )
+    .unwrap());
+
+    let untrusted_msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: untrusted_header,
+        signer: signer.clone(),
+    };
+
+    let res = validate(
+        &ctx_a,
+        &router_a,
+        MsgEnvelope::from(ClientMsg::from(untrusted_msg)),
+    );
+    assert!(res.is_err());
+
     let latest_header_height = block.height();
     let msg = MsgUpdateClient {
         client_id,
@@ -714,6 +1177,11 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
     let res = validate(&ctx_a, &router_a, msg_envelope.clone());
     assert!(res.is_ok(), "result: {res:?}");
 
+    let client_state_before = ctx_a.client_state(&msg.client_id).unwrap();
+    let consensus_states_before = ctx_a.ibc_store.lock().clients[&msg.client_id]
+        .consensus_states
+        .clone();
+
     let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
     assert!(res.is_ok(), "result: {res:?}");
 
@@ -724,6 +1192,35 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
         .is_active());
     assert_eq!(client_state.latest_height(), latest_header_height);
     assert_eq!(client_state, ctx_a.latest_client_states(&msg.client_id));
+
+    // The update is a no-op: nothing is written to the store.
+    assert_eq!(client_state, client_state_before);
+    assert_eq!(
+        ctx_a.ibc_store.lock().clients[&msg.client_id].consensus_states,
+        consensus_states_before
+    );
+    assert!(ctx_a
+        .update_meta(&msg.client_id, &latest_header_height)
+        .is_err());
+
+    let ibc_events = ctx_a.get_events();
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let redundant_update_event =
+        downcast!(&ibc_events[1] => IbcEvent::RedundantUpdateClient).unwrap();
+
+    assert_eq!(redundant_update_event.client_id(), &msg.client_id);
+    assert_eq!(redundant_update_event.client_type(), &tm_client_type());
+    assert_eq!(
+        redundant_update_event.header(),
+        &msg.client_message.to_vec()
+    );
+    assert!(!ibc_events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::UpdateClient(_))));
 }
 
 #[rstest]
@@ -807,6 +1304,43 @@ fn test_update_client_events(fixture: Fixture) {
     assert_eq!(update_client_event.consensus_height(), &height);
     assert_eq!(update_client_event.consensus_heights(), &vec![height]);
     assert_eq!(update_client_event.header(), &header.to_vec());
+    assert!(update_client_event.telemetry().is_none());
+}
+
+#[rstest]
+fn test_update_client_records_relayer(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::default();
+    let signer = dummy_account_id();
+
+    let height = Height::new(0, 46).unwrap();
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(height)
+            .with_timestamp(Timestamp::now())
+            .into(),
+        signer: signer.clone(),
+    };
+
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.client_update_relayer(&client_id, &height).unwrap(),
+        Some(signer)
+    );
+    assert!(ctx
+        .client_update_relayer(&client_id, &Height::new(0, 42).unwrap())
+        .unwrap()
+        .is_none());
 }
 
 fn ensure_misbehaviour(ctx: &MockContext, client_id: &ClientId, client_type: &ClientType) {
@@ -1015,6 +1549,119 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
 }
 
+/// Tests misbehaviour handling for the synthetic Tendermint client, with the
+/// evidence built by the testkit misbehaviour builders.
+#[rstest]
+#[case::conflicting_headers(conflicting_headers_misbehaviour)]
+#[case::time_travel(time_travel_misbehaviour)]
+#[case::duplicate_height(duplicate_height_misbehaviour)]
+fn test_misbehaviour_synthetic_tendermint_builders(
+    #[case] build_misbehaviour: fn(&MockContext, &ClientId, &Height, Height) -> TmMisbehaviour,
+) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: build_misbehaviour(&ctx_b, &client_id, &misbehaviour_height, client_height)
+            .into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "{res:?}");
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "{res:?}");
+    ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
+}
+
+/// Tests misbehaviour handling for the synthetic Tendermint client, with the
+/// evidence taken from a fork of the counterparty chain.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_fork() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 22).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    // Fork chain-B right after the trusted height of the client
+    let fork_b = ctx_b.fork_at(&client_height.increment(), Duration::from_secs(1));
+    assert_eq!(fork_b.latest_height(), ctx_b.latest_height());
+    assert_eq!(
+        fork_b.host_block(&client_height.increment()),
+        ctx_b.host_block(&client_height.increment())
+    );
+    assert_ne!(
+        fork_b.host_block(&misbehaviour_height),
+        ctx_b.host_block(&misbehaviour_height)
+    );
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: fork_misbehaviour(
+            &ctx_b,
+            &fork_b,
+            &client_id,
+            &misbehaviour_height,
+            client_height,
+        )
+        .into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "{res:?}");
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "{res:?}");
+    ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
+}
+
 #[rstest]
 fn test_expired_client() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1044,6 +1691,20 @@ fn test_expired_client() {
                 .build(),
         );
 
+    let tm_client_state = match ctx.client_state(&client_id).unwrap() {
+        AnyClientState::Tendermint(tm_client_state) => tm_client_state,
+        _ => panic!("unexpected client state type"),
+    };
+    let expiry = expires_at(tm_client_state.inner(), &ctx, &client_id)
+        .unwrap()
+        .expect("the client has a consensus state at its latest height");
+
+    assert_eq!(expiry, (timestamp + trusting_period).unwrap());
+    assert!(!tm_client_state.inner().is_expired(timestamp, expiry));
+    assert!(tm_client_state
+        .inner()
+        .is_expired(timestamp, (expiry + Duration::from_nanos(1)).unwrap()));
+
     while ctx.host_timestamp().expect("no error") < (timestamp + trusting_period).expect("no error")
     {
         ctx.advance_host_chain_height();
@@ -1126,3 +1787,187 @@ fn test_client_update_max_clock_drift() {
     let res = validate(&ctx_a, &router_a, msg_envelope);
     assert!(res.is_err());
 }
+
+#[rstest]
+#[case::client_state_drift(None, true)]
+#[case::tighter_drift(Some(Duration::from_secs(5)), false)]
+fn test_client_update_max_clock_drift_override(
+    #[case] drift_override: Option<Duration>,
+    #[case] expect_ok: bool,
+) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 20).unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .latest_height(client_height)
+                .client_type(tm_client_type())
+                .latest_timestamp(timestamp)
+                .max_clock_drift(Duration::from_secs(64))
+                .build(),
+        );
+
+    if let Some(drift) = drift_override {
+        ctx_a
+            .ibc_store
+            .lock()
+            .max_clock_drift_overrides
+            .insert(client_id.clone(), drift);
+    }
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let mut ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(client_height)
+        .latest_timestamp(timestamp)
+        .max_history_size(u64::MAX)
+        .build();
+
+    // The header is ahead of the host by more than the overridden drift, but
+    // by less than the drift of the client state.
+    while ctx_b.host_timestamp().expect("no error")
+        < (ctx_a.host_timestamp().expect("no error") + Duration::from_secs(10)).expect("no error")
+    {
+        ctx_b.advance_host_chain_height();
+    }
+
+    let update_height = ctx_b.latest_height();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+
+    let trusted_next_validator_set = match ctx_b.host_block(&client_height).expect("no error") {
+        HostBlock::SyntheticTendermint(header) => header.light_block.next_validators.clone(),
+        _ => panic!("unexpected host block type"),
+    };
+
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    let res = validate(&ctx_a, &router_a, MsgEnvelope::from(ClientMsg::from(msg)));
+    assert_eq!(res.is_ok(), expect_ok, "{res:?}");
+}
+
+#[rstest]
+#[case::provider_validators(0, true)]
+#[case::other_validators(PROVIDER_VALIDATOR_SET_WINDOW + 1, false)]
+#[case::last_in_window(PROVIDER_VALIDATOR_SET_WINDOW - 1, true)]
+#[case::outside_window(PROVIDER_VALIDATOR_SET_WINDOW, false)]
+fn test_consumer_client_update(
+    #[case] changed_provider_validator_sets: usize,
+    #[case] expect_ok: bool,
+) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let provider_chain_id = ChainId::new("mockprovider-1").unwrap();
+
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+
+    let consumer_client_id = tm_client_type().build_client_id(0);
+    let provider_client_id = tm_client_type().build_client_id(1);
+
+    // The provider client stores one more consensus state than the window of
+    // the validator sets the consumer headers are matched against.
+    let provider_heights: Vec<_> = (0..=PROVIDER_VALIDATOR_SET_WINDOW as u64)
+        .map(|i| client_height.sub(i).unwrap())
+        .collect();
+
+    // Both the consumer chain and the provider chain are signed by the
+    // default validators of the synthetic Tendermint chains.
+    let ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(consumer_client_id.clone())
+                .latest_height(client_height)
+                .client_type(tm_client_type())
+                .build(),
+        )
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(provider_chain_id)
+                .client_id(provider_client_id.clone())
+                .latest_height(client_height)
+                .consensus_state_heights(provider_heights.clone())
+                .client_type(tm_client_type())
+                .build(),
+        );
+
+    {
+        let mut ibc_store = ctx_a.ibc_store.lock();
+
+        ibc_store
+            .provider_clients
+            .insert(consumer_client_id.clone(), provider_client_id.clone());
+
+        // The validator sets of the latest provider consensus states are
+        // replaced by other ones.
+        for height in provider_heights
+            .iter()
+            .take(changed_provider_validator_sets)
+        {
+            let consensus_state = ibc_store
+                .clients
+                .get_mut(&provider_client_id)
+                .unwrap()
+                .consensus_states
+                .get_mut(height)
+                .unwrap();
+
+            let tm_consensus_state = match consensus_state {
+                AnyConsensusState::Tendermint(tm_consensus_state) => tm_consensus_state,
+                _ => panic!("unexpected consensus state type"),
+            };
+            let inner = tm_consensus_state.inner().clone();
+
+            *consensus_state = TmConsensusState::from(TmConsensusStateType::new(
+                inner.root,
+                inner.timestamp,
+                Hash::Sha256([0; 32]),
+            ))
+            .into();
+        }
+    }
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: consumer_client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx_a, &router_a, MsgEnvelope::from(ClientMsg::from(msg)));
+    assert_eq!(res.is_ok(), expect_ok, "{res:?}");
+}
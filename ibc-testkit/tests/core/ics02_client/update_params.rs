@@ -0,0 +1,130 @@
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::handler::update_params;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgUpdateParams, UPDATE_CLIENT_PARAMS_TYPE_URL,
+};
+use ibc::core::client::types::{ClientParams, Height};
+use ibc::core::entrypoint::{dispatch, dispatch_raw, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::Signer;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn gov_authority() -> Signer {
+    "gov".to_string().into()
+}
+
+fn tendermint_only() -> MsgUpdateParams {
+    MsgUpdateParams {
+        params: ClientParams::new(vec![tm_client_type().as_str().to_string()])
+            .expect("valid params"),
+        signer: gov_authority(),
+    }
+}
+
+#[test]
+fn test_update_client_params_ok() {
+    let mut ctx = MockContext::default().with_params_authority(gov_authority());
+
+    let msg = tendermint_only();
+
+    update_params::validate(&ctx, &msg).expect("validation happy path");
+    update_params::execute(&mut ctx, msg.clone()).expect("execution happy path");
+
+    assert_eq!(ctx.client_params().unwrap(), msg.params);
+
+    // Clients of a type no longer allowed cannot be created.
+    let height = Height::new(0, 42).unwrap();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    )));
+
+    assert!(matches!(
+        validate(&ctx, &MockRouter::new_with_transfer(), msg_envelope),
+        Err(ContextError::ClientError(
+            ClientError::ClientTypeNotAllowed { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_update_client_params_unauthorized() {
+    let msg = tendermint_only();
+
+    // The params cannot be updated without authority.
+    assert!(matches!(
+        update_params::validate(&MockContext::default(), &msg),
+        Err(ContextError::ClientError(
+            ClientError::UnauthorizedParamsUpdate { .. }
+        ))
+    ));
+
+    let ctx = MockContext::default().with_params_authority(dummy_account_id());
+
+    assert!(matches!(
+        update_params::validate(&ctx, &msg),
+        Err(ContextError::ClientError(
+            ClientError::UnauthorizedParamsUpdate { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_update_client_params_dispatch() {
+    let mut ctx = MockContext::default().with_params_authority(gov_authority());
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = tendermint_only();
+
+    dispatch(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg.clone())),
+    )
+    .expect("dispatch happy path");
+
+    assert_eq!(ctx.client_params().unwrap(), msg.params);
+
+    // The message is also routed from its protobuf encoding.
+    let mut ctx = MockContext::default().with_params_authority(gov_authority());
+
+    dispatch_raw(
+        &mut ctx,
+        &mut router,
+        UPDATE_CLIENT_PARAMS_TYPE_URL,
+        &msg.clone().encode_vec(),
+    )
+    .expect("raw dispatch happy path");
+
+    assert_eq!(ctx.client_params().unwrap(), msg.params);
+
+    // Dispatching still requires the params authority.
+    let mut ctx = MockContext::default();
+
+    assert!(matches!(
+        dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()),
+        Err(ContextError::ClientError(
+            ClientError::UnauthorizedParamsUpdate { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_client_params_validation() {
+    assert!(ClientParams::new(vec![" ".to_string()]).is_err());
+    assert!(ClientParams::new(vec!["07-tendermint".to_string(); 2]).is_err());
+
+    let params = ClientParams::default();
+    assert!(params.is_allowed(&tm_client_type()));
+}
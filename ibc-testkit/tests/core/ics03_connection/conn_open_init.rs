@@ -144,3 +144,41 @@ fn conn_open_init_with_counterparty_conn_id() {
     let expected_version = vec![fxt.msg.version.clone().unwrap()];
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
+
+#[test]
+fn conn_open_init_custom_versions() {
+    let custom_version = Version::new(
+        "1",
+        vec![
+            "ORDER_ORDERED".to_string(),
+            "ORDER_UNORDERED".to_string(),
+            "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+        ],
+    )
+    .unwrap();
+
+    let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::NoVersion);
+    fxt.ctx.ibc_store.lock().connection_versions = Some(vec![custom_version.clone()]);
+
+    conn_open_init_validate(&fxt, Expect::Success);
+    conn_open_init_execute(&mut fxt, Expect::Success, vec![custom_version.clone()]);
+
+    let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::Default);
+    fxt.msg.version = Some(custom_version.clone());
+
+    // The custom version is not supported by the default host.
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+
+    fxt.ctx.ibc_store.lock().connection_versions = Some(vec![custom_version.clone()]);
+
+    conn_open_init_validate(&fxt, Expect::Success);
+    conn_open_init_execute(&mut fxt, Expect::Success, vec![custom_version]);
+}
+
+#[test]
+fn conn_open_init_no_supported_version() {
+    let fxt = conn_open_init_fixture(Ctx::WithClient, Msg::NoVersion);
+    fxt.ctx.ibc_store.lock().connection_versions = Some(vec![]);
+
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+}
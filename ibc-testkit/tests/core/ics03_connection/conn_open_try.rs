@@ -1,9 +1,11 @@
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
+use ibc::core::connection::types::version::Version;
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_try;
@@ -148,3 +150,67 @@ fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+#[test]
+fn conn_open_try_forbidden_feature() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx
+        .ibc_store
+        .lock()
+        .forbidden_connection_features
+        .push("ORDER_ORDERED".to_string());
+
+    conn_open_try_validate(&fxt, Expect::Success);
+    conn_open_try_execute(&mut fxt, Expect::Success);
+
+    let conn_end = ValidationContext::connection_end(&fxt.ctx, &ConnectionId::new(0)).unwrap();
+    assert_eq!(
+        conn_end.versions(),
+        &[Version::new("1", vec!["ORDER_UNORDERED".to_string()]).unwrap()]
+    );
+}
+
+#[test]
+fn conn_open_try_all_features_forbidden() {
+    let fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx.ibc_store.lock().forbidden_connection_features =
+        vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()];
+
+    conn_open_try_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn conn_open_try_custom_versions() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    let custom_version = Version::new(
+        "1",
+        vec![
+            "ORDER_UNORDERED".to_string(),
+            "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+        ],
+    )
+    .unwrap();
+    fxt.ctx.ibc_store.lock().connection_versions = Some(vec![custom_version]);
+
+    conn_open_try_validate(&fxt, Expect::Success);
+    conn_open_try_execute(&mut fxt, Expect::Success);
+
+    // Only the features supported by both the host and the counterparty are
+    // kept.
+    let conn_end = ValidationContext::connection_end(&fxt.ctx, &ConnectionId::new(0)).unwrap();
+    assert_eq!(
+        conn_end.versions(),
+        &[Version::new("1", vec!["ORDER_UNORDERED".to_string()]).unwrap()]
+    );
+}
+
+#[test]
+fn conn_open_try_no_common_version() {
+    let fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx.ibc_store.lock().connection_versions =
+        Some(vec![
+            Version::new("2", vec!["ORDER_ORDERED".to_string()]).unwrap()
+        ]);
+
+    conn_open_try_validate(&fxt, Expect::Failure(None));
+}
@@ -0,0 +1,158 @@
+use core::time::Duration;
+
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::Height;
+use ibc::core::connection::delay::verify_conn_delay_passed;
+use ibc::core::connection::handler::update_params;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::msgs::{
+    ConnectionMsg, MsgUpdateParams, UPDATE_CONNECTION_PARAMS_TYPE_URL,
+};
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{ConnectionEnd, ConnectionParams, Counterparty, State};
+use ibc::core::entrypoint::{dispatch, dispatch_raw};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+#[test]
+fn test_update_connection_params() {
+    let authority: Signer = "gov".to_string().into();
+
+    let msg = MsgUpdateParams {
+        params: ConnectionParams::new(Duration::from_secs(6)),
+        signer: authority.clone(),
+    };
+
+    assert!(matches!(
+        update_params::validate(&MockContext::default(), &msg),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UnauthorizedParamsUpdate { .. }
+        ))
+    ));
+
+    let mut ctx = MockContext::default().with_params_authority(authority);
+
+    update_params::validate(&ctx, &msg).expect("validation happy path");
+    update_params::execute(&mut ctx, msg).expect("execution happy path");
+
+    assert_eq!(
+        ctx.connection_params().unwrap(),
+        ConnectionParams::new(Duration::from_secs(6))
+    );
+    // The block delays are computed with the updated params.
+    assert_eq!(ctx.block_delay(&Duration::from_secs(13)).unwrap(), 3);
+}
+
+#[test]
+fn test_update_connection_params_dispatch() {
+    let authority: Signer = "gov".to_string().into();
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateParams {
+        params: ConnectionParams::new(Duration::from_secs(6)),
+        signer: authority.clone(),
+    };
+
+    assert!(matches!(
+        dispatch(
+            &mut MockContext::default(),
+            &mut router,
+            ConnectionMsg::from(msg.clone()).into(),
+        ),
+        Err(ContextError::ConnectionError(
+            ConnectionError::UnauthorizedParamsUpdate { .. }
+        ))
+    ));
+
+    let mut ctx = MockContext::default().with_params_authority(authority.clone());
+
+    dispatch(
+        &mut ctx,
+        &mut router,
+        ConnectionMsg::from(msg.clone()).into(),
+    )
+    .expect("dispatch happy path");
+
+    assert_eq!(ctx.connection_params().unwrap(), msg.params);
+
+    // The message is also routed from its protobuf encoding.
+    let mut ctx = MockContext::default().with_params_authority(authority);
+
+    dispatch_raw(
+        &mut ctx,
+        &mut router,
+        UPDATE_CONNECTION_PARAMS_TYPE_URL,
+        &msg.clone().encode_vec(),
+    )
+    .expect("raw dispatch happy path");
+
+    assert_eq!(ctx.connection_params().unwrap(), msg.params);
+}
+
+#[test]
+fn test_sub_second_block_delay_from_params() {
+    let authority: Signer = "gov".to_string().into();
+
+    let proof_height = Height::new(0, 1).unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(proof_height)
+                .build(),
+        )
+        .with_params_authority(authority.clone())
+        .with_height(Height::new(0, 20).unwrap());
+
+    update_params::execute(
+        &mut ctx,
+        MsgUpdateParams {
+            params: ConnectionParams::new(Duration::from_millis(100)),
+            signer: authority,
+        },
+    )
+    .expect("execution happy path");
+
+    let conn_end = ConnectionEnd::new(
+        State::Open,
+        ClientId::default(),
+        Counterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        Duration::from_secs(1),
+    )
+    .unwrap();
+
+    let host_height = ctx.host_height().unwrap();
+
+    let mut verify_after_update_at = |update_height: Height| {
+        ctx.get_client_execution_context()
+            .store_update_meta(
+                ClientId::default(),
+                proof_height,
+                Timestamp::from_nanoseconds(1).unwrap(),
+                update_height,
+            )
+            .unwrap();
+
+        verify_conn_delay_passed(&ctx, proof_height, &conn_end)
+    };
+
+    // The delay period of one second spans ten blocks of 100 milliseconds.
+    assert!(matches!(
+        verify_after_update_at(host_height.sub(9).unwrap()),
+        Err(ContextError::ConnectionError(
+            ConnectionError::NotEnoughBlocksElapsed { .. }
+        ))
+    ));
+    assert!(verify_after_update_at(host_height.sub(10).unwrap()).is_ok());
+}
@@ -12,7 +12,8 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -217,6 +218,7 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
             packet_commitment,
         );
 
+    let relayer = msg.signer.clone();
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_envelope);
@@ -230,7 +232,10 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
         ibc_events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
+    assert!(matches!(
+        &ibc_events[1],
+        IbcEvent::AcknowledgePacket(event) if event.relayer() == Some(&relayer)
+    ));
 }
 
 #[rstest]
@@ -273,3 +278,56 @@ fn ack_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
+
+#[rstest]
+fn ack_execute_reports_packet_latency(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let commitment_path = CommitmentPath::new(
+        &msg.packet.port_id_on_a,
+        &msg.packet.chan_id_on_a,
+        msg.packet.seq_on_a,
+    );
+    let sent_at = ctx.host_timestamp().unwrap();
+    ctx.store_packet_send_timestamp(&commitment_path, sent_at)
+        .unwrap();
+
+    ctx.advance_host_chain_height();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    let event = match ctx.get_events()[1].clone() {
+        IbcEvent::AcknowledgePacket(event) => event,
+        _ => panic!("expected an acknowledge packet event"),
+    };
+    assert_eq!(event.latency(), Some(ctx.block_time));
+    assert_eq!(
+        ctx.get_packet_send_timestamp(&commitment_path).unwrap(),
+        None
+    );
+}
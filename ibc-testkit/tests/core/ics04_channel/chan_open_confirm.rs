@@ -1,3 +1,4 @@
+use ibc::core::channel::handler::{execute_chan_open_confirm, validate_chan_open_confirm};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenConfirm};
 use ibc::core::channel::types::Version;
@@ -10,6 +11,8 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_confirm;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
@@ -228,3 +231,46 @@ fn chan_open_confirm_fail_channel_wrong_state(fixture: Fixture) {
         "Validation fails because channel is in the wrong state"
     )
 }
+
+#[rstest]
+fn chan_open_confirm_standalone_functions(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_end_on_b,
+        proof_height,
+        ..
+    } = fixture;
+
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id_on_b.clone())
+                .latest_height(Height::new(0, proof_height).unwrap())
+                .build(),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            ChannelId::default(),
+            chan_end_on_b,
+        );
+
+    validate_chan_open_confirm(&context, &msg).expect("validation happy path");
+    execute_chan_open_confirm(&mut context, &msg).expect("execution happy path");
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(
+            &msg.port_id_on_b,
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_b.state(), &State::Open);
+
+    let ibc_events = context.get_events();
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(ibc_events[1], IbcEvent::OpenConfirmChannel(_)));
+}
@@ -1,12 +1,19 @@
 use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::handler::{execute_chan_open_init, validate_chan_open_init};
+use ibc::core::channel::types::channel::State;
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::error::IdentifierError;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::types::validate::{IdentifierLengthPolicy, LengthLimits};
 use ibc::core::host::ValidationContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
@@ -84,6 +91,66 @@ fn chan_open_init_validate_counterparty_chan_id_set(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn chan_open_init_validate_forbidden_port(fixture: Fixture) {
+    let Fixture {
+        ctx, router, msg, ..
+    } = fixture;
+
+    let ctx = ctx.with_forbidden_port_id(PortId::transfer());
+
+    let res = validate(&ctx, &router, msg);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::InvalidIdentifier(
+                IdentifierError::ForbiddenIdentifier { .. }
+            )))
+        ),
+        "Validation fails because the host policy forbids the port, got {res:?}"
+    )
+}
+
+#[rstest]
+fn chan_open_init_validate_counterparty_port_length(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let mut raw_msg = dummy_raw_msg_chan_open_init(None);
+    let counterparty = raw_msg
+        .channel
+        .as_mut()
+        .and_then(|c| c.counterparty.as_mut());
+    counterparty.expect("has a counterparty").port_id = format!("wasm.{}", "a".repeat(59));
+
+    let msg = MsgChannelOpenInit::try_from(raw_msg).expect("decodes the port");
+    let msg = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds within the ICS-24 port limit, got {res:?}"
+    );
+
+    let ctx = ctx.with_identifier_lengths(IdentifierLengthPolicy {
+        port: LengthLimits::new(2, 32).unwrap(),
+        ..Default::default()
+    });
+
+    let res = validate(&ctx, &router, msg);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::InvalidIdentifier(
+                IdentifierError::InvalidLength { .. }
+            )))
+        ),
+        "Validation fails because the host limits the ports to 32 characters, got {res:?}"
+    )
+}
+
 #[rstest]
 fn chan_open_init_execute_happy_path(fixture: Fixture) {
     let Fixture {
@@ -121,3 +188,30 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_standalone_functions(fixture: Fixture) {
+    let Fixture { mut ctx, .. } = fixture;
+
+    let msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+
+    validate_chan_open_init(&ctx, &msg).expect("validation happy path");
+    execute_chan_open_init(&mut ctx, &msg, msg.version_proposal.clone())
+        .expect("execution happy path");
+
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &ChannelId::new(0)))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Init);
+    assert_eq!(ctx.channel_counter().unwrap(), 1);
+
+    // Only the core events are emitted, as the application module is not
+    // called into.
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::OpenInitChannel(_)));
+}
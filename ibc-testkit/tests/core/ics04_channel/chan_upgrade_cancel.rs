@@ -0,0 +1,226 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeCancel};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_cancel;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+struct Fixture {
+    ctx: MockContext,
+    router: MockRouter,
+    msg: MsgChannelUpgradeCancel,
+    chan_end_on_a: ChannelEnd,
+}
+
+/// The upgrade sequence of the channel on chain A.
+const UPGRADE_SEQUENCE: u64 = 2;
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_height = Height::new(0, 2).unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeCancel::try_from(dummy_raw_msg_chan_upgrade_cancel(
+        UPGRADE_SEQUENCE + 1,
+        client_height.revision_height(),
+    ))
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Flushing,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let upgrade = Upgrade {
+        fields: UpgradeFields {
+            ordering: Order::Ordered,
+            connection_hops: vec![ConnectionId::default()],
+            version: Version::new("ics20-2".to_string()),
+        },
+        timeout: UpgradeTimeout {
+            height: TimeoutHeight::At(Height::new(0, 1000).unwrap()),
+            timestamp: Timestamp::none(),
+        },
+        next_sequence_send: Sequence::from(1),
+    };
+
+    let ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_channel_upgrade(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            upgrade,
+            UPGRADE_SEQUENCE,
+        );
+
+    Fixture {
+        ctx,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end_on_a,
+    }
+}
+
+impl Fixture {
+    fn with_channel(self) -> (MockContext, MockRouter, MsgChannelUpgradeCancel) {
+        let ctx = self.ctx.with_channel(
+            self.msg.port_id_on_a.clone(),
+            self.msg.chan_id_on_a.clone(),
+            self.chan_end_on_a,
+        );
+
+        (ctx, self.router, self.msg)
+    }
+}
+
+fn assert_cancelled(ctx: &MockContext, msg: &MsgChannelUpgradeCancel, upgrade_sequence: u64) {
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+
+    let chan_end_on_a = ctx.channel_end(&chan_end_path_on_a).unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+    assert_eq!(
+        ctx.channel_upgrade_sequence(&chan_end_path_on_a).unwrap(),
+        upgrade_sequence
+    );
+
+    let upgrade = ctx
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+    assert!(upgrade.is_none());
+
+    let error_receipt = ctx
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_receipt.upgrade_sequence, upgrade_sequence);
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeCancelChannel(_)));
+}
+
+#[rstest]
+fn chan_upgrade_cancel_fail_stale_error_receipt(mut fixture: Fixture) {
+    fixture.msg.error_receipt_on_b.upgrade_sequence = UPGRADE_SEQUENCE - 1;
+    let (ctx, router, msg) = fixture.with_channel();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the error receipt is of a previous upgrade attempt"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_cancel_fail_flush_complete(mut fixture: Fixture) {
+    fixture.chan_end_on_a.set_state(State::FlushComplete);
+    let (ctx, router, msg) = fixture.with_channel();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel flushed all its packets"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_cancel_fail_missing_proof(mut fixture: Fixture) {
+    fixture.msg.proof_error_receipt_on_b = None;
+    let (ctx, router, msg) = fixture.with_channel();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because only the authority may omit the proof"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_cancel_success(fixture: Fixture) {
+    let (mut ctx, mut router, msg) = fixture.with_channel();
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path: {res:?}");
+
+    // The upgrade sequence is fast-forwarded to the one of the counterparty.
+    assert_cancelled(&ctx, &msg, UPGRADE_SEQUENCE + 1);
+}
+
+#[rstest]
+fn chan_upgrade_cancel_by_authority(mut fixture: Fixture) {
+    fixture.chan_end_on_a.set_state(State::FlushComplete);
+    fixture.msg.error_receipt_on_b.upgrade_sequence = 0;
+    fixture.msg.proof_error_receipt_on_b = None;
+    let (ctx, mut router, msg) = fixture.with_channel();
+    let mut ctx = ctx.with_params_authority(msg.signer.clone());
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "The authority cancels upgrades unconditionally: {res:?}"
+    );
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path: {res:?}");
+
+    assert_cancelled(&ctx, &msg, UPGRADE_SEQUENCE);
+}
@@ -0,0 +1,238 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTimeout};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_timeout;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+struct Fixture {
+    ctx: MockContext,
+    router: MockRouter,
+    msg: MsgChannelUpgradeTimeout,
+    chan_end_on_a: ChannelEnd,
+    upgrade: Upgrade,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_height = Height::new(0, 2).unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a);
+
+    // The counterparty is still flushing its packets at the proof height.
+    let msg = MsgChannelUpgradeTimeout::try_from(dummy_raw_msg_chan_upgrade_timeout(
+        State::Flushing as i32,
+        client_height.revision_height(),
+    ))
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Flushing,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let upgrade = Upgrade {
+        fields: UpgradeFields {
+            ordering: Order::Ordered,
+            connection_hops: vec![ConnectionId::default()],
+            version: Version::new("ics20-2".to_string()),
+        },
+        timeout: UpgradeTimeout {
+            height: TimeoutHeight::At(Height::new(0, 1).unwrap()),
+            timestamp: Timestamp::none(),
+        },
+        next_sequence_send: Sequence::from(1),
+    };
+
+    Fixture {
+        ctx,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end_on_a,
+        upgrade,
+    }
+}
+
+impl Fixture {
+    fn upgrading_ctx(self) -> (MockContext, MockRouter, MsgChannelUpgradeTimeout) {
+        let ctx = self
+            .ctx
+            .with_channel(
+                self.msg.port_id_on_a.clone(),
+                self.msg.chan_id_on_a.clone(),
+                self.chan_end_on_a,
+            )
+            .with_channel_upgrade(
+                self.msg.port_id_on_a.clone(),
+                self.msg.chan_id_on_a.clone(),
+                self.upgrade,
+                1,
+            );
+
+        (ctx, self.router, self.msg)
+    }
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_not_upgrading(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        mut chan_end_on_a,
+        upgrade,
+    } = fixture;
+    chan_end_on_a.set_state(State::Open);
+
+    let ctx = ctx
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            upgrade,
+            1,
+        );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is not upgrading"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_no_upgrade(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        chan_end_on_a,
+        ..
+    } = fixture;
+    let ctx = ctx.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because no upgrade is in progress"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_not_elapsed(mut fixture: Fixture) {
+    fixture.upgrade.timeout.height = TimeoutHeight::At(Height::new(0, 1000).unwrap());
+    let (ctx, router, msg) = fixture.upgrading_ctx();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade timeout has not elapsed on the counterparty"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_counterparty_flush_complete(mut fixture: Fixture) {
+    fixture.msg.chan_end_on_b.set_state(State::FlushComplete);
+    let (ctx, router, msg) = fixture.upgrading_ctx();
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty flushed all its packets"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_success(fixture: Fixture) {
+    let (mut ctx, mut router, msg) = fixture.upgrading_ctx();
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path: {res:?}");
+
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+
+    let upgrade = ctx
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+    assert!(upgrade.is_none());
+
+    let error_receipt = ctx
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap()
+        .unwrap();
+    assert_eq!(error_receipt.upgrade_sequence, 1);
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTimeoutChannel(_)));
+}
@@ -5,6 +5,8 @@ pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod chan_upgrade_cancel;
+pub mod chan_upgrade_timeout;
 pub mod recv_packet;
 pub mod send_packet;
 pub mod timeout;
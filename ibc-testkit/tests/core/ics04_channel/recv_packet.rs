@@ -1,4 +1,8 @@
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
@@ -12,8 +16,12 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::path::ReceiptPath;
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::*;
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::relayer::context::RelayerContext;
@@ -158,6 +166,50 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_fail_data_too_large(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        )
+        .with_max_packet_data_size(packet.data.len() - 1);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "recv_packet validation should fail when the packet data exceeds the maximum size"
+    )
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -177,7 +229,7 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
         chan_id_on_a: ChannelId::default(),
         port_id_on_b: PortId::transfer(),
         chan_id_on_b: ChannelId::default(),
-        data: Vec::new(),
+        data: Vec::new().into(),
         timeout_height_on_b: client_height.into(),
         timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
     };
@@ -230,6 +282,7 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         .with_connection(ConnectionId::default(), conn_end_on_b)
         .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b);
 
+    let relayer = msg.signer.clone();
     let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_env);
@@ -243,10 +296,191 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         &ibc_events[0],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+    assert!(matches!(
+        &ibc_events[1],
+        IbcEvent::ReceivePacket(event) if event.relayer() == Some(&relayer)
+    ));
     assert!(matches!(
         &ibc_events[2],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_execute_records_relayer(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b);
+
+    let receipt_path = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+    let relayer = msg.signer.clone();
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx, &mut router, msg_env).unwrap();
+
+    assert_eq!(
+        ctx.get_packet_receipt_relayer(&receipt_path).unwrap(),
+        Some(relayer)
+    );
+}
+
+/// A module whose `onRecvPacket` callback panics, e.g. on an arithmetic
+/// overflow of the application logic.
+#[derive(Debug)]
+struct PanickingModule;
+
+impl Module for PanickingModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        panic!("attempt to add with overflow")
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+/// With the `catch-unwind` feature, the panic of the module is turned into an
+/// error acknowledgement of the packet rather than unwinding through the
+/// dispatch.
+#[rstest]
+fn recv_packet_execute_panicking_module(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut router = MockRouter::default();
+    let module_id = ModuleId::new("panicking".to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router.add_route(module_id, PanickingModule).unwrap();
+
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b);
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_env);
+
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let expected_ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("application module panicked").unwrap())
+            .into();
+
+    assert!(ctx.get_events().iter().any(|event| matches!(
+        event,
+        IbcEvent::WriteAcknowledgement(event) if event.acknowledgement() == &expected_ack
+    )));
+}
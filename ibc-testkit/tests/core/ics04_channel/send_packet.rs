@@ -60,14 +60,14 @@ fn send_packet_processing() {
             .try_into()
             .unwrap();
     packet.seq_on_a = 1.into();
-    packet.data = vec![0];
+    packet.data = vec![0].into();
 
     let mut packet_with_timestamp_old: Packet =
         dummy_raw_packet(timeout_height_future, timestamp_ns_past)
             .try_into()
             .unwrap();
     packet_with_timestamp_old.seq_on_a = 1.into();
-    packet_with_timestamp_old.data = vec![0];
+    packet_with_timestamp_old.data = vec![0].into();
 
     let client_raw_height = 5;
     let packet_timeout_equal_client_height: Packet =
@@ -104,9 +104,49 @@ fn send_packet_processing() {
                     chan_end_on_a.clone(),
                 )
                 .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into()),
-            packet,
+            packet: packet.clone(),
+            want_pass: true,
+        },
+        Test {
+            name: "Packet data of the maximum size".to_string(),
+            ctx: context
+                .clone()
+                .with_client_config(
+                    MockClientConfig::builder()
+                        .latest_height(client_height)
+                        .build(),
+                )
+                .with_connection(ConnectionId::default(), conn_end_on_a.clone())
+                .with_channel(
+                    PortId::transfer(),
+                    ChannelId::default(),
+                    chan_end_on_a.clone(),
+                )
+                .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+                .with_max_packet_data_size(1),
+            packet: packet.clone(),
             want_pass: true,
         },
+        Test {
+            name: "Packet data exceeds the maximum size".to_string(),
+            ctx: context
+                .clone()
+                .with_client_config(
+                    MockClientConfig::builder()
+                        .latest_height(client_height)
+                        .build(),
+                )
+                .with_connection(ConnectionId::default(), conn_end_on_a.clone())
+                .with_channel(
+                    PortId::transfer(),
+                    ChannelId::default(),
+                    chan_end_on_a.clone(),
+                )
+                .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+                .with_max_packet_data_size(0),
+            packet,
+            want_pass: false,
+        },
         Test {
             name: "Packet timeout height same as destination chain height".to_string(),
             ctx: context
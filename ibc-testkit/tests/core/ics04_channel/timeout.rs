@@ -1,9 +1,15 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
+use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -11,10 +17,15 @@ use ibc::core::connection::types::{
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, ConnectionId, PortId};
+use ibc::core::host::types::path::Path;
 use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::*;
+use ibc::primitives::proto::Any;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -374,6 +385,7 @@ fn timeout_unordered_chan_execute(fixture: Fixture) {
             packet_commitment,
         );
 
+    let relayer = msg.signer.clone();
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_envelope);
@@ -388,7 +400,10 @@ fn timeout_unordered_chan_execute(fixture: Fixture) {
         ibc_events[0],
         IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+    assert!(matches!(
+        &ibc_events[1],
+        IbcEvent::TimeoutPacket(event) if event.relayer() == Some(&relayer)
+    ));
 }
 
 #[rstest]
@@ -437,3 +452,104 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+/// A mock client of a chain whose heights compare by their revision height
+/// only, e.g. a rollup using its batch number as revision number and the L1
+/// block as revision height.
+struct L1HeightClientState(MockClientState);
+
+impl ClientStateCommon for L1HeightClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        self.0.verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        self.0.client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        self.0.validate_proof_height(proof_height)
+    }
+
+    fn is_past_height(&self, height: Height, timeout_height: Height) -> bool {
+        height.revision_height() > timeout_height.revision_height()
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        self.0.verify_upgrade_client(
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            root,
+        )
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.0.verify_membership(prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.0.verify_non_membership(prefix, proof, root, path)
+    }
+}
+
+#[rstest]
+fn timeout_with_custom_height_comparison(fixture: Fixture) {
+    let Fixture { msg, .. } = fixture;
+
+    let proof_height = Height::new(1, 20).unwrap();
+    let mut packet = msg.packet;
+    packet.timeout_height_on_b = TimeoutHeight::At(Height::new(5, 10).unwrap());
+    packet.timeout_timestamp_on_b = Timestamp::none();
+
+    let mock_client_state = MockClientState::new(MockHeader::new(proof_height));
+    let l1_client_state = L1HeightClientState(mock_client_state);
+
+    // The heights compare as Tendermint ones by default, so that the packet
+    // has not timed out at a lower revision.
+    assert!(!mock_client_state.is_past_height(proof_height, Height::new(5, 10).unwrap()));
+    assert!(!packet.timed_out_with(
+        &Timestamp::none(),
+        proof_height,
+        |height, timeout_height| { mock_client_state.is_past_height(height, timeout_height) }
+    ));
+
+    // The packet has timed out past the L1 block of its timeout height.
+    assert!(packet.timed_out_with(
+        &Timestamp::none(),
+        proof_height,
+        |height, timeout_height| { l1_client_state.is_past_height(height, timeout_height) }
+    ));
+
+    packet.timeout_height_on_b = TimeoutHeight::At(Height::new(0, 20).unwrap());
+    assert!(!packet.timed_out_with(
+        &Timestamp::none(),
+        proof_height,
+        |height, timeout_height| { l1_client_state.is_past_height(height, timeout_height) }
+    ));
+}
@@ -0,0 +1,481 @@
+use core::cell::RefCell;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleExtras;
+use ibc::derive::Module as DeriveModule;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+
+/// A module recording the inherent callbacks the derived `Module`
+/// implementation delegates to.
+#[derive(Debug, Default, DeriveModule)]
+#[module(chan_open_ack, chan_open_confirm)]
+#[module(chan_close_init, chan_close_confirm)]
+struct RecordingModule {
+    calls: RefCell<Vec<&'static str>>,
+}
+
+impl RecordingModule {
+    fn record(&self, callback: &'static str) {
+        self.calls.borrow_mut().push(callback);
+    }
+
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.record("on_chan_open_init_validate");
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.record("on_chan_open_init_execute");
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.record("on_chan_open_try_validate");
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.record("on_chan_open_try_execute");
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.record("on_chan_open_ack_validate");
+        Ok(())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.record("on_chan_open_ack_execute");
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.record("on_chan_open_confirm_validate");
+        Ok(())
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.record("on_chan_open_confirm_execute");
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.record("on_chan_close_init_validate");
+        Ok(())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.record("on_chan_close_init_execute");
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.record("on_chan_close_confirm_validate");
+        Ok(())
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.record("on_chan_close_confirm_execute");
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        self.record("on_recv_packet_execute");
+        (
+            ModuleExtras::empty(),
+            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+        )
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.record("on_acknowledgement_packet_validate");
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.record("on_acknowledgement_packet_execute");
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.record("on_timeout_packet_validate");
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.record("on_timeout_packet_execute");
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+/// A module listing none of the optional callbacks, which keep the default
+/// implementations of the `Module` trait.
+#[derive(Debug, Default, DeriveModule)]
+struct DefaultCallbacksModule {
+    inner: RecordingModule,
+}
+
+impl DefaultCallbacksModule {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.inner.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.inner.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.inner.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.inner.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        self.inner.on_recv_packet_execute(packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.inner
+            .on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.inner
+            .on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.inner.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.inner.on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+/// Invokes every callback of the given module through the `Module` trait.
+fn invoke_all_callbacks(module: &mut dyn Module) {
+    let order = Order::Unordered;
+    let connection_hops = [ConnectionId::default()];
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::default();
+    let counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::default()));
+    let version = Version::new("ics20-1".to_string());
+    let packet = Packet::try_from(dummy_raw_msg_recv_packet(10).packet.unwrap()).unwrap();
+    let ack = Acknowledgement::try_from(vec![1u8]).unwrap();
+    let relayer = dummy_account_id();
+
+    module
+        .on_chan_open_init_validate(
+            order,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+    module
+        .on_chan_open_init_execute(
+            order,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+    module
+        .on_chan_open_try_validate(
+            order,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+    module
+        .on_chan_open_try_execute(
+            order,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+    module
+        .on_chan_open_ack_validate(&port_id, &channel_id, &version)
+        .unwrap();
+    module
+        .on_chan_open_ack_execute(&port_id, &channel_id, &version)
+        .unwrap();
+    module
+        .on_chan_open_confirm_validate(&port_id, &channel_id)
+        .unwrap();
+    module
+        .on_chan_open_confirm_execute(&port_id, &channel_id)
+        .unwrap();
+    module
+        .on_chan_close_init_validate(&port_id, &channel_id)
+        .unwrap();
+    module
+        .on_chan_close_init_execute(&port_id, &channel_id)
+        .unwrap();
+    module
+        .on_chan_close_confirm_validate(&port_id, &channel_id)
+        .unwrap();
+    module
+        .on_chan_close_confirm_execute(&port_id, &channel_id)
+        .unwrap();
+    module.on_recv_packet_execute(&packet, &relayer);
+    module
+        .on_acknowledgement_packet_validate(&packet, &ack, &relayer)
+        .unwrap();
+    module
+        .on_acknowledgement_packet_execute(&packet, &ack, &relayer)
+        .1
+        .unwrap();
+    module
+        .on_timeout_packet_validate(&packet, &relayer)
+        .unwrap();
+    module
+        .on_timeout_packet_execute(&packet, &relayer)
+        .1
+        .unwrap();
+}
+
+const REQUIRED_CALLBACKS: [&str; 4] = [
+    "on_chan_open_init_validate",
+    "on_chan_open_init_execute",
+    "on_chan_open_try_validate",
+    "on_chan_open_try_execute",
+];
+
+const PACKET_CALLBACKS: [&str; 5] = [
+    "on_recv_packet_execute",
+    "on_acknowledgement_packet_validate",
+    "on_acknowledgement_packet_execute",
+    "on_timeout_packet_validate",
+    "on_timeout_packet_execute",
+];
+
+#[test]
+fn test_derived_module_delegates_every_callback() {
+    let mut module = RecordingModule::default();
+
+    invoke_all_callbacks(&mut module);
+
+    let expected: Vec<_> = REQUIRED_CALLBACKS
+        .into_iter()
+        .chain([
+            "on_chan_open_ack_validate",
+            "on_chan_open_ack_execute",
+            "on_chan_open_confirm_validate",
+            "on_chan_open_confirm_execute",
+            "on_chan_close_init_validate",
+            "on_chan_close_init_execute",
+            "on_chan_close_confirm_validate",
+            "on_chan_close_confirm_execute",
+        ])
+        .chain(PACKET_CALLBACKS)
+        .collect();
+
+    assert_eq!(module.calls.into_inner(), expected);
+}
+
+#[test]
+fn test_derived_module_keeps_default_optional_callbacks() {
+    let mut module = DefaultCallbacksModule::default();
+
+    invoke_all_callbacks(&mut module);
+
+    let expected: Vec<_> = REQUIRED_CALLBACKS
+        .into_iter()
+        .chain(PACKET_CALLBACKS)
+        .collect();
+
+    assert_eq!(module.inner.calls.into_inner(), expected);
+}
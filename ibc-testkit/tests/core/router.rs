@@ -8,10 +8,12 @@ use ibc::core::channel::types::msgs::{
     MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeoutOnClose, PacketMsg,
 };
 use ibc::core::channel::types::timeout::TimeoutHeight;
-use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgUpdateClient, CREATE_CLIENT_TYPE_URL,
+};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::ConnectionMsg;
-use ibc::core::entrypoint::dispatch;
+use ibc::core::entrypoint::{dispatch, dispatch_raw};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
@@ -19,7 +21,9 @@ use ibc::core::host::types::identifiers::ConnectionId;
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Protobuf;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::types::error::RouterError;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
 };
@@ -50,7 +54,7 @@ fn routing_module_and_keepers() {
     #[derive(Clone, Debug)]
     enum TestMsg {
         Ics26(MsgEnvelope),
-        Ics20(MsgTransfer),
+        Ics20(Box<MsgTransfer>),
     }
 
     impl From<MsgEnvelope> for TestMsg {
@@ -61,7 +65,7 @@ fn routing_module_and_keepers() {
 
     impl From<MsgTransfer> for TestMsg {
         fn from(msg: MsgTransfer) -> Self {
-            Self::Ics20(msg)
+            Self::Ics20(Box::new(msg))
         }
     }
 
@@ -169,7 +173,7 @@ fn routing_module_and_keepers() {
     let packet_data = serde_json::to_vec(&msg_transfer_two.packet_data)
         .expect("PacketData's infallible Serialize impl failed");
 
-    msg_to_on_close.packet.data = packet_data;
+    msg_to_on_close.packet.data = packet_data.into();
 
     let msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap();
     let msg_ack_packet = MsgAcknowledgement::try_from(dummy_raw_msg_ack_with_packet(
@@ -404,7 +408,7 @@ fn routing_module_and_keepers() {
     for test in tests {
         let res = match test.msg.clone() {
             TestMsg::Ics26(msg) => dispatch(&mut ctx, &mut router, msg).map(|_| ()),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx, &mut DummyTransferModule, msg)
+            TestMsg::Ics20(msg) => send_transfer(&mut ctx, &mut DummyTransferModule, *msg)
                 .map_err(|e: TokenTransferError| ChannelError::AppModule {
                     description: e.to_string(),
                 })
@@ -432,3 +436,48 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn dispatch_raw_create_client() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_height = Height::new(0, 5).unwrap();
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(client_height).with_current_timestamp()).into(),
+        MockConsensusState::new(MockHeader::new(client_height).with_current_timestamp()).into(),
+        dummy_account_id(),
+    );
+
+    let res = dispatch_raw(
+        &mut ctx,
+        &mut router,
+        CREATE_CLIENT_TYPE_URL,
+        &msg.encode_vec(),
+    );
+
+    assert!(res.is_ok(), "raw client creation failed: {res:?}");
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+}
+
+#[test]
+fn dispatch_raw_rejects_undecodable_messages() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = dispatch_raw(&mut ctx, &mut router, "/unknown.MsgType", &[]);
+    assert!(matches!(
+        res,
+        Err(ContextError::RouterError(
+            RouterError::UnknownMessageTypeUrl { .. }
+        ))
+    ));
+
+    let res = dispatch_raw(&mut ctx, &mut router, CREATE_CLIENT_TYPE_URL, &[0xff]);
+    assert!(matches!(
+        res,
+        Err(ContextError::RouterError(
+            RouterError::MalformedMessageBytes { .. }
+        ))
+    ));
+}
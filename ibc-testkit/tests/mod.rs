@@ -8,3 +8,4 @@
 )]
 pub mod applications;
 pub mod core;
+pub mod query;
@@ -0,0 +1,82 @@
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ClientStatePath, ConnectionPath};
+use ibc::core::primitives::prelude::*;
+use ibc::cosmos_host::IBC_QUERY_PATH;
+use ibc::primitives::proto::Protobuf;
+use ibc_query::abci::{query_abci_store, AbciQueryRequest};
+use ibc_query::error::QueryError;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+#[fixture]
+fn ctx() -> MockContext {
+    MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .latest_height(Height::new(0, 3).unwrap())
+            .build(),
+    )
+}
+
+fn request(path: impl ToString, height: u64, prove: bool) -> AbciQueryRequest {
+    AbciQueryRequest {
+        path: IBC_QUERY_PATH.to_string(),
+        data: path.to_string().into_bytes(),
+        height,
+        prove,
+    }
+}
+
+#[rstest]
+fn abci_store_query(ctx: MockContext) {
+    let client_state_path = ClientStatePath::new(ClientId::default());
+
+    let response = query_abci_store(&ctx, &request(&client_state_path, 0, true)).unwrap();
+
+    assert_eq!(response.key, client_state_path.to_string().into_bytes());
+    assert_eq!(response.height, ctx.latest_height().revision_height());
+
+    let client_state = AnyClientState::decode_vec(&response.value).unwrap();
+
+    assert_eq!(client_state, ctx.latest_client_states(&ClientId::default()));
+
+    // The single existence proof of the mock host is the proof of the IBC
+    // store, keyed by the queried path.
+    assert_eq!(response.proof_ops.len(), 1);
+    assert_eq!(response.proof_ops[0].field_type, "ics23:iavl");
+    assert_eq!(response.proof_ops[0].key, response.key);
+}
+
+#[rstest]
+fn abci_store_query_of_missing_value(ctx: MockContext) {
+    let connection_path = ConnectionPath::new(&ConnectionId::default());
+
+    let response = query_abci_store(
+        &ctx,
+        &request(
+            connection_path,
+            ctx.latest_height().revision_height(),
+            false,
+        ),
+    )
+    .unwrap();
+
+    assert!(response.value.is_empty());
+    assert!(response.proof_ops.is_empty());
+}
+
+#[rstest]
+#[case::unsupported_path(AbciQueryRequest {
+    path: "store/bank/key".to_string(),
+    ..request(ClientStatePath::new(ClientId::default()), 0, false)
+})]
+#[case::past_height(request(ClientStatePath::new(ClientId::default()), 1, false))]
+#[case::not_an_ibc_path(request("balances/cosmos1", 0, false))]
+#[case::unsupported_ibc_path(request("ports/transfer", 0, false))]
+fn abci_store_query_invalid_request(ctx: MockContext, #[case] request: AbciQueryRequest) {
+    assert!(matches!(
+        query_abci_store(&ctx, &request),
+        Err(QueryError::InvalidRequest { .. })
+    ));
+}
@@ -0,0 +1,157 @@
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::core::channel::v1::{QueryChannelRequest, QueryPacketReceiptRequest};
+use ibc_proto::ibc::core::client::v1::QueryClientStateRequest;
+use ibc_proto::ibc::core::connection::v1::QueryConnectionRequest;
+use ibc_query::core::batch::{
+    BatchQuery, BatchQueryResponse, QueryBatchRequest, RawBatchQuery, RawQueryBatchRequest,
+    RawQueryBatchResponse, MAX_QUERIES_PER_BATCH,
+};
+use ibc_query::core::extension::{ExtensionQuery, ExtensionQueryServer, ExtensionQueryService};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use tonic::client::Grpc;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::{Code, Request};
+
+#[fixture]
+fn ctx() -> MockContext {
+    MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .latest_height(Height::new(0, 3).unwrap())
+            .build(),
+    )
+}
+
+#[fixture]
+fn service(ctx: MockContext) -> ExtensionQueryService<MockContext> {
+    ExtensionQueryService::new(ctx)
+}
+
+fn client_state_query() -> BatchQuery {
+    BatchQuery::ClientState(QueryClientStateRequest {
+        client_id: ClientId::default().to_string(),
+    })
+}
+
+fn batch_request(queries: Vec<BatchQuery>) -> Request<RawQueryBatchRequest> {
+    Request::new(QueryBatchRequest { queries }.into())
+}
+
+fn answers(response: RawQueryBatchResponse) -> Vec<BatchQueryResponse> {
+    response
+        .responses
+        .into_iter()
+        .map(|answer| answer.response.unwrap())
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn batch(ctx: MockContext) {
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let response: RawQueryBatchResponse = client
+        .unary(
+            batch_request(vec![
+                client_state_query(),
+                BatchQuery::PacketReceipt(QueryPacketReceiptRequest {
+                    port_id: PortId::transfer().to_string(),
+                    channel_id: ChannelId::default().to_string(),
+                    sequence: 1,
+                }),
+            ]),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/Batch"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    let height = response.height.clone();
+    let responses = answers(response);
+
+    assert_eq!(responses.len(), 2);
+    assert!(matches!(responses[0], BatchQueryResponse::ClientState(_)));
+    assert!(matches!(
+        &responses[1],
+        BatchQueryResponse::PacketReceipt(receipt) if !receipt.received
+    ));
+
+    // All the proofs are at the height of the batch.
+    assert!(responses
+        .iter()
+        .all(|answer| answer.proof_height() == height.as_ref()));
+}
+
+#[rstest]
+#[tokio::test]
+async fn batch_of_missing_values(service: ExtensionQueryService<MockContext>) {
+    for query in [
+        BatchQuery::Connection(QueryConnectionRequest {
+            connection_id: ConnectionId::default().to_string(),
+        }),
+        BatchQuery::Channel(QueryChannelRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::default().to_string(),
+        }),
+    ] {
+        // A failing sub-query fails the whole batch.
+        let status = service
+            .batch(batch_request(vec![client_state_query(), query]))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Internal);
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn batch_without_sub_query(service: ExtensionQueryService<MockContext>) {
+    let status = service
+        .batch(Request::new(RawQueryBatchRequest {
+            queries: vec![RawBatchQuery { query: None }],
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+#[tokio::test]
+async fn batch_size_bounds(service: ExtensionQueryService<MockContext>) {
+    let response = service
+        .batch(batch_request(vec![
+            client_state_query();
+            MAX_QUERIES_PER_BATCH
+        ]))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.responses.len(), MAX_QUERIES_PER_BATCH);
+
+    let status = service
+        .batch(batch_request(vec![
+            client_state_query();
+            MAX_QUERIES_PER_BATCH + 1
+        ]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    let response = service
+        .batch(batch_request(vec![]))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(response.responses.is_empty());
+}
@@ -0,0 +1,756 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelUpgradeErrorPath, ReceiptPath};
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::ibc::core::channel::v1::query_server::Query as ChannelQuery;
+use ibc_proto::ibc::core::channel::v1::{
+    QueryChannelClientStateRequest, QueryChannelConsensusStateRequest, QueryChannelRequest,
+    QueryChannelsRequest, QueryConnectionChannelsRequest, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceSendRequest, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
+    QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryUnreceivedAcksRequest,
+    QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+};
+use ibc_query::core::channel::{
+    query_unreceived_acks_chunked, query_unreceived_packets_chunked, ChannelQueryService,
+    PacketLifecycleStatus, PacketSequenceFilter, PacketStatus, QueryChannelUpgradeErrorRequest,
+    QueryChannelUpgradeErrorResponse, QueryChannelUpgradeRequest,
+    QueryFilteredPacketAcknowledgementsRequest, QueryFilteredPacketCommitmentsRequest,
+    QueryFilteredPacketCommitmentsResponse, QueryPacketStatusRequest, QueryPacketStatusResponse,
+    RawQueryFilteredPacketCommitmentsRequest,
+};
+use ibc_query::core::extension::{ExtensionQuery, ExtensionQueryServer, ExtensionQueryService};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use tonic::client::Grpc;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::codegen::tokio_stream::StreamExt;
+use tonic::{Code, Request};
+
+/// The number of channels of the fixture, all on the transfer port.
+const CHANNELS: u64 = 3;
+
+pub struct Fixture {
+    pub ctx: MockContext,
+    pub service: ChannelQueryService<MockContext>,
+    pub client_height: Height,
+    pub upgrade: Upgrade,
+}
+
+/// Sets up a context with three open channels over the same connection, the
+/// first one of them with packets 1 to 3 sent, packets 1 and 2 received and
+/// packet 1 acknowledged, and the second one in the middle of an upgrade.
+#[fixture]
+fn fixture() -> Fixture {
+    let client_height = Height::new(0, 3).unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let upgrade = Upgrade {
+        fields: UpgradeFields {
+            ordering: Order::Ordered,
+            connection_hops: vec![ConnectionId::default()],
+            version: Version::new("ics20-2".to_string()),
+        },
+        timeout: UpgradeTimeout {
+            height: TimeoutHeight::At(Height::new(0, 1000).unwrap()),
+            timestamp: Timestamp::none(),
+        },
+        next_sequence_send: Sequence::from(1),
+    };
+
+    let ctx = (0..CHANNELS).fold(
+        MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .latest_height(client_height)
+                    .build(),
+            )
+            .with_connection(ConnectionId::default(), conn_end),
+        |ctx, n| ctx.with_channel(PortId::transfer(), ChannelId::new(n), chan_end.clone()),
+    );
+
+    let mut ctx = (1..=3)
+        .fold(ctx, |ctx, seq| {
+            ctx.with_packet_commitment(
+                PortId::transfer(),
+                ChannelId::new(0),
+                Sequence::from(seq),
+                PacketCommitment::from(vec![seq as u8]),
+            )
+        })
+        .with_send_sequence(PortId::transfer(), ChannelId::new(0), 4.into())
+        .with_recv_sequence(PortId::transfer(), ChannelId::new(0), 1.into())
+        .with_ack_sequence(PortId::transfer(), ChannelId::new(0), 1.into())
+        .with_channel_upgrade(PortId::transfer(), ChannelId::new(1), upgrade.clone(), 1);
+
+    for seq in 1..=2 {
+        ctx.store_packet_receipt(
+            &ReceiptPath::new(&PortId::transfer(), &ChannelId::new(0), seq.into()),
+            Receipt::Ok,
+        )
+        .unwrap();
+    }
+
+    ctx.store_packet_acknowledgement(
+        &AckPath::new(&PortId::transfer(), &ChannelId::new(0), 1.into()),
+        AcknowledgementCommitment::from(vec![1]),
+    )
+    .unwrap();
+
+    Fixture {
+        service: ChannelQueryService::new(ctx.clone()),
+        ctx,
+        client_height,
+        upgrade,
+    }
+}
+
+fn page(key: Vec<u8>, offset: u64, limit: u64, reverse: bool) -> Option<PageRequest> {
+    Some(PageRequest {
+        key,
+        offset,
+        limit,
+        count_total: true,
+        reverse,
+    })
+}
+
+#[rstest]
+#[tokio::test]
+async fn channel(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let response = service
+        .channel(Request::new(QueryChannelRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(2).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.channel.unwrap().connection_hops,
+        [ConnectionId::default().to_string()]
+    );
+    assert!(!response.proof.is_empty());
+
+    let status = service
+        .channel(Request::new(QueryChannelRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(CHANNELS).to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
+
+#[rstest]
+#[tokio::test]
+async fn channels_pagination(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let first_page = service
+        .channels(Request::new(QueryChannelsRequest {
+            pagination: page(vec![], 0, CHANNELS - 1, true),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let channel_ids: Vec<_> = first_page
+        .channels
+        .iter()
+        .map(|channel| channel.channel_id.clone())
+        .collect();
+
+    assert_eq!(
+        channel_ids,
+        [ChannelId::new(2).to_string(), ChannelId::new(1).to_string()]
+    );
+
+    let first_pagination = first_page.pagination.unwrap();
+
+    assert_eq!(first_pagination.total, CHANNELS);
+
+    let last_page = service
+        .channels(Request::new(QueryChannelsRequest {
+            pagination: page(first_pagination.next_key, 0, CHANNELS - 1, true),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(last_page.channels.len(), 1);
+    assert_eq!(
+        last_page.channels[0].channel_id,
+        ChannelId::new(0).to_string()
+    );
+    assert!(last_page.pagination.unwrap().next_key.is_empty());
+
+    let connection_channels = service
+        .connection_channels(Request::new(QueryConnectionChannelsRequest {
+            connection: ConnectionId::default().to_string(),
+            pagination: page(vec![], CHANNELS - 1, 0, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(connection_channels.channels.len(), 1);
+    assert!(connection_channels.pagination.unwrap().next_key.is_empty());
+
+    let connection_channels = service
+        .connection_channels(Request::new(QueryConnectionChannelsRequest {
+            connection: ConnectionId::new(1).to_string(),
+            pagination: None,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(connection_channels.channels.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn channel_client_and_consensus_states(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_height,
+        ..
+    } = fixture;
+
+    let client_state = service
+        .channel_client_state(Request::new(QueryChannelClientStateRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        client_state.identified_client_state.unwrap().client_id,
+        ClientId::default().to_string()
+    );
+
+    let consensus_state = service
+        .channel_consensus_state(Request::new(QueryChannelConsensusStateRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            revision_number: client_height.revision_number(),
+            revision_height: client_height.revision_height(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(consensus_state.client_id, ClientId::default().to_string());
+    assert!(consensus_state.consensus_state.is_some());
+}
+
+#[rstest]
+#[tokio::test]
+async fn packet_commitments(fixture: Fixture) {
+    let Fixture { ctx, service, .. } = fixture;
+
+    let commitment = service
+        .packet_commitment(Request::new(QueryPacketCommitmentRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            sequence: 2,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(commitment.commitment, [2]);
+
+    let status = service
+        .packet_commitment(Request::new(QueryPacketCommitmentRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            sequence: 4,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+
+    let first_page = service
+        .packet_commitments(Request::new(QueryPacketCommitmentsRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            pagination: page(vec![], 0, 3, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let sequences: Vec<_> = first_page
+        .commitments
+        .iter()
+        .map(|commitment| commitment.sequence)
+        .collect();
+
+    assert_eq!(sequences, [1, 2, 3]);
+
+    // A page ending exactly at the last commitment has no cursor.
+    let first_pagination = first_page.pagination.unwrap();
+
+    assert_eq!(first_pagination.total, 3);
+    assert!(first_pagination.next_key.is_empty());
+
+    let empty_channel = service
+        .packet_commitments(Request::new(QueryPacketCommitmentsRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(1).to_string(),
+            pagination: page(vec![], 0, 3, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(empty_channel.commitments.is_empty());
+    assert_eq!(empty_channel.pagination.unwrap().total, 0);
+
+    let extension_service = ExtensionQueryService::new(ctx.clone());
+
+    let filtered = extension_service
+        .filtered_packet_commitments(Request::new(
+            QueryFilteredPacketCommitmentsRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: ChannelId::new(0).to_string(),
+                sequences: Some(PacketSequenceFilter::Range { start: 2, end: 7 }),
+            }
+            .into(),
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let sequences: Vec<_> = filtered
+        .commitments
+        .iter()
+        .map(|commitment| commitment.sequence)
+        .collect();
+
+    assert_eq!(sequences, [2, 3]);
+
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let filtered: QueryFilteredPacketCommitmentsResponse = client
+        .unary(
+            Request::new(RawQueryFilteredPacketCommitmentsRequest::from(
+                QueryFilteredPacketCommitmentsRequest {
+                    port_id: PortId::transfer().to_string(),
+                    channel_id: ChannelId::new(0).to_string(),
+                    sequences: Some(PacketSequenceFilter::List(vec![3, 5])),
+                },
+            )),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/FilteredPacketCommitments"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(filtered.commitments.len(), 1);
+    assert_eq!(filtered.commitments[0].sequence, 3);
+}
+
+#[rstest]
+#[tokio::test]
+async fn packet_receipts_and_acknowledgements(fixture: Fixture) {
+    let Fixture { ctx, service, .. } = fixture;
+
+    let receipt = |sequence| QueryPacketReceiptRequest {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::new(0).to_string(),
+        sequence,
+    };
+
+    let received = service
+        .packet_receipt(Request::new(receipt(2)))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(received.received);
+
+    let unreceived = service
+        .packet_receipt(Request::new(receipt(3)))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(!unreceived.received);
+
+    let ack = service
+        .packet_acknowledgement(Request::new(QueryPacketAcknowledgementRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            sequence: 1,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(ack.acknowledgement, [1]);
+
+    let status = service
+        .packet_acknowledgement(Request::new(QueryPacketAcknowledgementRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            sequence: 2,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+
+    let acks = service
+        .packet_acknowledgements(Request::new(QueryPacketAcknowledgementsRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            pagination: page(vec![], 1, 1, false),
+            packet_commitment_sequences: vec![],
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    // The offset skips the only acknowledgement of the channel.
+    assert!(acks.acknowledgements.is_empty());
+    assert!(acks.pagination.unwrap().next_key.is_empty());
+
+    let acks = ExtensionQueryService::new(ctx)
+        .filtered_packet_acknowledgements(Request::new(
+            QueryFilteredPacketAcknowledgementsRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: ChannelId::new(0).to_string(),
+                sequences: Some(PacketSequenceFilter::Range { start: 1, end: 3 }),
+            }
+            .into(),
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(acks.acknowledgements.len(), 1);
+    assert_eq!(acks.acknowledgements[0].sequence, 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn unreceived_packets_and_acks(fixture: Fixture) {
+    let Fixture { ctx, service, .. } = fixture;
+
+    let unreceived_packets = QueryUnreceivedPacketsRequest {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::new(0).to_string(),
+        packet_commitment_sequences: vec![1, 2, 3, 4, 5],
+    };
+
+    let response = service
+        .unreceived_packets(Request::new(unreceived_packets.clone()))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.sequences, [3, 4, 5]);
+
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(
+        ctx.clone(),
+    )));
+
+    client.ready().await.unwrap();
+
+    let responses: Vec<QueryUnreceivedPacketsResponse> = client
+        .server_streaming(
+            Request::new(unreceived_packets.clone()),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/UnreceivedPacketsStream"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].sequences, [3, 4, 5]);
+
+    // The chunks end exactly at the last sequence, and every chunk carries
+    // the height of the query.
+    let chunks: Vec<_> = query_unreceived_packets_chunked(ctx.clone(), &unreceived_packets, 2)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+
+    let chunked_sequences: Vec<_> = chunks.iter().map(|chunk| chunk.sequences.clone()).collect();
+
+    assert_eq!(chunked_sequences, [vec![3, 4], vec![5]]);
+    assert!(chunks.iter().all(|chunk| chunk.height == response.height));
+
+    let empty: Vec<_> = query_unreceived_packets_chunked(
+        ctx.clone(),
+        &QueryUnreceivedPacketsRequest {
+            packet_commitment_sequences: vec![1, 2],
+            ..unreceived_packets
+        },
+        2,
+    )
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+
+    assert_eq!(empty.len(), 1);
+    assert!(empty[0].sequences.is_empty());
+
+    let unreceived_acks = QueryUnreceivedAcksRequest {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::new(0).to_string(),
+        packet_ack_sequences: vec![1, 4],
+    };
+
+    let response = service
+        .unreceived_acks(Request::new(unreceived_acks.clone()))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.sequences, [1]);
+
+    let extension_service = ExtensionQueryService::new(ctx.clone());
+
+    let responses: Vec<_> = extension_service
+        .unreceived_acks_stream(Request::new(unreceived_acks.clone()))
+        .await
+        .unwrap()
+        .into_inner()
+        .collect()
+        .await;
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].as_ref().unwrap().sequences, [1]);
+
+    // Without any sequence, all the unreceived acknowledgements are listed,
+    // and then streamed in chunks.
+    let chunks: Vec<_> = query_unreceived_acks_chunked(
+        ctx,
+        &QueryUnreceivedAcksRequest {
+            packet_ack_sequences: vec![],
+            ..unreceived_acks
+        },
+        2,
+    )
+    .unwrap()
+    .map(Result::unwrap)
+    .map(|chunk| chunk.sequences)
+    .collect();
+
+    assert_eq!(chunks, [vec![1, 2], vec![3]]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn next_sequences(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let next_sequence_send = service
+        .next_sequence_send(Request::new(QueryNextSequenceSendRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(next_sequence_send.next_sequence_send, 4);
+
+    let next_sequence_receive = service
+        .next_sequence_receive(Request::new(QueryNextSequenceReceiveRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(next_sequence_receive.next_sequence_receive, 1);
+
+    let status = service
+        .next_sequence_send(Request::new(QueryNextSequenceSendRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(1).to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
+
+#[rstest]
+#[case(1, PacketStatus::Committed, PacketStatus::Acknowledged)]
+#[case(2, PacketStatus::Committed, PacketStatus::Received)]
+#[case(3, PacketStatus::Committed, PacketStatus::Unsent)]
+#[case(4, PacketStatus::Unsent, PacketStatus::Unsent)]
+#[tokio::test]
+async fn packet_status(
+    fixture: Fixture,
+    #[case] sequence: u64,
+    #[case] sent: PacketStatus,
+    #[case] received: PacketStatus,
+) {
+    let Fixture { ctx, .. } = fixture;
+
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let response: QueryPacketStatusResponse = client
+        .unary(
+            Request::new(QueryPacketStatusRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: ChannelId::new(0).to_string(),
+                sequence,
+            }),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/PacketStatus"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.status(), PacketLifecycleStatus { sent, received });
+}
+
+#[rstest]
+#[tokio::test]
+async fn packet_status_of_sequence_zero(fixture: Fixture) {
+    let Fixture { ctx, .. } = fixture;
+
+    let status = ExtensionQueryService::new(ctx)
+        .packet_status(Request::new(QueryPacketStatusRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            sequence: 0,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+#[tokio::test]
+async fn channel_upgrade(fixture: Fixture) {
+    let Fixture {
+        mut ctx, upgrade, ..
+    } = fixture;
+
+    let error_receipt = ErrorReceipt {
+        upgrade_sequence: 1,
+        message: "upgrade timed out".to_string(),
+    };
+
+    ctx.store_channel_upgrade_error(
+        &ChannelUpgradeErrorPath::new(&PortId::transfer(), &ChannelId::new(1)),
+        error_receipt.clone(),
+    )
+    .unwrap();
+
+    let service = ExtensionQueryService::new(ctx.clone());
+
+    let response = service
+        .channel_upgrade(Request::new(QueryChannelUpgradeRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(1).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.upgrade, Some(upgrade.into()));
+    assert!(!response.proof.is_empty());
+
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let response: QueryChannelUpgradeErrorResponse = client
+        .unary(
+            Request::new(QueryChannelUpgradeErrorRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: ChannelId::new(1).to_string(),
+            }),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/ChannelUpgradeError"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.error_receipt, Some(error_receipt.into()));
+
+    let status = service
+        .channel_upgrade(Request::new(QueryChannelUpgradeRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+
+    let status = service
+        .channel_upgrade_error(Request::new(QueryChannelUpgradeErrorRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+}
@@ -0,0 +1,437 @@
+use ibc::core::client::types::error::UpgradeClientError;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::UpgradeClientPath;
+use ibc::core::primitives::prelude::*;
+use ibc::cosmos_host::upgrade_proposal::{Plan, UpgradeValidationContext};
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::ibc::core::client::v1::query_server::Query as ClientQuery;
+use ibc_proto::ibc::core::client::v1::{
+    QueryClientParamsRequest, QueryClientStateRequest, QueryClientStatesRequest,
+    QueryClientStatusRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
+    QueryConsensusStatesRequest, QueryUpgradedClientStateRequest,
+    QueryUpgradedConsensusStateRequest,
+};
+use ibc_query::core::client::ClientQueryService;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use tonic::{Code, Request};
+
+/// An upgrade context holding at most one scheduled upgrade, which upgrades
+/// the mock clients to the first revision at the planned height.
+#[derive(Clone, Default)]
+pub struct MockUpgradeContext {
+    pub plan: Option<Plan>,
+}
+
+impl MockUpgradeContext {
+    fn upgraded_header(
+        &self,
+        upgrade_path: &UpgradeClientPath,
+    ) -> Result<MockHeader, UpgradeClientError> {
+        let plan = self.upgrade_plan()?;
+
+        let upgrade_height = match upgrade_path {
+            UpgradeClientPath::UpgradedClientState(height)
+            | UpgradeClientPath::UpgradedClientConsensusState(height) => *height,
+        };
+
+        if upgrade_height != plan.height {
+            return Err(UpgradeClientError::Other {
+                reason: format!("no upgrade is planned at height {upgrade_height}"),
+            });
+        }
+
+        Height::new(1, plan.height)
+            .map(MockHeader::new)
+            .map_err(|e| UpgradeClientError::Other {
+                reason: e.to_string(),
+            })
+    }
+}
+
+impl UpgradeValidationContext for MockUpgradeContext {
+    type V = MockContext;
+    type E = MockContext;
+    type AnyConsensusState = AnyConsensusState;
+    type AnyClientState = AnyClientState;
+
+    fn upgrade_plan(&self) -> Result<Plan, UpgradeClientError> {
+        self.plan
+            .clone()
+            .ok_or_else(|| UpgradeClientError::InvalidUpgradePlan {
+                reason: "no upgrade is scheduled".to_string(),
+            })
+    }
+
+    fn upgraded_client_state(
+        &self,
+        upgrade_path: &UpgradeClientPath,
+    ) -> Result<AnyClientState, UpgradeClientError> {
+        self.upgraded_header(upgrade_path)
+            .map(|header| MockClientState::new(header).into())
+    }
+
+    fn upgraded_consensus_state(
+        &self,
+        upgrade_path: &UpgradeClientPath,
+    ) -> Result<AnyConsensusState, UpgradeClientError> {
+        self.upgraded_header(upgrade_path)
+            .map(|header| MockConsensusState::new(header).into())
+    }
+}
+
+pub struct Fixture {
+    pub service: ClientQueryService<MockContext, MockUpgradeContext>,
+    pub client_ids: Vec<ClientId>,
+    pub consensus_heights: Vec<Height>,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_ids: Vec<_> = (0..3)
+        .map(|n| mock_client_type().build_client_id(n))
+        .collect();
+
+    let consensus_heights: Vec<_> = (1..=3).map(|h| Height::new(0, h).unwrap()).collect();
+
+    let ctx = client_ids
+        .iter()
+        .fold(MockContext::default(), |ctx, client_id| {
+            ctx.with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(Height::new(0, 3).unwrap())
+                    .consensus_state_heights(consensus_heights.clone())
+                    .build(),
+            )
+        });
+
+    Fixture {
+        service: ClientQueryService::new(ctx, MockUpgradeContext::default()),
+        client_ids,
+        consensus_heights,
+    }
+}
+
+fn page(key: Vec<u8>, offset: u64, limit: u64, reverse: bool) -> Option<PageRequest> {
+    Some(PageRequest {
+        key,
+        offset,
+        limit,
+        count_total: true,
+        reverse,
+    })
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_state(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_ids,
+        ..
+    } = fixture;
+
+    let response = service
+        .client_state(Request::new(QueryClientStateRequest {
+            client_id: client_ids[0].to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(response.client_state.is_some());
+    assert!(!response.proof.is_empty());
+    assert_eq!(
+        response.proof_height,
+        Some(Height::new(0, 5).unwrap().into())
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_state_not_found(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let missing_client_id = mock_client_type().build_client_id(7);
+
+    let status = service
+        .client_state(Request::new(QueryClientStateRequest {
+            client_id: missing_client_id.to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert!(status.message().contains(missing_client_id.as_str()));
+
+    let status = service
+        .client_state(Request::new(QueryClientStateRequest {
+            client_id: "not a client id".to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_states_pagination(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_ids,
+        ..
+    } = fixture;
+
+    let first_page = service
+        .client_states(Request::new(QueryClientStatesRequest {
+            pagination: page(vec![], 0, 2, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let ids: Vec<_> = first_page
+        .client_states
+        .iter()
+        .map(|client_state| client_state.client_id.clone())
+        .collect();
+
+    assert_eq!(ids, [client_ids[0].to_string(), client_ids[1].to_string()]);
+
+    let first_pagination = first_page.pagination.unwrap();
+
+    assert_eq!(first_pagination.total, 3);
+    assert!(!first_pagination.next_key.is_empty());
+
+    // The last page ends exactly at the last client state.
+    let last_page = service
+        .client_states(Request::new(QueryClientStatesRequest {
+            pagination: page(first_pagination.next_key, 0, 2, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(last_page.client_states.len(), 1);
+    assert_eq!(
+        last_page.client_states[0].client_id,
+        client_ids[2].to_string()
+    );
+    assert!(last_page.pagination.unwrap().next_key.is_empty());
+
+    // An offset past the last client state returns an empty page.
+    let past_the_end = service
+        .client_states(Request::new(QueryClientStatesRequest {
+            pagination: page(vec![], 3, 2, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(past_the_end.client_states.is_empty());
+    assert!(past_the_end.pagination.unwrap().next_key.is_empty());
+
+    let reversed = service
+        .client_states(Request::new(QueryClientStatesRequest {
+            pagination: page(vec![], 0, 1, true),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reversed.client_states[0].client_id,
+        client_ids[2].to_string()
+    );
+
+    let status = service
+        .client_states(Request::new(QueryClientStatesRequest {
+            pagination: page(reversed.pagination.unwrap().next_key, 1, 1, true),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+#[tokio::test]
+async fn consensus_state(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_ids,
+        consensus_heights,
+    } = fixture;
+
+    let response = service
+        .consensus_state(Request::new(QueryConsensusStateRequest {
+            client_id: client_ids[1].to_string(),
+            revision_number: 0,
+            revision_height: consensus_heights[0].revision_height(),
+            latest_height: false,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(response.consensus_state.is_some());
+    assert!(!response.proof.is_empty());
+
+    let status = service
+        .consensus_state(Request::new(QueryConsensusStateRequest {
+            client_id: client_ids[1].to_string(),
+            revision_number: 0,
+            revision_height: 4,
+            latest_height: false,
+        }))
+        .await
+        .unwrap_err();
+
+    assert!(status.message().contains(client_ids[1].as_str()));
+}
+
+#[rstest]
+#[tokio::test]
+async fn consensus_states_and_heights(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_ids,
+        consensus_heights,
+    } = fixture;
+
+    let states = service
+        .consensus_states(Request::new(QueryConsensusStatesRequest {
+            client_id: client_ids[0].to_string(),
+            pagination: page(vec![], 1, 1, false),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(states.consensus_states.len(), 1);
+    assert_eq!(
+        states.consensus_states[0].height,
+        Some(consensus_heights[1].into())
+    );
+    assert!(!states.pagination.unwrap().next_key.is_empty());
+
+    let heights = service
+        .consensus_state_heights(Request::new(QueryConsensusStateHeightsRequest {
+            client_id: client_ids[0].to_string(),
+            pagination: page(vec![], 0, 0, true),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let expected: Vec<_> = consensus_heights
+        .iter()
+        .rev()
+        .map(|height| (*height).into())
+        .collect();
+
+    assert_eq!(heights.consensus_state_heights, expected);
+
+    // A client without consensus states has no heights, rather than failing.
+    let heights = service
+        .consensus_state_heights(Request::new(QueryConsensusStateHeightsRequest {
+            client_id: mock_client_type().build_client_id(7).to_string(),
+            pagination: None,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(heights.consensus_state_heights.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_status_and_params(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_ids,
+        ..
+    } = fixture;
+
+    let status = service
+        .client_status(Request::new(QueryClientStatusRequest {
+            client_id: client_ids[0].to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(status.status, "Active");
+
+    let params = service
+        .client_params(Request::new(QueryClientParamsRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(params.params.is_some());
+}
+
+#[tokio::test]
+async fn upgraded_states() {
+    let service = ClientQueryService::new(
+        MockContext::default(),
+        MockUpgradeContext {
+            plan: Some(Plan {
+                name: "upgrade".to_string(),
+                height: 10,
+                info: String::new(),
+            }),
+        },
+    );
+
+    let client_state = service
+        .upgraded_client_state(Request::new(QueryUpgradedClientStateRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .upgraded_client_state
+        .unwrap();
+
+    let client_state = MockClientState::try_from(client_state).unwrap();
+
+    assert_eq!(client_state.header.height(), Height::new(1, 10).unwrap());
+
+    let consensus_state = service
+        .upgraded_consensus_state(Request::new(QueryUpgradedConsensusStateRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .upgraded_consensus_state;
+
+    assert!(consensus_state.is_some());
+}
+
+#[rstest]
+#[tokio::test]
+async fn upgraded_states_without_plan(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let status = service
+        .upgraded_client_state(Request::new(QueryUpgradedClientStateRequest {}))
+        .await
+        .unwrap_err();
+
+    assert!(status.message().contains("no upgrade is scheduled"));
+
+    let status = service
+        .upgraded_consensus_state(Request::new(QueryUpgradedConsensusStateRequest {}))
+        .await
+        .unwrap_err();
+
+    assert!(status.message().contains("no upgrade is scheduled"));
+}
@@ -0,0 +1,229 @@
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::ClientConnectionPath;
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::ibc::core::connection::v1::query_server::Query as ConnectionQuery;
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest, QueryConnectionClientStateRequest,
+    QueryConnectionConsensusStateRequest, QueryConnectionParamsRequest, QueryConnectionRequest,
+    QueryConnectionsRequest,
+};
+use ibc_query::core::connection::ConnectionQueryService;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use tonic::{Code, Request};
+
+pub struct Fixture {
+    pub service: ConnectionQueryService<MockContext>,
+    pub client_height: Height,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_height = Height::new(0, 3).unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        State::Open,
+        ClientId::default(),
+        Counterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = (0..3).fold(
+        MockContext::default().with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        ),
+        |ctx, n| ctx.with_connection(ConnectionId::new(n), conn_end.clone()),
+    );
+
+    ctx.store_connection_to_client(
+        &ClientConnectionPath::new(ClientId::default()),
+        ConnectionId::new(0),
+    )
+    .unwrap();
+
+    Fixture {
+        service: ConnectionQueryService::new(ctx),
+        client_height,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn connection(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let response = service
+        .connection(Request::new(QueryConnectionRequest {
+            connection_id: ConnectionId::new(1).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.connection.unwrap().client_id,
+        ClientId::default().to_string()
+    );
+    assert!(!response.proof.is_empty());
+
+    let status = service
+        .connection(Request::new(QueryConnectionRequest {
+            connection_id: ConnectionId::new(7).to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert!(status.message().contains("connection-7"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn connections_pagination(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let page = |key, offset, limit| {
+        Some(PageRequest {
+            key,
+            offset,
+            limit,
+            count_total: true,
+            reverse: false,
+        })
+    };
+
+    let first_page = service
+        .connections(Request::new(QueryConnectionsRequest {
+            pagination: page(vec![], 1, 1),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(first_page.connections.len(), 1);
+    assert_eq!(
+        first_page.connections[0].id,
+        ConnectionId::new(1).to_string()
+    );
+
+    let first_pagination = first_page.pagination.unwrap();
+
+    assert_eq!(first_pagination.total, 3);
+
+    // Resuming from the cursor neither skips nor repeats connections, and
+    // the page ending at the last connection has no cursor.
+    let last_page = service
+        .connections(Request::new(QueryConnectionsRequest {
+            pagination: page(first_pagination.next_key, 0, 1),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        last_page.connections[0].id,
+        ConnectionId::new(2).to_string()
+    );
+    assert!(last_page.pagination.unwrap().next_key.is_empty());
+
+    // Without a page request, all the connections are returned.
+    let all = service
+        .connections(Request::new(QueryConnectionsRequest { pagination: None }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(all.connections.len(), 3);
+    assert!(all.pagination.is_none());
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_connections(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let response = service
+        .client_connections(Request::new(QueryClientConnectionsRequest {
+            client_id: ClientId::default().to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.connection_paths,
+        [ConnectionId::new(0).to_string()]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn connection_client_and_consensus_states(fixture: Fixture) {
+    let Fixture {
+        service,
+        client_height,
+    } = fixture;
+
+    let client_state = service
+        .connection_client_state(Request::new(QueryConnectionClientStateRequest {
+            connection_id: ConnectionId::new(0).to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .identified_client_state
+        .unwrap();
+
+    assert_eq!(client_state.client_id, ClientId::default().to_string());
+
+    let consensus_state = service
+        .connection_consensus_state(Request::new(QueryConnectionConsensusStateRequest {
+            connection_id: ConnectionId::new(0).to_string(),
+            revision_number: client_height.revision_number(),
+            revision_height: client_height.revision_height(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(consensus_state.client_id, ClientId::default().to_string());
+    assert!(consensus_state.consensus_state.is_some());
+
+    let status = service
+        .connection_consensus_state(Request::new(QueryConnectionConsensusStateRequest {
+            connection_id: ConnectionId::new(0).to_string(),
+            revision_number: client_height.revision_number(),
+            revision_height: client_height.revision_height() + 1,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
+
+#[rstest]
+#[tokio::test]
+async fn connection_params(fixture: Fixture) {
+    let Fixture { service, .. } = fixture;
+
+    let params = service
+        .connection_params(Request::new(QueryConnectionParamsRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(params.params.is_some());
+}
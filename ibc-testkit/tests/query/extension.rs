@@ -0,0 +1,262 @@
+use core::time::Duration;
+
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::path::ReceiptPath;
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc_query::core::channel::{
+    PacketReceiptStatus, QueryNextChannelSequenceRequest, QueryPacketReceiptsResponse,
+    RawPacketSequenceRange, RawQueryPacketReceiptsRequest, MAX_PACKET_RECEIPTS_PER_QUERY,
+};
+use ibc_query::core::client::{
+    QueryClientExpiryRequest, QueryClientExpiryResponse, QueryNextClientSequenceRequest,
+    QueryNextClientSequenceResponse,
+};
+use ibc_query::core::connection::QueryNextConnectionSequenceRequest;
+use ibc_query::core::extension::{ExtensionQuery, ExtensionQueryServer, ExtensionQueryService};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use tonic::client::Grpc;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::{Code, Request};
+
+#[fixture]
+fn ctx() -> MockContext {
+    let mut ctx = MockContext::default();
+
+    for seq in [1, 3] {
+        ctx.store_packet_receipt(
+            &ReceiptPath::new(&PortId::transfer(), &ChannelId::default(), seq.into()),
+            Receipt::Ok,
+        )
+        .unwrap();
+    }
+
+    ctx.increase_client_counter().unwrap();
+    ctx.increase_client_counter().unwrap();
+    ctx.increase_connection_counter().unwrap();
+
+    ctx
+}
+
+fn receipts_request(
+    sequences: Vec<u64>,
+    range: Option<(u64, u64)>,
+) -> Request<RawQueryPacketReceiptsRequest> {
+    Request::new(RawQueryPacketReceiptsRequest {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        sequences,
+        range: range.map(|(start, end)| RawPacketSequenceRange { start, end }),
+    })
+}
+
+fn received(response: &QueryPacketReceiptsResponse) -> Vec<(u64, bool)> {
+    response
+        .receipts
+        .iter()
+        .map(|receipt| (receipt.sequence, receipt.received))
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn packet_receipts(ctx: MockContext) {
+    let service = ExtensionQueryService::new(ctx);
+
+    let response = service
+        .packet_receipts(receipts_request(vec![3, 2], None))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(received(&response), [(3, true), (2, false)]);
+
+    let response = service
+        .packet_receipts(receipts_request(vec![], Some((1, 3))))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(received(&response), [(1, true), (2, false), (3, true)]);
+
+    // A range of a single sequence is valid.
+    let response = service
+        .packet_receipts(receipts_request(vec![], Some((3, 3))))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(received(&response), [(3, true)]);
+}
+
+#[rstest]
+#[case::list_and_range(vec![1], Some((1, 3)))]
+#[case::empty_range(vec![], Some((3, 2)))]
+#[case::range_too_large(vec![], Some((1, MAX_PACKET_RECEIPTS_PER_QUERY + 1)))]
+#[case::list_too_large(
+    (1..=MAX_PACKET_RECEIPTS_PER_QUERY + 1).collect(),
+    None
+)]
+#[tokio::test]
+async fn packet_receipts_invalid_request(
+    ctx: MockContext,
+    #[case] sequences: Vec<u64>,
+    #[case] range: Option<(u64, u64)>,
+) {
+    let service = ExtensionQueryService::new(ctx);
+
+    let status = service
+        .packet_receipts(receipts_request(sequences, range))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+#[tokio::test]
+async fn packet_receipts_at_the_bounds(ctx: MockContext) {
+    let service = ExtensionQueryService::new(ctx);
+
+    let response = service
+        .packet_receipts(receipts_request(
+            vec![],
+            Some((1, MAX_PACKET_RECEIPTS_PER_QUERY)),
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.receipts.len() as u64,
+        MAX_PACKET_RECEIPTS_PER_QUERY
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn identifier_sequences(ctx: MockContext) {
+    let service = ExtensionQueryService::new(ctx);
+
+    let next_client_sequence = service
+        .next_client_sequence(Request::new(QueryNextClientSequenceRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(next_client_sequence.next_client_sequence, 2);
+
+    let next_connection_sequence = service
+        .next_connection_sequence(Request::new(QueryNextConnectionSequenceRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(next_connection_sequence.next_connection_sequence, 1);
+
+    let next_channel_sequence = service
+        .next_channel_sequence(Request::new(QueryNextChannelSequenceRequest {}))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(next_channel_sequence.next_channel_sequence, 0);
+}
+
+#[rstest]
+#[tokio::test]
+async fn served_over_grpc(ctx: MockContext) {
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let response: QueryPacketReceiptsResponse = client
+        .unary(
+            receipts_request(vec![1], None),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/PacketReceipts"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.receipts,
+        [PacketReceiptStatus {
+            sequence: 1,
+            received: true,
+        }]
+    );
+
+    client.ready().await.unwrap();
+
+    let response: QueryNextClientSequenceResponse = client
+        .unary(
+            Request::new(QueryNextClientSequenceRequest {}),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/NextClientSequence"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.next_client_sequence, 2);
+
+    client.ready().await.unwrap();
+
+    let status = client
+        .unary::<_, QueryNextClientSequenceResponse, _>(
+            Request::new(QueryNextClientSequenceRequest {}),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/Unknown"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Unimplemented);
+}
+
+#[tokio::test]
+async fn client_expiry_served_over_grpc() {
+    let client_id = tm_client_type().build_client_id(0);
+    let latest_timestamp = Timestamp::now();
+    let trusting_period = Duration::from_secs(64000);
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_type(tm_client_type())
+            .client_id(client_id.clone())
+            .latest_height(Height::new(1, 3).unwrap())
+            .latest_timestamp(latest_timestamp)
+            .trusting_period(trusting_period)
+            .build(),
+    );
+
+    let mut client = Grpc::new(ExtensionQueryServer::new(ExtensionQueryService::new(ctx)));
+
+    client.ready().await.unwrap();
+
+    let response: QueryClientExpiryResponse = client
+        .unary(
+            Request::new(QueryClientExpiryRequest {
+                client_id: client_id.to_string(),
+            }),
+            PathAndQuery::from_static("/ibc_rs.query.v1.ExtensionQuery/ClientExpiry"),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        response.expires_at,
+        (latest_timestamp + trusting_period).unwrap().nanoseconds()
+    );
+    assert!(response.time_until_expiry.is_some());
+}
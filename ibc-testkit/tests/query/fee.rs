@@ -0,0 +1,421 @@
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+use ibc_proto::ibc::applications::fee::v1::query_server::Query as FeeQuery;
+use ibc_proto::ibc::applications::fee::v1::{
+    QueryCounterpartyPayeeRequest, QueryFeeEnabledChannelRequest, QueryFeeEnabledChannelsRequest,
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketsForChannelRequest,
+    QueryIncentivizedPacketsRequest, QueryPayeeRequest, QueryTotalAckFeesRequest,
+    QueryTotalRecvFeesRequest, QueryTotalTimeoutFeesRequest,
+};
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+use ibc_query::apps::fee::{
+    Fee, FeeQueryContext, FeeQueryService, IdentifiedPacketFees, PacketFee, PacketId,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use rstest::*;
+use tonic::{Code, Request};
+
+/// The largest amount of a coin.
+const MAX_AMOUNT: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+/// A fee middleware state kept in memory.
+#[derive(Clone, Default)]
+pub struct MockFeeContext {
+    pub packet_fees: Vec<IdentifiedPacketFees>,
+    pub payees: Vec<(ChannelId, Signer, Signer)>,
+    pub counterparty_payees: Vec<(ChannelId, Signer, Signer)>,
+    pub fee_enabled_channels: Vec<(PortId, ChannelId)>,
+}
+
+impl FeeQueryContext for MockFeeContext {
+    fn incentivized_packets(&self) -> Result<Vec<IdentifiedPacketFees>, ContextError> {
+        Ok(self.packet_fees.clone())
+    }
+
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Option<Vec<PacketFee>>, ContextError> {
+        Ok(self
+            .packet_fees
+            .iter()
+            .find(|packet_fees| &packet_fees.packet_id == packet_id)
+            .map(|packet_fees| packet_fees.packet_fees.clone()))
+    }
+
+    fn payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(find_payee(&self.payees, channel_id, relayer))
+    }
+
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(find_payee(&self.counterparty_payees, channel_id, relayer))
+    }
+
+    fn fee_enabled_channels(&self) -> Result<Vec<(PortId, ChannelId)>, ContextError> {
+        Ok(self.fee_enabled_channels.clone())
+    }
+}
+
+fn find_payee(
+    payees: &[(ChannelId, Signer, Signer)],
+    channel_id: &ChannelId,
+    relayer: &Signer,
+) -> Option<Signer> {
+    payees
+        .iter()
+        .find(|(c, r, _)| c == channel_id && r == relayer)
+        .map(|(_, _, payee)| payee.clone())
+}
+
+fn coins(coins: &str) -> Vec<PrefixedCoin> {
+    if coins.is_empty() {
+        return vec![];
+    }
+
+    PrefixedCoin::from_string_list(coins).unwrap()
+}
+
+fn packet_id(channel_id: u64, sequence: u64) -> PacketId {
+    PacketId {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(channel_id),
+        sequence: Sequence::from(sequence),
+    }
+}
+
+fn raw_packet_id(channel_id: u64, sequence: u64) -> Option<RawPacketId> {
+    Some(RawPacketId {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::new(channel_id).to_string(),
+        sequence,
+    })
+}
+
+fn packet_fee(recv_fee: &str, ack_fee: &str, timeout_fee: &str) -> PacketFee {
+    PacketFee {
+        fee: Fee {
+            recv_fee: coins(recv_fee),
+            ack_fee: coins(ack_fee),
+            timeout_fee: coins(timeout_fee),
+        },
+        refund_address: dummy_account_id(),
+        relayers: vec![],
+    }
+}
+
+/// Sets up the fees of packets 1 and 2 of channel 0 and of packet 1 of
+/// channel 1, the first one of them incentivized twice.
+#[fixture]
+fn service() -> FeeQueryService<MockFeeContext> {
+    let relayer = Signer::from("relayer".to_string());
+
+    FeeQueryService::new(MockFeeContext {
+        packet_fees: vec![
+            IdentifiedPacketFees {
+                packet_id: packet_id(0, 1),
+                packet_fees: vec![
+                    packet_fee("10uatom,5stake", "3uatom", "1uatom"),
+                    packet_fee("20uatom", "3uatom", "2stake"),
+                ],
+            },
+            IdentifiedPacketFees {
+                packet_id: packet_id(0, 2),
+                packet_fees: vec![packet_fee(&format!("{MAX_AMOUNT}uatom"), "", "")],
+            },
+            IdentifiedPacketFees {
+                packet_id: packet_id(1, 1),
+                packet_fees: vec![packet_fee("1uatom", "1uatom", "1uatom")],
+            },
+        ],
+        payees: vec![(
+            ChannelId::new(0),
+            relayer.clone(),
+            Signer::from("payee".to_string()),
+        )],
+        counterparty_payees: vec![(
+            ChannelId::new(0),
+            relayer,
+            Signer::from("counterparty-payee".to_string()),
+        )],
+        fee_enabled_channels: vec![
+            (PortId::transfer(), ChannelId::new(0)),
+            (PortId::transfer(), ChannelId::new(1)),
+        ],
+    })
+}
+
+fn raw_coins(coins: &[(&str, u64)]) -> Vec<RawCoin> {
+    coins
+        .iter()
+        .map(|(denom, amount)| RawCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        })
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn incentivized_packets(service: FeeQueryService<MockFeeContext>) {
+    let page = service
+        .incentivized_packets(Request::new(QueryIncentivizedPacketsRequest {
+            pagination: Some(PageRequest {
+                limit: 2,
+                count_total: true,
+                ..Default::default()
+            }),
+            query_height: 0,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(page.incentivized_packets.len(), 2);
+
+    let pagination = page.pagination.unwrap();
+
+    assert_eq!(pagination.total, 3);
+    assert!(!pagination.next_key.is_empty());
+
+    let channel_page = service
+        .incentivized_packets_for_channel(Request::new(QueryIncentivizedPacketsForChannelRequest {
+            pagination: Some(PageRequest {
+                offset: 1,
+                ..Default::default()
+            }),
+            port_id: PortId::transfer().to_string(),
+            channel_id: ChannelId::new(0).to_string(),
+            query_height: 0,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(channel_page.incentivized_packets.len(), 1);
+    assert_eq!(
+        channel_page.incentivized_packets[0].packet_id,
+        raw_packet_id(0, 2)
+    );
+    assert!(channel_page.pagination.unwrap().next_key.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn incentivized_packet(service: FeeQueryService<MockFeeContext>) {
+    let packet = service
+        .incentivized_packet(Request::new(QueryIncentivizedPacketRequest {
+            packet_id: raw_packet_id(0, 1),
+            query_height: 0,
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .incentivized_packet
+        .unwrap();
+
+    assert_eq!(packet.packet_fees.len(), 2);
+
+    let status = service
+        .incentivized_packet(Request::new(QueryIncentivizedPacketRequest {
+            packet_id: raw_packet_id(0, 3),
+            query_height: 0,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+
+    let status = service
+        .incentivized_packet(Request::new(QueryIncentivizedPacketRequest {
+            packet_id: None,
+            query_height: 0,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[rstest]
+#[tokio::test]
+async fn total_fees(service: FeeQueryService<MockFeeContext>) {
+    let recv_fees = service
+        .total_recv_fees(Request::new(QueryTotalRecvFeesRequest {
+            packet_id: raw_packet_id(0, 1),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .recv_fees;
+
+    assert_eq!(recv_fees, raw_coins(&[("stake", 5), ("uatom", 30)]));
+
+    let ack_fees = service
+        .total_ack_fees(Request::new(QueryTotalAckFeesRequest {
+            packet_id: raw_packet_id(0, 1),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .ack_fees;
+
+    assert_eq!(ack_fees, raw_coins(&[("uatom", 6)]));
+
+    let timeout_fees = service
+        .total_timeout_fees(Request::new(QueryTotalTimeoutFeesRequest {
+            packet_id: raw_packet_id(0, 1),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .timeout_fees;
+
+    assert_eq!(timeout_fees, raw_coins(&[("stake", 2), ("uatom", 1)]));
+
+    let status = service
+        .total_recv_fees(Request::new(QueryTotalRecvFeesRequest {
+            packet_id: raw_packet_id(1, 2),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[rstest]
+#[tokio::test]
+async fn total_fees_at_the_amount_bound(service: FeeQueryService<MockFeeContext>) {
+    let recv_fees = service
+        .total_recv_fees(Request::new(QueryTotalRecvFeesRequest {
+            packet_id: raw_packet_id(0, 2),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .recv_fees;
+
+    assert_eq!(recv_fees[0].amount, MAX_AMOUNT);
+
+    let mut fee_context = MockFeeContext::default();
+
+    fee_context.packet_fees.push(IdentifiedPacketFees {
+        packet_id: packet_id(0, 1),
+        packet_fees: vec![
+            packet_fee(&format!("{MAX_AMOUNT}uatom"), "", ""),
+            packet_fee("1uatom", "", ""),
+        ],
+    });
+
+    let status = FeeQueryService::new(fee_context)
+        .total_recv_fees(Request::new(QueryTotalRecvFeesRequest {
+            packet_id: raw_packet_id(0, 1),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::OutOfRange);
+}
+
+#[rstest]
+#[tokio::test]
+async fn payees(service: FeeQueryService<MockFeeContext>) {
+    let payee = service
+        .payee(Request::new(QueryPayeeRequest {
+            channel_id: ChannelId::new(0).to_string(),
+            relayer: "relayer".to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(payee.payee_address, "payee");
+
+    let counterparty_payee = service
+        .counterparty_payee(Request::new(QueryCounterpartyPayeeRequest {
+            channel_id: ChannelId::new(0).to_string(),
+            relayer: "relayer".to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(counterparty_payee.counterparty_payee, "counterparty-payee");
+
+    let status = service
+        .payee(Request::new(QueryPayeeRequest {
+            channel_id: ChannelId::new(1).to_string(),
+            relayer: "relayer".to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+
+    let status = service
+        .counterparty_payee(Request::new(QueryCounterpartyPayeeRequest {
+            channel_id: ChannelId::new(0).to_string(),
+            relayer: "another-relayer".to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[rstest]
+#[tokio::test]
+async fn fee_enabled_channels(service: FeeQueryService<MockFeeContext>) {
+    let page = service
+        .fee_enabled_channels(Request::new(QueryFeeEnabledChannelsRequest {
+            pagination: Some(PageRequest {
+                limit: 2,
+                count_total: true,
+                ..Default::default()
+            }),
+            query_height: 0,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(page.fee_enabled_channels.len(), 2);
+
+    let pagination = page.pagination.unwrap();
+
+    assert_eq!(pagination.total, 2);
+    assert!(pagination.next_key.is_empty());
+
+    for (channel_id, fee_enabled) in [(1, true), (2, false)] {
+        let response = service
+            .fee_enabled_channel(Request::new(QueryFeeEnabledChannelRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: ChannelId::new(channel_id).to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.fee_enabled, fee_enabled);
+    }
+
+    let status = service
+        .fee_enabled_channel(Request::new(QueryFeeEnabledChannelRequest {
+            port_id: PortId::transfer().to_string(),
+            channel_id: "not a channel id".to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
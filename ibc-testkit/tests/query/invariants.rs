@@ -0,0 +1,209 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ReceiptPath};
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc_query::core::invariants::{
+    InvariantRegistry, ACKNOWLEDGEMENT_RECEIPTS_INVARIANT_ROUTE,
+    CHANNEL_CONNECTIONS_INVARIANT_ROUTE, PACKET_SEQUENCES_INVARIANT_ROUTE,
+};
+use ibc_query::error::QueryError;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+/// Sets up a consistent state with an open unordered channel over an open
+/// connection, where packet 1 was sent and packet 1 was received and
+/// acknowledged.
+#[fixture]
+fn ctx() -> MockContext {
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 3).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end)
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            channel_end(Order::Unordered, ConnectionId::default()),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::default(), 2.into())
+        .with_recv_sequence(PortId::transfer(), ChannelId::default(), 2.into())
+        .with_ack_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+        .with_packet_commitment(
+            PortId::transfer(),
+            ChannelId::default(),
+            1.into(),
+            PacketCommitment::from(vec![1]),
+        );
+
+    store_ack(&mut ctx, 1.into());
+
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&PortId::transfer(), &ChannelId::default(), 1.into()),
+        Receipt::Ok,
+    )
+    .unwrap();
+
+    ctx
+}
+
+fn channel_end(ordering: Order, connection_id: ConnectionId) -> ChannelEnd {
+    ChannelEnd::new(
+        State::Open,
+        ordering,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![connection_id],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+}
+
+fn store_ack(ctx: &mut MockContext, seq: Sequence) {
+    ctx.store_packet_acknowledgement(
+        &AckPath::new(&PortId::transfer(), &ChannelId::default(), seq),
+        AcknowledgementCommitment::from(vec![1]),
+    )
+    .unwrap();
+}
+
+fn broken_routes(ctx: &MockContext) -> Vec<String> {
+    InvariantRegistry::with_core_invariants()
+        .run_all(ctx)
+        .into_iter()
+        .filter(|report| report.broken)
+        .map(|report| report.route)
+        .collect()
+}
+
+#[rstest]
+fn consistent_state(ctx: MockContext) {
+    let registry = InvariantRegistry::with_core_invariants();
+
+    assert_eq!(
+        registry.routes().collect::<Vec<_>>(),
+        [
+            CHANNEL_CONNECTIONS_INVARIANT_ROUTE,
+            PACKET_SEQUENCES_INVARIANT_ROUTE,
+            ACKNOWLEDGEMENT_RECEIPTS_INVARIANT_ROUTE,
+        ]
+    );
+
+    assert!(broken_routes(&ctx).is_empty());
+    assert!(registry.assert_invariants(&ctx).is_ok());
+}
+
+#[rstest]
+fn channel_with_missing_connection(ctx: MockContext) {
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::new(1),
+            channel_end(Order::Unordered, ConnectionId::new(1)),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::new(1), 1.into())
+        .with_recv_sequence(PortId::transfer(), ChannelId::new(1), 1.into())
+        .with_ack_sequence(PortId::transfer(), ChannelId::new(1), 1.into());
+
+    assert_eq!(broken_routes(&ctx), [CHANNEL_CONNECTIONS_INVARIANT_ROUTE]);
+
+    let report = InvariantRegistry::with_core_invariants()
+        .run(&ctx, CHANNEL_CONNECTIONS_INVARIANT_ROUTE)
+        .unwrap();
+
+    assert!(report
+        .message
+        .contains("references missing connection connection-1"));
+}
+
+#[rstest]
+fn commitment_beyond_next_sequence_send(ctx: MockContext) {
+    let ctx = ctx.with_packet_commitment(
+        PortId::transfer(),
+        ChannelId::default(),
+        2.into(),
+        PacketCommitment::from(vec![2]),
+    );
+
+    assert_eq!(broken_routes(&ctx), [PACKET_SEQUENCES_INVARIANT_ROUTE]);
+
+    let error = InvariantRegistry::with_core_invariants()
+        .assert_invariants(&ctx)
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        QueryError::InvariantBroken { route, .. } if route == PACKET_SEQUENCES_INVARIANT_ROUTE
+    ));
+}
+
+#[rstest]
+fn acknowledgement_without_receipt(mut ctx: MockContext) {
+    store_ack(&mut ctx, 3.into());
+
+    assert_eq!(
+        broken_routes(&ctx),
+        [ACKNOWLEDGEMENT_RECEIPTS_INVARIANT_ROUTE]
+    );
+}
+
+#[rstest]
+fn unreadable_state(ctx: MockContext) {
+    // The next sequences of the channel are not stored.
+    let ctx = ctx.with_channel(
+        PortId::transfer(),
+        ChannelId::new(1),
+        channel_end(Order::Unordered, ConnectionId::default()),
+    );
+
+    let report = InvariantRegistry::with_core_invariants()
+        .run(&ctx, PACKET_SEQUENCES_INVARIANT_ROUTE)
+        .unwrap();
+
+    assert!(report.broken);
+    assert!(report.message.contains("failed to read the state"));
+}
+
+#[rstest]
+fn custom_invariant(ctx: MockContext) {
+    fn no_channel(ctx: &MockContext) -> Result<Vec<String>, ContextError> {
+        Ok(ibc_query::core::context::QueryContext::channel_ends(ctx)?
+            .into_iter()
+            .map(|channel| format!("channel {} exists", channel.channel_id))
+            .collect())
+    }
+
+    let registry = InvariantRegistry::new().register("host/no-channel", no_channel);
+
+    let report = registry.run(&ctx, "host/no-channel").unwrap();
+
+    assert!(report.broken);
+    assert!(report.message.contains("channel channel-0 exists"));
+
+    assert!(registry.run(&ctx, "host/unknown").is_none());
+    assert!(InvariantRegistry::new().assert_invariants(&ctx).is_ok());
+}
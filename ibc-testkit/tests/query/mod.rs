@@ -0,0 +1,11 @@
+pub mod abci;
+pub mod batch;
+pub mod channel;
+pub mod client;
+pub mod connection;
+pub mod extension;
+pub mod fee;
+pub mod invariants;
+pub mod server;
+pub mod tx_builder;
+pub mod wasm;
@@ -0,0 +1,53 @@
+use ibc_query::server::QueryServerBuilder;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+use tonic::codegen::http::Request;
+use tonic::codegen::Service;
+use tonic::transport::{Body, Server};
+
+use super::client::MockUpgradeContext;
+use super::fee::MockFeeContext;
+use super::wasm::MockWasmContext;
+
+const FEE_ENABLED_CHANNELS: &str = "/ibc.applications.fee.v1.Query/FeeEnabledChannels";
+
+const WASM_CHECKSUMS: &str = "/ibc.lightclients.wasm.v1.Query/Checksums";
+
+fn builder() -> QueryServerBuilder<MockContext, MockUpgradeContext> {
+    QueryServerBuilder::new(MockContext::default(), MockUpgradeContext::default())
+}
+
+/// Returns whether the router built by the given builder serves the given
+/// method, i.e. does not answer its (empty) request with `Unimplemented`.
+async fn is_served(
+    builder: QueryServerBuilder<MockContext, MockUpgradeContext>,
+    method: &str,
+) -> bool {
+    let mut service = builder.add_to(&mut Server::builder()).into_service();
+
+    let request = Request::post(method)
+        .header("content-type", "application/grpc")
+        .body(Body::from(vec![0u8; 5]))
+        .expect("valid request");
+
+    let response = service.call(request).await.expect("infallible");
+
+    response
+        .headers()
+        .get("grpc-status")
+        .map_or(true, |status| status != "12")
+}
+
+#[rstest]
+#[case::fee(FEE_ENABLED_CHANNELS)]
+#[case::wasm(WASM_CHECKSUMS)]
+#[tokio::test]
+async fn served_once_their_context_is_provided(#[case] method: &str) {
+    assert!(!is_served(builder(), method).await);
+
+    let builder = builder()
+        .with_fee_context(MockFeeContext::default())
+        .with_wasm_context(MockWasmContext::default());
+
+    assert!(is_served(builder, method).await);
+}
@@ -0,0 +1,382 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::ReceiptPath;
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc_query::core::channel::typed::{channel, packet_commitment, packet_receipt};
+use ibc_query::core::connection::typed::connection;
+use ibc_query::core::typed::{ConsensusStateWithHeight, IdentifiedClientState, Proven};
+use ibc_query::error::QueryError;
+use ibc_query::tx_builder::{
+    chan_open_ack, conn_open_confirm, conn_open_try, create_client, recv_packet, timeout_ordered,
+    timeout_unordered, update_client,
+};
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+pub struct Fixture {
+    pub ctx_a: MockContext,
+    pub packet: Packet,
+    pub conn_end: ConnectionEnd,
+    pub chan_end: ChannelEnd,
+}
+
+/// Sets up chain A with a committed packet, sent over an open transfer
+/// channel to the same channel identifiers on chain B.
+#[fixture]
+fn fixture() -> Fixture {
+    let ctx_a = MockContext::default();
+
+    let timeout_height = ctx_a.latest_height().add(10);
+
+    let packet =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(timeout_height.revision_height()))
+            .unwrap()
+            .packet;
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let commitment = compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    );
+
+    let ctx_a = ctx_a
+        .with_connection(ConnectionId::default(), conn_end.clone())
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end.clone(),
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            commitment,
+        );
+
+    Fixture {
+        ctx_a,
+        packet,
+        conn_end,
+        chan_end,
+    }
+}
+
+fn proven<T>(value: T, proof_height: Height) -> Proven<T> {
+    Proven {
+        value,
+        proof: vec![1],
+        proof_height,
+    }
+}
+
+#[rstest]
+fn recv_packet_validates_on_counterparty(fixture: Fixture) {
+    let Fixture {
+        ctx_a,
+        packet,
+        conn_end,
+        chan_end,
+    } = fixture;
+
+    let commitment_on_a = packet_commitment(
+        &ctx_a,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        packet.seq_on_a,
+    )
+    .unwrap();
+
+    let proof_height = commitment_on_a.proof_height;
+
+    assert_eq!(proof_height, ctx_a.latest_height());
+
+    let msg = recv_packet(packet.clone(), commitment_on_a, dummy_account_id()).unwrap();
+
+    assert_eq!(msg.proof_height_on_a, proof_height);
+
+    let mut ctx_b = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(proof_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end,
+        )
+        .with_height(proof_height.increment());
+
+    ctx_b
+        .get_client_execution_context()
+        .store_update_meta(
+            ClientId::default(),
+            proof_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&ctx_b, &router, MsgEnvelope::from(PacketMsg::from(msg)));
+
+    assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+}
+
+#[rstest]
+fn timeout_unordered_from_receipt(fixture: Fixture) {
+    let Fixture { ctx_a, packet, .. } = fixture;
+
+    // Chain A stands in for chain B here, as the receipt is looked up under
+    // the same identifiers.
+    let receipt_on_b = packet_receipt(
+        &ctx_a,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        packet.seq_on_a,
+    )
+    .unwrap();
+
+    assert!(!receipt_on_b.value);
+
+    let msg = timeout_unordered(packet.clone(), receipt_on_b, dummy_account_id()).unwrap();
+
+    assert_eq!(msg.next_seq_recv_on_b, packet.seq_on_a);
+    assert_eq!(msg.proof_height_on_b, ctx_a.latest_height());
+
+    let mut ctx_b = ctx_a;
+
+    ctx_b
+        .store_packet_receipt(
+            &ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a),
+            Receipt::Ok,
+        )
+        .unwrap();
+
+    let receipt_on_b = packet_receipt(
+        &ctx_b,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        packet.seq_on_a,
+    )
+    .unwrap();
+
+    assert!(receipt_on_b.value);
+
+    let res = timeout_unordered(packet, receipt_on_b, dummy_account_id());
+
+    assert!(matches!(res, Err(QueryError::InvalidRequest { .. })));
+}
+
+#[rstest]
+fn timeout_ordered_after_receive(fixture: Fixture) {
+    let Fixture { ctx_a, packet, .. } = fixture;
+
+    let proof_height = ctx_a.latest_height();
+
+    let msg = timeout_ordered(
+        packet.clone(),
+        proven(packet.seq_on_a, proof_height),
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    assert_eq!(msg.next_seq_recv_on_b, packet.seq_on_a);
+
+    let next_seq_recv_on_b = Sequence::from(packet.seq_on_a.value() + 1);
+
+    let res = timeout_ordered(
+        packet,
+        proven(next_seq_recv_on_b, proof_height),
+        dummy_account_id(),
+    );
+
+    assert!(matches!(res, Err(QueryError::InvalidRequest { .. })));
+}
+
+#[rstest]
+fn conn_open_try_mismatched_proof_heights(fixture: Fixture) {
+    let Fixture { ctx_a, .. } = fixture;
+
+    let connection_on_a = connection(&ctx_a, &ConnectionId::default()).unwrap();
+
+    let proof_height = connection_on_a.proof_height;
+
+    let header = MockHeader::new(proof_height);
+
+    let client_state_of_b_on_a = IdentifiedClientState {
+        client_id: ClientId::default(),
+        client_state: MockClientState::new(header),
+    };
+
+    let consensus_state_of_b_on_a = ConsensusStateWithHeight {
+        height: proof_height,
+        consensus_state: MockConsensusState::new(header),
+    };
+
+    let msg = conn_open_try(
+        ConnectionId::default(),
+        Default::default(),
+        connection_on_a.clone(),
+        proven(client_state_of_b_on_a.clone(), proof_height),
+        proven(consensus_state_of_b_on_a.clone(), proof_height),
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    assert_eq!(msg.proofs_height_on_a, proof_height);
+    assert_eq!(msg.consensus_height_of_b_on_a, proof_height);
+    assert_eq!(
+        msg.counterparty.connection_id(),
+        Some(&ConnectionId::default())
+    );
+
+    let res = conn_open_try(
+        ConnectionId::default(),
+        Default::default(),
+        connection_on_a,
+        proven(client_state_of_b_on_a, proof_height),
+        proven(consensus_state_of_b_on_a, proof_height.increment()),
+        dummy_account_id(),
+    );
+
+    assert!(matches!(res, Err(QueryError::InvalidRequest { .. })));
+}
+
+#[rstest]
+fn handshake_without_counterparty_identifier(fixture: Fixture) {
+    let Fixture {
+        ctx_a,
+        conn_end,
+        chan_end,
+        ..
+    } = fixture;
+
+    let proof_height = ctx_a.latest_height();
+
+    let msg = conn_open_confirm(
+        &ConnectionId::default(),
+        proven(conn_end.clone(), proof_height),
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    assert_eq!(msg.conn_id_on_b, ConnectionId::default());
+
+    let conn_end_in_init = ConnectionEnd::new(
+        ConnectionState::Init,
+        conn_end.client_id().clone(),
+        ConnectionCounterparty::new(
+            conn_end.counterparty().client_id().clone(),
+            None,
+            Default::default(),
+        ),
+        conn_end.versions().to_vec(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let res = conn_open_confirm(
+        &ConnectionId::default(),
+        proven(conn_end_in_init, proof_height),
+        dummy_account_id(),
+    );
+
+    assert!(matches!(res, Err(QueryError::InvalidRequest { .. })));
+
+    let channel_on_b = channel(&ctx_a, &PortId::transfer(), &ChannelId::default()).unwrap();
+
+    let msg = chan_open_ack(
+        &PortId::transfer(),
+        ChannelId::default(),
+        channel_on_b,
+        dummy_account_id(),
+    )
+    .unwrap();
+
+    assert_eq!(msg.chan_id_on_a, ChannelId::default());
+
+    let chan_end_in_init = ChannelEnd::new(
+        State::Init,
+        *chan_end.ordering(),
+        Counterparty::new(PortId::transfer(), None),
+        chan_end.connection_hops().clone(),
+        chan_end.version().clone(),
+    )
+    .unwrap();
+
+    let res = chan_open_ack(
+        &PortId::transfer(),
+        ChannelId::default(),
+        proven(chan_end_in_init, proof_height),
+        dummy_account_id(),
+    );
+
+    assert!(matches!(res, Err(QueryError::InvalidRequest { .. })));
+}
+
+#[test]
+fn client_messages() {
+    let header = MockHeader::new(Height::new(0, 5).unwrap());
+
+    let msg = create_client(
+        MockClientState::new(header),
+        MockConsensusState::new(header),
+        dummy_account_id(),
+    );
+
+    assert_eq!(msg.client_state, MockClientState::new(header).into());
+    assert_eq!(msg.consensus_state, MockConsensusState::new(header).into());
+
+    let msg = update_client(ClientId::default(), header, dummy_account_id());
+
+    assert_eq!(msg.client_id, ClientId::default());
+    assert_eq!(msg.client_message, header.into());
+}
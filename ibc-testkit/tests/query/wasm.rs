@@ -0,0 +1,138 @@
+use ibc::clients::wasm::checksum::{checksum_to_hex, compute_checksum, Checksum};
+use ibc::clients::wasm::context::WasmValidationContext;
+use ibc::clients::wasm::types::client_state::ClientState as WasmClientState;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::ibc::lightclients::wasm::v1::query_server::Query as WasmQuery;
+use ibc_proto::ibc::lightclients::wasm::v1::{QueryChecksumsRequest, QueryCodeRequest};
+use ibc_query::clients::wasm::WasmQueryService;
+use rstest::*;
+use tonic::{Code, Request};
+
+/// A store of Wasm codes kept in memory, without any Wasm client.
+#[derive(Clone, Default)]
+pub struct MockWasmContext {
+    pub codes: BTreeMap<Checksum, Vec<u8>>,
+}
+
+impl WasmValidationContext for MockWasmContext {
+    fn wasm_code(&self, checksum: &Checksum) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(self.codes.get(checksum).cloned())
+    }
+
+    fn checksums(&self) -> Result<Vec<Checksum>, ContextError> {
+        Ok(self.codes.keys().cloned().collect())
+    }
+
+    fn wasm_client_state(&self, client_id: &ClientId) -> Result<WasmClientState, ContextError> {
+        Err(ClientError::ClientStateNotFound {
+            client_id: client_id.clone(),
+        }
+        .into())
+    }
+}
+
+#[fixture]
+fn codes() -> Vec<Vec<u8>> {
+    (0..3u8).map(|n| vec![0, 97, 115, 109, n]).collect()
+}
+
+#[fixture]
+fn service(codes: Vec<Vec<u8>>) -> WasmQueryService<MockWasmContext> {
+    WasmQueryService::new(MockWasmContext {
+        codes: codes
+            .into_iter()
+            .map(|code| (compute_checksum(&code), code))
+            .collect(),
+    })
+}
+
+#[rstest]
+#[tokio::test]
+async fn checksums_pagination(service: WasmQueryService<MockWasmContext>, codes: Vec<Vec<u8>>) {
+    let mut checksums: Vec<_> = codes.iter().map(|code| compute_checksum(code)).collect();
+
+    checksums.sort();
+
+    let first_page = service
+        .checksums(Request::new(QueryChecksumsRequest {
+            pagination: Some(PageRequest {
+                limit: 2,
+                count_total: true,
+                ..Default::default()
+            }),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        first_page.checksums,
+        [
+            checksum_to_hex(&checksums[0]),
+            checksum_to_hex(&checksums[1])
+        ]
+    );
+
+    let first_pagination = first_page.pagination.unwrap();
+
+    assert_eq!(first_pagination.total, 3);
+
+    let last_page = service
+        .checksums(Request::new(QueryChecksumsRequest {
+            pagination: Some(PageRequest {
+                key: first_pagination.next_key,
+                limit: 2,
+                ..Default::default()
+            }),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(last_page.checksums, [checksum_to_hex(&checksums[2])]);
+    assert!(last_page.pagination.unwrap().next_key.is_empty());
+
+    let empty = WasmQueryService::new(MockWasmContext::default())
+        .checksums(Request::new(QueryChecksumsRequest { pagination: None }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(empty.checksums.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn code(service: WasmQueryService<MockWasmContext>, codes: Vec<Vec<u8>>) {
+    let response = service
+        .code(Request::new(QueryCodeRequest {
+            checksum: checksum_to_hex(&compute_checksum(&codes[1])),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.data, codes[1]);
+
+    let status = service
+        .code(Request::new(QueryCodeRequest {
+            checksum: checksum_to_hex(&compute_checksum(b"missing")),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+
+    let status = service
+        .code(Request::new(QueryCodeRequest {
+            checksum: "not hex".to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}